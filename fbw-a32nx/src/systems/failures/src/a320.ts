@@ -27,6 +27,12 @@ export const A320Failure = Object.freeze({
     Generator1: 24020,
     Generator2: 24021,
     ApuGenerator1: 24030,
+    Battery1ThermalRunaway: 24040,
+    Battery2ThermalRunaway: 24041,
+    Generator1VoltageRegulator: 24050,
+    Generator2VoltageRegulator: 24051,
+    Idg1ConstantSpeedDrive: 24060,
+    Idg2ConstantSpeedDrive: 24061,
     AlternatingCurrent1: 24100,
     AlternatingCurrent2: 24101,
     AlternatingCurrentEssential: 24102,
@@ -50,6 +56,80 @@ export const A320Failure = Object.freeze({
     Fcdc1Failure: 27005,
     Fcdc2Failure: 27006,
 
+    LeftAileronBlueActuatorJam: 27100,
+    LeftAileronGreenActuatorJam: 27101,
+    RightAileronBlueActuatorJam: 27102,
+    RightAileronGreenActuatorJam: 27103,
+    LeftElevatorBlueActuatorJam: 27104,
+    LeftElevatorGreenActuatorJam: 27105,
+    RightElevatorBlueActuatorJam: 27106,
+    RightElevatorYellowActuatorJam: 27107,
+    RudderGreenActuatorJam: 27108,
+    RudderBlueActuatorJam: 27109,
+    RudderYellowActuatorJam: 27110,
+    LeftSpoiler1ActuatorJam: 27111,
+    LeftSpoiler2ActuatorJam: 27112,
+    LeftSpoiler3ActuatorJam: 27113,
+    LeftSpoiler4ActuatorJam: 27114,
+    LeftSpoiler5ActuatorJam: 27115,
+    RightSpoiler1ActuatorJam: 27116,
+    RightSpoiler2ActuatorJam: 27117,
+    RightSpoiler3ActuatorJam: 27118,
+    RightSpoiler4ActuatorJam: 27119,
+    RightSpoiler5ActuatorJam: 27120,
+
+    LeftAileronBlueActuatorRunaway: 27200,
+    LeftAileronGreenActuatorRunaway: 27201,
+    RightAileronBlueActuatorRunaway: 27202,
+    RightAileronGreenActuatorRunaway: 27203,
+    LeftElevatorBlueActuatorRunaway: 27204,
+    LeftElevatorGreenActuatorRunaway: 27205,
+    RightElevatorBlueActuatorRunaway: 27206,
+    RightElevatorYellowActuatorRunaway: 27207,
+    RudderGreenActuatorRunaway: 27208,
+    RudderBlueActuatorRunaway: 27209,
+    RudderYellowActuatorRunaway: 27210,
+    LeftSpoiler1ActuatorRunaway: 27211,
+    LeftSpoiler2ActuatorRunaway: 27212,
+    LeftSpoiler3ActuatorRunaway: 27213,
+    LeftSpoiler4ActuatorRunaway: 27214,
+    LeftSpoiler5ActuatorRunaway: 27215,
+    RightSpoiler1ActuatorRunaway: 27216,
+    RightSpoiler2ActuatorRunaway: 27217,
+    RightSpoiler3ActuatorRunaway: 27218,
+    RightSpoiler4ActuatorRunaway: 27219,
+    RightSpoiler5ActuatorRunaway: 27220,
+
+    LeftAileronBlueActuatorDisconnect: 27300,
+    LeftAileronGreenActuatorDisconnect: 27301,
+    RightAileronBlueActuatorDisconnect: 27302,
+    RightAileronGreenActuatorDisconnect: 27303,
+    LeftElevatorBlueActuatorDisconnect: 27304,
+    LeftElevatorGreenActuatorDisconnect: 27305,
+    RightElevatorBlueActuatorDisconnect: 27306,
+    RightElevatorYellowActuatorDisconnect: 27307,
+    RudderGreenActuatorDisconnect: 27308,
+    RudderBlueActuatorDisconnect: 27309,
+    RudderYellowActuatorDisconnect: 27310,
+    LeftSpoiler1ActuatorDisconnect: 27311,
+    LeftSpoiler2ActuatorDisconnect: 27312,
+    LeftSpoiler3ActuatorDisconnect: 27313,
+    LeftSpoiler4ActuatorDisconnect: 27314,
+    LeftSpoiler5ActuatorDisconnect: 27315,
+    RightSpoiler1ActuatorDisconnect: 27316,
+    RightSpoiler2ActuatorDisconnect: 27317,
+    RightSpoiler3ActuatorDisconnect: 27318,
+    RightSpoiler4ActuatorDisconnect: 27319,
+    RightSpoiler5ActuatorDisconnect: 27320,
+
+    LeftInnerTankPump1: 28000,
+    LeftInnerTankPump2: 28001,
+    RightInnerTankPump1: 28002,
+    RightInnerTankPump2: 28003,
+    LeftCenterTankJetPump: 28004,
+    RightCenterTankJetPump: 28005,
+    ApuFuelPump: 28006,
+
     GreenReservoirLeak: 29000,
     BlueReservoirLeak: 29001,
     YellowReservoirLeak: 29002,
@@ -97,6 +177,53 @@ export const A320Failure = Object.freeze({
     YellowBrakeHydraulicLeak: 32101,
     YellowBrakeAccumulatorGasLeak: 32150,
 
+    TireBurst1: 32200,
+    TireBurst2: 32201,
+    TireBurst3: 32202,
+    TireBurst4: 32203,
+    TireBurst5: 32204,
+    TireBurst6: 32205,
+    TireSlowLeak1: 32210,
+    TireSlowLeak2: 32211,
+    TireSlowLeak3: 32212,
+    TireSlowLeak4: 32213,
+    TireSlowLeak5: 32214,
+    TireSlowLeak6: 32215,
+    WheelSpeedSensor1: 32220,
+    WheelSpeedSensor2: 32221,
+    WheelSpeedSensor3: 32222,
+    WheelSpeedSensor4: 32223,
+    AntiSkid: 32230,
+
     RadioAltimeter1: 34000,
     RadioAltimeter2: 34001,
+
+    Adr1Fault: 34010,
+    Adr2Fault: 34011,
+    Adr3Fault: 34012,
+
+    Ir1Fault: 34020,
+    Ir2Fault: 34021,
+    Ir3Fault: 34022,
+
+    PitotBlockage1: 34030,
+    PitotBlockage2: 34031,
+    PitotBlockage3: 34032,
+
+    StaticPortBlockage1: 34040,
+    StaticPortBlockage2: 34041,
+    StaticPortBlockage3: 34042,
+
+    AngleOfAttackVaneJam1: 34050,
+    AngleOfAttackVaneJam2: 34051,
+    AngleOfAttackVaneJam3: 34052,
+
+    GpsReceiver1: 34060,
+    GpsReceiver2: 34061,
+
+    Eng1BleedDuctLeak: 36000,
+    Eng2BleedDuctLeak: 36001,
+    LeftWingBleedDuctLeak: 36002,
+    RightWingBleedDuctLeak: 36003,
+    ApuBleedDuctLeak: 36004,
 });
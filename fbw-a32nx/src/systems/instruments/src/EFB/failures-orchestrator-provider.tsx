@@ -41,6 +41,12 @@ const createOrchestrator = () => new FailuresOrchestrator('A32NX', [
     [24, A320Failure.Generator1, 'Generator 1'],
     [24, A320Failure.Generator2, 'Generator 2'],
     [24, A320Failure.ApuGenerator1, 'APU Generator'],
+    [24, A320Failure.Battery1ThermalRunaway, 'Battery 1 Thermal Runaway'],
+    [24, A320Failure.Battery2ThermalRunaway, 'Battery 2 Thermal Runaway'],
+    [24, A320Failure.Generator1VoltageRegulator, 'Generator 1 Voltage Regulator'],
+    [24, A320Failure.Generator2VoltageRegulator, 'Generator 2 Voltage Regulator'],
+    [24, A320Failure.Idg1ConstantSpeedDrive, 'IDG 1 Constant Speed Drive'],
+    [24, A320Failure.Idg2ConstantSpeedDrive, 'IDG 2 Constant Speed Drive'],
     [24, A320Failure.AlternatingCurrent1, 'AC 1'],
     [24, A320Failure.AlternatingCurrent2, 'AC 2'],
     [24, A320Failure.AlternatingCurrentEssential, 'AC ESS'],
@@ -63,6 +69,77 @@ const createOrchestrator = () => new FailuresOrchestrator('A32NX', [
     [27, A320Failure.Sec3Failure, 'SEC 3'],
     [27, A320Failure.Fcdc1Failure, 'FCDC 1'],
     [27, A320Failure.Fcdc2Failure, 'FCDC 2'],
+    [27, A320Failure.LeftAileronBlueActuatorJam, 'L aileron blue actuator jammed'],
+    [27, A320Failure.LeftAileronGreenActuatorJam, 'L aileron green actuator jammed'],
+    [27, A320Failure.RightAileronBlueActuatorJam, 'R aileron blue actuator jammed'],
+    [27, A320Failure.RightAileronGreenActuatorJam, 'R aileron green actuator jammed'],
+    [27, A320Failure.LeftElevatorBlueActuatorJam, 'L elevator blue actuator jammed'],
+    [27, A320Failure.LeftElevatorGreenActuatorJam, 'L elevator green actuator jammed'],
+    [27, A320Failure.RightElevatorBlueActuatorJam, 'R elevator blue actuator jammed'],
+    [27, A320Failure.RightElevatorYellowActuatorJam, 'R elevator yellow actuator jammed'],
+    [27, A320Failure.RudderGreenActuatorJam, 'Rudder green actuator jammed'],
+    [27, A320Failure.RudderBlueActuatorJam, 'Rudder blue actuator jammed'],
+    [27, A320Failure.RudderYellowActuatorJam, 'Rudder yellow actuator jammed'],
+    [27, A320Failure.LeftSpoiler1ActuatorJam, 'L spoiler 1 actuator jammed'],
+    [27, A320Failure.LeftSpoiler2ActuatorJam, 'L spoiler 2 actuator jammed'],
+    [27, A320Failure.LeftSpoiler3ActuatorJam, 'L spoiler 3 actuator jammed'],
+    [27, A320Failure.LeftSpoiler4ActuatorJam, 'L spoiler 4 actuator jammed'],
+    [27, A320Failure.LeftSpoiler5ActuatorJam, 'L spoiler 5 actuator jammed'],
+    [27, A320Failure.RightSpoiler1ActuatorJam, 'R spoiler 1 actuator jammed'],
+    [27, A320Failure.RightSpoiler2ActuatorJam, 'R spoiler 2 actuator jammed'],
+    [27, A320Failure.RightSpoiler3ActuatorJam, 'R spoiler 3 actuator jammed'],
+    [27, A320Failure.RightSpoiler4ActuatorJam, 'R spoiler 4 actuator jammed'],
+    [27, A320Failure.RightSpoiler5ActuatorJam, 'R spoiler 5 actuator jammed'],
+    [27, A320Failure.LeftAileronBlueActuatorRunaway, 'L aileron blue actuator runaway'],
+    [27, A320Failure.LeftAileronGreenActuatorRunaway, 'L aileron green actuator runaway'],
+    [27, A320Failure.RightAileronBlueActuatorRunaway, 'R aileron blue actuator runaway'],
+    [27, A320Failure.RightAileronGreenActuatorRunaway, 'R aileron green actuator runaway'],
+    [27, A320Failure.LeftElevatorBlueActuatorRunaway, 'L elevator blue actuator runaway'],
+    [27, A320Failure.LeftElevatorGreenActuatorRunaway, 'L elevator green actuator runaway'],
+    [27, A320Failure.RightElevatorBlueActuatorRunaway, 'R elevator blue actuator runaway'],
+    [27, A320Failure.RightElevatorYellowActuatorRunaway, 'R elevator yellow actuator runaway'],
+    [27, A320Failure.RudderGreenActuatorRunaway, 'Rudder green actuator runaway'],
+    [27, A320Failure.RudderBlueActuatorRunaway, 'Rudder blue actuator runaway'],
+    [27, A320Failure.RudderYellowActuatorRunaway, 'Rudder yellow actuator runaway'],
+    [27, A320Failure.LeftSpoiler1ActuatorRunaway, 'L spoiler 1 actuator runaway'],
+    [27, A320Failure.LeftSpoiler2ActuatorRunaway, 'L spoiler 2 actuator runaway'],
+    [27, A320Failure.LeftSpoiler3ActuatorRunaway, 'L spoiler 3 actuator runaway'],
+    [27, A320Failure.LeftSpoiler4ActuatorRunaway, 'L spoiler 4 actuator runaway'],
+    [27, A320Failure.LeftSpoiler5ActuatorRunaway, 'L spoiler 5 actuator runaway'],
+    [27, A320Failure.RightSpoiler1ActuatorRunaway, 'R spoiler 1 actuator runaway'],
+    [27, A320Failure.RightSpoiler2ActuatorRunaway, 'R spoiler 2 actuator runaway'],
+    [27, A320Failure.RightSpoiler3ActuatorRunaway, 'R spoiler 3 actuator runaway'],
+    [27, A320Failure.RightSpoiler4ActuatorRunaway, 'R spoiler 4 actuator runaway'],
+    [27, A320Failure.RightSpoiler5ActuatorRunaway, 'R spoiler 5 actuator runaway'],
+    [27, A320Failure.LeftAileronBlueActuatorDisconnect, 'L aileron blue actuator disconnected'],
+    [27, A320Failure.LeftAileronGreenActuatorDisconnect, 'L aileron green actuator disconnected'],
+    [27, A320Failure.RightAileronBlueActuatorDisconnect, 'R aileron blue actuator disconnected'],
+    [27, A320Failure.RightAileronGreenActuatorDisconnect, 'R aileron green actuator disconnected'],
+    [27, A320Failure.LeftElevatorBlueActuatorDisconnect, 'L elevator blue actuator disconnected'],
+    [27, A320Failure.LeftElevatorGreenActuatorDisconnect, 'L elevator green actuator disconnected'],
+    [27, A320Failure.RightElevatorBlueActuatorDisconnect, 'R elevator blue actuator disconnected'],
+    [27, A320Failure.RightElevatorYellowActuatorDisconnect, 'R elevator yellow actuator disconnected'],
+    [27, A320Failure.RudderGreenActuatorDisconnect, 'Rudder green actuator disconnected'],
+    [27, A320Failure.RudderBlueActuatorDisconnect, 'Rudder blue actuator disconnected'],
+    [27, A320Failure.RudderYellowActuatorDisconnect, 'Rudder yellow actuator disconnected'],
+    [27, A320Failure.LeftSpoiler1ActuatorDisconnect, 'L spoiler 1 actuator disconnected'],
+    [27, A320Failure.LeftSpoiler2ActuatorDisconnect, 'L spoiler 2 actuator disconnected'],
+    [27, A320Failure.LeftSpoiler3ActuatorDisconnect, 'L spoiler 3 actuator disconnected'],
+    [27, A320Failure.LeftSpoiler4ActuatorDisconnect, 'L spoiler 4 actuator disconnected'],
+    [27, A320Failure.LeftSpoiler5ActuatorDisconnect, 'L spoiler 5 actuator disconnected'],
+    [27, A320Failure.RightSpoiler1ActuatorDisconnect, 'R spoiler 1 actuator disconnected'],
+    [27, A320Failure.RightSpoiler2ActuatorDisconnect, 'R spoiler 2 actuator disconnected'],
+    [27, A320Failure.RightSpoiler3ActuatorDisconnect, 'R spoiler 3 actuator disconnected'],
+    [27, A320Failure.RightSpoiler4ActuatorDisconnect, 'R spoiler 4 actuator disconnected'],
+    [27, A320Failure.RightSpoiler5ActuatorDisconnect, 'R spoiler 5 actuator disconnected'],
+
+    [28, A320Failure.LeftInnerTankPump1, 'L TK pump 1'],
+    [28, A320Failure.LeftInnerTankPump2, 'L TK pump 2'],
+    [28, A320Failure.RightInnerTankPump1, 'R TK pump 1'],
+    [28, A320Failure.RightInnerTankPump2, 'R TK pump 2'],
+    [28, A320Failure.LeftCenterTankJetPump, 'CTR TK L jet pump'],
+    [28, A320Failure.RightCenterTankJetPump, 'CTR TK R jet pump'],
+    [28, A320Failure.ApuFuelPump, 'APU fuel pump'],
 
     [29, A320Failure.GreenReservoirLeak, 'Green reservoir leak'],
     [29, A320Failure.BlueReservoirLeak, 'Blue reservoir leak'],
@@ -110,8 +187,48 @@ const createOrchestrator = () => new FailuresOrchestrator('A32NX', [
     [32, A320Failure.YellowBrakeHydraulicLeak, 'Yellow brakes circuit leak'],
     [32, A320Failure.YellowBrakeAccumulatorGasLeak, 'Yellow brake accumulator gas leak'],
 
+    [32, A320Failure.TireBurst1, 'Main left tire 1 burst'],
+    [32, A320Failure.TireBurst2, 'Main left tire 2 burst'],
+    [32, A320Failure.TireBurst3, 'Main right tire 3 burst'],
+    [32, A320Failure.TireBurst4, 'Main right tire 4 burst'],
+    [32, A320Failure.TireBurst5, 'Nose tire 5 burst'],
+    [32, A320Failure.TireBurst6, 'Nose tire 6 burst'],
+    [32, A320Failure.TireSlowLeak1, 'Main left tire 1 slow leak'],
+    [32, A320Failure.TireSlowLeak2, 'Main left tire 2 slow leak'],
+    [32, A320Failure.TireSlowLeak3, 'Main right tire 3 slow leak'],
+    [32, A320Failure.TireSlowLeak4, 'Main right tire 4 slow leak'],
+    [32, A320Failure.TireSlowLeak5, 'Nose tire 5 slow leak'],
+    [32, A320Failure.TireSlowLeak6, 'Nose tire 6 slow leak'],
+    [32, A320Failure.WheelSpeedSensor1, 'Main left wheel 1 speed sensor'],
+    [32, A320Failure.WheelSpeedSensor2, 'Main left wheel 2 speed sensor'],
+    [32, A320Failure.WheelSpeedSensor3, 'Main right wheel 3 speed sensor'],
+    [32, A320Failure.WheelSpeedSensor4, 'Main right wheel 4 speed sensor'],
+    [32, A320Failure.AntiSkid, 'Anti-skid'],
+
     [34, A320Failure.RadioAltimeter1, 'RA 1'],
     [34, A320Failure.RadioAltimeter2, 'RA 2'],
+    [34, A320Failure.Adr1Fault, 'ADR 1'],
+    [34, A320Failure.Adr2Fault, 'ADR 2'],
+    [34, A320Failure.Adr3Fault, 'ADR 3'],
+    [34, A320Failure.Ir1Fault, 'IR 1'],
+    [34, A320Failure.Ir2Fault, 'IR 2'],
+    [34, A320Failure.Ir3Fault, 'IR 3'],
+    [34, A320Failure.PitotBlockage1, 'Pitot probe 1 blocked'],
+    [34, A320Failure.PitotBlockage2, 'Pitot probe 2 blocked'],
+    [34, A320Failure.PitotBlockage3, 'Pitot probe 3 blocked'],
+    [34, A320Failure.StaticPortBlockage1, 'Static ports 1 blocked'],
+    [34, A320Failure.StaticPortBlockage2, 'Static ports 2 blocked'],
+    [34, A320Failure.StaticPortBlockage3, 'Static ports 3 blocked'],
+    [34, A320Failure.AngleOfAttackVaneJam1, 'AOA vane 1 jammed'],
+    [34, A320Failure.AngleOfAttackVaneJam2, 'AOA vane 2 jammed'],
+    [34, A320Failure.AngleOfAttackVaneJam3, 'AOA vane 3 jammed'],
+    [34, A320Failure.GpsReceiver1, 'GPS 1'],
+    [34, A320Failure.GpsReceiver2, 'GPS 2'],
+    [36, A320Failure.Eng1BleedDuctLeak, 'Engine 1 pylon bleed duct leak'],
+    [36, A320Failure.Eng2BleedDuctLeak, 'Engine 2 pylon bleed duct leak'],
+    [36, A320Failure.LeftWingBleedDuctLeak, 'Left wing bleed duct leak'],
+    [36, A320Failure.RightWingBleedDuctLeak, 'Right wing bleed duct leak'],
+    [36, A320Failure.ApuBleedDuctLeak, 'APU bleed duct leak'],
 ]);
 
 const Context = React.createContext<FailuresOrchestratorContext>({
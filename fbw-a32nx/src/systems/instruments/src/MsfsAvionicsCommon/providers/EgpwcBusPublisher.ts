@@ -12,6 +12,8 @@ export interface EgpwcSimVars {
     'egpwc.minElevationMode': TerrainLevelMode,
     'egpwc.maxElevation': number,
     'egpwc.maxElevationMode': TerrainLevelMode,
+    'egpwc.terrainCaution': boolean,
+    'egpwc.terrainWarning': boolean,
 }
 
 export class EgpwcBusPublisher extends SimVarPublisher<EgpwcSimVars> {
@@ -21,6 +23,8 @@ export class EgpwcBusPublisher extends SimVarPublisher<EgpwcSimVars> {
             ['egpwc.minElevationMode', { name: `L:A32NX_EGPWC_ND_${side}_TERRAIN_MIN_ELEVATION_MODE`, type: SimVarValueType.Number }],
             ['egpwc.maxElevation', { name: `L:A32NX_EGPWC_ND_${side}_TERRAIN_MAX_ELEVATION`, type: SimVarValueType.Number }],
             ['egpwc.maxElevationMode', { name: `L:A32NX_EGPWC_ND_${side}_TERRAIN_MAX_ELEVATION_MODE`, type: SimVarValueType.Number }],
+            ['egpwc.terrainCaution', { name: 'L:A32NX_EGPWC_TERRAIN_CAUTION', type: SimVarValueType.Bool }],
+            ['egpwc.terrainWarning', { name: 'L:A32NX_EGPWC_TERRAIN_WARNING', type: SimVarValueType.Bool }],
         ]), bus);
     }
 }
@@ -2,6 +2,7 @@ import { FSComponent, DisplayComponent, VNode, Subject, MappedSubject, EventBus,
 import { EfisNdMode, TcasWxrMessage } from '@shared/NavigationDisplay';
 import { Layer } from '../MsfsAvionicsCommon/Layer';
 import { TcasSimVars } from '../MsfsAvionicsCommon/providers/TcasBusPublisher';
+import { EgpwcSimVars } from '../MsfsAvionicsCommon/providers/EgpwcBusPublisher';
 
 export interface TcasWXMessagesProps {
     bus: EventBus,
@@ -13,6 +14,10 @@ export class TcasWxrMessages extends DisplayComponent<TcasWXMessagesProps> {
 
     private readonly failSub = Subject.create(false);
 
+    private readonly terrainCautionSub = Subject.create(false);
+
+    private readonly terrainWarningSub = Subject.create(false);
+
     private readonly leftMessage = Subject.create<TcasWxrMessage | undefined>(undefined);
 
     private readonly rightMessage = Subject.create<TcasWxrMessage | undefined>(undefined);
@@ -30,10 +35,12 @@ export class TcasWxrMessages extends DisplayComponent<TcasWXMessagesProps> {
     onAfterRender(node: VNode) {
         super.onAfterRender(node);
 
-        const sub = this.props.bus.getSubscriber<TcasSimVars>();
+        const sub = this.props.bus.getSubscriber<TcasSimVars & EgpwcSimVars>();
 
         sub.on('tcasTaOnly').whenChanged().handle((value) => this.taOnlySub.set(value));
         sub.on('tcasFault').whenChanged().handle((value) => this.failSub.set(value));
+        sub.on('egpwc.terrainCaution').whenChanged().handle((value) => this.terrainCautionSub.set(value));
+        sub.on('egpwc.terrainWarning').whenChanged().handle((value) => this.terrainWarningSub.set(value));
 
         MappedSubject.create(([taOnly, failed]) => {
             if (failed) {
@@ -44,6 +51,16 @@ export class TcasWxrMessages extends DisplayComponent<TcasWXMessagesProps> {
                 this.leftMessage.set(undefined);
             }
         }, this.taOnlySub, this.failSub);
+
+        MappedSubject.create(([caution, warning]) => {
+            if (warning) {
+                this.rightMessage.set({ text: 'TERRAIN AHEAD', color: 'Red' });
+            } else if (caution) {
+                this.rightMessage.set({ text: 'TERRAIN AHEAD', color: 'Amber' });
+            } else {
+                this.rightMessage.set(undefined);
+            }
+        }, this.terrainCautionSub, this.terrainWarningSub);
     }
 
     render(): VNode | null {
@@ -151,5 +151,5 @@ export const enum NavAidMode {
 
 export interface TcasWxrMessage {
     text: string;
-    color: 'White' | 'Amber';
+    color: 'White' | 'Amber' | 'Red';
 }
@@ -289,7 +289,8 @@ export class TcasComputer implements TcasComponent {
         this.trueHeading = SimVar.GetSimVarValue('PLANE HEADING DEGREES TRUE', 'degrees');
         this.isSlewActive = !!SimVar.GetSimVarValue('IS SLEW ACTIVE', 'boolean');
         this.simRate = SimVar.GetGlobalVarValue('SIMULATION RATE', 'number');
-        this.gpwsWarning = !!SimVar.GetSimVarValue('L:A32NX_GPWS_Warning_Active', 'boolean');
+        this.gpwsWarning = !!SimVar.GetSimVarValue('L:A32NX_EGPWC_GPWS_WARNING', 'boolean')
+            || !!SimVar.GetSimVarValue('L:A32NX_EGPWC_TERRAIN_WARNING', 'boolean');
 
         this.tcasMode.setVar((this.xpdrStatus === XpdrMode.STBY || !this.tcasPower || !this.altRptgSwitchPos) ? TcasMode.STBY : this.tcasSwitchPos); // 34-43-00:A32
     }
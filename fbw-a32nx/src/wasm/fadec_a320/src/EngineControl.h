@@ -1223,7 +1223,11 @@ class EngineControl {
       simN1 = simVars->getN1(engine);
       simN2 = simVars->getN2(engine);
       thrust = simVars->getThrust(engine);
-      engineFuelValveOpen = simVars->getValve(engine);
+      // the engine is starved when the fuel system no longer feeds it, regardless of the engine master,
+      // and the systems FADEC cuts the fuel off when it aborts an automatic start. The starved flag defaults
+      // to 0 so the engines stay fed until the systems wasm reports otherwise. The abort is only read here:
+      // the cut reads as the engine master turning off, which moves the state machine below to shutting.
+      engineFuelValveOpen = !simVars->getEngineFuelStarved(engine) && !simVars->getStartAborted(engine) ? simVars->getValve(engine) : 0;
       engineStarterPressurized = simVars->getStarterPressurized(engine);
 
       // simulates delay to start valve open through fuel valve travel time
@@ -161,6 +161,10 @@ class SimVars {
   ID PacksState2;
   ID Eng1StarterPressurized;
   ID Eng2StarterPressurized;
+  ID Eng1FuelStarved;
+  ID Eng2FuelStarved;
+  ID Eng1StartAborted;
+  ID Eng2StartAborted;
   ID APUrpmPercent;
 
   SimVars() { this->initializeVars(); }
@@ -210,6 +214,11 @@ class SimVars {
     PumpStateRight = register_named_variable("A32NX_PUMP_STATE:2");
     Eng1StarterPressurized = register_named_variable("A32NX_PNEU_ENG_1_STARTER_PRESSURIZED");
     Eng2StarterPressurized = register_named_variable("A32NX_PNEU_ENG_2_STARTER_PRESSURIZED");
+    Eng1FuelStarved = register_named_variable("A32NX_ENGINE_FUEL_STARVED:1");
+    Eng2FuelStarved = register_named_variable("A32NX_ENGINE_FUEL_STARVED:2");
+    // written by the start sequencer of the systems wasm, which owns the igniter and start fault variables as well
+    Eng1StartAborted = register_named_variable("A32NX_FADEC_START_ABORTED_ENG1");
+    Eng2StartAborted = register_named_variable("A32NX_FADEC_START_ABORTED_ENG2");
     APUrpmPercent = register_named_variable("A32NX_APU_N_RAW");
 
     ThrustLimitType = register_named_variable("A32NX_AUTOTHRUST_THRUST_LIMIT_TYPE");
@@ -357,6 +366,8 @@ class SimVars {
   FLOAT64 getStarterPressurized(int engine) {
     return get_named_variable_value(engine == 1 ? Eng1StarterPressurized : Eng2StarterPressurized);
   }
+  FLOAT64 getEngineFuelStarved(int engine) { return get_named_variable_value(engine == 1 ? Eng1FuelStarved : Eng2FuelStarved); }
+  FLOAT64 getStartAborted(int engine) { return get_named_variable_value(engine == 1 ? Eng1StartAborted : Eng2StartAborted); }
   FLOAT64 getRightSystemPressure() { return get_named_variable_value(Eng2StarterPressurized); }
   FLOAT64 getAPUrpmPercent() { return get_named_variable_value(APUrpmPercent); }
 
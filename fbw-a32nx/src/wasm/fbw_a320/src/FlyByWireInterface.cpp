@@ -614,6 +614,7 @@ void FlyByWireInterface::setupLocalVariables() {
 
     idSecPushbuttonPressed[i] = std::make_unique<LocalVariable>("A32NX_SEC_" + idString + "_PUSHBUTTON_PRESSED");
     idSecFaultLightOn[i] = std::make_unique<LocalVariable>("A32NX_SEC_" + idString + "_FAULT_LIGHT_ON");
+    idSecFailed[i] = std::make_unique<LocalVariable>("A32NX_SEC_" + idString + "_FAILED");
     idSecGroundSpoilersOut[i] = std::make_unique<LocalVariable>("A32NX_SEC_" + idString + "_GROUND_SPOILER_OUT");
   }
 
@@ -1250,9 +1251,9 @@ bool FlyByWireInterface::updateElac(double sampleTime, int elacIndex) {
   elacs[elacIndex].modelInputs.in.sim_data.tracking_mode_on_override = idExternalOverride->get() == 1;
   elacs[elacIndex].modelInputs.in.sim_data.tailstrike_protection_on = tailstrikeProtectionEnabled;
 
-  elacs[elacIndex].modelInputs.in.discrete_inputs.ground_spoilers_active_1 = secsDiscreteOutputs[0].ground_spoiler_out;
+  elacs[elacIndex].modelInputs.in.discrete_inputs.ground_spoilers_active_1 = idSecGroundSpoilersOut[0]->get();
   elacs[elacIndex].modelInputs.in.discrete_inputs.ground_spoilers_active_2 =
-      elacIndex == 0 ? secsDiscreteOutputs[1].ground_spoiler_out : secsDiscreteOutputs[2].ground_spoiler_out;
+      elacIndex == 0 ? idSecGroundSpoilersOut[1]->get() : idSecGroundSpoilersOut[2]->get();
   elacs[elacIndex].modelInputs.in.discrete_inputs.is_unit_1 = elacIndex == 0;
   elacs[elacIndex].modelInputs.in.discrete_inputs.is_unit_2 = elacIndex == 1;
   elacs[elacIndex].modelInputs.in.discrete_inputs.opp_axis_pitch_failure = !elacsDiscreteOutputs[oppElacIndex].pitch_axis_ok;
@@ -1492,6 +1493,8 @@ bool FlyByWireInterface::updateSec(double sampleTime, int secIndex) {
   secs[secIndex].modelInputs.in.bus_inputs.lgciu_1_bus = lgciuBusOutputs[0];
   secs[secIndex].modelInputs.in.bus_inputs.lgciu_2_bus = lgciuBusOutputs[1];
 
+  Failures failureIndex = secIndex == 0 ? Failures::Sec1 : (secIndex == 1 ? Failures::Sec2 : Failures::Sec3);
+
   if (secIndex == secDisabled) {
     simConnectInterface.setClientDataSecDiscretes(secs[secIndex].modelInputs.in.discrete_inputs);
     simConnectInterface.setClientDataSecAnalog(secs[secIndex].modelInputs.in.analog_inputs);
@@ -1509,7 +1512,6 @@ bool FlyByWireInterface::updateSec(double sampleTime, int secIndex) {
       powerSupplyAvailable = idElecDcBus2Powered->get();
     }
 
-    Failures failureIndex = secIndex == 0 ? Failures::Sec1 : (secIndex == 1 ? Failures::Sec2 : Failures::Sec3);
     secs[secIndex].update(sampleTime, simData.simulationTime, failuresConsumer.isActive(failureIndex), powerSupplyAvailable);
 
     secsDiscreteOutputs[secIndex] = secs[secIndex].getDiscreteOutputs();
@@ -1521,8 +1523,9 @@ bool FlyByWireInterface::updateSec(double sampleTime, int secIndex) {
     simConnectInterface.setClientDataSecBus(secsBusOutputs[secIndex], secIndex);
   }
 
+  // the systems wasm SEC computing the ground spoilers and reverser unlock shares the failure of this SEC
+  idSecFailed[secIndex]->set(failuresConsumer.isActive(failureIndex));
   idSecFaultLightOn[secIndex]->set(secsDiscreteOutputs[secIndex].sec_failed);
-  idSecGroundSpoilersOut[secIndex]->set(secsDiscreteOutputs[secIndex].ground_spoiler_out);
 
   return true;
 }
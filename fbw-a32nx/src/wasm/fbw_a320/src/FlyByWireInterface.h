@@ -492,6 +492,9 @@ class FlyByWireInterface {
 
   // SEC discrete output Lvars
   std::unique_ptr<LocalVariable> idSecFaultLightOn[3];
+  std::unique_ptr<LocalVariable> idSecFailed[3];
+
+  // SEC ground spoiler Lvars computed by the systems wasm
   std::unique_ptr<LocalVariable> idSecGroundSpoilersOut[3];
 
   // Flight controls solenoid valve energization Lvars
@@ -12,7 +12,7 @@ use systems::{
     },
     shared::{
         AuxiliaryPowerUnitElectrical, DelayedTrueLogicGate, ElectricalBusType, EngineCorrectedN2,
-        EngineFirePushButtons,
+        EngineFirePushButtons, PotentialOrigin,
     },
     simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
 };
@@ -275,6 +275,15 @@ impl A320AlternatingCurrentElectrical {
         self.main_power_sources.gen_contactor_open(number)
     }
 
+    pub fn idg_fault(&self, number: usize) -> bool {
+        self.main_power_sources.idg_fault(number)
+    }
+
+    /// The engine and APU generators connected to the AC buses.
+    pub fn connected_generators(&self) -> Vec<PotentialOrigin> {
+        self.main_power_sources.connected_generators()
+    }
+
     pub fn emergency_generator_contactor_is_closed(&self) -> bool {
         self.emergency_gen_contactor.is_closed()
     }
@@ -453,6 +462,27 @@ impl A320MainPowerSources {
     pub fn gen_contactor_open(&self, number: usize) -> bool {
         self.engine_generator_contactors[number - 1].is_open()
     }
+
+    fn idg_fault(&self, number: usize) -> bool {
+        match number {
+            1 => self.engine_1_gen.idg_fault(),
+            2 => self.engine_2_gen.idg_fault(),
+            _ => panic!("Invalid engine generator number"),
+        }
+    }
+
+    fn connected_generators(&self) -> Vec<PotentialOrigin> {
+        let apu_generators = [(&self.apu_gen_contactor, PotentialOrigin::ApuGenerator(1))];
+
+        self.engine_generator_contactors
+            .iter()
+            .enumerate()
+            .map(|(index, contactor)| (contactor, PotentialOrigin::EngineGenerator(index + 1)))
+            .chain(apu_generators)
+            .filter(|(contactor, _)| contactor.is_closed())
+            .map(|(_, origin)| origin)
+            .collect()
+    }
 }
 impl SimulationElement for A320MainPowerSources {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -9,7 +9,7 @@ use self::{
 };
 pub(super) use direct_current::APU_START_MOTOR_BUS_TYPE;
 
-use uom::si::{angular_velocity::revolution_per_minute, f64::*};
+use uom::si::{angular_velocity::revolution_per_minute, f64::*, power::watt};
 
 #[cfg(test)]
 use systems::electrical::Battery;
@@ -19,8 +19,8 @@ use systems::{
     accept_iterable,
     electrical::{
         AlternatingCurrentElectricalSystem, BatteryPushButtons, Electricity, EmergencyElectrical,
-        EmergencyGenerator, EngineGeneratorPushButtons, ExternalPowerSource, StaticInverter,
-        TransformerRectifier,
+        EmergencyGenerator, EngineGeneratorPushButtons, ExternalPowerSource, LoadShedGroup,
+        LoadShedManager, StaticInverter, TransformerRectifier,
     },
     overhead::{
         AutoOffFaultPushButton, FaultIndication, FaultReleasePushButton, MomentaryPushButton,
@@ -29,7 +29,7 @@ use systems::{
     shared::{
         ApuMaster, ApuStart, AuxiliaryPowerUnitElectrical, EmergencyElectricalRatPushButton,
         EmergencyElectricalState, EmergencyGeneratorControlUnit, EmergencyGeneratorPower,
-        EngineCorrectedN2, EngineFirePushButtons, LgciuWeightOnWheels,
+        EngineCorrectedN2, EngineFirePushButtons, LgciuWeightOnWheels, PotentialOrigin,
     },
     simulation::{
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
@@ -44,11 +44,18 @@ pub(super) struct A320Electrical {
     direct_current: A320DirectCurrentElectrical,
     main_galley: MainGalley,
     secondary_galley: SecondaryGalley,
+    load_shed_manager: LoadShedManager,
     emergency_elec: EmergencyElectrical,
     emergency_gen: EmergencyGenerator,
 }
 impl A320Electrical {
     const MIN_EMERGENCY_GENERATOR_RPM_TO_ALLOW_CURRENT_SUPPLY: f64 = 10000.;
+    // 90 kVA at a power factor of 0.8.
+    const GENERATOR_RATING_WATT: f64 = 90000. / 0.8;
+    const GALLEY_DEMAND_WATT: f64 = 20000.;
+    const IN_FLIGHT_ENTERTAINMENT_DEMAND_WATT: f64 = 3000.;
+    const CABIN_DEMAND_WATT: f64 = 5000.;
+
     pub fn new(context: &mut InitContext) -> A320Electrical {
         A320Electrical {
             galley_is_shed_id: context.get_identifier("ELEC_GALLEY_IS_SHED".to_owned()),
@@ -56,6 +63,37 @@ impl A320Electrical {
             direct_current: A320DirectCurrentElectrical::new(context),
             main_galley: MainGalley::new(),
             secondary_galley: SecondaryGalley::new(),
+            load_shed_manager: LoadShedManager::new(
+                context,
+                &[
+                    (
+                        PotentialOrigin::EngineGenerator(1),
+                        Power::new::<watt>(Self::GENERATOR_RATING_WATT),
+                    ),
+                    (
+                        PotentialOrigin::EngineGenerator(2),
+                        Power::new::<watt>(Self::GENERATOR_RATING_WATT),
+                    ),
+                    (
+                        PotentialOrigin::ApuGenerator(1),
+                        Power::new::<watt>(Self::GENERATOR_RATING_WATT),
+                    ),
+                ],
+                &[
+                    (
+                        LoadShedGroup::Galley,
+                        Power::new::<watt>(Self::GALLEY_DEMAND_WATT),
+                    ),
+                    (
+                        LoadShedGroup::InFlightEntertainment,
+                        Power::new::<watt>(Self::IN_FLIGHT_ENTERTAINMENT_DEMAND_WATT),
+                    ),
+                    (
+                        LoadShedGroup::Cabin,
+                        Power::new::<watt>(Self::CABIN_DEMAND_WATT),
+                    ),
+                ],
+            ),
             emergency_elec: EmergencyElectrical::new(),
             emergency_gen: EmergencyGenerator::new(
                 context,
@@ -128,6 +166,8 @@ impl A320Electrical {
             .update(context, electricity, &self.alternating_current, overhead);
         self.secondary_galley
             .update(electricity, &self.alternating_current, overhead);
+        self.load_shed_manager
+            .update(context, &self.alternating_current.connected_generators());
 
         self.debug_assert_invariants();
     }
@@ -142,7 +182,9 @@ impl A320Electrical {
     }
 
     fn galley_is_shed(&self) -> bool {
-        self.main_galley.is_shed() || self.secondary_galley.is_shed()
+        self.main_galley.is_shed()
+            || self.secondary_galley.is_shed()
+            || self.load_shed_manager.is_shed(LoadShedGroup::Galley)
     }
 
     fn debug_assert_invariants(&self) {
@@ -189,6 +231,10 @@ impl A320Electrical {
         self.alternating_current.gen_contactor_open(number)
     }
 
+    pub fn idg_fault(&self, number: usize) -> bool {
+        self.alternating_current.idg_fault(number)
+    }
+
     pub fn in_emergency_elec(&self) -> bool {
         self.emergency_elec.is_active()
     }
@@ -198,6 +244,7 @@ impl SimulationElement for A320Electrical {
         self.alternating_current.accept(visitor);
         self.direct_current.accept(visitor);
         self.emergency_gen.accept(visitor);
+        self.load_shed_manager.accept(visitor);
 
         visitor.visit(self);
     }
@@ -278,6 +325,10 @@ impl A320ElectricalOverheadPanel {
             .for_each(|(index, gen)| {
                 gen.set_fault(electrical.gen_contactor_open(index + 1) && gen.is_on());
             });
+
+        self.idgs.iter_mut().enumerate().for_each(|(index, idg)| {
+            idg.set_fault(electrical.idg_fault(index + 1));
+        });
     }
 
     fn generator_is_on(&self, number: usize) -> bool {
@@ -425,9 +476,10 @@ mod a320_electrical_circuit_tests {
     use systems::{
         apu::ApuGenerator,
         electrical::{
-            ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-            Electricity, ElectricitySource, ExternalPowerSource, Potential, ProvideFrequency,
-            ProvidePotential, INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
+            consumption::PowerConsumer, ElectricalElement, ElectricalElementIdentifier,
+            ElectricalElementIdentifierProvider, Electricity, ElectricitySource,
+            ExternalPowerSource, Potential, ProvideFrequency, ProvidePotential,
+            INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
         },
         failures::FailureType,
         shared::{
@@ -1784,8 +1836,30 @@ mod a320_electrical_circuit_tests {
     }
 
     #[test]
-    #[ignore = "Generator overloading is not yet supported."]
-    fn when_aircraft_on_the_ground_and_apu_gen_is_overloaded_galley_is_shed() {}
+    fn when_aircraft_on_the_ground_and_apu_gen_is_overloaded_galley_is_shed() {
+        let mut test_bed = test_bed_with()
+            .running_apu()
+            .and()
+            .on_the_ground()
+            .power_demand_of(Power::new::<watt>(150000.))
+            .run()
+            .run_waiting_for(Duration::from_secs(10));
+
+        assert!(test_bed.galley_is_shed());
+    }
+
+    #[test]
+    fn when_aircraft_on_the_ground_and_apu_gen_is_briefly_overloaded_galley_is_not_shed() {
+        let mut test_bed = test_bed_with()
+            .running_apu()
+            .and()
+            .on_the_ground()
+            .power_demand_of(Power::new::<watt>(150000.))
+            .run()
+            .run();
+
+        assert!(!test_bed.galley_is_shed());
+    }
 
     #[rstest]
     #[case(1)]
@@ -2359,6 +2433,7 @@ mod a320_electrical_circuit_tests {
         apu_overhead: TestApuOverhead,
         engine_fire_push_buttons: TestEngineFirePushButtons,
         hydraulics: TestHydraulicSystem,
+        power_consumer: PowerConsumer,
         force_run_emergency_gen: bool,
     }
     impl A320ElectricalTestAircraft {
@@ -2373,6 +2448,7 @@ mod a320_electrical_circuit_tests {
                 apu_overhead: TestApuOverhead::new(),
                 engine_fire_push_buttons: TestEngineFirePushButtons::new(),
                 hydraulics: TestHydraulicSystem::new(),
+                power_consumer: PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1)),
                 force_run_emergency_gen: false,
             }
         }
@@ -2381,6 +2457,10 @@ mod a320_electrical_circuit_tests {
             self.engines[number - 1].run();
         }
 
+        fn set_power_demand(&mut self, power: Power) {
+            self.power_consumer.demand(power);
+        }
+
         fn stopped_engine(&mut self, number: usize) {
             self.engines[number - 1].stop();
         }
@@ -2484,6 +2564,7 @@ mod a320_electrical_circuit_tests {
             self.overhead.accept(visitor);
             self.emergency_overhead.accept(visitor);
             self.apu.accept(visitor);
+            self.power_consumer.accept(visitor);
 
             visitor.visit(self);
         }
@@ -2525,6 +2606,11 @@ mod a320_electrical_circuit_tests {
             self
         }
 
+        fn power_demand_of(mut self, power: Power) -> Self {
+            self.command(|a| a.set_power_demand(power));
+            self
+        }
+
         fn connected_external_power(mut self) -> Self {
             self.write_by_name("EXTERNAL POWER AVAILABLE:1", true);
 
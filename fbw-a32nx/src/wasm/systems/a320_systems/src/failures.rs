@@ -0,0 +1,534 @@
+use systems::{
+    air_conditioning::{acs_controller::AcscId, Channel, ZoneType},
+    failures::FailureType,
+    pneumatic::BleedDuct,
+    shared::{
+        AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, FlightControlActuatorId,
+        GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
+    },
+};
+
+/// The failures of the A320, together with the identifiers the simulator uses to
+/// activate, deactivate, arm and disarm them.
+pub fn failures() -> Vec<(u64, FailureType)> {
+    vec![
+        (
+            21_000,
+            FailureType::Acsc(AcscId::Acsc1(Channel::ChannelOne)),
+        ),
+        (
+            21_001,
+            FailureType::Acsc(AcscId::Acsc1(Channel::ChannelTwo)),
+        ),
+        (
+            21_002,
+            FailureType::Acsc(AcscId::Acsc2(Channel::ChannelOne)),
+        ),
+        (
+            21_003,
+            FailureType::Acsc(AcscId::Acsc2(Channel::ChannelTwo)),
+        ),
+        (21_004, FailureType::HotAir(1)),
+        (21_005, FailureType::TrimAirHighPressure),
+        (21_006, FailureType::TrimAirFault(ZoneType::Cockpit)),
+        (21_007, FailureType::TrimAirFault(ZoneType::Cabin(1))),
+        (21_008, FailureType::TrimAirFault(ZoneType::Cabin(2))),
+        (21_009, FailureType::TrimAirOverheat(ZoneType::Cockpit)),
+        (21_010, FailureType::TrimAirOverheat(ZoneType::Cabin(1))),
+        (21_011, FailureType::TrimAirOverheat(ZoneType::Cabin(2))),
+        (21_012, FailureType::CabinFan(1)),
+        (21_013, FailureType::CabinFan(2)),
+        (21_014, FailureType::GalleyFans),
+        (24_000, FailureType::TransformerRectifier(1)),
+        (24_001, FailureType::TransformerRectifier(2)),
+        (24_002, FailureType::TransformerRectifier(3)),
+        (24_004, FailureType::StaticInverter),
+        (24_020, FailureType::Generator(1)),
+        (24_021, FailureType::Generator(2)),
+        (24_030, FailureType::ApuGenerator(1)),
+        (24_040, FailureType::BatteryThermalRunaway(1)),
+        (24_041, FailureType::BatteryThermalRunaway(2)),
+        (24_050, FailureType::GeneratorVoltageRegulator(1)),
+        (24_051, FailureType::GeneratorVoltageRegulator(2)),
+        (24_060, FailureType::IdgConstantSpeedDrive(1)),
+        (24_061, FailureType::IdgConstantSpeedDrive(2)),
+        (
+            24_100,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(1)),
+        ),
+        (
+            24_101,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(2)),
+        ),
+        (
+            24_102,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssential),
+        ),
+        (
+            24_103,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssentialShed),
+        ),
+        (
+            24_104,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentStaticInverter),
+        ),
+        (
+            24_105,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentGndFltService),
+        ),
+        (
+            24_106,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(1)),
+        ),
+        (
+            24_107,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(2)),
+        ),
+        (
+            24_108,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssential),
+        ),
+        (
+            24_109,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssentialShed),
+        ),
+        (
+            24_110,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentBattery),
+        ),
+        (
+            24_111,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(1)),
+        ),
+        (
+            24_112,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(2)),
+        ),
+        (
+            24_113,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
+        ),
+        (
+            27_100,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftAileron(1)),
+        ),
+        (
+            27_101,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftAileron(2)),
+        ),
+        (
+            27_102,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightAileron(1)),
+        ),
+        (
+            27_103,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightAileron(2)),
+        ),
+        (
+            27_104,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftElevator(1)),
+        ),
+        (
+            27_105,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftElevator(2)),
+        ),
+        (
+            27_106,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightElevator(1)),
+        ),
+        (
+            27_107,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightElevator(2)),
+        ),
+        (
+            27_108,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::Rudder(1)),
+        ),
+        (
+            27_109,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::Rudder(2)),
+        ),
+        (
+            27_110,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::Rudder(3)),
+        ),
+        (
+            27_111,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftSpoiler(1)),
+        ),
+        (
+            27_112,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftSpoiler(2)),
+        ),
+        (
+            27_113,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftSpoiler(3)),
+        ),
+        (
+            27_114,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftSpoiler(4)),
+        ),
+        (
+            27_115,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftSpoiler(5)),
+        ),
+        (
+            27_116,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightSpoiler(1)),
+        ),
+        (
+            27_117,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightSpoiler(2)),
+        ),
+        (
+            27_118,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightSpoiler(3)),
+        ),
+        (
+            27_119,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightSpoiler(4)),
+        ),
+        (
+            27_120,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightSpoiler(5)),
+        ),
+        (
+            27_200,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftAileron(1)),
+        ),
+        (
+            27_201,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftAileron(2)),
+        ),
+        (
+            27_202,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightAileron(1)),
+        ),
+        (
+            27_203,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightAileron(2)),
+        ),
+        (
+            27_204,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftElevator(1)),
+        ),
+        (
+            27_205,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftElevator(2)),
+        ),
+        (
+            27_206,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightElevator(1)),
+        ),
+        (
+            27_207,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightElevator(2)),
+        ),
+        (
+            27_208,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::Rudder(1)),
+        ),
+        (
+            27_209,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::Rudder(2)),
+        ),
+        (
+            27_210,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::Rudder(3)),
+        ),
+        (
+            27_211,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftSpoiler(1)),
+        ),
+        (
+            27_212,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftSpoiler(2)),
+        ),
+        (
+            27_213,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftSpoiler(3)),
+        ),
+        (
+            27_214,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftSpoiler(4)),
+        ),
+        (
+            27_215,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftSpoiler(5)),
+        ),
+        (
+            27_216,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightSpoiler(1)),
+        ),
+        (
+            27_217,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightSpoiler(2)),
+        ),
+        (
+            27_218,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightSpoiler(3)),
+        ),
+        (
+            27_219,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightSpoiler(4)),
+        ),
+        (
+            27_220,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightSpoiler(5)),
+        ),
+        (
+            27_300,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftAileron(1)),
+        ),
+        (
+            27_301,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftAileron(2)),
+        ),
+        (
+            27_302,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightAileron(1)),
+        ),
+        (
+            27_303,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightAileron(2)),
+        ),
+        (
+            27_304,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftElevator(1)),
+        ),
+        (
+            27_305,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftElevator(2)),
+        ),
+        (
+            27_306,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightElevator(1)),
+        ),
+        (
+            27_307,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightElevator(2)),
+        ),
+        (
+            27_308,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::Rudder(1)),
+        ),
+        (
+            27_309,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::Rudder(2)),
+        ),
+        (
+            27_310,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::Rudder(3)),
+        ),
+        (
+            27_311,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftSpoiler(1)),
+        ),
+        (
+            27_312,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftSpoiler(2)),
+        ),
+        (
+            27_313,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftSpoiler(3)),
+        ),
+        (
+            27_314,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftSpoiler(4)),
+        ),
+        (
+            27_315,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftSpoiler(5)),
+        ),
+        (
+            27_316,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightSpoiler(1)),
+        ),
+        (
+            27_317,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightSpoiler(2)),
+        ),
+        (
+            27_318,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightSpoiler(3)),
+        ),
+        (
+            27_319,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightSpoiler(4)),
+        ),
+        (
+            27_320,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightSpoiler(5)),
+        ),
+        (28_000, FailureType::FuelPump(2)),
+        (28_001, FailureType::FuelPump(5)),
+        (28_002, FailureType::FuelPump(3)),
+        (28_003, FailureType::FuelPump(6)),
+        (28_004, FailureType::FuelPump(1)),
+        (28_005, FailureType::FuelPump(4)),
+        (28_006, FailureType::FuelPump(7)),
+        (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
+        (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
+        (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
+        (29_003, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
+        (29_004, FailureType::ReservoirAirLeak(HydraulicColor::Blue)),
+        (
+            29_005,
+            FailureType::ReservoirAirLeak(HydraulicColor::Yellow),
+        ),
+        (
+            29_006,
+            FailureType::ReservoirReturnLeak(HydraulicColor::Green),
+        ),
+        (
+            29_007,
+            FailureType::ReservoirReturnLeak(HydraulicColor::Blue),
+        ),
+        (
+            29_008,
+            FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
+        ),
+        (
+            29_009,
+            FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Green),
+        ),
+        (
+            29_010,
+            FailureType::ElecPumpOverheat(AirbusElectricPumpId::Blue),
+        ),
+        (
+            29_011,
+            FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Yellow),
+        ),
+        (
+            29_012,
+            FailureType::ElecPumpOverheat(AirbusElectricPumpId::Yellow),
+        ),
+        (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
+        (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
+        (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
+        (32_003, FailureType::LgciuInternalError(LgciuId::Lgciu2)),
+        (
+            32_004,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearNose1),
+        ),
+        (
+            32_005,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearNose2),
+        ),
+        (
+            32_006,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearRight1),
+        ),
+        (
+            32_007,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearRight2),
+        ),
+        (
+            32_008,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearLeft2),
+        ),
+        (
+            32_009,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearLeft1),
+        ),
+        (
+            32_010,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorNose1),
+        ),
+        (
+            32_011,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorNose2),
+        ),
+        (
+            32_012,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorRight2),
+        ),
+        (
+            32_013,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorRight1),
+        ),
+        (
+            32_014,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorLeft2),
+        ),
+        (
+            32_015,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorLeft1),
+        ),
+        (
+            32_020,
+            FailureType::GearActuatorJammed(GearActuatorId::GearNose),
+        ),
+        (
+            32_021,
+            FailureType::GearActuatorJammed(GearActuatorId::GearLeft),
+        ),
+        (
+            32_022,
+            FailureType::GearActuatorJammed(GearActuatorId::GearRight),
+        ),
+        (
+            32_023,
+            FailureType::GearActuatorJammed(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_024,
+            FailureType::GearActuatorJammed(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_025,
+            FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
+        ),
+        (
+            32_100,
+            FailureType::BrakeHydraulicLeak(HydraulicColor::Green),
+        ),
+        (
+            32_101,
+            FailureType::BrakeHydraulicLeak(HydraulicColor::Yellow),
+        ),
+        (32_150, FailureType::BrakeAccumulatorGasLeak),
+        (32_200, FailureType::TireBurst(1)),
+        (32_201, FailureType::TireBurst(2)),
+        (32_202, FailureType::TireBurst(3)),
+        (32_203, FailureType::TireBurst(4)),
+        (32_204, FailureType::TireBurst(5)),
+        (32_205, FailureType::TireBurst(6)),
+        (32_210, FailureType::TireSlowLeak(1)),
+        (32_211, FailureType::TireSlowLeak(2)),
+        (32_212, FailureType::TireSlowLeak(3)),
+        (32_213, FailureType::TireSlowLeak(4)),
+        (32_214, FailureType::TireSlowLeak(5)),
+        (32_215, FailureType::TireSlowLeak(6)),
+        (32_220, FailureType::WheelSpeedSensor(1)),
+        (32_221, FailureType::WheelSpeedSensor(2)),
+        (32_222, FailureType::WheelSpeedSensor(3)),
+        (32_223, FailureType::WheelSpeedSensor(4)),
+        (32_230, FailureType::AntiSkid),
+        (34_000, FailureType::RadioAltimeter(1)),
+        (34_001, FailureType::RadioAltimeter(2)),
+        (34_010, FailureType::AdrFault(1)),
+        (34_011, FailureType::AdrFault(2)),
+        (34_012, FailureType::AdrFault(3)),
+        (34_020, FailureType::IrFault(1)),
+        (34_021, FailureType::IrFault(2)),
+        (34_022, FailureType::IrFault(3)),
+        (34_030, FailureType::PitotBlockage(1)),
+        (34_031, FailureType::PitotBlockage(2)),
+        (34_032, FailureType::PitotBlockage(3)),
+        (34_040, FailureType::StaticPortBlockage(1)),
+        (34_041, FailureType::StaticPortBlockage(2)),
+        (34_042, FailureType::StaticPortBlockage(3)),
+        (34_050, FailureType::AngleOfAttackVaneJam(1)),
+        (34_051, FailureType::AngleOfAttackVaneJam(2)),
+        (34_052, FailureType::AngleOfAttackVaneJam(3)),
+        (34_060, FailureType::GpsReceiver(1)),
+        (34_061, FailureType::GpsReceiver(2)),
+        (36_000, FailureType::BleedDuctLeak(BleedDuct::Engine(1))),
+        (36_001, FailureType::BleedDuctLeak(BleedDuct::Engine(2))),
+        (36_002, FailureType::BleedDuctLeak(BleedDuct::Wing(1))),
+        (36_003, FailureType::BleedDuctLeak(BleedDuct::Wing(2))),
+        (36_004, FailureType::BleedDuctLeak(BleedDuct::Apu)),
+    ]
+}
@@ -1,11 +1,21 @@
-// Note: Fuel system for now is still handled in MSFS. This is used for calculating fuel-related factors.
+// Note: Fuel burn is still handled by MSFS. The distribution of fuel between the tanks
+// and the feeding of the engines and APU is simulated here.
 
 use nalgebra::Vector3;
+use std::time::Duration;
 use systems::{
-    fuel::{FuelCG, FuelInfo, FuelPayload, FuelSystem, FuelTank},
-    simulation::{InitContext, SimulationElement, SimulationElementVisitor},
+    accept_iterable,
+    fuel::{
+        FuelCG, FuelFeed, FuelFeedLine, FuelInfo, FuelPayload, FuelPump, FuelSystem, FuelTank,
+        FuelValve, FUEL_GALLONS_TO_KG,
+    },
+    shared::{ApuMaster, ElectricalBusType, EngineFirePushButtons},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        UpdateContext, VariableIdentifier,
+    },
 };
-use uom::si::f64::*;
+use uom::si::{f64::*, mass::kilogram, mass_rate::kilogram_per_second};
 
 #[cfg(test)]
 mod test;
@@ -46,8 +56,51 @@ impl From<usize> for A320FuelTankType {
 
 pub struct A320Fuel {
     fuel_system: FuelSystem<5>,
+
+    pump_switch_ids: [VariableIdentifier; 7],
+    pump_switches: [bool; 7],
+    engine_master_switch_ids: [VariableIdentifier; 2],
+    engine_master_switches: [bool; 2],
+    crossfeed_switch_id: VariableIdentifier,
+    crossfeed_switch: bool,
+    center_transfer_switch_ids: [VariableIdentifier; 2],
+    center_transfer_switches: [bool; 2],
+
+    left_inner_pumps: [FuelPump; 2],
+    right_inner_pumps: [FuelPump; 2],
+    center_jet_pumps: [FuelPump; 2],
+    apu_pump: FuelPump,
+
+    center_transfer_valves: [FuelValve; 2],
+    center_transfer_is_commanded: [bool; 2],
+    outer_transfer_valves: [FuelValve; 2],
+    outer_transfer_is_latched: bool,
+    crossfeed_valve: FuelValve,
+    engine_low_pressure_valves: [FuelValve; 2],
+    apu_low_pressure_valve: FuelValve,
+
+    feed_lines: [FuelFeedLine; 2],
+    apu_feed_line: FuelFeedLine,
 }
 impl A320Fuel {
+    // Pump numbers match the pump indices of the MSFS fuel system.
+    const LEFT_CENTER_JET_PUMP_NUMBER: usize = 1;
+    const LEFT_INNER_PUMP_1_NUMBER: usize = 2;
+    const RIGHT_INNER_PUMP_1_NUMBER: usize = 3;
+    const RIGHT_CENTER_JET_PUMP_NUMBER: usize = 4;
+    const LEFT_INNER_PUMP_2_NUMBER: usize = 5;
+    const RIGHT_INNER_PUMP_2_NUMBER: usize = 6;
+    const APU_PUMP_NUMBER: usize = 7;
+
+    const INNER_TANK_CAPACITY_GALLONS: f64 = 1816.;
+    const CENTER_TRANSFER_START_GALLONS: f64 = 1651.5;
+    const CENTER_TRANSFER_STOP_GALLONS: f64 = 1815.;
+    const CENTER_TRANSFER_RATE_KG_PER_S: f64 = 0.85;
+
+    const OUTER_TRANSFER_START_KG: f64 = 750.;
+    const OUTER_TANK_REFUELED_GALLONS: f64 = 227.9;
+    const OUTER_TRANSFER_RATE_KG_PER_S: f64 = 0.5;
+
     pub const A320_FUEL: [FuelInfo<'_>; 5] = [
         FuelInfo {
             fuel_tank_id: "FUEL TANK CENTER QUANTITY",
@@ -71,22 +124,337 @@ impl A320Fuel {
         },
     ];
 
+    const A320_FUEL_QUANTITY_REQUESTS: [&'static str; 5] = [
+        "FUEL_TANK_CENTER_QUANTITY_REQ",
+        "FUEL_TANK_LEFT_MAIN_QUANTITY_REQ",
+        "FUEL_TANK_LEFT_AUX_QUANTITY_REQ",
+        "FUEL_TANK_RIGHT_MAIN_QUANTITY_REQ",
+        "FUEL_TANK_RIGHT_AUX_QUANTITY_REQ",
+    ];
+
     pub fn new(context: &mut InitContext) -> Self {
+        let mut requests = Self::A320_FUEL_QUANTITY_REQUESTS.iter();
         let fuel_tanks = Self::A320_FUEL.map(|f| {
-            FuelTank::new(
+            FuelTank::new_with_quantity_request(
                 context,
                 f.fuel_tank_id,
+                requests.next().unwrap(),
                 Vector3::new(f.position.0, f.position.1, f.position.2),
             )
         });
         A320Fuel {
             fuel_system: FuelSystem::new(context, fuel_tanks),
+
+            pump_switch_ids: [1, 2, 3, 4, 5, 6, 7]
+                .map(|i| context.get_identifier(format!("FUELSYSTEM PUMP SWITCH:{}", i))),
+            pump_switches: [false; 7],
+            engine_master_switch_ids: [1, 2]
+                .map(|i| context.get_identifier(format!("FUELSYSTEM VALVE SWITCH:{}", i))),
+            engine_master_switches: [false; 2],
+            crossfeed_switch_id: context.get_identifier("FUELSYSTEM VALVE SWITCH:3".to_owned()),
+            crossfeed_switch: false,
+            center_transfer_switch_ids: [9, 10]
+                .map(|i| context.get_identifier(format!("FUELSYSTEM VALVE SWITCH:{}", i))),
+            center_transfer_switches: [false; 2],
+
+            left_inner_pumps: [
+                FuelPump::new(
+                    context,
+                    Self::LEFT_INNER_PUMP_1_NUMBER,
+                    ElectricalBusType::AlternatingCurrent(1),
+                ),
+                FuelPump::new(
+                    context,
+                    Self::LEFT_INNER_PUMP_2_NUMBER,
+                    ElectricalBusType::AlternatingCurrent(2),
+                ),
+            ],
+            right_inner_pumps: [
+                FuelPump::new(
+                    context,
+                    Self::RIGHT_INNER_PUMP_1_NUMBER,
+                    ElectricalBusType::AlternatingCurrent(1),
+                ),
+                FuelPump::new(
+                    context,
+                    Self::RIGHT_INNER_PUMP_2_NUMBER,
+                    ElectricalBusType::AlternatingCurrent(2),
+                ),
+            ],
+            center_jet_pumps: [
+                FuelPump::new(
+                    context,
+                    Self::LEFT_CENTER_JET_PUMP_NUMBER,
+                    ElectricalBusType::AlternatingCurrent(1),
+                ),
+                FuelPump::new(
+                    context,
+                    Self::RIGHT_CENTER_JET_PUMP_NUMBER,
+                    ElectricalBusType::AlternatingCurrent(2),
+                ),
+            ],
+            apu_pump: FuelPump::new(
+                context,
+                Self::APU_PUMP_NUMBER,
+                ElectricalBusType::AlternatingCurrentEssential,
+            ),
+
+            center_transfer_valves: [
+                FuelValve::new(
+                    context,
+                    "L_CTR_XFR",
+                    Duration::from_secs(2),
+                    ElectricalBusType::DirectCurrent(1),
+                ),
+                FuelValve::new(
+                    context,
+                    "R_CTR_XFR",
+                    Duration::from_secs(2),
+                    ElectricalBusType::DirectCurrent(2),
+                ),
+            ],
+            center_transfer_is_commanded: [false; 2],
+            outer_transfer_valves: [
+                FuelValve::new(
+                    context,
+                    "L_OUTER_XFR",
+                    Duration::from_secs(2),
+                    ElectricalBusType::DirectCurrentEssential,
+                ),
+                FuelValve::new(
+                    context,
+                    "R_OUTER_XFR",
+                    Duration::from_secs(2),
+                    ElectricalBusType::DirectCurrent(2),
+                ),
+            ],
+            outer_transfer_is_latched: false,
+            crossfeed_valve: FuelValve::new(
+                context,
+                "XFEED",
+                Duration::from_secs(3),
+                ElectricalBusType::DirectCurrentEssential,
+            ),
+            engine_low_pressure_valves: [
+                FuelValve::new(
+                    context,
+                    "ENG_1_LP",
+                    Duration::from_millis(1700),
+                    ElectricalBusType::DirectCurrentHot(1),
+                ),
+                FuelValve::new(
+                    context,
+                    "ENG_2_LP",
+                    Duration::from_millis(1700),
+                    ElectricalBusType::DirectCurrentHot(2),
+                ),
+            ],
+            apu_low_pressure_valve: FuelValve::new(
+                context,
+                "APU_LP",
+                Duration::from_secs(3),
+                ElectricalBusType::DirectCurrentBattery,
+            ),
+
+            feed_lines: [
+                FuelFeedLine::new(context, "ENG_1"),
+                FuelFeedLine::new(context, "ENG_2"),
+            ],
+            apu_feed_line: FuelFeedLine::new(context, "APU"),
         }
     }
 
-    pub fn left_inner_tank_has_fuel_remaining(&self) -> bool {
-        self.fuel_system
-            .tank_has_fuel(A320FuelTankType::LeftInner.into())
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine_fire_push_buttons: &impl EngineFirePushButtons,
+        apu_master: &impl ApuMaster,
+    ) {
+        self.update_pumps(context);
+        self.update_center_transfer(context);
+        self.update_outer_transfer(context);
+        self.update_feed(context, engine_fire_push_buttons, apu_master);
+    }
+
+    fn update_pumps(&mut self, context: &UpdateContext) {
+        let switches = self.pump_switches;
+        let switch_is_on = |pump_number: usize| switches[pump_number - 1];
+
+        let left_inner = self.fuel_system.tank(A320FuelTankType::LeftInner.into());
+        let right_inner = self.fuel_system.tank(A320FuelTankType::RightInner.into());
+        let center = self.fuel_system.tank(A320FuelTankType::Center.into());
+
+        self.left_inner_pumps[0].update(
+            context,
+            switch_is_on(Self::LEFT_INNER_PUMP_1_NUMBER),
+            left_inner,
+        );
+        self.left_inner_pumps[1].update(
+            context,
+            switch_is_on(Self::LEFT_INNER_PUMP_2_NUMBER),
+            left_inner,
+        );
+        self.right_inner_pumps[0].update(
+            context,
+            switch_is_on(Self::RIGHT_INNER_PUMP_1_NUMBER),
+            right_inner,
+        );
+        self.right_inner_pumps[1].update(
+            context,
+            switch_is_on(Self::RIGHT_INNER_PUMP_2_NUMBER),
+            right_inner,
+        );
+        self.center_jet_pumps[0].update(
+            context,
+            switch_is_on(Self::LEFT_CENTER_JET_PUMP_NUMBER),
+            center,
+        );
+        self.center_jet_pumps[1].update(
+            context,
+            switch_is_on(Self::RIGHT_CENTER_JET_PUMP_NUMBER),
+            center,
+        );
+
+        // The APU pump only runs when there is no pressure in the left feed line.
+        self.apu_pump.update(
+            context,
+            switch_is_on(Self::APU_PUMP_NUMBER) && self.feed_lines[0].is_low_pressure(),
+            left_inner,
+        );
+    }
+
+    fn update_center_transfer(&mut self, context: &UpdateContext) {
+        let inner_tanks = [A320FuelTankType::LeftInner, A320FuelTankType::RightInner];
+
+        for (side, inner_tank) in inner_tanks.into_iter().enumerate() {
+            let inner_tank: usize = inner_tank.into();
+            let inner_quantity = self.fuel_system.tank_mass(inner_tank);
+
+            if inner_quantity < Self::gallons_to_mass(Self::CENTER_TRANSFER_START_GALLONS) {
+                self.center_transfer_is_commanded[side] = true;
+            } else if inner_quantity > Self::gallons_to_mass(Self::CENTER_TRANSFER_STOP_GALLONS) {
+                self.center_transfer_is_commanded[side] = false;
+            }
+
+            self.center_transfer_valves[side].update(
+                context,
+                self.center_transfer_switches[side] && self.center_transfer_is_commanded[side],
+            );
+
+            if self.center_transfer_valves[side].is_fully_open()
+                && self.center_jet_pumps[side].is_pressurised()
+            {
+                let mass = self.transferable_mass(
+                    inner_tank,
+                    MassRate::new::<kilogram_per_second>(Self::CENTER_TRANSFER_RATE_KG_PER_S)
+                        * context.delta_as_time(),
+                );
+                self.fuel_system
+                    .transfer_fuel(A320FuelTankType::Center.into(), inner_tank, mass);
+            }
+        }
+    }
+
+    fn update_outer_transfer(&mut self, context: &UpdateContext) {
+        let left_inner = self
+            .fuel_system
+            .tank_mass(A320FuelTankType::LeftInner.into());
+        let right_inner = self
+            .fuel_system
+            .tank_mass(A320FuelTankType::RightInner.into());
+        let outer_tanks_refueled = self
+            .fuel_system
+            .tank_mass(A320FuelTankType::LeftOuter.into())
+            > Self::gallons_to_mass(Self::OUTER_TANK_REFUELED_GALLONS)
+            && self
+                .fuel_system
+                .tank_mass(A320FuelTankType::RightOuter.into())
+                > Self::gallons_to_mass(Self::OUTER_TANK_REFUELED_GALLONS);
+
+        // Once opened, the transfer valves remain latched open until the outer tanks are refueled.
+        let start_threshold = Mass::new::<kilogram>(Self::OUTER_TRANSFER_START_KG);
+        if left_inner < start_threshold || right_inner < start_threshold {
+            self.outer_transfer_is_latched = true;
+        } else if outer_tanks_refueled {
+            self.outer_transfer_is_latched = false;
+        }
+
+        let tanks = [
+            (A320FuelTankType::LeftOuter, A320FuelTankType::LeftInner),
+            (A320FuelTankType::RightOuter, A320FuelTankType::RightInner),
+        ];
+        for (side, (outer_tank, inner_tank)) in tanks.into_iter().enumerate() {
+            self.outer_transfer_valves[side].update(context, self.outer_transfer_is_latched);
+
+            // Fuel flows from the outer to the inner tank by gravity.
+            if self.outer_transfer_valves[side].is_open() {
+                let inner_tank: usize = inner_tank.into();
+                let mass = self.transferable_mass(
+                    inner_tank,
+                    MassRate::new::<kilogram_per_second>(Self::OUTER_TRANSFER_RATE_KG_PER_S)
+                        * context.delta_as_time()
+                        * self.outer_transfer_valves[side].open_amount().value,
+                );
+                self.fuel_system
+                    .transfer_fuel(outer_tank.into(), inner_tank, mass);
+            }
+        }
+    }
+
+    fn update_feed(
+        &mut self,
+        context: &UpdateContext,
+        engine_fire_push_buttons: &impl EngineFirePushButtons,
+        apu_master: &impl ApuMaster,
+    ) {
+        self.crossfeed_valve.update(context, self.crossfeed_switch);
+
+        let crossfeed_is_open = self.crossfeed_valve.is_fully_open();
+        let left_pumps = self.left_inner_pumps.iter();
+        let right_pumps = self.right_inner_pumps.iter();
+        self.feed_lines[0].update(
+            context,
+            left_pumps
+                .clone()
+                .chain(right_pumps.clone().filter(|_| crossfeed_is_open)),
+            self.fuel_system.tank(A320FuelTankType::LeftInner.into()),
+        );
+        self.feed_lines[1].update(
+            context,
+            right_pumps.chain(left_pumps.filter(|_| crossfeed_is_open)),
+            self.fuel_system.tank(A320FuelTankType::RightInner.into()),
+        );
+
+        for (index, valve) in self.engine_low_pressure_valves.iter_mut().enumerate() {
+            valve.update(
+                context,
+                self.engine_master_switches[index]
+                    && !engine_fire_push_buttons.is_released(index + 1),
+            );
+        }
+
+        self.apu_low_pressure_valve
+            .update(context, apu_master.master_sw_is_on());
+        self.apu_feed_line.update(
+            context,
+            self.left_inner_pumps
+                .iter()
+                .chain(self.right_inner_pumps.iter().filter(|_| crossfeed_is_open))
+                .chain(std::iter::once(&self.apu_pump)),
+            self.fuel_system.tank(A320FuelTankType::LeftInner.into()),
+        );
+    }
+
+    /// Limits the given mass to the space remaining in the given inner tank.
+    fn transferable_mass(&self, inner_tank: usize, mass: Mass) -> Mass {
+        let remaining_capacity = Self::gallons_to_mass(Self::INNER_TANK_CAPACITY_GALLONS)
+            - self.fuel_system.tank_mass(inner_tank);
+
+        mass.min(remaining_capacity).max(Mass::default())
+    }
+
+    fn gallons_to_mass(gallons: f64) -> Mass {
+        Mass::new::<kilogram>(gallons * FUEL_GALLONS_TO_KG)
     }
 
     fn center_tank_has_fuel(&self) -> bool {
@@ -143,6 +511,16 @@ impl FuelLevel for A320Fuel {
         self.center_tank_has_fuel()
     }
 }
+impl FuelFeed for A320Fuel {
+    fn engine_is_fed(&self, engine_number: usize) -> bool {
+        self.engine_low_pressure_valves[engine_number - 1].is_open()
+            && self.feed_lines[engine_number - 1].delivers_fuel()
+    }
+
+    fn apu_is_fed(&self) -> bool {
+        self.apu_low_pressure_valve.is_open() && self.apu_feed_line.delivers_fuel()
+    }
+}
 impl FuelPayload for A320Fuel {
     fn total_load(&self) -> Mass {
         self.total_load()
@@ -159,6 +537,39 @@ impl FuelCG for A320Fuel {
 impl SimulationElement for A320Fuel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
+        accept_iterable!(self.left_inner_pumps, visitor);
+        accept_iterable!(self.right_inner_pumps, visitor);
+        accept_iterable!(self.center_jet_pumps, visitor);
+        self.apu_pump.accept(visitor);
+        accept_iterable!(self.center_transfer_valves, visitor);
+        accept_iterable!(self.outer_transfer_valves, visitor);
+        self.crossfeed_valve.accept(visitor);
+        accept_iterable!(self.engine_low_pressure_valves, visitor);
+        self.apu_low_pressure_valve.accept(visitor);
+        accept_iterable!(self.feed_lines, visitor);
+        self.apu_feed_line.accept(visitor);
+
         visitor.visit(self);
     }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        for (switch, id) in self.pump_switches.iter_mut().zip(&self.pump_switch_ids) {
+            *switch = reader.read(id);
+        }
+        for (switch, id) in self
+            .engine_master_switches
+            .iter_mut()
+            .zip(&self.engine_master_switch_ids)
+        {
+            *switch = reader.read(id);
+        }
+        self.crossfeed_switch = reader.read(&self.crossfeed_switch_id);
+        for (switch, id) in self
+            .center_transfer_switches
+            .iter_mut()
+            .zip(&self.center_transfer_switch_ids)
+        {
+            *switch = reader.read(id);
+        }
+    }
 }
@@ -1,31 +1,87 @@
 use std::time::Duration;
 
 use super::*;
-use crate::systems::simulation::{
-    test::{SimulationTestBed, TestBed, WriteByName},
-    Aircraft, SimulationElement, SimulationElementVisitor,
+use crate::systems::{
+    apu::AuxiliaryPowerUnitOverheadPanel,
+    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    engine::EngineFireOverheadPanel,
+    failures::FailureType,
+    shared::PotentialOrigin,
+    simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft, SimulationElement, SimulationElementVisitor,
+    },
 };
+use uom::si::length::foot;
 
 struct FuelTestAircraft {
     fuel: A320Fuel,
+    engine_fire_overhead: EngineFireOverheadPanel<2>,
+    apu_overhead: AuxiliaryPowerUnitOverheadPanel,
+
+    powered_source: TestElectricitySource,
+    buses: Vec<ElectricalBus>,
 }
 
 impl FuelTestAircraft {
     fn new(context: &mut InitContext) -> Self {
         Self {
             fuel: A320Fuel::new(context),
+            engine_fire_overhead: EngineFireOverheadPanel::new(context),
+            apu_overhead: AuxiliaryPowerUnitOverheadPanel::new(context),
+
+            powered_source: TestElectricitySource::unpowered(
+                context,
+                PotentialOrigin::EngineGenerator(1),
+            ),
+            buses: [
+                ElectricalBusType::AlternatingCurrent(1),
+                ElectricalBusType::AlternatingCurrent(2),
+                ElectricalBusType::AlternatingCurrentEssential,
+                ElectricalBusType::DirectCurrent(1),
+                ElectricalBusType::DirectCurrent(2),
+                ElectricalBusType::DirectCurrentEssential,
+                ElectricalBusType::DirectCurrentBattery,
+                ElectricalBusType::DirectCurrentHot(1),
+                ElectricalBusType::DirectCurrentHot(2),
+            ]
+            .into_iter()
+            .map(|bus_type| ElectricalBus::new(context, bus_type))
+            .collect(),
         }
     }
 
+    fn power(&mut self) {
+        self.powered_source.power();
+    }
+
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.fuel.fore_aft_center_of_gravity()
     }
 }
 
-impl Aircraft for FuelTestAircraft {}
+impl Aircraft for FuelTestAircraft {
+    fn update_before_power_distribution(
+        &mut self,
+        _: &UpdateContext,
+        electricity: &mut Electricity,
+    ) {
+        electricity.supplied_by(&self.powered_source);
+        for bus in &self.buses {
+            electricity.flow(&self.powered_source, bus);
+        }
+    }
+
+    fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        self.fuel
+            .update(context, &self.engine_fire_overhead, &self.apu_overhead);
+    }
+}
 impl SimulationElement for FuelTestAircraft {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel.accept(visitor);
+        self.engine_fire_overhead.accept(visitor);
+        self.apu_overhead.accept(visitor);
 
         visitor.visit(self);
     }
@@ -82,9 +138,114 @@ impl FuelTestBed {
         self
     }
 
+    fn fuel_full(mut self) -> Self {
+        self.write_by_name("FUEL TANK LEFT MAIN QUANTITY", 1816.);
+        self.write_by_name("FUEL TANK LEFT AUX QUANTITY", 228.);
+        self.write_by_name("FUEL TANK RIGHT MAIN QUANTITY", 1816.);
+        self.write_by_name("FUEL TANK RIGHT AUX QUANTITY", 228.);
+        self.write_by_name("FUEL TANK CENTER QUANTITY", 2179.);
+
+        self
+    }
+
+    fn powered(mut self) -> Self {
+        self.command(|a| a.power());
+
+        self
+    }
+
+    fn pumps_on(mut self) -> Self {
+        for number in 1..=7 {
+            self.write_by_name(&format!("FUELSYSTEM PUMP SWITCH:{}", number), true);
+        }
+
+        self
+    }
+
+    fn left_inner_tank_pumps_off(mut self) -> Self {
+        self.write_by_name("FUELSYSTEM PUMP SWITCH:2", false);
+        self.write_by_name("FUELSYSTEM PUMP SWITCH:5", false);
+
+        self
+    }
+
+    fn engine_master_on(mut self, engine_number: usize) -> Self {
+        self.write_by_name(&format!("FUELSYSTEM VALVE SWITCH:{}", engine_number), true);
+
+        self
+    }
+
+    fn crossfeed_on(mut self) -> Self {
+        self.write_by_name("FUELSYSTEM VALVE SWITCH:3", true);
+
+        self
+    }
+
+    fn center_transfer_on(mut self) -> Self {
+        self.write_by_name("FUELSYSTEM VALVE SWITCH:9", true);
+        self.write_by_name("FUELSYSTEM VALVE SWITCH:10", true);
+
+        self
+    }
+
+    fn apu_master_on(mut self) -> Self {
+        self.write_by_name("OVHD_APU_MASTER_SW_PB_IS_ON", true);
+
+        self
+    }
+
+    fn release_fire_push_button(mut self, engine_number: usize) -> Self {
+        self.write_by_name(&format!("FIRE_BUTTON_ENG{}", engine_number), true);
+
+        self
+    }
+
+    fn at_altitude(mut self, altitude: Length) -> Self {
+        self.set_indicated_altitude(altitude);
+
+        self
+    }
+
+    /// Runs the simulation while feeding the requested tank quantities back into the
+    /// tank quantities, as is done by the fuel aspect in the simulator.
+    fn run_for(mut self, duration: Duration) -> Self {
+        let frame = Duration::from_millis(100);
+        let mut elapsed = Duration::ZERO;
+        while elapsed < duration {
+            self.run_with_delta(frame);
+            for (info, request) in A320Fuel::A320_FUEL
+                .iter()
+                .zip(A320Fuel::A320_FUEL_QUANTITY_REQUESTS)
+            {
+                let quantity: f64 = self.read_by_name(request);
+                self.write_by_name(info.fuel_tank_id, quantity);
+            }
+
+            elapsed += frame;
+        }
+
+        self
+    }
+
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.query(|a: &FuelTestAircraft| a.fore_aft_center_of_gravity())
     }
+
+    fn engine_is_fed(&self, engine_number: usize) -> bool {
+        self.query(|a| a.fuel.engine_is_fed(engine_number))
+    }
+
+    fn apu_is_fed(&self) -> bool {
+        self.query(|a| a.fuel.apu_is_fed())
+    }
+
+    fn feed_line_is_low_pressure(&mut self, engine_number: usize) -> bool {
+        self.read_by_name(&format!("FUEL_ENG_{}_FEED_LO_PR", engine_number))
+    }
+
+    fn tank_quantity(&self, tank: A320FuelTankType) -> Mass {
+        self.query(|a| a.fuel.fuel_system.tank_mass(tank.into()))
+    }
 }
 
 impl TestBed for FuelTestBed {
@@ -144,3 +305,224 @@ fn high_fuel() {
         (test_bed.fore_aft_center_of_gravity() * 100.).round() / 100.,
     );
 }
+
+#[test]
+fn unpowered_fuel_system_does_not_transfer_fuel() {
+    let test_bed = test_bed_with()
+        .fuel_low()
+        .pumps_on()
+        .center_transfer_on()
+        .run_for(Duration::from_secs(60));
+
+    assert!(
+        (test_bed
+            .tank_quantity(A320FuelTankType::LeftOuter)
+            .get::<kilogram>()
+            - 150.)
+            .abs()
+            < 1.
+    );
+}
+
+#[test]
+fn pumps_pressurise_feed_lines() {
+    let mut test_bed = test_bed_with()
+        .fuel_full()
+        .powered()
+        .pumps_on()
+        .run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.feed_line_is_low_pressure(1));
+    assert!(!test_bed.feed_line_is_low_pressure(2));
+}
+
+#[test]
+fn feed_lines_are_low_pressure_without_pumps() {
+    let mut test_bed = test_bed_with()
+        .fuel_full()
+        .powered()
+        .run_for(Duration::from_secs(5));
+
+    assert!(test_bed.feed_line_is_low_pressure(1));
+    assert!(test_bed.feed_line_is_low_pressure(2));
+}
+
+#[test]
+fn failed_pumps_do_not_pressurise_feed_line() {
+    let mut test_bed = test_bed_with().fuel_full().powered().pumps_on();
+    test_bed.fail(FailureType::FuelPump(A320Fuel::LEFT_INNER_PUMP_1_NUMBER));
+    test_bed.fail(FailureType::FuelPump(A320Fuel::LEFT_INNER_PUMP_2_NUMBER));
+    test_bed = test_bed.run_for(Duration::from_secs(5));
+
+    assert!(test_bed.feed_line_is_low_pressure(1));
+    assert!(!test_bed.feed_line_is_low_pressure(2));
+}
+
+#[test]
+fn crossfeed_pressurises_opposite_feed_line() {
+    let mut test_bed = test_bed_with()
+        .fuel_full()
+        .powered()
+        .pumps_on()
+        .left_inner_tank_pumps_off()
+        .crossfeed_on()
+        .run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.feed_line_is_low_pressure(1));
+}
+
+#[test]
+fn engine_is_not_fed_with_master_off() {
+    let test_bed = test_bed_with()
+        .fuel_full()
+        .powered()
+        .pumps_on()
+        .run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.engine_is_fed(1));
+    assert!(!test_bed.engine_is_fed(2));
+}
+
+#[test]
+fn engine_is_fed_with_master_on() {
+    let test_bed = test_bed_with()
+        .fuel_full()
+        .powered()
+        .pumps_on()
+        .engine_master_on(1)
+        .engine_master_on(2)
+        .run_for(Duration::from_secs(5));
+
+    assert!(test_bed.engine_is_fed(1));
+    assert!(test_bed.engine_is_fed(2));
+}
+
+#[test]
+fn released_fire_push_button_closes_low_pressure_valve() {
+    let test_bed = test_bed_with()
+        .fuel_full()
+        .powered()
+        .pumps_on()
+        .engine_master_on(1)
+        .engine_master_on(2)
+        .run_for(Duration::from_secs(5))
+        .release_fire_push_button(1)
+        .run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.engine_is_fed(1));
+    assert!(test_bed.engine_is_fed(2));
+}
+
+#[test]
+fn engine_suction_feeds_at_low_altitude() {
+    let test_bed = test_bed_with()
+        .fuel_full()
+        .powered()
+        .engine_master_on(1)
+        .at_altitude(Length::new::<foot>(10000.))
+        .run_for(Duration::from_secs(5));
+
+    assert!(test_bed.engine_is_fed(1));
+}
+
+#[test]
+fn engine_cannot_suction_feed_at_high_altitude() {
+    let test_bed = test_bed_with()
+        .fuel_full()
+        .powered()
+        .engine_master_on(1)
+        .at_altitude(Length::new::<foot>(30000.))
+        .run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.engine_is_fed(1));
+}
+
+#[test]
+fn apu_is_fed_with_master_on() {
+    let test_bed = test_bed_with()
+        .fuel_full()
+        .powered()
+        .apu_master_on()
+        .run_for(Duration::from_secs(5));
+
+    assert!(test_bed.apu_is_fed());
+}
+
+#[test]
+fn apu_is_not_fed_with_master_off() {
+    let test_bed = test_bed_with()
+        .fuel_full()
+        .powered()
+        .run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.apu_is_fed());
+}
+
+#[test]
+fn center_tank_transfers_to_inner_tanks_when_inner_tanks_are_not_full() {
+    let mut test_bed = test_bed_with().fuel_full();
+    test_bed.write_by_name("FUEL TANK LEFT MAIN QUANTITY", 1000.);
+    test_bed.write_by_name("FUEL TANK RIGHT MAIN QUANTITY", 1000.);
+    test_bed = test_bed
+        .powered()
+        .pumps_on()
+        .center_transfer_on()
+        .run_for(Duration::from_secs(60));
+
+    assert!(
+        test_bed.tank_quantity(A320FuelTankType::LeftInner)
+            > Mass::new::<kilogram>(1000. * FUEL_GALLONS_TO_KG)
+    );
+    assert!(
+        test_bed.tank_quantity(A320FuelTankType::Center)
+            < Mass::new::<kilogram>(2179. * FUEL_GALLONS_TO_KG)
+    );
+}
+
+#[test]
+fn center_tank_does_not_transfer_when_transfer_is_off() {
+    let mut test_bed = test_bed_with().fuel_full();
+    test_bed.write_by_name("FUEL TANK LEFT MAIN QUANTITY", 1000.);
+    test_bed = test_bed
+        .powered()
+        .pumps_on()
+        .run_for(Duration::from_secs(60));
+
+    assert!(
+        (test_bed
+            .tank_quantity(A320FuelTankType::Center)
+            .get::<kilogram>()
+            - 2179. * FUEL_GALLONS_TO_KG)
+            .abs()
+            < 1.
+    );
+}
+
+#[test]
+fn outer_tanks_transfer_to_inner_tanks_when_inner_tank_is_low() {
+    let test_bed = test_bed_with()
+        .fuel_low()
+        .powered()
+        .run_for(Duration::from_secs(60));
+
+    assert!(test_bed.tank_quantity(A320FuelTankType::LeftOuter) < Mass::new::<kilogram>(150.));
+    assert!(test_bed.tank_quantity(A320FuelTankType::RightOuter) < Mass::new::<kilogram>(150.));
+    assert!(test_bed.tank_quantity(A320FuelTankType::LeftInner) > Mass::new::<kilogram>(324.));
+}
+
+#[test]
+fn outer_tanks_do_not_transfer_when_inner_tanks_are_full() {
+    let test_bed = test_bed_with()
+        .fuel_full()
+        .powered()
+        .run_for(Duration::from_secs(60));
+
+    assert!(
+        (test_bed
+            .tank_quantity(A320FuelTankType::LeftOuter)
+            .get::<kilogram>()
+            - 228. * FUEL_GALLONS_TO_KG)
+            .abs()
+            < 1.
+    );
+}
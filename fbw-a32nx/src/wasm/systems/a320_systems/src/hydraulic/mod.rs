@@ -20,18 +20,21 @@ use uom::si::{
 use systems::{
     accept_iterable,
     engine::Engine,
+    failures::{Failure, FailureType},
     hydraulic::{
         aerodynamic_model::AerodynamicModel,
         brake_circuit::{
-            AutobrakeDecelerationGovernor, AutobrakeMode, AutobrakePanel,
+            AntiSkidController, AutobrakeDecelerationGovernor, AutobrakeMode, AutobrakePanel,
             BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController,
         },
+        brake_heat_sink::{BrakeFans, BrakeHeatSinks},
         cargo_doors::{CargoDoor, HydraulicDoorController},
         electrical_generator::{GeneratorControlUnit, HydraulicGeneratorMotor},
         flap_slat::FlapSlatAssembly,
         landing_gear::{GearGravityExtension, GearSystemController, HydraulicGearSystem},
         linear_actuator::{
-            Actuator, BoundedLinearLength, ElectroHydrostaticPowered, HydraulicAssemblyController,
+            passivate_failed_actuators, Actuator, BoundedLinearLength, ElectroHydrostaticPowered,
+            FlightControlActuatorFailures, HydraulicAssemblyController,
             HydraulicLinearActuatorAssembly, HydraulicLocking, LinearActuatedRigidBodyOnHingeAxis,
             LinearActuator, LinearActuatorCharacteristics, LinearActuatorMode,
         },
@@ -54,19 +57,25 @@ use systems::{
         PowerTransferUnitCharacteristics, PowerTransferUnitController, PressureSwitch,
         PressureSwitchType, PriorityValve, PumpController, RamAirTurbine, Reservoir,
     },
-    landing_gear::{GearSystemSensors, LandingGearControlInterfaceUnitSet},
+    landing_gear::{
+        tire::{Tire, Tires},
+        wheel_slip::WheelSlips,
+        wheel_speed_sensor::{WheelReferenceSpeed, WheelSpeedSensors},
+        GearSystemSensors, LandingGearControlInterfaceUnitSet,
+    },
     overhead::{
         AutoOffFaultPushButton, AutoOnFaultPushButton, MomentaryOnPushButton, MomentaryPushButton,
     },
     shared::{
         interpolation, random_from_normal_distribution, random_from_range,
         update_iterator::MaxStepLoop, AdirsDiscreteOutputs, AirbusElectricPumpId,
-        AirbusEngineDrivenPumpId, DelayedFalseLogicGate, DelayedPulseTrueLogicGate,
-        DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, EmergencyElectricalRatPushButton,
-        EmergencyElectricalState, EmergencyGeneratorControlUnit, EmergencyGeneratorPower,
-        EngineFirePushButtons, GearWheel, HydraulicColor, LandingGearHandle, LgciuInterface,
-        LgciuWeightOnWheels, RamAirTurbineController, ReservoirAirPressure, ReverserPosition,
-        SectionPressure, TrimmableHorizontalStabilizer,
+        AirbusEngineDrivenPumpId, BrakingEffectiveness, DelayedFalseLogicGate,
+        DelayedPulseTrueLogicGate, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses,
+        EmergencyElectricalRatPushButton, EmergencyElectricalState, EmergencyGeneratorControlUnit,
+        EmergencyGeneratorPower, EngineFirePushButtons, FlightControlActuatorId, GearWheel,
+        HydraulicColor, LandingGearHandle, LgciuInterface, LgciuWeightOnWheels,
+        RamAirTurbineController, ReservoirAirPressure, ReverserPosition, SectionPressure,
+        TirePressures, TrimmableHorizontalStabilizer, WheelSlipRatios, WheelSpeeds,
     },
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -77,6 +86,9 @@ use systems::{
 mod flaps_computer;
 use flaps_computer::SlatFlapComplex;
 
+mod spoiler_elevator_computer;
+use spoiler_elevator_computer::SpoilerElevatorComputerSet;
+
 #[cfg(test)]
 use systems::hydraulic::PressureSwitchState;
 
@@ -553,11 +565,13 @@ impl A320SpoilerFactory {
             ActuatorSide::Left => SpoilerGroup::new(
                 context,
                 "LEFT",
+                FlightControlActuatorId::LeftSpoiler,
                 [spoiler_1, spoiler_2, spoiler_3, spoiler_4, spoiler_5],
             ),
             ActuatorSide::Right => SpoilerGroup::new(
                 context,
                 "RIGHT",
+                FlightControlActuatorId::RightSpoiler,
                 [spoiler_1, spoiler_2, spoiler_3, spoiler_4, spoiler_5],
             ),
         }
@@ -1445,6 +1459,7 @@ pub(super) struct A320Hydraulic {
     hyd_ptu_ecam_memo_id: VariableIdentifier,
     ptu_high_pitch_sound_id: VariableIdentifier,
     ptu_continuous_mode_id: VariableIdentifier,
+    brake_fan_button_pressed_id: VariableIdentifier,
 
     nose_steering: SteeringActuator,
 
@@ -1482,6 +1497,10 @@ pub(super) struct A320Hydraulic {
     braking_circuit_norm: BrakeCircuit,
     braking_circuit_altn: BrakeCircuit,
     braking_force: A320BrakingForce,
+    brake_heat_sinks: BrakeHeatSinks,
+    is_brake_fan_button_pressed: bool,
+    tires: Tires,
+    wheel_speed_sensors: WheelSpeedSensors,
 
     flap_system: FlapSlatAssembly,
     slat_system: FlapSlatAssembly,
@@ -1506,6 +1525,7 @@ pub(super) struct A320Hydraulic {
     rudder_mechanical_assembly: RudderSystemHydraulicController,
     rudder: RudderAssembly,
 
+    spoiler_elevator_computers: SpoilerElevatorComputerSet,
     left_spoilers: SpoilerGroup,
     right_spoilers: SpoilerGroup,
 
@@ -1562,6 +1582,17 @@ impl A320Hydraulic {
 
     const PTU_CONTROL_POWER_BUS: ElectricalBusType = ElectricalBusType::DirectCurrentGndFltService;
 
+    const BRAKE_FANS_POWER_BUS: ElectricalBusType = ElectricalBusType::AlternatingCurrent(2);
+
+    // Main wheels 46x17R20
+    const MAIN_TIRE_RADIUS_METER: f64 = 0.584;
+    const BRAKE_HEAT_SINK_MASS_KG: f64 = 60.;
+    const MAIN_TIRE_NOMINAL_PRESSURE_PSI: f64 = 200.;
+
+    // Nose wheels 30x8.8R15
+    const NOSE_TIRE_RADIUS_METER: f64 = 0.381;
+    const NOSE_TIRE_NOMINAL_PRESSURE_PSI: f64 = 180.;
+
     const RAT_CONTROL_SOLENOID1_POWER_BUS: ElectricalBusType =
         ElectricalBusType::DirectCurrentHot(1);
     const RAT_CONTROL_SOLENOID2_POWER_BUS: ElectricalBusType =
@@ -1579,11 +1610,13 @@ impl A320Hydraulic {
             Pressure::new::<psi>(A320HydraulicCircuitFactory::HYDRAULIC_TARGET_PRESSURE_PSI),
             Ratio::new::<ratio>(0.03),
         );
+        let brake_fans = BrakeFans::new(context, Self::BRAKE_FANS_POWER_BUS);
 
         A320Hydraulic {
             hyd_ptu_ecam_memo_id: context.get_identifier("HYD_PTU_ON_ECAM_MEMO".to_owned()),
             ptu_high_pitch_sound_id: context.get_identifier("HYD_PTU_HIGH_PITCH_SOUND".to_owned()),
             ptu_continuous_mode_id: context.get_identifier("HYD_PTU_CONTINUOUS_MODE".to_owned()),
+            brake_fan_button_pressed_id: context.get_identifier("BRAKE_FAN_BTN_PRESSED".to_owned()),
 
             nose_steering: SteeringActuator::new(
                 context,
@@ -1703,6 +1736,43 @@ impl A320Hydraulic {
             ),
 
             braking_force: A320BrakingForce::new(context),
+            // Brakes 1 and 2 are on the left main gear, brakes 3 and 4 on the right main gear
+            brake_heat_sinks: BrakeHeatSinks::new(
+                context,
+                &[
+                    (GearWheel::LEFT, 1),
+                    (GearWheel::LEFT, 1),
+                    (GearWheel::RIGHT, 2),
+                    (GearWheel::RIGHT, 2),
+                ],
+                Mass::new::<kilogram>(Self::BRAKE_HEAT_SINK_MASS_KG),
+                Length::new::<meter>(Self::MAIN_TIRE_RADIUS_METER),
+                Some(brake_fans),
+            ),
+            is_brake_fan_button_pressed: false,
+            // Tires 1 to 4 are numbered as the brakes they are fitted with, 5 and 6 are the nose tires
+            tires: Tires::new(vec![
+                Self::main_tire(context, 1, GearWheel::LEFT, 1),
+                Self::main_tire(context, 2, GearWheel::LEFT, 1),
+                Self::main_tire(context, 3, GearWheel::RIGHT, 2),
+                Self::main_tire(context, 4, GearWheel::RIGHT, 2),
+                Self::nose_tire(context, 5),
+                Self::nose_tire(context, 6),
+            ]),
+            // One tachometer per main wheel, numbered as the brakes. The simulator only models a
+            // single wheel per main gear, so both tachometers of a gear measure the same sim wheel
+            // and differ only by their failures and the slip of their own brake.
+            wheel_speed_sensors: WheelSpeedSensors::new(
+                context,
+                &[
+                    (GearWheel::LEFT, 1),
+                    (GearWheel::LEFT, 1),
+                    (GearWheel::RIGHT, 2),
+                    (GearWheel::RIGHT, 2),
+                ],
+                Length::new::<meter>(Self::MAIN_TIRE_RADIUS_METER),
+                Pressure::new::<psi>(Self::MAIN_TIRE_NOMINAL_PRESSURE_PSI),
+            ),
 
             flap_system: FlapSlatAssembly::new(
                 context,
@@ -1765,6 +1835,10 @@ impl A320Hydraulic {
             rudder_mechanical_assembly: RudderSystemHydraulicController::new(context),
             rudder: A320RudderFactory::new_rudder(context),
 
+            spoiler_elevator_computers: SpoilerElevatorComputerSet::new(
+                context,
+                Length::new::<meter>(Self::MAIN_TIRE_RADIUS_METER),
+            ),
             left_spoilers: A320SpoilerFactory::new_a320_spoiler_group(context, ActuatorSide::Left),
             right_spoilers: A320SpoilerFactory::new_a320_spoiler_group(
                 context,
@@ -1800,6 +1874,35 @@ impl A320Hydraulic {
         }
     }
 
+    fn main_tire(
+        context: &mut InitContext,
+        number: usize,
+        gear: GearWheel,
+        sim_wheel_id: usize,
+    ) -> Tire {
+        Tire::new(
+            context,
+            number,
+            gear,
+            sim_wheel_id,
+            Some(number),
+            Pressure::new::<psi>(Self::MAIN_TIRE_NOMINAL_PRESSURE_PSI),
+            Length::new::<meter>(Self::MAIN_TIRE_RADIUS_METER),
+        )
+    }
+
+    fn nose_tire(context: &mut InitContext, number: usize) -> Tire {
+        Tire::new(
+            context,
+            number,
+            GearWheel::NOSE,
+            GearWheel::NOSE as usize,
+            None,
+            Pressure::new::<psi>(Self::NOSE_TIRE_NOMINAL_PRESSURE_PSI),
+            Length::new::<meter>(Self::NOSE_TIRE_RADIUS_METER),
+        )
+    }
+
     pub(super) fn update(
         &mut self,
         context: &UpdateContext,
@@ -2002,6 +2105,9 @@ impl A320Hydraulic {
             ],
         );
 
+        self.aileron_system_controller.update(context);
+        self.elevator_system_controller.update(context);
+
         self.left_aileron.update(
             context,
             self.aileron_system_controller.left_controllers(),
@@ -2042,6 +2148,7 @@ impl A320Hydraulic {
 
         self.left_spoilers.update(
             context,
+            &self.spoiler_elevator_computers,
             self.green_circuit.system_section(),
             self.blue_circuit.system_section(),
             self.yellow_circuit.system_section(),
@@ -2049,6 +2156,7 @@ impl A320Hydraulic {
 
         self.right_spoilers.update(
             context,
+            &self.spoiler_elevator_computers,
             self.green_circuit.system_section(),
             self.blue_circuit.system_section(),
             self.yellow_circuit.system_section(),
@@ -2074,6 +2182,12 @@ impl A320Hydraulic {
         engine1: &impl Engine,
         engine2: &impl Engine,
     ) {
+        self.spoiler_elevator_computers
+            .update(context, lgciu1, lgciu2);
+
+        self.wheel_speed_sensors
+            .update(context, self.braking_force.wheel_slips());
+
         self.nose_steering.update(
             context,
             self.yellow_circuit.system_section(),
@@ -2089,6 +2203,8 @@ impl A320Hydraulic {
             lgciu1,
             lgciu2,
             autobrake_panel,
+            self.spoiler_elevator_computers.ground_spoilers_out(),
+            &self.wheel_speed_sensors,
             engine1,
             engine2,
         );
@@ -2110,11 +2226,26 @@ impl A320Hydraulic {
             context,
             &self.braking_circuit_norm,
             &self.braking_circuit_altn,
+            &self.tires,
             engine1,
             engine2,
             &self.pushback_tug,
         );
 
+        self.brake_heat_sinks.update(
+            context,
+            self.braking_circuit_norm
+                .left_brake_pressure()
+                .max(self.braking_circuit_altn.left_brake_pressure()),
+            self.braking_circuit_norm
+                .right_brake_pressure()
+                .max(self.braking_circuit_altn.right_brake_pressure()),
+            lgciu1.main_down_and_locked(),
+            self.is_brake_fan_button_pressed,
+        );
+
+        self.tires.update(context, &self.brake_heat_sinks);
+
         self.slats_flaps_complex
             .update(context, &self.flap_system, &self.slat_system);
 
@@ -2463,12 +2594,16 @@ impl A320Hydraulic {
             engine1,
             lgciu1,
             self.reversers_assembly.reverser_feedback(0),
+            self.spoiler_elevator_computers
+                .reverser_tertiary_lock_should_unlock(1),
         );
         self.engine_reverser_control[1].update(
             context,
             engine2,
             lgciu2,
             self.reversers_assembly.reverser_feedback(1),
+            self.spoiler_elevator_computers
+                .reverser_tertiary_lock_should_unlock(2),
         );
 
         self.reversers_assembly.update(
@@ -2532,6 +2667,10 @@ impl A320Hydraulic {
     pub fn gear_system(&self) -> &impl GearSystemSensors {
         &self.gear_system
     }
+
+    pub fn tires(&self) -> &impl TirePressures {
+        &self.tires
+    }
 }
 impl SimulationElement for A320Hydraulic {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -2570,6 +2709,9 @@ impl SimulationElement for A320Hydraulic {
         self.braking_circuit_norm.accept(visitor);
         self.braking_circuit_altn.accept(visitor);
         self.braking_force.accept(visitor);
+        self.brake_heat_sinks.accept(visitor);
+        self.tires.accept(visitor);
+        self.wheel_speed_sensors.accept(visitor);
 
         self.emergency_gen.accept(visitor);
         self.nose_steering.accept(visitor);
@@ -2588,6 +2730,7 @@ impl SimulationElement for A320Hydraulic {
         self.rudder_mechanical_assembly.accept(visitor);
         self.rudder.accept(visitor);
 
+        self.spoiler_elevator_computers.accept(visitor);
         self.left_spoilers.accept(visitor);
         self.right_spoilers.accept(visitor);
 
@@ -2604,6 +2747,10 @@ impl SimulationElement for A320Hydraulic {
         visitor.visit(self);
     }
 
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_brake_fan_button_pressed = reader.read(&self.brake_fan_button_pressed_id);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(
             &self.hyd_ptu_ecam_memo_id,
@@ -3530,8 +3677,6 @@ struct A320HydraulicBrakeSteerComputerUnit {
     left_brake_pedal_input_id: VariableIdentifier,
     right_brake_pedal_input_id: VariableIdentifier,
 
-    ground_speed_id: VariableIdentifier,
-
     rudder_pedal_input_id: VariableIdentifier,
     tiller_handle_input_id: VariableIdentifier,
     tiller_pedal_disconnect_id: VariableIdentifier,
@@ -3550,6 +3695,16 @@ struct A320HydraulicBrakeSteerComputerUnit {
     should_disable_auto_brake_when_retracting: DelayedTrueLogicGate,
     anti_skid_activated: bool,
 
+    reference_speed: WheelReferenceSpeed,
+    left_wheel_speeds_valid: bool,
+    right_wheel_speeds_valid: bool,
+
+    left_wheels_slip: Ratio,
+    right_wheels_slip: Ratio,
+    left_anti_skid: AntiSkidController,
+    right_anti_skid: AntiSkidController,
+    anti_skid_failure: Failure,
+
     tiller_pedal_disconnect: bool,
     tiller_handle_position: Ratio,
     rudder_pedal_position: Ratio,
@@ -3560,8 +3715,6 @@ struct A320HydraulicBrakeSteerComputerUnit {
     tiller_steering_limiter: SteeringAngleLimiter<5>,
     tiller_input_map: SteeringRatioToAngle<6>,
     final_steering_position_request: Angle,
-
-    ground_speed: Velocity,
 }
 impl A320HydraulicBrakeSteerComputerUnit {
     const RUDDER_PEDAL_INPUT_GAIN: f64 = 32.;
@@ -3596,6 +3749,10 @@ impl A320HydraulicBrakeSteerComputerUnit {
 
     const PILOT_INPUT_DETECTION_TRESHOLD: f64 = 0.2;
 
+    // Wheel speed sensors numbers of each main gear
+    const LEFT_WHEELS: [usize; 2] = [1, 2];
+    const RIGHT_WHEELS: [usize; 2] = [3, 4];
+
     fn new(context: &mut InitContext) -> Self {
         Self {
             park_brake_lever_pos_id: context.get_identifier("PARK_BRAKE_LEVER_POS".to_owned()),
@@ -3604,7 +3761,6 @@ impl A320HydraulicBrakeSteerComputerUnit {
             right_brake_pedal_input_id: context
                 .get_identifier("RIGHT_BRAKE_PEDAL_INPUT".to_owned()),
 
-            ground_speed_id: context.get_identifier("GPS GROUND SPEED".to_owned()),
             rudder_pedal_input_id: context.get_identifier("RUDDER_PEDAL_POSITION_RATIO".to_owned()),
             tiller_handle_input_id: context.get_identifier("TILLER_HANDLE_POSITION".to_owned()),
             tiller_pedal_disconnect_id: context
@@ -3625,6 +3781,16 @@ impl A320HydraulicBrakeSteerComputerUnit {
             ),
             anti_skid_activated: true,
 
+            reference_speed: WheelReferenceSpeed::new(),
+            left_wheel_speeds_valid: true,
+            right_wheel_speeds_valid: true,
+
+            left_wheels_slip: Ratio::new::<ratio>(0.),
+            right_wheels_slip: Ratio::new::<ratio>(0.),
+            left_anti_skid: AntiSkidController::new(),
+            right_anti_skid: AntiSkidController::new(),
+            anti_skid_failure: Failure::new(FailureType::AntiSkid),
+
             tiller_pedal_disconnect: false,
             tiller_handle_position: Ratio::new::<ratio>(0.),
             rudder_pedal_position: Ratio::new::<ratio>(0.),
@@ -3649,13 +3815,84 @@ impl A320HydraulicBrakeSteerComputerUnit {
                 Self::TILLER_INPUT_CURVE_MAP,
             ),
             final_steering_position_request: Angle::new::<degree>(0.),
-
-            ground_speed: Velocity::new::<knot>(0.),
         }
     }
 
+    /// Autobrake needs the anti skid of both main gears to brake symmetrically
     fn allow_autobrake_arming(&self) -> bool {
-        self.anti_skid_activated && self.normal_brakes_available
+        self.is_anti_skid_available()
+            && self.left_wheel_speeds_valid
+            && self.right_wheel_speeds_valid
+            && self.normal_brakes_available
+    }
+
+    /// Anti skid is lost as a whole when the BSCU can't measure the speed of any wheel pair.
+    /// A single failed wheel speed sensor only deactivates the anti skid of its pair.
+    fn is_anti_skid_available(&self) -> bool {
+        self.anti_skid_activated
+            && (self.left_wheel_speeds_valid || self.right_wheel_speeds_valid)
+            && !self.anti_skid_failure.is_active()
+    }
+
+    fn update_wheel_speeds(&mut self, context: &UpdateContext, wheel_speeds: &impl WheelSpeeds) {
+        self.reference_speed.update(context, wheel_speeds);
+
+        self.left_wheel_speeds_valid = Self::wheel_speeds_valid(wheel_speeds, &Self::LEFT_WHEELS);
+        self.right_wheel_speeds_valid = Self::wheel_speeds_valid(wheel_speeds, &Self::RIGHT_WHEELS);
+
+        self.left_wheels_slip = self.gear_slip(wheel_speeds, &Self::LEFT_WHEELS);
+        self.right_wheels_slip = self.gear_slip(wheel_speeds, &Self::RIGHT_WHEELS);
+    }
+
+    /// Anti skid of a wheel pair needs the speed of both its wheels to detect when one skids
+    fn wheel_speeds_valid(wheel_speeds: &impl WheelSpeeds, wheels: &[usize]) -> bool {
+        wheels
+            .iter()
+            .all(|&wheel_number| wheel_speeds.wheel_speed(wheel_number).is_some())
+    }
+
+    /// Slip of the most skidding wheel of a gear
+    fn gear_slip(&self, wheel_speeds: &impl WheelSpeeds, wheels: &[usize]) -> Ratio {
+        wheels
+            .iter()
+            .map(|&wheel_number| {
+                self.reference_speed
+                    .wheel_slip(wheel_speeds.wheel_speed(wheel_number))
+            })
+            .fold(Ratio::new::<ratio>(0.), |max_slip, slip| max_slip.max(slip))
+    }
+
+    fn update_anti_skid(&mut self, context: &UpdateContext) {
+        let is_anti_skid_active = self.is_anti_skid_available() && !self.parking_brake_demand;
+
+        self.left_anti_skid.update(
+            context,
+            is_anti_skid_active && self.left_wheel_speeds_valid,
+            self.reference_speed.speed(),
+            self.left_wheels_slip,
+        );
+        self.right_anti_skid.update(
+            context,
+            is_anti_skid_active && self.right_wheel_speeds_valid,
+            self.reference_speed.speed(),
+            self.right_wheels_slip,
+        );
+    }
+
+    /// Releases the pressure the anti skid asks for on the braking circuit in use
+    fn modulate_brake_demands_with_anti_skid(&mut self) {
+        self.norm_brake_outputs.set_brake_demands(
+            self.left_anti_skid
+                .modulated_demand(self.norm_brake_outputs.left_demand()),
+            self.right_anti_skid
+                .modulated_demand(self.norm_brake_outputs.right_demand()),
+        );
+        self.alternate_brake_outputs.set_brake_demands(
+            self.left_anti_skid
+                .modulated_demand(self.alternate_brake_outputs.left_demand()),
+            self.right_anti_skid
+                .modulated_demand(self.alternate_brake_outputs.right_demand()),
+        );
     }
 
     fn update_normal_braking_availability(&mut self, normal_braking_circuit_pressure: Pressure) {
@@ -3690,7 +3927,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
                 // Else manual action limited to a higher max nominal pressure
                 2538.
             }
-        } else if !self.anti_skid_activated {
+        } else if !self.is_anti_skid_available() {
             1160.
         } else {
             // Else if any manual braking we use standard limit
@@ -3710,13 +3947,17 @@ impl A320HydraulicBrakeSteerComputerUnit {
         lgciu1: &impl LgciuInterface,
         lgciu2: &impl LgciuInterface,
         autobrake_panel: &AutobrakePanel,
+        ground_spoilers_out: [bool; 3],
+        wheel_speeds: &impl WheelSpeeds,
         engine1: &impl Engine,
         engine2: &impl Engine,
     ) {
+        self.update_wheel_speeds(context, wheel_speeds);
         self.update_steering_demands(lgciu1, engine1, engine2);
 
         self.update_normal_braking_availability(current_pressure.pressure());
         self.update_brake_pressure_limitation();
+        self.update_anti_skid(context);
 
         self.autobrake_controller.update(
             context,
@@ -3724,6 +3965,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
             self.allow_autobrake_arming(),
             self.left_brake_pilot_input,
             self.right_brake_pilot_input,
+            ground_spoilers_out,
             lgciu1,
             lgciu2,
         );
@@ -3747,7 +3989,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
             self.alternate_brake_outputs.set_no_demands();
         } else {
             let green_used_for_brakes = self.normal_brakes_available
-                && self.anti_skid_activated
+                && self.is_anti_skid_available()
                 && !self.parking_brake_demand;
 
             if green_used_for_brakes {
@@ -3787,6 +4029,8 @@ impl A320HydraulicBrakeSteerComputerUnit {
                 }
             }
         }
+
+        self.modulate_brake_demands_with_anti_skid();
     }
 
     fn update_steering_demands(
@@ -3806,9 +4050,11 @@ impl A320HydraulicBrakeSteerComputerUnit {
                 .angle_demand_from_input_demand(self.rudder_pedal_position)
         };
 
-        // TODO Here ground speed would be probably computed from wheel sensor logic
+        // Steering authority is limited by the reference speed computed from the wheel speed sensors
+        let reference_speed = self.reference_speed.speed().unwrap_or_default();
+
         let final_steer_rudder_plus_autopilot = self.pedal_steering_limiter.angle_from_speed(
-            self.ground_speed,
+            reference_speed,
             (steer_angle_from_pedals + steer_angle_from_autopilot)
                 .min(Angle::new::<degree>(
                     Self::MAX_RUDDER_INPUT_INCLUDING_AUTOPILOT_DEGREE,
@@ -3819,7 +4065,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
         );
 
         let steer_angle_from_tiller = self.tiller_steering_limiter.angle_from_speed(
-            self.ground_speed,
+            reference_speed,
             self.tiller_input_map
                 .angle_demand_from_input_demand(self.tiller_handle_position),
         );
@@ -3829,6 +4075,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
 
         self.final_steering_position_request = if !is_both_engine_low_oil_pressure
             && self.anti_skid_activated
+            && self.reference_speed.is_valid()
             && lgciu1.nose_gear_compressed(false)
         {
             (final_steer_rudder_plus_autopilot + steer_angle_from_tiller)
@@ -3854,6 +4101,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
 impl SimulationElement for A320HydraulicBrakeSteerComputerUnit {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.autobrake_controller.accept(visitor);
+        self.anti_skid_failure.accept(visitor);
         visitor.visit(self);
     }
 
@@ -3870,7 +4118,6 @@ impl SimulationElement for A320HydraulicBrakeSteerComputerUnit {
             Ratio::new::<ratio>(reader.read(&self.tiller_handle_input_id));
         self.rudder_pedal_position = Ratio::new::<ratio>(reader.read(&self.rudder_pedal_input_id));
         self.tiller_pedal_disconnect = reader.read(&self.tiller_pedal_disconnect_id);
-        self.ground_speed = reader.read(&self.ground_speed_id);
 
         self.autopilot_nosewheel_demand =
             Ratio::new::<ratio>(reader.read(&self.autopilot_nosewheel_demand_id));
@@ -3894,6 +4141,8 @@ struct A320BrakingForce {
     left_braking_force: f64,
     right_braking_force: f64,
 
+    wheel_slips: WheelSlips,
+
     flap_position: f64,
 
     is_chocks_enabled: bool,
@@ -3922,6 +4171,17 @@ impl A320BrakingForce {
             left_braking_force: 0.,
             right_braking_force: 0.,
 
+            wheel_slips: WheelSlips::new(
+                context,
+                &[
+                    (GearWheel::LEFT, 1),
+                    (GearWheel::LEFT, 1),
+                    (GearWheel::RIGHT, 2),
+                    (GearWheel::RIGHT, 2),
+                ],
+                Length::new::<meter>(A320Hydraulic::MAIN_TIRE_RADIUS_METER),
+            ),
+
             flap_position: 0.,
 
             is_chocks_enabled: false,
@@ -3934,6 +4194,7 @@ impl A320BrakingForce {
         context: &UpdateContext,
         norm_brakes: &BrakeCircuit,
         altn_brakes: &BrakeCircuit,
+        tires: &impl BrakingEffectiveness,
         engine1: &impl Engine,
         engine2: &impl Engine,
         pushback_tug: &PushbackTug,
@@ -3956,6 +4217,25 @@ impl A320BrakingForce {
         self.right_braking_force = right_force_norm + right_force_altn;
         self.right_braking_force = self.right_braking_force.max(0.).min(1.);
 
+        // Tires can't transmit more than the runway grip: past it the wheels skid and lose force
+        self.wheel_slips.update(
+            context,
+            Ratio::new::<ratio>(self.left_braking_force),
+            Ratio::new::<ratio>(self.right_braking_force),
+        );
+        self.left_braking_force = self
+            .wheel_slips
+            .braking_force(GearWheel::LEFT)
+            .get::<ratio>();
+        self.right_braking_force = self
+            .wheel_slips
+            .braking_force(GearWheel::RIGHT)
+            .get::<ratio>();
+
+        // A burst or deflated tire can't turn all of the brake torque into braking force
+        self.left_braking_force *= tires.braking_effectiveness(GearWheel::LEFT).get::<ratio>();
+        self.right_braking_force *= tires.braking_effectiveness(GearWheel::RIGHT).get::<ratio>();
+
         self.correct_with_flaps_state(context);
 
         self.update_chocks_braking(context, engine1, engine2, pushback_tug);
@@ -4001,9 +4281,18 @@ impl A320BrakingForce {
             self.right_braking_force = 1.;
         }
     }
+
+    fn wheel_slips(&self) -> &impl WheelSlipRatios {
+        &self.wheel_slips
+    }
 }
 
 impl SimulationElement for A320BrakingForce {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.wheel_slips.accept(visitor);
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         // BRAKE XXXX FORCE FACTOR is the actual braking force we want the plane to generate in the simulator
         writer.write(&self.brake_left_force_factor_id, self.left_braking_force);
@@ -4027,9 +4316,6 @@ pub struct A320AutobrakeController {
     armed_mode_id_set: VariableIdentifier,
     decel_light_id: VariableIdentifier,
     active_id: VariableIdentifier,
-    ground_spoilers_out_sec1_id: VariableIdentifier,
-    ground_spoilers_out_sec2_id: VariableIdentifier,
-    ground_spoilers_out_sec3_id: VariableIdentifier,
     external_disarm_event_id: VariableIdentifier,
 
     deceleration_governor: AutobrakeDecelerationGovernor,
@@ -4071,12 +4357,6 @@ impl A320AutobrakeController {
             armed_mode_id_set: context.get_identifier("AUTOBRAKES_ARMED_MODE_SET".to_owned()),
             decel_light_id: context.get_identifier("AUTOBRAKES_DECEL_LIGHT".to_owned()),
             active_id: context.get_identifier("AUTOBRAKES_ACTIVE".to_owned()),
-            ground_spoilers_out_sec1_id: context
-                .get_identifier("SEC_1_GROUND_SPOILER_OUT".to_owned()),
-            ground_spoilers_out_sec2_id: context
-                .get_identifier("SEC_2_GROUND_SPOILER_OUT".to_owned()),
-            ground_spoilers_out_sec3_id: context
-                .get_identifier("SEC_3_GROUND_SPOILER_OUT".to_owned()),
             external_disarm_event_id: context.get_identifier("AUTOBRAKE_DISARM".to_owned()),
 
             deceleration_governor: AutobrakeDecelerationGovernor::new(),
@@ -4218,9 +4498,16 @@ impl A320AutobrakeController {
         allow_arming: bool,
         pedal_input_left: Ratio,
         pedal_input_right: Ratio,
+        ground_spoilers_out: [bool; 3],
         lgciu1: &impl LgciuInterface,
         lgciu2: &impl LgciuInterface,
     ) {
+        let [sec_1_gnd_splrs_out, sec_2_gnd_splrs_out, sec_3_gnd_splrs_out] = ground_spoilers_out;
+        self.last_ground_spoilers_are_deployed = self.ground_spoilers_are_deployed;
+        self.ground_spoilers_are_deployed = (sec_1_gnd_splrs_out
+            && (sec_3_gnd_splrs_out || sec_2_gnd_splrs_out))
+            || (sec_2_gnd_splrs_out && sec_3_gnd_splrs_out);
+
         let in_flight_lgciu1 =
             !lgciu1.right_gear_compressed(false) && !lgciu1.left_gear_compressed(false);
         let in_flight_lgciu2 =
@@ -4243,6 +4530,7 @@ impl A320AutobrakeController {
         allow_arming: bool,
         pedal_input_left: Ratio,
         pedal_input_right: Ratio,
+        ground_spoilers_out: [bool; 3],
         lgciu1: &impl LgciuInterface,
         lgciu2: &impl LgciuInterface,
     ) {
@@ -4251,6 +4539,7 @@ impl A320AutobrakeController {
             allow_arming,
             pedal_input_left,
             pedal_input_right,
+            ground_spoilers_out,
             lgciu1,
             lgciu2,
         );
@@ -4272,13 +4561,6 @@ impl SimulationElement for A320AutobrakeController {
     }
 
     fn read(&mut self, reader: &mut SimulatorReader) {
-        self.last_ground_spoilers_are_deployed = self.ground_spoilers_are_deployed;
-        let sec_1_gnd_splrs_out = reader.read(&self.ground_spoilers_out_sec1_id);
-        let sec_2_gnd_splrs_out = reader.read(&self.ground_spoilers_out_sec2_id);
-        let sec_3_gnd_splrs_out = reader.read(&self.ground_spoilers_out_sec3_id);
-        self.ground_spoilers_are_deployed = (sec_1_gnd_splrs_out
-            && (sec_3_gnd_splrs_out || sec_2_gnd_splrs_out))
-            || (sec_2_gnd_splrs_out && sec_3_gnd_splrs_out);
         self.external_disarm_event = reader.read(&self.external_disarm_event_id);
 
         // Reading current mode in sim to initialize correct mode if sim changes it (from .FLT files for example)
@@ -4423,13 +4705,17 @@ impl SimulationElement for A320HydraulicOverheadPanel {
 struct AileronController {
     mode: LinearActuatorMode,
     requested_position: Ratio,
+
+    failures: FlightControlActuatorFailures,
 }
 impl AileronController {
-    fn new() -> Self {
+    fn new(id: FlightControlActuatorId) -> Self {
         Self {
             mode: LinearActuatorMode::ClosedCircuitDamping,
 
             requested_position: Ratio::new::<ratio>(0.),
+
+            failures: FlightControlActuatorFailures::new(id),
         }
     }
 
@@ -4437,6 +4723,32 @@ impl AileronController {
         self.mode = mode;
     }
 
+    fn update_failures(&mut self, context: &UpdateContext) {
+        self.failures
+            .update(context, self.mode == LinearActuatorMode::PositionControl);
+    }
+
+    fn has_failure_detected(&self) -> bool {
+        self.failures.is_failure_detected()
+    }
+
+    /// Passivates the failed actuators of a surface, handing over active control to a healthy one
+    fn passivate_failed<const N: usize>(controllers: &mut [AileronController; N]) {
+        let mut modes = [LinearActuatorMode::ClosedCircuitDamping; N];
+        let mut failures_detected = [false; N];
+        for (index, controller) in controllers.iter().enumerate() {
+            modes[index] = controller.mode;
+            failures_detected[index] = controller.has_failure_detected();
+        }
+
+        for (controller, mode) in controllers
+            .iter_mut()
+            .zip(passivate_failed_actuators(modes, failures_detected))
+        {
+            controller.set_mode(mode);
+        }
+    }
+
     /// Receives a [0;1] position request, 0 is down 1 is up
     fn set_requested_position(&mut self, requested_position: Ratio) {
         self.requested_position = requested_position
@@ -4450,7 +4762,7 @@ impl HydraulicAssemblyController for AileronController {
     }
 
     fn requested_position(&self) -> Ratio {
-        self.requested_position
+        self.failures.requested_position(self.requested_position)
     }
 
     fn should_lock(&self) -> bool {
@@ -4460,9 +4772,24 @@ impl HydraulicAssemblyController for AileronController {
     fn requested_lock_position(&self) -> Ratio {
         Ratio::default()
     }
+
+    fn is_jammed(&self) -> bool {
+        self.failures.is_jammed()
+    }
+
+    fn is_disconnected(&self) -> bool {
+        self.failures.is_disconnected()
+    }
 }
 impl HydraulicLocking for AileronController {}
 impl ElectroHydrostaticPowered for AileronController {}
+impl SimulationElement for AileronController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failures.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
 
 struct AileronSystemHydraulicController {
     left_aileron_blue_actuator_solenoid_id: VariableIdentifier,
@@ -4500,9 +4827,30 @@ impl AileronSystemHydraulicController {
                 .get_identifier("RIGHT_AIL_GREEN_COMMANDED_POSITION".to_owned()),
 
             // Controllers are in outward->inward order, so for aileron [Blue circuit, Green circuit]
-            left_aileron_controllers: [AileronController::new(), AileronController::new()],
-            right_aileron_controllers: [AileronController::new(), AileronController::new()],
+            left_aileron_controllers: [
+                AileronController::new(FlightControlActuatorId::LeftAileron(1)),
+                AileronController::new(FlightControlActuatorId::LeftAileron(2)),
+            ],
+            right_aileron_controllers: [
+                AileronController::new(FlightControlActuatorId::RightAileron(1)),
+                AileronController::new(FlightControlActuatorId::RightAileron(2)),
+            ],
+        }
+    }
+
+    /// Servo loop monitoring passivates a failed actuator, the other actuator of the aileron
+    /// then takes over active control.
+    fn update(&mut self, context: &UpdateContext) {
+        for controller in self
+            .left_aileron_controllers
+            .iter_mut()
+            .chain(self.right_aileron_controllers.iter_mut())
+        {
+            controller.update_failures(context);
         }
+
+        AileronController::passivate_failed(&mut self.left_aileron_controllers);
+        AileronController::passivate_failed(&mut self.right_aileron_controllers);
     }
 
     fn left_controllers(
@@ -4597,6 +4945,13 @@ impl AileronSystemHydraulicController {
     }
 }
 impl SimulationElement for AileronSystemHydraulicController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.left_aileron_controllers, visitor);
+        accept_iterable!(self.right_aileron_controllers, visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         // Note that we reverse left, as positions are just passed through msfs for now
         self.update_aileron_controllers_positions(
@@ -4667,9 +5022,30 @@ impl ElevatorSystemHydraulicController {
                 .get_identifier("RIGHT_ELEV_YELLOW_COMMANDED_POSITION".to_owned()),
 
             // Controllers are in outboard->inboard order
-            left_controllers: [AileronController::new(), AileronController::new()],
-            right_controllers: [AileronController::new(), AileronController::new()],
+            left_controllers: [
+                AileronController::new(FlightControlActuatorId::LeftElevator(1)),
+                AileronController::new(FlightControlActuatorId::LeftElevator(2)),
+            ],
+            right_controllers: [
+                AileronController::new(FlightControlActuatorId::RightElevator(1)),
+                AileronController::new(FlightControlActuatorId::RightElevator(2)),
+            ],
+        }
+    }
+
+    /// Servo loop monitoring passivates a failed actuator, the other actuator of the elevator
+    /// then takes over active control.
+    fn update(&mut self, context: &UpdateContext) {
+        for controller in self
+            .left_controllers
+            .iter_mut()
+            .chain(self.right_controllers.iter_mut())
+        {
+            controller.update_failures(context);
         }
+
+        AileronController::passivate_failed(&mut self.left_controllers);
+        AileronController::passivate_failed(&mut self.right_controllers);
     }
 
     fn left_controllers(
@@ -4763,6 +5139,13 @@ impl ElevatorSystemHydraulicController {
     }
 }
 impl SimulationElement for ElevatorSystemHydraulicController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.left_controllers, visitor);
+        accept_iterable!(self.right_controllers, visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.update_elevator_controllers_positions(
             [
@@ -4986,9 +5369,9 @@ impl RudderSystemHydraulicController {
 
             // Controllers are in [ Green circuit, Blue circuit, Yellow circuit] order
             rudder_controllers: [
-                AileronController::new(),
-                AileronController::new(),
-                AileronController::new(),
+                AileronController::new(FlightControlActuatorId::Rudder(1)),
+                AileronController::new(FlightControlActuatorId::Rudder(2)),
+                AileronController::new(FlightControlActuatorId::Rudder(3)),
             ],
         }
     }
@@ -5123,6 +5506,11 @@ impl RudderSystemHydraulicController {
         );
 
         self.update_rudder_control_state();
+
+        for controller in &mut self.rudder_controllers {
+            controller.update_failures(context);
+        }
+        AileronController::passivate_failed(&mut self.rudder_controllers);
     }
 
     fn rudder_controllers(
@@ -5145,6 +5533,7 @@ impl SimulationElement for RudderSystemHydraulicController {
         self.trim_control.accept(visitor);
         self.travel_limiter_control.accept(visitor);
         self.rudder_mechanical_assembly.accept(visitor);
+        accept_iterable!(self.rudder_controllers, visitor);
 
         visitor.visit(self);
     }
@@ -5449,15 +5838,20 @@ struct SpoilerGroup {
     hydraulic_controllers: [SpoilerController; 5],
 }
 impl SpoilerGroup {
-    fn new(context: &mut InitContext, spoiler_side: &str, spoilers: [SpoilerElement; 5]) -> Self {
+    fn new(
+        context: &mut InitContext,
+        spoiler_side: &str,
+        actuator_id: fn(usize) -> FlightControlActuatorId,
+        spoilers: [SpoilerElement; 5],
+    ) -> Self {
         Self {
             spoilers,
             hydraulic_controllers: [
-                SpoilerController::new(context, spoiler_side, 1),
-                SpoilerController::new(context, spoiler_side, 2),
-                SpoilerController::new(context, spoiler_side, 3),
-                SpoilerController::new(context, spoiler_side, 4),
-                SpoilerController::new(context, spoiler_side, 5),
+                SpoilerController::new(context, spoiler_side, 1, actuator_id(1)),
+                SpoilerController::new(context, spoiler_side, 2, actuator_id(2)),
+                SpoilerController::new(context, spoiler_side, 3, actuator_id(3)),
+                SpoilerController::new(context, spoiler_side, 4, actuator_id(4)),
+                SpoilerController::new(context, spoiler_side, 5, actuator_id(5)),
             ],
         }
     }
@@ -5465,10 +5859,18 @@ impl SpoilerGroup {
     fn update(
         &mut self,
         context: &UpdateContext,
+        spoiler_elevator_computers: &SpoilerElevatorComputerSet,
         green_section: &impl SectionPressure,
         blue_section: &impl SectionPressure,
         yellow_section: &impl SectionPressure,
     ) {
+        for (spoiler_index, controller) in self.hydraulic_controllers.iter_mut().enumerate() {
+            controller.update(
+                context,
+                spoiler_elevator_computers.ground_spoiler_order(spoiler_index + 1),
+            );
+        }
+
         self.spoilers[0].update(
             context,
             &self.hydraulic_controllers[0],
@@ -5514,33 +5916,62 @@ impl SimulationElement for SpoilerGroup {
     }
 }
 
+/// Combines the roll and speed brake order of a spoiler from the flight control laws SEC with
+/// the ground spoiler order of its SEC. Spoiler is retracted if its SEC is not engaged.
+/// A spoiler which actuator failure is detected is passivated by its SEC.
 struct SpoilerController {
     position_demand_id: VariableIdentifier,
+    flight_law_demand: Angle,
     requested_position: Ratio,
+
+    failures: FlightControlActuatorFailures,
 }
 impl SpoilerController {
-    fn new(context: &mut InitContext, spoiler_side: &str, spoiler_id_number: usize) -> Self {
+    fn new(
+        context: &mut InitContext,
+        spoiler_side: &str,
+        spoiler_id_number: usize,
+        actuator_id: FlightControlActuatorId,
+    ) -> Self {
         Self {
             position_demand_id: context.get_identifier(format!(
                 "{}_SPOILER_{}_COMMANDED_POSITION",
                 spoiler_side, spoiler_id_number
             )),
 
+            flight_law_demand: Angle::default(),
             requested_position: Ratio::new::<ratio>(0.),
+
+            failures: FlightControlActuatorFailures::new(actuator_id),
         }
     }
 
+    fn update(&mut self, context: &UpdateContext, sec_order: Option<Angle>) {
+        self.requested_position = match sec_order {
+            Some(order) => Self::spoiler_actuator_position_from_surface_angle(
+                order.max(self.flight_law_demand),
+            ),
+            None => Ratio::new::<ratio>(0.),
+        };
+
+        self.failures.update(context, true);
+    }
+
     fn spoiler_actuator_position_from_surface_angle(surface_angle: Angle) -> Ratio {
         Ratio::new::<ratio>((surface_angle.get::<degree>() / 50.).min(1.).max(0.))
     }
 }
 impl HydraulicAssemblyController for SpoilerController {
     fn requested_mode(&self) -> LinearActuatorMode {
-        LinearActuatorMode::PositionControl
+        if self.failures.is_failure_detected() {
+            LinearActuatorMode::ActiveDamping
+        } else {
+            LinearActuatorMode::PositionControl
+        }
     }
 
     fn requested_position(&self) -> Ratio {
-        self.requested_position
+        self.failures.requested_position(self.requested_position)
     }
 
     fn should_lock(&self) -> bool {
@@ -5550,13 +5981,24 @@ impl HydraulicAssemblyController for SpoilerController {
     fn requested_lock_position(&self) -> Ratio {
         Ratio::default()
     }
+
+    fn is_jammed(&self) -> bool {
+        self.failures.is_jammed()
+    }
+
+    fn is_disconnected(&self) -> bool {
+        self.failures.is_disconnected()
+    }
 }
 impl SimulationElement for SpoilerController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failures.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
-        self.requested_position =
-            Self::spoiler_actuator_position_from_surface_angle(Angle::new::<degree>(
-                reader.read(&self.position_demand_id),
-            ));
+        self.flight_law_demand = Angle::new::<degree>(reader.read(&self.position_demand_id));
     }
 }
 impl HydraulicLocking for SpoilerController {}
@@ -5711,6 +6153,7 @@ impl A320ReverserController {
         engine: &impl Engine,
         lgciu: &impl LgciuWeightOnWheels,
         reverser_feedback: &impl ReverserFeedback,
+        tertiary_lock_unlock_order_from_sec: bool,
     ) {
         let is_confirmed_stowed_available_for_deploy =
             reverser_feedback.position_sensor().get::<ratio>() <= 0.1
@@ -5728,9 +6171,8 @@ impl A320ReverserController {
         let allow_directional_valve_to_deploy =
             allow_isolation_valve_to_open && reverser_feedback.pressure_switch_pressurised();
 
-        // TODO: this should come from a SEC output, this is a placeholder of the SEC output
         self.tertiary_lock_from_sec_should_unlock
-            .update(context, self.throttle_lever_angle.get::<degree>() <= -3.);
+            .update(context, tertiary_lock_unlock_order_from_sec);
 
         self.state = match self.state {
             ReverserControlState::StowedOff => {
@@ -6004,6 +6446,7 @@ mod tests {
             length::foot,
             mass_density::kilogram_per_cubic_meter,
             ratio::{percent, ratio},
+            velocity::meter_per_second,
             volume::liter,
         };
 
@@ -6471,12 +6914,17 @@ mod tests {
         }
         impl A320HydraulicsTestBed {
             fn new_with_start_state(start_state: StartState) -> Self {
-                Self {
+                let test_bed = Self {
                     test_bed: SimulationTestBed::new_with_start_state(
                         start_state,
                         A320HydraulicsTestAircraft::new,
                     ),
-                }
+                };
+
+                test_bed
+                    .set_sec_pushbutton_pressed(1, true)
+                    .set_sec_pushbutton_pressed(2, true)
+                    .set_sec_pushbutton_pressed(3, true)
             }
 
             fn run_one_tick(mut self) -> Self {
@@ -6637,6 +7085,14 @@ mod tests {
                 self.read_by_name("HYD_BRAKE_NORM_RIGHT_PRESS")
             }
 
+            fn get_left_braking_force(&mut self) -> f64 {
+                self.read_by_name("BRAKE LEFT FORCE FACTOR")
+            }
+
+            fn get_right_braking_force(&mut self) -> f64 {
+                self.read_by_name("BRAKE RIGHT FORCE FACTOR")
+            }
+
             fn get_brake_yellow_accumulator_pressure(&mut self) -> Pressure {
                 self.read_by_name("HYD_BRAKE_ALTN_ACC_PRESS")
             }
@@ -6683,6 +7139,12 @@ mod tests {
                     / 5.
             }
 
+            fn get_left_spoiler_position(&mut self, spoiler_number: usize) -> Ratio {
+                Ratio::new::<ratio>(
+                    self.read_by_name(&format!("HYD_SPOILER_{}_LEFT_DEFLECTION", spoiler_number)),
+                )
+            }
+
             fn get_right_elevator_position(&mut self) -> Ratio {
                 Ratio::new::<ratio>(self.read_by_name("HYD_ELEV_RIGHT_DEFLECTION"))
             }
@@ -6819,6 +7281,13 @@ mod tests {
                 self
             }
 
+            fn on_the_ground_on_left_main_gear_only(mut self) -> Self {
+                self.set_indicated_altitude(Length::new::<foot>(0.));
+                self.set_on_ground(false);
+                self.write_by_name(LandingGear::GEAR_LEFT_COMPRESSION, Ratio::new::<ratio>(0.8));
+                self
+            }
+
             fn in_flight(mut self) -> Self {
                 self.set_on_ground(false);
                 self.set_indicated_altitude(Length::new::<foot>(2500.));
@@ -6996,6 +7465,20 @@ mod tests {
                 self
             }
 
+            fn on_snow_covered_runway(mut self) -> Self {
+                self.write_by_name("SURFACE TYPE", 8.);
+                self
+            }
+
+            fn set_main_wheels_speed(mut self, speed: Velocity) -> Self {
+                let wheel_rpm = AngularVelocity::new::<radian_per_second>(
+                    speed.get::<meter_per_second>() / A320Hydraulic::MAIN_TIRE_RADIUS_METER,
+                );
+                self.write_by_name("WHEEL RPM:1", wheel_rpm);
+                self.write_by_name("WHEEL RPM:2", wheel_rpm);
+                self
+            }
+
             fn set_yellow_e_pump(mut self, is_auto: bool) -> Self {
                 self.write_by_name("OVHD_HYD_EPUMPY_PB_IS_AUTO", is_auto);
                 self
@@ -7202,16 +7685,37 @@ mod tests {
             }
 
             fn set_deploy_ground_spoilers(mut self) -> Self {
-                self.write_by_name("SEC_1_GROUND_SPOILER_OUT", true);
-                self.write_by_name("SEC_2_GROUND_SPOILER_OUT", true);
-                self.write_by_name("SEC_3_GROUND_SPOILER_OUT", true);
+                // Armed ground spoilers with wheels spun up above 72 knots
+                self.write_by_name("SPOILERS_ARMED", true);
+                self.write_by_name("WHEEL RPM:1", 1000.);
+                self.write_by_name("WHEEL RPM:2", 1000.);
+                self
+            }
+
+            fn set_speed_brake_lever(mut self, position: Ratio) -> Self {
+                self.write_by_name("SPOILERS_HANDLE_POSITION", position.get::<ratio>());
                 self
             }
 
             fn set_retract_ground_spoilers(mut self) -> Self {
-                self.write_by_name("SEC_1_GROUND_SPOILER_OUT", false);
-                self.write_by_name("SEC_2_GROUND_SPOILER_OUT", false);
-                self.write_by_name("SEC_3_GROUND_SPOILER_OUT", false);
+                self.write_by_name("SPOILERS_ARMED", false);
+                self
+            }
+
+            fn set_sec_pushbutton_pressed(mut self, sec_number: usize, is_pressed: bool) -> Self {
+                self.write_by_name(
+                    &format!("SEC_{}_PUSHBUTTON_PRESSED", sec_number),
+                    is_pressed,
+                );
+                self
+            }
+
+            fn is_sec_ground_spoiler_out(&mut self, sec_number: usize) -> bool {
+                self.read_by_name(&format!("SEC_{}_GROUND_SPOILER_OUT", sec_number))
+            }
+
+            fn set_sec_failed(mut self, sec_number: usize, is_failed: bool) -> Self {
+                self.write_by_name(&format!("SEC_{}_FAILED", sec_number), is_failed);
                 self
             }
 
@@ -8878,98 +9382,236 @@ mod tests {
         }
 
         #[test]
-        fn no_norm_brake_inversion() {
+        fn wheel_speed_sensor_failure_keeps_normal_braking() {
             let mut test_bed = test_bed_on_ground_with()
                 .engines_off()
                 .on_the_ground()
                 .set_cold_dark_inputs()
-                .run_one_tick();
-
-            test_bed = test_bed
                 .start_eng1(Ratio::new::<percent>(100.))
                 .start_eng2(Ratio::new::<percent>(100.))
                 .set_park_brake(false)
                 .run_waiting_for(Duration::from_secs(5));
 
-            assert!(test_bed.is_green_pressure_switch_pressurised());
-            assert!(test_bed.is_yellow_pressure_switch_pressurised());
-
-            // Braking left
             test_bed = test_bed
                 .set_left_brake(Ratio::new::<percent>(100.))
-                .set_right_brake(Ratio::new::<percent>(0.))
-                .run_waiting_for(Duration::from_secs(1));
-
-            assert!(test_bed.get_brake_left_green_pressure() > Pressure::new::<psi>(2000.));
-            assert!(test_bed.get_brake_right_green_pressure() < Pressure::new::<psi>(50.));
-            assert!(test_bed.get_brake_left_yellow_pressure() < Pressure::new::<psi>(50.));
-            assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(50.));
-
-            // Braking right
-            test_bed = test_bed
-                .set_left_brake(Ratio::new::<percent>(0.))
                 .set_right_brake(Ratio::new::<percent>(100.))
                 .run_waiting_for(Duration::from_secs(1));
 
-            assert!(test_bed.get_brake_left_green_pressure() < Pressure::new::<psi>(50.));
+            test_bed.fail(FailureType::WheelSpeedSensor(3));
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(2));
+
+            assert!(test_bed.get_brake_left_green_pressure() > Pressure::new::<psi>(2000.));
             assert!(test_bed.get_brake_right_green_pressure() > Pressure::new::<psi>(2000.));
             assert!(test_bed.get_brake_left_yellow_pressure() < Pressure::new::<psi>(50.));
             assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(50.));
         }
 
         #[test]
-        fn no_alternate_brake_inversion() {
+        fn wheel_speed_sensor_failure_on_both_gears_causes_alternate_braking() {
             let mut test_bed = test_bed_on_ground_with()
                 .engines_off()
                 .on_the_ground()
                 .set_cold_dark_inputs()
-                .run_one_tick();
-
-            test_bed = test_bed
                 .start_eng1(Ratio::new::<percent>(100.))
                 .start_eng2(Ratio::new::<percent>(100.))
                 .set_park_brake(false)
                 .run_waiting_for(Duration::from_secs(5));
 
-            assert!(test_bed.is_green_pressure_switch_pressurised());
-            assert!(test_bed.is_yellow_pressure_switch_pressurised());
-
-            // Disabling Askid causes alternate braking to work and release green brakes
             test_bed = test_bed
-                .set_left_brake(Ratio::new::<percent>(0.))
+                .set_left_brake(Ratio::new::<percent>(100.))
                 .set_right_brake(Ratio::new::<percent>(100.))
-                .set_anti_skid(false)
-                .run_waiting_for(Duration::from_secs(2));
+                .run_waiting_for(Duration::from_secs(1));
 
-            assert!(test_bed.get_brake_left_green_pressure() < Pressure::new::<psi>(50.));
-            assert!(test_bed.get_brake_right_green_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_left_green_pressure() > Pressure::new::<psi>(2000.));
             assert!(test_bed.get_brake_left_yellow_pressure() < Pressure::new::<psi>(50.));
-            assert!(test_bed.get_brake_right_yellow_pressure() > Pressure::new::<psi>(950.));
 
-            test_bed = test_bed
-                .set_left_brake(Ratio::new::<percent>(100.))
-                .set_right_brake(Ratio::new::<percent>(0.))
-                .run_waiting_for(Duration::from_secs(2));
+            test_bed.fail(FailureType::WheelSpeedSensor(2));
+            test_bed.fail(FailureType::WheelSpeedSensor(3));
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(2));
 
             assert!(test_bed.get_brake_left_green_pressure() < Pressure::new::<psi>(50.));
             assert!(test_bed.get_brake_right_green_pressure() < Pressure::new::<psi>(50.));
             assert!(test_bed.get_brake_left_yellow_pressure() > Pressure::new::<psi>(950.));
-            assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_right_yellow_pressure() > Pressure::new::<psi>(950.));
         }
 
         #[test]
-        fn auto_brake_at_gear_retraction() {
+        fn anti_skid_failure_causes_alternate_braking() {
             let mut test_bed = test_bed_on_ground_with()
                 .engines_off()
                 .on_the_ground()
                 .set_cold_dark_inputs()
-                .run_one_tick();
-
-            test_bed = test_bed
                 .start_eng1(Ratio::new::<percent>(100.))
                 .start_eng2(Ratio::new::<percent>(100.))
                 .set_park_brake(false)
-                .run_waiting_for(Duration::from_secs(15));
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.get_brake_left_green_pressure() > Pressure::new::<psi>(2000.));
+            assert!(test_bed.get_brake_left_yellow_pressure() < Pressure::new::<psi>(50.));
+
+            test_bed.fail(FailureType::AntiSkid);
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(2));
+
+            assert!(test_bed.get_brake_left_green_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_right_green_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_left_yellow_pressure() > Pressure::new::<psi>(950.));
+            assert!(test_bed.get_brake_left_yellow_pressure() < Pressure::new::<psi>(1300.));
+        }
+
+        #[test]
+        fn anti_skid_keeps_more_braking_force_than_locked_wheels_on_slippery_runway() {
+            let mean_braking_force = |anti_skid_failed: bool| {
+                let mut test_bed = test_bed_on_ground_with()
+                    .engines_off()
+                    .on_the_ground()
+                    .set_cold_dark_inputs()
+                    .start_eng1(Ratio::new::<percent>(100.))
+                    .start_eng2(Ratio::new::<percent>(100.))
+                    .set_park_brake(false)
+                    .on_snow_covered_runway()
+                    .set_main_wheels_speed(Velocity::new::<knot>(100.))
+                    .run_waiting_for(Duration::from_secs(5));
+
+                if anti_skid_failed {
+                    test_bed.fail(FailureType::AntiSkid);
+                }
+
+                test_bed = test_bed
+                    .set_left_brake(Ratio::new::<percent>(100.))
+                    .set_right_brake(Ratio::new::<percent>(100.))
+                    .run_waiting_for(Duration::from_secs(3));
+
+                let mut braking_force_sum = 0.;
+                for _ in 0..20 {
+                    test_bed = test_bed.run_waiting_for(Duration::from_millis(200));
+                    braking_force_sum += test_bed.get_left_braking_force();
+                }
+
+                braking_force_sum / 20.
+            };
+
+            assert!(mean_braking_force(false) > mean_braking_force(true));
+        }
+
+        #[test]
+        fn burst_tire_reduces_braking_force_of_its_gear() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed.fail(FailureType::TireBurst(1));
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.get_left_braking_force() < test_bed.get_right_braking_force());
+        }
+
+        #[test]
+        fn no_norm_brake_inversion() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.is_green_pressure_switch_pressurised());
+            assert!(test_bed.is_yellow_pressure_switch_pressurised());
+
+            // Braking left
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(0.))
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.get_brake_left_green_pressure() > Pressure::new::<psi>(2000.));
+            assert!(test_bed.get_brake_right_green_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_left_yellow_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(50.));
+
+            // Braking right
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(0.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.get_brake_left_green_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_right_green_pressure() > Pressure::new::<psi>(2000.));
+            assert!(test_bed.get_brake_left_yellow_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(50.));
+        }
+
+        #[test]
+        fn no_alternate_brake_inversion() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.is_green_pressure_switch_pressurised());
+            assert!(test_bed.is_yellow_pressure_switch_pressurised());
+
+            // Disabling Askid causes alternate braking to work and release green brakes
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(0.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .set_anti_skid(false)
+                .run_waiting_for(Duration::from_secs(2));
+
+            assert!(test_bed.get_brake_left_green_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_right_green_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_left_yellow_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_right_yellow_pressure() > Pressure::new::<psi>(950.));
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(0.))
+                .run_waiting_for(Duration::from_secs(2));
+
+            assert!(test_bed.get_brake_left_green_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_right_green_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_left_yellow_pressure() > Pressure::new::<psi>(950.));
+            assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(50.));
+        }
+
+        #[test]
+        fn auto_brake_at_gear_retraction() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(15));
 
             // No brake inputs
             test_bed = test_bed
@@ -9321,6 +9963,26 @@ mod tests {
             assert!(test_bed.autobrake_mode() == AutobrakeMode::NONE);
         }
 
+        #[test]
+        fn autobrakes_disarms_if_wheel_speed_sensor_failed() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_gear_lever_up()
+                .run_waiting_for(Duration::from_secs(12));
+
+            test_bed = test_bed
+                .set_autobrake_med()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::MED);
+
+            test_bed.fail(FailureType::WheelSpeedSensor(1));
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::NONE);
+        }
+
         #[test]
         fn autobrakes_max_wont_arm_in_flight() {
             let mut test_bed = test_bed_on_ground_with()
@@ -10516,6 +11178,61 @@ mod tests {
             assert!(test_bed.nose_steering_position().get::<degree>() >= -0.1);
         }
 
+        #[test]
+        fn nose_steering_tiller_authority_reduces_with_wheel_speed() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_yellow_e_pump(false)
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .set_main_wheels_speed(Velocity::new::<knot>(80.))
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            test_bed = test_bed
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.nose_steering_position().get::<degree>() <= 0.1);
+            assert!(test_bed.nose_steering_position().get::<degree>() >= -0.1);
+
+            test_bed = test_bed
+                .set_main_wheels_speed(Velocity::new::<knot>(10.))
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.nose_steering_position().get::<degree>() >= 70.);
+        }
+
+        #[test]
+        fn nose_steering_centers_itself_when_all_wheel_speed_sensors_failed() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_yellow_e_pump(false)
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.nose_steering_position().get::<degree>() >= 70.);
+
+            test_bed.fail(FailureType::WheelSpeedSensor(1));
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.nose_steering_position().get::<degree>() >= 70.);
+
+            for number in 2..=4 {
+                test_bed.fail(FailureType::WheelSpeedSensor(number));
+            }
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.nose_steering_position().get::<degree>() <= 0.1);
+            assert!(test_bed.nose_steering_position().get::<degree>() >= -0.1);
+        }
+
         #[test]
         fn nose_steering_responds_to_autopilot_demand() {
             let mut test_bed = test_bed_on_ground_with()
@@ -10854,6 +11571,80 @@ mod tests {
             assert!(test_bed.get_right_elevator_position().get::<ratio>() < 0.1);
         }
 
+        #[test]
+        fn aileron_runaway_actuator_is_passivated_and_other_actuator_takes_over() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .set_blue_e_pump_ovrd_pressed(true)
+                .run_one_tick();
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(5.));
+            assert!((test_bed.get_left_aileron_position().get::<ratio>() - 0.5).abs() < 0.05);
+
+            test_bed.fail(FailureType::FlightControlActuatorRunaway(
+                FlightControlActuatorId::LeftAileron(1),
+            ));
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!((test_bed.get_left_aileron_position().get::<ratio>() - 0.5).abs() < 0.05);
+
+            test_bed = test_bed
+                .set_ailerons_left_turn()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.get_left_aileron_position().get::<ratio>() > 0.9);
+        }
+
+        #[test]
+        fn aileron_jammed_actuator_holds_aileron_in_position() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_blue_e_pump_ovrd_pressed(true)
+                .run_one_tick();
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(5.));
+            let jammed_position = test_bed.get_left_aileron_position().get::<ratio>();
+
+            test_bed.fail(FailureType::FlightControlActuatorJam(
+                FlightControlActuatorId::LeftAileron(2),
+            ));
+            test_bed = test_bed
+                .set_ailerons_left_turn()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(
+                (test_bed.get_left_aileron_position().get::<ratio>() - jammed_position).abs()
+                    < 0.01
+            );
+            assert!(test_bed.get_right_aileron_position().get::<ratio>() < 0.1);
+        }
+
+        #[test]
+        fn elevator_disconnected_actuator_is_passivated_and_other_actuator_takes_over() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .set_blue_e_pump_ovrd_pressed(true)
+                .run_one_tick();
+
+            test_bed.fail(FailureType::FlightControlActuatorDisconnect(
+                FlightControlActuatorId::LeftElevator(1),
+            ));
+            test_bed = test_bed
+                .set_elevator_full_up()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.get_left_elevator_position().get::<ratio>() > 0.9);
+            assert!(test_bed.get_right_elevator_position().get::<ratio>() > 0.9);
+        }
+
         #[test]
         fn elevators_centers_with_pressure_but_no_computer_command() {
             let mut test_bed = test_bed_on_ground_with()
@@ -11234,6 +12025,158 @@ mod tests {
             assert!(test_bed.get_mean_right_spoilers_position().get::<ratio>() < 0.01);
         }
 
+        #[test]
+        fn ground_spoilers_extend_when_armed_with_wheels_spun_up() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .start_eng1(Ratio::new::<percent>(60.))
+                .start_eng2(Ratio::new::<percent>(60.))
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(!test_bed.is_sec_ground_spoiler_out(1));
+            assert!(test_bed.get_mean_left_spoilers_position().get::<ratio>() < 0.01);
+
+            test_bed = test_bed
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(test_bed.is_sec_ground_spoiler_out(1));
+            assert!(test_bed.is_sec_ground_spoiler_out(2));
+            assert!(test_bed.is_sec_ground_spoiler_out(3));
+            assert!(test_bed.get_mean_left_spoilers_position().get::<ratio>() > 0.9);
+            assert!(test_bed.get_mean_right_spoilers_position().get::<ratio>() > 0.9);
+
+            test_bed = test_bed
+                .set_retract_ground_spoilers()
+                .run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(!test_bed.is_sec_ground_spoiler_out(1));
+            assert!(test_bed.get_mean_left_spoilers_position().get::<ratio>() < 0.01);
+            assert!(test_bed.get_mean_right_spoilers_position().get::<ratio>() < 0.01);
+        }
+
+        #[test]
+        fn ground_spoilers_do_not_extend_when_thrust_above_idle() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .start_eng1(Ratio::new::<percent>(60.))
+                .start_eng2(Ratio::new::<percent>(60.))
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed.write_by_name("AUTOTHRUST_TLA:2", 20.);
+
+            test_bed = test_bed
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(!test_bed.is_sec_ground_spoiler_out(1));
+            assert!(test_bed.get_mean_left_spoilers_position().get::<ratio>() < 0.01);
+        }
+
+        #[test]
+        fn ground_spoilers_partially_extend_with_reverse_on_one_main_gear() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .start_eng1(Ratio::new::<percent>(60.))
+                .start_eng2(Ratio::new::<percent>(60.))
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed = test_bed
+                .on_the_ground_on_left_main_gear_only()
+                .eng1_throttle_reverse_idle()
+                .run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(test_bed.is_sec_ground_spoiler_out(1));
+
+            let mean_position = test_bed.get_mean_left_spoilers_position().get::<ratio>();
+            assert!(mean_position > 0.1 && mean_position < 0.3);
+        }
+
+        #[test]
+        fn spoilers_retract_when_their_sec_fails() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .start_eng1(Ratio::new::<percent>(60.))
+                .start_eng2(Ratio::new::<percent>(60.))
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed = test_bed
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(test_bed.get_left_spoiler_position(1).get::<ratio>() > 0.9);
+            assert!(test_bed.get_left_spoiler_position(3).get::<ratio>() > 0.9);
+
+            test_bed = test_bed
+                .set_sec_failed(3, true)
+                .run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(!test_bed.is_sec_ground_spoiler_out(3));
+            assert!(test_bed.get_left_spoiler_position(1).get::<ratio>() < 0.01);
+            assert!(test_bed.get_left_spoiler_position(2).get::<ratio>() < 0.01);
+            assert!(test_bed.get_left_spoiler_position(3).get::<ratio>() > 0.9);
+            assert!(test_bed.get_left_spoiler_position(5).get::<ratio>() > 0.9);
+        }
+
+        #[test]
+        fn spoilers_retract_when_their_sec_is_switched_off() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .start_eng1(Ratio::new::<percent>(60.))
+                .start_eng2(Ratio::new::<percent>(60.))
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed = test_bed
+                .set_sec_pushbutton_pressed(2, false)
+                .run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(!test_bed.is_sec_ground_spoiler_out(2));
+            assert!(test_bed.get_left_spoiler_position(5).get::<ratio>() < 0.01);
+            assert!(test_bed.get_left_spoiler_position(4).get::<ratio>() > 0.9);
+        }
+
+        #[test]
+        fn ground_spoilers_extend_at_touchdown_with_speed_brake_lever_out_when_not_armed() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_speed_brake_lever(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(!test_bed.is_sec_ground_spoiler_out(1));
+
+            test_bed = test_bed
+                .on_the_ground()
+                .run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(test_bed.is_sec_ground_spoiler_out(1));
+            assert!(test_bed.is_sec_ground_spoiler_out(2));
+            assert!(test_bed.is_sec_ground_spoiler_out(3));
+            assert!(test_bed.get_mean_left_spoilers_position().get::<ratio>() > 0.9);
+            assert!(test_bed.get_mean_right_spoilers_position().get::<ratio>() > 0.9);
+        }
+
+        #[test]
+        fn ground_spoilers_stay_retracted_at_touchdown_with_speed_brake_lever_retracted() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed = test_bed
+                .on_the_ground()
+                .run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(!test_bed.is_sec_ground_spoiler_out(1));
+            assert!(test_bed.get_mean_left_spoilers_position().get::<ratio>() < 0.01);
+        }
+
         #[test]
         fn gear_init_up_if_spawning_in_air() {
             let test_bed = test_bed_in_flight_with()
@@ -11736,6 +12679,25 @@ mod tests {
             assert!(test_bed.get_reverser_2_position().get::<ratio>() < 0.01);
         }
 
+        #[test]
+        fn reverser_1_does_not_deploy_with_sec_1_off() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .start_eng1(Ratio::new::<percent>(60.))
+                .start_eng2(Ratio::new::<percent>(60.))
+                .set_sec_pushbutton_pressed(1, false)
+                .run_waiting_for(Duration::from_secs_f64(10.));
+
+            test_bed = test_bed
+                .eng1_throttle_reverse_full()
+                .eng2_throttle_reverse_full()
+                .run_waiting_for(Duration::from_secs_f64(3.));
+
+            assert!(test_bed.get_reverser_1_position().get::<ratio>() < 0.01);
+            assert!(test_bed.get_reverser_2_position().get::<ratio>() > 0.99);
+        }
+
         #[test]
         fn reversers_do_not_deploy_on_ground_with_pressure_but_eng_off() {
             let mut test_bed = test_bed_in_flight_with()
@@ -0,0 +1,352 @@
+use systems::{
+    shared::{DelayedFalseLogicGate, ElectricalBusType, ElectricalBuses, LgciuWeightOnWheels},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+use std::time::Duration;
+use uom::si::{
+    angle::degree,
+    angular_velocity::radian_per_second,
+    f64::*,
+    length::meter,
+    ratio::ratio,
+    velocity::{knot, meter_per_second},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum GroundSpoilerExtension {
+    Retracted,
+    Partial,
+    Full,
+}
+
+/// Cockpit and aircraft inputs acquired by each of the three SECs
+struct SpoilerElevatorComputerInputs {
+    spoilers_handle_position_id: VariableIdentifier,
+    spoilers_armed_id: VariableIdentifier,
+    throttle_lever_angle_ids: [VariableIdentifier; 2],
+    main_wheel_rpm_ids: [VariableIdentifier; 2],
+
+    spoilers_handle_position: Ratio,
+    spoilers_armed: bool,
+    throttle_lever_angles: [Angle; 2],
+    main_wheel_rpms: [AngularVelocity; 2],
+
+    main_wheel_radius: Length,
+}
+impl SpoilerElevatorComputerInputs {
+    const IDLE_MAX_TLA_DEGREES: f64 = 2.5;
+    const REVERSE_SELECTED_MAX_TLA_DEGREES: f64 = -3.;
+
+    const SPEED_BRAKE_LEVER_RETRACTED_THRESHOLD: f64 = 0.05;
+
+    const WHEEL_SPIN_UP_SPEED_KNOTS: f64 = 72.;
+
+    fn new(context: &mut InitContext, main_wheel_radius: Length) -> Self {
+        Self {
+            spoilers_handle_position_id: context
+                .get_identifier("SPOILERS_HANDLE_POSITION".to_owned()),
+            spoilers_armed_id: context.get_identifier("SPOILERS_ARMED".to_owned()),
+            throttle_lever_angle_ids: [1, 2].map(|engine_number| {
+                context.get_identifier(format!("AUTOTHRUST_TLA:{}", engine_number))
+            }),
+            // Sim wheels 1 and 2 are the left and right main gear wheels
+            main_wheel_rpm_ids: [1, 2]
+                .map(|sim_wheel_id| context.get_identifier(format!("WHEEL RPM:{}", sim_wheel_id))),
+
+            spoilers_handle_position: Ratio::default(),
+            spoilers_armed: false,
+            throttle_lever_angles: [Angle::default(); 2],
+            main_wheel_rpms: [AngularVelocity::default(); 2],
+
+            main_wheel_radius,
+        }
+    }
+
+    fn thrust_lever_at_or_below_idle(&self, engine_index: usize) -> bool {
+        self.throttle_lever_angles[engine_index].get::<degree>() <= Self::IDLE_MAX_TLA_DEGREES
+    }
+
+    fn both_thrust_levers_at_or_below_idle(&self) -> bool {
+        self.thrust_lever_at_or_below_idle(0) && self.thrust_lever_at_or_below_idle(1)
+    }
+
+    fn reverse_selected(&self, engine_index: usize) -> bool {
+        self.throttle_lever_angles[engine_index].get::<degree>()
+            <= Self::REVERSE_SELECTED_MAX_TLA_DEGREES
+    }
+
+    fn reverse_selected_with_other_at_idle(&self) -> bool {
+        (self.reverse_selected(0) && self.thrust_lever_at_or_below_idle(1))
+            || (self.reverse_selected(1) && self.thrust_lever_at_or_below_idle(0))
+    }
+
+    fn speed_brake_lever_is_out(&self) -> bool {
+        self.spoilers_handle_position.get::<ratio>() > Self::SPEED_BRAKE_LEVER_RETRACTED_THRESHOLD
+    }
+
+    fn main_wheel_speed(&self, wheel_index: usize) -> Velocity {
+        Velocity::new::<meter_per_second>(
+            self.main_wheel_rpms[wheel_index]
+                .get::<radian_per_second>()
+                .abs()
+                * self.main_wheel_radius.get::<meter>(),
+        )
+    }
+
+    fn main_wheels_spun_up(&self) -> bool {
+        (0..2).all(|wheel_index| {
+            self.main_wheel_speed(wheel_index).get::<knot>() > Self::WHEEL_SPIN_UP_SPEED_KNOTS
+        })
+    }
+}
+impl SimulationElement for SpoilerElevatorComputerInputs {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.spoilers_handle_position =
+            Ratio::new::<ratio>(reader.read(&self.spoilers_handle_position_id));
+        self.spoilers_armed = reader.read(&self.spoilers_armed_id);
+
+        for (tla, id) in self
+            .throttle_lever_angles
+            .iter_mut()
+            .zip(&self.throttle_lever_angle_ids)
+        {
+            *tla = reader.read(id);
+        }
+
+        for (rpm, id) in self
+            .main_wheel_rpms
+            .iter_mut()
+            .zip(&self.main_wheel_rpm_ids)
+        {
+            *rpm = reader.read(id);
+        }
+    }
+}
+
+/// Ground spoiler and reverser unlock logic of a SEC. Roll and speed brake orders, the fault
+/// light and the failure of the SEC are owned by the flight control laws SEC model, which this
+/// logic shares its failure with.
+struct SpoilerElevatorComputer {
+    pushbutton_pressed_id: VariableIdentifier,
+    failed_id: VariableIdentifier,
+    ground_spoiler_out_id: VariableIdentifier,
+
+    powered_by: Vec<ElectricalBusType>,
+    is_powered: bool,
+    failed: bool,
+    pushbutton_pressed: bool,
+
+    recently_airborne: DelayedFalseLogicGate,
+
+    ground_spoiler_extension: GroundSpoilerExtension,
+    reverser_unlock_orders: [bool; 2],
+}
+impl SpoilerElevatorComputer {
+    const TOUCHDOWN_CONFIRMATION_DURATION: Duration = Duration::from_secs(10);
+
+    const GROUND_SPOILER_FULL_EXTENSION_DEGREES: f64 = 50.;
+    const GROUND_SPOILER_PARTIAL_EXTENSION_DEGREES: f64 = 10.;
+
+    fn new(context: &mut InitContext, number: usize, powered_by: Vec<ElectricalBusType>) -> Self {
+        Self {
+            pushbutton_pressed_id: context
+                .get_identifier(format!("SEC_{}_PUSHBUTTON_PRESSED", number)),
+            failed_id: context.get_identifier(format!("SEC_{}_FAILED", number)),
+            ground_spoiler_out_id: context
+                .get_identifier(format!("SEC_{}_GROUND_SPOILER_OUT", number)),
+
+            powered_by,
+            is_powered: false,
+            failed: false,
+            pushbutton_pressed: false,
+
+            recently_airborne: DelayedFalseLogicGate::new(Self::TOUCHDOWN_CONFIRMATION_DURATION),
+
+            ground_spoiler_extension: GroundSpoilerExtension::Retracted,
+            reverser_unlock_orders: [false; 2],
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        inputs: &SpoilerElevatorComputerInputs,
+        lgciu1: &impl LgciuWeightOnWheels,
+        lgciu2: &impl LgciuWeightOnWheels,
+    ) {
+        let left_main_gear_compressed =
+            lgciu1.left_gear_compressed(false) || lgciu2.left_gear_compressed(false);
+        let right_main_gear_compressed =
+            lgciu1.right_gear_compressed(false) || lgciu2.right_gear_compressed(false);
+        let both_main_gear_compressed = left_main_gear_compressed && right_main_gear_compressed;
+        let any_main_gear_compressed = left_main_gear_compressed || right_main_gear_compressed;
+
+        self.recently_airborne
+            .update(context, !any_main_gear_compressed);
+
+        if !self.is_engaged() {
+            self.ground_spoiler_extension = GroundSpoilerExtension::Retracted;
+            self.reverser_unlock_orders = [false; 2];
+            return;
+        }
+
+        self.ground_spoiler_extension = self.ground_spoiler_extension(
+            inputs,
+            both_main_gear_compressed,
+            any_main_gear_compressed,
+        );
+
+        for (engine_index, unlock_order) in self.reverser_unlock_orders.iter_mut().enumerate() {
+            *unlock_order = any_main_gear_compressed && inputs.reverse_selected(engine_index);
+        }
+    }
+
+    fn ground_spoiler_extension(
+        &self,
+        inputs: &SpoilerElevatorComputerInputs,
+        both_main_gear_compressed: bool,
+        any_main_gear_compressed: bool,
+    ) -> GroundSpoilerExtension {
+        let reverse_selected = inputs.reverse_selected_with_other_at_idle();
+        // Without arming, a speed brake lever left out of retract also extends them
+        let extension_requested = reverse_selected
+            || ((inputs.spoilers_armed || inputs.speed_brake_lever_is_out())
+                && inputs.both_thrust_levers_at_or_below_idle());
+
+        // Full extension at touchdown, on a rejected takeoff once wheels are spun up,
+        // then maintained on ground as long as extension is requested
+        let ground_condition_for_full_extension = inputs.main_wheels_spun_up()
+            || (both_main_gear_compressed
+                && (self.recently_airborne.output()
+                    || self.ground_spoiler_extension == GroundSpoilerExtension::Full));
+
+        if extension_requested && ground_condition_for_full_extension {
+            GroundSpoilerExtension::Full
+        } else if reverse_selected && any_main_gear_compressed {
+            // Partial lift dumping: reverse selected with only one main gear compressed
+            GroundSpoilerExtension::Partial
+        } else {
+            GroundSpoilerExtension::Retracted
+        }
+    }
+
+    fn is_engaged(&self) -> bool {
+        self.is_powered && !self.failed && self.pushbutton_pressed
+    }
+
+    fn ground_spoilers_are_out(&self) -> bool {
+        self.is_engaged() && self.ground_spoiler_extension != GroundSpoilerExtension::Retracted
+    }
+
+    fn ground_spoiler_order(&self) -> Angle {
+        Angle::new::<degree>(match self.ground_spoiler_extension {
+            GroundSpoilerExtension::Retracted => 0.,
+            GroundSpoilerExtension::Partial => Self::GROUND_SPOILER_PARTIAL_EXTENSION_DEGREES,
+            GroundSpoilerExtension::Full => Self::GROUND_SPOILER_FULL_EXTENSION_DEGREES,
+        })
+    }
+
+    fn reverser_unlock_order(&self, engine_index: usize) -> bool {
+        self.is_engaged() && self.reverser_unlock_orders[engine_index]
+    }
+}
+impl SimulationElement for SpoilerElevatorComputer {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.pushbutton_pressed = reader.read(&self.pushbutton_pressed_id);
+        self.failed = reader.read(&self.failed_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.ground_spoiler_out_id, self.ground_spoilers_are_out());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.any_is_powered(&self.powered_by);
+    }
+}
+
+/// The three Spoiler Elevator Computers computing ground spoiler orders.
+/// Each spoiler pair is controlled by a single SEC: spoilers are retracted when it is lost.
+pub(super) struct SpoilerElevatorComputerSet {
+    inputs: SpoilerElevatorComputerInputs,
+    secs: [SpoilerElevatorComputer; 3],
+}
+impl SpoilerElevatorComputerSet {
+    // SEC 3 controls spoilers 1 and 2, SEC 1 spoilers 3 and 4, SEC 2 spoiler 5
+    const SPOILER_TO_SEC_NUMBER: [usize; 5] = [3, 3, 1, 1, 2];
+
+    // Reverser tertiary locks are controlled by SEC 1 for engine 1 and SEC 2 for engine 2
+    const REVERSER_TO_SEC_NUMBER: [usize; 2] = [1, 2];
+
+    pub(super) fn new(context: &mut InitContext, main_wheel_radius: Length) -> Self {
+        Self {
+            inputs: SpoilerElevatorComputerInputs::new(context, main_wheel_radius),
+            secs: [
+                SpoilerElevatorComputer::new(
+                    context,
+                    1,
+                    vec![
+                        ElectricalBusType::DirectCurrentEssential,
+                        ElectricalBusType::DirectCurrentHot(1),
+                    ],
+                ),
+                SpoilerElevatorComputer::new(context, 2, vec![ElectricalBusType::DirectCurrent(2)]),
+                SpoilerElevatorComputer::new(context, 3, vec![ElectricalBusType::DirectCurrent(2)]),
+            ],
+        }
+    }
+
+    pub(super) fn update(
+        &mut self,
+        context: &UpdateContext,
+        lgciu1: &impl LgciuWeightOnWheels,
+        lgciu2: &impl LgciuWeightOnWheels,
+    ) {
+        for sec in &mut self.secs {
+            sec.update(context, &self.inputs, lgciu1, lgciu2);
+        }
+    }
+
+    fn sec(&self, sec_number: usize) -> &SpoilerElevatorComputer {
+        &self.secs[sec_number - 1]
+    }
+
+    /// Ground spoiler order of a spoiler, numbered from 1 (inboard) to 5 (outboard).
+    /// None if the controlling SEC is not engaged.
+    pub(super) fn ground_spoiler_order(&self, spoiler_number: usize) -> Option<Angle> {
+        let sec = self.sec(Self::SPOILER_TO_SEC_NUMBER[spoiler_number - 1]);
+
+        if sec.is_engaged() {
+            Some(sec.ground_spoiler_order())
+        } else {
+            None
+        }
+    }
+
+    pub(super) fn reverser_tertiary_lock_should_unlock(&self, engine_number: usize) -> bool {
+        self.sec(Self::REVERSER_TO_SEC_NUMBER[engine_number - 1])
+            .reverser_unlock_order(engine_number - 1)
+    }
+
+    pub(super) fn ground_spoilers_out(&self) -> [bool; 3] {
+        [
+            self.secs[0].ground_spoilers_are_out(),
+            self.secs[1].ground_spoilers_are_out(),
+            self.secs[2].ground_spoilers_are_out(),
+        ]
+    }
+}
+impl SimulationElement for SpoilerElevatorComputerSet {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.inputs.accept(visitor);
+        for sec in &mut self.secs {
+            sec.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+}
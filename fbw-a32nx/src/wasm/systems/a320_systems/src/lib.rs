@@ -3,6 +3,7 @@ extern crate systems;
 mod air_conditioning;
 mod airframe;
 mod electrical;
+mod failures;
 mod fuel;
 pub mod hydraulic;
 mod navigation;
@@ -21,6 +22,7 @@ use electrical::{
     A320Electrical, A320ElectricalOverheadPanel, A320EmergencyElectricalOverheadPanel,
     APU_START_MOTOR_BUS_TYPE,
 };
+pub use failures::failures;
 use hydraulic::{A320Hydraulic, A320HydraulicOverheadPanel};
 use navigation::A320RadioAltimeters;
 use power_consumption::A320PowerConsumption;
@@ -36,8 +38,11 @@ use systems::{
     },
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
     engine::{leap_engine::LeapEngine, reverser_thrust::ReverserForce, EngineFireOverheadPanel},
+    fuel::FuelFeed,
     hydraulic::brake_circuit::AutobrakePanel,
-    landing_gear::{LandingGear, LandingGearControlInterfaceUnitSet},
+    landing_gear::{
+        tire::TirePressureIndicatingSystem, LandingGear, LandingGearControlInterfaceUnitSet,
+    },
     navigation::adirs::{
         AirDataInertialReferenceSystem, AirDataInertialReferenceSystemOverheadPanel,
     },
@@ -71,6 +76,7 @@ pub struct A320 {
     hydraulic_overhead: A320HydraulicOverheadPanel,
     autobrake_panel: AutobrakePanel,
     landing_gear: LandingGear,
+    tpis: TirePressureIndicatingSystem,
     pneumatic: A320Pneumatic,
     radio_altimeters: A320RadioAltimeters,
     egpwc: EnhancedGroundProximityWarningComputer,
@@ -114,6 +120,11 @@ impl A320 {
             hydraulic_overhead: A320HydraulicOverheadPanel::new(context),
             autobrake_panel: AutobrakePanel::new(context),
             landing_gear: LandingGear::new(context),
+            tpis: TirePressureIndicatingSystem::new(
+                context,
+                6,
+                ElectricalBusType::DirectCurrent(1),
+            ),
             pneumatic: A320Pneumatic::new(context),
             radio_altimeters: A320RadioAltimeters::new(context),
             egpwc: EnhancedGroundProximityWarningComputer::new(
@@ -143,7 +154,7 @@ impl Aircraft for A320 {
             context,
             &self.apu_overhead,
             &self.apu_fire_overhead,
-            self.pneumatic_overhead.apu_bleed_is_on(),
+            self.pneumatic_overhead.apu_bleed_is_on() && !self.pneumatic.apu_bleed_is_isolated(),
             // This will be replaced when integrating the whole electrical system.
             // For now we use the same logic as found in the JavaScript code; ignoring whether or not
             // the engine generators are supplying electricity.
@@ -151,7 +162,7 @@ impl Aircraft for A320 {
                 && !(self.electrical_overhead.external_power_is_on()
                     && self.electrical_overhead.external_power_is_available()),
             self.pneumatic.apu_bleed_air_valve(),
-            self.fuel.left_inner_tank_has_fuel_remaining(),
+            self.fuel.apu_is_fed(),
         );
 
         self.electrical.update(
@@ -183,6 +194,11 @@ impl Aircraft for A320 {
 
         self.asu.update();
 
+        self.fuel
+            .update(context, &self.engine_fire_overhead, &self.apu_overhead);
+        self.engine_1.update(context, self.fuel.engine_is_fed(1));
+        self.engine_2.update(context, self.fuel.engine_is_fed(2));
+
         self.lgcius.update(
             context,
             &self.landing_gear,
@@ -220,6 +236,8 @@ impl Aircraft for A320 {
 
         self.hydraulic_overhead.update(&self.hydraulic);
 
+        self.tpis.update(self.hydraulic.tires());
+
         self.adirs.update(context, &self.adirs_overhead);
         self.adirs_overhead.update(context, &self.adirs);
 
@@ -235,6 +253,8 @@ impl Aircraft for A320 {
             &self.air_conditioning,
             [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
         );
+        self.pneumatic_overhead
+            .update_after_pneumatic(&self.pneumatic);
         self.air_conditioning
             .mix_packs_air_update(self.pneumatic.packs());
         self.air_conditioning.update(
@@ -248,7 +268,15 @@ impl Aircraft for A320 {
             [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
         );
 
-        self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
+        self.egpwc.update(
+            context,
+            &self.adirs,
+            self.lgcius.lgciu1(),
+            [
+                self.radio_altimeters.radio_altimeter_1(),
+                self.radio_altimeters.radio_altimeter_2(),
+            ],
+        );
     }
 }
 impl SimulationElement for A320 {
@@ -279,6 +307,7 @@ impl SimulationElement for A320 {
         self.hydraulic.accept(visitor);
         self.hydraulic_overhead.accept(visitor);
         self.landing_gear.accept(visitor);
+        self.tpis.accept(visitor);
         self.pneumatic.accept(visitor);
         self.egpwc.accept(visitor);
         self.reverse_thrust.accept(visitor);
@@ -4,7 +4,7 @@ use uom::si::{
     f64::*,
     length::foot,
     pressure::psi,
-    ratio::{percent, ratio},
+    ratio::ratio,
     thermodynamic_temperature::degree_celsius,
     velocity::foot_per_minute,
     volume::{cubic_meter, gallon},
@@ -13,27 +13,29 @@ use uom::si::{
 use systems::{
     accept_iterable,
     air_conditioning::PackFlowControllers,
+    engine::{fadec::FullAuthorityDigitalEngineControl, leap_engine::LeapEngine},
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
-        valve::*, BleedMonitoringComputerChannelOperationMode,
-        BleedMonitoringComputerIsAliveSignal, BleedTemperatureSensor, CompressionChamber,
-        ControllablePneumaticValve, CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode,
-        DifferentialPressureTransducer, EngineCompressionChamberController, EngineModeSelector,
-        EngineState, PneumaticContainer, PneumaticPipe, PneumaticValveSignal, Precooler,
-        PressureTransducer, PressurisedReservoirWithExhaustValve, PressurizeableReservoir,
-        SolenoidSignal, TargetPressureTemperatureSignal, VariableVolumeContainer,
-        WingAntiIcePushButton, WingAntiIceSelected,
+        valve::*, BleedDuct, BleedDuctLeak, BleedLeakDetection, BleedLeakMonitor,
+        BleedMonitoringComputerChannelOperationMode, BleedMonitoringComputerIsAliveSignal,
+        BleedTemperatureSensor, CompressionChamber, ControllablePneumaticValve,
+        CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode, DifferentialPressureTransducer,
+        EngineCompressionChamberController, EngineModeSelector, EngineState, OverheatDetectionLoop,
+        PneumaticContainer, PneumaticPipe, PneumaticValveSignal, Precooler, PressureTransducer,
+        PressurisedReservoirWithExhaustValve, PressurizeableReservoir, SolenoidSignal,
+        TargetPressureTemperatureSignal, VariableVolumeContainer, WingAntiIcePushButton,
+        WingAntiIceSelected,
     },
     shared::{
         pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, DelayedTrueLogicGate,
         ElectricalBusType, ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1,
-        EngineCorrectedN2, EngineFirePushButtons, EngineStartState, HydraulicColor,
-        LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
+        EngineCorrectedN2, EngineFirePushButtons, EngineStartState, EngineUncorrectedN2,
+        HydraulicColor, LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
         ReservoirAirPressure,
     },
     simulation::{
-        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
     },
     valve_signal_implementation,
 };
@@ -105,11 +107,14 @@ pub struct A320Pneumatic {
 
     cross_bleed_valve: CrossBleedValve,
 
-    fadec: FullAuthorityDigitalEngineControl,
+    fadec: FullAuthorityDigitalEngineControl<2>,
     engine_starter_valve_controllers: [EngineStarterValveController; 2],
 
     apu_compression_chamber: CompressionChamber,
     apu_bleed_air_valve: DefaultValve,
+    apu_bleed_leak: BleedDuctLeak,
+
+    leak_detection_loops: A320BleedLeakDetectionLoops,
 
     air_starter_unit_compression_chamber: CompressionChamber,
     air_starter_unit_bleed_air_valve: PurelyPneumaticValve,
@@ -160,13 +165,18 @@ impl A320Pneumatic {
                 EngineBleedAirSystem::new(context, 2, ElectricalBusType::DirectCurrent(2)),
             ],
             cross_bleed_valve: CrossBleedValve::new(Ratio::new::<ratio>(0.4)),
-            fadec: FullAuthorityDigitalEngineControl::new(context),
+            fadec: FullAuthorityDigitalEngineControl::new(
+                context,
+                LeapEngine::START_SEQUENCE_PARAMETERS,
+            ),
             engine_starter_valve_controllers: [
                 EngineStarterValveController::new(1),
                 EngineStarterValveController::new(2),
             ],
             apu_compression_chamber: CompressionChamber::new(Volume::new::<cubic_meter>(5.)),
             apu_bleed_air_valve: DefaultValve::new_closed(),
+            apu_bleed_leak: BleedDuctLeak::new(BleedDuct::Apu),
+            leak_detection_loops: A320BleedLeakDetectionLoops::new(),
             air_starter_unit_compression_chamber: CompressionChamber::new(
                 Volume::new::<cubic_meter>(5.),
             ),
@@ -225,7 +235,7 @@ impl A320Pneumatic {
     pub(crate) fn update(
         &mut self,
         context: &UpdateContext,
-        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2); 2],
+        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2 + EngineUncorrectedN2); 2],
         overhead_panel: &A320PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
@@ -233,6 +243,8 @@ impl A320Pneumatic {
         pack_flow_valve_signals: &impl PackFlowControllers,
         lgciu: [&impl LgciuWeightOnWheels; 2],
     ) {
+        self.fadec.update(context, engines);
+
         self.physics_updater.update(context);
 
         for cur_time_step in self.physics_updater {
@@ -263,10 +275,16 @@ impl A320Pneumatic {
         self.apu_compression_chamber.update(apu);
         self.air_starter_unit_compression_chamber.update(asu);
 
+        self.apu_bleed_leak
+            .update_move_fluid(context, &mut self.apu_compression_chamber);
+        self.leak_detection_loops
+            .update(context, &self.engine_systems, &self.apu_bleed_leak);
+
         for bleed_monitoring_computer in self.bleed_monitoring_computers.iter_mut() {
             bleed_monitoring_computer.update(
                 context,
                 &self.engine_systems,
+                &self.leak_detection_loops,
                 &self.apu_bleed_air_valve,
                 overhead_panel,
                 engine_fire_push_buttons,
@@ -381,6 +399,32 @@ impl A320Pneumatic {
     pub fn packs(&mut self) -> &mut [PackComplex; 2] {
         &mut self.packs
     }
+
+    /// Indicates if the BMC commands the APU bleed valve closed because of a leak
+    /// in the left wing or APU bleed duct.
+    pub fn apu_bleed_is_isolated(&self) -> bool {
+        self.master_channels_for_engine(1)
+            .any(|channel| channel.should_isolate_apu_bleed())
+    }
+
+    pub fn engine_bleed_has_fault(&self, engine_number: usize) -> bool {
+        self.master_channels_for_engine(engine_number)
+            .any(|channel| channel.has_engine_bleed_fault())
+    }
+
+    pub fn apu_bleed_has_fault(&self) -> bool {
+        self.master_channels_for_engine(1)
+            .any(|channel| channel.has_apu_leak())
+    }
+
+    fn master_channels_for_engine(
+        &self,
+        engine_number: usize,
+    ) -> impl Iterator<Item = &BleedMonitoringComputerChannel> {
+        self.bleed_monitoring_computers
+            .iter()
+            .filter_map(move |bmc| bmc.channel_for_engine(engine_number))
+    }
 }
 impl PneumaticBleed for A320Pneumatic {
     fn apu_bleed_is_on(&self) -> bool {
@@ -415,6 +459,7 @@ impl SimulationElement for A320Pneumatic {
         self.cross_bleed_valve.accept(visitor);
         self.fadec.accept(visitor);
         self.wing_anti_ice.accept(visitor);
+        self.apu_bleed_leak.accept(visitor);
 
         accept_iterable!(self.bleed_monitoring_computers, visitor);
         accept_iterable!(self.engine_systems, visitor);
@@ -462,17 +507,14 @@ impl ReservoirAirPressure for A320Pneumatic {
 
 struct EngineStarterValveController {
     number: usize,
-    engine_state: EngineState,
-    engine_n2_percent: f64,
+    is_commanded_open: bool,
 }
 impl ControllerSignal<EngineStarterValveSignal> for EngineStarterValveController {
     fn signal(&self) -> Option<EngineStarterValveSignal> {
-        match self.engine_state {
-            //FIXME should start at around 60% N2 and complete at 65% N2 because of traveltime of valve
-            EngineState::Starting | EngineState::Restarting if self.engine_n2_percent < 65. => {
-                Some(EngineStarterValveSignal::new_open())
-            }
-            _ => Some(EngineStarterValveSignal::new_closed()),
+        if self.is_commanded_open {
+            Some(EngineStarterValveSignal::new_open())
+        } else {
+            Some(EngineStarterValveSignal::new_closed())
         }
     }
 }
@@ -480,14 +522,12 @@ impl EngineStarterValveController {
     fn new(number: usize) -> Self {
         Self {
             number,
-            engine_state: EngineState::Off,
-            engine_n2_percent: 0.,
+            is_commanded_open: false,
         }
     }
 
-    fn update(&mut self, fadec: &FullAuthorityDigitalEngineControl) {
-        self.engine_state = fadec.engine_state(self.number);
-        self.engine_n2_percent = fadec.engine_n2_percent(self.number);
+    fn update(&mut self, fadec: &FullAuthorityDigitalEngineControl<2>) {
+        self.is_commanded_open = fadec.start_valve_is_commanded_open(self.number);
     }
 }
 
@@ -528,16 +568,18 @@ impl BleedMonitoringComputer {
         &mut self,
         context: &UpdateContext,
         sensors: &[EngineBleedAirSystem; 2],
+        leak_detection: &impl BleedLeakDetection,
         apu_bleed_valve: &impl PneumaticValve,
         overhead_panel: &A320PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         cross_bleed_valve: &impl PneumaticValve,
-        fadec: &FullAuthorityDigitalEngineControl,
+        fadec: &impl EngineStartState,
         wing_anti_ice: &impl WingAntiIceSelected,
     ) {
         self.main_channel.update(
             context,
             &sensors[self.main_channel_engine_number - 1],
+            leak_detection,
             engine_fire_push_buttons,
             apu_bleed_valve,
             cross_bleed_valve,
@@ -549,6 +591,7 @@ impl BleedMonitoringComputer {
         self.backup_channel.update(
             context,
             &sensors[self.backup_channel_engine_number - 1],
+            leak_detection,
             engine_fire_push_buttons,
             apu_bleed_valve,
             cross_bleed_valve,
@@ -651,10 +694,16 @@ struct BleedMonitoringComputerChannel {
     flight_phase_loop: FlightPhaseLoop,
     low_temperature_regulation_active: DelayedTrueLogicGate,
     should_command_onside_prv_closed: bool,
+    engine_leak_monitor: BleedLeakMonitor,
+    wing_leak_monitors: [BleedLeakMonitor; 2],
+    apu_leak_monitor: BleedLeakMonitor,
 
     low_temperature_id: VariableIdentifier,
     overheat_id: VariableIdentifier,
     overpressure_id: VariableIdentifier,
+    engine_leak_id: VariableIdentifier,
+    wing_leak_id: VariableIdentifier,
+    apu_leak_id: VariableIdentifier,
 }
 impl BleedMonitoringComputerChannel {
     const PRESSURE_REGULATING_VALVE_SINGLE_BLEED_CONFIG_TARGET_PSI: f64 = 50.;
@@ -703,12 +752,18 @@ impl BleedMonitoringComputerChannel {
             flight_phase_loop: FlightPhaseLoop::new(),
             low_temperature_regulation_active: DelayedTrueLogicGate::new(Duration::from_secs(20)),
             should_command_onside_prv_closed: false,
+            engine_leak_monitor: BleedLeakMonitor::default(),
+            wing_leak_monitors: [BleedLeakMonitor::default(), BleedLeakMonitor::default()],
+            apu_leak_monitor: BleedLeakMonitor::default(),
             is_in_dual_bleed_config: false,
             low_temperature_id: context
                 .get_identifier(format!("PNEU_ENG_{}_LOW_TEMPERATURE", engine_number)),
             overheat_id: context.get_identifier(format!("PNEU_ENG_{}_OVERHEAT", engine_number)),
             overpressure_id: context
                 .get_identifier(format!("PNEU_ENG_{}_OVERPRESSURE", engine_number)),
+            engine_leak_id: context.get_identifier(format!("PNEU_ENG_{}_LEAK", engine_number)),
+            wing_leak_id: context.get_identifier(format!("PNEU_WING_{}_LEAK", engine_number)),
+            apu_leak_id: context.get_identifier("PNEU_APU_LEAK".to_owned()),
         }
     }
 
@@ -716,11 +771,12 @@ impl BleedMonitoringComputerChannel {
         &mut self,
         context: &UpdateContext,
         sensors: &EngineBleedAirSystem,
+        leak_detection: &impl BleedLeakDetection,
         engine_fire_pushbuttons: &impl EngineFirePushButtons,
         apu_bleed_valve: &impl PneumaticValve,
         cross_bleed_valve: &impl PneumaticValve,
         overhead_panel: &A320PneumaticOverheadPanel,
-        fadec: &FullAuthorityDigitalEngineControl,
+        fadec: &impl EngineStartState,
         wing_anti_ice: &impl WingAntiIceSelected,
     ) {
         // READ IN SENSORS
@@ -737,6 +793,7 @@ impl BleedMonitoringComputerChannel {
 
         // UPDATE STATE
 
+        self.update_leak_monitors(leak_detection, overhead_panel);
         self.flight_phase_loop.update(context);
         self.update_dual_vs_single_bleed_operation(
             sensors,
@@ -831,6 +888,30 @@ impl BleedMonitoringComputerChannel {
         }
     }
 
+    fn update_leak_monitors(
+        &mut self,
+        leak_detection: &impl BleedLeakDetection,
+        overhead_panel: &A320PneumaticOverheadPanel,
+    ) {
+        // A latched leak is reset by switching off the bleed source feeding the leaking duct
+        self.engine_leak_monitor.update(
+            leak_detection.has_overheat(BleedDuct::Engine(self.engine_number)),
+            !overhead_panel.engine_bleed_pb_is_auto(self.engine_number),
+        );
+
+        for (index, monitor) in self.wing_leak_monitors.iter_mut().enumerate() {
+            monitor.update(
+                leak_detection.has_overheat(BleedDuct::Wing(index + 1)),
+                !overhead_panel.engine_bleed_pb_is_auto(index + 1),
+            );
+        }
+
+        self.apu_leak_monitor.update(
+            leak_detection.has_overheat(BleedDuct::Apu),
+            !overhead_panel.apu_bleed_is_on(),
+        );
+    }
+
     fn operation_mode(&self) -> BleedMonitoringComputerChannelOperationMode {
         self.operation_mode
     }
@@ -894,6 +975,33 @@ impl BleedMonitoringComputerChannel {
         self.overheat_monitor.has_overheat()
     }
 
+    fn has_engine_leak(&self) -> bool {
+        self.engine_leak_monitor.has_leak()
+    }
+
+    fn has_wing_leak(&self, wing_number: usize) -> bool {
+        self.wing_leak_monitors[wing_number - 1].has_leak()
+    }
+
+    fn has_apu_leak(&self) -> bool {
+        self.apu_leak_monitor.has_leak()
+    }
+
+    fn has_engine_bleed_fault(&self) -> bool {
+        self.has_overheat()
+            || self.has_overpressure()
+            || self.has_engine_leak()
+            || self.has_wing_leak(self.engine_number)
+    }
+
+    fn should_isolate_cross_bleed(&self) -> bool {
+        self.has_wing_leak(1) || self.has_wing_leak(2) || self.has_apu_leak()
+    }
+
+    fn should_isolate_apu_bleed(&self) -> bool {
+        self.has_wing_leak(1) || self.has_apu_leak()
+    }
+
     fn determine_temperature_setpoint(&self) -> f64 {
         if self.low_temperature_regulation_active.output() {
             Self::LOW_TEMPERATURE_REGULATION_THRESHOLD
@@ -908,7 +1016,7 @@ impl BleedMonitoringComputerChannel {
         overhead_panel: &A320PneumaticOverheadPanel,
         engine_fire_pushbuttons: &impl EngineFirePushButtons,
         cross_bleed_valve: &impl PneumaticValve,
-        fadec: &FullAuthorityDigitalEngineControl,
+        fadec: &impl EngineStartState,
     ) {
         self.should_command_onside_prv_closed = self.should_command_prv_closed(
             self.engine_number,
@@ -917,7 +1025,9 @@ impl BleedMonitoringComputerChannel {
             cross_bleed_valve,
         ) || sensors.engine_starter_valve_is_open()
             || self.overpressure_monitor.has_overpressure()
-            || self.overheat_monitor.has_overheat();
+            || self.overheat_monitor.has_overheat()
+            || self.has_engine_leak()
+            || self.has_wing_leak(self.engine_number);
 
         let should_command_offside_prv_closed = self.should_command_prv_closed(
             self.engine_number % 2 + 1,
@@ -926,7 +1036,7 @@ impl BleedMonitoringComputerChannel {
             cross_bleed_valve,
         );
 
-        self.is_in_dual_bleed_config = !fadec.is_single_vs_dual_bleed_config()
+        self.is_in_dual_bleed_config = !is_single_vs_dual_bleed_config(fadec)
             && !self.should_command_onside_prv_closed
             && !should_command_offside_prv_closed;
     }
@@ -999,7 +1109,7 @@ impl ControllerSignal<CrossBleedValveSignal> for BleedMonitoringComputerChannel
                 CrossBleedValveSignalType::Manual,
             )),
             CrossBleedValveSelectorMode::Auto => {
-                if self.is_apu_bleed_valve_open {
+                if self.is_apu_bleed_valve_open && !self.should_isolate_cross_bleed() {
                     Some(CrossBleedValveSignal::new_open(
                         CrossBleedValveSignalType::Automatic,
                     ))
@@ -1022,6 +1132,12 @@ impl SimulationElement for BleedMonitoringComputerChannel {
             writer.write(&self.low_temperature_id, self.has_low_temperature());
             writer.write(&self.overheat_id, self.has_overheat());
             writer.write(&self.overpressure_id, self.has_overpressure());
+            writer.write(&self.engine_leak_id, self.has_engine_leak());
+            writer.write(&self.wing_leak_id, self.has_wing_leak(self.engine_number));
+
+            if self.engine_number == 1 {
+                writer.write(&self.apu_leak_id, self.has_apu_leak());
+            }
         }
     }
 }
@@ -1072,6 +1188,9 @@ struct EngineBleedAirSystem {
     regulated_pressure_transducer: PressureTransducer,
     differential_pressure_transducer: DifferentialPressureTransducer,
     bleed_temperature_sensor: BleedTemperatureSensor,
+
+    engine_leak: BleedDuctLeak,
+    wing_leak: BleedDuctLeak,
 }
 impl EngineBleedAirSystem {
     const MIN_ENGINE_START_CONTAINER_PRESSURE_PSIG_HIGH: f64 = 10.;
@@ -1192,6 +1311,8 @@ impl EngineBleedAirSystem {
             regulated_pressure_transducer: PressureTransducer::new(powered_by),
             differential_pressure_transducer: DifferentialPressureTransducer::new(powered_by),
             bleed_temperature_sensor: BleedTemperatureSensor::new(powered_by),
+            engine_leak: BleedDuctLeak::new(BleedDuct::Engine(number)),
+            wing_leak: BleedDuctLeak::new(BleedDuct::Wing(number)),
         }
     }
 
@@ -1270,6 +1391,13 @@ impl EngineBleedAirSystem {
             .update_move_fluid(context, &mut self.engine_starter_container);
         self.update_engine_start_pressurization(context);
 
+        // The pylon duct runs from the HP and IP ports onwards, a leak upstream of the
+        // pressure regulating valve releases the hottest air of the bleed system.
+        self.engine_leak
+            .update_move_fluid(context, &mut self.transfer_pressure_pipe);
+        self.wing_leak
+            .update_move_fluid(context, &mut self.precooler_outlet_pipe);
+
         self.transfer_pressure_transducer
             .update(context, &self.transfer_pressure_pipe);
         self.regulated_pressure_transducer
@@ -1350,6 +1478,14 @@ impl EngineBleedAirSystem {
         self.engine_starter_valve.is_open()
     }
 
+    fn engine_leak(&self) -> &BleedDuctLeak {
+        &self.engine_leak
+    }
+
+    fn wing_leak(&self) -> &BleedDuctLeak {
+        &self.wing_leak
+    }
+
     fn pressure_regulating_valve_is_open(&self) -> bool {
         self.pressure_regulating_valve.is_open()
     }
@@ -1381,6 +1517,9 @@ impl SimulationElement for EngineBleedAirSystem {
         self.differential_pressure_transducer.accept(visitor);
         self.bleed_temperature_sensor.accept(visitor);
 
+        self.engine_leak.accept(visitor);
+        self.wing_leak.accept(visitor);
+
         visitor.visit(self);
     }
 
@@ -1482,6 +1621,65 @@ impl PneumaticContainer for EngineBleedAirSystem {
     }
 }
 
+/// The overheat detection loops along the pylon, wing and APU bleed ducts.
+struct A320BleedLeakDetectionLoops {
+    engine_loops: [OverheatDetectionLoop; 2],
+    wing_loops: [OverheatDetectionLoop; 2],
+    apu_loop: OverheatDetectionLoop,
+}
+impl A320BleedLeakDetectionLoops {
+    const PYLON_LOOP_ALARM_TEMPERATURE_C: f64 = 204.;
+    const WING_AND_APU_LOOP_ALARM_TEMPERATURE_C: f64 = 124.;
+
+    fn new() -> Self {
+        let pylon_alarm_temperature =
+            ThermodynamicTemperature::new::<degree_celsius>(Self::PYLON_LOOP_ALARM_TEMPERATURE_C);
+        let wing_and_apu_alarm_temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            Self::WING_AND_APU_LOOP_ALARM_TEMPERATURE_C,
+        );
+
+        Self {
+            engine_loops: [
+                OverheatDetectionLoop::new(pylon_alarm_temperature),
+                OverheatDetectionLoop::new(pylon_alarm_temperature),
+            ],
+            wing_loops: [
+                OverheatDetectionLoop::new(wing_and_apu_alarm_temperature),
+                OverheatDetectionLoop::new(wing_and_apu_alarm_temperature),
+            ],
+            apu_loop: OverheatDetectionLoop::new(wing_and_apu_alarm_temperature),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine_systems: &[EngineBleedAirSystem; 2],
+        apu_bleed_leak: &BleedDuctLeak,
+    ) {
+        for ((engine_loop, wing_loop), engine_system) in self
+            .engine_loops
+            .iter_mut()
+            .zip(self.wing_loops.iter_mut())
+            .zip(engine_systems)
+        {
+            engine_loop.update(context, engine_system.engine_leak());
+            wing_loop.update(context, engine_system.wing_leak());
+        }
+
+        self.apu_loop.update(context, apu_bleed_leak);
+    }
+}
+impl BleedLeakDetection for A320BleedLeakDetectionLoops {
+    fn has_overheat(&self, duct: BleedDuct) -> bool {
+        match duct {
+            BleedDuct::Engine(number) => self.engine_loops[number - 1].has_overheat(),
+            BleedDuct::Wing(number) => self.wing_loops[number - 1].has_overheat(),
+            BleedDuct::Apu => self.apu_loop.has_overheat(),
+        }
+    }
+}
+
 struct BleedOverheatMonitor {
     temperature_over_257_for_55s: DelayedTrueLogicGate,
     temperature_over_270_for_15s: DelayedTrueLogicGate,
@@ -1571,6 +1769,14 @@ impl A320PneumaticOverheadPanel {
             _ => panic!("Invalid engine number"),
         }
     }
+
+    pub fn update_after_pneumatic(&mut self, pneumatic: &A320Pneumatic) {
+        self.engine_1_bleed
+            .set_fault(pneumatic.engine_bleed_has_fault(1));
+        self.engine_2_bleed
+            .set_fault(pneumatic.engine_bleed_has_fault(2));
+        self.apu_bleed.set_fault(pneumatic.apu_bleed_has_fault());
+    }
 }
 impl EngineBleedPushbutton<2> for A320PneumaticOverheadPanel {
     fn engine_bleed_pushbuttons_are_auto(&self) -> [bool; 2] {
@@ -1589,70 +1795,9 @@ impl SimulationElement for A320PneumaticOverheadPanel {
     }
 }
 
-/// We use this simply as an interface to engine parameter simvars. It should probably not be part of the pneumatic system.
-pub struct FullAuthorityDigitalEngineControl {
-    engine_1_state_id: VariableIdentifier,
-    engine_2_state_id: VariableIdentifier,
-    engine_1_state: EngineState,
-    engine_2_state: EngineState,
-
-    engine_mode_selector1_id: VariableIdentifier,
-    engine_mode_selector1_position: EngineModeSelector,
-
-    engine_1_n2_percent_id: VariableIdentifier,
-    engine_2_n2_percent_id: VariableIdentifier,
-    engine_1_n2_percent: Ratio,
-    engine_2_n2_percent: Ratio,
-}
-impl FullAuthorityDigitalEngineControl {
-    fn new(context: &mut InitContext) -> Self {
-        Self {
-            engine_1_state_id: context.get_identifier("ENGINE_STATE:1".to_owned()),
-            engine_2_state_id: context.get_identifier("ENGINE_STATE:2".to_owned()),
-            engine_1_state: EngineState::Off,
-            engine_2_state: EngineState::Off,
-            engine_mode_selector1_id: context
-                .get_identifier("TURB ENG IGNITION SWITCH EX1:1".to_owned()),
-            engine_mode_selector1_position: EngineModeSelector::Norm,
-            engine_1_n2_percent_id: context.get_identifier("ENGINE_N2:1".to_owned()),
-            engine_2_n2_percent_id: context.get_identifier("ENGINE_N2:2".to_owned()),
-            engine_1_n2_percent: Ratio::new::<percent>(0.),
-            engine_2_n2_percent: Ratio::new::<percent>(0.),
-        }
-    }
-
-    fn engine_state(&self, number: usize) -> EngineState {
-        match number {
-            1 => self.engine_1_state,
-            2 => self.engine_2_state,
-            _ => panic!("Invalid engine number"),
-        }
-    }
-
-    fn engine_n2_percent(&self, number: usize) -> f64 {
-        match number {
-            1 => self.engine_1_n2_percent.get::<percent>(),
-            2 => self.engine_2_n2_percent.get::<percent>(),
-            _ => panic!("Invalid engine number"),
-        }
-    }
-
-    fn is_single_vs_dual_bleed_config(&self) -> bool {
-        (self.engine_1_state == EngineState::On) ^ (self.engine_2_state == EngineState::On)
-    }
-
-    fn engine_mode_selector(&self) -> EngineModeSelector {
-        self.engine_mode_selector1_position
-    }
-}
-impl SimulationElement for FullAuthorityDigitalEngineControl {
-    fn read(&mut self, reader: &mut SimulatorReader) {
-        self.engine_1_state = reader.read(&self.engine_1_state_id);
-        self.engine_2_state = reader.read(&self.engine_2_state_id);
-        self.engine_mode_selector1_position = reader.read(&self.engine_mode_selector1_id);
-        self.engine_1_n2_percent = Ratio::new::<percent>(reader.read(&self.engine_1_n2_percent_id));
-        self.engine_2_n2_percent = Ratio::new::<percent>(reader.read(&self.engine_2_n2_percent_id));
-    }
+/// Indicates if exactly one of the engines is running.
+fn is_single_vs_dual_bleed_config(fadec: &impl EngineStartState) -> bool {
+    (fadec.engine_state(1) == EngineState::On) ^ (fadec.engine_state(2) == EngineState::On)
 }
 
 /// A struct to hold all the pack related components
@@ -1912,9 +2057,9 @@ pub mod tests {
         engine::leap_engine::LeapEngine,
         failures::FailureType,
         pneumatic::{
-            BleedMonitoringComputerChannelOperationMode, ControllablePneumaticValve,
-            CrossBleedValveSelectorMode, EngineState, PneumaticContainer, PneumaticValveSignal,
-            TargetPressureTemperatureSignal, WingAntiIcePushButtonMode,
+            BleedDuct, BleedMonitoringComputerChannelOperationMode, ControllablePneumaticValve,
+            CrossBleedValveSelectorMode, EngineModeSelector, EngineState, PneumaticContainer,
+            PneumaticValveSignal, TargetPressureTemperatureSignal, WingAntiIcePushButtonMode,
         },
         shared::{
             arinc429::{Arinc429Word, SignStatus},
@@ -1944,7 +2089,7 @@ pub mod tests {
 
     use crate::air_conditioning::{A320AirConditioningSystem, A320PressurizationOverheadPanel};
 
-    use super::{A320Pneumatic, A320PneumaticOverheadPanel};
+    use super::{is_single_vs_dual_bleed_config, A320Pneumatic, A320PneumaticOverheadPanel};
 
     struct TestAirConditioning {
         a320_air_conditioning_system: A320AirConditioningSystem,
@@ -2299,6 +2444,8 @@ pub mod tests {
                 &self.air_conditioning,
                 [&self.lgciu; 2],
             );
+            self.pneumatic_overhead_panel
+                .update_after_pneumatic(&self.pneumatic);
             self.air_conditioning.update(
                 context,
                 [&self.engine_1, &self.engine_2],
@@ -2388,7 +2535,7 @@ pub mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.7));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.2));
-            self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N2:1", Ratio::new::<ratio>(0.7));
 
             self
         }
@@ -2397,7 +2544,7 @@ pub mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.7));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.2));
-            self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N2:2", Ratio::new::<ratio>(0.7));
 
             self
         }
@@ -2406,7 +2553,7 @@ pub mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(1.05));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.87));
-            self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N2:1", Ratio::new::<ratio>(1.05));
 
             self
         }
@@ -2415,7 +2562,7 @@ pub mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(1.05));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.87));
-            self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N2:2", Ratio::new::<ratio>(1.05));
 
             self
         }
@@ -2423,7 +2570,7 @@ pub mod tests {
         fn eng1_n1(mut self, n1: f64) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(n1));
-            self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N2:1", Ratio::new::<ratio>(0.7));
 
             self
         }
@@ -2437,7 +2584,7 @@ pub mod tests {
         fn eng2_n1(mut self, n1: f64) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(n1));
-            self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N2:2", Ratio::new::<ratio>(0.7));
 
             self
         }
@@ -2470,7 +2617,7 @@ pub mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.92));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.5));
-            self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N2:1", Ratio::new::<ratio>(0.92));
 
             self
         }
@@ -2479,7 +2626,7 @@ pub mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.92));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.5));
-            self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N2:2", Ratio::new::<ratio>(0.92));
 
             self
         }
@@ -2488,7 +2635,7 @@ pub mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", false);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.));
-            self.write_by_name("ENGINE_STATE:1", EngineState::Off);
+            self.write_by_name("ENGINE_N2:1", Ratio::new::<ratio>(0.));
 
             self
         }
@@ -2497,21 +2644,22 @@ pub mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", false);
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.));
-            self.write_by_name("ENGINE_STATE:2", EngineState::Off);
+            self.write_by_name("ENGINE_N2:2", Ratio::new::<ratio>(0.));
 
             self
         }
 
-        fn start_eng1(mut self) -> Self {
-            self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
-            self.write_by_name("ENGINE_STATE:1", EngineState::Starting);
-
-            self
-        }
+        fn start_engine(mut self, number: usize) -> Self {
+            // A start sequence only begins after the engine master has been off.
+            self.write_by_name(&format!("GENERAL ENG STARTER ACTIVE:{}", number), false);
+            self.write_by_name(&format!("ENGINE_N2:{}", number), Ratio::new::<ratio>(0.));
+            self.run();
 
-        fn start_eng2(mut self) -> Self {
-            self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
-            self.write_by_name("ENGINE_STATE:2", EngineState::Starting);
+            self.write_by_name(&format!("GENERAL ENG STARTER ACTIVE:{}", number), true);
+            self.write_by_name(
+                "TURB ENG IGNITION SWITCH EX1:1",
+                EngineModeSelector::Ignition,
+            );
 
             self
         }
@@ -2733,8 +2881,9 @@ pub mod tests {
             self
         }
 
-        fn set_engine_state(mut self, number: usize, engine_state: EngineState) -> Self {
-            self.write_by_name(&format!("ENGINE_STATE:{}", number), engine_state);
+        fn shut_down_eng(mut self, number: usize, n2: f64) -> Self {
+            self.write_by_name(&format!("GENERAL ENG STARTER ACTIVE:{}", number), false);
+            self.write_by_name(&format!("ENGINE_N2:{}", number), Ratio::new::<ratio>(n2));
 
             self
         }
@@ -2817,7 +2966,7 @@ pub mod tests {
         }
 
         fn fadec_single_vs_dual_bleed_config(&self) -> bool {
-            self.query(|a| a.pneumatic.fadec.is_single_vs_dual_bleed_config())
+            self.query(|a| is_single_vs_dual_bleed_config(&a.pneumatic.fadec))
         }
 
         fn bmc_in_low_temperature_regulation(&self, bmc_number: usize) -> bool {
@@ -2948,6 +3097,36 @@ pub mod tests {
             self.query(|a| a.pneumatic.wing_anti_ice.wai_mass_flow(1))
         }
 
+        fn bleed_duct_leak(mut self, duct: BleedDuct) -> Self {
+            self.fail(FailureType::BleedDuctLeak(duct));
+
+            self
+        }
+
+        fn has_engine_leak(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_ENG_{}_LEAK", number))
+        }
+
+        fn has_wing_leak(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_WING_{}_LEAK", number))
+        }
+
+        fn has_apu_leak(&mut self) -> bool {
+            self.read_by_name("PNEU_APU_LEAK")
+        }
+
+        fn engine_bleed_push_button_has_fault(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("OVHD_PNEU_ENG_{}_BLEED_PB_HAS_FAULT", number))
+        }
+
+        fn apu_bleed_push_button_has_fault(&mut self) -> bool {
+            self.read_by_name("OVHD_PNEU_APU_BLEED_PB_HAS_FAULT")
+        }
+
+        fn apu_bleed_is_isolated(&self) -> bool {
+            self.query(|a| a.pneumatic.apu_bleed_is_isolated())
+        }
+
         fn set_asu(mut self, value: bool) -> Self {
             self.write_by_name("ASU_TURNED_ON", value);
 
@@ -3424,7 +3603,7 @@ pub mod tests {
         assert!(!test_bed.es_valve_is_open(1));
         assert!(!test_bed.es_valve_is_open(2));
 
-        test_bed = test_bed.start_eng1().start_eng2().and_run();
+        test_bed = test_bed.start_engine(1).start_engine(2).and_run();
 
         assert!(test_bed.es_valve_is_open(1));
         assert!(test_bed.es_valve_is_open(2));
@@ -3433,7 +3612,7 @@ pub mod tests {
     #[test]
     fn apu_bleed_engine_start() {
         let mut test_bed = test_bed_with()
-            .start_eng1()
+            .start_engine(1)
             .stop_eng2()
             .set_bleed_air_running()
             .and_stabilize();
@@ -3448,7 +3627,7 @@ pub mod tests {
             test_bed.regulated_pressure_transducer_signal(1).unwrap() > Pressure::new::<psi>(21.),
         );
 
-        test_bed = test_bed.idle_eng1().start_eng2().and_stabilize();
+        test_bed = test_bed.idle_eng1().start_engine(2).and_stabilize();
 
         assert!(!test_bed.es_valve_is_open(1));
         assert!(test_bed.es_valve_is_open(2));
@@ -3462,7 +3641,7 @@ pub mod tests {
     #[test]
     fn asu_bleed_engine_start() {
         let mut test_bed = test_bed_with()
-            .start_eng1()
+            .start_engine(1)
             .stop_eng2()
             .set_asu(true)
             .and_stabilize();
@@ -3480,7 +3659,7 @@ pub mod tests {
         test_bed = test_bed
             .stop_eng1()
             .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Open)
-            .start_eng2()
+            .start_engine(2)
             .and_stabilize();
 
         assert!(!test_bed.es_valve_is_open(1));
@@ -3495,7 +3674,7 @@ pub mod tests {
     #[test]
     fn cross_bleed_engine_start() {
         let mut test_bed = test_bed_with()
-            .start_eng1()
+            .start_engine(1)
             .eng2_n1(0.3)
             .eng2_n2(0.75)
             .set_engine_bleed_push_button_off(1)
@@ -3525,7 +3704,7 @@ pub mod tests {
         test_bed = test_bed
             .eng1_n1(0.3)
             .eng1_n2(0.75)
-            .start_eng2()
+            .start_engine(2)
             .set_engine_bleed_push_button_auto(1)
             .set_engine_bleed_push_button_off(2)
             .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Open)
@@ -3677,6 +3856,14 @@ pub mod tests {
 
         assert!(test_bed.contains_variable_with_name("PNEU_ENG_1_OVERPRESSURE"));
         assert!(test_bed.contains_variable_with_name("PNEU_ENG_2_OVERPRESSURE"));
+
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_1_LEAK"));
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_2_LEAK"));
+
+        assert!(test_bed.contains_variable_with_name("PNEU_WING_1_LEAK"));
+        assert!(test_bed.contains_variable_with_name("PNEU_WING_2_LEAK"));
+
+        assert!(test_bed.contains_variable_with_name("PNEU_APU_LEAK"));
     }
 
     #[test]
@@ -3777,50 +3964,65 @@ pub mod tests {
 
     #[test]
     fn fadec_represents_engine_state() {
-        let mut test_bed = test_bed_with()
-            .set_engine_state(1, EngineState::Off)
-            .set_engine_state(2, EngineState::Off);
+        let mut test_bed = test_bed_with().stop_eng1().stop_eng2();
+        test_bed.set_lgciu_on_ground(true);
+        test_bed = test_bed.and_run();
 
         assert_eq!(test_bed.engine_state(1), EngineState::Off);
         assert_eq!(test_bed.engine_state(2), EngineState::Off);
 
-        test_bed = test_bed
-            .set_engine_state(1, EngineState::Starting)
-            .and_run();
+        test_bed = test_bed.start_engine(1).and_run();
 
         assert_eq!(test_bed.engine_state(1), EngineState::Starting);
         assert_eq!(test_bed.engine_state(2), EngineState::Off);
 
-        test_bed = test_bed
-            .set_engine_state(2, EngineState::Starting)
-            .and_run();
+        test_bed = test_bed.start_engine(2).and_run();
 
         assert_eq!(test_bed.engine_state(1), EngineState::Starting);
         assert_eq!(test_bed.engine_state(2), EngineState::Starting);
 
-        test_bed = test_bed.set_engine_state(1, EngineState::On).and_run();
+        test_bed = test_bed.idle_eng1().and_run();
 
         assert_eq!(test_bed.engine_state(1), EngineState::On);
         assert_eq!(test_bed.engine_state(2), EngineState::Starting);
 
-        test_bed = test_bed.set_engine_state(2, EngineState::On).and_run();
+        test_bed = test_bed.idle_eng2().and_run();
 
         assert_eq!(test_bed.engine_state(1), EngineState::On);
         assert_eq!(test_bed.engine_state(2), EngineState::On);
 
-        test_bed = test_bed
-            .set_engine_state(1, EngineState::Shutting)
-            .and_run();
+        test_bed = test_bed.shut_down_eng(1, 0.3).and_run();
 
         assert_eq!(test_bed.engine_state(1), EngineState::Shutting);
         assert_eq!(test_bed.engine_state(2), EngineState::On);
 
-        test_bed = test_bed
-            .set_engine_state(2, EngineState::Shutting)
-            .and_run();
+        test_bed = test_bed.shut_down_eng(2, 0.3).and_run();
 
         assert_eq!(test_bed.engine_state(1), EngineState::Shutting);
         assert_eq!(test_bed.engine_state(2), EngineState::Shutting);
+
+        test_bed = test_bed.stop_eng1().stop_eng2().and_run();
+
+        assert_eq!(test_bed.engine_state(1), EngineState::Off);
+        assert_eq!(test_bed.engine_state(2), EngineState::Off);
+    }
+
+    #[test]
+    fn starter_valve_closes_at_starter_cutout() {
+        let mut test_bed = test_bed_with()
+            .stop_eng1()
+            .stop_eng2()
+            .set_bleed_air_running()
+            .start_engine(1)
+            .and_run();
+
+        assert!(test_bed.es_valve_is_open(1));
+
+        test_bed.write_by_name("ENGINE_N2:1", Ratio::new::<ratio>(0.52));
+        test_bed = test_bed.and_stabilize();
+
+        assert!(!test_bed.es_valve_is_open(1));
+        assert_eq!(test_bed.engine_state(1), EngineState::Restarting);
     }
 
     #[test]
@@ -4590,7 +4792,7 @@ pub mod tests {
         );
 
         // This will open the starter valve
-        test_bed = test_bed.start_eng2().and_stabilize();
+        test_bed = test_bed.start_engine(2).and_stabilize();
 
         // Check starter valve has opened
         assert!(test_bed.es_valve_is_open(2));
@@ -5217,4 +5419,116 @@ pub mod tests {
             assert!(test_bed.pack_flow_valve_flow(2) < flow_rate_tolerance());
         }
     }
+
+    mod bleed_leak_detection {
+        use super::*;
+
+        fn test_bed_at_take_off_power() -> PneumaticTestBed {
+            test_bed_with()
+                .in_isa_atmosphere(Length::new::<foot>(0.))
+                .eng1_n1(0.8)
+                .and_eng1_n2_based_on_n1()
+                .eng2_n1(0.8)
+                .and_eng2_n2_based_on_n1()
+                .and_stabilize()
+        }
+
+        #[test]
+        fn no_leak_is_indicated_without_failure() {
+            let mut test_bed = test_bed_at_take_off_power();
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(!test_bed.has_engine_leak(1));
+            assert!(!test_bed.has_engine_leak(2));
+            assert!(!test_bed.has_wing_leak(1));
+            assert!(!test_bed.has_wing_leak(2));
+            assert!(!test_bed.has_apu_leak());
+
+            assert!(!test_bed.engine_bleed_push_button_has_fault(1));
+            assert!(!test_bed.engine_bleed_push_button_has_fault(2));
+            assert!(!test_bed.apu_bleed_push_button_has_fault());
+        }
+
+        #[test]
+        fn engine_leak_closes_onside_bleed_valves() {
+            let mut test_bed = test_bed_at_take_off_power().bleed_duct_leak(BleedDuct::Engine(1));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(test_bed.has_engine_leak(1));
+            assert!(!test_bed.has_engine_leak(2));
+            assert!(test_bed.engine_bleed_push_button_has_fault(1));
+
+            assert!(!test_bed.pr_valve_is_open(1));
+            assert!(!test_bed.hp_valve_is_open(1));
+            assert!(test_bed.pr_valve_is_open(2));
+        }
+
+        #[test]
+        fn wing_leak_closes_onside_bleed_valves() {
+            let mut test_bed = test_bed_at_take_off_power().bleed_duct_leak(BleedDuct::Wing(2));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(test_bed.has_wing_leak(2));
+            assert!(!test_bed.has_wing_leak(1));
+            assert!(test_bed.engine_bleed_push_button_has_fault(2));
+            assert!(!test_bed.engine_bleed_push_button_has_fault(1));
+
+            assert!(!test_bed.pr_valve_is_open(2));
+            assert!(test_bed.pr_valve_is_open(1));
+        }
+
+        #[test]
+        fn left_wing_leak_isolates_apu_bleed() {
+            let mut test_bed = test_bed_at_take_off_power().bleed_duct_leak(BleedDuct::Wing(1));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(test_bed.apu_bleed_is_isolated());
+        }
+
+        #[test]
+        fn right_wing_leak_does_not_isolate_apu_bleed() {
+            let mut test_bed = test_bed_at_take_off_power().bleed_duct_leak(BleedDuct::Wing(2));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(!test_bed.apu_bleed_is_isolated());
+        }
+
+        #[test]
+        fn apu_leak_closes_cross_bleed_valve_and_isolates_apu_bleed() {
+            let mut test_bed = test_bed_with()
+                .in_isa_atmosphere(Length::new::<foot>(0.))
+                .stop_eng1()
+                .stop_eng2()
+                .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+                .set_bleed_air_running()
+                .and_stabilize();
+
+            assert!(test_bed.cross_bleed_valve_is_open());
+
+            test_bed = test_bed.bleed_duct_leak(BleedDuct::Apu);
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(test_bed.has_apu_leak());
+            assert!(test_bed.apu_bleed_push_button_has_fault());
+            assert!(test_bed.apu_bleed_is_isolated());
+            assert!(!test_bed.cross_bleed_valve_is_open());
+        }
+
+        #[test]
+        fn leak_remains_latched_until_engine_bleed_is_switched_off() {
+            let mut test_bed = test_bed_at_take_off_power().bleed_duct_leak(BleedDuct::Wing(1));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            test_bed.unfail(FailureType::BleedDuctLeak(BleedDuct::Wing(1)));
+            test_bed.run_multiple_frames(Duration::from_secs(60));
+
+            assert!(test_bed.has_wing_leak(1));
+            assert!(!test_bed.pr_valve_is_open(1));
+
+            test_bed = test_bed.set_engine_bleed_push_button_off(1).and_run();
+
+            assert!(!test_bed.has_wing_leak(1));
+            assert!(!test_bed.engine_bleed_push_button_has_fault(1));
+        }
+    }
 }
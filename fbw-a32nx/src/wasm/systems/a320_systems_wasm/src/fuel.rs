@@ -0,0 +1,96 @@
+use std::error::Error;
+
+use msfs::sim_connect;
+use systems_wasm::sim_connect::{SimConnect, SIMCONNECT_OBJECT_ID_USER};
+
+use systems_wasm::aspects::{MsfsAspectBuilder, ObjectWrite, VariablesToObject};
+use systems_wasm::{set_data_on_sim_object, Variable};
+
+pub(super) fn fuel(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
+    builder.copy(
+        Variable::aircraft("FUEL TANK CENTER QUANTITY", "gallons", 0),
+        Variable::aspect("FUEL_TANK_CENTER_QUANTITY_REQ"),
+    );
+    builder.copy(
+        Variable::aircraft("FUEL TANK LEFT MAIN QUANTITY", "gallons", 0),
+        Variable::aspect("FUEL_TANK_LEFT_MAIN_QUANTITY_REQ"),
+    );
+    builder.copy(
+        Variable::aircraft("FUEL TANK LEFT AUX QUANTITY", "gallons", 0),
+        Variable::aspect("FUEL_TANK_LEFT_AUX_QUANTITY_REQ"),
+    );
+    builder.copy(
+        Variable::aircraft("FUEL TANK RIGHT MAIN QUANTITY", "gallons", 0),
+        Variable::aspect("FUEL_TANK_RIGHT_MAIN_QUANTITY_REQ"),
+    );
+    builder.copy(
+        Variable::aircraft("FUEL TANK RIGHT AUX QUANTITY", "gallons", 0),
+        Variable::aspect("FUEL_TANK_RIGHT_AUX_QUANTITY_REQ"),
+    );
+
+    builder.variables_to_object(Box::new(FuelTankQuantities {
+        center: 0.,
+        left_main: 0.,
+        left_aux: 0.,
+        right_main: 0.,
+        right_aux: 0.,
+    }));
+
+    Ok(())
+}
+
+#[sim_connect::data_definition]
+struct FuelTankQuantities {
+    #[name = "FUEL TANK CENTER QUANTITY"]
+    #[unit = "gallons"]
+    center: f64,
+
+    #[name = "FUEL TANK LEFT MAIN QUANTITY"]
+    #[unit = "gallons"]
+    left_main: f64,
+
+    #[name = "FUEL TANK LEFT AUX QUANTITY"]
+    #[unit = "gallons"]
+    left_aux: f64,
+
+    #[name = "FUEL TANK RIGHT MAIN QUANTITY"]
+    #[unit = "gallons"]
+    right_main: f64,
+
+    #[name = "FUEL TANK RIGHT AUX QUANTITY"]
+    #[unit = "gallons"]
+    right_aux: f64,
+}
+
+impl VariablesToObject for FuelTankQuantities {
+    fn variables(&self) -> Vec<Variable> {
+        vec![
+            Variable::aspect("FUEL_TANK_CENTER_QUANTITY_REQ"),
+            Variable::aspect("FUEL_TANK_LEFT_MAIN_QUANTITY_REQ"),
+            Variable::aspect("FUEL_TANK_LEFT_AUX_QUANTITY_REQ"),
+            Variable::aspect("FUEL_TANK_RIGHT_MAIN_QUANTITY_REQ"),
+            Variable::aspect("FUEL_TANK_RIGHT_AUX_QUANTITY_REQ"),
+        ]
+    }
+
+    fn write(&mut self, values: Vec<f64>) -> ObjectWrite {
+        let last_written = [
+            self.center,
+            self.left_main,
+            self.left_aux,
+            self.right_main,
+            self.right_aux,
+        ];
+
+        self.center = values[0];
+        self.left_main = values[1];
+        self.left_aux = values[2];
+        self.right_main = values[3];
+        self.right_aux = values[4];
+
+        // Only write changed quantities, so fuel burnt or set by the simulator isn't overwritten
+        ObjectWrite::on(values[..] != last_written[..])
+    }
+
+    set_data_on_sim_object!();
+}
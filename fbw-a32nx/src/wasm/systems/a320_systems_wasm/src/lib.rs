@@ -3,6 +3,7 @@ mod autobrakes;
 mod brakes;
 mod elevators;
 mod flaps;
+mod fuel;
 mod gear;
 mod nose_wheel_steering;
 mod payload;
@@ -11,12 +12,13 @@ mod rudder;
 mod spoilers;
 mod trimmable_horizontal_stabilizer;
 
-use a320_systems::A320;
+use a320_systems::{failures, A320};
 use ailerons::ailerons;
 use autobrakes::autobrakes;
 use brakes::brakes;
 use elevators::elevators;
 use flaps::flaps;
+use fuel::fuel;
 use gear::gear;
 use nose_wheel_steering::nose_wheel_steering;
 use payload::payload;
@@ -24,25 +26,36 @@ use reversers::reversers;
 use rudder::rudder;
 use spoilers::spoilers;
 use std::error::Error;
-use systems::air_conditioning::{acs_controller::AcscId, Channel, ZoneType};
-use systems::failures::FailureType;
-use systems::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, GearActuatorId,
-    HydraulicColor, LgciuId, ProximityDetectorId,
-};
+use systems::shared::ElectricalBusType;
+use systems::simulation::Simulation;
 use systems_wasm::aspects::ExecuteOn;
-use systems_wasm::{MsfsSimulationBuilder, Variable};
+use systems_wasm::sim_connect::SimConnect;
+use systems_wasm::{MsfsHandler, MsfsSimulationBuilder, Variable};
 use trimmable_horizontal_stabilizer::trimmable_horizontal_stabilizer;
 
+#[cfg(target_arch = "wasm32")]
 #[msfs::gauge(name=systems)]
 async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     let mut sim_connect = gauge.open_simconnect("systems")?;
 
+    let (mut simulation, mut handler) = simulation(sim_connect.as_mut().get_mut())?;
+
+    while let Some(event) = gauge.next_event().await {
+        handler.handle(event, &mut simulation, sim_connect.as_mut().get_mut())?;
+    }
+
+    Ok(())
+}
+
+/// Builds the A320 simulation together with the handler bridging it to the simulator.
+pub fn simulation(
+    sim_connect: &mut SimConnect,
+) -> Result<(Simulation<A320>, MsfsHandler), Box<dyn Error>> {
     let key_prefix = "A32NX_";
-    let (mut simulation, mut handler) = MsfsSimulationBuilder::new(
+    MsfsSimulationBuilder::new(
         key_prefix,
         Variable::named(&format!("{}START_STATE", key_prefix)),
-        sim_connect.as_mut().get_mut(),
+        sim_connect,
     )
     .with_electrical_buses([
         (ElectricalBusType::AlternatingCurrent(1), 2),
@@ -62,222 +75,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     ])?
     .with_auxiliary_power_unit(Variable::named("OVHD_APU_START_PB_IS_AVAILABLE"), 8, 7)?
     .with_engines(2)?
-    .with_failures(vec![
-        (
-            21_000,
-            FailureType::Acsc(AcscId::Acsc1(Channel::ChannelOne)),
-        ),
-        (
-            21_001,
-            FailureType::Acsc(AcscId::Acsc1(Channel::ChannelTwo)),
-        ),
-        (
-            21_002,
-            FailureType::Acsc(AcscId::Acsc2(Channel::ChannelOne)),
-        ),
-        (
-            21_003,
-            FailureType::Acsc(AcscId::Acsc2(Channel::ChannelTwo)),
-        ),
-        (21_004, FailureType::HotAir(1)),
-        (21_005, FailureType::TrimAirHighPressure),
-        (21_006, FailureType::TrimAirFault(ZoneType::Cockpit)),
-        (21_007, FailureType::TrimAirFault(ZoneType::Cabin(1))),
-        (21_008, FailureType::TrimAirFault(ZoneType::Cabin(2))),
-        (21_009, FailureType::TrimAirOverheat(ZoneType::Cockpit)),
-        (21_010, FailureType::TrimAirOverheat(ZoneType::Cabin(1))),
-        (21_011, FailureType::TrimAirOverheat(ZoneType::Cabin(2))),
-        (21_012, FailureType::CabinFan(1)),
-        (21_013, FailureType::CabinFan(2)),
-        (21_014, FailureType::GalleyFans),
-        (24_000, FailureType::TransformerRectifier(1)),
-        (24_001, FailureType::TransformerRectifier(2)),
-        (24_002, FailureType::TransformerRectifier(3)),
-        (24_004, FailureType::StaticInverter),
-        (24_020, FailureType::Generator(1)),
-        (24_021, FailureType::Generator(2)),
-        (24_030, FailureType::ApuGenerator(1)),
-        (
-            24_100,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(1)),
-        ),
-        (
-            24_101,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(2)),
-        ),
-        (
-            24_102,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssential),
-        ),
-        (
-            24_103,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssentialShed),
-        ),
-        (
-            24_104,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentStaticInverter),
-        ),
-        (
-            24_105,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentGndFltService),
-        ),
-        (
-            24_106,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(1)),
-        ),
-        (
-            24_107,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(2)),
-        ),
-        (
-            24_108,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssential),
-        ),
-        (
-            24_109,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssentialShed),
-        ),
-        (
-            24_110,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentBattery),
-        ),
-        (
-            24_111,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(1)),
-        ),
-        (
-            24_112,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(2)),
-        ),
-        (
-            24_113,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
-        ),
-        (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
-        (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
-        (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
-        (29_003, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
-        (29_004, FailureType::ReservoirAirLeak(HydraulicColor::Blue)),
-        (
-            29_005,
-            FailureType::ReservoirAirLeak(HydraulicColor::Yellow),
-        ),
-        (
-            29_006,
-            FailureType::ReservoirReturnLeak(HydraulicColor::Green),
-        ),
-        (
-            29_007,
-            FailureType::ReservoirReturnLeak(HydraulicColor::Blue),
-        ),
-        (
-            29_008,
-            FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
-        ),
-        (
-            29_009,
-            FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Green),
-        ),
-        (
-            29_010,
-            FailureType::ElecPumpOverheat(AirbusElectricPumpId::Blue),
-        ),
-        (
-            29_011,
-            FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Yellow),
-        ),
-        (
-            29_012,
-            FailureType::ElecPumpOverheat(AirbusElectricPumpId::Yellow),
-        ),
-        (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
-        (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
-        (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
-        (32_003, FailureType::LgciuInternalError(LgciuId::Lgciu2)),
-        (
-            32_004,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearNose1),
-        ),
-        (
-            32_005,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearNose2),
-        ),
-        (
-            32_006,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearRight1),
-        ),
-        (
-            32_007,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearRight2),
-        ),
-        (
-            32_008,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearLeft2),
-        ),
-        (
-            32_009,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearLeft1),
-        ),
-        (
-            32_010,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorNose1),
-        ),
-        (
-            32_011,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorNose2),
-        ),
-        (
-            32_012,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorRight2),
-        ),
-        (
-            32_013,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorRight1),
-        ),
-        (
-            32_014,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorLeft2),
-        ),
-        (
-            32_015,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorLeft1),
-        ),
-        (
-            32_020,
-            FailureType::GearActuatorJammed(GearActuatorId::GearNose),
-        ),
-        (
-            32_021,
-            FailureType::GearActuatorJammed(GearActuatorId::GearLeft),
-        ),
-        (
-            32_022,
-            FailureType::GearActuatorJammed(GearActuatorId::GearRight),
-        ),
-        (
-            32_023,
-            FailureType::GearActuatorJammed(GearActuatorId::GearDoorNose),
-        ),
-        (
-            32_024,
-            FailureType::GearActuatorJammed(GearActuatorId::GearDoorLeft),
-        ),
-        (
-            32_025,
-            FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
-        ),
-        (
-            32_100,
-            FailureType::BrakeHydraulicLeak(HydraulicColor::Green),
-        ),
-        (
-            32_101,
-            FailureType::BrakeHydraulicLeak(HydraulicColor::Yellow),
-        ),
-        (32_150, FailureType::BrakeAccumulatorGasLeak),
-        (34_000, FailureType::RadioAltimeter(1)),
-        (34_001, FailureType::RadioAltimeter(2)),
-    ])
+    .with_failures(failures())
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Z", "feet per second squared", 0)?
@@ -302,6 +100,18 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("FUEL TANK RIGHT MAIN QUANTITY", "gallons", 0)?
     .provides_aircraft_variable("FUEL TANK RIGHT AUX QUANTITY", "gallons", 0)?
     .provides_aircraft_variable("FUEL TOTAL QUANTITY WEIGHT", "Pounds", 0)?
+    .provides_aircraft_variable("FUELSYSTEM PUMP SWITCH", "Bool", 1)?
+    .provides_aircraft_variable("FUELSYSTEM PUMP SWITCH", "Bool", 2)?
+    .provides_aircraft_variable("FUELSYSTEM PUMP SWITCH", "Bool", 3)?
+    .provides_aircraft_variable("FUELSYSTEM PUMP SWITCH", "Bool", 4)?
+    .provides_aircraft_variable("FUELSYSTEM PUMP SWITCH", "Bool", 5)?
+    .provides_aircraft_variable("FUELSYSTEM PUMP SWITCH", "Bool", 6)?
+    .provides_aircraft_variable("FUELSYSTEM PUMP SWITCH", "Bool", 7)?
+    .provides_aircraft_variable("FUELSYSTEM VALVE SWITCH", "Bool", 1)?
+    .provides_aircraft_variable("FUELSYSTEM VALVE SWITCH", "Bool", 2)?
+    .provides_aircraft_variable("FUELSYSTEM VALVE SWITCH", "Bool", 3)?
+    .provides_aircraft_variable("FUELSYSTEM VALVE SWITCH", "Bool", 9)?
+    .provides_aircraft_variable("FUELSYSTEM VALVE SWITCH", "Bool", 10)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 0)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 1)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 2)?
@@ -333,6 +143,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("PUSHBACK ANGLE", "Radians", 0)?
     .provides_aircraft_variable("SEA LEVEL PRESSURE", "Millibars", 0)?
     .provides_aircraft_variable("SIM ON GROUND", "Bool", 0)?
+    .provides_aircraft_variable("SURFACE TYPE", "Enum", 0)?
     .provides_aircraft_variable("TOTAL AIR TEMPERATURE", "celsius", 0)?
     .provides_aircraft_variable("TRAILING EDGE FLAPS LEFT PERCENT", "Percent", 0)?
     .provides_aircraft_variable("TRAILING EDGE FLAPS RIGHT PERCENT", "Percent", 0)?
@@ -348,6 +159,9 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("VELOCITY BODY Y", "feet per second", 0)?
     .provides_aircraft_variable("VELOCITY BODY Z", "feet per second", 0)?
     .provides_aircraft_variable("VELOCITY WORLD Y", "feet per minute", 0)?
+    .provides_aircraft_variable("WHEEL RPM", "RPM", 0)?
+    .provides_aircraft_variable("WHEEL RPM", "RPM", 1)?
+    .provides_aircraft_variable("WHEEL RPM", "RPM", 2)?
     .provides_aircraft_variable("INCIDENCE ALPHA", "degree", 0)?
     .provides_aircraft_variable("ROTATION VELOCITY BODY X", "degree per second", 0)?
     .provides_aircraft_variable("ROTATION VELOCITY BODY Y", "degree per second", 0)?
@@ -430,13 +244,13 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .with_aspect(reversers)?
     .with_aspect(rudder)?
     .with_aspect(gear)?
+    .with_aspect(fuel)?
     .with_aspect(payload)?
     .with_aspect(trimmable_horizontal_stabilizer)?
-    .build(A320::new)?;
-
-    while let Some(event) = gauge.next_event().await {
-        handler.handle(event, &mut simulation, sim_connect.as_mut().get_mut())?;
-    }
-
-    Ok(())
+    .with_persistent_state("\\work/systems_persistent_state.dat")
+    .with_flight_trace(
+        "\\work/systems_flight_trace.dat",
+        Variable::named(&format!("{}SYSTEMS_FLIGHT_TRACE_ENABLED", key_prefix)),
+    )
+    .build(A320::new)
 }
@@ -1,7 +1,7 @@
 use std::error::Error;
 
 use msfs::sim_connect;
-use msfs::{sim_connect::SimConnect, sim_connect::SIMCONNECT_OBJECT_ID_USER};
+use systems_wasm::sim_connect::{SimConnect, SIMCONNECT_OBJECT_ID_USER};
 
 use systems_wasm::aspects::{MsfsAspectBuilder, ObjectWrite, VariablesToObject};
 use systems_wasm::{set_data_on_sim_object, Variable};
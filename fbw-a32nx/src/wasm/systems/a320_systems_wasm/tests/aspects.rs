@@ -0,0 +1,87 @@
+use a320_systems::A320;
+use std::time::Duration;
+use systems_wasm::headless::HeadlessSimulator;
+
+fn simulator() -> HeadlessSimulator<A320> {
+    HeadlessSimulator::new(a320_systems_wasm::simulation).unwrap()
+}
+
+fn tick(simulator: &mut HeadlessSimulator<A320>) {
+    simulator.tick(Duration::from_millis(50)).unwrap();
+}
+
+#[test]
+fn parking_brakes_event_toggles_the_park_brake_lever() {
+    let mut simulator = simulator();
+    tick(&mut simulator);
+    let initial_position = simulator.read_named_variable("A32NX_PARK_BRAKE_LEVER_POS");
+
+    simulator.send_event("PARKING_BRAKES", 0).unwrap();
+    tick(&mut simulator);
+    assert_ne!(
+        simulator.read_named_variable("A32NX_PARK_BRAKE_LEVER_POS"),
+        initial_position
+    );
+
+    simulator.send_event("PARKING_BRAKES", 0).unwrap();
+    tick(&mut simulator);
+    assert_eq!(
+        simulator.read_named_variable("A32NX_PARK_BRAKE_LEVER_POS"),
+        initial_position
+    );
+}
+
+#[test]
+fn brake_events_are_masked() {
+    let simulator = simulator();
+
+    assert!(simulator.event_is_masked("PARKING_BRAKES"));
+    assert!(simulator.event_is_masked("AXIS_LEFT_BRAKE_SET"));
+    assert!(simulator.event_is_masked("AXIS_RIGHT_BRAKE_SET"));
+}
+
+#[test]
+fn brake_force_is_transmitted_through_the_axis_brake_events_every_tick() {
+    let mut simulator = simulator();
+    tick(&mut simulator);
+
+    let events = simulator.take_transmitted_events();
+    assert!(events.iter().any(|(name, _)| name == "AXIS_LEFT_BRAKE_SET"));
+    assert!(events
+        .iter()
+        .any(|(name, _)| name == "AXIS_RIGHT_BRAKE_SET"));
+}
+
+#[test]
+fn sending_an_unmapped_event_is_an_error() {
+    let mut simulator = simulator();
+
+    assert!(simulator.send_event("NOT_AN_EVENT", 0).is_err());
+}
+
+#[test]
+fn activated_failure_is_reflected_in_its_state_variable() {
+    let mut simulator = simulator();
+    tick(&mut simulator);
+    assert_eq!(
+        simulator.read_named_variable("A32NX_FAILURE_STATE_21000"),
+        0.
+    );
+
+    simulator.write_named_variable("A32NX_FAILURE_ACTIVATE", 21_000.);
+    tick(&mut simulator);
+
+    assert_eq!(
+        simulator.read_named_variable("A32NX_FAILURE_STATE_21000"),
+        2.
+    );
+}
+
+#[test]
+fn gear_position_is_set_on_the_sim_object() {
+    let mut simulator = simulator();
+    tick(&mut simulator);
+    tick(&mut simulator);
+
+    assert_eq!(simulator.data_on_sim_object_writes("GearPosition"), 2);
+}
@@ -36,8 +36,7 @@ impl A380Airframe {
         self.center_of_gravity.zero_fuel_weight_center_of_gravity()
     }
 
-    #[cfg(test)]
-    fn gross_weight_center_of_gravity(&self) -> f64 {
+    pub(crate) fn gross_weight_center_of_gravity(&self) -> f64 {
         self.center_of_gravity.gross_weight_center_of_gravity()
     }
 
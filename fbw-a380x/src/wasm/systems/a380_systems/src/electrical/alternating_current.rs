@@ -13,6 +13,7 @@ use systems::{
     },
     shared::{
         AuxiliaryPowerUnitElectrical, ElectricalBusType, EngineCorrectedN2, EngineFirePushButtons,
+        PotentialOrigin,
     },
     simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
 };
@@ -206,6 +207,15 @@ impl A380AlternatingCurrentElectrical {
         self.main_power_sources.gen_contactor_open(number)
     }
 
+    pub fn idg_fault(&self, number: usize) -> bool {
+        self.main_power_sources.idg_fault(number)
+    }
+
+    /// The engine and APU generators connected to the AC buses.
+    pub fn connected_generators(&self) -> Vec<PotentialOrigin> {
+        self.main_power_sources.connected_generators()
+    }
+
     pub fn emergency_generator_contactor_is_closed(&self) -> bool {
         self.emergency_gen_contactor.is_closed()
     }
@@ -611,6 +621,27 @@ impl A380MainPowerSources {
     pub fn gen_contactor_open(&self, number: usize) -> bool {
         self.engine_generator_contactors[number - 1].is_open()
     }
+
+    fn idg_fault(&self, number: usize) -> bool {
+        self.engine_gens[number - 1].idg_fault()
+    }
+
+    fn connected_generators(&self) -> Vec<PotentialOrigin> {
+        let apu_generators = self
+            .apu_gen_contactors
+            .iter()
+            .enumerate()
+            .map(|(index, contactor)| (contactor, PotentialOrigin::ApuGenerator(index + 1)));
+
+        self.engine_generator_contactors
+            .iter()
+            .enumerate()
+            .map(|(index, contactor)| (contactor, PotentialOrigin::EngineGenerator(index + 1)))
+            .chain(apu_generators)
+            .filter(|(contactor, _)| contactor.is_closed())
+            .map(|(_, origin)| origin)
+            .collect()
+    }
 }
 impl SimulationElement for A380MainPowerSources {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -9,7 +9,7 @@ use self::{
 };
 pub(super) use direct_current::APU_START_MOTOR_BUS_TYPE;
 
-use uom::si::{angular_velocity::revolution_per_minute, f64::*, ratio::percent};
+use uom::si::{angular_velocity::revolution_per_minute, f64::*, power::watt, ratio::percent};
 
 #[cfg(test)]
 use systems::electrical::{Battery, BatteryChargeRectifierUnit};
@@ -20,7 +20,8 @@ use systems::{
     electrical::{
         AlternatingCurrentElectricalSystem, BatteryPushButtons, ElectricalElement, Electricity,
         EmergencyElectrical, EmergencyGenerator, EngineGeneratorPushButtons, ExternalPowerSource,
-        GeneratorControlUnit, RamAirTurbine, StaticInverter, TransformerRectifier,
+        GeneratorControlUnit, LoadShedGroup, LoadShedManager, RamAirTurbine, StaticInverter,
+        TransformerRectifier,
     },
     overhead::{
         AutoOffFaultPushButton, FaultIndication, FaultReleasePushButton, MomentaryPushButton,
@@ -30,7 +31,7 @@ use systems::{
         update_iterator::MaxStepLoop, AdirsDiscreteOutputs, AuxiliaryPowerUnitElectrical,
         ElectricalBusType, ElectricalBuses, EmergencyElectricalRatPushButton,
         EmergencyElectricalState, EngineCorrectedN2, EngineFirePushButtons, LgciuWeightOnWheels,
-        RamAirTurbineController,
+        PotentialOrigin, RamAirTurbineController,
     },
     simulation::{
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
@@ -46,6 +47,7 @@ pub(super) struct A380Electrical {
     direct_current: A380DirectCurrentElectrical,
     main_galley: MainGalley,
     secondary_galley: SecondaryGalley,
+    load_shed_manager: LoadShedManager,
     emergency_elec: EmergencyElectrical,
     emergency_gen: EmergencyGenerator,
 
@@ -66,6 +68,13 @@ impl A380Electrical {
 
     const RAT_SIM_TIME_STEP: Duration = Duration::from_millis(33);
 
+    // Ratings are given at a power factor of 0.8.
+    const ENGINE_GENERATOR_RATING_WATT: f64 = 90000. / 0.8;
+    const APU_GENERATOR_RATING_WATT: f64 = 120000. / 0.8;
+    const GALLEY_DEMAND_WATT: f64 = 60000.;
+    const IN_FLIGHT_ENTERTAINMENT_DEMAND_WATT: f64 = 15000.;
+    const CABIN_DEMAND_WATT: f64 = 10000.;
+
     pub fn new(context: &mut InitContext) -> A380Electrical {
         A380Electrical {
             galley_is_shed_id: context.get_identifier("ELEC_GALLEY_IS_SHED".to_owned()),
@@ -73,6 +82,49 @@ impl A380Electrical {
             direct_current: A380DirectCurrentElectrical::new(context),
             main_galley: MainGalley::new(),
             secondary_galley: SecondaryGalley::new(),
+            load_shed_manager: LoadShedManager::new(
+                context,
+                &[
+                    (
+                        PotentialOrigin::EngineGenerator(1),
+                        Power::new::<watt>(Self::ENGINE_GENERATOR_RATING_WATT),
+                    ),
+                    (
+                        PotentialOrigin::EngineGenerator(2),
+                        Power::new::<watt>(Self::ENGINE_GENERATOR_RATING_WATT),
+                    ),
+                    (
+                        PotentialOrigin::EngineGenerator(3),
+                        Power::new::<watt>(Self::ENGINE_GENERATOR_RATING_WATT),
+                    ),
+                    (
+                        PotentialOrigin::EngineGenerator(4),
+                        Power::new::<watt>(Self::ENGINE_GENERATOR_RATING_WATT),
+                    ),
+                    (
+                        PotentialOrigin::ApuGenerator(1),
+                        Power::new::<watt>(Self::APU_GENERATOR_RATING_WATT),
+                    ),
+                    (
+                        PotentialOrigin::ApuGenerator(2),
+                        Power::new::<watt>(Self::APU_GENERATOR_RATING_WATT),
+                    ),
+                ],
+                &[
+                    (
+                        LoadShedGroup::Galley,
+                        Power::new::<watt>(Self::GALLEY_DEMAND_WATT),
+                    ),
+                    (
+                        LoadShedGroup::InFlightEntertainment,
+                        Power::new::<watt>(Self::IN_FLIGHT_ENTERTAINMENT_DEMAND_WATT),
+                    ),
+                    (
+                        LoadShedGroup::Cabin,
+                        Power::new::<watt>(Self::CABIN_DEMAND_WATT),
+                    ),
+                ],
+            ),
             emergency_elec: EmergencyElectrical::new(),
             emergency_gen: EmergencyGenerator::new(
                 context,
@@ -167,6 +219,8 @@ impl A380Electrical {
             .update(electricity, &self.alternating_current, overhead);
         self.secondary_galley
             .update(electricity, &self.alternating_current, overhead);
+        self.load_shed_manager
+            .update(context, &self.alternating_current.connected_generators());
 
         // Update relay states
         let dc_ess_powered =
@@ -205,7 +259,9 @@ impl A380Electrical {
     }
 
     fn galley_is_shed(&self) -> bool {
-        self.main_galley.is_shed() || self.secondary_galley.is_shed()
+        self.main_galley.is_shed()
+            || self.secondary_galley.is_shed()
+            || self.load_shed_manager.is_shed(LoadShedGroup::Galley)
     }
 
     #[cfg(test)]
@@ -272,6 +328,10 @@ impl A380Electrical {
         self.alternating_current.gen_contactor_open(number)
     }
 
+    pub fn idg_fault(&self, number: usize) -> bool {
+        self.alternating_current.idg_fault(number)
+    }
+
     pub fn in_emergency_elec(&self) -> bool {
         self.emergency_elec.is_in_emergency_elec()
     }
@@ -283,6 +343,7 @@ impl SimulationElement for A380Electrical {
         self.emergency_gen.accept(visitor);
         self.ram_air_turbine.accept(visitor);
         self.rat_controller.accept(visitor);
+        self.load_shed_manager.accept(visitor);
 
         visitor.visit(self);
     }
@@ -356,6 +417,10 @@ impl A380ElectricalOverheadPanel {
             .for_each(|(index, gen)| {
                 gen.set_fault(electrical.gen_contactor_open(index + 1) && gen.is_on());
             });
+
+        self.idgs.iter_mut().enumerate().for_each(|(index, idg)| {
+            idg.set_fault(electrical.idg_fault(index + 1));
+        });
     }
 
     pub fn external_power_is_available(&self, number: usize) -> bool {
@@ -543,9 +608,10 @@ mod a380_electrical_circuit_tests {
     use systems::{
         apu::ApuGenerator,
         electrical::{
-            ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-            Electricity, ElectricitySource, ExternalPowerSource, Potential, ProvideFrequency,
-            ProvidePotential, INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
+            consumption::PowerConsumer, ElectricalElement, ElectricalElementIdentifier,
+            ElectricalElementIdentifierProvider, Electricity, ElectricitySource,
+            ExternalPowerSource, Potential, ProvideFrequency, ProvidePotential,
+            INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
         },
         failures::FailureType,
         shared::{
@@ -560,7 +626,7 @@ mod a380_electrical_circuit_tests {
 
     use uom::si::{
         angular_velocity::revolution_per_minute, electric_potential::volt, frequency::hertz,
-        length::foot, mass_density::slug_per_cubic_foot, ratio::percent,
+        length::foot, mass_density::slug_per_cubic_foot, power::watt, ratio::percent,
         thermodynamic_temperature::degree_celsius, velocity::knot,
     };
 
@@ -2525,8 +2591,17 @@ mod a380_electrical_circuit_tests {
     }
 
     #[test]
-    #[ignore = "Generator overloading is not yet supported."]
-    fn when_aircraft_on_the_ground_and_apu_gen_is_overloaded_galley_is_shed() {}
+    fn when_aircraft_on_the_ground_and_apu_gen_is_overloaded_galley_is_shed() {
+        let mut test_bed = test_bed_with()
+            .running_apu()
+            .and()
+            .on_the_ground()
+            .power_demand_of(Power::new::<watt>(200000.))
+            .run()
+            .run_waiting_for(Duration::from_secs(10));
+
+        assert!(test_bed.galley_is_shed());
+    }
 
     #[rstest]
     #[case(1)]
@@ -3043,6 +3118,7 @@ mod a380_electrical_circuit_tests {
         apu_overhead: TestApuOverhead,
         engine_fire_push_buttons: TestEngineFirePushButtons,
         emergency_generator: TestEmergencyGenerator,
+        power_consumer: PowerConsumer,
         force_run_emergency_gen: bool,
     }
     impl A380ElectricalTestAircraft {
@@ -3057,10 +3133,15 @@ mod a380_electrical_circuit_tests {
                 apu_overhead: TestApuOverhead::new(),
                 engine_fire_push_buttons: TestEngineFirePushButtons::new(),
                 emergency_generator: TestEmergencyGenerator::new(),
+                power_consumer: PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1)),
                 force_run_emergency_gen: false,
             }
         }
 
+        fn set_power_demand(&mut self, power: Power) {
+            self.power_consumer.demand(power);
+        }
+
         fn running_engine(&mut self, number: usize) {
             self.engines[number - 1].run();
         }
@@ -3188,6 +3269,7 @@ mod a380_electrical_circuit_tests {
             self.overhead.accept(visitor);
             self.emergency_overhead.accept(visitor);
             self.apu.accept(visitor);
+            self.power_consumer.accept(visitor);
 
             visitor.visit(self);
         }
@@ -3237,6 +3319,11 @@ mod a380_electrical_circuit_tests {
             self
         }
 
+        fn power_demand_of(mut self, power: Power) -> Self {
+            self.command(|a| a.set_power_demand(power));
+            self
+        }
+
         fn connected_external_power(mut self) -> Self {
             for i in 1..=4 {
                 self.write_by_name(&format!("EXTERNAL POWER AVAILABLE:{i}"), true);
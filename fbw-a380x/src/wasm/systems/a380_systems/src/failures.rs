@@ -0,0 +1,726 @@
+use systems::{
+    failures::FailureType,
+    pneumatic::BleedDuct,
+    shared::{
+        ElectricalBusType, FlightControlActuatorId, GearActuatorId, HydraulicColor, LgciuId,
+        ProximityDetectorId,
+    },
+};
+
+/// The failures of the A380, together with the identifiers the simulator uses to
+/// activate, deactivate, arm and disarm them.
+pub fn failures() -> Vec<(u64, FailureType)> {
+    vec![
+        (24_000, FailureType::TransformerRectifier(1)),
+        (24_001, FailureType::TransformerRectifier(2)),
+        (24_002, FailureType::TransformerRectifier(3)),
+        (24_004, FailureType::StaticInverter),
+        (24_020, FailureType::Generator(1)),
+        (24_021, FailureType::Generator(2)),
+        (24_022, FailureType::Generator(3)),
+        (24_023, FailureType::Generator(4)),
+        (24_030, FailureType::ApuGenerator(1)),
+        (24_031, FailureType::ApuGenerator(2)),
+        (24_040, FailureType::BatteryThermalRunaway(1)),
+        (24_041, FailureType::BatteryThermalRunaway(2)),
+        (24_042, FailureType::BatteryThermalRunaway(3)),
+        (24_043, FailureType::BatteryThermalRunaway(4)),
+        (24_050, FailureType::GeneratorVoltageRegulator(1)),
+        (24_051, FailureType::GeneratorVoltageRegulator(2)),
+        (24_052, FailureType::GeneratorVoltageRegulator(3)),
+        (24_053, FailureType::GeneratorVoltageRegulator(4)),
+        (24_060, FailureType::IdgConstantSpeedDrive(1)),
+        (24_061, FailureType::IdgConstantSpeedDrive(2)),
+        (24_062, FailureType::IdgConstantSpeedDrive(3)),
+        (24_063, FailureType::IdgConstantSpeedDrive(4)),
+        (
+            24_100,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(1)),
+        ),
+        (
+            24_101,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(2)),
+        ),
+        (
+            24_102,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(3)),
+        ),
+        (
+            24_103,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(4)),
+        ),
+        (
+            24_104,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssential),
+        ),
+        (
+            24_105,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssentialShed),
+        ),
+        (
+            24_106,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentNamed("247XP")),
+        ),
+        (
+            24_107,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentGndFltService),
+        ),
+        (
+            24_108,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(1)),
+        ),
+        (
+            24_109,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(2)),
+        ),
+        (
+            24_110,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssential),
+        ),
+        (
+            24_111,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentNamed("247PP")),
+        ),
+        (
+            24_112,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentNamed("309PP")),
+        ),
+        (
+            24_113,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(1)),
+        ),
+        (
+            24_114,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(2)),
+        ),
+        (
+            24_115,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(3)),
+        ),
+        (
+            24_116,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(4)),
+        ),
+        (
+            24_117,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
+        ),
+        (
+            27_100,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftAileron(1)),
+        ),
+        (
+            27_101,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftAileron(2)),
+        ),
+        (
+            27_102,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftAileron(3)),
+        ),
+        (
+            27_103,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftAileron(4)),
+        ),
+        (
+            27_104,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftAileron(5)),
+        ),
+        (
+            27_105,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftAileron(6)),
+        ),
+        (
+            27_106,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightAileron(1)),
+        ),
+        (
+            27_107,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightAileron(2)),
+        ),
+        (
+            27_108,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightAileron(3)),
+        ),
+        (
+            27_109,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightAileron(4)),
+        ),
+        (
+            27_110,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightAileron(5)),
+        ),
+        (
+            27_111,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightAileron(6)),
+        ),
+        (
+            27_112,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftElevator(1)),
+        ),
+        (
+            27_113,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftElevator(2)),
+        ),
+        (
+            27_114,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftElevator(3)),
+        ),
+        (
+            27_115,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftElevator(4)),
+        ),
+        (
+            27_116,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightElevator(1)),
+        ),
+        (
+            27_117,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightElevator(2)),
+        ),
+        (
+            27_118,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightElevator(3)),
+        ),
+        (
+            27_119,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightElevator(4)),
+        ),
+        (
+            27_120,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::Rudder(1)),
+        ),
+        (
+            27_121,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::Rudder(2)),
+        ),
+        (
+            27_122,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::Rudder(3)),
+        ),
+        (
+            27_123,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::Rudder(4)),
+        ),
+        (
+            27_124,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftSpoiler(1)),
+        ),
+        (
+            27_125,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftSpoiler(2)),
+        ),
+        (
+            27_126,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftSpoiler(3)),
+        ),
+        (
+            27_127,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftSpoiler(4)),
+        ),
+        (
+            27_128,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftSpoiler(5)),
+        ),
+        (
+            27_129,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftSpoiler(6)),
+        ),
+        (
+            27_130,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftSpoiler(7)),
+        ),
+        (
+            27_131,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::LeftSpoiler(8)),
+        ),
+        (
+            27_132,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightSpoiler(1)),
+        ),
+        (
+            27_133,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightSpoiler(2)),
+        ),
+        (
+            27_134,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightSpoiler(3)),
+        ),
+        (
+            27_135,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightSpoiler(4)),
+        ),
+        (
+            27_136,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightSpoiler(5)),
+        ),
+        (
+            27_137,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightSpoiler(6)),
+        ),
+        (
+            27_138,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightSpoiler(7)),
+        ),
+        (
+            27_139,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::RightSpoiler(8)),
+        ),
+        (
+            27_200,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftAileron(1)),
+        ),
+        (
+            27_201,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftAileron(2)),
+        ),
+        (
+            27_202,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftAileron(3)),
+        ),
+        (
+            27_203,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftAileron(4)),
+        ),
+        (
+            27_204,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftAileron(5)),
+        ),
+        (
+            27_205,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftAileron(6)),
+        ),
+        (
+            27_206,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightAileron(1)),
+        ),
+        (
+            27_207,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightAileron(2)),
+        ),
+        (
+            27_208,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightAileron(3)),
+        ),
+        (
+            27_209,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightAileron(4)),
+        ),
+        (
+            27_210,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightAileron(5)),
+        ),
+        (
+            27_211,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightAileron(6)),
+        ),
+        (
+            27_212,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftElevator(1)),
+        ),
+        (
+            27_213,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftElevator(2)),
+        ),
+        (
+            27_214,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftElevator(3)),
+        ),
+        (
+            27_215,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftElevator(4)),
+        ),
+        (
+            27_216,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightElevator(1)),
+        ),
+        (
+            27_217,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightElevator(2)),
+        ),
+        (
+            27_218,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightElevator(3)),
+        ),
+        (
+            27_219,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightElevator(4)),
+        ),
+        (
+            27_220,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::Rudder(1)),
+        ),
+        (
+            27_221,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::Rudder(2)),
+        ),
+        (
+            27_222,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::Rudder(3)),
+        ),
+        (
+            27_223,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::Rudder(4)),
+        ),
+        (
+            27_224,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftSpoiler(1)),
+        ),
+        (
+            27_225,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftSpoiler(2)),
+        ),
+        (
+            27_226,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftSpoiler(3)),
+        ),
+        (
+            27_227,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftSpoiler(4)),
+        ),
+        (
+            27_228,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftSpoiler(5)),
+        ),
+        (
+            27_229,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftSpoiler(6)),
+        ),
+        (
+            27_230,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftSpoiler(7)),
+        ),
+        (
+            27_231,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::LeftSpoiler(8)),
+        ),
+        (
+            27_232,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightSpoiler(1)),
+        ),
+        (
+            27_233,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightSpoiler(2)),
+        ),
+        (
+            27_234,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightSpoiler(3)),
+        ),
+        (
+            27_235,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightSpoiler(4)),
+        ),
+        (
+            27_236,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightSpoiler(5)),
+        ),
+        (
+            27_237,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightSpoiler(6)),
+        ),
+        (
+            27_238,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightSpoiler(7)),
+        ),
+        (
+            27_239,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::RightSpoiler(8)),
+        ),
+        (
+            27_300,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftAileron(1)),
+        ),
+        (
+            27_301,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftAileron(2)),
+        ),
+        (
+            27_302,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftAileron(3)),
+        ),
+        (
+            27_303,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftAileron(4)),
+        ),
+        (
+            27_304,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftAileron(5)),
+        ),
+        (
+            27_305,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftAileron(6)),
+        ),
+        (
+            27_306,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightAileron(1)),
+        ),
+        (
+            27_307,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightAileron(2)),
+        ),
+        (
+            27_308,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightAileron(3)),
+        ),
+        (
+            27_309,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightAileron(4)),
+        ),
+        (
+            27_310,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightAileron(5)),
+        ),
+        (
+            27_311,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightAileron(6)),
+        ),
+        (
+            27_312,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftElevator(1)),
+        ),
+        (
+            27_313,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftElevator(2)),
+        ),
+        (
+            27_314,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftElevator(3)),
+        ),
+        (
+            27_315,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftElevator(4)),
+        ),
+        (
+            27_316,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightElevator(1)),
+        ),
+        (
+            27_317,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightElevator(2)),
+        ),
+        (
+            27_318,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightElevator(3)),
+        ),
+        (
+            27_319,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightElevator(4)),
+        ),
+        (
+            27_320,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::Rudder(1)),
+        ),
+        (
+            27_321,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::Rudder(2)),
+        ),
+        (
+            27_322,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::Rudder(3)),
+        ),
+        (
+            27_323,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::Rudder(4)),
+        ),
+        (
+            27_324,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftSpoiler(1)),
+        ),
+        (
+            27_325,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftSpoiler(2)),
+        ),
+        (
+            27_326,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftSpoiler(3)),
+        ),
+        (
+            27_327,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftSpoiler(4)),
+        ),
+        (
+            27_328,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftSpoiler(5)),
+        ),
+        (
+            27_329,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftSpoiler(6)),
+        ),
+        (
+            27_330,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftSpoiler(7)),
+        ),
+        (
+            27_331,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::LeftSpoiler(8)),
+        ),
+        (
+            27_332,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightSpoiler(1)),
+        ),
+        (
+            27_333,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightSpoiler(2)),
+        ),
+        (
+            27_334,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightSpoiler(3)),
+        ),
+        (
+            27_335,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightSpoiler(4)),
+        ),
+        (
+            27_336,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightSpoiler(5)),
+        ),
+        (
+            27_337,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightSpoiler(6)),
+        ),
+        (
+            27_338,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightSpoiler(7)),
+        ),
+        (
+            27_339,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::RightSpoiler(8)),
+        ),
+        (28_000, FailureType::FuelPump(1)),
+        (28_001, FailureType::FuelPump(2)),
+        (28_002, FailureType::FuelPump(3)),
+        (28_003, FailureType::FuelPump(4)),
+        (28_004, FailureType::FuelPump(5)),
+        (28_005, FailureType::FuelPump(6)),
+        (28_006, FailureType::FuelPump(7)),
+        (28_007, FailureType::FuelPump(8)),
+        (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
+        (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
+        (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
+        (29_003, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
+        (29_004, FailureType::ReservoirAirLeak(HydraulicColor::Blue)),
+        (
+            29_005,
+            FailureType::ReservoirAirLeak(HydraulicColor::Yellow),
+        ),
+        (
+            29_006,
+            FailureType::ReservoirReturnLeak(HydraulicColor::Green),
+        ),
+        (
+            29_007,
+            FailureType::ReservoirReturnLeak(HydraulicColor::Blue),
+        ),
+        (
+            29_008,
+            FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
+        ),
+        (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
+        (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
+        (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
+        (32_003, FailureType::LgciuInternalError(LgciuId::Lgciu2)),
+        (
+            32_004,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearNose1),
+        ),
+        (
+            32_005,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearNose2),
+        ),
+        (
+            32_006,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearRight1),
+        ),
+        (
+            32_007,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearRight2),
+        ),
+        (
+            32_008,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearLeft2),
+        ),
+        (
+            32_009,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearLeft1),
+        ),
+        (
+            32_010,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorNose1),
+        ),
+        (
+            32_011,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorNose2),
+        ),
+        (
+            32_012,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorRight2),
+        ),
+        (
+            32_013,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorRight1),
+        ),
+        (
+            32_014,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorLeft2),
+        ),
+        (
+            32_015,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorLeft1),
+        ),
+        (
+            32_020,
+            FailureType::GearActuatorJammed(GearActuatorId::GearNose),
+        ),
+        (
+            32_021,
+            FailureType::GearActuatorJammed(GearActuatorId::GearLeft),
+        ),
+        (
+            32_022,
+            FailureType::GearActuatorJammed(GearActuatorId::GearRight),
+        ),
+        (
+            32_023,
+            FailureType::GearActuatorJammed(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_024,
+            FailureType::GearActuatorJammed(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_025,
+            FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
+        ),
+        (32_220, FailureType::WheelSpeedSensor(1)),
+        (32_221, FailureType::WheelSpeedSensor(2)),
+        (32_222, FailureType::WheelSpeedSensor(3)),
+        (32_223, FailureType::WheelSpeedSensor(4)),
+        (32_230, FailureType::AntiSkid),
+        (34_000, FailureType::RadioAltimeter(1)),
+        (34_001, FailureType::RadioAltimeter(2)),
+        (34_002, FailureType::RadioAltimeter(3)),
+        (34_010, FailureType::AdrFault(1)),
+        (34_011, FailureType::AdrFault(2)),
+        (34_012, FailureType::AdrFault(3)),
+        (34_020, FailureType::IrFault(1)),
+        (34_021, FailureType::IrFault(2)),
+        (34_022, FailureType::IrFault(3)),
+        (34_030, FailureType::PitotBlockage(1)),
+        (34_031, FailureType::PitotBlockage(2)),
+        (34_032, FailureType::PitotBlockage(3)),
+        (34_040, FailureType::StaticPortBlockage(1)),
+        (34_041, FailureType::StaticPortBlockage(2)),
+        (34_042, FailureType::StaticPortBlockage(3)),
+        (34_050, FailureType::AngleOfAttackVaneJam(1)),
+        (34_051, FailureType::AngleOfAttackVaneJam(2)),
+        (34_052, FailureType::AngleOfAttackVaneJam(3)),
+        (34_060, FailureType::GpsReceiver(1)),
+        (34_061, FailureType::GpsReceiver(2)),
+        (36_000, FailureType::BleedDuctLeak(BleedDuct::Engine(1))),
+        (36_001, FailureType::BleedDuctLeak(BleedDuct::Engine(2))),
+        (36_002, FailureType::BleedDuctLeak(BleedDuct::Engine(3))),
+        (36_003, FailureType::BleedDuctLeak(BleedDuct::Engine(4))),
+        (36_004, FailureType::BleedDuctLeak(BleedDuct::Wing(1))),
+        (36_005, FailureType::BleedDuctLeak(BleedDuct::Wing(2))),
+        (36_006, FailureType::BleedDuctLeak(BleedDuct::Apu)),
+    ]
+}
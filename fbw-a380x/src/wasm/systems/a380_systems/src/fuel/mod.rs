@@ -1,11 +1,17 @@
-// Note: Fuel system for now is still handled in MSFS. This is used for calculating fuel-related factors.
+// Note: Fuel burn is still handled by MSFS. The transfers between the tanks performed by the
+// fuel quantity and management system (FQMS) are simulated here.
 
 use nalgebra::Vector3;
 use systems::{
-    fuel::{FuelCG, FuelInfo, FuelPayload, FuelSystem, FuelTank},
-    simulation::{InitContext, SimulationElement, SimulationElementVisitor},
+    accept_iterable,
+    fuel::{FuelCG, FuelInfo, FuelPayload, FuelPump, FuelSystem, FuelTank, FUEL_GALLONS_TO_KG},
+    shared::ElectricalBusType,
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
 };
-use uom::si::f64::*;
+use uom::si::{f64::*, length::foot, mass::kilogram, mass_rate::kilogram_per_second};
 
 #[cfg(test)]
 mod test;
@@ -24,6 +30,7 @@ pub trait FuelLevel {
     fn trim_tank_has_fuel(&self) -> bool;
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum A380FuelTankType {
     LeftOuter,
     FeedOne,
@@ -37,12 +44,139 @@ pub enum A380FuelTankType {
     RightOuter,
     Trim,
 }
+impl From<A380FuelTankType> for usize {
+    fn from(value: A380FuelTankType) -> Self {
+        value as usize
+    }
+}
+
+/// The transfer pumps of the A380. Each wing transfer tank has a single transfer pump
+/// in this model, the trim tank has two.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum A380FuelTransferPump {
+    LeftOuter,
+    LeftMid,
+    LeftInner,
+    RightInner,
+    RightMid,
+    RightOuter,
+    TrimLeft,
+    TrimRight,
+}
+impl A380FuelTransferPump {
+    const ALL: [A380FuelTransferPump; 8] = [
+        A380FuelTransferPump::LeftOuter,
+        A380FuelTransferPump::LeftMid,
+        A380FuelTransferPump::LeftInner,
+        A380FuelTransferPump::RightInner,
+        A380FuelTransferPump::RightMid,
+        A380FuelTransferPump::RightOuter,
+        A380FuelTransferPump::TrimLeft,
+        A380FuelTransferPump::TrimRight,
+    ];
+
+    fn number(self) -> usize {
+        self as usize + 1
+    }
+
+    fn powered_by(self) -> ElectricalBusType {
+        match self {
+            A380FuelTransferPump::LeftOuter | A380FuelTransferPump::LeftMid => {
+                ElectricalBusType::AlternatingCurrent(1)
+            }
+            A380FuelTransferPump::LeftInner | A380FuelTransferPump::TrimLeft => {
+                ElectricalBusType::AlternatingCurrent(2)
+            }
+            A380FuelTransferPump::RightInner | A380FuelTransferPump::TrimRight => {
+                ElectricalBusType::AlternatingCurrent(3)
+            }
+            A380FuelTransferPump::RightMid | A380FuelTransferPump::RightOuter => {
+                ElectricalBusType::AlternatingCurrent(4)
+            }
+        }
+    }
+
+    fn tank(self) -> A380FuelTankType {
+        match self {
+            A380FuelTransferPump::LeftOuter => A380FuelTankType::LeftOuter,
+            A380FuelTransferPump::LeftMid => A380FuelTankType::LeftMid,
+            A380FuelTransferPump::LeftInner => A380FuelTankType::LeftInner,
+            A380FuelTransferPump::RightInner => A380FuelTankType::RightInner,
+            A380FuelTransferPump::RightMid => A380FuelTankType::RightMid,
+            A380FuelTransferPump::RightOuter => A380FuelTankType::RightOuter,
+            A380FuelTransferPump::TrimLeft | A380FuelTransferPump::TrimRight => {
+                A380FuelTankType::Trim
+            }
+        }
+    }
+}
+
+/// A wing side of the aircraft, containing two feed tanks and three transfer tanks.
+struct A380FuelWingSide {
+    feed_tanks: [A380FuelTankType; 2],
+    // Ordered by transfer priority.
+    transfer_pumps: [A380FuelTransferPump; 3],
+}
 
 pub struct A380Fuel {
     fuel_system: FuelSystem<11>,
+
+    feed_tank_transfer_is_active_ids: [VariableIdentifier; 4],
+    trim_tank_aft_transfer_is_active_id: VariableIdentifier,
+    trim_tank_forward_transfer_is_active_id: VariableIdentifier,
+
+    transfer_pumps: [FuelPump; 8],
+    transfer_pump_commands: [bool; 8],
+    feed_tank_transfer_is_active: [bool; 4],
+    trim_tank_aft_transfer_is_active: bool,
+    trim_tank_forward_transfer_is_active: bool,
 }
 
 impl A380Fuel {
+    const WING_SIDES: [A380FuelWingSide; 2] = [
+        A380FuelWingSide {
+            feed_tanks: [A380FuelTankType::FeedOne, A380FuelTankType::FeedTwo],
+            transfer_pumps: [
+                A380FuelTransferPump::LeftInner,
+                A380FuelTransferPump::LeftMid,
+                A380FuelTransferPump::LeftOuter,
+            ],
+        },
+        A380FuelWingSide {
+            feed_tanks: [A380FuelTankType::FeedFour, A380FuelTankType::FeedThree],
+            transfer_pumps: [
+                A380FuelTransferPump::RightInner,
+                A380FuelTransferPump::RightMid,
+                A380FuelTransferPump::RightOuter,
+            ],
+        },
+    ];
+
+    const FEED_TANKS: [A380FuelTankType; 4] = [
+        A380FuelTankType::FeedOne,
+        A380FuelTankType::FeedTwo,
+        A380FuelTankType::FeedThree,
+        A380FuelTankType::FeedFour,
+    ];
+
+    // Capacities in gallons, as defined in the flight model.
+    const FEED_ONE_AND_FOUR_CAPACITY_GALLONS: f64 = 7299.6;
+    const FEED_TWO_AND_THREE_CAPACITY_GALLONS: f64 = 7753.2;
+    const TRIM_CAPACITY_GALLONS: f64 = 6260.3;
+
+    // A feed tank is topped up when it drops below the lower ratio of its capacity,
+    // until it reaches the upper ratio.
+    const FEED_TANK_TRANSFER_START_RATIO: f64 = 0.9;
+    const FEED_TANK_TRANSFER_STOP_RATIO: f64 = 0.98;
+
+    const TRANSFER_PUMP_FLOW_KG_PER_S: f64 = 3.;
+
+    // The FQMS keeps the center of gravity near a target in cruise by moving
+    // fuel into and out of the trim tank.
+    const TARGET_CENTER_OF_GRAVITY_PERCENT_MAC: f64 = 39.;
+    const CENTER_OF_GRAVITY_DEADBAND_PERCENT_MAC: f64 = 0.5;
+    const TRIM_TRANSFER_MINIMUM_ALTITUDE_FOOT: f64 = 25500.;
+
     pub const A380_FUEL: [FuelInfo<'_>; 11] = [
         FuelInfo {
             // LEFT_OUTER - Capacity: 2731.5
@@ -102,18 +236,266 @@ impl A380Fuel {
     ];
 
     pub fn new(context: &mut InitContext) -> Self {
+        let mut tank_number = 0;
         let fuel_tanks = Self::A380_FUEL.map(|f| {
-            FuelTank::new(
+            tank_number += 1;
+            FuelTank::new_with_quantity_request(
                 context,
                 f.fuel_tank_id,
+                &format!("FUELSYSTEM_TANK_{}_QUANTITY_REQ", tank_number),
                 Vector3::new(f.position.0, f.position.1, f.position.2),
             )
         });
         A380Fuel {
             fuel_system: FuelSystem::new(context, fuel_tanks),
+
+            feed_tank_transfer_is_active_ids: [1, 2, 3, 4].map(|number| {
+                context.get_identifier(format!("FUEL_FEED_TANK_{}_XFR_IS_ACTIVE", number))
+            }),
+            trim_tank_aft_transfer_is_active_id: context
+                .get_identifier("FUEL_TRIM_TANK_AFT_XFR_IS_ACTIVE".to_owned()),
+            trim_tank_forward_transfer_is_active_id: context
+                .get_identifier("FUEL_TRIM_TANK_FWD_XFR_IS_ACTIVE".to_owned()),
+
+            transfer_pumps: A380FuelTransferPump::ALL
+                .map(|pump| FuelPump::new(context, pump.number(), pump.powered_by())),
+            transfer_pump_commands: [false; 8],
+            feed_tank_transfer_is_active: [false; 4],
+            trim_tank_aft_transfer_is_active: false,
+            trim_tank_forward_transfer_is_active: false,
+        }
+    }
+
+    /// Updates the fuel quantity and management system.
+    /// The gross weight center of gravity is given in percent of the mean aerodynamic chord.
+    pub fn update(&mut self, context: &UpdateContext, gross_weight_center_of_gravity: f64) {
+        self.update_feed_tank_transfer_demand();
+        self.update_trim_tank_transfer_demand(context, gross_weight_center_of_gravity);
+        self.update_transfer_pump_commands();
+
+        for ((pump, &commanded_on), pump_type) in self
+            .transfer_pumps
+            .iter_mut()
+            .zip(self.transfer_pump_commands.iter())
+            .zip(A380FuelTransferPump::ALL)
+        {
+            pump.update(
+                context,
+                commanded_on,
+                self.fuel_system.tank(pump_type.tank().into()),
+            );
+        }
+
+        self.transfer_to_feed_tanks(context);
+        self.transfer_trim_tank(context);
+    }
+
+    fn update_feed_tank_transfer_demand(&mut self) {
+        for (index, feed_tank) in Self::FEED_TANKS.into_iter().enumerate() {
+            let ratio = self
+                .fuel_system
+                .tank_mass(feed_tank.into())
+                .get::<kilogram>()
+                / Self::feed_tank_capacity(feed_tank).get::<kilogram>();
+
+            if ratio < Self::FEED_TANK_TRANSFER_START_RATIO {
+                self.feed_tank_transfer_is_active[index] = true;
+            } else if ratio > Self::FEED_TANK_TRANSFER_STOP_RATIO {
+                self.feed_tank_transfer_is_active[index] = false;
+            }
         }
     }
 
+    fn update_trim_tank_transfer_demand(
+        &mut self,
+        context: &UpdateContext,
+        gross_weight_center_of_gravity: f64,
+    ) {
+        let in_cruise = context.is_in_flight()
+            && context.indicated_altitude()
+                > Length::new::<foot>(Self::TRIM_TRANSFER_MINIMUM_ALTITUDE_FOOT);
+        let trim_tank_quantity = self.quantity_of(A380FuelTankType::Trim);
+        let inner_tanks_have_fuel = self.quantity_of(A380FuelTankType::LeftInner) > Mass::default()
+            || self.quantity_of(A380FuelTankType::RightInner) > Mass::default();
+
+        let cg_is_forward_of_target = gross_weight_center_of_gravity
+            < Self::TARGET_CENTER_OF_GRAVITY_PERCENT_MAC
+                - Self::CENTER_OF_GRAVITY_DEADBAND_PERCENT_MAC;
+        let cg_has_reached_target =
+            gross_weight_center_of_gravity >= Self::TARGET_CENTER_OF_GRAVITY_PERCENT_MAC;
+        let cg_is_aft_of_target = gross_weight_center_of_gravity
+            > Self::TARGET_CENTER_OF_GRAVITY_PERCENT_MAC
+                + Self::CENTER_OF_GRAVITY_DEADBAND_PERCENT_MAC;
+
+        if in_cruise
+            && cg_is_forward_of_target
+            && inner_tanks_have_fuel
+            && trim_tank_quantity < Self::gallons_to_mass(Self::TRIM_CAPACITY_GALLONS)
+        {
+            self.trim_tank_aft_transfer_is_active = true;
+        } else if !in_cruise
+            || cg_has_reached_target
+            || !inner_tanks_have_fuel
+            || trim_tank_quantity >= Self::gallons_to_mass(Self::TRIM_CAPACITY_GALLONS)
+        {
+            self.trim_tank_aft_transfer_is_active = false;
+        }
+
+        // Trim tank fuel is moved forward when the center of gravity is too far aft, or
+        // when the wing transfer tanks can no longer supply the feed tanks.
+        let wing_transfer_tanks_are_empty = Self::WING_SIDES.iter().all(|side| {
+            side.transfer_pumps
+                .iter()
+                .all(|pump| self.quantity_of(pump.tank()) <= Mass::default())
+        });
+        self.trim_tank_forward_transfer_is_active = trim_tank_quantity > Mass::default()
+            && !self.trim_tank_aft_transfer_is_active
+            && (cg_is_aft_of_target
+                || (wing_transfer_tanks_are_empty
+                    && self.feed_tank_transfer_is_active.iter().any(|&a| a)));
+    }
+
+    fn update_transfer_pump_commands(&mut self) {
+        self.transfer_pump_commands = [false; 8];
+
+        for side in Self::WING_SIDES.iter() {
+            let side_needs_fuel = side.feed_tanks.iter().any(|&feed_tank| {
+                self.feed_tank_transfer_is_active[Self::feed_tank_index(feed_tank)]
+            });
+
+            if side_needs_fuel {
+                if let Some(pump) = side
+                    .transfer_pumps
+                    .iter()
+                    .find(|pump| self.quantity_of(pump.tank()) > Mass::default())
+                {
+                    self.transfer_pump_commands[pump_index(pump.number())] = true;
+                }
+            }
+        }
+
+        if self.trim_tank_aft_transfer_is_active {
+            self.transfer_pump_commands[pump_index(A380FuelTransferPump::LeftInner.number())] =
+                true;
+            self.transfer_pump_commands[pump_index(A380FuelTransferPump::RightInner.number())] =
+                true;
+        }
+
+        if self.trim_tank_forward_transfer_is_active {
+            self.transfer_pump_commands[pump_index(A380FuelTransferPump::TrimLeft.number())] = true;
+            self.transfer_pump_commands[pump_index(A380FuelTransferPump::TrimRight.number())] =
+                true;
+        }
+    }
+
+    fn transfer_to_feed_tanks(&mut self, context: &UpdateContext) {
+        for side in Self::WING_SIDES.iter() {
+            let demanding_feed_tanks: Vec<A380FuelTankType> = side
+                .feed_tanks
+                .iter()
+                .copied()
+                .filter(|&feed_tank| {
+                    self.feed_tank_transfer_is_active[Self::feed_tank_index(feed_tank)]
+                })
+                .collect();
+            if demanding_feed_tanks.is_empty() {
+                continue;
+            }
+
+            let pressurised_pump = side.transfer_pumps.iter().find(|pump| {
+                self.transfer_pump_commands[pump_index(pump.number())]
+                    && self.transfer_pumps[pump_index(pump.number())].is_pressurised()
+            });
+
+            if let Some(pump) = pressurised_pump {
+                let mass = Self::transfer_flow(context) / demanding_feed_tanks.len() as f64;
+                for feed_tank in demanding_feed_tanks {
+                    let mass = mass.min(self.remaining_feed_tank_capacity(feed_tank));
+                    self.fuel_system
+                        .transfer_fuel(pump.tank().into(), feed_tank.into(), mass);
+                }
+            }
+        }
+    }
+
+    fn transfer_trim_tank(&mut self, context: &UpdateContext) {
+        if self.trim_tank_aft_transfer_is_active {
+            for pump in [
+                A380FuelTransferPump::LeftInner,
+                A380FuelTransferPump::RightInner,
+            ] {
+                if self.transfer_pumps[pump_index(pump.number())].is_pressurised() {
+                    let mass = Self::transfer_flow(context).min(
+                        Self::gallons_to_mass(Self::TRIM_CAPACITY_GALLONS)
+                            - self.quantity_of(A380FuelTankType::Trim),
+                    );
+                    self.fuel_system.transfer_fuel(
+                        pump.tank().into(),
+                        A380FuelTankType::Trim.into(),
+                        mass,
+                    );
+                }
+            }
+        }
+
+        if self.trim_tank_forward_transfer_is_active {
+            let pressurised_pumps = [
+                A380FuelTransferPump::TrimLeft,
+                A380FuelTransferPump::TrimRight,
+            ]
+            .iter()
+            .filter(|pump| self.transfer_pumps[pump_index(pump.number())].is_pressurised())
+            .count();
+
+            // Trim tank fuel is distributed evenly over the feed tanks.
+            let mass = Self::transfer_flow(context) * pressurised_pumps as f64
+                / Self::FEED_TANKS.len() as f64;
+            for feed_tank in Self::FEED_TANKS {
+                self.fuel_system.transfer_fuel(
+                    A380FuelTankType::Trim.into(),
+                    feed_tank.into(),
+                    mass.min(self.remaining_feed_tank_capacity(feed_tank)),
+                );
+            }
+        }
+    }
+
+    fn transfer_flow(context: &UpdateContext) -> Mass {
+        MassRate::new::<kilogram_per_second>(Self::TRANSFER_PUMP_FLOW_KG_PER_S)
+            * context.delta_as_time()
+    }
+
+    fn feed_tank_capacity(feed_tank: A380FuelTankType) -> Mass {
+        match feed_tank {
+            A380FuelTankType::FeedOne | A380FuelTankType::FeedFour => {
+                Self::gallons_to_mass(Self::FEED_ONE_AND_FOUR_CAPACITY_GALLONS)
+            }
+            A380FuelTankType::FeedTwo | A380FuelTankType::FeedThree => {
+                Self::gallons_to_mass(Self::FEED_TWO_AND_THREE_CAPACITY_GALLONS)
+            }
+            _ => panic!("Only feed tanks have a feed tank capacity."),
+        }
+    }
+
+    fn remaining_feed_tank_capacity(&self, feed_tank: A380FuelTankType) -> Mass {
+        (Self::feed_tank_capacity(feed_tank) - self.quantity_of(feed_tank)).max(Mass::default())
+    }
+
+    fn feed_tank_index(feed_tank: A380FuelTankType) -> usize {
+        Self::FEED_TANKS
+            .iter()
+            .position(|&t| t == feed_tank)
+            .expect("Only feed tanks have a feed tank index.")
+    }
+
+    fn gallons_to_mass(gallons: f64) -> Mass {
+        Mass::new::<kilogram>(gallons * FUEL_GALLONS_TO_KG)
+    }
+
+    fn quantity_of(&self, tank: A380FuelTankType) -> Mass {
+        self.fuel_system.tank_mass(tank.into())
+    }
+
     fn left_outer_tank_has_fuel(&self) -> bool {
         self.fuel_system
             .tank_has_fuel(A380FuelTankType::LeftOuter as usize)
@@ -237,6 +619,30 @@ impl FuelCG for A380Fuel {
 impl SimulationElement for A380Fuel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
+        accept_iterable!(self.transfer_pumps, visitor);
+
         visitor.visit(self);
     }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (id, is_active) in self
+            .feed_tank_transfer_is_active_ids
+            .iter()
+            .zip(self.feed_tank_transfer_is_active)
+        {
+            writer.write(id, is_active);
+        }
+        writer.write(
+            &self.trim_tank_aft_transfer_is_active_id,
+            self.trim_tank_aft_transfer_is_active,
+        );
+        writer.write(
+            &self.trim_tank_forward_transfer_is_active_id,
+            self.trim_tank_forward_transfer_is_active,
+        );
+    }
+}
+
+fn pump_index(pump_number: usize) -> usize {
+    pump_number - 1
 }
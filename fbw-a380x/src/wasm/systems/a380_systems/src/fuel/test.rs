@@ -1,28 +1,70 @@
 use std::time::Duration;
 
 use super::*;
-use crate::systems::simulation::{
-    test::{SimulationTestBed, TestBed, WriteByName},
-    Aircraft, SimulationElement, SimulationElementVisitor,
+use crate::systems::{
+    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    failures::FailureType,
+    shared::PotentialOrigin,
+    simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft, SimulationElement, SimulationElementVisitor,
+    },
 };
 
 struct FuelTestAircraft {
     fuel: A380Fuel,
+    gross_weight_center_of_gravity: f64,
+
+    powered_source: TestElectricitySource,
+    ac_buses: [ElectricalBus; 4],
 }
 
 impl FuelTestAircraft {
     fn new(context: &mut InitContext) -> Self {
         Self {
             fuel: A380Fuel::new(context),
+            gross_weight_center_of_gravity: A380Fuel::TARGET_CENTER_OF_GRAVITY_PERCENT_MAC,
+
+            powered_source: TestElectricitySource::unpowered(
+                context,
+                PotentialOrigin::EngineGenerator(1),
+            ),
+            ac_buses: [1, 2, 3, 4].map(|number| {
+                ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(number))
+            }),
         }
     }
 
+    fn power(&mut self) {
+        self.powered_source.power();
+    }
+
+    fn set_gross_weight_center_of_gravity(&mut self, center_of_gravity: f64) {
+        self.gross_weight_center_of_gravity = center_of_gravity;
+    }
+
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.fuel.fore_aft_center_of_gravity()
     }
 }
 
-impl Aircraft for FuelTestAircraft {}
+impl Aircraft for FuelTestAircraft {
+    fn update_before_power_distribution(
+        &mut self,
+        _: &UpdateContext,
+        electricity: &mut Electricity,
+    ) {
+        electricity.supplied_by(&self.powered_source);
+        for bus in &self.ac_buses {
+            electricity.flow(&self.powered_source, bus);
+        }
+    }
+
+    fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        self.fuel
+            .update(context, self.gross_weight_center_of_gravity);
+    }
+}
 impl SimulationElement for FuelTestAircraft {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel.accept(visitor);
@@ -93,6 +135,81 @@ impl FuelTestBed {
         self
     }
 
+    fn fuel_for_long_haul(mut self) -> Self {
+        self.write_by_name("FUELSYSTEM TANK QUANTITY:1", 2000.);
+        self.write_by_name("FUELSYSTEM TANK QUANTITY:2", 7000.);
+        self.write_by_name("FUELSYSTEM TANK QUANTITY:3", 9000.);
+        self.write_by_name("FUELSYSTEM TANK QUANTITY:4", 12000.);
+        self.write_by_name("FUELSYSTEM TANK QUANTITY:5", 7500.);
+        self.write_by_name("FUELSYSTEM TANK QUANTITY:6", 7500.);
+        self.write_by_name("FUELSYSTEM TANK QUANTITY:7", 12000.);
+        self.write_by_name("FUELSYSTEM TANK QUANTITY:8", 9000.);
+        self.write_by_name("FUELSYSTEM TANK QUANTITY:9", 7000.);
+        self.write_by_name("FUELSYSTEM TANK QUANTITY:10", 2000.);
+        self.write_by_name("FUELSYSTEM TANK QUANTITY:11", 2000.);
+
+        self
+    }
+
+    fn tank_quantity_gallons(mut self, tank: A380FuelTankType, gallons: f64) -> Self {
+        self.write_by_name(
+            &format!("FUELSYSTEM TANK QUANTITY:{}", tank as usize + 1),
+            gallons,
+        );
+
+        self
+    }
+
+    fn powered(mut self) -> Self {
+        self.command(|a| a.power());
+
+        self
+    }
+
+    fn in_cruise(mut self) -> Self {
+        self.set_on_ground(false);
+        self.set_indicated_altitude(Length::new::<foot>(37000.));
+
+        self
+    }
+
+    fn with_center_of_gravity(mut self, center_of_gravity: f64) -> Self {
+        self.command(|a| a.set_gross_weight_center_of_gravity(center_of_gravity));
+
+        self
+    }
+
+    /// Runs the simulation while feeding the requested tank quantities back into the
+    /// tank quantities, as is done by the fuel aspect in the simulator.
+    fn run_for(mut self, duration: Duration) -> Self {
+        let frame = Duration::from_millis(100);
+        let mut elapsed = Duration::ZERO;
+        while elapsed < duration {
+            self.run_with_delta(frame);
+            for number in 1..=A380Fuel::A380_FUEL.len() {
+                let quantity: f64 =
+                    self.read_by_name(&format!("FUELSYSTEM_TANK_{}_QUANTITY_REQ", number));
+                self.write_by_name(&format!("FUELSYSTEM TANK QUANTITY:{}", number), quantity);
+            }
+
+            elapsed += frame;
+        }
+
+        self
+    }
+
+    fn tank_quantity(&self, tank: A380FuelTankType) -> Mass {
+        self.query(|a| a.fuel.quantity_of(tank))
+    }
+
+    fn trim_tank_aft_transfer_is_active(&mut self) -> bool {
+        self.read_by_name("FUEL_TRIM_TANK_AFT_XFR_IS_ACTIVE")
+    }
+
+    fn trim_tank_forward_transfer_is_active(&mut self) -> bool {
+        self.read_by_name("FUEL_TRIM_TANK_FWD_XFR_IS_ACTIVE")
+    }
+
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.query(|a: &FuelTestAircraft| a.fore_aft_center_of_gravity())
     }
@@ -161,3 +278,203 @@ fn high_fuel() {
         (test_bed.fore_aft_center_of_gravity() * 100.).round() / 100.,
     );
 }
+
+#[test]
+fn unpowered_fuel_system_does_not_transfer_fuel() {
+    let test_bed = test_bed_with()
+        .fuel_for_long_haul()
+        .tank_quantity_gallons(A380FuelTankType::FeedOne, 3000.)
+        .run_for(Duration::from_secs(30));
+
+    assert!(
+        (test_bed
+            .tank_quantity(A380FuelTankType::FeedOne)
+            .get::<kilogram>()
+            - 3000. * FUEL_GALLONS_TO_KG)
+            .abs()
+            < 1.
+    );
+}
+
+#[test]
+fn full_feed_tanks_do_not_receive_fuel() {
+    let test_bed = test_bed_with()
+        .fuel_for_long_haul()
+        .powered()
+        .run_for(Duration::from_secs(30));
+
+    assert!(
+        (test_bed
+            .tank_quantity(A380FuelTankType::LeftInner)
+            .get::<kilogram>()
+            - 12000. * FUEL_GALLONS_TO_KG)
+            .abs()
+            < 1.
+    );
+}
+
+#[test]
+fn feed_tank_is_not_filled_beyond_its_capacity() {
+    let mut test_bed = test_bed_with()
+        .fuel_for_long_haul()
+        .tank_quantity_gallons(A380FuelTankType::FeedOne, 6000.)
+        .powered();
+
+    test_bed.run_with_delta(Duration::from_secs(3600));
+
+    assert!(
+        test_bed.tank_quantity(A380FuelTankType::FeedOne)
+            <= Mass::new::<kilogram>(7299.6 * FUEL_GALLONS_TO_KG + 1.)
+    );
+}
+
+#[test]
+fn inner_tank_transfers_to_feed_tank_first() {
+    let test_bed = test_bed_with()
+        .fuel_for_long_haul()
+        .tank_quantity_gallons(A380FuelTankType::FeedOne, 3000.)
+        .powered()
+        .run_for(Duration::from_secs(30));
+
+    assert!(
+        test_bed.tank_quantity(A380FuelTankType::FeedOne)
+            > Mass::new::<kilogram>(3000. * FUEL_GALLONS_TO_KG)
+    );
+    assert!(
+        test_bed.tank_quantity(A380FuelTankType::LeftInner)
+            < Mass::new::<kilogram>(12000. * FUEL_GALLONS_TO_KG)
+    );
+    assert!(
+        (test_bed
+            .tank_quantity(A380FuelTankType::LeftMid)
+            .get::<kilogram>()
+            - 9000. * FUEL_GALLONS_TO_KG)
+            .abs()
+            < 1.
+    );
+}
+
+#[test]
+fn mid_tank_transfers_when_inner_tank_is_empty() {
+    let test_bed = test_bed_with()
+        .fuel_for_long_haul()
+        .tank_quantity_gallons(A380FuelTankType::LeftInner, 0.)
+        .tank_quantity_gallons(A380FuelTankType::FeedOne, 3000.)
+        .powered()
+        .run_for(Duration::from_secs(30));
+
+    assert!(
+        test_bed.tank_quantity(A380FuelTankType::LeftMid)
+            < Mass::new::<kilogram>(9000. * FUEL_GALLONS_TO_KG)
+    );
+    assert!(
+        (test_bed
+            .tank_quantity(A380FuelTankType::LeftOuter)
+            .get::<kilogram>()
+            - 2000. * FUEL_GALLONS_TO_KG)
+            .abs()
+            < 1.
+    );
+}
+
+#[test]
+fn outer_tank_transfers_last() {
+    let test_bed = test_bed_with()
+        .fuel_for_long_haul()
+        .tank_quantity_gallons(A380FuelTankType::LeftInner, 0.)
+        .tank_quantity_gallons(A380FuelTankType::LeftMid, 0.)
+        .tank_quantity_gallons(A380FuelTankType::FeedOne, 3000.)
+        .powered()
+        .run_for(Duration::from_secs(30));
+
+    assert!(
+        test_bed.tank_quantity(A380FuelTankType::LeftOuter)
+            < Mass::new::<kilogram>(2000. * FUEL_GALLONS_TO_KG)
+    );
+}
+
+#[test]
+fn failed_transfer_pump_does_not_transfer_fuel() {
+    let mut test_bed = test_bed_with()
+        .fuel_for_long_haul()
+        .tank_quantity_gallons(A380FuelTankType::FeedOne, 3000.)
+        .powered();
+    test_bed.fail(FailureType::FuelPump(
+        A380FuelTransferPump::LeftInner.number(),
+    ));
+    test_bed = test_bed.run_for(Duration::from_secs(30));
+
+    assert!(
+        (test_bed
+            .tank_quantity(A380FuelTankType::LeftInner)
+            .get::<kilogram>()
+            - 12000. * FUEL_GALLONS_TO_KG)
+            .abs()
+            < 1.
+    );
+}
+
+#[test]
+fn trim_tank_receives_fuel_in_cruise_when_center_of_gravity_is_forward() {
+    let mut test_bed = test_bed_with()
+        .fuel_for_long_haul()
+        .powered()
+        .in_cruise()
+        .with_center_of_gravity(35.)
+        .run_for(Duration::from_secs(30));
+
+    assert!(test_bed.trim_tank_aft_transfer_is_active());
+    assert!(
+        test_bed.tank_quantity(A380FuelTankType::Trim)
+            > Mass::new::<kilogram>(2000. * FUEL_GALLONS_TO_KG)
+    );
+}
+
+#[test]
+fn trim_tank_does_not_receive_fuel_on_ground() {
+    let mut test_bed = test_bed_with()
+        .fuel_for_long_haul()
+        .powered()
+        .with_center_of_gravity(35.)
+        .run_for(Duration::from_secs(30));
+
+    assert!(!test_bed.trim_tank_aft_transfer_is_active());
+    assert!(
+        (test_bed
+            .tank_quantity(A380FuelTankType::Trim)
+            .get::<kilogram>()
+            - 2000. * FUEL_GALLONS_TO_KG)
+            .abs()
+            < 1.
+    );
+}
+
+#[test]
+fn trim_tank_transfers_forward_when_center_of_gravity_is_aft() {
+    let mut test_bed = test_bed_with()
+        .fuel_for_long_haul()
+        .tank_quantity_gallons(A380FuelTankType::FeedOne, 3000.)
+        .powered()
+        .in_cruise()
+        .with_center_of_gravity(42.)
+        .run_for(Duration::from_secs(30));
+
+    assert!(test_bed.trim_tank_forward_transfer_is_active());
+    assert!(
+        test_bed.tank_quantity(A380FuelTankType::Trim)
+            < Mass::new::<kilogram>(2000. * FUEL_GALLONS_TO_KG)
+    );
+}
+
+#[test]
+fn trim_tank_transfer_stops_when_center_of_gravity_is_on_target() {
+    let mut test_bed = test_bed_with()
+        .fuel_for_long_haul()
+        .powered()
+        .in_cruise()
+        .with_center_of_gravity(A380Fuel::TARGET_CENTER_OF_GRAVITY_PERCENT_MAC)
+        .run_for(Duration::from_secs(30));
+
+    assert!(!test_bed.trim_tank_aft_transfer_is_active());
+    assert!(!test_bed.trim_tank_forward_transfer_is_active());
+}
@@ -19,18 +19,21 @@ use uom::si::{
 use systems::{
     accept_iterable,
     engine::Engine,
+    failures::{Failure, FailureType},
     hydraulic::{
         aerodynamic_model::AerodynamicModel,
         brake_circuit::{
-            AutobrakeDecelerationGovernor, AutobrakeMode, AutobrakePanel,
+            AntiSkidController, AutobrakeDecelerationGovernor, AutobrakeMode, AutobrakePanel,
             BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController,
         },
+        brake_heat_sink::BrakeHeatSinks,
         cargo_doors::{CargoDoor, HydraulicDoorController},
         flap_slat::FlapSlatAssembly,
         landing_gear::{GearGravityExtension, GearSystemController, HydraulicGearSystem},
         linear_actuator::{
-            Actuator, BoundedLinearLength, ElectroHydrostaticActuatorType,
-            ElectroHydrostaticBackup, ElectroHydrostaticPowered, HydraulicAssemblyController,
+            passivate_failed_actuators, Actuator, BoundedLinearLength,
+            ElectroHydrostaticActuatorType, ElectroHydrostaticBackup, ElectroHydrostaticPowered,
+            FlightControlActuatorFailures, HydraulicAssemblyController,
             HydraulicLinearActuatorAssembly, HydraulicLocking, LinearActuatedRigidBodyOnHingeAxis,
             LinearActuator, LinearActuatorCharacteristics, LinearActuatorMode,
         },
@@ -47,14 +50,19 @@ use systems::{
         HydraulicCircuitController, HydraulicPressureSensors, ManualPump, PressureSwitch,
         PressureSwitchType, PriorityValve, PumpController, Reservoir,
     },
-    landing_gear::{GearSystemSensors, LandingGearControlInterfaceUnitSet, TiltingGear},
+    landing_gear::{
+        wheel_slip::WheelSlips,
+        wheel_speed_sensor::{WheelReferenceSpeed, WheelSpeedSensors},
+        GearSystemSensors, LandingGearControlInterfaceUnitSet, TiltingGear,
+    },
     overhead::{AutoOffFaultPushButton, AutoOnFaultPushButton},
     shared::{
         interpolation, random_from_range, update_iterator::MaxStepLoop, AdirsDiscreteOutputs,
         AirbusElectricPumpId, AirbusEngineDrivenPumpId, CargoDoorLocked, DelayedFalseLogicGate,
         DelayedPulseTrueLogicGate, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses,
-        EngineFirePushButtons, GearWheel, HydraulicColor, LandingGearHandle, LgciuInterface,
-        LgciuWeightOnWheels, ReservoirAirPressure, SectionPressure, SurfacesPositions,
+        EngineFirePushButtons, FlightControlActuatorId, GearWheel, HydraulicColor,
+        LandingGearHandle, LgciuInterface, LgciuWeightOnWheels, ReservoirAirPressure,
+        SectionPressure, SurfacesPositions, WheelSlipRatios, WheelSpeeds,
     },
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -633,6 +641,10 @@ impl A380SpoilerFactory {
                 ActuatorSide::Left => "LEFT",
                 ActuatorSide::Right => "RIGHT",
             },
+            match id {
+                ActuatorSide::Left => FlightControlActuatorId::LeftSpoiler,
+                ActuatorSide::Right => FlightControlActuatorId::RightSpoiler,
+            },
             [
                 spoiler_1, spoiler_2, spoiler_3, spoiler_4, spoiler_5, spoiler_6, spoiler_7,
                 spoiler_8,
@@ -1555,6 +1567,8 @@ pub(super) struct A380Hydraulic {
     braking_circuit_norm: BrakeCircuit,
     braking_circuit_altn: BrakeCircuit,
     braking_force: A380BrakingForce,
+    brake_heat_sinks: BrakeHeatSinks,
+    wheel_speed_sensors: WheelSpeedSensors,
 
     flap_system: FlapSlatAssembly,
     slat_system: FlapSlatAssembly,
@@ -1624,6 +1638,11 @@ impl A380Hydraulic {
 
     const EDP_CONTROL_POWER_BUS1: ElectricalBusType = ElectricalBusType::DirectCurrentEssential;
 
+    // Main wheels 1400x530R23
+    const MAIN_TIRE_RADIUS_METER: f64 = 0.7;
+    const MAIN_TIRE_NOMINAL_PRESSURE_PSI: f64 = 218.;
+    const BRAKE_HEAT_SINK_MASS_KG: f64 = 130.;
+
     const ALTERNATE_BRAKE_ACCUMULATOR_GAS_PRE_CHARGE: f64 = 1000.0; // Nitrogen PSI
                                                                     // Refresh rate of core hydraulic simulation
     const HYDRAULIC_SIM_TIME_STEP: Duration = Duration::from_millis(10);
@@ -1821,6 +1840,33 @@ impl A380Hydraulic {
             ),
 
             braking_force: A380BrakingForce::new(context),
+            // All four wheels of each wing gear and the four outer wheels of each body gear are braked.
+            // Brakes are numbered from the left wing gear to the right wing gear.
+            brake_heat_sinks: BrakeHeatSinks::new(
+                context,
+                &[
+                    [(GearWheel::LEFT, 3); 4],
+                    [(GearWheel::LEFT, 1); 4],
+                    [(GearWheel::RIGHT, 2); 4],
+                    [(GearWheel::RIGHT, 4); 4],
+                ]
+                .concat(),
+                Mass::new::<kilogram>(Self::BRAKE_HEAT_SINK_MASS_KG),
+                Length::new::<meter>(Self::MAIN_TIRE_RADIUS_METER),
+                None,
+            ),
+            // One tachometer per simulator wheel, ordered as the brakes
+            wheel_speed_sensors: WheelSpeedSensors::new(
+                context,
+                &[
+                    (GearWheel::LEFT, 3),
+                    (GearWheel::LEFT, 1),
+                    (GearWheel::RIGHT, 2),
+                    (GearWheel::RIGHT, 4),
+                ],
+                Length::new::<meter>(Self::MAIN_TIRE_RADIUS_METER),
+                Pressure::new::<psi>(Self::MAIN_TIRE_NOMINAL_PRESSURE_PSI),
+            ),
 
             flap_system: FlapSlatAssembly::new(
                 context,
@@ -2199,16 +2245,19 @@ impl A380Hydraulic {
         engine1: &impl Engine,
         engine2: &impl Engine,
     ) {
-        self.aileron_system_controller.update();
+        self.aileron_system_controller.update(context);
 
-        self.elevator_system_controller.update();
+        self.elevator_system_controller.update(context);
 
         self.ths_system_controller.update();
 
-        self.rudder_system_controller.update();
+        self.rudder_system_controller.update(context);
 
         self.tilting_gears.update(context);
 
+        self.wheel_speed_sensors
+            .update(context, self.braking_force.wheel_slips());
+
         self.nose_steering.update(
             context,
             self.yellow_circuit.system_section(),
@@ -2224,6 +2273,7 @@ impl A380Hydraulic {
             lgciu1,
             lgciu2,
             autobrake_panel,
+            &self.wheel_speed_sensors,
             engine1,
             engine2,
         );
@@ -2239,6 +2289,18 @@ impl A380Hydraulic {
             &self.pushback_tug,
         );
 
+        self.brake_heat_sinks.update(
+            context,
+            self.braking_circuit_norm
+                .left_brake_pressure()
+                .max(self.braking_circuit_altn.left_brake_pressure()),
+            self.braking_circuit_norm
+                .right_brake_pressure()
+                .max(self.braking_circuit_altn.right_brake_pressure()),
+            lgciu1.main_down_and_locked(),
+            false,
+        );
+
         self.slats_flaps_complex
             .update(context, &self.flap_system, &self.slat_system);
 
@@ -2894,6 +2956,8 @@ impl SimulationElement for A380Hydraulic {
         self.braking_circuit_norm.accept(visitor);
         self.braking_circuit_altn.accept(visitor);
         self.braking_force.accept(visitor);
+        self.brake_heat_sinks.accept(visitor);
+        self.wheel_speed_sensors.accept(visitor);
 
         self.nose_steering.accept(visitor);
         self.slats_flaps_complex.accept(visitor);
@@ -3773,8 +3837,6 @@ struct A380HydraulicBrakeSteerComputerUnit {
     left_brake_pedal_input_id: VariableIdentifier,
     right_brake_pedal_input_id: VariableIdentifier,
 
-    ground_speed_id: VariableIdentifier,
-
     rudder_pedal_input_id: VariableIdentifier,
     tiller_handle_input_id: VariableIdentifier,
     tiller_pedal_disconnect_id: VariableIdentifier,
@@ -3793,6 +3855,15 @@ struct A380HydraulicBrakeSteerComputerUnit {
     should_disable_auto_brake_when_retracting: DelayedTrueLogicGate,
     anti_skid_activated: bool,
 
+    reference_speed: WheelReferenceSpeed,
+    wheel_speed_sensors_valid: bool,
+
+    left_wheels_slip: Ratio,
+    right_wheels_slip: Ratio,
+    left_anti_skid: AntiSkidController,
+    right_anti_skid: AntiSkidController,
+    anti_skid_failure: Failure,
+
     tiller_pedal_disconnect: bool,
     tiller_handle_position: Ratio,
     rudder_pedal_position: Ratio,
@@ -3803,8 +3874,6 @@ struct A380HydraulicBrakeSteerComputerUnit {
     tiller_steering_limiter: SteeringAngleLimiter<5>,
     tiller_input_map: SteeringRatioToAngle<6>,
     final_steering_position_request: Angle,
-
-    ground_speed: Velocity,
 }
 impl A380HydraulicBrakeSteerComputerUnit {
     const RUDDER_PEDAL_INPUT_GAIN: f64 = 32.;
@@ -3839,6 +3908,10 @@ impl A380HydraulicBrakeSteerComputerUnit {
 
     const PILOT_INPUT_DETECTION_TRESHOLD: f64 = 0.2;
 
+    // Wheel speed sensors numbers of each main gear
+    const LEFT_WHEELS: [usize; 2] = [1, 2];
+    const RIGHT_WHEELS: [usize; 2] = [3, 4];
+
     fn new(context: &mut InitContext) -> Self {
         Self {
             park_brake_lever_pos_id: context.get_identifier("PARK_BRAKE_LEVER_POS".to_owned()),
@@ -3847,7 +3920,6 @@ impl A380HydraulicBrakeSteerComputerUnit {
             right_brake_pedal_input_id: context
                 .get_identifier("RIGHT_BRAKE_PEDAL_INPUT".to_owned()),
 
-            ground_speed_id: context.get_identifier("GPS GROUND SPEED".to_owned()),
             rudder_pedal_input_id: context.get_identifier("RUDDER_PEDAL_POSITION_RATIO".to_owned()),
             tiller_handle_input_id: context.get_identifier("TILLER_HANDLE_POSITION".to_owned()),
             tiller_pedal_disconnect_id: context
@@ -3868,6 +3940,15 @@ impl A380HydraulicBrakeSteerComputerUnit {
             ),
             anti_skid_activated: true,
 
+            reference_speed: WheelReferenceSpeed::new(),
+            wheel_speed_sensors_valid: true,
+
+            left_wheels_slip: Ratio::new::<ratio>(0.),
+            right_wheels_slip: Ratio::new::<ratio>(0.),
+            left_anti_skid: AntiSkidController::new(),
+            right_anti_skid: AntiSkidController::new(),
+            anti_skid_failure: Failure::new(FailureType::AntiSkid),
+
             tiller_pedal_disconnect: false,
             tiller_handle_position: Ratio::new::<ratio>(0.),
             rudder_pedal_position: Ratio::new::<ratio>(0.),
@@ -3892,13 +3973,72 @@ impl A380HydraulicBrakeSteerComputerUnit {
                 Self::TILLER_INPUT_CURVE_MAP,
             ),
             final_steering_position_request: Angle::new::<degree>(0.),
-
-            ground_speed: Velocity::new::<knot>(0.),
         }
     }
 
     fn allow_autobrake_arming(&self) -> bool {
-        self.anti_skid_activated && self.normal_brakes_available
+        self.is_anti_skid_available() && self.normal_brakes_available
+    }
+
+    /// Anti skid needs the speed of every braked wheel to detect when it skids
+    fn is_anti_skid_available(&self) -> bool {
+        self.anti_skid_activated
+            && self.wheel_speed_sensors_valid
+            && !self.anti_skid_failure.is_active()
+    }
+
+    fn update_wheel_speeds(&mut self, context: &UpdateContext, wheel_speeds: &impl WheelSpeeds) {
+        self.reference_speed.update(context, wheel_speeds);
+
+        self.wheel_speed_sensors_valid = (1..=wheel_speeds.wheel_count())
+            .all(|wheel_number| wheel_speeds.wheel_speed(wheel_number).is_some());
+
+        self.left_wheels_slip = self.gear_slip(wheel_speeds, &Self::LEFT_WHEELS);
+        self.right_wheels_slip = self.gear_slip(wheel_speeds, &Self::RIGHT_WHEELS);
+    }
+
+    /// Slip of the most skidding wheel of a gear
+    fn gear_slip(&self, wheel_speeds: &impl WheelSpeeds, wheels: &[usize]) -> Ratio {
+        wheels
+            .iter()
+            .map(|&wheel_number| {
+                self.reference_speed
+                    .wheel_slip(wheel_speeds.wheel_speed(wheel_number))
+            })
+            .fold(Ratio::new::<ratio>(0.), |max_slip, slip| max_slip.max(slip))
+    }
+
+    fn update_anti_skid(&mut self, context: &UpdateContext) {
+        let is_anti_skid_active = self.is_anti_skid_available() && !self.parking_brake_demand;
+
+        self.left_anti_skid.update(
+            context,
+            is_anti_skid_active,
+            self.reference_speed.speed(),
+            self.left_wheels_slip,
+        );
+        self.right_anti_skid.update(
+            context,
+            is_anti_skid_active,
+            self.reference_speed.speed(),
+            self.right_wheels_slip,
+        );
+    }
+
+    /// Releases the pressure the anti skid asks for on the braking circuit in use
+    fn modulate_brake_demands_with_anti_skid(&mut self) {
+        self.norm_brake_outputs.set_brake_demands(
+            self.left_anti_skid
+                .modulated_demand(self.norm_brake_outputs.left_demand()),
+            self.right_anti_skid
+                .modulated_demand(self.norm_brake_outputs.right_demand()),
+        );
+        self.alternate_brake_outputs.set_brake_demands(
+            self.left_anti_skid
+                .modulated_demand(self.alternate_brake_outputs.left_demand()),
+            self.right_anti_skid
+                .modulated_demand(self.alternate_brake_outputs.right_demand()),
+        );
     }
 
     fn update_normal_braking_availability(&mut self, normal_braking_circuit_pressure: Pressure) {
@@ -3933,7 +4073,7 @@ impl A380HydraulicBrakeSteerComputerUnit {
                 // Else manual action limited to a higher max nominal pressure
                 2538.
             }
-        } else if !self.anti_skid_activated {
+        } else if !self.is_anti_skid_available() {
             1160.
         } else {
             // Else if any manual braking we use standard limit
@@ -3953,13 +4093,16 @@ impl A380HydraulicBrakeSteerComputerUnit {
         lgciu1: &impl LgciuInterface,
         lgciu2: &impl LgciuInterface,
         autobrake_panel: &AutobrakePanel,
+        wheel_speeds: &impl WheelSpeeds,
         engine1: &impl Engine,
         engine2: &impl Engine,
     ) {
+        self.update_wheel_speeds(context, wheel_speeds);
         self.update_steering_demands(lgciu1, engine1, engine2);
 
         self.update_normal_braking_availability(current_pressure.pressure());
         self.update_brake_pressure_limitation();
+        self.update_anti_skid(context);
 
         self.autobrake_controller.update(
             context,
@@ -3990,7 +4133,7 @@ impl A380HydraulicBrakeSteerComputerUnit {
             self.alternate_brake_outputs.set_no_demands();
         } else {
             let green_used_for_brakes = self.normal_brakes_available
-                && self.anti_skid_activated
+                && self.is_anti_skid_available()
                 && !self.parking_brake_demand;
 
             if green_used_for_brakes {
@@ -4030,6 +4173,8 @@ impl A380HydraulicBrakeSteerComputerUnit {
                 }
             }
         }
+
+        self.modulate_brake_demands_with_anti_skid();
     }
 
     fn update_steering_demands(
@@ -4049,9 +4194,11 @@ impl A380HydraulicBrakeSteerComputerUnit {
                 .angle_demand_from_input_demand(self.rudder_pedal_position)
         };
 
-        // TODO Here ground speed would be probably computed from wheel sensor logic
+        // Steering authority is limited by the reference speed computed from the wheel speed sensors
+        let reference_speed = self.reference_speed.speed().unwrap_or_default();
+
         let final_steer_rudder_plus_autopilot = self.pedal_steering_limiter.angle_from_speed(
-            self.ground_speed,
+            reference_speed,
             (steer_angle_from_pedals + steer_angle_from_autopilot)
                 .min(Angle::new::<degree>(
                     Self::MAX_RUDDER_INPUT_INCLUDING_AUTOPILOT_DEGREE,
@@ -4062,7 +4209,7 @@ impl A380HydraulicBrakeSteerComputerUnit {
         );
 
         let steer_angle_from_tiller = self.tiller_steering_limiter.angle_from_speed(
-            self.ground_speed,
+            reference_speed,
             self.tiller_input_map
                 .angle_demand_from_input_demand(self.tiller_handle_position),
         );
@@ -4072,6 +4219,7 @@ impl A380HydraulicBrakeSteerComputerUnit {
 
         self.final_steering_position_request = if !is_both_engine_low_oil_pressure
             && self.anti_skid_activated
+            && self.reference_speed.is_valid()
             && lgciu1.nose_gear_compressed(false)
         {
             (final_steer_rudder_plus_autopilot + steer_angle_from_tiller)
@@ -4097,6 +4245,7 @@ impl A380HydraulicBrakeSteerComputerUnit {
 impl SimulationElement for A380HydraulicBrakeSteerComputerUnit {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.autobrake_controller.accept(visitor);
+        self.anti_skid_failure.accept(visitor);
         visitor.visit(self);
     }
 
@@ -4113,7 +4262,6 @@ impl SimulationElement for A380HydraulicBrakeSteerComputerUnit {
             Ratio::new::<ratio>(reader.read(&self.tiller_handle_input_id));
         self.rudder_pedal_position = Ratio::new::<ratio>(reader.read(&self.rudder_pedal_input_id));
         self.tiller_pedal_disconnect = reader.read(&self.tiller_pedal_disconnect_id);
-        self.ground_speed = reader.read(&self.ground_speed_id);
 
         self.autopilot_nosewheel_demand =
             Ratio::new::<ratio>(reader.read(&self.autopilot_nosewheel_demand_id));
@@ -4137,6 +4285,8 @@ struct A380BrakingForce {
     left_braking_force: f64,
     right_braking_force: f64,
 
+    wheel_slips: WheelSlips,
+
     flap_position: f64,
 
     is_chocks_enabled: bool,
@@ -4165,6 +4315,17 @@ impl A380BrakingForce {
             left_braking_force: 0.,
             right_braking_force: 0.,
 
+            wheel_slips: WheelSlips::new(
+                context,
+                &[
+                    (GearWheel::LEFT, 3),
+                    (GearWheel::LEFT, 1),
+                    (GearWheel::RIGHT, 2),
+                    (GearWheel::RIGHT, 4),
+                ],
+                Length::new::<meter>(A380Hydraulic::MAIN_TIRE_RADIUS_METER),
+            ),
+
             flap_position: 0.,
 
             is_chocks_enabled: false,
@@ -4199,6 +4360,21 @@ impl A380BrakingForce {
         self.right_braking_force = right_force_norm + right_force_altn;
         self.right_braking_force = self.right_braking_force.max(0.).min(1.);
 
+        // Tires can't transmit more than the runway grip: past it the wheels skid and lose force
+        self.wheel_slips.update(
+            context,
+            Ratio::new::<ratio>(self.left_braking_force),
+            Ratio::new::<ratio>(self.right_braking_force),
+        );
+        self.left_braking_force = self
+            .wheel_slips
+            .braking_force(GearWheel::LEFT)
+            .get::<ratio>();
+        self.right_braking_force = self
+            .wheel_slips
+            .braking_force(GearWheel::RIGHT)
+            .get::<ratio>();
+
         self.correct_with_flaps_state(context);
 
         self.update_chocks_braking(context, engine1, engine2, pushback_tug);
@@ -4244,9 +4420,18 @@ impl A380BrakingForce {
             self.right_braking_force = 1.;
         }
     }
+
+    fn wheel_slips(&self) -> &impl WheelSlipRatios {
+        &self.wheel_slips
+    }
 }
 
 impl SimulationElement for A380BrakingForce {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.wheel_slips.accept(visitor);
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         // BRAKE XXXX FORCE FACTOR is the actual braking force we want the plane to generate in the simulator
         writer.write(&self.brake_left_force_factor_id, self.left_braking_force);
@@ -4767,17 +4952,20 @@ impl SimulationElement for A380HydraulicOverheadPanel {
     }
 }
 
-#[derive(Clone, Copy, PartialEq)]
 struct AileronController {
     mode: LinearActuatorMode,
     requested_position: Ratio,
+
+    failures: FlightControlActuatorFailures,
 }
 impl AileronController {
-    fn new() -> Self {
+    fn new(id: FlightControlActuatorId) -> Self {
         Self {
             mode: LinearActuatorMode::ClosedCircuitDamping,
 
             requested_position: Ratio::new::<ratio>(0.),
+
+            failures: FlightControlActuatorFailures::new(id),
         }
     }
 
@@ -4785,6 +4973,32 @@ impl AileronController {
         self.mode = mode;
     }
 
+    fn update_failures(&mut self, context: &UpdateContext) {
+        self.failures
+            .update(context, self.mode == LinearActuatorMode::PositionControl);
+    }
+
+    fn has_failure_detected(&self) -> bool {
+        self.failures.is_failure_detected()
+    }
+
+    /// Passivates the failed actuators of a surface, handing over active control to a healthy one
+    fn passivate_failed<const N: usize>(controllers: &mut [AileronController; N]) {
+        let mut modes = [LinearActuatorMode::ClosedCircuitDamping; N];
+        let mut failures_detected = [false; N];
+        for (index, controller) in controllers.iter().enumerate() {
+            modes[index] = controller.mode;
+            failures_detected[index] = controller.has_failure_detected();
+        }
+
+        for (controller, mode) in controllers
+            .iter_mut()
+            .zip(passivate_failed_actuators(modes, failures_detected))
+        {
+            controller.set_mode(mode);
+        }
+    }
+
     /// Receives a [0;1] position request, 0 is down 1 is up
     fn set_requested_position(&mut self, requested_position: Ratio) {
         self.requested_position = requested_position
@@ -4798,7 +5012,7 @@ impl HydraulicAssemblyController for AileronController {
     }
 
     fn requested_position(&self) -> Ratio {
-        self.requested_position
+        self.failures.requested_position(self.requested_position)
     }
 
     fn should_lock(&self) -> bool {
@@ -4808,6 +5022,14 @@ impl HydraulicAssemblyController for AileronController {
     fn requested_lock_position(&self) -> Ratio {
         Ratio::default()
     }
+
+    fn is_jammed(&self) -> bool {
+        self.failures.is_jammed()
+    }
+
+    fn is_disconnected(&self) -> bool {
+        self.failures.is_disconnected()
+    }
 }
 impl HydraulicLocking for AileronController {}
 impl ElectroHydrostaticPowered for AileronController {
@@ -4815,6 +5037,13 @@ impl ElectroHydrostaticPowered for AileronController {
         self.requested_mode() == LinearActuatorMode::PositionControl
     }
 }
+impl SimulationElement for AileronController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failures.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
 
 struct AileronSystemHydraulicController {
     left_inboard_aileron_green_actuator_solenoid_id: VariableIdentifier,
@@ -4926,8 +5155,34 @@ impl AileronSystemHydraulicController {
             right_outboard_solenoid_energized_from_fbw: [false; 2],
 
             // Controllers are in outward->inward order, so for aileron [Blue circuit, Green circuit]
-            left_aileron_controllers: [[AileronController::new(), AileronController::new()]; 3],
-            right_aileron_controllers: [[AileronController::new(), AileronController::new()]; 3],
+            left_aileron_controllers: [
+                [
+                    AileronController::new(FlightControlActuatorId::LeftAileron(1)),
+                    AileronController::new(FlightControlActuatorId::LeftAileron(2)),
+                ],
+                [
+                    AileronController::new(FlightControlActuatorId::LeftAileron(3)),
+                    AileronController::new(FlightControlActuatorId::LeftAileron(4)),
+                ],
+                [
+                    AileronController::new(FlightControlActuatorId::LeftAileron(5)),
+                    AileronController::new(FlightControlActuatorId::LeftAileron(6)),
+                ],
+            ],
+            right_aileron_controllers: [
+                [
+                    AileronController::new(FlightControlActuatorId::RightAileron(1)),
+                    AileronController::new(FlightControlActuatorId::RightAileron(2)),
+                ],
+                [
+                    AileronController::new(FlightControlActuatorId::RightAileron(3)),
+                    AileronController::new(FlightControlActuatorId::RightAileron(4)),
+                ],
+                [
+                    AileronController::new(FlightControlActuatorId::RightAileron(5)),
+                    AileronController::new(FlightControlActuatorId::RightAileron(6)),
+                ],
+            ],
         }
     }
 
@@ -4945,9 +5200,26 @@ impl AileronSystemHydraulicController {
         &self.right_aileron_controllers[panel as usize][..]
     }
 
-    fn update(&mut self) {
+    fn update(&mut self, context: &UpdateContext) {
         self.update_aileron_controllers_positions();
         self.update_aileron_controllers_modes();
+        self.update_actuators_passivation(context);
+    }
+
+    /// Servo loop monitoring passivates a failed actuator, the other actuator of the aileron
+    /// panel then takes over active control.
+    fn update_actuators_passivation(&mut self, context: &UpdateContext) {
+        for panel_controllers in self
+            .left_aileron_controllers
+            .iter_mut()
+            .chain(self.right_aileron_controllers.iter_mut())
+        {
+            for controller in panel_controllers.iter_mut() {
+                controller.update_failures(context);
+            }
+
+            AileronController::passivate_failed(panel_controllers);
+        }
     }
 
     fn update_aileron_controllers_positions(&mut self) {
@@ -5200,6 +5472,18 @@ impl AileronSystemHydraulicController {
     }
 }
 impl SimulationElement for AileronSystemHydraulicController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        for panel_controllers in self
+            .left_aileron_controllers
+            .iter_mut()
+            .chain(self.right_aileron_controllers.iter_mut())
+        {
+            accept_iterable!(panel_controllers, visitor);
+        }
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         // Note that we reverse left, as positions are just passed through msfs for now
         self.left_inboard_position_requests_from_fbw = [
@@ -5360,8 +5644,26 @@ impl ElevatorSystemHydraulicController {
             right_outboard_solenoid_energized_from_fbw: [false; 2],
 
             // Controllers are in outboard->inboard order
-            left_controllers: [[AileronController::new(), AileronController::new()]; 2],
-            right_controllers: [[AileronController::new(), AileronController::new()]; 2],
+            left_controllers: [
+                [
+                    AileronController::new(FlightControlActuatorId::LeftElevator(1)),
+                    AileronController::new(FlightControlActuatorId::LeftElevator(2)),
+                ],
+                [
+                    AileronController::new(FlightControlActuatorId::LeftElevator(3)),
+                    AileronController::new(FlightControlActuatorId::LeftElevator(4)),
+                ],
+            ],
+            right_controllers: [
+                [
+                    AileronController::new(FlightControlActuatorId::RightElevator(1)),
+                    AileronController::new(FlightControlActuatorId::RightElevator(2)),
+                ],
+                [
+                    AileronController::new(FlightControlActuatorId::RightElevator(3)),
+                    AileronController::new(FlightControlActuatorId::RightElevator(4)),
+                ],
+            ],
         }
     }
 
@@ -5379,9 +5681,26 @@ impl ElevatorSystemHydraulicController {
         &self.right_controllers[panel as usize][..]
     }
 
-    fn update(&mut self) {
+    fn update(&mut self, context: &UpdateContext) {
         self.update_elevator_controllers_positions();
         self.update_elevator_controllers_solenoids();
+        self.update_actuators_passivation(context);
+    }
+
+    /// Servo loop monitoring passivates a failed actuator, the other actuator of the elevator
+    /// panel then takes over active control.
+    fn update_actuators_passivation(&mut self, context: &UpdateContext) {
+        for panel_controllers in self
+            .left_controllers
+            .iter_mut()
+            .chain(self.right_controllers.iter_mut())
+        {
+            for controller in panel_controllers.iter_mut() {
+                controller.update_failures(context);
+            }
+
+            AileronController::passivate_failed(panel_controllers);
+        }
     }
 
     fn update_elevator_controllers_positions(&mut self) {
@@ -5545,6 +5864,18 @@ impl ElevatorSystemHydraulicController {
     }
 }
 impl SimulationElement for ElevatorSystemHydraulicController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        for panel_controllers in self
+            .left_controllers
+            .iter_mut()
+            .chain(self.right_controllers.iter_mut())
+        {
+            accept_iterable!(panel_controllers, visitor);
+        }
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.left_inboard_position_requests_from_fbw = [
             Self::elevator_actuator_position_from_surface_angle(Angle::new::<degree>(
@@ -5717,20 +6048,23 @@ impl SimulationElement for TrimmableHorizontalStabilizerSystemHydraulicControlle
     }
 }
 
-#[derive(Clone, Copy, PartialEq)]
 struct RudderController {
     mode: LinearActuatorMode,
     electric_mode_active: bool,
     requested_position: Ratio,
+
+    failures: FlightControlActuatorFailures,
 }
 impl RudderController {
-    fn new() -> Self {
+    fn new(id: FlightControlActuatorId) -> Self {
         Self {
             mode: LinearActuatorMode::ClosedCircuitDamping,
 
             electric_mode_active: false,
 
             requested_position: Ratio::new::<ratio>(0.),
+
+            failures: FlightControlActuatorFailures::new(id),
         }
     }
 
@@ -5739,6 +6073,30 @@ impl RudderController {
         self.electric_mode_active = electric_mode_active;
     }
 
+    fn update_failures(&mut self, context: &UpdateContext) {
+        self.failures
+            .update(context, self.mode == LinearActuatorMode::PositionControl);
+    }
+
+    /// Passivates the failed actuators of a rudder panel, handing over active control to a
+    /// healthy one. A passivated actuator also stops its electrical backup.
+    fn passivate_failed(controllers: &mut [RudderController; 2]) {
+        let modes = [controllers[0].mode, controllers[1].mode];
+        let failures_detected = [
+            controllers[0].failures.is_failure_detected(),
+            controllers[1].failures.is_failure_detected(),
+        ];
+
+        for ((controller, mode), failure_detected) in controllers
+            .iter_mut()
+            .zip(passivate_failed_actuators(modes, failures_detected))
+            .zip(failures_detected)
+        {
+            let electric_mode_active = controller.electric_mode_active && !failure_detected;
+            controller.set_mode(mode, electric_mode_active);
+        }
+    }
+
     /// Receives a [0;1] position request, 0 is down 1 is up
     fn set_requested_position(&mut self, requested_position: Ratio) {
         self.requested_position = requested_position
@@ -5752,7 +6110,7 @@ impl HydraulicAssemblyController for RudderController {
     }
 
     fn requested_position(&self) -> Ratio {
-        self.requested_position
+        self.failures.requested_position(self.requested_position)
     }
 
     fn should_lock(&self) -> bool {
@@ -5762,6 +6120,14 @@ impl HydraulicAssemblyController for RudderController {
     fn requested_lock_position(&self) -> Ratio {
         Ratio::default()
     }
+
+    fn is_jammed(&self) -> bool {
+        self.failures.is_jammed()
+    }
+
+    fn is_disconnected(&self) -> bool {
+        self.failures.is_disconnected()
+    }
 }
 impl HydraulicLocking for RudderController {}
 impl ElectroHydrostaticPowered for RudderController {
@@ -5769,6 +6135,13 @@ impl ElectroHydrostaticPowered for RudderController {
         self.electric_mode_active
     }
 }
+impl SimulationElement for RudderController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failures.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
 
 struct RudderSystemHydraulicController {
     upper_rudder_yellow_actuator_hydraulic_solenoid_id: VariableIdentifier,
@@ -5839,7 +6212,16 @@ impl RudderSystemHydraulicController {
             lower_electric_mode_solenoid_energized_from_fbw: [false; 2],
 
             // Controllers are in Upper -> Lower order
-            rudder_controllers: [[RudderController::new(), RudderController::new()]; 2],
+            rudder_controllers: [
+                [
+                    RudderController::new(FlightControlActuatorId::Rudder(1)),
+                    RudderController::new(FlightControlActuatorId::Rudder(2)),
+                ],
+                [
+                    RudderController::new(FlightControlActuatorId::Rudder(3)),
+                    RudderController::new(FlightControlActuatorId::Rudder(4)),
+                ],
+            ],
         }
     }
 
@@ -5850,9 +6232,17 @@ impl RudderSystemHydraulicController {
         &self.rudder_controllers[panel as usize][..]
     }
 
-    fn update(&mut self) {
+    fn update(&mut self, context: &UpdateContext) {
         self.update_rudder_controllers_positions();
         self.update_rudder_controllers_solenoids();
+
+        for panel_controllers in &mut self.rudder_controllers {
+            for controller in panel_controllers.iter_mut() {
+                controller.update_failures(context);
+            }
+
+            RudderController::passivate_failed(panel_controllers);
+        }
     }
 
     fn update_rudder_controllers_positions(&mut self) {
@@ -5970,6 +6360,14 @@ impl RudderSystemHydraulicController {
     }
 }
 impl SimulationElement for RudderSystemHydraulicController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        for panel_controllers in &mut self.rudder_controllers {
+            accept_iterable!(panel_controllers, visitor);
+        }
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.upper_position_requests_from_fbw = [
             Self::rudder_actuator_position_from_surface_angle(Angle::new::<degree>(
@@ -6443,18 +6841,23 @@ struct SpoilerGroup {
     spoiler_positions: [f64; 8],
 }
 impl SpoilerGroup {
-    fn new(context: &mut InitContext, spoiler_side: &str, spoilers: [SpoilerElement; 8]) -> Self {
+    fn new(
+        context: &mut InitContext,
+        spoiler_side: &str,
+        actuator_id: fn(usize) -> FlightControlActuatorId,
+        spoilers: [SpoilerElement; 8],
+    ) -> Self {
         Self {
             spoilers,
             hydraulic_controllers: [
-                SpoilerController::new(context, spoiler_side, 1),
-                SpoilerController::new(context, spoiler_side, 2),
-                SpoilerController::new(context, spoiler_side, 3),
-                SpoilerController::new(context, spoiler_side, 4),
-                SpoilerController::new(context, spoiler_side, 5),
-                SpoilerController::new(context, spoiler_side, 6),
-                SpoilerController::new(context, spoiler_side, 7),
-                SpoilerController::new(context, spoiler_side, 8),
+                SpoilerController::new(context, spoiler_side, 1, actuator_id(1)),
+                SpoilerController::new(context, spoiler_side, 2, actuator_id(2)),
+                SpoilerController::new(context, spoiler_side, 3, actuator_id(3)),
+                SpoilerController::new(context, spoiler_side, 4, actuator_id(4)),
+                SpoilerController::new(context, spoiler_side, 5, actuator_id(5)),
+                SpoilerController::new(context, spoiler_side, 6, actuator_id(6)),
+                SpoilerController::new(context, spoiler_side, 7, actuator_id(7)),
+                SpoilerController::new(context, spoiler_side, 8, actuator_id(8)),
             ],
             spoiler_positions: [0.; 8],
         }
@@ -6466,6 +6869,10 @@ impl SpoilerGroup {
         green_section: &impl SectionPressure,
         yellow_section: &impl SectionPressure,
     ) {
+        for controller in &mut self.hydraulic_controllers {
+            controller.update(context);
+        }
+
         self.spoilers[0].update(
             context,
             &self.hydraulic_controllers[0],
@@ -6541,14 +6948,22 @@ impl SimulationElement for SpoilerGroup {
     }
 }
 
+/// A spoiler which actuator failure is detected is passivated by its computer.
 struct SpoilerController {
     position_demand_id: VariableIdentifier,
     electrical_mode_ena_id: Option<VariableIdentifier>,
     requested_position: Ratio,
     elec_backup_active: bool,
+
+    failures: FlightControlActuatorFailures,
 }
 impl SpoilerController {
-    fn new(context: &mut InitContext, spoiler_side: &str, spoiler_id_number: usize) -> Self {
+    fn new(
+        context: &mut InitContext,
+        spoiler_side: &str,
+        spoiler_id_number: usize,
+        actuator_id: FlightControlActuatorId,
+    ) -> Self {
         Self {
             position_demand_id: context.get_identifier(format!(
                 "{}_SPOILER_{}_COMMANDED_POSITION",
@@ -6567,20 +6982,30 @@ impl SpoilerController {
 
             requested_position: Ratio::new::<ratio>(0.),
             elec_backup_active: false,
+
+            failures: FlightControlActuatorFailures::new(actuator_id),
         }
     }
 
+    fn update(&mut self, context: &UpdateContext) {
+        self.failures.update(context, true);
+    }
+
     fn spoiler_actuator_position_from_surface_angle(surface_angle: Angle) -> Ratio {
         Ratio::new::<ratio>((surface_angle.get::<degree>() / 50.).min(1.).max(0.))
     }
 }
 impl HydraulicAssemblyController for SpoilerController {
     fn requested_mode(&self) -> LinearActuatorMode {
-        LinearActuatorMode::PositionControl
+        if self.failures.is_failure_detected() {
+            LinearActuatorMode::ActiveDamping
+        } else {
+            LinearActuatorMode::PositionControl
+        }
     }
 
     fn requested_position(&self) -> Ratio {
-        self.requested_position
+        self.failures.requested_position(self.requested_position)
     }
 
     fn should_lock(&self) -> bool {
@@ -6590,8 +7015,22 @@ impl HydraulicAssemblyController for SpoilerController {
     fn requested_lock_position(&self) -> Ratio {
         Ratio::default()
     }
+
+    fn is_jammed(&self) -> bool {
+        self.failures.is_jammed()
+    }
+
+    fn is_disconnected(&self) -> bool {
+        self.failures.is_disconnected()
+    }
 }
 impl SimulationElement for SpoilerController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failures.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.requested_position =
             Self::spoiler_actuator_position_from_surface_angle(Angle::new::<degree>(
@@ -6606,7 +7045,7 @@ impl SimulationElement for SpoilerController {
 impl HydraulicLocking for SpoilerController {}
 impl ElectroHydrostaticPowered for SpoilerController {
     fn should_activate_electrical_mode(&self) -> bool {
-        self.elec_backup_active
+        self.elec_backup_active && !self.failures.is_failure_detected()
     }
 }
 
@@ -8305,6 +8744,70 @@ mod tests {
             );
         }
 
+        #[test]
+        fn outward_left_aileron_panel_stays_in_position_with_jammed_actuator() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.is_green_pressure_switch_pressurised());
+
+            test_bed.fail(FailureType::FlightControlActuatorJam(
+                FlightControlActuatorId::LeftAileron(2),
+            ));
+            test_bed = test_bed
+                .reset_all_aileron_commands()
+                .set_aileron_panel_neutral(
+                    ActuatorSide::Left,
+                    AileronPanelPosition::Outward,
+                    AileronActuatorPosition::Outward,
+                )
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(
+                test_bed
+                    .get_left_aileron_panel_position(AileronPanelPosition::Outward)
+                    .get::<ratio>()
+                    < 0.1
+            );
+        }
+
+        #[test]
+        fn outward_left_aileron_panel_disconnected_actuator_is_passivated() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.is_green_pressure_switch_pressurised());
+            assert!(!test_bed.is_yellow_pressure_switch_pressurised());
+
+            // Inward actuator taking over has no yellow pressure to move the panel
+            test_bed.fail(FailureType::FlightControlActuatorDisconnect(
+                FlightControlActuatorId::LeftAileron(1),
+            ));
+            test_bed = test_bed
+                .reset_all_aileron_commands()
+                .set_aileron_panel_neutral(
+                    ActuatorSide::Left,
+                    AileronPanelPosition::Outward,
+                    AileronActuatorPosition::Outward,
+                )
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(
+                test_bed
+                    .get_left_aileron_panel_position(AileronPanelPosition::Outward)
+                    .get::<ratio>()
+                    < 0.1
+            );
+        }
+
         #[test]
         fn outward_right_aileron_panel_responds_only_with_green_pressure_on_outward_jack() {
             let mut test_bed = test_bed_on_ground_with()
@@ -9635,6 +10138,46 @@ mod tests {
             assert!(test_bed.autobrake_mode() == AutobrakeMode::NONE);
         }
 
+        #[test]
+        fn autobrakes_disarms_if_wheel_speed_sensor_failed() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_gear_lever_up()
+                .run_waiting_for(Duration::from_secs(12));
+
+            test_bed = test_bed
+                .set_autobrake_med()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::MED);
+
+            test_bed.fail(FailureType::WheelSpeedSensor(2));
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::NONE);
+        }
+
+        #[test]
+        fn autobrakes_disarms_if_anti_skid_failed() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_gear_lever_up()
+                .run_waiting_for(Duration::from_secs(12));
+
+            test_bed = test_bed
+                .set_autobrake_med()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::MED);
+
+            test_bed.fail(FailureType::AntiSkid);
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::NONE);
+        }
+
         #[test]
         fn autobrakes_max_wont_arm_in_flight() {
             let mut test_bed = test_bed_on_ground_with()
@@ -10277,6 +10820,29 @@ mod tests {
             assert!(test_bed.nose_steering_position().get::<degree>() <= 0.1);
         }
 
+        #[test]
+        fn nose_steering_does_not_move_when_all_wheel_speed_sensors_failed() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_yellow_e_pump_a(false)
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .run_one_tick();
+
+            for number in 1..=4 {
+                test_bed.fail(FailureType::WheelSpeedSensor(number));
+            }
+
+            test_bed = test_bed
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.nose_steering_position().get::<degree>() >= -0.1);
+            assert!(test_bed.nose_steering_position().get::<degree>() <= 0.1);
+        }
+
         #[test]
         fn yellow_epump_has_cavitation_at_low_air_press() {
             let mut test_bed = test_bed_on_ground_with()
@@ -5,6 +5,7 @@ mod airframe;
 mod avionics_data_communication_network;
 mod control_display_system;
 mod electrical;
+mod failures;
 mod fuel;
 pub mod hydraulic;
 mod icing;
@@ -27,6 +28,7 @@ use electrical::{
     A380Electrical, A380ElectricalOverheadPanel, A380EmergencyElectricalOverheadPanel,
     APU_START_MOTOR_BUS_TYPE,
 };
+pub use failures::failures;
 use fuel::FuelLevel;
 use hydraulic::{A380Hydraulic, A380HydraulicOverheadPanel};
 use icing::Icing;
@@ -38,8 +40,8 @@ use uom::si::{f64::Length, length::nautical_mile};
 use systems::{
     accept_iterable,
     apu::{
-        Aps3200ApuGenerator, Aps3200StartMotor, AuxiliaryPowerUnit, AuxiliaryPowerUnitFactory,
-        AuxiliaryPowerUnitFireOverheadPanel, AuxiliaryPowerUnitOverheadPanel,
+        Aps3200StartMotor, AuxiliaryPowerUnit, AuxiliaryPowerUnitFactory,
+        AuxiliaryPowerUnitFireOverheadPanel, AuxiliaryPowerUnitOverheadPanel, Pw980ApuGenerator,
     },
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
     engine::{trent_engine::TrentEngine, EngineFireOverheadPanel},
@@ -60,7 +62,7 @@ pub struct A380 {
     adirs: AirDataInertialReferenceSystem,
     adirs_overhead: AirDataInertialReferenceSystemOverheadPanel,
     air_conditioning: A380AirConditioning,
-    apu: AuxiliaryPowerUnit<Aps3200ApuGenerator, Aps3200StartMotor, 2>,
+    apu: AuxiliaryPowerUnit<Pw980ApuGenerator, Aps3200StartMotor, 2>,
     apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel,
     apu_overhead: AuxiliaryPowerUnitOverheadPanel,
     pneumatic_overhead: A380PneumaticOverheadPanel,
@@ -163,7 +165,7 @@ impl Aircraft for A380 {
             context,
             &self.apu_overhead,
             &self.apu_fire_overhead,
-            self.pneumatic_overhead.apu_bleed_is_on(),
+            self.pneumatic_overhead.apu_bleed_is_on() && !self.pneumatic.apu_bleed_is_isolated(),
             // This will be replaced when integrating the whole electrical system.
             // For now we use the same logic as found in the JavaScript code; ignoring whether or not
             // the engine generators are supplying electricity.
@@ -207,6 +209,9 @@ impl Aircraft for A380 {
         self.apu_overhead.update_after_apu(&self.apu);
 
         self.adcn.update();
+
+        self.fuel
+            .update(context, self.airframe.gross_weight_center_of_gravity());
         self.lgcius.update(
             context,
             &self.landing_gear,
@@ -257,6 +262,8 @@ impl Aircraft for A380 {
             &self.apu,
             &self.air_conditioning,
         );
+        self.pneumatic_overhead
+            .update_after_pneumatic(&self.pneumatic);
         self.air_conditioning
             .mix_packs_air_update(self.pneumatic.packs());
         self.air_conditioning.update(
@@ -279,8 +286,6 @@ impl Aircraft for A380 {
 
         self.cds.update();
 
-        self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
-
         self.structural_flex.update(
             context,
             [
@@ -295,7 +300,15 @@ impl Aircraft for A380 {
 
         self.icing_simulation.update(context);
 
-        self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
+        self.egpwc.update(
+            context,
+            &self.adirs,
+            self.lgcius.lgciu1(),
+            [
+                self.radio_altimeters.radio_altimeter_1(),
+                self.radio_altimeters.radio_altimeter_2(),
+            ],
+        );
     }
 }
 impl SimulationElement for A380 {
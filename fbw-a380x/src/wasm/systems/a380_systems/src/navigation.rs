@@ -1,8 +1,8 @@
 use systems::navigation::ala52b::{
     Ala52BAircraftInstallationDelay, Ala52BRadioAltimeter, Ala52BTransceiverPair,
 };
-use systems::navigation::radio_altimeter::AntennaInstallation;
-use systems::shared::ElectricalBusType;
+use systems::navigation::radio_altimeter::{AntennaInstallation, RadioAltimeter};
+use systems::shared::{arinc429::Arinc429Word, ElectricalBusType};
 use systems::simulation::{
     InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
 };
@@ -69,6 +69,14 @@ impl A380RadioAltimeters {
         }
     }
 
+    pub fn radio_altimeter_1(&self) -> &A380RadioAltimeter {
+        &self.radio_altimeter_1
+    }
+
+    pub fn radio_altimeter_2(&self) -> &A380RadioAltimeter {
+        &self.radio_altimeter_2
+    }
+
     pub fn update(&mut self, context: &UpdateContext) {
         self.radio_altimeter_1.update(context);
         self.radio_altimeter_2.update(context);
@@ -115,6 +123,12 @@ impl A380RadioAltimeter {
     }
 }
 
+impl RadioAltimeter for A380RadioAltimeter {
+    fn radio_altitude(&self) -> Arinc429Word<Length> {
+        self.radio_altimeter.radio_altitude()
+    }
+}
+
 impl SimulationElement for A380RadioAltimeter {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.transceivers.accept(visitor);
@@ -12,24 +12,26 @@ use uom::si::{
 use systems::{
     accept_iterable,
     air_conditioning::PackFlowControllers,
+    engine::{fadec::FullAuthorityDigitalEngineControl, trent_engine::TrentEngine},
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
-        valve::*, BleedMonitoringComputerIsAliveSignal, CompressionChamber,
-        ControllablePneumaticValve, CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode,
-        DifferentialPressureTransducer, EngineCompressionChamberController, EngineModeSelector,
-        EngineState, PneumaticContainer, PneumaticPipe, PneumaticValveSignal, Precooler,
-        PressureTransducer, PressurisedReservoirWithExhaustValve, PressurizeableReservoir,
+        valve::*, BleedDuct, BleedDuctLeak, BleedLeakDetection, BleedLeakMonitor,
+        BleedMonitoringComputerIsAliveSignal, CompressionChamber, ControllablePneumaticValve,
+        CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode, DifferentialPressureTransducer,
+        EngineCompressionChamberController, EngineModeSelector, EngineState, OverheatDetectionLoop,
+        PneumaticContainer, PneumaticPipe, PneumaticValveSignal, Precooler, PressureTransducer,
+        PressurisedReservoirWithExhaustValve, PressurizeableReservoir,
         TargetPressureTemperatureSignal, VariableVolumeContainer,
     },
     shared::{
         pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, ElectricalBusType,
         ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1, EngineCorrectedN2,
-        EngineFirePushButtons, EngineStartState, HydraulicColor, PackFlowValveState,
-        PneumaticBleed, PneumaticValve, ReservoirAirPressure,
+        EngineFirePushButtons, EngineStartState, EngineUncorrectedN2, HydraulicColor,
+        PackFlowValveState, PneumaticBleed, PneumaticValve, ReservoirAirPressure,
     },
     simulation::{
-        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
     },
 };
 
@@ -110,18 +112,25 @@ pub struct A380Pneumatic {
     physics_updater: MaxStepLoop,
 
     apu_bleed_air_valve_open_id: VariableIdentifier,
+    engine_leak_ids: [VariableIdentifier; 4],
+    wing_leak_ids: [VariableIdentifier; 2],
+    apu_leak_id: VariableIdentifier,
 
     core_processing_input_output_module_a: CoreProcessingInputOutputModuleA,
     engine_systems: [EngineBleedAirSystem; 4],
 
     cross_bleed_valves: [CrossBleedValve; 3],
 
-    fadec: FullAuthorityDigitalEngineControl,
+    fadec: FullAuthorityDigitalEngineControl<4>,
     engine_starter_valve_controllers: [EngineStarterValveController; 4],
 
     apu_compression_chamber: CompressionChamber,
     apu_bleed_air_valve: DefaultValve,
 
+    wing_leaks: [BleedDuctLeak; 2],
+    apu_bleed_leak: BleedDuctLeak,
+    leak_detection_loops: A380BleedLeakDetectionLoops,
+
     hydraulic_reservoir_bleed_air_valves: [PurelyPneumaticValve; 2],
     hydraulic_reservoir_bleed_air_pipe: PneumaticPipe,
 
@@ -140,6 +149,11 @@ impl A380Pneumatic {
             physics_updater: MaxStepLoop::new(Self::PNEUMATIC_SIM_MAX_TIME_STEP),
             apu_bleed_air_valve_open_id: context
                 .get_identifier("APU_BLEED_AIR_VALVE_OPEN".to_owned()),
+            engine_leak_ids: [1, 2, 3, 4]
+                .map(|number| context.get_identifier(format!("PNEU_ENG_{}_LEAK", number))),
+            wing_leak_ids: [1, 2]
+                .map(|number| context.get_identifier(format!("PNEU_WING_{}_LEAK", number))),
+            apu_leak_id: context.get_identifier("PNEU_APU_LEAK".to_owned()),
             core_processing_input_output_module_a: CoreProcessingInputOutputModuleA::new(
                 ElectricalBusType::DirectCurrentEssential, // TTM 2
             ),
@@ -154,7 +168,10 @@ impl A380Pneumatic {
                 CrossBleedValve::new(context.get_identifier("PNEU_XBLEED_VALVE_C_OPEN".to_owned())),
                 CrossBleedValve::new(context.get_identifier("PNEU_XBLEED_VALVE_R_OPEN".to_owned())),
             ],
-            fadec: FullAuthorityDigitalEngineControl::new(context),
+            fadec: FullAuthorityDigitalEngineControl::new(
+                context,
+                TrentEngine::START_SEQUENCE_PARAMETERS,
+            ),
             engine_starter_valve_controllers: [
                 EngineStarterValveController::new(1),
                 EngineStarterValveController::new(2),
@@ -163,6 +180,12 @@ impl A380Pneumatic {
             ],
             apu_compression_chamber: CompressionChamber::new(Volume::new::<cubic_meter>(5.)),
             apu_bleed_air_valve: DefaultValve::new_closed(),
+            wing_leaks: [
+                BleedDuctLeak::new(BleedDuct::Wing(1)),
+                BleedDuctLeak::new(BleedDuct::Wing(2)),
+            ],
+            apu_bleed_leak: BleedDuctLeak::new(BleedDuct::Apu),
+            leak_detection_loops: A380BleedLeakDetectionLoops::new(),
             hydraulic_reservoir_bleed_air_valves: [
                 PurelyPneumaticValve::new(),
                 PurelyPneumaticValve::new(),
@@ -201,12 +224,14 @@ impl A380Pneumatic {
     pub(crate) fn update(
         &mut self,
         context: &UpdateContext,
-        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2); 4],
+        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2 + EngineUncorrectedN2); 4],
         pneumatic_overhead_panel: &A380PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
         pack_flow_valve_signals: &impl PackFlowControllers,
     ) {
+        self.fadec.update(context, engines);
+
         self.physics_updater.update(context);
 
         for cur_time_step in self.physics_updater {
@@ -232,9 +257,19 @@ impl A380Pneumatic {
     ) {
         self.apu_compression_chamber.update(apu);
 
+        self.apu_bleed_leak
+            .update_move_fluid(context, &mut self.apu_compression_chamber);
+        self.leak_detection_loops.update(
+            context,
+            &self.engine_systems,
+            &self.wing_leaks,
+            &self.apu_bleed_leak,
+        );
+
         self.core_processing_input_output_module_a.update(
             context,
             &self.engine_systems,
+            &self.leak_detection_loops,
             &self.apu_bleed_air_valve,
             overhead_panel,
             engine_fire_push_buttons,
@@ -269,6 +304,12 @@ impl A380Pneumatic {
 
         let [engine_1_system, engine_2_system, engine_3_system, engine_4_system] =
             &mut self.engine_systems;
+
+        // The wing ducts are fed by the inner engines, and by the outer engines through the left and right crossbleed valves
+        let [left_wing_leak, right_wing_leak] = &mut self.wing_leaks;
+        left_wing_leak.update_move_fluid(context, engine_2_system);
+        right_wing_leak.update_move_fluid(context, engine_3_system);
+
         self.apu_bleed_air_valve.update_move_fluid(
             context,
             &mut self.apu_compression_chamber,
@@ -339,6 +380,21 @@ impl A380Pneumatic {
         &mut self.apu_bleed_air_valve
     }
 
+    /// Indicates if the CPIOM commands the APU bleed valve closed because of a leak
+    /// in the left wing or APU bleed duct.
+    pub fn apu_bleed_is_isolated(&self) -> bool {
+        self.core_processing_input_output_module_a.units[0].should_isolate_apu_bleed()
+    }
+
+    pub fn engine_bleed_has_fault(&self, engine_number: usize) -> bool {
+        self.core_processing_input_output_module_a.units[engine_number - 1]
+            .should_isolate_engine_bleed()
+    }
+
+    pub fn apu_bleed_has_fault(&self) -> bool {
+        self.core_processing_input_output_module_a.units[0].has_apu_leak()
+    }
+
     pub fn update_hydraulic_reservoir_spatial_volumes(
         &mut self,
         green_hydraulic_reservoir: &impl PressurizeableReservoir,
@@ -401,9 +457,11 @@ impl PackFlowValveState for A380Pneumatic {
 impl SimulationElement for A380Pneumatic {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fadec.accept(visitor);
+        self.apu_bleed_leak.accept(visitor);
 
         accept_iterable!(self.cross_bleed_valves, visitor);
         accept_iterable!(self.engine_systems, visitor);
+        accept_iterable!(self.wing_leaks, visitor);
         accept_iterable!(self.packs, visitor);
 
         self.yellow_hydraulic_reservoir_with_valve.accept(visitor);
@@ -417,6 +475,15 @@ impl SimulationElement for A380Pneumatic {
             &self.apu_bleed_air_valve_open_id,
             self.apu_bleed_air_valve.is_open(),
         );
+
+        let units = &self.core_processing_input_output_module_a.units;
+        for (id, unit) in self.engine_leak_ids.iter().zip(units) {
+            writer.write(id, unit.has_engine_leak());
+        }
+        for (wing_number, id) in (1..=2).zip(&self.wing_leak_ids) {
+            writer.write(id, units[0].has_wing_leak(wing_number));
+        }
+        writer.write(&self.apu_leak_id, units[0].has_apu_leak());
     }
 }
 impl ReservoirAirPressure for A380Pneumatic {
@@ -435,13 +502,14 @@ impl ReservoirAirPressure for A380Pneumatic {
 
 struct EngineStarterValveController {
     number: usize,
-    engine_state: EngineState,
+    is_commanded_open: bool,
 }
 impl ControllerSignal<EngineStarterValveSignal> for EngineStarterValveController {
     fn signal(&self) -> Option<EngineStarterValveSignal> {
-        match self.engine_state {
-            EngineState::Starting => Some(EngineStarterValveSignal::new_open()),
-            _ => Some(EngineStarterValveSignal::new_closed()),
+        if self.is_commanded_open {
+            Some(EngineStarterValveSignal::new_open())
+        } else {
+            Some(EngineStarterValveSignal::new_closed())
         }
     }
 }
@@ -449,12 +517,12 @@ impl EngineStarterValveController {
     fn new(number: usize) -> Self {
         Self {
             number,
-            engine_state: EngineState::Off,
+            is_commanded_open: false,
         }
     }
 
-    fn update(&mut self, fadec: &FullAuthorityDigitalEngineControl) {
-        self.engine_state = fadec.engine_state(self.number);
+    fn update(&mut self, fadec: &FullAuthorityDigitalEngineControl<4>) {
+        self.is_commanded_open = fadec.start_valve_is_commanded_open(self.number);
     }
 }
 
@@ -484,6 +552,7 @@ impl CoreProcessingInputOutputModuleA {
         &mut self,
         context: &UpdateContext,
         sensors: &[EngineBleedAirSystem; 4],
+        leak_detection: &impl BleedLeakDetection,
         apu_bleed_valve: &impl PneumaticValve,
         pneumatic_overhead_panel: &A380PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
@@ -492,6 +561,7 @@ impl CoreProcessingInputOutputModuleA {
             unit.update(
                 context,
                 sensor,
+                leak_detection,
                 engine_fire_push_buttons.is_released(unit.engine_number),
                 apu_bleed_valve,
                 pneumatic_overhead_panel,
@@ -534,6 +604,9 @@ struct CoreProcessingInputOutputModuleAUnit {
     pressure_regulating_valve_pid: PidController,
     fan_air_valve_pid: PidController,
     cross_bleed_valve_selector: CrossBleedValveSelectorMode,
+    engine_leak_monitor: BleedLeakMonitor,
+    wing_leak_monitors: [BleedLeakMonitor; 2],
+    apu_leak_monitor: BleedLeakMonitor,
 }
 impl CoreProcessingInputOutputModuleAUnit {
     const PRESSURE_REGULATING_VALVE_TARGET_PSI: f64 = 40.; // FCOM
@@ -563,6 +636,9 @@ impl CoreProcessingInputOutputModuleAUnit {
             ),
             fan_air_valve_pid: PidController::new(-0.005, -0.001, 0., 0., 1., 200., 1.),
             cross_bleed_valve_selector: CrossBleedValveSelectorMode::Auto,
+            engine_leak_monitor: BleedLeakMonitor::default(),
+            wing_leak_monitors: [BleedLeakMonitor::default(), BleedLeakMonitor::default()],
+            apu_leak_monitor: BleedLeakMonitor::default(),
         }
     }
 
@@ -570,6 +646,7 @@ impl CoreProcessingInputOutputModuleAUnit {
         &mut self,
         context: &UpdateContext,
         sensors: &EngineBleedAirSystem,
+        leak_detection: &impl BleedLeakDetection,
         is_engine_fire_pushbutton_released: bool,
         apu_bleed_valve: &impl PneumaticValve,
         pneumatic_overhead_panel: &A380PneumaticOverheadPanel,
@@ -612,6 +689,73 @@ impl CoreProcessingInputOutputModuleAUnit {
         self.is_apu_bleed_on = pneumatic_overhead_panel.apu_bleed_is_on();
 
         self.cross_bleed_valve_selector = pneumatic_overhead_panel.cross_bleed_mode();
+
+        self.update_leak_monitors(leak_detection, pneumatic_overhead_panel);
+    }
+
+    fn update_leak_monitors(
+        &mut self,
+        leak_detection: &impl BleedLeakDetection,
+        pneumatic_overhead_panel: &A380PneumaticOverheadPanel,
+    ) {
+        // A latched leak is reset by switching off all bleed sources feeding the leaking duct
+        self.engine_leak_monitor.update(
+            leak_detection.has_overheat(BleedDuct::Engine(self.engine_number)),
+            !self.is_engine_bleed_pushbutton_auto,
+        );
+
+        for (index, monitor) in self.wing_leak_monitors.iter_mut().enumerate() {
+            let wing_number = index + 1;
+            monitor.update(
+                leak_detection.has_overheat(BleedDuct::Wing(wing_number)),
+                Self::engines_feeding_wing(wing_number)
+                    .into_iter()
+                    .all(|engine_number| {
+                        !pneumatic_overhead_panel.engine_bleed_pb_is_auto(engine_number)
+                    }),
+            );
+        }
+
+        self.apu_leak_monitor.update(
+            leak_detection.has_overheat(BleedDuct::Apu),
+            !self.is_apu_bleed_on,
+        );
+    }
+
+    fn engines_feeding_wing(wing_number: usize) -> [usize; 2] {
+        match wing_number {
+            1 => [1, 2],
+            2 => [3, 4],
+            _ => panic!("Invalid wing number"),
+        }
+    }
+
+    fn has_engine_leak(&self) -> bool {
+        self.engine_leak_monitor.has_leak()
+    }
+
+    fn has_wing_leak(&self, wing_number: usize) -> bool {
+        self.wing_leak_monitors[wing_number - 1].has_leak()
+    }
+
+    fn has_apu_leak(&self) -> bool {
+        self.apu_leak_monitor.has_leak()
+    }
+
+    fn has_onside_wing_leak(&self) -> bool {
+        self.has_wing_leak(if self.engine_number <= 2 { 1 } else { 2 })
+    }
+
+    fn should_isolate_engine_bleed(&self) -> bool {
+        self.has_engine_leak() || self.has_onside_wing_leak()
+    }
+
+    fn should_isolate_cross_bleed(&self) -> bool {
+        self.has_wing_leak(1) || self.has_wing_leak(2) || self.has_apu_leak()
+    }
+
+    fn should_isolate_apu_bleed(&self) -> bool {
+        self.has_wing_leak(1) || self.has_apu_leak()
     }
 
     fn should_close_pressure_regulating_valve_because_apu_bleed_is_on(&self) -> bool {
@@ -622,6 +766,7 @@ impl ControllerSignal<HighPressureValveSignal> for CoreProcessingInputOutputModu
     fn signal(&self) -> Option<HighPressureValveSignal> {
         // TODO: Add overtemperature condition here
         if self.pressure_regulating_valve_is_closed
+            || self.should_isolate_engine_bleed()
             || self.high_pressure_compressor_pressure < Pressure::new::<psi>(15.)
             || self.intermediate_pressure_compressor_pressure > Pressure::new::<psi>(33.5)
         {
@@ -639,6 +784,7 @@ impl ControllerSignal<PressureRegulatingValveSignal> for CoreProcessingInputOutp
             || (!self.is_engine_bleed_pushbutton_auto || self.is_engine_fire_pushbutton_released)
             || self.should_close_pressure_regulating_valve_because_apu_bleed_is_on()
             || self.engine_starter_valve_is_open
+            || self.should_isolate_engine_bleed()
         {
             Some(PressureRegulatingValveSignal::new_closed())
         } else {
@@ -665,7 +811,9 @@ impl ControllerSignal<CrossBleedValveSignal> for CoreProcessingInputOutputModule
                 CrossBleedValveSignalType::Manual,
             )),
             CrossBleedValveSelectorMode::Auto => {
-                if self.is_apu_bleed_valve_open || self.is_any_bleed_pushbutton_off {
+                if (self.is_apu_bleed_valve_open || self.is_any_bleed_pushbutton_off)
+                    && !self.should_isolate_cross_bleed()
+                {
                     Some(CrossBleedValveSignal::new_open(
                         CrossBleedValveSignalType::Automatic,
                     ))
@@ -720,6 +868,8 @@ struct EngineBleedAirSystem {
     transfer_pressure_transducer: PressureTransducer,
     regulated_pressure_transducer: PressureTransducer,
     differential_pressure_transducer: DifferentialPressureTransducer,
+
+    engine_leak: BleedDuctLeak,
 }
 impl EngineBleedAirSystem {
     fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
@@ -809,6 +959,7 @@ impl EngineBleedAirSystem {
             transfer_pressure_transducer: PressureTransducer::new(powered_by),
             regulated_pressure_transducer: PressureTransducer::new(powered_by),
             differential_pressure_transducer: DifferentialPressureTransducer::new(powered_by),
+            engine_leak: BleedDuctLeak::new(BleedDuct::Engine(number)),
         }
     }
 
@@ -878,6 +1029,10 @@ impl EngineBleedAirSystem {
         );
         self.engine_starter_exhaust
             .update_move_fluid(context, &mut self.engine_starter_container);
+        // The pylon duct runs from the HP and IP ports onwards, a leak upstream of the
+        // pressure regulating valve releases the hottest air of the bleed system.
+        self.engine_leak
+            .update_move_fluid(context, &mut self.transfer_pressure_pipe);
 
         self.intermediate_pressure_transducer
             .update(context, &self.intermediate_pressure_compression_chamber);
@@ -931,6 +1086,10 @@ impl EngineBleedAirSystem {
         self.precooler_outlet_pipe.temperature()
     }
 
+    fn engine_leak(&self) -> &BleedDuctLeak {
+        &self.engine_leak
+    }
+
     fn engine_starter_valve_is_open(&self) -> bool {
         self.engine_starter_valve.is_open()
     }
@@ -966,6 +1125,8 @@ impl SimulationElement for EngineBleedAirSystem {
         self.regulated_pressure_transducer.accept(visitor);
         self.differential_pressure_transducer.accept(visitor);
 
+        self.engine_leak.accept(visitor);
+
         visitor.visit(self);
     }
 
@@ -1066,6 +1227,58 @@ impl PneumaticContainer for EngineBleedAirSystem {
     }
 }
 
+/// The overheat detection loops along the pylon, wing and APU bleed ducts.
+struct A380BleedLeakDetectionLoops {
+    engine_loops: [OverheatDetectionLoop; 4],
+    wing_loops: [OverheatDetectionLoop; 2],
+    apu_loop: OverheatDetectionLoop,
+}
+impl A380BleedLeakDetectionLoops {
+    const PYLON_LOOP_ALARM_TEMPERATURE_C: f64 = 204.;
+    const WING_AND_APU_LOOP_ALARM_TEMPERATURE_C: f64 = 124.;
+
+    fn new() -> Self {
+        let pylon_alarm_temperature =
+            ThermodynamicTemperature::new::<degree_celsius>(Self::PYLON_LOOP_ALARM_TEMPERATURE_C);
+        let wing_and_apu_alarm_temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            Self::WING_AND_APU_LOOP_ALARM_TEMPERATURE_C,
+        );
+
+        Self {
+            engine_loops: [(); 4].map(|_| OverheatDetectionLoop::new(pylon_alarm_temperature)),
+            wing_loops: [(); 2].map(|_| OverheatDetectionLoop::new(wing_and_apu_alarm_temperature)),
+            apu_loop: OverheatDetectionLoop::new(wing_and_apu_alarm_temperature),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine_systems: &[EngineBleedAirSystem; 4],
+        wing_leaks: &[BleedDuctLeak; 2],
+        apu_bleed_leak: &BleedDuctLeak,
+    ) {
+        for (engine_loop, engine_system) in self.engine_loops.iter_mut().zip(engine_systems) {
+            engine_loop.update(context, engine_system.engine_leak());
+        }
+
+        for (wing_loop, wing_leak) in self.wing_loops.iter_mut().zip(wing_leaks) {
+            wing_loop.update(context, wing_leak);
+        }
+
+        self.apu_loop.update(context, apu_bleed_leak);
+    }
+}
+impl BleedLeakDetection for A380BleedLeakDetectionLoops {
+    fn has_overheat(&self, duct: BleedDuct) -> bool {
+        match duct {
+            BleedDuct::Engine(number) => self.engine_loops[number - 1].has_overheat(),
+            BleedDuct::Wing(number) => self.wing_loops[number - 1].has_overheat(),
+            BleedDuct::Apu => self.apu_loop.has_overheat(),
+        }
+    }
+}
+
 pub struct A380PneumaticOverheadPanel {
     apu_bleed: OnOffFaultPushButton,
     cross_bleed: CrossBleedValveSelectorKnob,
@@ -1103,6 +1316,21 @@ impl A380PneumaticOverheadPanel {
             _ => panic!("Invalid engine number"),
         }
     }
+
+    pub fn update_after_pneumatic(&mut self, pneumatic: &A380Pneumatic) {
+        for (number, engine_bleed) in [
+            &mut self.engine_1_bleed,
+            &mut self.engine_2_bleed,
+            &mut self.engine_3_bleed,
+            &mut self.engine_4_bleed,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            engine_bleed.set_fault(pneumatic.engine_bleed_has_fault(number + 1));
+        }
+        self.apu_bleed.set_fault(pneumatic.apu_bleed_has_fault());
+    }
 }
 impl EngineBleedPushbutton<4> for A380PneumaticOverheadPanel {
     fn engine_bleed_pushbuttons_are_auto(&self) -> [bool; 4] {
@@ -1127,62 +1355,6 @@ impl SimulationElement for A380PneumaticOverheadPanel {
     }
 }
 
-/// We use this simply as an interface to engine parameter simvars. It should probably not be part of the pneumatic system.
-pub struct FullAuthorityDigitalEngineControl {
-    engine_1_state_id: VariableIdentifier,
-    engine_2_state_id: VariableIdentifier,
-    engine_3_state_id: VariableIdentifier,
-    engine_4_state_id: VariableIdentifier,
-
-    engine_1_state: EngineState,
-    engine_2_state: EngineState,
-    engine_3_state: EngineState,
-    engine_4_state: EngineState,
-
-    engine_mode_selector1_id: VariableIdentifier,
-    engine_mode_selector1_position: EngineModeSelector,
-}
-impl FullAuthorityDigitalEngineControl {
-    fn new(context: &mut InitContext) -> Self {
-        Self {
-            engine_1_state_id: context.get_identifier("ENGINE_STATE:1".to_owned()),
-            engine_2_state_id: context.get_identifier("ENGINE_STATE:2".to_owned()),
-            engine_3_state_id: context.get_identifier("ENGINE_STATE:3".to_owned()),
-            engine_4_state_id: context.get_identifier("ENGINE_STATE:4".to_owned()),
-            engine_1_state: EngineState::Off,
-            engine_2_state: EngineState::Off,
-            engine_3_state: EngineState::Off,
-            engine_4_state: EngineState::Off,
-            engine_mode_selector1_id: context
-                .get_identifier("TURB ENG IGNITION SWITCH EX1:1".to_owned()),
-            engine_mode_selector1_position: EngineModeSelector::Norm,
-        }
-    }
-
-    fn engine_state(&self, number: usize) -> EngineState {
-        match number {
-            1 => self.engine_1_state,
-            2 => self.engine_2_state,
-            3 => self.engine_3_state,
-            4 => self.engine_4_state,
-            _ => panic!("Invalid engine number"),
-        }
-    }
-
-    fn engine_mode_selector(&self) -> EngineModeSelector {
-        self.engine_mode_selector1_position
-    }
-}
-impl SimulationElement for FullAuthorityDigitalEngineControl {
-    fn read(&mut self, reader: &mut SimulatorReader) {
-        self.engine_1_state = reader.read(&self.engine_1_state_id);
-        self.engine_2_state = reader.read(&self.engine_2_state_id);
-        self.engine_3_state = reader.read(&self.engine_3_state_id);
-        self.engine_4_state = reader.read(&self.engine_4_state_id);
-        self.engine_mode_selector1_position = reader.read(&self.engine_mode_selector1_id);
-    }
-}
-
 /// A struct to hold all the pack related components
 pub struct PackComplex {
     pack_number: usize,
@@ -1439,8 +1611,8 @@ mod tests {
         engine::trent_engine::TrentEngine,
         failures::FailureType,
         pneumatic::{
-            ControllablePneumaticValve, CrossBleedValveSelectorMode, EngineState,
-            PneumaticContainer, PneumaticValveSignal, TargetPressureTemperatureSignal,
+            BleedDuct, ControllablePneumaticValve, CrossBleedValveSelectorMode, EngineModeSelector,
+            EngineState, PneumaticContainer, PneumaticValveSignal, TargetPressureTemperatureSignal,
         },
         shared::{
             arinc429::{Arinc429Word, SignStatus},
@@ -1451,7 +1623,7 @@ mod tests {
             PneumaticBleed, PneumaticValve, PotentialOrigin,
         },
         simulation::{
-            test::{SimulationTestBed, TestBed, WriteByName},
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
             Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
         },
     };
@@ -1810,6 +1982,8 @@ mod tests {
                 &self.apu,
                 &self.air_conditioning,
             );
+            self.pneumatic_overhead_panel
+                .update_after_pneumatic(&self.pneumatic);
             self.air_conditioning.update(
                 context,
                 [
@@ -1877,6 +2051,10 @@ mod tests {
             self
         }
 
+        fn run_multiple_frames(&mut self, duration: Duration) {
+            self.test_bed.run_multiple_frames(duration);
+        }
+
         fn mach_number(mut self, mach: MachNumber) -> Self {
             self.write_by_name("AIRSPEED MACH", mach);
 
@@ -1899,7 +2077,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.55));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.2));
-            self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N2:1", Ratio::new::<ratio>(0.55));
 
             self
         }
@@ -1908,7 +2086,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.55));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.2));
-            self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N2:2", Ratio::new::<ratio>(0.55));
 
             self
         }
@@ -1917,7 +2095,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:3", true);
             self.write_by_name("TURB ENG CORRECTED N2:3", Ratio::new::<ratio>(0.55));
             self.write_by_name("TURB ENG CORRECTED N1:3", Ratio::new::<ratio>(0.2));
-            self.write_by_name("ENGINE_STATE:3", EngineState::On);
+            self.write_by_name("ENGINE_N2:3", Ratio::new::<ratio>(0.55));
 
             self
         }
@@ -1926,7 +2104,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:4", true);
             self.write_by_name("TURB ENG CORRECTED N2:4", Ratio::new::<ratio>(0.55));
             self.write_by_name("TURB ENG CORRECTED N1:4", Ratio::new::<ratio>(0.2));
-            self.write_by_name("ENGINE_STATE:4", EngineState::On);
+            self.write_by_name("ENGINE_N2:4", Ratio::new::<ratio>(0.55));
 
             self
         }
@@ -1935,7 +2113,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.65));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.5));
-            self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N2:1", Ratio::new::<ratio>(0.65));
 
             self
         }
@@ -1944,7 +2122,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.65));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.5));
-            self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N2:2", Ratio::new::<ratio>(0.65));
 
             self
         }
@@ -1953,7 +2131,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:3", true);
             self.write_by_name("TURB ENG CORRECTED N2:3", Ratio::new::<ratio>(0.65));
             self.write_by_name("TURB ENG CORRECTED N1:3", Ratio::new::<ratio>(0.5));
-            self.write_by_name("ENGINE_STATE:3", EngineState::On);
+            self.write_by_name("ENGINE_N2:3", Ratio::new::<ratio>(0.65));
 
             self
         }
@@ -1962,7 +2140,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:4", true);
             self.write_by_name("TURB ENG CORRECTED N2:4", Ratio::new::<ratio>(0.65));
             self.write_by_name("TURB ENG CORRECTED N1:4", Ratio::new::<ratio>(0.5));
-            self.write_by_name("ENGINE_STATE:4", EngineState::On);
+            self.write_by_name("ENGINE_N2:4", Ratio::new::<ratio>(0.65));
 
             self
         }
@@ -1971,7 +2149,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", false);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.));
-            self.write_by_name("ENGINE_STATE:1", EngineState::Off);
+            self.write_by_name("ENGINE_N2:1", Ratio::new::<ratio>(0.));
 
             self
         }
@@ -1980,7 +2158,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", false);
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.));
-            self.write_by_name("ENGINE_STATE:2", EngineState::Off);
+            self.write_by_name("ENGINE_N2:2", Ratio::new::<ratio>(0.));
 
             self
         }
@@ -1989,7 +2167,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:3", false);
             self.write_by_name("TURB ENG CORRECTED N2:3", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:3", Ratio::new::<ratio>(0.));
-            self.write_by_name("ENGINE_STATE:3", EngineState::Off);
+            self.write_by_name("ENGINE_N2:3", Ratio::new::<ratio>(0.));
 
             self
         }
@@ -1998,7 +2176,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:4", false);
             self.write_by_name("TURB ENG CORRECTED N2:4", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:4", Ratio::new::<ratio>(0.));
-            self.write_by_name("ENGINE_STATE:4", EngineState::Off);
+            self.write_by_name("ENGINE_N2:4", Ratio::new::<ratio>(0.));
 
             self
         }
@@ -2006,7 +2184,7 @@ mod tests {
         fn eng1_n1(mut self, n1: f64) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(n1));
-            self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N2:1", Ratio::new::<ratio>(0.7));
 
             self
         }
@@ -2014,7 +2192,7 @@ mod tests {
         fn eng2_n1(mut self, n1: f64) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(n1));
-            self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N2:2", Ratio::new::<ratio>(0.7));
 
             self
         }
@@ -2022,7 +2200,7 @@ mod tests {
         fn eng3_n1(mut self, n1: f64) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:3", true);
             self.write_by_name("TURB ENG CORRECTED N1:3", Ratio::new::<ratio>(n1));
-            self.write_by_name("ENGINE_STATE:3", EngineState::On);
+            self.write_by_name("ENGINE_N2:3", Ratio::new::<ratio>(0.7));
 
             self
         }
@@ -2030,7 +2208,7 @@ mod tests {
         fn eng4_n1(mut self, n1: f64) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:4", true);
             self.write_by_name("TURB ENG CORRECTED N1:4", Ratio::new::<ratio>(n1));
-            self.write_by_name("ENGINE_STATE:4", EngineState::On);
+            self.write_by_name("ENGINE_N2:4", Ratio::new::<ratio>(0.7));
 
             self
         }
@@ -2059,30 +2237,17 @@ mod tests {
             self
         }
 
-        fn start_eng1(mut self) -> Self {
-            self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
-            self.write_by_name("ENGINE_STATE:1", EngineState::Starting);
-
-            self
-        }
-
-        fn start_eng2(mut self) -> Self {
-            self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
-            self.write_by_name("ENGINE_STATE:2", EngineState::Starting);
-
-            self
-        }
-
-        fn start_eng3(mut self) -> Self {
-            self.write_by_name("GENERAL ENG STARTER ACTIVE:3", true);
-            self.write_by_name("ENGINE_STATE:3", EngineState::Starting);
-
-            self
-        }
+        fn start_engine(mut self, number: usize) -> Self {
+            // A start sequence only begins after the engine master has been off.
+            self.write_by_name(&format!("GENERAL ENG STARTER ACTIVE:{}", number), false);
+            self.write_by_name(&format!("ENGINE_N2:{}", number), Ratio::new::<ratio>(0.));
+            self.run();
 
-        fn start_eng4(mut self) -> Self {
-            self.write_by_name("GENERAL ENG STARTER ACTIVE:4", true);
-            self.write_by_name("ENGINE_STATE:4", EngineState::Starting);
+            self.write_by_name(&format!("GENERAL ENG STARTER ACTIVE:{}", number), true);
+            self.write_by_name(
+                "TURB ENG IGNITION SWITCH EX1:1",
+                EngineModeSelector::Ignition,
+            );
 
             self
         }
@@ -2274,8 +2439,15 @@ mod tests {
             self
         }
 
-        fn set_engine_state(mut self, number: usize, engine_state: EngineState) -> Self {
-            self.write_by_name(&format!("ENGINE_STATE:{}", number), engine_state);
+        fn eng_uncorrected_n2(mut self, number: usize, n2: f64) -> Self {
+            self.write_by_name(&format!("ENGINE_N2:{}", number), Ratio::new::<ratio>(n2));
+
+            self
+        }
+
+        fn shut_down_eng(mut self, number: usize, n2: f64) -> Self {
+            self.write_by_name(&format!("GENERAL ENG STARTER ACTIVE:{}", number), false);
+            self.write_by_name(&format!("ENGINE_N2:{}", number), Ratio::new::<ratio>(n2));
 
             self
         }
@@ -2284,6 +2456,45 @@ mod tests {
             self.query(|a| a.pneumatic.fadec.engine_state(number))
         }
 
+        fn bleed_duct_leak(mut self, duct: BleedDuct) -> Self {
+            self.fail(FailureType::BleedDuctLeak(duct));
+
+            self
+        }
+
+        fn has_engine_leak(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_ENG_{}_LEAK", number))
+        }
+
+        fn has_wing_leak(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_WING_{}_LEAK", number))
+        }
+
+        fn has_apu_leak(&mut self) -> bool {
+            self.read_by_name("PNEU_APU_LEAK")
+        }
+
+        fn engine_bleed_push_button_has_fault(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("OVHD_PNEU_ENG_{}_BLEED_PB_HAS_FAULT", number))
+        }
+
+        fn apu_bleed_push_button_has_fault(&mut self) -> bool {
+            self.read_by_name("OVHD_PNEU_APU_BLEED_PB_HAS_FAULT")
+        }
+
+        fn apu_bleed_is_isolated(&self) -> bool {
+            self.query(|a| a.pneumatic.apu_bleed_is_isolated())
+        }
+
+        fn cross_bleed_valves_are_closed(&self) -> bool {
+            self.query(|a| {
+                a.pneumatic
+                    .cross_bleed_valves
+                    .iter()
+                    .all(|cbv| !cbv.is_open())
+            })
+        }
+
         fn cross_bleed_valves_are_open(&self) -> bool {
             self.query(|a| {
                 a.pneumatic
@@ -2838,14 +3049,7 @@ mod tests {
 
         assert!(!test_bed.es_valve_is_open(engine_number));
 
-        test_bed = match engine_number {
-            1 => test_bed.start_eng1(),
-            2 => test_bed.start_eng2(),
-            3 => test_bed.start_eng3(),
-            4 => test_bed.start_eng4(),
-            _ => panic!("Unexpected engine number"),
-        }
-        .and_run();
+        test_bed = test_bed.start_engine(engine_number).and_run();
 
         assert!(test_bed.es_valve_is_open(engine_number));
     }
@@ -3021,6 +3225,90 @@ mod tests {
         assert!(test_bed.contains_variable_with_name("PNEU_XBLEED_VALVE_L_OPEN"));
         assert!(test_bed.contains_variable_with_name("PNEU_XBLEED_VALVE_C_OPEN"));
         assert!(test_bed.contains_variable_with_name("PNEU_XBLEED_VALVE_R_OPEN"));
+
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_1_LEAK"));
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_2_LEAK"));
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_3_LEAK"));
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_4_LEAK"));
+
+        assert!(test_bed.contains_variable_with_name("PNEU_WING_1_LEAK"));
+        assert!(test_bed.contains_variable_with_name("PNEU_WING_2_LEAK"));
+
+        assert!(test_bed.contains_variable_with_name("PNEU_APU_LEAK"));
+    }
+
+    #[rstest]
+    fn engine_leak_closes_onside_bleed_valves(#[values(1, 2, 3, 4)] engine_number: usize) {
+        let mut test_bed = test_bed_with()
+            .in_isa_atmosphere(Length::new::<foot>(0.))
+            .eng1_n1(0.8)
+            .eng1_n2(0.9)
+            .eng2_n1(0.8)
+            .eng2_n2(0.9)
+            .eng3_n1(0.8)
+            .eng3_n2(0.9)
+            .eng4_n1(0.8)
+            .eng4_n2(0.9)
+            .and_stabilize()
+            .bleed_duct_leak(BleedDuct::Engine(engine_number));
+
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+
+        for i in 1..=4 {
+            assert_eq!(test_bed.has_engine_leak(i), i == engine_number);
+            assert_eq!(
+                test_bed.engine_bleed_push_button_has_fault(i),
+                i == engine_number
+            );
+            assert_eq!(test_bed.pr_valve_is_open(i), i != engine_number);
+        }
+        assert!(!test_bed.hp_valve_is_open(engine_number));
+    }
+
+    #[test]
+    fn apu_leak_closes_cross_bleed_valves_and_isolates_apu_bleed() {
+        let mut test_bed = test_bed_with()
+            .stop_eng1()
+            .stop_eng2()
+            .stop_eng3()
+            .stop_eng4()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+            .set_bleed_air_running()
+            .and_stabilize();
+
+        assert!(test_bed.cross_bleed_valves_are_open());
+        assert!(!test_bed.has_apu_leak());
+
+        test_bed = test_bed.bleed_duct_leak(BleedDuct::Apu);
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+
+        assert!(test_bed.has_apu_leak());
+        assert!(test_bed.apu_bleed_push_button_has_fault());
+        assert!(test_bed.apu_bleed_is_isolated());
+        assert!(test_bed.cross_bleed_valves_are_closed());
+    }
+
+    #[test]
+    fn apu_leak_resets_when_apu_bleed_is_switched_off() {
+        let mut test_bed = test_bed_with()
+            .set_bleed_air_running()
+            .and_stabilize()
+            .bleed_duct_leak(BleedDuct::Apu);
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+
+        assert!(test_bed.has_apu_leak());
+
+        test_bed.unfail(FailureType::BleedDuctLeak(BleedDuct::Apu));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        assert!(test_bed.has_apu_leak());
+
+        test_bed.write_by_name("OVHD_PNEU_APU_BLEED_PB_IS_ON", false);
+        test_bed = test_bed.and_run();
+
+        assert!(!test_bed.has_apu_leak());
+        assert!(!test_bed.has_wing_leak(1));
+        assert!(!test_bed.has_wing_leak(2));
     }
 
     #[rstest]
@@ -3129,22 +3417,35 @@ mod tests {
 
     #[rstest]
     fn fadec_represents_engine_state(#[values(1, 2, 3, 4)] engine: usize) {
-        let mut test_bed = test_bed_with().set_engine_state(engine, EngineState::Off);
+        let mut test_bed = test_bed_with().shut_down_eng(engine, 0.);
+        test_bed.set_on_ground(true);
+        test_bed = test_bed.and_run();
 
         assert_eq!(test_bed.engine_state(engine), EngineState::Off);
 
-        test_bed = test_bed
-            .set_engine_state(engine, EngineState::Starting)
-            .and_run();
+        test_bed = test_bed.start_engine(engine).and_run();
         assert_eq!(test_bed.engine_state(engine), EngineState::Starting);
+        assert!(test_bed.es_valve_is_open(engine));
 
-        test_bed = test_bed.set_engine_state(engine, EngineState::On).and_run();
+        test_bed = test_bed.eng_uncorrected_n2(engine, 0.6).and_run();
         assert_eq!(test_bed.engine_state(engine), EngineState::On);
 
-        test_bed = test_bed
-            .set_engine_state(engine, EngineState::Shutting)
-            .and_run();
+        test_bed = test_bed.shut_down_eng(engine, 0.3).and_run();
         assert_eq!(test_bed.engine_state(engine), EngineState::Shutting);
+
+        test_bed = test_bed.shut_down_eng(engine, 0.).and_run();
+        assert_eq!(test_bed.engine_state(engine), EngineState::Off);
+    }
+
+    #[rstest]
+    fn starter_valve_closes_at_starter_cutout(#[values(1, 2, 3, 4)] engine: usize) {
+        let mut test_bed = test_bed_with().start_engine(engine).and_run();
+
+        assert!(test_bed.es_valve_is_open(engine));
+
+        test_bed = test_bed.eng_uncorrected_n2(engine, 0.52).and_stabilize();
+
+        assert!(!test_bed.es_valve_is_open(engine));
     }
 
     #[test]
@@ -5,7 +5,7 @@ use systems_wasm::aspects::{ExecuteOn, MsfsAspectBuilder, ObjectWrite, Variables
 use systems_wasm::{set_data_on_sim_object, Variable};
 
 use msfs::sim_connect;
-use msfs::{sim_connect::SimConnect, sim_connect::SIMCONNECT_OBJECT_ID_USER};
+use systems_wasm::sim_connect::{SimConnect, SIMCONNECT_OBJECT_ID_USER};
 
 pub(super) fn ailerons(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
     const MIN_ACTUAL_DEFLECTION_ANGLE: f64 = 20.;
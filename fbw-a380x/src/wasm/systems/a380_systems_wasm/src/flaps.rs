@@ -1,9 +1,9 @@
 use msfs::sim_connect;
-use msfs::{sim_connect::SimConnect, sim_connect::SIMCONNECT_OBJECT_ID_USER};
 use std::error::Error;
 use systems_wasm::aspects::{
     EventToVariableMapping, ExecuteOn, MsfsAspectBuilder, ObjectWrite, VariablesToObject,
 };
+use systems_wasm::sim_connect::{SimConnect, SIMCONNECT_OBJECT_ID_USER};
 use systems_wasm::{set_data_on_sim_object, Variable};
 
 pub(super) fn flaps(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
@@ -0,0 +1,168 @@
+use std::error::Error;
+
+use msfs::sim_connect;
+use systems_wasm::sim_connect::{SimConnect, SIMCONNECT_OBJECT_ID_USER};
+
+use systems_wasm::aspects::{MsfsAspectBuilder, ObjectWrite, VariablesToObject};
+use systems_wasm::{set_data_on_sim_object, Variable};
+
+pub(super) fn fuel(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
+    builder.copy(
+        Variable::aircraft("FUELSYSTEM TANK QUANTITY", "gallons", 1),
+        Variable::aspect("FUELSYSTEM_TANK_1_QUANTITY_REQ"),
+    );
+    builder.copy(
+        Variable::aircraft("FUELSYSTEM TANK QUANTITY", "gallons", 2),
+        Variable::aspect("FUELSYSTEM_TANK_2_QUANTITY_REQ"),
+    );
+    builder.copy(
+        Variable::aircraft("FUELSYSTEM TANK QUANTITY", "gallons", 3),
+        Variable::aspect("FUELSYSTEM_TANK_3_QUANTITY_REQ"),
+    );
+    builder.copy(
+        Variable::aircraft("FUELSYSTEM TANK QUANTITY", "gallons", 4),
+        Variable::aspect("FUELSYSTEM_TANK_4_QUANTITY_REQ"),
+    );
+    builder.copy(
+        Variable::aircraft("FUELSYSTEM TANK QUANTITY", "gallons", 5),
+        Variable::aspect("FUELSYSTEM_TANK_5_QUANTITY_REQ"),
+    );
+    builder.copy(
+        Variable::aircraft("FUELSYSTEM TANK QUANTITY", "gallons", 6),
+        Variable::aspect("FUELSYSTEM_TANK_6_QUANTITY_REQ"),
+    );
+    builder.copy(
+        Variable::aircraft("FUELSYSTEM TANK QUANTITY", "gallons", 7),
+        Variable::aspect("FUELSYSTEM_TANK_7_QUANTITY_REQ"),
+    );
+    builder.copy(
+        Variable::aircraft("FUELSYSTEM TANK QUANTITY", "gallons", 8),
+        Variable::aspect("FUELSYSTEM_TANK_8_QUANTITY_REQ"),
+    );
+    builder.copy(
+        Variable::aircraft("FUELSYSTEM TANK QUANTITY", "gallons", 9),
+        Variable::aspect("FUELSYSTEM_TANK_9_QUANTITY_REQ"),
+    );
+    builder.copy(
+        Variable::aircraft("FUELSYSTEM TANK QUANTITY", "gallons", 10),
+        Variable::aspect("FUELSYSTEM_TANK_10_QUANTITY_REQ"),
+    );
+    builder.copy(
+        Variable::aircraft("FUELSYSTEM TANK QUANTITY", "gallons", 11),
+        Variable::aspect("FUELSYSTEM_TANK_11_QUANTITY_REQ"),
+    );
+
+    builder.variables_to_object(Box::new(FuelTankQuantities {
+        left_outer: 0.,
+        feed_one: 0.,
+        left_mid: 0.,
+        left_inner: 0.,
+        feed_two: 0.,
+        feed_three: 0.,
+        right_inner: 0.,
+        right_mid: 0.,
+        feed_four: 0.,
+        right_outer: 0.,
+        trim: 0.,
+    }));
+
+    Ok(())
+}
+
+#[sim_connect::data_definition]
+struct FuelTankQuantities {
+    #[name = "FUELSYSTEM TANK QUANTITY:1"]
+    #[unit = "gallons"]
+    left_outer: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:2"]
+    #[unit = "gallons"]
+    feed_one: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:3"]
+    #[unit = "gallons"]
+    left_mid: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:4"]
+    #[unit = "gallons"]
+    left_inner: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:5"]
+    #[unit = "gallons"]
+    feed_two: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:6"]
+    #[unit = "gallons"]
+    feed_three: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:7"]
+    #[unit = "gallons"]
+    right_inner: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:8"]
+    #[unit = "gallons"]
+    right_mid: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:9"]
+    #[unit = "gallons"]
+    feed_four: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:10"]
+    #[unit = "gallons"]
+    right_outer: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:11"]
+    #[unit = "gallons"]
+    trim: f64,
+}
+
+impl VariablesToObject for FuelTankQuantities {
+    fn variables(&self) -> Vec<Variable> {
+        vec![
+            Variable::aspect("FUELSYSTEM_TANK_1_QUANTITY_REQ"),
+            Variable::aspect("FUELSYSTEM_TANK_2_QUANTITY_REQ"),
+            Variable::aspect("FUELSYSTEM_TANK_3_QUANTITY_REQ"),
+            Variable::aspect("FUELSYSTEM_TANK_4_QUANTITY_REQ"),
+            Variable::aspect("FUELSYSTEM_TANK_5_QUANTITY_REQ"),
+            Variable::aspect("FUELSYSTEM_TANK_6_QUANTITY_REQ"),
+            Variable::aspect("FUELSYSTEM_TANK_7_QUANTITY_REQ"),
+            Variable::aspect("FUELSYSTEM_TANK_8_QUANTITY_REQ"),
+            Variable::aspect("FUELSYSTEM_TANK_9_QUANTITY_REQ"),
+            Variable::aspect("FUELSYSTEM_TANK_10_QUANTITY_REQ"),
+            Variable::aspect("FUELSYSTEM_TANK_11_QUANTITY_REQ"),
+        ]
+    }
+
+    fn write(&mut self, values: Vec<f64>) -> ObjectWrite {
+        let last_written = [
+            self.left_outer,
+            self.feed_one,
+            self.left_mid,
+            self.left_inner,
+            self.feed_two,
+            self.feed_three,
+            self.right_inner,
+            self.right_mid,
+            self.feed_four,
+            self.right_outer,
+            self.trim,
+        ];
+
+        self.left_outer = values[0];
+        self.feed_one = values[1];
+        self.left_mid = values[2];
+        self.left_inner = values[3];
+        self.feed_two = values[4];
+        self.feed_three = values[5];
+        self.right_inner = values[6];
+        self.right_mid = values[7];
+        self.feed_four = values[8];
+        self.right_outer = values[9];
+        self.trim = values[10];
+
+        // Only write changed quantities, so fuel burnt or set by the simulator isn't overwritten
+        ObjectWrite::on(values[..] != last_written[..])
+    }
+
+    set_data_on_sim_object!();
+}
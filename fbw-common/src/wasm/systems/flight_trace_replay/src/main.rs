@@ -0,0 +1,183 @@
+//! Replays a flight trace recorded with `FlightTraceRecorder` through the systems of an
+//! aircraft and writes the selected variables of every frame to standard output as CSV.
+//!
+//! Usage: `flight_trace_replay <a320|a380> <trace file> <variable>[,<variable>...]
+//! [<telemetry address> <peer address>]`
+//!
+//! Variables are named as they are registered by the systems, without the aircraft's
+//! key prefix, e.g. `ELEC_AC_1_BUS_IS_POWERED`.
+//!
+//! When telemetry addresses are given, the selected variables are also streamed to the peer
+//! through a `TelemetryBridge` bound to the telemetry address, and the peer can override the
+//! variables read by the systems. The trace is then replayed in real time.
+//!
+//! The failures and persistent state recorded into the trace are applied at the frame in which
+//! they were recorded.
+
+use std::{
+    collections::HashMap,
+    env,
+    error::Error,
+    fs,
+    io::{self, BufWriter, Write},
+    process, thread,
+};
+
+use a320_systems::A320;
+use a380_systems::A380;
+use systems::{
+    failures::FailureType,
+    simulation::{
+        Aircraft, FlightTrace, FlightTraceEvent, FlightTraceReplay, InitContext, Simulation,
+        TelemetryBridge,
+    },
+};
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 4 && args.len() != 6 {
+        eprintln!(
+            "Usage: {} <a320|a380> <trace file> <variable>[,<variable>...] [<telemetry address> <peer address>]",
+            args.first()
+                .map(String::as_str)
+                .unwrap_or("flight_trace_replay")
+        );
+        process::exit(2);
+    }
+
+    let variables: Vec<&str> = args[3]
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .collect();
+    let telemetry = args.get(4).zip(args.get(5));
+
+    let result = match args[1].to_lowercase().as_str() {
+        "a320" => replay(
+            &args[2],
+            &variables,
+            telemetry,
+            A320::new,
+            a320_systems::failures(),
+        ),
+        "a380" => replay(
+            &args[2],
+            &variables,
+            telemetry,
+            A380::new,
+            a380_systems::failures(),
+        ),
+        aircraft => Err(format!("Unknown aircraft '{}', expected a320 or a380.", aircraft).into()),
+    };
+
+    if let Err(error) = result {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+}
+
+fn replay<T: Aircraft, U: FnOnce(&mut InitContext) -> T>(
+    trace_path: &str,
+    variables: &[&str],
+    telemetry: Option<(&String, &String)>,
+    aircraft_ctor_fn: U,
+    failures: Vec<(u64, FailureType)>,
+) -> Result<(), Box<dyn Error>> {
+    let failures: HashMap<u64, FailureType> = failures.into_iter().collect();
+    let trace = FlightTrace::from_bytes(&fs::read(trace_path)?)?;
+    let start_state = trace.start_state();
+    let random_seed = trace.random_seed();
+    let mut replay = FlightTraceReplay::new(trace);
+    let mut bridge = telemetry
+        .map(|(address, peer_address)| TelemetryBridge::bind(address, peer_address, variables))
+        .transpose()?;
+    let mut simulation = match &mut bridge {
+        Some(bridge) => Simulation::new_with_random_seed(
+            start_state,
+            aircraft_ctor_fn,
+            &mut bridge.variable_registry(&mut replay),
+            random_seed,
+        ),
+        None => Simulation::new_with_random_seed(
+            start_state,
+            aircraft_ctor_fn,
+            &mut replay,
+            random_seed,
+        ),
+    };
+
+    let stdout = io::stdout();
+    let mut output = BufWriter::new(stdout.lock());
+    writeln!(output, "simulation_time,{}", variables.join(","))?;
+
+    while let Some((delta, simulation_time)) = replay.next_frame() {
+        for event in replay.events() {
+            apply_event(&mut simulation, &failures, event)?;
+        }
+
+        match &mut bridge {
+            Some(bridge) => {
+                for error in bridge.receive_overrides()?.rejected() {
+                    eprintln!("Skipped invalid telemetry overrides: {}", error);
+                }
+                simulation.tick(
+                    delta,
+                    simulation_time,
+                    &mut bridge.frame(simulation_time, &mut replay),
+                );
+                bridge.publish()?;
+                thread::sleep(delta);
+            }
+            None => simulation.tick(delta, simulation_time, &mut replay),
+        }
+
+        let values: Vec<String> = variables
+            .iter()
+            .map(|name| {
+                replay
+                    .value(name)
+                    .map(|value| value.to_string())
+                    .unwrap_or_default()
+            })
+            .collect();
+        writeln!(output, "{},{}", simulation_time, values.join(","))?;
+    }
+
+    output.flush()?;
+
+    Ok(())
+}
+
+fn apply_event<T: Aircraft>(
+    simulation: &mut Simulation<T>,
+    failures: &HashMap<u64, FailureType>,
+    event: &FlightTraceEvent,
+) -> Result<(), Box<dyn Error>> {
+    let failure_type = |identifier: &u64| {
+        failures
+            .get(identifier)
+            .copied()
+            .ok_or_else(|| format!("The trace contains unknown failure {}.", identifier))
+    };
+
+    match event {
+        FlightTraceEvent::FailureActivated(identifier) => {
+            simulation.activate_failure(failure_type(identifier)?)
+        }
+        FlightTraceEvent::FailureDeactivated(identifier) => {
+            simulation.deactivate_failure(failure_type(identifier)?)
+        }
+        FlightTraceEvent::FailureArmed {
+            identifier,
+            trigger,
+        } => simulation.arm_failure(failure_type(identifier)?, *trigger),
+        FlightTraceEvent::FailureDisarmed(identifier) => {
+            simulation.disarm_failure(failure_type(identifier)?)
+        }
+        FlightTraceEvent::PersistentStateRestored(snapshot) => {
+            simulation.restore_persistent(snapshot)?
+        }
+    }
+
+    Ok(())
+}
@@ -16,7 +16,10 @@ use crate::{
         calculate_towards_target_temperature, random_number, ConsumePower, ControllerSignal,
         ElectricalBusType, ElectricalBuses, PotentialOrigin, PowerConsumptionReport,
     },
-    simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
+    simulation::{
+        InitContext, SimulationElement, SimulatorWriter, SnapshotReader, SnapshotWriter,
+        UpdateContext,
+    },
 };
 
 use super::{ApuGenerator, ApuStartMotor, Turbine, TurbineSignal, TurbineState};
@@ -66,6 +69,15 @@ impl Turbine for ShutdownAps3200Turbine {
     fn bleed_air_pressure(&self) -> Pressure {
         Pressure::new::<psi>(14.7)
     }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64(TURBINE_STATE_KEY, SHUTDOWN_STATE);
+        writer.write_f64(EGT_KEY, self.egt.get::<degree_celsius>());
+    }
+
+    fn restore_state(self: Box<Self>, reader: &mut SnapshotReader) -> Box<dyn Turbine> {
+        restore_turbine(reader).unwrap_or(self)
+    }
 }
 
 struct Starting {
@@ -220,6 +232,18 @@ impl Turbine for Starting {
     fn bleed_air_pressure(&self) -> Pressure {
         Pressure::new::<psi>(14.7)
     }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64(TURBINE_STATE_KEY, STARTING_STATE);
+        writer.write_f64(EGT_KEY, self.egt.get::<degree_celsius>());
+        writer.write_f64(N_KEY, self.n.get::<percent>());
+        writer.write_f64(SINCE_KEY, self.since.as_secs_f64());
+        writer.write_bool("ignore_calculated_egt", self.ignore_calculated_egt);
+    }
+
+    fn restore_state(self: Box<Self>, reader: &mut SnapshotReader) -> Box<dyn Turbine> {
+        restore_turbine(reader).unwrap_or(self)
+    }
 }
 
 struct BleedAirUsageEgtDelta {
@@ -408,6 +432,21 @@ impl Turbine for Running {
         // TODO: Figure out what value this is supposed to be.
         Pressure::new::<psi>(50.)
     }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64(TURBINE_STATE_KEY, RUNNING_STATE);
+        writer.write_f64(EGT_KEY, self.egt.get::<degree_celsius>());
+        writer.write_f64("base_egt", self.base_egt.get::<degree_celsius>());
+        writer.write_f64(
+            "base_egt_deviation",
+            self.base_egt_deviation
+                .get::<temperature_interval::degree_celsius>(),
+        );
+    }
+
+    fn restore_state(self: Box<Self>, reader: &mut SnapshotReader) -> Box<dyn Turbine> {
+        restore_turbine(reader).unwrap_or(self)
+    }
 }
 
 struct Stopping {
@@ -544,6 +583,79 @@ impl Turbine for Stopping {
     fn bleed_air_pressure(&self) -> Pressure {
         Pressure::new::<psi>(14.7)
     }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64(TURBINE_STATE_KEY, STOPPING_STATE);
+        writer.write_f64(EGT_KEY, self.egt.get::<degree_celsius>());
+        writer.write_f64(N_KEY, self.n.get::<percent>());
+        writer.write_f64(SINCE_KEY, self.since.as_secs_f64());
+        writer.write_f64(
+            "base_temperature",
+            self.base_temperature.get::<degree_celsius>(),
+        );
+        writer.write_f64("n_factor", self.n_factor);
+    }
+
+    fn restore_state(self: Box<Self>, reader: &mut SnapshotReader) -> Box<dyn Turbine> {
+        restore_turbine(reader).unwrap_or(self)
+    }
+}
+
+const TURBINE_STATE_KEY: &str = "turbine_state";
+const EGT_KEY: &str = "egt";
+const N_KEY: &str = "n";
+const SINCE_KEY: &str = "since";
+
+const SHUTDOWN_STATE: f64 = 0.;
+const STARTING_STATE: f64 = 1.;
+const RUNNING_STATE: f64 = 2.;
+const STOPPING_STATE: f64 = 3.;
+
+/// Creates the turbine state which was saved into the snapshot. Returns `None` when
+/// the snapshot doesn't contain a turbine state.
+fn restore_turbine(reader: &mut SnapshotReader) -> Option<Box<dyn Turbine>> {
+    let state = reader.read_f64(TURBINE_STATE_KEY)?;
+    let egt = ThermodynamicTemperature::new::<degree_celsius>(reader.read_f64(EGT_KEY)?);
+    let n = Ratio::new::<percent>(reader.read_f64(N_KEY).unwrap_or(0.));
+    let since = Duration::from_secs_f64(reader.read_f64(SINCE_KEY).unwrap_or(0.).max(0.));
+
+    if (state - STARTING_STATE).abs() < f64::EPSILON {
+        Some(Box::new(Starting {
+            since,
+            n,
+            egt,
+            ignore_calculated_egt: reader.read_bool("ignore_calculated_egt")?,
+        }))
+    } else if (state - RUNNING_STATE).abs() < f64::EPSILON {
+        // The EGT deltas caused by bleed air and generator usage are not saved,
+        // they settle again within seconds after restoring.
+        let mut running = Running::new(egt);
+        running.base_egt =
+            ThermodynamicTemperature::new::<degree_celsius>(reader.read_f64("base_egt")?);
+        running.base_egt_deviation = TemperatureInterval::new::<temperature_interval::degree_celsius>(
+            reader.read_f64("base_egt_deviation")?,
+        );
+
+        Some(Box::new(running))
+    } else if (state - STOPPING_STATE).abs() < f64::EPSILON {
+        let n_factor = reader.read_f64("n_factor")?;
+        Some(Box::new(Stopping {
+            since,
+            base_temperature: ThermodynamicTemperature::new::<degree_celsius>(
+                reader.read_f64("base_temperature")?,
+            ),
+            n_factor,
+            egt_delta_at_entry: Stopping::calculate_egt_delta(Ratio::new::<percent>(
+                n_factor * 100.,
+            )),
+            n,
+            egt,
+        }))
+    } else if (state - SHUTDOWN_STATE).abs() < f64::EPSILON {
+        Some(Box::new(ShutdownAps3200Turbine::new_with_egt(egt)))
+    } else {
+        None
+    }
 }
 
 fn calculate_towards_ambient_egt(
@@ -1,6 +1,6 @@
 use self::{
     air_intake_flap::AirIntakeFlap, aps3200::ShutdownAps3200Turbine,
-    electronic_control_box::ElectronicControlBox,
+    electronic_control_box::ElectronicControlBox, pw980::ShutdownPw980Turbine,
 };
 use crate::{
     electrical::{ElectricalElement, ElectricitySource, ProvideFrequency, ProvidePotential},
@@ -11,7 +11,8 @@ use crate::{
         ContactorSignal, ControllerSignal, ElectricalBusType,
     },
     simulation::{
-        SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext, Write,
+        SimulationElement, SimulationElementVisitor, SimulatorWriter, SnapshotReader,
+        SnapshotWriter, UpdateContext, Write,
     },
 };
 #[cfg(test)]
@@ -25,6 +26,8 @@ use crate::simulation::{InitContext, VariableIdentifier};
 pub use aps3200::{Aps3200ApuGenerator, Aps3200StartMotor};
 
 mod electronic_control_box;
+mod pw980;
+pub use pw980::Pw980ApuGenerator;
 
 pub struct AuxiliaryPowerUnitFactory {}
 impl AuxiliaryPowerUnitFactory {
@@ -51,12 +54,11 @@ impl AuxiliaryPowerUnitFactory {
         start_motor_powered_by: ElectricalBusType,
         electronic_control_box_powered_by: ElectricalBusType,
         air_intake_flap_powered_by: ElectricalBusType,
-    ) -> AuxiliaryPowerUnit<Aps3200ApuGenerator, Aps3200StartMotor, 2> {
-        // TODO: replace with correct generator and turbine model
-        let generators = [1, 2].map(|i| Aps3200ApuGenerator::new(context, i));
+    ) -> AuxiliaryPowerUnit<Pw980ApuGenerator, Aps3200StartMotor, 2> {
+        let generators = [1, 2].map(|i| Pw980ApuGenerator::new(context, i));
         AuxiliaryPowerUnit::new(
             context,
-            Box::new(ShutdownAps3200Turbine::new()),
+            Box::new(ShutdownPw980Turbine::new()),
             generators,
             Aps3200StartMotor::new(start_motor_powered_by),
             electronic_control_box_powered_by,
@@ -266,6 +268,18 @@ impl<T: ApuGenerator, U: ApuStartMotor, const N: usize> SimulationElement
             self.air_intake_flap.open_amount(),
         );
     }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        if let Some(turbine) = &self.turbine {
+            turbine.save_state(writer);
+        }
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(turbine) = self.turbine.take() {
+            self.turbine = Some(turbine.restore_state(reader));
+        }
+    }
 }
 
 pub trait Turbine {
@@ -280,6 +294,13 @@ pub trait Turbine {
     fn egt(&self) -> ThermodynamicTemperature;
     fn state(&self) -> TurbineState;
     fn bleed_air_pressure(&self) -> Pressure;
+
+    /// Saves the state of the turbine into a snapshot.
+    fn save_state(&self, _writer: &mut SnapshotWriter) {}
+
+    /// Restores the state of the turbine from a snapshot. As the saved turbine
+    /// might have been in a different state, the restored turbine is returned.
+    fn restore_state(self: Box<Self>, reader: &mut SnapshotReader) -> Box<dyn Turbine>;
 }
 
 #[derive(PartialEq, Eq)]
@@ -434,6 +455,10 @@ mod tests {
         fn bleed_air_pressure(&self) -> Pressure {
             Pressure::new::<psi>(42.)
         }
+
+        fn restore_state(self: Box<Self>, _: &mut SnapshotReader) -> Box<dyn Turbine> {
+            self
+        }
     }
 
     struct TestPneumatic {
@@ -1502,6 +1527,26 @@ mod tests {
             }
         }
 
+        #[test]
+        fn restoring_a_snapshot_of_a_cooling_down_apu_restores_egt() {
+            let mut test_bed = test_bed_with()
+                .cooling_down_apu()
+                .run(Duration::from_secs(0));
+            let egt = test_bed.egt().value();
+            let snapshot = test_bed.snapshot();
+
+            test_bed = test_bed.run(Duration::from_secs(600));
+            assert!(test_bed.egt().value() < egt);
+
+            test_bed.restore(&snapshot).unwrap();
+            test_bed = test_bed.run(Duration::from_secs(0));
+
+            assert_about_eq!(
+                test_bed.egt().value().get::<degree_celsius>(),
+                egt.get::<degree_celsius>()
+            );
+        }
+
         #[test]
         fn restarting_apu_which_is_cooling_down_does_not_suddenly_reduce_egt_to_ambient_temperature(
         ) {
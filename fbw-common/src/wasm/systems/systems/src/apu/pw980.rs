@@ -0,0 +1,1079 @@
+use std::time::Duration;
+
+use uom::si::{
+    electric_potential::volt, f64::*, frequency::hertz, power::watt, pressure::psi, ratio::percent,
+    temperature_interval, thermodynamic_temperature::degree_celsius,
+};
+
+use crate::{
+    electrical::{
+        ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
+        ElectricalStateWriter, ElectricitySource, Potential, ProvideFrequency, ProvideLoad,
+        ProvidePotential,
+    },
+    failures::{Failure, FailureType},
+    shared::{
+        calculate_towards_target_temperature, interpolation, low_pass_filter::LowPassFilter,
+        random_number, ControllerSignal, PotentialOrigin, PowerConsumptionReport,
+    },
+    simulation::{
+        InitContext, SimulationElement, SimulatorWriter, SnapshotReader, SnapshotWriter,
+        UpdateContext,
+    },
+};
+
+use super::{ApuGenerator, Turbine, TurbineSignal, TurbineState};
+
+pub struct ShutdownPw980Turbine {
+    egt: ThermodynamicTemperature,
+}
+impl ShutdownPw980Turbine {
+    pub fn new() -> Self {
+        ShutdownPw980Turbine {
+            egt: ThermodynamicTemperature::new::<degree_celsius>(0.),
+        }
+    }
+
+    fn new_with_egt(egt: ThermodynamicTemperature) -> Self {
+        ShutdownPw980Turbine { egt }
+    }
+}
+impl Turbine for ShutdownPw980Turbine {
+    fn update(
+        mut self: Box<Self>,
+        context: &UpdateContext,
+        _: bool,
+        _: bool,
+        controller: &dyn ControllerSignal<TurbineSignal>,
+    ) -> Box<dyn Turbine> {
+        self.egt = calculate_towards_ambient_egt(self.egt, AMBIENT_COOLING_COEFFICIENT, context);
+
+        match controller.signal() {
+            Some(TurbineSignal::StartOrContinue) => Box::new(Starting::new(self.egt)),
+            Some(TurbineSignal::Stop) | None => self,
+        }
+    }
+
+    fn n(&self) -> Ratio {
+        Ratio::new::<percent>(0.)
+    }
+
+    fn egt(&self) -> ThermodynamicTemperature {
+        self.egt
+    }
+
+    fn state(&self) -> TurbineState {
+        TurbineState::Shutdown
+    }
+
+    fn bleed_air_pressure(&self) -> Pressure {
+        Pressure::new::<psi>(14.7)
+    }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64(TURBINE_STATE_KEY, SHUTDOWN_STATE);
+        writer.write_f64(EGT_KEY, self.egt.get::<degree_celsius>());
+    }
+
+    fn restore_state(self: Box<Self>, reader: &mut SnapshotReader) -> Box<dyn Turbine> {
+        restore_turbine(reader).unwrap_or(self)
+    }
+}
+
+struct Starting {
+    since: Duration,
+    n: Ratio,
+    egt: ThermodynamicTemperature,
+    ignore_calculated_egt: bool,
+}
+impl Starting {
+    // The PW980 takes roughly a minute to accelerate from light-off to 100% N.
+    const SINCE_IGNITION_SECONDS: [f64; 13] = [
+        0., 5., 10., 15., 20., 25., 30., 35., 40., 45., 50., 55., 60.,
+    ];
+    const N_PERCENT: [f64; 13] = [
+        0., 6., 12., 20., 29., 38., 48., 58., 68., 78., 87., 95., 100.,
+    ];
+
+    // Light-off happens around 8% N. EGT peaks around 45% N and settles
+    // while the turbine approaches governed speed.
+    const EGT_N_PERCENT: [f64; 12] = [0., 8., 12., 20., 30., 40., 50., 60., 70., 80., 90., 100.];
+    const EGT_DEGREE_CELSIUS: [f64; 12] = [
+        -50., -50., 150., 420., 620., 720., 740., 700., 620., 560., 500., 470.,
+    ];
+
+    fn new(egt: ThermodynamicTemperature) -> Starting {
+        Starting {
+            since: Duration::from_secs(0),
+            n: Ratio::new::<percent>(0.),
+            egt,
+            ignore_calculated_egt: true,
+        }
+    }
+
+    fn calculate_egt(&mut self, context: &UpdateContext) -> ThermodynamicTemperature {
+        let temperature = ThermodynamicTemperature::new::<degree_celsius>(interpolation(
+            &Starting::EGT_N_PERCENT,
+            &Starting::EGT_DEGREE_CELSIUS,
+            self.n.get::<percent>(),
+        ));
+
+        // The EGT before light-off is below the ambient temperature, and below
+        // the current EGT when the APU is restarted while still warm. To prevent
+        // sudden changes in temperature, we ignore the calculated EGT until it
+        // exceeds the current EGT.
+        let towards_ambient_egt =
+            calculate_towards_ambient_egt(self.egt, AMBIENT_COOLING_COEFFICIENT, context);
+        if temperature > towards_ambient_egt {
+            self.ignore_calculated_egt = false;
+        }
+
+        if self.ignore_calculated_egt {
+            towards_ambient_egt
+        } else {
+            temperature
+        }
+    }
+
+    fn calculate_n(&self) -> Ratio {
+        const START_IGNITION_AFTER_SECONDS: f64 = 1.5;
+        let ignition_turned_on_secs = self.since.as_secs_f64() - START_IGNITION_AFTER_SECONDS;
+
+        Ratio::new::<percent>(interpolation(
+            &Starting::SINCE_IGNITION_SECONDS,
+            &Starting::N_PERCENT,
+            ignition_turned_on_secs,
+        ))
+    }
+}
+impl Turbine for Starting {
+    fn update(
+        mut self: Box<Self>,
+        context: &UpdateContext,
+        _: bool,
+        _: bool,
+        controller: &dyn ControllerSignal<TurbineSignal>,
+    ) -> Box<dyn Turbine> {
+        self.since += context.delta();
+        self.n = self.calculate_n();
+        self.egt = self.calculate_egt(context);
+
+        match controller.signal() {
+            Some(TurbineSignal::Stop) | None => Box::new(Stopping::new(self.egt, self.n)),
+            Some(TurbineSignal::StartOrContinue)
+                if { (self.n.get::<percent>() - 100.).abs() < f64::EPSILON } =>
+            {
+                Box::new(Running::new(self.egt))
+            }
+            Some(TurbineSignal::StartOrContinue) => self,
+        }
+    }
+
+    fn n(&self) -> Ratio {
+        self.n
+    }
+
+    fn egt(&self) -> ThermodynamicTemperature {
+        self.egt
+    }
+
+    fn state(&self) -> TurbineState {
+        TurbineState::Starting
+    }
+
+    fn bleed_air_pressure(&self) -> Pressure {
+        Pressure::new::<psi>(14.7)
+    }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64(TURBINE_STATE_KEY, STARTING_STATE);
+        writer.write_f64(EGT_KEY, self.egt.get::<degree_celsius>());
+        writer.write_f64(N_KEY, self.n.get::<percent>());
+        writer.write_f64(SINCE_KEY, self.since.as_secs_f64());
+        writer.write_bool("ignore_calculated_egt", self.ignore_calculated_egt);
+    }
+
+    fn restore_state(self: Box<Self>, reader: &mut SnapshotReader) -> Box<dyn Turbine> {
+        restore_turbine(reader).unwrap_or(self)
+    }
+}
+
+/// The EGT increase caused by a load on the APU, such as bleed air extraction
+/// or the generators. The increase settles with a first order lag.
+struct LoadEgtDelta {
+    max: TemperatureInterval,
+    filter: LowPassFilter<TemperatureInterval>,
+}
+impl LoadEgtDelta {
+    fn new(max: TemperatureInterval, time_constant: Duration) -> Self {
+        Self {
+            max,
+            filter: LowPassFilter::new(time_constant),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, is_loaded: bool) {
+        self.filter.update(
+            context.delta(),
+            if is_loaded {
+                self.max
+            } else {
+                TemperatureInterval::new::<temperature_interval::degree_celsius>(0.)
+            },
+        );
+    }
+
+    fn egt_delta(&self) -> TemperatureInterval {
+        self.filter.output()
+    }
+}
+
+struct Running {
+    egt: ThermodynamicTemperature,
+    base_egt: ThermodynamicTemperature,
+    base_egt_deviation: TemperatureInterval,
+    bleed_air_usage: LoadEgtDelta,
+    apu_gen_usage: LoadEgtDelta,
+}
+impl Running {
+    const BLEED_AIR_USAGE_EGT_DELTA: f64 = 120.;
+    const BLEED_AIR_USAGE_TIME_CONSTANT: Duration = Duration::from_secs(10);
+    const APU_GEN_USAGE_EGT_DELTA: f64 = 25.;
+    const APU_GEN_USAGE_TIME_CONSTANT: Duration = Duration::from_secs(5);
+
+    fn new(egt: ThermodynamicTemperature) -> Running {
+        let base_egt = 430. + ((random_number() % 11) as f64);
+        let bleed_air_randomisation = 0.95 + ((random_number() % 101) as f64 / 1000.);
+        Running {
+            egt,
+            base_egt: ThermodynamicTemperature::new::<degree_celsius>(base_egt),
+            // This contains the deviation from the base EGT at the moment of entering the running state.
+            // The starting EGT at 100% N is above the highest possible base EGT, thus the deviation
+            // is always positive.
+            base_egt_deviation: TemperatureInterval::new::<temperature_interval::degree_celsius>(
+                egt.get::<degree_celsius>() - base_egt,
+            ),
+            bleed_air_usage: LoadEgtDelta::new(
+                TemperatureInterval::new::<temperature_interval::degree_celsius>(
+                    Running::BLEED_AIR_USAGE_EGT_DELTA * bleed_air_randomisation,
+                ),
+                Running::BLEED_AIR_USAGE_TIME_CONSTANT,
+            ),
+            apu_gen_usage: LoadEgtDelta::new(
+                TemperatureInterval::new::<temperature_interval::degree_celsius>(
+                    Running::APU_GEN_USAGE_EGT_DELTA,
+                ),
+                Running::APU_GEN_USAGE_TIME_CONSTANT,
+            ),
+        }
+    }
+
+    fn calculate_egt(
+        &mut self,
+        context: &UpdateContext,
+        apu_gen_is_used: bool,
+        apu_bleed_is_used: bool,
+    ) -> ThermodynamicTemperature {
+        // Reduce the deviation by 1 per second to slowly creep back to normal temperatures
+        self.base_egt_deviation -= TemperatureInterval::new::<temperature_interval::degree_celsius>(
+            (context.delta_as_secs_f64() * 1.).min(
+                self.base_egt_deviation
+                    .get::<temperature_interval::degree_celsius>(),
+            ),
+        );
+
+        let mut target = self.base_egt + self.base_egt_deviation;
+        self.apu_gen_usage.update(context, apu_gen_is_used);
+        target += self.apu_gen_usage.egt_delta();
+
+        self.bleed_air_usage.update(context, apu_bleed_is_used);
+        target += self.bleed_air_usage.egt_delta();
+
+        target
+    }
+}
+impl Turbine for Running {
+    fn update(
+        mut self: Box<Self>,
+        context: &UpdateContext,
+        apu_bleed_is_used: bool,
+        apu_gen_is_used: bool,
+        controller: &dyn ControllerSignal<TurbineSignal>,
+    ) -> Box<dyn Turbine> {
+        self.egt = self.calculate_egt(context, apu_gen_is_used, apu_bleed_is_used);
+
+        match controller.signal() {
+            Some(TurbineSignal::StartOrContinue) => self,
+            Some(TurbineSignal::Stop) | None => {
+                Box::new(Stopping::new(self.egt, Ratio::new::<percent>(100.)))
+            }
+        }
+    }
+
+    fn n(&self) -> Ratio {
+        Ratio::new::<percent>(100.)
+    }
+
+    fn egt(&self) -> ThermodynamicTemperature {
+        self.egt
+    }
+
+    fn state(&self) -> TurbineState {
+        TurbineState::Running
+    }
+
+    fn bleed_air_pressure(&self) -> Pressure {
+        Pressure::new::<psi>(50.)
+    }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64(TURBINE_STATE_KEY, RUNNING_STATE);
+        writer.write_f64(EGT_KEY, self.egt.get::<degree_celsius>());
+        writer.write_f64("base_egt", self.base_egt.get::<degree_celsius>());
+        writer.write_f64(
+            "base_egt_deviation",
+            self.base_egt_deviation
+                .get::<temperature_interval::degree_celsius>(),
+        );
+    }
+
+    fn restore_state(self: Box<Self>, reader: &mut SnapshotReader) -> Box<dyn Turbine> {
+        restore_turbine(reader).unwrap_or(self)
+    }
+}
+
+struct Stopping {
+    since: Duration,
+    n: Ratio,
+    egt: ThermodynamicTemperature,
+}
+impl Stopping {
+    const SINCE_SECONDS: [f64; 8] = [0., 5., 10., 15., 20., 30., 40., 50.];
+    const N_PERCENT: [f64; 8] = [100., 70., 48., 32., 20., 8., 2., 0.];
+
+    // While the turbine spools down the airflow through it cools the
+    // exhaust much faster than natural convection does after shutdown.
+    const COOLING_COEFFICIENT_AT_ZERO_N: f64 = 2.;
+    const COOLING_COEFFICIENT_AT_100_N: f64 = 12.;
+
+    fn new(egt: ThermodynamicTemperature, n: Ratio) -> Stopping {
+        Stopping {
+            // When the APU start is unsuccessful the stopping state is entered with N < 100%.
+            // The spool down then continues from the point of the curve at that N, so that N
+            // doesn't jump and the turbine doesn't take longer to stop than from 100%.
+            since: Stopping::since_for_n(n),
+            n,
+            egt,
+        }
+    }
+
+    fn since_for_n(n: Ratio) -> Duration {
+        let mut n_percent = Stopping::N_PERCENT;
+        n_percent.reverse();
+        let mut since_seconds = Stopping::SINCE_SECONDS;
+        since_seconds.reverse();
+
+        Duration::from_secs_f64(interpolation(
+            &n_percent,
+            &since_seconds,
+            n.get::<percent>(),
+        ))
+    }
+
+    fn calculate_n(since: Duration) -> Ratio {
+        Ratio::new::<percent>(interpolation(
+            &Stopping::SINCE_SECONDS,
+            &Stopping::N_PERCENT,
+            since.as_secs_f64(),
+        ))
+    }
+
+    fn cooling_coefficient(n: Ratio) -> f64 {
+        Stopping::COOLING_COEFFICIENT_AT_ZERO_N
+            + (Stopping::COOLING_COEFFICIENT_AT_100_N - Stopping::COOLING_COEFFICIENT_AT_ZERO_N)
+                * n.get::<percent>()
+                / 100.
+    }
+}
+impl Turbine for Stopping {
+    fn update(
+        mut self: Box<Self>,
+        context: &UpdateContext,
+        _: bool,
+        _: bool,
+        _: &dyn ControllerSignal<TurbineSignal>,
+    ) -> Box<dyn Turbine> {
+        self.since += context.delta();
+        self.n = Stopping::calculate_n(self.since);
+        self.egt =
+            calculate_towards_ambient_egt(self.egt, Stopping::cooling_coefficient(self.n), context);
+
+        if self.n.get::<percent>() == 0. {
+            Box::new(ShutdownPw980Turbine::new_with_egt(self.egt))
+        } else {
+            self
+        }
+    }
+
+    fn n(&self) -> Ratio {
+        self.n
+    }
+
+    fn egt(&self) -> ThermodynamicTemperature {
+        self.egt
+    }
+
+    fn state(&self) -> TurbineState {
+        TurbineState::Stopping
+    }
+
+    fn bleed_air_pressure(&self) -> Pressure {
+        Pressure::new::<psi>(14.7)
+    }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64(TURBINE_STATE_KEY, STOPPING_STATE);
+        writer.write_f64(EGT_KEY, self.egt.get::<degree_celsius>());
+        writer.write_f64(N_KEY, self.n.get::<percent>());
+        writer.write_f64(SINCE_KEY, self.since.as_secs_f64());
+    }
+
+    fn restore_state(self: Box<Self>, reader: &mut SnapshotReader) -> Box<dyn Turbine> {
+        restore_turbine(reader).unwrap_or(self)
+    }
+}
+
+const TURBINE_STATE_KEY: &str = "turbine_state";
+const EGT_KEY: &str = "egt";
+const N_KEY: &str = "n";
+const SINCE_KEY: &str = "since";
+
+const SHUTDOWN_STATE: f64 = 0.;
+const STARTING_STATE: f64 = 1.;
+const RUNNING_STATE: f64 = 2.;
+const STOPPING_STATE: f64 = 3.;
+
+const AMBIENT_COOLING_COEFFICIENT: f64 = 1.;
+
+/// Creates the turbine state which was saved into the snapshot. Returns `None` when
+/// the snapshot doesn't contain a turbine state.
+fn restore_turbine(reader: &mut SnapshotReader) -> Option<Box<dyn Turbine>> {
+    let state = reader.read_f64(TURBINE_STATE_KEY)?;
+    let egt = ThermodynamicTemperature::new::<degree_celsius>(reader.read_f64(EGT_KEY)?);
+    let n = Ratio::new::<percent>(reader.read_f64(N_KEY).unwrap_or(0.));
+    let since = Duration::from_secs_f64(reader.read_f64(SINCE_KEY).unwrap_or(0.).max(0.));
+
+    if (state - STARTING_STATE).abs() < f64::EPSILON {
+        Some(Box::new(Starting {
+            since,
+            n,
+            egt,
+            ignore_calculated_egt: reader.read_bool("ignore_calculated_egt")?,
+        }))
+    } else if (state - RUNNING_STATE).abs() < f64::EPSILON {
+        // The EGT deltas caused by bleed air and generator usage are not saved,
+        // they settle again within seconds after restoring.
+        let mut running = Running::new(egt);
+        running.base_egt =
+            ThermodynamicTemperature::new::<degree_celsius>(reader.read_f64("base_egt")?);
+        running.base_egt_deviation = TemperatureInterval::new::<temperature_interval::degree_celsius>(
+            reader.read_f64("base_egt_deviation")?,
+        );
+
+        Some(Box::new(running))
+    } else if (state - STOPPING_STATE).abs() < f64::EPSILON {
+        Some(Box::new(Stopping { since, n, egt }))
+    } else if (state - SHUTDOWN_STATE).abs() < f64::EPSILON {
+        Some(Box::new(ShutdownPw980Turbine::new_with_egt(egt)))
+    } else {
+        None
+    }
+}
+
+fn calculate_towards_ambient_egt(
+    current_egt: ThermodynamicTemperature,
+    coefficient: f64,
+    context: &UpdateContext,
+) -> ThermodynamicTemperature {
+    calculate_towards_target_temperature(
+        current_egt,
+        context.ambient_temperature(),
+        coefficient,
+        context.delta(),
+    )
+}
+
+/// PW980 APU Generator. The PW980 drives two 120 kVA generators.
+pub struct Pw980ApuGenerator {
+    number: usize,
+    identifier: ElectricalElementIdentifier,
+    n: Ratio,
+    writer: ElectricalStateWriter,
+    output_frequency: Frequency,
+    output_potential: ElectricPotential,
+    load: Ratio,
+    is_emergency_shutdown: bool,
+    failure: Failure,
+}
+impl Pw980ApuGenerator {
+    pub(super) const APU_GEN_POWERED_N: f64 = 95.;
+    const NOMINAL_POTENTIAL_FROM_N: f64 = 98.;
+    const MAXIMUM_LOAD_VOLT_AMPERE: f64 = 120000.;
+
+    pub fn new(context: &mut InitContext, number: usize) -> Pw980ApuGenerator {
+        Pw980ApuGenerator {
+            number,
+            identifier: context.next_electrical_identifier(),
+            n: Ratio::new::<percent>(0.),
+            writer: ElectricalStateWriter::new(context, &format!("APU_GEN_{}", number)),
+            output_potential: ElectricPotential::new::<volt>(0.),
+            output_frequency: Frequency::new::<hertz>(0.),
+            load: Ratio::new::<percent>(0.),
+            is_emergency_shutdown: false,
+            failure: Failure::new(FailureType::ApuGenerator(number)),
+        }
+    }
+
+    fn calculate_potential(&self, n: Ratio) -> ElectricPotential {
+        let n = n.get::<percent>();
+
+        if n < Pw980ApuGenerator::APU_GEN_POWERED_N {
+            panic!("Should not be invoked for APU N below {}", n);
+        } else {
+            ElectricPotential::new::<volt>(interpolation(
+                &[
+                    Pw980ApuGenerator::APU_GEN_POWERED_N,
+                    Pw980ApuGenerator::NOMINAL_POTENTIAL_FROM_N,
+                ],
+                &[105., 115.],
+                n,
+            ))
+        }
+    }
+
+    fn calculate_frequency(&self, n: Ratio) -> Frequency {
+        let n = n.get::<percent>();
+
+        // The generators are directly driven by the gearbox, thus the
+        // frequency is proportional to N.
+        if n < Pw980ApuGenerator::APU_GEN_POWERED_N {
+            panic!("Should not be invoked for APU N below {}", n);
+        } else {
+            Frequency::new::<hertz>(400. * n / 100.)
+        }
+    }
+
+    fn should_provide_output(&self) -> bool {
+        !self.failure.is_active()
+            && !self.is_emergency_shutdown
+            && self.n.get::<percent>() >= Pw980ApuGenerator::APU_GEN_POWERED_N
+    }
+}
+impl ApuGenerator for Pw980ApuGenerator {
+    fn update(&mut self, n: Ratio, is_emergency_shutdown: bool) {
+        self.n = n;
+        self.is_emergency_shutdown = is_emergency_shutdown;
+    }
+
+    /// Indicates if the provided electricity's potential and frequency
+    /// are within normal parameters. Use this to decide if the
+    /// generator contactor should close.
+    /// Load shouldn't be taken into account, as overloading causes an
+    /// overtemperature which over time will trigger a mechanical
+    /// disconnect of the generator.
+    fn output_within_normal_parameters(&self) -> bool {
+        self.should_provide_output() && self.potential_normal() && self.frequency_normal()
+    }
+}
+provide_potential!(Pw980ApuGenerator, (110.0..=120.0));
+provide_frequency!(Pw980ApuGenerator, (390.0..=410.0));
+provide_load!(Pw980ApuGenerator);
+impl ElectricalElement for Pw980ApuGenerator {
+    fn input_identifier(&self) -> ElectricalElementIdentifier {
+        self.identifier
+    }
+
+    fn output_identifier(&self) -> ElectricalElementIdentifier {
+        self.identifier
+    }
+
+    fn is_conductive(&self) -> bool {
+        true
+    }
+}
+impl ElectricitySource for Pw980ApuGenerator {
+    fn output_potential(&self) -> Potential {
+        if self.should_provide_output() {
+            Potential::new(
+                PotentialOrigin::ApuGenerator(self.number),
+                self.output_potential,
+            )
+        } else {
+            Potential::none()
+        }
+    }
+}
+impl SimulationElement for Pw980ApuGenerator {
+    fn accept<T: crate::simulation::SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        self.writer.write_alternating_with_load(self, writer);
+    }
+
+    fn process_power_consumption_report<T: PowerConsumptionReport>(
+        &mut self,
+        _: &UpdateContext,
+        report: &T,
+    ) {
+        self.output_potential = if self.should_provide_output() {
+            self.calculate_potential(self.n)
+        } else {
+            ElectricPotential::new::<volt>(0.)
+        };
+
+        self.output_frequency = if self.should_provide_output() {
+            self.calculate_frequency(self.n)
+        } else {
+            Frequency::new::<hertz>(0.)
+        };
+
+        let power_consumption = report
+            .total_consumption_of(PotentialOrigin::ApuGenerator(self.number))
+            .get::<watt>();
+        let power_factor_correction = 0.8;
+        self.load = Ratio::new::<percent>(
+            (power_consumption * power_factor_correction
+                / Pw980ApuGenerator::MAXIMUM_LOAD_VOLT_AMPERE)
+                * 100.,
+        );
+    }
+}
+
+#[cfg(test)]
+mod turbine_tests {
+    use super::*;
+    use crate::simulation::{
+        test::{SimulationTestBed, TestBed},
+        Aircraft, SimulationElementVisitor,
+    };
+    use ntest::assert_about_eq;
+
+    struct TestTurbine {
+        turbine: Option<Box<dyn Turbine>>,
+        start: bool,
+        apu_bleed_is_used: bool,
+        apu_gen_is_used: bool,
+    }
+    impl TestTurbine {
+        fn new(_: &mut InitContext) -> Self {
+            Self {
+                turbine: Some(Box::new(ShutdownPw980Turbine::new())),
+                start: false,
+                apu_bleed_is_used: false,
+                apu_gen_is_used: false,
+            }
+        }
+
+        fn turbine(&self) -> &dyn Turbine {
+            self.turbine.as_deref().unwrap()
+        }
+    }
+    impl ControllerSignal<TurbineSignal> for TestTurbine {
+        fn signal(&self) -> Option<TurbineSignal> {
+            if self.start {
+                Some(TurbineSignal::StartOrContinue)
+            } else {
+                Some(TurbineSignal::Stop)
+            }
+        }
+    }
+    impl Aircraft for TestTurbine {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            let turbine = self.turbine.take().unwrap();
+            let turbine =
+                turbine.update(context, self.apu_bleed_is_used, self.apu_gen_is_used, self);
+            self.turbine = Some(turbine);
+        }
+    }
+    impl SimulationElement for TestTurbine {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            visitor.visit(self);
+        }
+    }
+
+    struct TurbineTestBed {
+        test_bed: SimulationTestBed<TestTurbine>,
+    }
+    impl TurbineTestBed {
+        fn new() -> Self {
+            Self {
+                test_bed: SimulationTestBed::new(TestTurbine::new),
+            }
+        }
+
+        fn start(&mut self) {
+            self.test_bed.command(|a| a.start = true);
+        }
+
+        fn stop(&mut self) {
+            self.test_bed.command(|a| a.start = false);
+        }
+
+        fn use_bleed_air(&mut self) {
+            self.test_bed.command(|a| a.apu_bleed_is_used = true);
+        }
+
+        fn use_generators(&mut self) {
+            self.test_bed.command(|a| a.apu_gen_is_used = true);
+        }
+
+        fn run_for(&mut self, duration: Duration) {
+            let frames = (duration.as_millis() / 100) as usize;
+            for _ in 0..frames {
+                self.test_bed.run_with_delta(Duration::from_millis(100));
+            }
+        }
+
+        fn run_until_running(&mut self) {
+            self.start();
+            self.run_for(Duration::from_secs(70));
+        }
+
+        fn n(&self) -> f64 {
+            self.test_bed.query(|a| a.turbine().n()).get::<percent>()
+        }
+
+        fn egt(&self) -> f64 {
+            self.test_bed
+                .query(|a| a.turbine().egt())
+                .get::<degree_celsius>()
+        }
+
+        fn is_in_state(&self, state: TurbineState) -> bool {
+            self.test_bed.query(|a| a.turbine().state() == state)
+        }
+    }
+
+    #[test]
+    fn starts_shutdown() {
+        let test_bed = TurbineTestBed::new();
+
+        assert!(test_bed.is_in_state(TurbineState::Shutdown));
+        assert_about_eq!(test_bed.n(), 0.);
+    }
+
+    #[test]
+    fn start_takes_about_a_minute() {
+        let mut test_bed = TurbineTestBed::new();
+        test_bed.start();
+
+        test_bed.run_for(Duration::from_secs(55));
+        assert!(test_bed.is_in_state(TurbineState::Starting));
+
+        test_bed.run_for(Duration::from_secs(10));
+        assert!(test_bed.is_in_state(TurbineState::Running));
+        assert_about_eq!(test_bed.n(), 100.);
+    }
+
+    #[test]
+    fn n_increases_during_start() {
+        let mut test_bed = TurbineTestBed::new();
+        test_bed.start();
+
+        let mut previous_n = 0.;
+        for _ in 0..60 {
+            test_bed.run_for(Duration::from_secs(1));
+            let n = test_bed.n();
+            assert!(n >= previous_n);
+            previous_n = n;
+        }
+    }
+
+    #[test]
+    fn egt_peaks_during_start_below_starting_egt_limit() {
+        let mut test_bed = TurbineTestBed::new();
+        test_bed.start();
+
+        let mut max_egt: f64 = 0.;
+        for _ in 0..650 {
+            test_bed.run_for(Duration::from_millis(100));
+            max_egt = max_egt.max(test_bed.egt());
+        }
+
+        assert!(max_egt > 700.);
+        assert!(max_egt < 900.);
+        assert!(test_bed.egt() < max_egt);
+    }
+
+    #[test]
+    fn egt_settles_at_base_egt_when_running_without_load() {
+        let mut test_bed = TurbineTestBed::new();
+        test_bed.run_until_running();
+        test_bed.run_for(Duration::from_secs(120));
+
+        let egt = test_bed.egt();
+        assert!((430.0..=440.0).contains(&egt));
+    }
+
+    #[test]
+    fn bleed_air_usage_increases_egt() {
+        let mut test_bed = TurbineTestBed::new();
+        test_bed.run_until_running();
+        test_bed.run_for(Duration::from_secs(120));
+        let unloaded_egt = test_bed.egt();
+
+        test_bed.use_bleed_air();
+        test_bed.run_for(Duration::from_secs(60));
+
+        let delta = test_bed.egt() - unloaded_egt;
+        assert!((100.0..=130.0).contains(&delta));
+    }
+
+    #[test]
+    fn generator_usage_increases_egt() {
+        let mut test_bed = TurbineTestBed::new();
+        test_bed.run_until_running();
+        test_bed.run_for(Duration::from_secs(120));
+        let unloaded_egt = test_bed.egt();
+
+        test_bed.use_generators();
+        test_bed.run_for(Duration::from_secs(30));
+
+        assert_about_eq!(test_bed.egt() - unloaded_egt, 25., 0.5);
+    }
+
+    #[test]
+    fn fully_loaded_egt_stays_below_running_egt_limit() {
+        let mut test_bed = TurbineTestBed::new();
+        test_bed.use_bleed_air();
+        test_bed.use_generators();
+        test_bed.run_until_running();
+
+        for _ in 0..120 {
+            test_bed.run_for(Duration::from_secs(1));
+            assert!(test_bed.egt() < 682.);
+        }
+    }
+
+    #[test]
+    fn stops_when_stop_signalled() {
+        let mut test_bed = TurbineTestBed::new();
+        test_bed.run_until_running();
+
+        test_bed.stop();
+        test_bed.run_for(Duration::from_secs(1));
+        assert!(test_bed.is_in_state(TurbineState::Stopping));
+
+        test_bed.run_for(Duration::from_secs(50));
+        assert!(test_bed.is_in_state(TurbineState::Shutdown));
+        assert_about_eq!(test_bed.n(), 0.);
+    }
+
+    #[test]
+    fn egt_cools_down_while_stopping() {
+        let mut test_bed = TurbineTestBed::new();
+        test_bed.run_until_running();
+        test_bed.run_for(Duration::from_secs(60));
+        let running_egt = test_bed.egt();
+
+        test_bed.stop();
+        test_bed.run_for(Duration::from_secs(10));
+        let stopping_egt = test_bed.egt();
+        assert!(stopping_egt < running_egt - 50.);
+
+        test_bed.run_for(Duration::from_secs(60));
+        assert!(test_bed.egt() < stopping_egt);
+    }
+
+    #[test]
+    fn aborted_start_does_not_increase_n() {
+        let mut test_bed = TurbineTestBed::new();
+        test_bed.start();
+        test_bed.run_for(Duration::from_secs(20));
+        let n_at_abort = test_bed.n();
+
+        test_bed.stop();
+        for _ in 0..50 {
+            test_bed.run_for(Duration::from_secs(1));
+            assert!(test_bed.n() <= n_at_abort);
+        }
+
+        assert!(test_bed.is_in_state(TurbineState::Shutdown));
+    }
+}
+
+#[cfg(test)]
+mod apu_generator_tests {
+    use ntest::assert_about_eq;
+    use uom::si::frequency::hertz;
+
+    use crate::{
+        electrical::{consumption::PowerConsumer, ElectricalBus, Electricity},
+        shared::{self, ElectricalBusType},
+        simulation::{
+            test::{ElementCtorFn, SimulationTestBed, TestAircraft, TestBed},
+            Aircraft, SimulationElementVisitor,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn starts_without_output() {
+        let test_bed = SimulationTestBed::from(ElementCtorFn(apu_generator));
+
+        assert!(!test_bed
+            .query_element_elec(|e, elec| { shared::PowerConsumptionReport::is_powered(elec, e) }));
+    }
+
+    #[test]
+    fn when_apu_running_provides_output() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(apu_generator));
+
+        update_with_n(&mut test_bed, 100.);
+
+        assert!(test_bed
+            .query_element_elec(|e, elec| { shared::PowerConsumptionReport::is_powered(elec, e) }));
+    }
+
+    #[test]
+    fn below_powered_n_provides_no_output() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(apu_generator));
+
+        update_with_n(&mut test_bed, 94.);
+
+        assert!(!test_bed
+            .query_element_elec(|e, elec| { shared::PowerConsumptionReport::is_powered(elec, e) }));
+    }
+
+    #[test]
+    fn when_n_100_voltage_115_and_frequency_400() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(apu_generator));
+
+        update_with_n(&mut test_bed, 100.);
+
+        assert_about_eq!(
+            test_bed.query_element(|e| e.output_potential).get::<volt>(),
+            115.
+        );
+        assert_about_eq!(
+            test_bed
+                .query_element(|e| e.output_frequency)
+                .get::<hertz>(),
+            400.
+        );
+        assert!(test_bed.query_element(|e| e.output_within_normal_parameters()));
+    }
+
+    #[test]
+    fn output_not_normal_at_powered_n() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(apu_generator));
+
+        update_with_n(&mut test_bed, Pw980ApuGenerator::APU_GEN_POWERED_N);
+
+        assert!(!test_bed.query_element(|e| e.output_within_normal_parameters()));
+    }
+
+    #[test]
+    fn when_load_below_120_kva_it_is_normal() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            LoadTestAircraft::new(context, Power::new::<watt>(120000. / 0.8))
+        });
+
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.generator.load_normal()));
+    }
+
+    #[test]
+    fn when_load_exceeds_120_kva_not_normal() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            LoadTestAircraft::new(context, Power::new::<watt>((120000. / 0.8) + 1.))
+        });
+
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.generator.load_normal()));
+    }
+
+    #[test]
+    fn when_emergency_shutdown_provides_no_output() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(apu_generator));
+
+        test_bed.set_update_before_power_distribution(|generator, _, electricity| {
+            generator.update(Ratio::new::<percent>(100.), true);
+            electricity.supplied_by(generator);
+        });
+        test_bed.run();
+
+        assert!(!test_bed
+            .query_element_elec(|e, elec| { shared::PowerConsumptionReport::is_powered(elec, e) }));
+    }
+
+    #[test]
+    fn writes_its_state() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(apu_generator_2));
+
+        test_bed.run();
+
+        assert!(test_bed.contains_variable_with_name("ELEC_APU_GEN_2_POTENTIAL"));
+        assert!(test_bed.contains_variable_with_name("ELEC_APU_GEN_2_FREQUENCY"));
+        assert!(test_bed.contains_variable_with_name("ELEC_APU_GEN_2_LOAD"));
+    }
+
+    fn apu_generator(context: &mut InitContext) -> Pw980ApuGenerator {
+        Pw980ApuGenerator::new(context, 1)
+    }
+
+    fn apu_generator_2(context: &mut InitContext) -> Pw980ApuGenerator {
+        Pw980ApuGenerator::new(context, 2)
+    }
+
+    fn update_with_n(test_bed: &mut SimulationTestBed<TestAircraft<Pw980ApuGenerator>>, n: f64) {
+        test_bed.set_update_before_power_distribution(move |generator, _, electricity| {
+            generator.update(Ratio::new::<percent>(n), false);
+            electricity.supplied_by(generator);
+        });
+        test_bed.run();
+    }
+
+    struct LoadTestAircraft {
+        generator: Pw980ApuGenerator,
+        ac_bus: ElectricalBus,
+        power_consumer: PowerConsumer,
+    }
+    impl LoadTestAircraft {
+        fn new(context: &mut InitContext, demand: Power) -> Self {
+            let mut power_consumer = PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1));
+            power_consumer.demand(demand);
+
+            Self {
+                generator: Pw980ApuGenerator::new(context, 1),
+                ac_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                power_consumer,
+            }
+        }
+    }
+    impl Aircraft for LoadTestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.generator.update(Ratio::new::<percent>(100.), false);
+            electricity.supplied_by(&self.generator);
+            electricity.flow(&self.generator, &self.ac_bus);
+        }
+    }
+    impl SimulationElement for LoadTestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.generator.accept(visitor);
+            self.power_consumer.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+}
@@ -1,17 +1,21 @@
 use uom::si::{
     electric_charge::ampere_hour, electric_current::ampere, electric_potential::volt,
-    electrical_resistance::ohm, f64::*, time::second,
+    electrical_resistance::ohm, f64::*, thermodynamic_temperature::degree_celsius, time::second,
 };
 
 use crate::{
-    shared::{ConsumePower, PowerConsumptionReport},
-    simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
+    failures::{Failure, FailureType},
+    shared::{interpolation, ConsumePower, PowerConsumptionReport},
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, SnapshotReader,
+        SnapshotWriter, UpdateContext,
+    },
 };
 
 use super::{
     ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
     ElectricalStateWriter, ElectricitySource, Potential, PotentialOrigin, ProvideCurrent,
-    ProvidePotential,
+    ProvidePotential, ProvideTemperature,
 };
 
 pub struct Battery {
@@ -22,10 +26,45 @@ pub struct Battery {
     input_potential: ElectricPotential,
     output_potential: ElectricPotential,
     current: ElectricCurrent,
+    temperature: ThermodynamicTemperature,
+    temperature_is_initialised: bool,
+    is_overheated: bool,
+    state_of_health: f64,
+    thermal_runaway: Failure,
 }
 impl Battery {
     const RATED_CAPACITY_AMPERE_HOURS: f64 = 23.;
 
+    // Cell temperature until the first update, from which on the cells start out at the
+    // ambient temperature.
+    const INITIAL_TEMPERATURE_DEGREE_CELSIUS: f64 = 15.;
+    const HEAT_CAPACITY_JOULE_PER_KELVIN: f64 = 20000.;
+    const HEAT_TRANSFER_TO_AMBIENT_WATT_PER_KELVIN: f64 = 4.;
+    const THERMAL_RUNAWAY_HEAT_WATT: f64 = 3000.;
+    const CHARGING_THERMAL_RUNAWAY_DEGREE_CELSIUS: f64 = 70.;
+    const OVERHEAT_DEGREE_CELSIUS: f64 = 60.;
+    const OVERHEAT_RESET_DEGREE_CELSIUS: f64 = 50.;
+
+    // Rated capacity is available from freezing point upwards. Below it, part of
+    // the charge cannot be drawn from the cells.
+    const CAPACITY_RATIO_TEMPERATURE_DEGREE_CELSIUS: [f64; 4] = [-40., -20., -10., 0.];
+    const CAPACITY_RATIO: [f64; 4] = [0.5, 0.7, 0.85, 1.];
+
+    // Internal resistance rises sharply below freezing point.
+    const INTERNAL_RESISTANCE_TEMPERATURE_DEGREE_CELSIUS: [f64; 5] = [-40., -20., 0., 40., 60.];
+    const INTERNAL_RESISTANCE_OHM: [f64; 5] = [0.055, 0.027, 0.011, 0.011, 0.013];
+    // Internal resistance = 0.011 ohm. However that would make current go through the roof.
+    // Thus we add some fake wire resistance here too. If needed, later one can
+    // add resistance of wires between buses to calculate correct values.
+    const WIRE_RESISTANCE_OHM: f64 = 0.139;
+
+    // Roughly 20% capacity loss after 1000 full cycles at 25 °C. Every 10 °C above
+    // that doubles the rate at which capacity fades.
+    const CAPACITY_FADE_PER_AMPERE_HOUR: f64 = 0.2 / (1000. * 2. * 23.);
+    const CAPACITY_FADE_REFERENCE_TEMPERATURE_DEGREE_CELSIUS: f64 = 25.;
+    const CAPACITY_FADE_DOUBLING_DEGREE_CELSIUS: f64 = 10.;
+    const MINIMUM_STATE_OF_HEALTH: f64 = 0.5;
+
     pub fn full(context: &mut InitContext, number: usize) -> Battery {
         Battery::new(
             context,
@@ -47,25 +86,47 @@ impl Battery {
     }
 
     pub fn new(context: &mut InitContext, number: usize, charge: ElectricCharge) -> Self {
-        Self {
+        let mut battery = Self {
             number,
             identifier: context.next_electrical_identifier(),
             writer: ElectricalStateWriter::new(context, &format!("BAT_{}", number)),
             charge,
             input_potential: ElectricPotential::new::<volt>(0.),
-            output_potential: Battery::calculate_output_potential_for_charge(charge),
+            output_potential: ElectricPotential::new::<volt>(0.),
             current: ElectricCurrent::new::<ampere>(0.),
-        }
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(
+                Battery::INITIAL_TEMPERATURE_DEGREE_CELSIUS,
+            ),
+            temperature_is_initialised: false,
+            is_overheated: false,
+            state_of_health: 1.,
+            thermal_runaway: Failure::new(FailureType::BatteryThermalRunaway(number)),
+        };
+        battery.output_potential = battery.calculate_output_potential();
+
+        battery
     }
 
     pub fn needs_charging(&self) -> bool {
         self.charge <= ElectricCharge::new::<ampere_hour>(Battery::RATED_CAPACITY_AMPERE_HOURS - 3.)
     }
 
+    /// The ratio of the battery's capacity when new which it can still hold.
+    pub fn state_of_health(&self) -> f64 {
+        self.state_of_health
+    }
+
     fn is_powered_by_other_potential(&self) -> bool {
         self.input_potential > self.output_potential
     }
 
+    fn is_in_thermal_runaway(&self) -> bool {
+        self.thermal_runaway.is_active()
+            || (self.temperature.get::<degree_celsius>()
+                > Battery::CHARGING_THERMAL_RUNAWAY_DEGREE_CELSIUS
+                && self.current > ElectricCurrent::new::<ampere>(0.))
+    }
+
     #[cfg(test)]
     fn charge(&self) -> ElectricCharge {
         self.charge
@@ -75,7 +136,7 @@ impl Battery {
     fn set_charge(&mut self, charge: ElectricCharge) {
         self.charge = charge;
         self.input_potential = ElectricPotential::new::<volt>(0.);
-        self.output_potential = Battery::calculate_output_potential_for_charge(self.charge);
+        self.output_potential = self.calculate_output_potential();
     }
 
     #[cfg(test)]
@@ -95,6 +156,48 @@ impl Battery {
         self.set_charge(ElectricCharge::new::<ampere_hour>(0.))
     }
 
+    #[cfg(test)]
+    pub(crate) fn set_temperature(&mut self, temperature: ThermodynamicTemperature) {
+        self.temperature = temperature;
+        self.temperature_is_initialised = true;
+        self.output_potential = self.calculate_output_potential();
+    }
+
+    #[cfg(test)]
+    fn set_state_of_health(&mut self, state_of_health: f64) {
+        self.state_of_health = state_of_health;
+        self.output_potential = self.calculate_output_potential();
+    }
+
+    fn capacity_ratio(&self) -> f64 {
+        interpolation(
+            &Battery::CAPACITY_RATIO_TEMPERATURE_DEGREE_CELSIUS,
+            &Battery::CAPACITY_RATIO,
+            self.temperature.get::<degree_celsius>(),
+        ) * self.state_of_health
+    }
+
+    /// The charge which can be drawn from the cells given their temperature and age.
+    fn available_charge(&self) -> ElectricCharge {
+        let unavailable_charge = ElectricCharge::new::<ampere_hour>(
+            Battery::RATED_CAPACITY_AMPERE_HOURS * (1. - self.capacity_ratio()),
+        );
+
+        (self.charge - unavailable_charge).max(ElectricCharge::new::<ampere_hour>(0.))
+    }
+
+    fn internal_resistance(&self) -> ElectricalResistance {
+        ElectricalResistance::new::<ohm>(interpolation(
+            &Battery::INTERNAL_RESISTANCE_TEMPERATURE_DEGREE_CELSIUS,
+            &Battery::INTERNAL_RESISTANCE_OHM,
+            self.temperature.get::<degree_celsius>(),
+        ))
+    }
+
+    fn calculate_output_potential(&self) -> ElectricPotential {
+        Battery::calculate_output_potential_for_charge(self.available_charge())
+    }
+
     fn calculate_output_potential_for_charge(charge: ElectricCharge) -> ElectricPotential {
         // There are four distinct charges, being:
         // 1. No charge, giving no potential.
@@ -120,18 +223,57 @@ impl Battery {
         })
     }
 
-    fn calculate_charging_current(
-        input: ElectricPotential,
-        output: ElectricPotential,
-    ) -> ElectricCurrent {
-        // Internal resistance = 0.011 ohm. However that would make current go through the roof.
-        // Thus we add some fake wire resistance here too. If needed, later one can
-        // add resistance of wires between buses to calculate correct values.
-        let resistance = ElectricalResistance::new::<ohm>(0.15);
-        ((input - output) / resistance)
+    fn calculate_charging_current(&self) -> ElectricCurrent {
+        let resistance = self.internal_resistance()
+            + ElectricalResistance::new::<ohm>(Battery::WIRE_RESISTANCE_OHM);
+        ((self.input_potential - self.output_potential) / resistance)
             .min(ElectricCurrent::new::<ampere>(10.))
             .max(ElectricCurrent::new::<ampere>(0.))
     }
+
+    fn update_temperature(&mut self, context: &UpdateContext) {
+        if !self.temperature_is_initialised {
+            self.temperature = context.ambient_temperature();
+            self.temperature_is_initialised = true;
+        }
+
+        let delta = context.delta_as_secs_f64();
+        let current = self.current.get::<ampere>();
+        let temperature = self.temperature.get::<degree_celsius>();
+
+        let mut heat = current.powi(2) * self.internal_resistance().get::<ohm>()
+            - Battery::HEAT_TRANSFER_TO_AMBIENT_WATT_PER_KELVIN
+                * (temperature - context.ambient_temperature().get::<degree_celsius>());
+        if self.is_in_thermal_runaway() {
+            heat += Battery::THERMAL_RUNAWAY_HEAT_WATT;
+        }
+
+        self.temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            temperature + heat * delta / Battery::HEAT_CAPACITY_JOULE_PER_KELVIN,
+        );
+
+        let temperature = self.temperature.get::<degree_celsius>();
+        if temperature > Battery::OVERHEAT_DEGREE_CELSIUS {
+            self.is_overheated = true;
+        } else if temperature < Battery::OVERHEAT_RESET_DEGREE_CELSIUS {
+            self.is_overheated = false;
+        }
+    }
+
+    fn update_state_of_health(&mut self, context: &UpdateContext) {
+        let cycled_charge =
+            self.current.get::<ampere>().abs() * context.delta_as_secs_f64() / 3600.;
+        let temperature_factor = 2_f64.powf(
+            ((self.temperature.get::<degree_celsius>()
+                - Battery::CAPACITY_FADE_REFERENCE_TEMPERATURE_DEGREE_CELSIUS)
+                / Battery::CAPACITY_FADE_DOUBLING_DEGREE_CELSIUS)
+                .max(0.),
+        );
+
+        self.state_of_health = (self.state_of_health
+            - cycled_charge * Battery::CAPACITY_FADE_PER_AMPERE_HOUR * temperature_factor)
+            .max(Battery::MINIMUM_STATE_OF_HEALTH);
+    }
 }
 impl ProvideCurrent for Battery {
     fn current(&self) -> ElectricCurrent {
@@ -153,6 +295,15 @@ impl ProvidePotential for Battery {
             .contains(&ProvidePotential::potential(self))
     }
 }
+impl ProvideTemperature for Battery {
+    fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    fn temperature_normal(&self) -> bool {
+        !self.is_overheated
+    }
+}
 impl ElectricalElement for Battery {
     fn input_identifier(&self) -> ElectricalElementIdentifier {
         self.identifier
@@ -176,16 +327,21 @@ impl ElectricitySource for Battery {
     }
 }
 impl SimulationElement for Battery {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.thermal_runaway.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
-        self.writer.write_direct(self, writer);
+        self.writer.write_direct_with_temperature(self, writer);
     }
 
     fn consume_power<T: ConsumePower>(&mut self, context: &UpdateContext, consumption: &mut T) {
         self.input_potential = consumption.input_of(self).raw();
 
         if self.is_powered_by_other_potential() {
-            self.current =
-                Battery::calculate_charging_current(self.input_potential, self.output_potential);
+            self.current = self.calculate_charging_current();
 
             let power = self.input_potential * self.current;
             consumption.consume_from_input(self, power);
@@ -215,7 +371,43 @@ impl SimulationElement for Battery {
             }
         }
 
-        self.output_potential = Battery::calculate_output_potential_for_charge(self.charge);
+        self.update_temperature(context);
+        self.update_state_of_health(context);
+
+        self.output_potential = self.calculate_output_potential();
+    }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64("charge", self.charge.get::<ampere_hour>());
+        writer.write_f64("temperature", self.temperature.get::<degree_celsius>());
+        writer.write_f64("state_of_health", self.state_of_health);
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(charge) = reader.read_f64("charge") {
+            self.charge = ElectricCharge::new::<ampere_hour>(charge);
+        }
+        if let Some(temperature) = reader.read_f64("temperature") {
+            self.temperature = ThermodynamicTemperature::new::<degree_celsius>(temperature);
+            self.temperature_is_initialised = true;
+        }
+        if let Some(state_of_health) = reader.read_f64("state_of_health") {
+            self.state_of_health = state_of_health;
+        }
+
+        self.output_potential = self.calculate_output_potential();
+    }
+
+    fn save_persistent_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64("state_of_health", self.state_of_health);
+    }
+
+    fn restore_persistent_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(state_of_health) = reader.read_f64("state_of_health") {
+            self.state_of_health = state_of_health.clamp(Battery::MINIMUM_STATE_OF_HEALTH, 1.);
+        }
+
+        self.output_potential = self.calculate_output_potential();
     }
 }
 
@@ -332,6 +524,14 @@ mod tests {
             fn potential(&mut self, number: usize) -> ElectricPotential {
                 self.read_by_name(&format!("ELEC_BAT_{}_POTENTIAL", number))
             }
+
+            fn temperature(&mut self, number: usize) -> ThermodynamicTemperature {
+                self.read_by_name(&format!("ELEC_BAT_{}_TEMPERATURE", number))
+            }
+
+            fn temperature_is_normal(&mut self, number: usize) -> bool {
+                self.read_by_name(&format!("ELEC_BAT_{}_TEMPERATURE_NORMAL", number))
+            }
         }
         impl TestBed for BatteryTestBed {
             type Aircraft = TestAircraft;
@@ -396,6 +596,18 @@ mod tests {
                 self.battery_2.charge()
             }
 
+            fn set_battery_1_temperature(&mut self, temperature: ThermodynamicTemperature) {
+                self.battery_1.set_temperature(temperature);
+            }
+
+            fn set_battery_1_state_of_health(&mut self, state_of_health: f64) {
+                self.battery_1.set_state_of_health(state_of_health);
+            }
+
+            fn battery_1_state_of_health(&self) -> f64 {
+                self.battery_1.state_of_health()
+            }
+
             fn bat_bus_is_powered(&self, electricity: &Electricity) -> bool {
                 electricity.is_powered(&self.bat_bus)
             }
@@ -571,6 +783,22 @@ mod tests {
             assert!(test_bed.query(|a| a.battery_1_charge()) < charge_prior_to_run);
         }
 
+        #[test]
+        fn restoring_a_snapshot_restores_charge() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            let snapshot = test_bed.snapshot();
+            let charge_prior_to_run = test_bed.query(|a| a.battery_1_charge());
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(28. * 5.)));
+            test_bed.run_with_delta(Duration::from_secs(60));
+            test_bed.restore(&snapshot).unwrap();
+
+            assert_eq!(
+                test_bed.query(|a| a.battery_1_charge()),
+                charge_prior_to_run
+            );
+        }
+
         #[test]
         fn when_charging_gains_charge() {
             let mut test_bed = BatteryTestBed::with_empty_batteries();
@@ -695,5 +923,167 @@ mod tests {
                     < ElectricCharge::new::<ampere_hour>(0.001)
             );
         }
+
+        #[test]
+        fn temperature_rises_when_discharging_quickly() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(
+                Battery::INITIAL_TEMPERATURE_DEGREE_CELSIUS,
+            ));
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(5000.)));
+            test_bed.run_with_delta(Duration::from_secs(60));
+
+            assert!(
+                test_bed.temperature(1)
+                    > ThermodynamicTemperature::new::<degree_celsius>(
+                        Battery::INITIAL_TEMPERATURE_DEGREE_CELSIUS
+                    )
+            );
+        }
+
+        #[test]
+        fn temperature_starts_at_ambient_temperature() {
+            let mut test_bed = BatteryTestBed::with_half_charged_batteries();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-20.));
+
+            test_bed.run_with_delta(Duration::from_secs(0));
+
+            assert_eq!(
+                test_bed.temperature(1),
+                ThermodynamicTemperature::new::<degree_celsius>(-20.)
+            );
+        }
+
+        #[test]
+        fn temperature_moves_towards_ambient_temperature_when_idle() {
+            let mut test_bed = BatteryTestBed::with_half_charged_batteries();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            test_bed.run_with_delta(Duration::from_secs(0));
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-20.));
+
+            test_bed.run_with_delta(Duration::from_secs(1_000));
+
+            let temperature = test_bed.temperature(1);
+            assert!(temperature < ThermodynamicTemperature::new::<degree_celsius>(15.));
+            assert!(temperature > ThermodynamicTemperature::new::<degree_celsius>(-20.));
+        }
+
+        #[test]
+        fn temperature_is_normal_when_not_overheated() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+
+            test_bed.run();
+
+            assert!(test_bed.temperature_is_normal(1));
+        }
+
+        #[test]
+        fn thermal_runaway_overheats_battery() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+
+            test_bed.fail(FailureType::BatteryThermalRunaway(1));
+            for _ in 0..60 {
+                test_bed.run_with_delta(Duration::from_secs(10));
+            }
+
+            assert!(!test_bed.temperature_is_normal(1));
+            assert!(test_bed.temperature_is_normal(2));
+        }
+
+        #[test]
+        fn overheat_resets_once_cooled_down_below_reset_temperature() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+
+            test_bed.command(|a| {
+                a.set_battery_1_temperature(ThermodynamicTemperature::new::<degree_celsius>(65.))
+            });
+            test_bed.run();
+            assert!(!test_bed.temperature_is_normal(1));
+
+            test_bed.command(|a| {
+                a.set_battery_1_temperature(ThermodynamicTemperature::new::<degree_celsius>(55.))
+            });
+            test_bed.run();
+            assert!(!test_bed.temperature_is_normal(1));
+
+            test_bed.command(|a| {
+                a.set_battery_1_temperature(ThermodynamicTemperature::new::<degree_celsius>(45.))
+            });
+            test_bed.run();
+            assert!(test_bed.temperature_is_normal(1));
+        }
+
+        #[test]
+        fn cold_battery_has_lower_potential() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.run();
+            let warm_potential = test_bed.potential(1);
+
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-30.));
+            test_bed.command(|a| {
+                a.set_battery_1_temperature(ThermodynamicTemperature::new::<degree_celsius>(-30.))
+            });
+            test_bed.run();
+
+            assert!(test_bed.potential(1) < warm_potential);
+        }
+
+        #[test]
+        fn aged_battery_has_lower_potential() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.run();
+            let new_potential = test_bed.potential(1);
+
+            test_bed.command(|a| a.set_battery_1_state_of_health(0.8));
+            test_bed.run();
+
+            assert!(test_bed.potential(1) < new_potential);
+        }
+
+        #[test]
+        fn cycling_reduces_state_of_health() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(28. * 50.)));
+            test_bed.run_with_delta(Duration::from_secs(600));
+
+            assert!(test_bed.query(|a| a.battery_1_state_of_health()) < 1.);
+        }
+
+        #[test]
+        fn restoring_a_snapshot_restores_temperature_and_state_of_health() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.run();
+            let snapshot = test_bed.snapshot();
+            let temperature_prior_to_run = test_bed.temperature(1);
+            let state_of_health_prior_to_run = test_bed.query(|a| a.battery_1_state_of_health());
+
+            test_bed.fail(FailureType::BatteryThermalRunaway(1));
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(28. * 50.)));
+            test_bed.run_with_delta(Duration::from_secs(600));
+            test_bed.restore(&snapshot).unwrap();
+            test_bed.unfail(FailureType::BatteryThermalRunaway(1));
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(0.)));
+            test_bed.run_with_delta(Duration::from_secs(0));
+
+            assert_eq!(test_bed.temperature(1), temperature_prior_to_run);
+            assert_eq!(
+                test_bed.query(|a| a.battery_1_state_of_health()),
+                state_of_health_prior_to_run
+            );
+        }
+
+        #[test]
+        fn state_of_health_is_kept_in_persistent_snapshot() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.command(|a| a.set_battery_1_state_of_health(0.8));
+            let snapshot = test_bed.persistent_snapshot();
+
+            let mut restored = BatteryTestBed::with_full_batteries();
+            restored.restore_persistent(&snapshot).unwrap();
+
+            assert_eq!(restored.query(|a| a.battery_1_state_of_health()), 0.8);
+        }
     }
 }
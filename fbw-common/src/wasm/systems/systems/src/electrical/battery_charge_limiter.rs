@@ -1,6 +1,6 @@
 use super::{
     AlternatingCurrentElectricalSystem, BatteryPushButtons, ElectricalElement, Electricity,
-    ElectricitySource, EmergencyElectrical, ProvideCurrent, ProvidePotential,
+    ElectricitySource, EmergencyElectrical, ProvideCurrent, ProvidePotential, ProvideTemperature,
 };
 use crate::simulation::{InitContext, VariableIdentifier};
 use crate::{
@@ -31,7 +31,7 @@ impl State {
         battery_number: usize,
         emergency_elec: &EmergencyElectrical,
         emergency_generator: &impl ElectricitySource,
-        battery: &(impl ProvidePotential + ProvideCurrent),
+        battery: &(impl ProvidePotential + ProvideCurrent + ProvideTemperature),
         battery_bus: &impl ElectricalElement,
         lgciu1: &impl LgciuWeightOnWheels,
         battery_push_buttons: &impl BatteryPushButtons,
@@ -100,7 +100,7 @@ impl BatteryChargeLimiter {
         electricity: &Electricity,
         emergency_elec: &EmergencyElectrical,
         emergency_generator: &impl ElectricitySource,
-        battery: &(impl ProvidePotential + ProvideCurrent),
+        battery: &(impl ProvidePotential + ProvideCurrent + ProvideTemperature),
         battery_bus: &impl ElectricalElement,
         lgciu1: &impl LgciuWeightOnWheels,
         battery_push_buttons: &impl BatteryPushButtons,
@@ -311,7 +311,7 @@ impl Open {
         battery_number: usize,
         emergency_elec: &EmergencyElectrical,
         emergency_generator: &impl ElectricitySource,
-        battery: &impl ProvidePotential,
+        battery: &(impl ProvidePotential + ProvideTemperature),
         battery_bus: &impl ElectricalElement,
         lgciu1: &impl LgciuWeightOnWheels,
         battery_push_buttons: &impl BatteryPushButtons,
@@ -323,16 +323,18 @@ impl Open {
 
         if !battery_push_buttons.bat_is_auto(battery_number) {
             State::Off(Off::new())
-        } else if self.should_close(
-            context,
-            electricity,
-            emergency_elec,
-            emergency_generator,
-            lgciu1,
-            apu,
-            apu_overhead,
-            ac_electrical_system,
-        ) {
+        } else if battery.temperature_normal()
+            && self.should_close(
+                context,
+                electricity,
+                emergency_elec,
+                emergency_generator,
+                lgciu1,
+                apu,
+                apu_overhead,
+                ac_electrical_system,
+            )
+        {
             State::Closed(Closed::from_open(emergency_elec.is_active()))
         } else {
             State::Open(self)
@@ -476,7 +478,7 @@ impl Closed {
         electricity: &Electricity,
         battery_number: usize,
         emergency_elec: &EmergencyElectrical,
-        battery: &(impl ProvidePotential + ProvideCurrent),
+        battery: &(impl ProvidePotential + ProvideCurrent + ProvideTemperature),
         lgciu1: &impl LgciuWeightOnWheels,
         battery_push_buttons: &impl BatteryPushButtons,
         apu: &impl ApuAvailable,
@@ -487,6 +489,8 @@ impl Closed {
 
         if !battery_push_buttons.bat_is_auto(battery_number) {
             State::Off(Off::new())
+        } else if !battery.temperature_normal() {
+            State::Open(Open::from_closed())
         } else if self.should_open_due_to_discharge_protection(context) {
             State::Open(Open::due_to_discharge_protection())
         } else if self
@@ -562,7 +566,7 @@ mod tests {
     mod battery_charge_limiter_tests {
         use std::time::Duration;
 
-        use uom::si::{length::foot, power::watt};
+        use uom::si::{length::foot, power::watt, thermodynamic_temperature::degree_celsius};
 
         use crate::{
             electrical::{
@@ -737,6 +741,11 @@ mod tests {
                 self
             }
 
+            fn battery_temperature_of(mut self, temperature: ThermodynamicTemperature) -> Self {
+                self.command(|a| a.set_battery_temperature(temperature));
+                self
+            }
+
             fn no_power_outside_of_battery(mut self) -> Self {
                 self.command(|a| a.set_battery_bus_unpowered());
                 self.command(|a| a.set_both_ac_buses_unpowered());
@@ -1006,6 +1015,10 @@ mod tests {
                 self.battery.set_nearly_empty_battery_charge();
             }
 
+            fn set_battery_temperature(&mut self, temperature: ThermodynamicTemperature) {
+                self.battery.set_temperature(temperature);
+            }
+
             fn set_battery_bus_at_minimum_charging_voltage(&mut self) {
                 self.battery_bus_electricity_source
                     .set_potential(ElectricPotential::new::<volt>(
@@ -1809,5 +1822,38 @@ mod tests {
 
             assert!(test_bed.battery_contactor_is_closed(),);
         }
+
+        #[test]
+        fn contactor_opens_when_battery_overheats() {
+            let test_bed = test_bed_with()
+                .wait_for_closed_contactor(true)
+                .then_continue_with()
+                .battery_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(80.))
+                .run(Duration::from_secs(0));
+
+            assert!(!test_bed.battery_contactor_is_closed());
+        }
+
+        #[test]
+        fn contactor_does_not_close_for_charging_while_battery_overheated() {
+            let test_bed = test_bed_with()
+                .battery_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(80.))
+                .wait_for_closed_contactor(false);
+
+            assert!(!test_bed.battery_contactor_is_closed());
+        }
+
+        #[test]
+        fn contactor_closes_again_once_battery_cooled_down() {
+            let test_bed = test_bed_with()
+                .battery_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(80.))
+                .wait_for_closed_contactor(false)
+                .then_continue_with()
+                .battery_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(40.))
+                .run(Duration::from_secs(0))
+                .wait_for_closed_contactor(true);
+
+            assert!(test_bed.battery_contactor_is_closed());
+        }
     }
 }
@@ -1,4 +1,4 @@
-use std::cmp::min;
+use std::{cmp::min, time::Duration};
 
 use uom::si::{
     electric_potential::volt, f64::*, frequency::hertz, power::watt, ratio::percent,
@@ -12,8 +12,8 @@ use crate::{
         PowerConsumptionReport,
     },
     simulation::{
-        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
-        VariableIdentifier, Write,
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, SnapshotReader,
+        SnapshotWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 
@@ -30,22 +30,29 @@ pub struct EngineGenerator {
     number: usize,
     identifier: ElectricalElementIdentifier,
     idg: IntegratedDriveGenerator,
+    gcu: GeneratorControlUnit,
     output_frequency: Frequency,
     output_potential: ElectricPotential,
     load: Ratio,
     failure: Failure,
+    voltage_regulator_failure: Failure,
 }
 impl EngineGenerator {
+    const REGULATED_POTENTIAL_VOLT: f64 = 115.;
+    const UNREGULATED_POTENTIAL_VOLT: f64 = 140.;
+
     pub fn new(context: &mut InitContext, number: usize) -> EngineGenerator {
         EngineGenerator {
             writer: ElectricalStateWriter::new(context, &format!("ENG_GEN_{}", number)),
             number,
             identifier: context.next_electrical_identifier(),
             idg: IntegratedDriveGenerator::new(context, number),
+            gcu: GeneratorControlUnit::new(context, number),
             output_frequency: Frequency::new::<hertz>(0.),
             output_potential: ElectricPotential::new::<volt>(0.),
             load: Ratio::new::<percent>(0.),
             failure: Failure::new(FailureType::Generator(number)),
+            voltage_regulator_failure: Failure::new(FailureType::GeneratorVoltageRegulator(number)),
         }
     }
 
@@ -57,7 +64,27 @@ impl EngineGenerator {
         fire_buttons: &impl EngineFirePushButtons,
     ) {
         self.idg
-            .update(context, engine, generator_buttons, fire_buttons);
+            .update(context, engine, generator_buttons, fire_buttons, self.load);
+        self.gcu.update(
+            context,
+            generator_buttons.engine_gen_push_button_is_on(self.number),
+            self.idg.provides_stable_power_output() && !self.failure.is_active(),
+            self.idg.output_frequency(),
+            self.regulated_potential(),
+        );
+    }
+
+    /// Indicates if the IDG FAULT light should illuminate: the oil outlet
+    /// temperature of a connected IDG is too high.
+    pub fn idg_fault(&self) -> bool {
+        self.idg.has_fault()
+    }
+
+    /// Indicates if the generator control unit tripped the generator
+    /// due to an under frequency, over frequency or overvoltage.
+    /// The trip is reset by switching the GEN push button off.
+    pub fn is_tripped(&self) -> bool {
+        self.gcu.is_tripped()
     }
 
     /// Indicates if the provided electricity's potential and frequency
@@ -71,7 +98,17 @@ impl EngineGenerator {
     }
 
     fn should_provide_output(&self) -> bool {
-        self.idg.provides_stable_power_output() && !self.failure.is_active()
+        self.idg.provides_stable_power_output()
+            && !self.failure.is_active()
+            && !self.gcu.is_tripped()
+    }
+
+    fn regulated_potential(&self) -> ElectricPotential {
+        ElectricPotential::new::<volt>(if self.voltage_regulator_failure.is_active() {
+            Self::UNREGULATED_POTENTIAL_VOLT
+        } else {
+            Self::REGULATED_POTENTIAL_VOLT
+        })
     }
 }
 impl ElectricitySource for EngineGenerator {
@@ -105,7 +142,9 @@ impl ElectricalElement for EngineGenerator {
 impl SimulationElement for EngineGenerator {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.idg.accept(visitor);
+        self.gcu.accept(visitor);
         self.failure.accept(visitor);
+        self.voltage_regulator_failure.accept(visitor);
 
         visitor.visit(self);
     }
@@ -116,13 +155,13 @@ impl SimulationElement for EngineGenerator {
         report: &T,
     ) {
         self.output_frequency = if self.should_provide_output() {
-            Frequency::new::<hertz>(400.)
+            self.idg.output_frequency()
         } else {
             Frequency::new::<hertz>(0.)
         };
 
         self.output_potential = if self.should_provide_output() {
-            ElectricPotential::new::<volt>(115.)
+            self.regulated_potential()
         } else {
             ElectricPotential::new::<volt>(0.)
         };
@@ -149,6 +188,8 @@ struct IntegratedDriveGenerator {
     connected: bool,
     activated: bool,
     number: usize,
+    corrected_n2: Ratio,
+    constant_speed_drive_failure: Failure,
 
     time_above_threshold_in_milliseconds: u64,
 }
@@ -156,6 +197,19 @@ impl IntegratedDriveGenerator {
     pub const ENGINE_N2_POWER_UP_OUTPUT_THRESHOLD: f64 = 58.;
     pub const ENGINE_N2_POWER_DOWN_OUTPUT_THRESHOLD: f64 = 56.;
 
+    const REGULATED_FREQUENCY_HERTZ: f64 = 400.;
+    /// The engine N2 at which a constant speed drive which no longer regulates
+    /// happens to drive the generator at the regulated frequency.
+    const UNREGULATED_REFERENCE_N2_PERCENT: f64 = 75.;
+
+    // Calibrated such that the oil outlet temperature stays below the overheat threshold
+    // up to full load at maximum N2 on a hot day, while an overload quickly overheats it.
+    const OIL_TEMPERATURE_INCREASE_PER_N2_PERCENT: f64 = 0.8;
+    const OIL_TEMPERATURE_INCREASE_PER_LOAD_PERCENT: f64 = 0.3;
+    const OIL_TEMPERATURE_INCREASE_PER_OVERLOAD_PERCENT: f64 = 4.5;
+    const OIL_OUTLET_OVERHEAT_DEGREE_CELSIUS: f64 = 185.;
+    const OIL_OUTLET_THERMAL_DISCONNECT_DEGREE_CELSIUS: f64 = 200.;
+
     fn new(context: &mut InitContext, number: usize) -> IntegratedDriveGenerator {
         IntegratedDriveGenerator {
             oil_outlet_temperature_id: context.get_identifier(format!(
@@ -168,6 +222,8 @@ impl IntegratedDriveGenerator {
             connected: true,
             activated: true,
             number,
+            corrected_n2: Ratio::new::<percent>(0.),
+            constant_speed_drive_failure: Failure::new(FailureType::IdgConstantSpeedDrive(number)),
 
             time_above_threshold_in_milliseconds:
                 INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
@@ -180,19 +236,26 @@ impl IntegratedDriveGenerator {
         engine: &impl EngineCorrectedN2,
         generator_buttons: &impl EngineGeneratorPushButtons,
         fire_buttons: &impl EngineFirePushButtons,
+        load: Ratio,
     ) {
-        if generator_buttons.idg_push_button_is_released(self.number) {
-            // The IDG cannot be reconnected.
+        self.corrected_n2 = engine.corrected_n2();
+
+        if generator_buttons.idg_push_button_is_released(self.number)
+            || self.is_thermally_disconnected()
+        {
+            // The IDG cannot be reconnected in flight.
             self.connected = false;
+        } else if !self.connected && self.can_be_reconnected(context) {
+            self.connected = true;
         }
 
         self.activated = generator_buttons.engine_gen_push_button_is_on(self.number)
             && !fire_buttons.is_released(self.number);
 
-        self.update_stable_time(context, engine.corrected_n2());
+        self.update_stable_time(context, self.corrected_n2);
         self.update_temperature(
             context,
-            self.get_target_temperature(context, engine.corrected_n2()),
+            self.get_target_temperature(context, self.corrected_n2, load),
         );
     }
 
@@ -201,6 +264,40 @@ impl IntegratedDriveGenerator {
             == INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS
     }
 
+    /// The frequency at which the IDG drives the generator. Without a
+    /// functioning constant speed drive the frequency follows the engine speed.
+    fn output_frequency(&self) -> Frequency {
+        Frequency::new::<hertz>(if self.constant_speed_drive_failure.is_active() {
+            Self::REGULATED_FREQUENCY_HERTZ * self.corrected_n2.get::<percent>()
+                / Self::UNREGULATED_REFERENCE_N2_PERCENT
+        } else {
+            Self::REGULATED_FREQUENCY_HERTZ
+        })
+    }
+
+    fn has_fault(&self) -> bool {
+        self.connected
+            && self.oil_outlet_temperature
+                > ThermodynamicTemperature::new::<degree_celsius>(
+                    Self::OIL_OUTLET_OVERHEAT_DEGREE_CELSIUS,
+                )
+    }
+
+    fn is_thermally_disconnected(&self) -> bool {
+        self.oil_outlet_temperature
+            > ThermodynamicTemperature::new::<degree_celsius>(
+                Self::OIL_OUTLET_THERMAL_DISCONNECT_DEGREE_CELSIUS,
+            )
+    }
+
+    /// Reconnection is a maintenance action which can only be performed
+    /// on the ground with the engine shut down.
+    fn can_be_reconnected(&self, context: &UpdateContext) -> bool {
+        context.is_on_ground()
+            && self.corrected_n2
+                <= Ratio::new::<percent>(Self::ENGINE_N2_POWER_DOWN_OUTPUT_THRESHOLD)
+    }
+
     fn update_stable_time(&mut self, context: &UpdateContext, corrected_n2: Ratio) {
         if !self.connected {
             self.time_above_threshold_in_milliseconds = 0;
@@ -260,12 +357,17 @@ impl IntegratedDriveGenerator {
         &self,
         context: &UpdateContext,
         corrected_n2: Ratio,
+        load: Ratio,
     ) -> ThermodynamicTemperature {
         if !self.connected {
             return context.ambient_temperature();
         }
 
-        let mut target_idg = corrected_n2.get::<percent>() * 1.8;
+        let mut target_idg =
+            corrected_n2.get::<percent>() * Self::OIL_TEMPERATURE_INCREASE_PER_N2_PERCENT;
+        target_idg += load.get::<percent>() * Self::OIL_TEMPERATURE_INCREASE_PER_LOAD_PERCENT;
+        target_idg += (load.get::<percent>() - 100.).max(0.)
+            * Self::OIL_TEMPERATURE_INCREASE_PER_OVERLOAD_PERCENT;
         let ambient_temperature = context.ambient_temperature().get::<degree_celsius>();
         target_idg += ambient_temperature;
 
@@ -275,10 +377,120 @@ impl IntegratedDriveGenerator {
     }
 }
 impl SimulationElement for IntegratedDriveGenerator {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.constant_speed_drive_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.oil_outlet_temperature_id, self.oil_outlet_temperature);
         writer.write(&self.is_connected_id, self.connected);
     }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64(
+            "oil_outlet_temperature",
+            self.oil_outlet_temperature.get::<degree_celsius>(),
+        );
+        writer.write_bool("connected", self.connected);
+        writer.write_f64(
+            "time_above_threshold",
+            self.time_above_threshold_in_milliseconds as f64,
+        );
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(temperature) = reader.read_f64("oil_outlet_temperature") {
+            self.oil_outlet_temperature =
+                ThermodynamicTemperature::new::<degree_celsius>(temperature);
+        }
+        if let Some(connected) = reader.read_bool("connected") {
+            self.connected = connected;
+        }
+        if let Some(time) = reader.read_f64("time_above_threshold") {
+            self.time_above_threshold_in_milliseconds = time.max(0.) as u64;
+        }
+    }
+}
+
+/// The generator control unit protects the network against a generator
+/// which provides power outside of the acceptable limits by tripping it.
+/// Once tripped, the generator remains tripped until the GEN push button
+/// is switched off.
+struct GeneratorControlUnit {
+    is_tripped_id: VariableIdentifier,
+    is_tripped: bool,
+    out_of_limits_duration: Duration,
+}
+impl GeneratorControlUnit {
+    const UNDER_FREQUENCY_TRIP_HERTZ: f64 = 375.;
+    const OVER_FREQUENCY_TRIP_HERTZ: f64 = 425.;
+    const OVERVOLTAGE_TRIP_VOLT: f64 = 130.;
+    const TRIP_DELAY: Duration = Duration::from_secs(1);
+
+    fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            is_tripped_id: context
+                .get_identifier(format!("ELEC_ENG_GEN_{}_GCU_IS_TRIPPED", number)),
+            is_tripped: false,
+            out_of_limits_duration: Duration::ZERO,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        gen_push_button_is_on: bool,
+        generator_is_excited: bool,
+        frequency: Frequency,
+        potential: ElectricPotential,
+    ) {
+        if !gen_push_button_is_on {
+            self.is_tripped = false;
+            self.out_of_limits_duration = Duration::ZERO;
+        } else if generator_is_excited
+            && !self.is_tripped
+            && Self::is_out_of_limits(frequency, potential)
+        {
+            self.out_of_limits_duration += context.delta();
+            self.is_tripped = self.out_of_limits_duration >= Self::TRIP_DELAY;
+        } else {
+            self.out_of_limits_duration = Duration::ZERO;
+        }
+    }
+
+    fn is_out_of_limits(frequency: Frequency, potential: ElectricPotential) -> bool {
+        frequency < Frequency::new::<hertz>(Self::UNDER_FREQUENCY_TRIP_HERTZ)
+            || frequency > Frequency::new::<hertz>(Self::OVER_FREQUENCY_TRIP_HERTZ)
+            || potential > ElectricPotential::new::<volt>(Self::OVERVOLTAGE_TRIP_VOLT)
+    }
+
+    fn is_tripped(&self) -> bool {
+        self.is_tripped
+    }
+}
+impl SimulationElement for GeneratorControlUnit {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_tripped_id, self.is_tripped);
+    }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_bool("is_tripped", self.is_tripped);
+        writer.write_f64(
+            "out_of_limits_duration",
+            self.out_of_limits_duration.as_secs_f64(),
+        );
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(is_tripped) = reader.read_bool("is_tripped") {
+            self.is_tripped = is_tripped;
+        }
+        if let Some(duration) = reader.read_f64("out_of_limits_duration") {
+            self.out_of_limits_duration = Duration::from_secs_f64(duration.max(0.));
+        }
+    }
 }
 
 /// Experimental feature copied from Rust stb lib.
@@ -365,6 +577,7 @@ mod tests {
                 Aircraft,
             },
         };
+        use std::time::Duration;
 
         struct EngineGeneratorTestBed {
             test_bed: SimulationTestBed<TestAircraft>,
@@ -401,6 +614,28 @@ mod tests {
             fn generator_is_powered(&mut self) -> bool {
                 self.query_elec(|a, elec| a.generator_is_powered(elec))
             }
+
+            fn idg_is_connected(&mut self) -> bool {
+                self.read_by_name("ELEC_ENG_GEN_1_IDG_IS_CONNECTED")
+            }
+
+            fn idg_oil_outlet_temperature(&self) -> ThermodynamicTemperature {
+                self.query(|a| a.engine_gen.idg.oil_outlet_temperature)
+            }
+
+            fn idg_fault(&self) -> bool {
+                self.query(|a| a.engine_gen.idg_fault())
+            }
+
+            fn generator_is_tripped(&self) -> bool {
+                self.query(|a| a.engine_gen.is_tripped())
+            }
+
+            fn run_for(&mut self, duration: Duration) {
+                for _ in 0..duration.as_secs() {
+                    self.run();
+                }
+            }
         }
         impl TestBed for EngineGeneratorTestBed {
             type Aircraft = TestAircraft;
@@ -417,7 +652,7 @@ mod tests {
         struct TestAircraft {
             engine_gen: EngineGenerator,
             bus: ElectricalBus,
-            running: bool,
+            corrected_n2: Ratio,
             gen_push_button_on: bool,
             idg_push_button_released: bool,
             fire_push_button_released: bool,
@@ -430,7 +665,7 @@ mod tests {
                 Self {
                     engine_gen: EngineGenerator::new(context, 1),
                     bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
-                    running,
+                    corrected_n2: Ratio::new::<percent>(if running { 80. } else { 0. }),
                     gen_push_button_on: true,
                     idg_push_button_released: false,
                     fire_push_button_released: false,
@@ -451,10 +686,18 @@ mod tests {
                 self.idg_push_button_released = true;
             }
 
+            fn reinstate_idg_push_button(&mut self) {
+                self.idg_push_button_released = false;
+            }
+
             fn gen_push_button_off(&mut self) {
                 self.gen_push_button_on = false;
             }
 
+            fn gen_push_button_on(&mut self) {
+                self.gen_push_button_on = true;
+            }
+
             fn release_fire_push_button(&mut self) {
                 self.fire_push_button_released = true;
             }
@@ -474,7 +717,11 @@ mod tests {
             }
 
             fn shutdown_engine(&mut self) {
-                self.running = false;
+                self.corrected_n2 = Ratio::new::<percent>(0.);
+            }
+
+            fn set_corrected_n2(&mut self, corrected_n2: Ratio) {
+                self.corrected_n2 = corrected_n2;
             }
 
             fn generator_output_within_normal_parameters_before_processing_power_consumption_report(
@@ -491,7 +738,7 @@ mod tests {
             ) {
                 self.engine_gen.update(
                     context,
-                    &TestEngine::new(Ratio::new::<percent>(if self.running { 80. } else { 0. })),
+                    &TestEngine::new(self.corrected_n2),
                     &TestOverhead::new(self.gen_push_button_on, self.idg_push_button_released),
                     &TestFireOverhead::new(self.fire_push_button_released),
                 );
@@ -781,6 +1028,185 @@ mod tests {
             assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_FREQUENCY_NORMAL"));
             assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_LOAD"));
             assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_LOAD_NORMAL"));
+            assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_GCU_IS_TRIPPED"));
+        }
+
+        #[test]
+        fn loaded_idg_runs_hotter_than_unloaded_idg() {
+            let mut unloaded = EngineGeneratorTestBed::with_running_engine();
+            unloaded.run_for(Duration::from_secs(150));
+
+            let mut loaded = EngineGeneratorTestBed::with_running_engine();
+            loaded.command(|a| a.power_demand(Power::new::<watt>(90000. / 0.8)));
+            loaded.run_for(Duration::from_secs(150));
+
+            assert!(loaded.idg_oil_outlet_temperature() > unloaded.idg_oil_outlet_temperature());
+        }
+
+        #[test]
+        fn idg_has_no_fault_under_normal_load() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(90000. / 0.8)));
+            test_bed.run_for(Duration::from_secs(300));
+
+            assert!(!test_bed.idg_fault());
+        }
+
+        #[test]
+        fn sustained_overload_causes_idg_fault() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(1.2 * 90000. / 0.8)));
+            test_bed.run_for(Duration::from_secs(150));
+
+            assert!(test_bed.idg_fault());
+            assert!(test_bed.idg_is_connected());
+        }
+
+        #[test]
+        fn severe_overload_thermally_disconnects_idg() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(1.6 * 90000. / 0.8)));
+            test_bed.run_for(Duration::from_secs(150));
+
+            assert!(!test_bed.idg_is_connected());
+            assert!(!test_bed.generator_is_powered());
+        }
+
+        #[test]
+        fn disconnected_idg_has_no_fault() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(1.2 * 90000. / 0.8)));
+            test_bed.run_for(Duration::from_secs(150));
+
+            test_bed.command(|a| a.disconnect_idg());
+            test_bed.run();
+
+            assert!(!test_bed.idg_fault());
+        }
+
+        #[test]
+        fn disconnected_idg_cannot_be_reconnected_in_flight() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+            test_bed.set_on_ground(false);
+
+            test_bed.command(|a| a.disconnect_idg());
+            test_bed.run();
+
+            test_bed.command(|a| a.reinstate_idg_push_button());
+            test_bed.command(|a| a.shutdown_engine());
+            test_bed.run();
+
+            assert!(!test_bed.idg_is_connected());
+        }
+
+        #[test]
+        fn disconnected_idg_can_be_reconnected_on_the_ground_with_engine_shut_down() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+            test_bed.set_on_ground(true);
+
+            test_bed.command(|a| a.disconnect_idg());
+            test_bed.run();
+
+            test_bed.command(|a| a.reinstate_idg_push_button());
+            test_bed.command(|a| a.shutdown_engine());
+            test_bed.run();
+
+            assert!(test_bed.idg_is_connected());
+        }
+
+        #[test]
+        fn disconnected_idg_cannot_be_reconnected_on_the_ground_with_engine_running() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+            test_bed.set_on_ground(true);
+
+            test_bed.command(|a| a.disconnect_idg());
+            test_bed.run();
+
+            test_bed.command(|a| a.reinstate_idg_push_button());
+            test_bed.run();
+
+            assert!(!test_bed.idg_is_connected());
+        }
+
+        #[test]
+        fn gcu_trips_generator_on_over_frequency() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.fail(FailureType::IdgConstantSpeedDrive(1));
+            test_bed.command(|a| a.set_corrected_n2(Ratio::new::<percent>(90.)));
+            test_bed.run();
+
+            assert!(test_bed.generator_is_tripped());
+            assert!(!test_bed.generator_is_powered());
+        }
+
+        #[test]
+        fn gcu_trips_generator_on_under_frequency() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.fail(FailureType::IdgConstantSpeedDrive(1));
+            test_bed.command(|a| a.set_corrected_n2(Ratio::new::<percent>(65.)));
+            test_bed.run();
+
+            assert!(test_bed.generator_is_tripped());
+            assert!(!test_bed.generator_is_powered());
+        }
+
+        #[test]
+        fn gcu_trips_generator_on_overvoltage() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.fail(FailureType::GeneratorVoltageRegulator(1));
+            test_bed.run();
+
+            assert!(test_bed.generator_is_tripped());
+            assert!(!test_bed.generator_is_powered());
+        }
+
+        #[test]
+        fn gcu_does_not_trip_generator_before_trip_delay() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.fail(FailureType::GeneratorVoltageRegulator(1));
+            test_bed.run_with_delta(Duration::from_millis(500));
+
+            assert!(!test_bed.generator_is_tripped());
+        }
+
+        #[test]
+        fn output_not_within_normal_parameters_when_frequency_out_of_limits_before_trip() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.fail(FailureType::IdgConstantSpeedDrive(1));
+            test_bed.command(|a| a.set_corrected_n2(Ratio::new::<percent>(72.)));
+            test_bed.run();
+
+            assert!(!test_bed.generator_is_tripped());
+            assert!(!test_bed.query(|a| a.generator_output_within_normal_parameters_after_processing_power_consumption_report()));
+        }
+
+        #[test]
+        fn gcu_trip_remains_until_gen_push_button_switched_off() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.fail(FailureType::GeneratorVoltageRegulator(1));
+            test_bed.run();
+            test_bed.unfail(FailureType::GeneratorVoltageRegulator(1));
+            test_bed.run();
+
+            assert!(test_bed.generator_is_tripped());
+
+            test_bed.command(|a| a.gen_push_button_off());
+            test_bed.run();
+            test_bed.command(|a| a.gen_push_button_on());
+            test_bed.run();
+
+            assert!(!test_bed.generator_is_tripped());
+            assert!(test_bed.generator_is_powered());
         }
     }
 
@@ -891,6 +1317,24 @@ mod tests {
             );
         }
 
+        #[test]
+        fn restoring_a_snapshot_restores_the_oil_outlet_temperature() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_above_threshold(false));
+            test_bed.run_with_delta(Duration::from_secs(10));
+            let snapshot = test_bed.snapshot();
+            let temperature = test_bed.query_element(|e| e.oil_outlet_temperature);
+
+            test_bed.set_update_after_power_distribution(engine_not_running);
+            test_bed.run_with_delta(Duration::from_secs(10));
+            test_bed.restore(&snapshot).unwrap();
+
+            assert_eq!(
+                test_bed.query_element(|e| e.oil_outlet_temperature),
+                temperature
+            );
+        }
+
         #[test]
         fn shutdown_engine_cools_down_idg() {
             let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
@@ -905,12 +1349,40 @@ mod tests {
             assert!(test_bed.query_element(|e| e.oil_outlet_temperature) < starting_temperature);
         }
 
+        #[test]
+        fn idg_stays_below_overheat_at_high_n2_in_normal_operation() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(
+                    |idg: &mut IntegratedDriveGenerator, context: &UpdateContext| {
+                        idg.update(
+                            context,
+                            &TestEngine::new(Ratio::new::<percent>(97.)),
+                            &TestOverhead::new(true, false),
+                            &TestFireOverhead::new(false),
+                            Ratio::new::<percent>(20.),
+                        )
+                    },
+                );
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(25.));
+
+            test_bed.run_with_delta(Duration::from_secs(600));
+
+            assert!(
+                test_bed.query_element(|e| e.oil_outlet_temperature)
+                    < ThermodynamicTemperature::new::<degree_celsius>(
+                        IntegratedDriveGenerator::OIL_OUTLET_OVERHEAT_DEGREE_CELSIUS
+                    )
+            );
+            assert!(!test_bed.query_element(|e| e.has_fault()));
+        }
+
         fn engine_not_running(idg: &mut IntegratedDriveGenerator, context: &UpdateContext) {
             idg.update(
                 context,
                 &TestEngine::new(Ratio::new::<percent>(0.)),
                 &TestOverhead::new(false, false),
                 &TestFireOverhead::new(false),
+                Ratio::new::<percent>(0.),
             )
         }
 
@@ -923,6 +1395,7 @@ mod tests {
                     &TestEngine::new(Ratio::new::<percent>(80.)),
                     &TestOverhead::new(true, idg_push_button_is_released),
                     &TestFireOverhead::new(false),
+                    Ratio::new::<percent>(0.),
                 )
             }
         }
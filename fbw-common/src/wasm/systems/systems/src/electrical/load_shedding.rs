@@ -0,0 +1,512 @@
+use std::time::Duration;
+
+use uom::si::{f64::*, power::watt};
+
+use crate::{
+    shared::{PotentialOrigin, PowerConsumptionReport},
+    simulation::{
+        InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+/// A group of commercial consumers which can be shed as a whole.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LoadShedGroup {
+    Galley,
+    InFlightEntertainment,
+    Cabin,
+}
+impl LoadShedGroup {
+    fn id(&self) -> &'static str {
+        match self {
+            LoadShedGroup::Galley => "GALLEY",
+            LoadShedGroup::InFlightEntertainment => "IFE",
+            LoadShedGroup::Cabin => "CABIN",
+        }
+    }
+}
+
+struct SheddableLoad {
+    is_shed_id: VariableIdentifier,
+    group: LoadShedGroup,
+    demand: Power,
+}
+
+struct MonitoredGenerator {
+    origin: PotentialOrigin,
+    rating: Power,
+    consumption: Power,
+}
+
+/// Sheds groups of commercial loads in steps when generator capacity is insufficient,
+/// and restores them in reverse order once sufficient capacity is available again.
+///
+/// Groups are shed in the order in which they are given. A step is shed when any of the
+/// monitored generators exceeds its rating for some time. When a generator drops off line,
+/// as many steps are shed at once as needed for the remaining generators to carry the load.
+/// A step is only restored when the most loaded generator can carry the group's demand
+/// with margin to spare, which prevents the manager from cycling between shedding and restoring.
+pub struct LoadShedManager {
+    loads: Vec<SheddableLoad>,
+    generators: Vec<MonitoredGenerator>,
+    shed_steps: usize,
+    connected_generators: Vec<PotentialOrigin>,
+    overloaded_duration: Duration,
+    restorable_duration: Duration,
+}
+impl LoadShedManager {
+    const SHED_AFTER_OVERLOAD_DURATION: Duration = Duration::from_secs(5);
+    const RESTORE_AFTER_DURATION: Duration = Duration::from_secs(30);
+    const RESTORE_BELOW_RATING_RATIO: f64 = 0.85;
+
+    /// Creates a manager which monitors the consumption of the given generators against
+    /// their rating, and sheds the given groups in order. The demand of a group is
+    /// its expected consumption, used to determine if it can be restored.
+    pub fn new(
+        context: &mut InitContext,
+        generators: &[(PotentialOrigin, Power)],
+        groups: &[(LoadShedGroup, Power)],
+    ) -> Self {
+        Self {
+            loads: groups
+                .iter()
+                .map(|&(group, demand)| SheddableLoad {
+                    is_shed_id: context
+                        .get_identifier(format!("ELEC_COMMERCIAL_{}_IS_SHED", group.id())),
+                    group,
+                    demand,
+                })
+                .collect(),
+            generators: generators
+                .iter()
+                .map(|&(origin, rating)| MonitoredGenerator {
+                    origin,
+                    rating,
+                    consumption: Power::new::<watt>(0.),
+                })
+                .collect(),
+            shed_steps: 0,
+            connected_generators: Vec::new(),
+            overloaded_duration: Duration::from_secs(0),
+            restorable_duration: Duration::from_secs(0),
+        }
+    }
+
+    /// Updates the manager with the generators currently connected to the network.
+    pub fn update(&mut self, context: &UpdateContext, connected_generators: &[PotentialOrigin]) {
+        let generator_dropped = self
+            .connected_generators
+            .iter()
+            .any(|origin| !connected_generators.contains(origin));
+        self.connected_generators = connected_generators.to_vec();
+
+        if generator_dropped {
+            self.shed_steps_exceeding_connected_rating();
+        }
+
+        if self.any_generator_overloaded() {
+            self.overloaded_duration += context.delta();
+            if self.overloaded_duration >= Self::SHED_AFTER_OVERLOAD_DURATION {
+                self.shed_step();
+            }
+        } else {
+            self.overloaded_duration = Duration::from_secs(0);
+        }
+
+        if !generator_dropped && self.can_restore_step() {
+            self.restorable_duration += context.delta();
+            if self.restorable_duration >= Self::RESTORE_AFTER_DURATION {
+                self.restore_step();
+            }
+        } else {
+            self.restorable_duration = Duration::from_secs(0);
+        }
+    }
+
+    pub fn is_shed(&self, group: LoadShedGroup) -> bool {
+        self.loads
+            .iter()
+            .position(|load| load.group == group)
+            .map_or(false, |index| index < self.shed_steps)
+    }
+
+    fn shed_step(&mut self) {
+        self.shed_steps = (self.shed_steps + 1).min(self.loads.len());
+        self.overloaded_duration = Duration::from_secs(0);
+        self.restorable_duration = Duration::from_secs(0);
+    }
+
+    fn restore_step(&mut self) {
+        self.shed_steps = self.shed_steps.saturating_sub(1);
+        self.restorable_duration = Duration::from_secs(0);
+    }
+
+    /// The consumption of the last report is still spread over the generators as they were
+    /// before the drop, so its total is the load the connected generators are left with.
+    fn shed_steps_exceeding_connected_rating(&mut self) {
+        let connected_rating = self
+            .generators
+            .iter()
+            .filter(|generator| self.connected_generators.contains(&generator.origin))
+            .fold(Power::new::<watt>(0.), |sum, generator| {
+                sum + generator.rating
+            });
+        let mut load = self
+            .generators
+            .iter()
+            .fold(Power::new::<watt>(0.), |sum, generator| {
+                sum + generator.consumption
+            });
+
+        while load > connected_rating && self.shed_steps < self.loads.len() {
+            load -= self.loads[self.shed_steps].demand;
+            self.shed_step();
+        }
+    }
+
+    fn any_generator_overloaded(&self) -> bool {
+        self.generators
+            .iter()
+            .any(|generator| generator.consumption > generator.rating)
+    }
+
+    fn can_restore_step(&self) -> bool {
+        if self.shed_steps == 0 || self.connected_generators.is_empty() {
+            return false;
+        }
+
+        let demand = self.loads[self.shed_steps - 1].demand;
+        self.generators.iter().all(|generator| {
+            generator.consumption <= Power::new::<watt>(0.)
+                || generator.consumption + demand
+                    <= generator.rating * Self::RESTORE_BELOW_RATING_RATIO
+        })
+    }
+}
+impl SimulationElement for LoadShedManager {
+    fn process_power_consumption_report<T: PowerConsumptionReport>(
+        &mut self,
+        _: &UpdateContext,
+        report: &T,
+    ) {
+        for generator in &mut self.generators {
+            generator.consumption = report.total_consumption_of(generator.origin);
+        }
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for load in &self.loads {
+            writer.write(&load.is_shed_id, self.is_shed(load.group));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{
+            consumption::PowerConsumer, test::TestElectricitySource, ElectricalBus,
+            ElectricalBusType, Electricity,
+        },
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed},
+            Aircraft, SimulationElementVisitor,
+        },
+    };
+    use uom::si::electric_potential::volt;
+
+    struct TestAircraft {
+        generator_1: TestElectricitySource,
+        generator_2: TestElectricitySource,
+        ac_bus_1: ElectricalBus,
+        ac_bus_2: ElectricalBus,
+        consumer_1: PowerConsumer,
+        consumer_2: PowerConsumer,
+        manager: LoadShedManager,
+    }
+    impl TestAircraft {
+        const GENERATOR_RATING_WATT: f64 = 100000.;
+        const GALLEY_DEMAND_WATT: f64 = 30000.;
+        const IFE_DEMAND_WATT: f64 = 10000.;
+        const CABIN_DEMAND_WATT: f64 = 5000.;
+
+        fn new(context: &mut InitContext) -> Self {
+            let mut generator_1 =
+                TestElectricitySource::unpowered(context, PotentialOrigin::EngineGenerator(1));
+            generator_1.power_with_potential(ElectricPotential::new::<volt>(115.));
+            let mut generator_2 =
+                TestElectricitySource::unpowered(context, PotentialOrigin::EngineGenerator(2));
+            generator_2.power_with_potential(ElectricPotential::new::<volt>(115.));
+
+            Self {
+                generator_1,
+                generator_2,
+                ac_bus_1: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                ac_bus_2: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(2)),
+                consumer_1: PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1)),
+                consumer_2: PowerConsumer::from(ElectricalBusType::AlternatingCurrent(2)),
+                manager: LoadShedManager::new(
+                    context,
+                    &[
+                        (
+                            PotentialOrigin::EngineGenerator(1),
+                            Power::new::<watt>(Self::GENERATOR_RATING_WATT),
+                        ),
+                        (
+                            PotentialOrigin::EngineGenerator(2),
+                            Power::new::<watt>(Self::GENERATOR_RATING_WATT),
+                        ),
+                    ],
+                    &[
+                        (
+                            LoadShedGroup::Galley,
+                            Power::new::<watt>(Self::GALLEY_DEMAND_WATT),
+                        ),
+                        (
+                            LoadShedGroup::InFlightEntertainment,
+                            Power::new::<watt>(Self::IFE_DEMAND_WATT),
+                        ),
+                        (
+                            LoadShedGroup::Cabin,
+                            Power::new::<watt>(Self::CABIN_DEMAND_WATT),
+                        ),
+                    ],
+                ),
+            }
+        }
+
+        fn set_demand(&mut self, bus: usize, power: Power) {
+            match bus {
+                1 => self.consumer_1.demand(power),
+                _ => self.consumer_2.demand(power),
+            }
+        }
+
+        fn fail_generator_2(&mut self) {
+            self.generator_2.unpower();
+        }
+
+        fn is_shed(&self, group: LoadShedGroup) -> bool {
+            self.manager.is_shed(group)
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            context: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.generator_1);
+            electricity.supplied_by(&self.generator_2);
+            electricity.flow(&self.generator_1, &self.ac_bus_1);
+            if electricity.is_powered(&self.generator_2) {
+                electricity.flow(&self.generator_2, &self.ac_bus_2);
+            } else {
+                electricity.flow(&self.generator_1, &self.ac_bus_2);
+            }
+
+            let connected_generators: Vec<PotentialOrigin> = [
+                (&self.generator_1, PotentialOrigin::EngineGenerator(1)),
+                (&self.generator_2, PotentialOrigin::EngineGenerator(2)),
+            ]
+            .into_iter()
+            .filter(|(generator, _)| electricity.is_powered(*generator))
+            .map(|(_, origin)| origin)
+            .collect();
+            self.manager.update(context, &connected_generators);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.ac_bus_1.accept(visitor);
+            self.ac_bus_2.accept(visitor);
+            self.consumer_1.accept(visitor);
+            self.consumer_2.accept(visitor);
+            self.manager.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run_with_delta(Duration::from_secs(0));
+
+        test_bed
+    }
+
+    fn run_for(test_bed: &mut SimulationTestBed<TestAircraft>, duration: Duration) {
+        for _ in 0..duration.as_secs() {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn nothing_is_shed_within_rating() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_demand(1, Power::new::<watt>(80000.)));
+
+        run_for(&mut test_bed, Duration::from_secs(60));
+
+        assert!(!test_bed.query(|a| a.is_shed(LoadShedGroup::Galley)));
+        assert!(!test_bed.query(|a| a.is_shed(LoadShedGroup::InFlightEntertainment)));
+        assert!(!test_bed.query(|a| a.is_shed(LoadShedGroup::Cabin)));
+    }
+
+    #[test]
+    fn brief_overload_does_not_shed() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_demand(1, Power::new::<watt>(120000.)));
+
+        run_for(
+            &mut test_bed,
+            LoadShedManager::SHED_AFTER_OVERLOAD_DURATION - Duration::from_secs(2),
+        );
+
+        assert!(!test_bed.query(|a| a.is_shed(LoadShedGroup::Galley)));
+    }
+
+    #[test]
+    fn sustained_overload_sheds_first_group() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_demand(1, Power::new::<watt>(120000.)));
+
+        run_for(
+            &mut test_bed,
+            LoadShedManager::SHED_AFTER_OVERLOAD_DURATION + Duration::from_secs(1),
+        );
+
+        assert!(test_bed.query(|a| a.is_shed(LoadShedGroup::Galley)));
+        assert!(!test_bed.query(|a| a.is_shed(LoadShedGroup::InFlightEntertainment)));
+    }
+
+    #[test]
+    fn continued_overload_sheds_groups_in_steps() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_demand(1, Power::new::<watt>(120000.)));
+
+        run_for(
+            &mut test_bed,
+            LoadShedManager::SHED_AFTER_OVERLOAD_DURATION * 4,
+        );
+
+        assert!(test_bed.query(|a| a.is_shed(LoadShedGroup::Galley)));
+        assert!(test_bed.query(|a| a.is_shed(LoadShedGroup::InFlightEntertainment)));
+        assert!(test_bed.query(|a| a.is_shed(LoadShedGroup::Cabin)));
+    }
+
+    #[test]
+    fn generator_drop_sheds_first_group_immediately_when_remaining_generator_cannot_carry_the_load()
+    {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_demand(1, Power::new::<watt>(60000.)));
+        test_bed.command(|a| a.set_demand(2, Power::new::<watt>(60000.)));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.command(|a| a.fail_generator_2());
+
+        test_bed.run_with_delta(Duration::from_secs(0));
+
+        assert!(test_bed.query(|a| a.is_shed(LoadShedGroup::Galley)));
+        assert!(!test_bed.query(|a| a.is_shed(LoadShedGroup::InFlightEntertainment)));
+    }
+
+    #[test]
+    fn generator_drop_sheds_as_many_groups_as_needed_at_once() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_demand(1, Power::new::<watt>(70000.)));
+        test_bed.command(|a| a.set_demand(2, Power::new::<watt>(70000.)));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.command(|a| a.fail_generator_2());
+
+        test_bed.run_with_delta(Duration::from_secs(0));
+
+        // 140 kW - the galley's 30 kW still exceeds the remaining generator's 100 kW rating.
+        assert!(test_bed.query(|a| a.is_shed(LoadShedGroup::Galley)));
+        assert!(test_bed.query(|a| a.is_shed(LoadShedGroup::InFlightEntertainment)));
+        assert!(!test_bed.query(|a| a.is_shed(LoadShedGroup::Cabin)));
+    }
+
+    #[test]
+    fn generator_drop_does_not_shed_when_remaining_generator_can_carry_the_load() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_demand(1, Power::new::<watt>(30000.)));
+        test_bed.command(|a| a.set_demand(2, Power::new::<watt>(30000.)));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.command(|a| a.fail_generator_2());
+
+        run_for(&mut test_bed, Duration::from_secs(10));
+
+        assert!(!test_bed.query(|a| a.is_shed(LoadShedGroup::Galley)));
+    }
+
+    #[test]
+    fn restores_group_once_load_allows_for_long_enough() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_demand(1, Power::new::<watt>(120000.)));
+        run_for(
+            &mut test_bed,
+            LoadShedManager::SHED_AFTER_OVERLOAD_DURATION + Duration::from_secs(1),
+        );
+
+        test_bed.command(|a| a.set_demand(1, Power::new::<watt>(40000.)));
+        run_for(
+            &mut test_bed,
+            LoadShedManager::RESTORE_AFTER_DURATION + Duration::from_secs(2),
+        );
+
+        assert!(!test_bed.query(|a| a.is_shed(LoadShedGroup::Galley)));
+    }
+
+    #[test]
+    fn does_not_restore_group_when_it_would_exceed_restore_margin() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_demand(1, Power::new::<watt>(120000.)));
+        run_for(
+            &mut test_bed,
+            LoadShedManager::SHED_AFTER_OVERLOAD_DURATION + Duration::from_secs(1),
+        );
+
+        // 60 kW + the galley's 30 kW exceeds 85% of the generator's rating.
+        test_bed.command(|a| a.set_demand(1, Power::new::<watt>(60000.)));
+        run_for(&mut test_bed, LoadShedManager::RESTORE_AFTER_DURATION * 2);
+
+        assert!(test_bed.query(|a| a.is_shed(LoadShedGroup::Galley)));
+    }
+
+    #[test]
+    fn restores_groups_in_reverse_order() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_demand(1, Power::new::<watt>(120000.)));
+        run_for(
+            &mut test_bed,
+            LoadShedManager::SHED_AFTER_OVERLOAD_DURATION * 4,
+        );
+
+        test_bed.command(|a| a.set_demand(1, Power::new::<watt>(40000.)));
+        run_for(
+            &mut test_bed,
+            LoadShedManager::RESTORE_AFTER_DURATION + Duration::from_secs(2),
+        );
+
+        assert!(test_bed.query(|a| a.is_shed(LoadShedGroup::Galley)));
+        assert!(test_bed.query(|a| a.is_shed(LoadShedGroup::InFlightEntertainment)));
+        assert!(!test_bed.query(|a| a.is_shed(LoadShedGroup::Cabin)));
+    }
+
+    #[test]
+    fn writes_shed_state_of_groups() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_demand(1, Power::new::<watt>(60000.)));
+        test_bed.command(|a| a.set_demand(2, Power::new::<watt>(60000.)));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.command(|a| a.fail_generator_2());
+
+        test_bed.run_with_delta(Duration::from_secs(0));
+
+        let galley_is_shed: bool = test_bed.read_by_name("ELEC_COMMERCIAL_GALLEY_IS_SHED");
+        let cabin_is_shed: bool = test_bed.read_by_name("ELEC_COMMERCIAL_CABIN_IS_SHED");
+        assert!(galley_is_shed);
+        assert!(!cabin_is_shed);
+    }
+}
@@ -7,6 +7,7 @@ pub mod consumption;
 mod emergency_generator;
 mod engine_generator;
 mod external_power_source;
+mod load_shedding;
 mod ram_air_turbine;
 mod static_inverter;
 mod transformer_rectifier;
@@ -39,6 +40,7 @@ pub use engine_generator::{
 };
 pub use external_power_source::ExternalPowerSource;
 use fxhash::{FxHashMap, FxHashSet};
+pub use load_shedding::{LoadShedGroup, LoadShedManager};
 pub use static_inverter::StaticInverter;
 pub use transformer_rectifier::TransformerRectifier;
 use uom::si::{electric_potential::volt, f64::*, power::watt, velocity::knot};
@@ -189,6 +191,8 @@ pub struct ElectricalStateWriter {
     frequency_normal_id: VariableIdentifier,
     load_id: VariableIdentifier,
     load_normal_id: VariableIdentifier,
+    temperature_id: VariableIdentifier,
+    temperature_normal_id: VariableIdentifier,
 }
 impl ElectricalStateWriter {
     pub fn new(context: &mut InitContext, element_id: &str) -> Self {
@@ -204,6 +208,9 @@ impl ElectricalStateWriter {
                 .get_identifier(format!("ELEC_{}_FREQUENCY_NORMAL", element_id)),
             load_id: context.get_identifier(format!("ELEC_{}_LOAD", element_id)),
             load_normal_id: context.get_identifier(format!("ELEC_{}_LOAD_NORMAL", element_id)),
+            temperature_id: context.get_identifier(format!("ELEC_{}_TEMPERATURE", element_id)),
+            temperature_normal_id: context
+                .get_identifier(format!("ELEC_{}_TEMPERATURE_NORMAL", element_id)),
         }
     }
 
@@ -234,6 +241,15 @@ impl ElectricalStateWriter {
         self.write_load(source, writer);
     }
 
+    pub fn write_direct_with_temperature(
+        &self,
+        source: &(impl ProvideCurrent + ProvidePotential + ProvideTemperature),
+        writer: &mut SimulatorWriter,
+    ) {
+        self.write_direct(source, writer);
+        self.write_temperature(source, writer);
+    }
+
     fn write_current(&self, source: &impl ProvideCurrent, writer: &mut SimulatorWriter) {
         writer.write(&self.current_id, source.current());
         writer.write(&self.current_normal_id, source.current_normal());
@@ -253,6 +269,11 @@ impl ElectricalStateWriter {
         writer.write(&self.load_id, source.load());
         writer.write(&self.load_normal_id, source.load_normal());
     }
+
+    fn write_temperature(&self, source: &impl ProvideTemperature, writer: &mut SimulatorWriter) {
+        writer.write(&self.temperature_id, source.temperature());
+        writer.write(&self.temperature_normal_id, source.temperature_normal());
+    }
 }
 
 pub trait ProvideCurrent {
@@ -275,6 +296,12 @@ pub trait ProvideLoad {
     fn load_normal(&self) -> bool;
 }
 
+pub trait ProvideTemperature {
+    fn temperature(&self) -> ThermodynamicTemperature;
+    /// Returns false when the element is overheated.
+    fn temperature_normal(&self) -> bool;
+}
+
 /// Determines if and for how long the aircraft is in an emergency electrical situation.
 pub struct EmergencyElectrical {
     is_active_for_duration: Duration,
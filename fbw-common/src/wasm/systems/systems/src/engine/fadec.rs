@@ -0,0 +1,768 @@
+use std::{convert::TryInto, time::Duration};
+
+use uom::si::{f64::*, ratio::percent, thermodynamic_temperature::degree_celsius};
+
+use crate::{
+    pneumatic::{EngineModeSelector, EngineState},
+    shared::{EngineStartState, EngineUncorrectedN2},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+/// The engine type specific parameters of the start sequence.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StartSequenceParameters {
+    /// N2 at which fuel is introduced and the igniters are energised during an automatic start.
+    pub ignition_n2_percent: f64,
+    /// N2 at which the start valve is closed.
+    pub starter_cutout_n2_percent: f64,
+    /// N2 at which the engine is considered running.
+    pub start_complete_n2_percent: f64,
+    /// EGT above which a hot start is detected.
+    pub start_egt_limit_celsius: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StartPhase {
+    Off,
+    /// The starter motors the engine without fuel and ignition.
+    Cranking,
+    Starting,
+    Running,
+    /// Fuel and ignition are cut off while the starter keeps motoring the engine to purge it.
+    Aborted,
+    Shutting,
+}
+
+/// The start logic of the FADEC of a single engine. It commands the start valve, selects the
+/// igniters and monitors the start for hung and hot starts. Automatic starts on ground are
+/// aborted when such a fault is detected, manual and in flight starts are only monitored.
+///
+/// The sequencer is the only writer of the igniter, start fault and start aborted variables.
+/// The engine model of the C++ FADEC only reads whether the start is aborted, to cut the fuel
+/// off, and leaves its ENGINE_STATE machine to follow from the resulting engine master cut.
+pub struct EngineStartSequencer {
+    engine_master_id: VariableIdentifier,
+    manual_start_id: VariableIdentifier,
+    egt_id: VariableIdentifier,
+    igniter_a_active_id: VariableIdentifier,
+    igniter_b_active_id: VariableIdentifier,
+    start_fault_id: VariableIdentifier,
+    start_aborted_id: VariableIdentifier,
+
+    parameters: StartSequenceParameters,
+
+    engine_master_is_on: bool,
+    manual_start_is_on: bool,
+    egt: ThermodynamicTemperature,
+    n2: Ratio,
+
+    phase: StartPhase,
+    is_manual_start: bool,
+    is_in_flight_start: bool,
+    fuel_is_on: bool,
+    starter_has_cut_out: bool,
+    use_igniter_b: bool,
+
+    n2_at_last_progress: Ratio,
+    duration_without_progress: Duration,
+    has_hung_start: bool,
+    has_hot_start: bool,
+    dry_crank_duration: Duration,
+}
+impl EngineStartSequencer {
+    const HUNG_START_N2_PROGRESS_PERCENT: f64 = 1.;
+    const HUNG_START_DETECTION_TIME: Duration = Duration::from_secs(10);
+    const DRY_CRANK_DURATION: Duration = Duration::from_secs(30);
+    const SHUTDOWN_COMPLETE_N2_PERCENT: f64 = 5.;
+
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        parameters: StartSequenceParameters,
+    ) -> Self {
+        Self {
+            engine_master_id: context
+                .get_identifier(format!("GENERAL ENG STARTER ACTIVE:{}", number)),
+            manual_start_id: context.get_identifier(format!("ENGMANSTART{}_TOGGLE", number)),
+            egt_id: context.get_identifier(format!("ENGINE_EGT:{}", number)),
+            igniter_a_active_id: context
+                .get_identifier(format!("FADEC_IGNITER_A_ACTIVE_ENG{}", number)),
+            igniter_b_active_id: context
+                .get_identifier(format!("FADEC_IGNITER_B_ACTIVE_ENG{}", number)),
+            start_fault_id: context.get_identifier(format!("FADEC_START_FAULT_ENG{}", number)),
+            start_aborted_id: context.get_identifier(format!("FADEC_START_ABORTED_ENG{}", number)),
+
+            parameters,
+
+            engine_master_is_on: false,
+            manual_start_is_on: false,
+            egt: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            n2: Ratio::default(),
+
+            phase: StartPhase::Off,
+            is_manual_start: false,
+            is_in_flight_start: false,
+            fuel_is_on: false,
+            starter_has_cut_out: false,
+            use_igniter_b: false,
+
+            n2_at_last_progress: Ratio::default(),
+            duration_without_progress: Duration::ZERO,
+            has_hung_start: false,
+            has_hot_start: false,
+            dry_crank_duration: Duration::ZERO,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        mode_selector: EngineModeSelector,
+        engine: &impl EngineUncorrectedN2,
+    ) {
+        self.n2 = engine.uncorrected_n2();
+
+        let next_phase = self.next_phase(context, mode_selector);
+        if next_phase != self.phase {
+            self.leave_phase();
+            self.enter_phase(context, next_phase);
+        }
+
+        match self.phase {
+            StartPhase::Starting => self.update_start(context),
+            StartPhase::Aborted => self.dry_crank_duration += context.delta(),
+            _ => {}
+        }
+    }
+
+    fn next_phase(&self, context: &UpdateContext, mode_selector: EngineModeSelector) -> StartPhase {
+        if !self.engine_master_is_on {
+            return if self.manual_start_is_on && mode_selector != EngineModeSelector::Norm {
+                StartPhase::Cranking
+            } else if self.phase != StartPhase::Off
+                && self.phase != StartPhase::Cranking
+                && self.n2.get::<percent>() > Self::SHUTDOWN_COMPLETE_N2_PERCENT
+            {
+                StartPhase::Shutting
+            } else {
+                StartPhase::Off
+            };
+        }
+
+        match self.phase {
+            StartPhase::Running | StartPhase::Aborted => self.phase,
+            StartPhase::Starting => {
+                if self.should_abort(context) {
+                    StartPhase::Aborted
+                } else if self.start_is_complete() {
+                    StartPhase::Running
+                } else {
+                    StartPhase::Starting
+                }
+            }
+            StartPhase::Off | StartPhase::Cranking | StartPhase::Shutting => {
+                if self.start_is_complete() {
+                    StartPhase::Running
+                } else if mode_selector == EngineModeSelector::Ignition {
+                    StartPhase::Starting
+                } else if self.phase == StartPhase::Shutting
+                    && self.n2.get::<percent>() > Self::SHUTDOWN_COMPLETE_N2_PERCENT
+                {
+                    StartPhase::Shutting
+                } else {
+                    StartPhase::Off
+                }
+            }
+        }
+    }
+
+    fn leave_phase(&mut self) {
+        if self.phase == StartPhase::Starting && !self.uses_both_igniters() {
+            self.use_igniter_b = !self.use_igniter_b;
+        }
+    }
+
+    fn enter_phase(&mut self, context: &UpdateContext, phase: StartPhase) {
+        if phase == StartPhase::Starting {
+            self.is_manual_start = self.manual_start_is_on;
+            self.is_in_flight_start = context.is_in_flight();
+            self.starter_has_cut_out = false;
+            self.n2_at_last_progress = self.n2;
+            self.duration_without_progress = Duration::ZERO;
+            self.has_hung_start = false;
+            self.has_hot_start = false;
+        } else if phase == StartPhase::Aborted {
+            self.dry_crank_duration = Duration::ZERO;
+        }
+
+        self.fuel_is_on = false;
+        self.phase = phase;
+    }
+
+    fn update_start(&mut self, context: &UpdateContext) {
+        self.fuel_is_on = self.fuel_is_on
+            || self.is_manual_start
+            || self.n2.get::<percent>() >= self.parameters.ignition_n2_percent;
+        self.starter_has_cut_out = self.starter_has_cut_out
+            || self.n2.get::<percent>() >= self.parameters.starter_cutout_n2_percent;
+
+        if !self.fuel_is_on {
+            return;
+        }
+
+        self.has_hot_start = self.has_hot_start
+            || self.egt.get::<degree_celsius>() > self.parameters.start_egt_limit_celsius;
+
+        if (self.n2 - self.n2_at_last_progress).get::<percent>()
+            >= Self::HUNG_START_N2_PROGRESS_PERCENT
+        {
+            self.n2_at_last_progress = self.n2;
+            self.duration_without_progress = Duration::ZERO;
+        } else {
+            self.duration_without_progress += context.delta();
+        }
+        self.has_hung_start = self.has_hung_start
+            || self.duration_without_progress >= Self::HUNG_START_DETECTION_TIME;
+    }
+
+    fn should_abort(&self, context: &UpdateContext) -> bool {
+        !self.is_manual_start && context.is_on_ground() && self.has_start_fault()
+    }
+
+    fn start_is_complete(&self) -> bool {
+        self.n2.get::<percent>() >= self.parameters.start_complete_n2_percent
+    }
+
+    fn uses_both_igniters(&self) -> bool {
+        self.is_manual_start || self.is_in_flight_start
+    }
+
+    fn is_igniting(&self) -> bool {
+        self.phase == StartPhase::Starting && self.fuel_is_on
+    }
+
+    pub fn engine_state(&self) -> EngineState {
+        match self.phase {
+            StartPhase::Off => EngineState::Off,
+            StartPhase::Cranking => EngineState::Starting,
+            StartPhase::Starting if self.is_in_flight_start => EngineState::Restarting,
+            StartPhase::Starting => EngineState::Starting,
+            StartPhase::Running => EngineState::On,
+            StartPhase::Aborted if self.start_valve_is_commanded_open() => EngineState::Starting,
+            StartPhase::Aborted => EngineState::Off,
+            StartPhase::Shutting => EngineState::Shutting,
+        }
+    }
+
+    pub fn start_valve_is_commanded_open(&self) -> bool {
+        match self.phase {
+            StartPhase::Cranking => true,
+            StartPhase::Starting => !self.starter_has_cut_out,
+            StartPhase::Aborted => self.dry_crank_duration < Self::DRY_CRANK_DURATION,
+            _ => false,
+        }
+    }
+
+    pub fn fuel_is_commanded_on(&self) -> bool {
+        self.fuel_is_on || self.phase == StartPhase::Running
+    }
+
+    pub fn igniter_a_is_active(&self) -> bool {
+        self.is_igniting() && (self.uses_both_igniters() || !self.use_igniter_b)
+    }
+
+    pub fn igniter_b_is_active(&self) -> bool {
+        self.is_igniting() && (self.uses_both_igniters() || self.use_igniter_b)
+    }
+
+    pub fn has_hung_start(&self) -> bool {
+        self.has_hung_start
+    }
+
+    pub fn has_hot_start(&self) -> bool {
+        self.has_hot_start
+    }
+
+    pub fn has_start_fault(&self) -> bool {
+        self.has_hung_start || self.has_hot_start
+    }
+
+    pub fn start_is_aborted(&self) -> bool {
+        self.phase == StartPhase::Aborted
+    }
+}
+impl SimulationElement for EngineStartSequencer {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.engine_master_is_on = reader.read(&self.engine_master_id);
+        self.manual_start_is_on = reader.read(&self.manual_start_id);
+        self.egt = reader.read(&self.egt_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.igniter_a_active_id, self.igniter_a_is_active());
+        writer.write(&self.igniter_b_active_id, self.igniter_b_is_active());
+        writer.write(&self.start_fault_id, self.has_start_fault());
+        writer.write(&self.start_aborted_id, self.start_is_aborted());
+    }
+}
+
+/// The start logic of the FADECs of all engines, sharing the engine mode selector.
+pub struct FullAuthorityDigitalEngineControl<const N: usize> {
+    engine_mode_selector_id: VariableIdentifier,
+    engine_mode_selector: EngineModeSelector,
+    start_sequencers: [EngineStartSequencer; N],
+}
+impl<const N: usize> FullAuthorityDigitalEngineControl<N> {
+    pub fn new(context: &mut InitContext, parameters: StartSequenceParameters) -> Self {
+        let mut start_sequencers = vec![];
+        for idx in 0..N {
+            start_sequencers.push(EngineStartSequencer::new(context, idx + 1, parameters));
+        }
+
+        Self {
+            engine_mode_selector_id: context
+                .get_identifier("TURB ENG IGNITION SWITCH EX1:1".to_owned()),
+            engine_mode_selector: EngineModeSelector::Norm,
+            start_sequencers: start_sequencers.try_into().unwrap_or_else(
+                |v: Vec<EngineStartSequencer>| {
+                    panic!("Expected a Vec of length {} but it was {}", N, v.len())
+                },
+            ),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, engines: [&impl EngineUncorrectedN2; N]) {
+        for (sequencer, engine) in self.start_sequencers.iter_mut().zip(engines) {
+            sequencer.update(context, self.engine_mode_selector, engine);
+        }
+    }
+
+    pub fn start_sequencer(&self, engine_number: usize) -> &EngineStartSequencer {
+        &self.start_sequencers[engine_number - 1]
+    }
+
+    pub fn start_valve_is_commanded_open(&self, engine_number: usize) -> bool {
+        self.start_sequencer(engine_number)
+            .start_valve_is_commanded_open()
+    }
+}
+impl<const N: usize> EngineStartState for FullAuthorityDigitalEngineControl<N> {
+    fn engine_state(&self, engine_number: usize) -> EngineState {
+        self.start_sequencer(engine_number).engine_state()
+    }
+
+    fn engine_mode_selector(&self) -> EngineModeSelector {
+        self.engine_mode_selector
+    }
+}
+impl<const N: usize> SimulationElement for FullAuthorityDigitalEngineControl<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.start_sequencers, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.engine_mode_selector = reader.read(&self.engine_mode_selector_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft,
+    };
+
+    const PARAMETERS: StartSequenceParameters = StartSequenceParameters {
+        ignition_n2_percent: 20.,
+        starter_cutout_n2_percent: 50.,
+        start_complete_n2_percent: 55.,
+        start_egt_limit_celsius: 750.,
+    };
+
+    struct TestEngine {
+        n2: Ratio,
+    }
+    impl EngineUncorrectedN2 for TestEngine {
+        fn uncorrected_n2(&self) -> Ratio {
+            self.n2
+        }
+    }
+
+    struct TestAircraft {
+        fadec: FullAuthorityDigitalEngineControl<1>,
+        engine: TestEngine,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                fadec: FullAuthorityDigitalEngineControl::new(context, PARAMETERS),
+                engine: TestEngine {
+                    n2: Ratio::default(),
+                },
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.fadec.update(context, [&self.engine]);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.fadec.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct FadecTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl FadecTestBed {
+        fn new() -> Self {
+            let mut fadec_test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            fadec_test_bed.set_on_ground(true);
+
+            fadec_test_bed
+        }
+
+        fn in_flight(mut self) -> Self {
+            self.set_on_ground(false);
+            self
+        }
+
+        fn mode_selector(mut self, position: EngineModeSelector) -> Self {
+            self.write_by_name("TURB ENG IGNITION SWITCH EX1:1", position);
+            self
+        }
+
+        fn engine_master(mut self, is_on: bool) -> Self {
+            self.write_by_name("GENERAL ENG STARTER ACTIVE:1", is_on);
+            self
+        }
+
+        fn manual_start(mut self, is_on: bool) -> Self {
+            self.write_by_name("ENGMANSTART1_TOGGLE", is_on);
+            self
+        }
+
+        fn n2(mut self, n2: f64) -> Self {
+            self.command(|a| a.engine.n2 = Ratio::new::<percent>(n2));
+            self
+        }
+
+        fn egt(mut self, egt: f64) -> Self {
+            self.write_by_name(
+                "ENGINE_EGT:1",
+                ThermodynamicTemperature::new::<degree_celsius>(egt),
+            );
+            self
+        }
+
+        fn and_run(mut self) -> Self {
+            self.run();
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.test_bed.run_multiple_frames(duration);
+            self
+        }
+
+        fn auto_start_to(self, n2: f64) -> Self {
+            self.mode_selector(EngineModeSelector::Ignition)
+                .engine_master(true)
+                .and_run()
+                .n2(n2)
+                .and_run()
+        }
+
+        fn complete_start(self) -> Self {
+            self.n2(60.).and_run().engine_master(false).n2(0.).and_run()
+        }
+
+        fn engine_state(&self) -> EngineState {
+            self.query(|a| a.fadec.engine_state(1))
+        }
+
+        fn start_valve_is_commanded_open(&self) -> bool {
+            self.query(|a| a.fadec.start_valve_is_commanded_open(1))
+        }
+
+        fn fuel_is_commanded_on(&self) -> bool {
+            self.query(|a| a.fadec.start_sequencer(1).fuel_is_commanded_on())
+        }
+
+        fn igniters_are_active(&mut self) -> (bool, bool) {
+            (
+                self.read_by_name("FADEC_IGNITER_A_ACTIVE_ENG1"),
+                self.read_by_name("FADEC_IGNITER_B_ACTIVE_ENG1"),
+            )
+        }
+
+        fn has_start_fault(&mut self) -> bool {
+            self.read_by_name("FADEC_START_FAULT_ENG1")
+        }
+
+        fn start_is_aborted(&mut self) -> bool {
+            self.read_by_name("FADEC_START_ABORTED_ENG1")
+        }
+    }
+    impl TestBed for FadecTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> FadecTestBed {
+        FadecTestBed::new()
+    }
+
+    #[test]
+    fn engine_is_off_after_construction() {
+        let mut test_bed = test_bed().and_run();
+
+        assert_eq!(test_bed.engine_state(), EngineState::Off);
+        assert!(!test_bed.start_valve_is_commanded_open());
+        assert_eq!(test_bed.igniters_are_active(), (false, false));
+    }
+
+    #[test]
+    fn engine_master_on_in_norm_does_not_start_the_engine() {
+        let test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Norm)
+            .engine_master(true)
+            .and_run();
+
+        assert_eq!(test_bed.engine_state(), EngineState::Off);
+        assert!(!test_bed.start_valve_is_commanded_open());
+    }
+
+    #[test]
+    fn engine_master_on_with_running_engine_is_on() {
+        let test_bed = test_bed().engine_master(true).n2(70.).and_run();
+
+        assert_eq!(test_bed.engine_state(), EngineState::On);
+        assert!(test_bed.fuel_is_commanded_on());
+    }
+
+    #[test]
+    fn auto_start_opens_start_valve_without_fuel_below_ignition_n2() {
+        let test_bed = test_bed().auto_start_to(10.);
+
+        assert_eq!(test_bed.engine_state(), EngineState::Starting);
+        assert!(test_bed.start_valve_is_commanded_open());
+        assert!(!test_bed.fuel_is_commanded_on());
+    }
+
+    #[test]
+    fn auto_start_on_ground_ignites_with_a_single_igniter() {
+        let mut test_bed = test_bed().auto_start_to(25.);
+
+        assert!(test_bed.fuel_is_commanded_on());
+        assert_eq!(test_bed.igniters_are_active(), (true, false));
+    }
+
+    #[test]
+    fn auto_start_on_ground_alternates_igniters_between_starts() {
+        let mut test_bed = test_bed().auto_start_to(25.).complete_start();
+
+        test_bed = test_bed.auto_start_to(25.);
+
+        assert_eq!(test_bed.igniters_are_active(), (false, true));
+    }
+
+    #[test]
+    fn auto_start_in_flight_ignites_with_both_igniters() {
+        let mut test_bed = test_bed().in_flight().auto_start_to(25.);
+
+        assert_eq!(test_bed.engine_state(), EngineState::Restarting);
+        assert_eq!(test_bed.igniters_are_active(), (true, true));
+    }
+
+    #[test]
+    fn start_valve_closes_at_starter_cutout_n2() {
+        let mut test_bed = test_bed().auto_start_to(52.);
+
+        assert_eq!(test_bed.engine_state(), EngineState::Starting);
+        assert!(!test_bed.start_valve_is_commanded_open());
+
+        test_bed = test_bed.n2(45.).and_run();
+
+        assert!(!test_bed.start_valve_is_commanded_open());
+    }
+
+    #[test]
+    fn engine_is_on_when_start_is_complete() {
+        let mut test_bed = test_bed().auto_start_to(25.).n2(60.).and_run();
+
+        assert_eq!(test_bed.engine_state(), EngineState::On);
+        assert!(!test_bed.start_valve_is_commanded_open());
+        assert_eq!(test_bed.igniters_are_active(), (false, false));
+    }
+
+    #[test]
+    fn hot_start_aborts_auto_start_on_ground() {
+        let mut test_bed = test_bed().auto_start_to(30.).egt(800.).and_run().and_run();
+
+        assert!(test_bed.has_start_fault());
+        assert!(test_bed.start_is_aborted());
+        assert!(!test_bed.fuel_is_commanded_on());
+        assert_eq!(test_bed.igniters_are_active(), (false, false));
+        assert!(test_bed.start_valve_is_commanded_open());
+    }
+
+    #[test]
+    fn aborted_start_closes_start_valve_after_dry_crank() {
+        let test_bed = test_bed()
+            .auto_start_to(30.)
+            .egt(800.)
+            .and_run()
+            .and_run()
+            .run_for(Duration::from_secs(31));
+
+        assert!(!test_bed.start_valve_is_commanded_open());
+        assert_eq!(test_bed.engine_state(), EngineState::Off);
+    }
+
+    #[test]
+    fn hung_start_aborts_auto_start_on_ground() {
+        let mut test_bed = test_bed()
+            .auto_start_to(30.)
+            .run_for(Duration::from_secs(11))
+            .and_run();
+
+        assert!(test_bed.query(|a| a.fadec.start_sequencer(1).has_hung_start()));
+        assert!(test_bed.start_is_aborted());
+    }
+
+    #[test]
+    fn accelerating_engine_is_not_a_hung_start() {
+        let mut test_bed = test_bed().auto_start_to(25.);
+
+        for n2 in (27..=49).step_by(2) {
+            test_bed = test_bed.n2(n2 as f64).run_for(Duration::from_secs(1));
+        }
+
+        assert!(!test_bed.has_start_fault());
+        assert!(!test_bed.start_is_aborted());
+    }
+
+    #[test]
+    fn aborted_start_is_reset_by_engine_master_off() {
+        let mut test_bed = test_bed()
+            .auto_start_to(30.)
+            .egt(800.)
+            .and_run()
+            .and_run()
+            .engine_master(false)
+            .n2(0.)
+            .egt(100.)
+            .and_run();
+
+        assert!(!test_bed.start_is_aborted());
+        assert_eq!(test_bed.engine_state(), EngineState::Off);
+
+        test_bed = test_bed.engine_master(true).and_run();
+
+        assert_eq!(test_bed.engine_state(), EngineState::Starting);
+        assert!(!test_bed.has_start_fault());
+    }
+
+    #[test]
+    fn start_fault_in_flight_does_not_abort_the_start() {
+        let mut test_bed = test_bed()
+            .in_flight()
+            .auto_start_to(30.)
+            .egt(800.)
+            .and_run()
+            .and_run();
+
+        assert!(test_bed.has_start_fault());
+        assert!(!test_bed.start_is_aborted());
+        assert!(test_bed.fuel_is_commanded_on());
+    }
+
+    #[test]
+    fn manual_start_motors_the_engine_before_engine_master_on() {
+        let mut test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Ignition)
+            .manual_start(true)
+            .and_run();
+
+        assert!(test_bed.start_valve_is_commanded_open());
+        assert!(!test_bed.fuel_is_commanded_on());
+        assert_eq!(test_bed.igniters_are_active(), (false, false));
+
+        test_bed = test_bed.n2(22.).engine_master(true).and_run();
+
+        assert!(test_bed.start_valve_is_commanded_open());
+        assert!(test_bed.fuel_is_commanded_on());
+        assert_eq!(test_bed.igniters_are_active(), (true, true));
+    }
+
+    #[test]
+    fn manual_start_fault_does_not_abort_the_start() {
+        let mut test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Ignition)
+            .manual_start(true)
+            .n2(22.)
+            .engine_master(true)
+            .and_run()
+            .egt(800.)
+            .and_run()
+            .and_run();
+
+        assert!(test_bed.has_start_fault());
+        assert!(!test_bed.start_is_aborted());
+    }
+
+    #[test]
+    fn crank_motors_the_engine_without_ignition() {
+        let mut test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Crank)
+            .manual_start(true)
+            .n2(20.)
+            .and_run();
+
+        assert!(test_bed.start_valve_is_commanded_open());
+        assert!(!test_bed.fuel_is_commanded_on());
+        assert_eq!(test_bed.igniters_are_active(), (false, false));
+
+        test_bed = test_bed.manual_start(false).and_run();
+
+        assert!(!test_bed.start_valve_is_commanded_open());
+    }
+
+    #[test]
+    fn engine_master_off_shuts_the_engine_down() {
+        let mut test_bed = test_bed()
+            .engine_master(true)
+            .n2(70.)
+            .and_run()
+            .engine_master(false)
+            .n2(40.)
+            .and_run();
+
+        assert_eq!(test_bed.engine_state(), EngineState::Shutting);
+        assert!(!test_bed.fuel_is_commanded_on());
+
+        test_bed = test_bed.n2(0.).and_run();
+
+        assert_eq!(test_bed.engine_state(), EngineState::Off);
+    }
+}
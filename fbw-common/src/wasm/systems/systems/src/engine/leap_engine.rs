@@ -2,16 +2,17 @@ use uom::si::{angular_velocity::revolution_per_minute, f64::*, pressure::psi, ra
 
 use crate::{
     shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2},
-    simulation::{Read, SimulationElement, SimulatorReader, UpdateContext},
+    simulation::{Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext, Write},
 };
 
-use super::Engine;
+use super::{fadec::StartSequenceParameters, Engine};
 use crate::simulation::{InitContext, VariableIdentifier};
 
 pub struct LeapEngine {
     corrected_n1_id: VariableIdentifier,
     corrected_n2_id: VariableIdentifier,
     thrust_id: VariableIdentifier,
+    fuel_starved_id: VariableIdentifier,
 
     corrected_n1: Ratio,
     corrected_n2: Ratio,
@@ -24,6 +25,8 @@ pub struct LeapEngine {
     oil_pressure: Pressure,
 
     net_thrust: Mass,
+
+    is_fed: bool,
 }
 impl LeapEngine {
     // According to the Type Certificate Data Sheet of LEAP 1A26
@@ -37,12 +40,20 @@ impl LeapEngine {
 
     const LOW_OIL_PRESSURE_THRESHOLD_PSI: f64 = 18.;
 
+    pub const START_SEQUENCE_PARAMETERS: StartSequenceParameters = StartSequenceParameters {
+        ignition_n2_percent: 20.,
+        starter_cutout_n2_percent: 50.,
+        start_complete_n2_percent: Self::MIN_IDLE_N2_UNCORRECTED_THRESHOLD_PERCENT,
+        start_egt_limit_celsius: 1060.,
+    };
+
     pub fn new(context: &mut InitContext, number: usize) -> LeapEngine {
         LeapEngine {
             corrected_n1_id: context.get_identifier(format!("TURB ENG CORRECTED N1:{}", number)),
             corrected_n2_id: context.get_identifier(format!("TURB ENG CORRECTED N2:{}", number)),
             uncorrected_n2_id: context.get_identifier(format!("ENGINE_N2:{}", number)),
             thrust_id: context.get_identifier(format!("TURB ENG JET THRUST:{}", number)),
+            fuel_starved_id: context.get_identifier(format!("ENGINE_FUEL_STARVED:{}", number)),
 
             corrected_n1: Ratio::new::<percent>(0.),
             corrected_n2: Ratio::new::<percent>(0.),
@@ -53,10 +64,17 @@ impl LeapEngine {
             oil_pressure: Pressure::new::<psi>(0.),
 
             net_thrust: Mass::default(),
+
+            is_fed: true,
         }
     }
 
-    pub fn update(&mut self, _: &UpdateContext) {}
+    /// Updates whether the fuel system feeds the engine. The FADEC cuts the engine when it
+    /// is starved, which is only signalled once the fuel system has been simulated so an
+    /// aircraft without it keeps its engines fed.
+    pub fn update(&mut self, _: &UpdateContext, is_fed: bool) {
+        self.is_fed = is_fed;
+    }
 
     fn update_parameters(&mut self) {
         self.n2_speed = AngularVelocity::new::<revolution_per_minute>(
@@ -76,6 +94,10 @@ impl SimulationElement for LeapEngine {
         self.net_thrust = reader.read(&self.thrust_id);
         self.update_parameters();
     }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.fuel_starved_id, !self.is_fed);
+    }
 }
 impl EngineCorrectedN1 for LeapEngine {
     fn corrected_n1(&self) -> Ratio {
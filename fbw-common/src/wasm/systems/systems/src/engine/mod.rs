@@ -7,6 +7,7 @@ use crate::{
     simulation::{SimulationElement, SimulationElementVisitor},
 };
 
+pub mod fadec;
 pub mod leap_engine;
 pub mod reverser_thrust;
 pub mod trent_engine;
@@ -5,7 +5,7 @@ use crate::{
     simulation::{Read, SimulationElement, SimulatorReader, UpdateContext},
 };
 
-use super::Engine;
+use super::{fadec::StartSequenceParameters, Engine};
 use crate::simulation::{InitContext, VariableIdentifier};
 
 pub struct TrentEngine {
@@ -41,6 +41,13 @@ impl TrentEngine {
 
     const LOW_OIL_PRESSURE_THRESHOLD_PSI: f64 = 18.;
 
+    pub const START_SEQUENCE_PARAMETERS: StartSequenceParameters = StartSequenceParameters {
+        ignition_n2_percent: 25.,
+        starter_cutout_n2_percent: 50.,
+        start_complete_n2_percent: Self::MIN_IDLE_N2_UNCORRECTED_THRESHOLD_PERCENT,
+        start_egt_limit_celsius: 900.,
+    };
+
     pub fn new(context: &mut InitContext, number: usize) -> TrentEngine {
         TrentEngine {
             thrust_id: context.get_identifier(format!("TURB ENG JET THRUST:{}", number)),
@@ -0,0 +1,663 @@
+use crate::{
+    shared::low_pass_filter::LowPassFilter,
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+use std::time::Duration;
+use uom::si::{
+    f64::{Length, Velocity},
+    length::foot,
+    velocity::{foot_per_minute, knot},
+};
+
+/// The aural alerts of the basic GPWS modes, ordered by increasing priority.
+/// Only the alert with the highest priority is announced at any time.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GpwsAuralAlert {
+    #[default]
+    None = 0,
+    Glideslope = 1,
+    DontSink = 2,
+    SinkRate = 3,
+    TooLowFlaps = 4,
+    TooLowGear = 5,
+    TooLowTerrain = 6,
+    Terrain = 7,
+    PullUp = 8,
+}
+
+/// The sensor data the basic modes work with. A `None` value means that the
+/// data isn't available or invalid.
+pub(super) struct GpwsInputs {
+    pub radio_altitude: Option<Length>,
+    pub barometric_altitude: Option<Length>,
+    pub vertical_speed: Option<Velocity>,
+    pub computed_airspeed: Option<Velocity>,
+    pub gear_is_down: bool,
+}
+
+/// Implements the basic ground proximity warning modes 1 to 5 as well as
+/// the radio altitude callouts of the EGPWC.
+pub struct GroundProximityWarning {
+    sys_off_pb_id: VariableIdentifier,
+    sys_off: bool,
+    gs_mode_off_pb_id: VariableIdentifier,
+    gs_mode_off: bool,
+    flap_mode_off_pb_id: VariableIdentifier,
+    flap_mode_off: bool,
+    landing_flaps_3_pb_id: VariableIdentifier,
+    landing_flaps_3: bool,
+    flaps_handle_index_id: VariableIdentifier,
+    flaps_handle_index: u8,
+    glideslope_is_valid_id: VariableIdentifier,
+    glideslope_is_valid: bool,
+    glideslope_deviation_id: VariableIdentifier,
+    glideslope_deviation_degrees: f64,
+
+    takeoff_mode: bool,
+    mode_1: ExcessiveDescentRate,
+    mode_2: ExcessiveTerrainClosureRate,
+    mode_3: AltitudeLossAfterTakeoff,
+    mode_4: UnsafeTerrainClearance,
+    mode_5: BelowGlideslope,
+    callouts: AltitudeCallouts,
+
+    aural_alert_id: VariableIdentifier,
+    altitude_callout_id: VariableIdentifier,
+    gpws_warning_id: VariableIdentifier,
+    glideslope_warning_id: VariableIdentifier,
+}
+
+impl GroundProximityWarning {
+    const MINIMUM_RADIO_ALTITUDE_FEET: f64 = 10.;
+    const MAXIMUM_RADIO_ALTITUDE_FEET: f64 = 2450.;
+    const TAKEOFF_MODE_END_RADIO_ALTITUDE_FEET: f64 = 1500.;
+    const FULL_FLAPS_HANDLE_INDEX: u8 = 4;
+    const FLAPS_3_HANDLE_INDEX: u8 = 3;
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            sys_off_pb_id: context.get_identifier("GPWS_SYS_OFF".to_owned()),
+            sys_off: false,
+            gs_mode_off_pb_id: context.get_identifier("GPWS_GS_OFF".to_owned()),
+            gs_mode_off: false,
+            flap_mode_off_pb_id: context.get_identifier("GPWS_FLAP_OFF".to_owned()),
+            flap_mode_off: false,
+            landing_flaps_3_pb_id: context.get_identifier("GPWS_FLAPS3".to_owned()),
+            landing_flaps_3: false,
+            flaps_handle_index_id: context.get_identifier("FLAPS_HANDLE_INDEX".to_owned()),
+            flaps_handle_index: 0,
+            glideslope_is_valid_id: context.get_identifier("RADIO_RECEIVER_GS_IS_VALID".to_owned()),
+            glideslope_is_valid: false,
+            glideslope_deviation_id: context
+                .get_identifier("RADIO_RECEIVER_GS_DEVIATION".to_owned()),
+            glideslope_deviation_degrees: 0.,
+
+            takeoff_mode: false,
+            mode_1: ExcessiveDescentRate::default(),
+            mode_2: ExcessiveTerrainClosureRate::new(),
+            mode_3: AltitudeLossAfterTakeoff::default(),
+            mode_4: UnsafeTerrainClearance::default(),
+            mode_5: BelowGlideslope::default(),
+            callouts: AltitudeCallouts::default(),
+
+            aural_alert_id: context.get_identifier("EGPWC_GPWS_AURAL_ALERT".to_owned()),
+            altitude_callout_id: context.get_identifier("EGPWC_ALTITUDE_CALLOUT".to_owned()),
+            gpws_warning_id: context.get_identifier("EGPWC_GPWS_WARNING".to_owned()),
+            glideslope_warning_id: context.get_identifier("EGPWC_GS_WARNING".to_owned()),
+        }
+    }
+
+    pub(super) fn update(&mut self, context: &UpdateContext, is_powered: bool, inputs: GpwsInputs) {
+        let radio_altitude = if is_powered {
+            inputs.radio_altitude
+        } else {
+            None
+        };
+
+        self.update_takeoff_mode(radio_altitude);
+        self.callouts.update(radio_altitude);
+
+        match radio_altitude {
+            Some(radio_altitude)
+                if !self.sys_off
+                    && radio_altitude.get::<foot>() >= Self::MINIMUM_RADIO_ALTITUDE_FEET
+                    && radio_altitude.get::<foot>() <= Self::MAXIMUM_RADIO_ALTITUDE_FEET =>
+            {
+                let flaps_in_landing_configuration = self.flaps_in_landing_configuration();
+                let airspeed = inputs.computed_airspeed.unwrap_or_default();
+
+                self.mode_1.update(radio_altitude, inputs.vertical_speed);
+                self.mode_2.update(
+                    context,
+                    radio_altitude,
+                    inputs.barometric_altitude,
+                    airspeed,
+                    inputs.gear_is_down,
+                    flaps_in_landing_configuration,
+                );
+                self.mode_3.update(
+                    radio_altitude,
+                    inputs.barometric_altitude,
+                    self.takeoff_mode,
+                );
+                self.mode_4.update(
+                    radio_altitude,
+                    airspeed,
+                    inputs.gear_is_down,
+                    flaps_in_landing_configuration || self.flap_mode_off,
+                    self.takeoff_mode,
+                );
+                self.mode_5.update(
+                    radio_altitude,
+                    inputs.gear_is_down && !self.gs_mode_off && self.glideslope_is_valid,
+                    self.glideslope_deviation_dots(),
+                );
+            }
+            _ => {
+                self.mode_1.reset();
+                self.mode_2.reset();
+                self.mode_3.reset();
+                self.mode_4.reset_alert();
+                self.mode_5.reset();
+            }
+        }
+    }
+
+    fn update_takeoff_mode(&mut self, radio_altitude: Option<Length>) {
+        if let Some(radio_altitude) = radio_altitude {
+            if radio_altitude.get::<foot>() < Self::MINIMUM_RADIO_ALTITUDE_FEET {
+                self.takeoff_mode = true;
+            } else if radio_altitude.get::<foot>() > Self::TAKEOFF_MODE_END_RADIO_ALTITUDE_FEET {
+                self.takeoff_mode = false;
+            }
+        }
+    }
+
+    fn flaps_in_landing_configuration(&self) -> bool {
+        if self.landing_flaps_3 {
+            self.flaps_handle_index >= Self::FLAPS_3_HANDLE_INDEX
+        } else {
+            self.flaps_handle_index >= Self::FULL_FLAPS_HANDLE_INDEX
+        }
+    }
+
+    /// Deviation below the glideslope in dots, where one dot is approximately 0.4°.
+    fn glideslope_deviation_dots(&self) -> f64 {
+        -self.glideslope_deviation_degrees / 0.4
+    }
+
+    pub fn aural_alert(&self) -> GpwsAuralAlert {
+        self.mode_1
+            .alert()
+            .max(self.mode_2.alert())
+            .max(self.mode_3.alert())
+            .max(self.mode_4.alert())
+            .max(self.mode_5.alert())
+    }
+
+    /// The radio altitude which has been called out last, if it hasn't been climbed above since.
+    pub fn altitude_callout(&self) -> Option<Length> {
+        self.callouts.callout()
+    }
+
+    /// Whether the GPWS lights are to be illuminated for one of the modes 1 to 4.
+    pub fn gpws_warning(&self) -> bool {
+        self.mode_1.alert() != GpwsAuralAlert::None
+            || self.mode_2.alert() != GpwsAuralAlert::None
+            || self.mode_3.alert() != GpwsAuralAlert::None
+            || self.mode_4.alert() != GpwsAuralAlert::None
+    }
+
+    /// Whether the G/S lights are to be illuminated for mode 5.
+    pub fn glideslope_warning(&self) -> bool {
+        self.mode_5.alert() != GpwsAuralAlert::None
+    }
+}
+
+impl SimulationElement for GroundProximityWarning {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.sys_off = reader.read(&self.sys_off_pb_id);
+        self.gs_mode_off = reader.read(&self.gs_mode_off_pb_id);
+        self.flap_mode_off = reader.read(&self.flap_mode_off_pb_id);
+        self.landing_flaps_3 = reader.read(&self.landing_flaps_3_pb_id);
+        self.flaps_handle_index = reader.read(&self.flaps_handle_index_id);
+        self.glideslope_is_valid = reader.read(&self.glideslope_is_valid_id);
+        self.glideslope_deviation_degrees = reader.read(&self.glideslope_deviation_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.aural_alert_id, self.aural_alert() as u8);
+        writer.write(
+            &self.altitude_callout_id,
+            self.altitude_callout()
+                .map_or(0., |altitude| altitude.get::<foot>()),
+        );
+        writer.write(&self.gpws_warning_id, self.gpws_warning());
+        writer.write(&self.glideslope_warning_id, self.glideslope_warning());
+    }
+}
+
+/// Mode 1: excessive descent rate, based on the inertial vertical speed.
+#[derive(Default)]
+struct ExcessiveDescentRate {
+    alert: GpwsAuralAlert,
+}
+
+impl ExcessiveDescentRate {
+    const MINIMUM_SINK_RATE_FPM: f64 = 1000.;
+
+    fn update(&mut self, radio_altitude: Length, vertical_speed: Option<Velocity>) {
+        self.alert = match vertical_speed {
+            Some(vertical_speed)
+                if -vertical_speed.get::<foot_per_minute>() > Self::MINIMUM_SINK_RATE_FPM =>
+            {
+                let sink_rate = -vertical_speed.get::<foot_per_minute>();
+                let radio_altitude = radio_altitude.get::<foot>();
+
+                let pull_up_altitude = if sink_rate < 1700. {
+                    1.3 * sink_rate - 1940.
+                } else {
+                    0.4 * sink_rate - 410.
+                };
+                let sink_rate_altitude = 0.61 * sink_rate - 600.;
+
+                if radio_altitude <= pull_up_altitude {
+                    GpwsAuralAlert::PullUp
+                } else if radio_altitude <= sink_rate_altitude {
+                    GpwsAuralAlert::SinkRate
+                } else {
+                    GpwsAuralAlert::None
+                }
+            }
+            _ => GpwsAuralAlert::None,
+        };
+    }
+
+    fn reset(&mut self) {
+        self.alert = GpwsAuralAlert::None;
+    }
+
+    fn alert(&self) -> GpwsAuralAlert {
+        self.alert
+    }
+}
+
+/// Mode 2: excessive terrain closure rate, based on the rate of change of the
+/// radio altitude. Mode 2A applies when the flaps are not in landing configuration,
+/// mode 2B when they are.
+struct ExcessiveTerrainClosureRate {
+    closure_rate: LowPassFilter<f64>,
+    previous_radio_altitude: Option<Length>,
+    time_in_envelope: Duration,
+    altitude_at_envelope_exit: Option<Length>,
+    alert: GpwsAuralAlert,
+}
+
+impl ExcessiveTerrainClosureRate {
+    const CLOSURE_RATE_FILTER_TIME_CONSTANT: Duration = Duration::from_millis(500);
+    const MINIMUM_CLOSURE_RATE_FPM: f64 = 2000.;
+    const MODE_2B_MAXIMUM_CLOSURE_RATE_FPM: f64 = 10000.;
+    const MODE_2B_MAXIMUM_RADIO_ALTITUDE_FEET: f64 = 789.;
+    const TERRAIN_ALERT_DURATION: Duration = Duration::from_secs(2);
+    const ALTITUDE_GAIN_AFTER_EXIT_FEET: f64 = 300.;
+
+    fn new() -> Self {
+        Self {
+            closure_rate: LowPassFilter::new(Self::CLOSURE_RATE_FILTER_TIME_CONSTANT),
+            previous_radio_altitude: None,
+            time_in_envelope: Duration::ZERO,
+            altitude_at_envelope_exit: None,
+            alert: GpwsAuralAlert::None,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        radio_altitude: Length,
+        barometric_altitude: Option<Length>,
+        airspeed: Velocity,
+        gear_is_down: bool,
+        flaps_in_landing_configuration: bool,
+    ) {
+        self.update_closure_rate(context, radio_altitude);
+
+        let closure_rate = self.closure_rate.output();
+        let radio_altitude_feet = radio_altitude.get::<foot>();
+        let upper_boundary_from_rate = if closure_rate < 3500. {
+            0.7937 * closure_rate - 1557.5
+        } else {
+            0.19166 * closure_rate + 610.
+        };
+
+        let in_envelope = closure_rate > Self::MINIMUM_CLOSURE_RATE_FPM
+            && radio_altitude_feet < upper_boundary_from_rate
+            && if flaps_in_landing_configuration {
+                radio_altitude_feet < Self::MODE_2B_MAXIMUM_RADIO_ALTITUDE_FEET
+                    && closure_rate < Self::MODE_2B_MAXIMUM_CLOSURE_RATE_FPM
+            } else {
+                let upper_boundary_from_airspeed =
+                    (8.8888 * airspeed.get::<knot>() - 305.555).clamp(1650., 2450.);
+                radio_altitude_feet < upper_boundary_from_airspeed
+            };
+
+        if in_envelope {
+            self.time_in_envelope += context.delta();
+            self.altitude_at_envelope_exit = None;
+
+            self.alert = if gear_is_down
+                || flaps_in_landing_configuration
+                || self.time_in_envelope < Self::TERRAIN_ALERT_DURATION
+            {
+                GpwsAuralAlert::Terrain
+            } else {
+                GpwsAuralAlert::PullUp
+            };
+        } else {
+            // After leaving the mode 2A envelope, the TERRAIN alert continues until
+            // the aircraft gained some barometric altitude.
+            if self.time_in_envelope > Duration::ZERO && !flaps_in_landing_configuration {
+                self.altitude_at_envelope_exit = barometric_altitude;
+            }
+            self.time_in_envelope = Duration::ZERO;
+
+            self.alert = match (self.altitude_at_envelope_exit, barometric_altitude) {
+                (Some(exit_altitude), Some(altitude))
+                    if (altitude - exit_altitude).get::<foot>()
+                        < Self::ALTITUDE_GAIN_AFTER_EXIT_FEET =>
+                {
+                    GpwsAuralAlert::Terrain
+                }
+                _ => {
+                    self.altitude_at_envelope_exit = None;
+                    GpwsAuralAlert::None
+                }
+            };
+        }
+    }
+
+    fn update_closure_rate(&mut self, context: &UpdateContext, radio_altitude: Length) {
+        if let Some(previous_radio_altitude) = self.previous_radio_altitude {
+            if context.delta() > Duration::ZERO {
+                let closure_rate = (previous_radio_altitude - radio_altitude).get::<foot>()
+                    / context.delta_as_secs_f64()
+                    * 60.;
+                self.closure_rate.update(context.delta(), closure_rate);
+            }
+        }
+
+        self.previous_radio_altitude = Some(radio_altitude);
+    }
+
+    fn reset(&mut self) {
+        self.closure_rate.reset(0.);
+        self.previous_radio_altitude = None;
+        self.time_in_envelope = Duration::ZERO;
+        self.altitude_at_envelope_exit = None;
+        self.alert = GpwsAuralAlert::None;
+    }
+
+    fn alert(&self) -> GpwsAuralAlert {
+        self.alert
+    }
+}
+
+/// Mode 3: barometric altitude loss after takeoff or go around.
+#[derive(Default)]
+struct AltitudeLossAfterTakeoff {
+    maximum_altitude: Option<Length>,
+    alert: GpwsAuralAlert,
+}
+
+impl AltitudeLossAfterTakeoff {
+    fn update(
+        &mut self,
+        radio_altitude: Length,
+        barometric_altitude: Option<Length>,
+        takeoff_mode: bool,
+    ) {
+        match barometric_altitude {
+            Some(altitude) if takeoff_mode => {
+                let maximum_altitude = self
+                    .maximum_altitude
+                    .map_or(altitude, |maximum| maximum.max(altitude));
+                self.maximum_altitude = Some(maximum_altitude);
+
+                let maximum_altitude_loss = 0.09 * radio_altitude.get::<foot>() + 7.1;
+                self.alert = if (maximum_altitude - altitude).get::<foot>() > maximum_altitude_loss
+                {
+                    GpwsAuralAlert::DontSink
+                } else {
+                    GpwsAuralAlert::None
+                };
+            }
+            _ => self.reset(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.maximum_altitude = None;
+        self.alert = GpwsAuralAlert::None;
+    }
+
+    fn alert(&self) -> GpwsAuralAlert {
+        self.alert
+    }
+}
+
+/// Mode 4: unsafe terrain clearance when not in landing configuration.
+/// Mode 4A applies with the gear up, mode 4B with the gear down and the flaps not in
+/// landing configuration, mode 4C during takeoff.
+#[derive(Default)]
+struct UnsafeTerrainClearance {
+    maximum_takeoff_radio_altitude: Option<Length>,
+    alert: GpwsAuralAlert,
+}
+
+impl UnsafeTerrainClearance {
+    const MINIMUM_RADIO_ALTITUDE_FEET: f64 = 30.;
+    const MAXIMUM_RADIO_ALTITUDE_FEET: f64 = 1000.;
+    const MODE_4C_MINIMUM_CLIMB_FEET: f64 = 100.;
+
+    fn update(
+        &mut self,
+        radio_altitude: Length,
+        airspeed: Velocity,
+        gear_is_down: bool,
+        flaps_in_landing_configuration: bool,
+        takeoff_mode: bool,
+    ) {
+        if takeoff_mode {
+            self.maximum_takeoff_radio_altitude = Some(
+                self.maximum_takeoff_radio_altitude
+                    .map_or(radio_altitude, |maximum| maximum.max(radio_altitude)),
+            );
+        } else {
+            self.maximum_takeoff_radio_altitude = None;
+        }
+
+        let radio_altitude_feet = radio_altitude.get::<foot>();
+        let airspeed_knots = airspeed.get::<knot>();
+
+        self.alert = if !(Self::MINIMUM_RADIO_ALTITUDE_FEET..=Self::MAXIMUM_RADIO_ALTITUDE_FEET)
+            .contains(&radio_altitude_feet)
+        {
+            GpwsAuralAlert::None
+        } else if takeoff_mode {
+            self.takeoff_alert(
+                radio_altitude_feet,
+                airspeed_knots,
+                gear_is_down && flaps_in_landing_configuration,
+            )
+        } else if !gear_is_down {
+            if airspeed_knots < 190. {
+                Self::alert_below(radio_altitude_feet, 500., GpwsAuralAlert::TooLowGear)
+            } else {
+                Self::alert_below(
+                    radio_altitude_feet,
+                    8.3333 * airspeed_knots - 1083.33,
+                    GpwsAuralAlert::TooLowTerrain,
+                )
+            }
+        } else if !flaps_in_landing_configuration {
+            if airspeed_knots < 159. {
+                Self::alert_below(radio_altitude_feet, 245., GpwsAuralAlert::TooLowFlaps)
+            } else {
+                Self::alert_below(
+                    radio_altitude_feet,
+                    8.2967 * airspeed_knots - 1074.18,
+                    GpwsAuralAlert::TooLowTerrain,
+                )
+            }
+        } else {
+            GpwsAuralAlert::None
+        };
+    }
+
+    fn takeoff_alert(
+        &self,
+        radio_altitude_feet: f64,
+        airspeed_knots: f64,
+        in_landing_configuration: bool,
+    ) -> GpwsAuralAlert {
+        match self.maximum_takeoff_radio_altitude {
+            Some(maximum) if !in_landing_configuration => {
+                let maximum_feet = maximum.get::<foot>();
+                let clearance_floor = (0.75 * maximum_feet)
+                    .min((8.3333 * airspeed_knots - 1083.33).clamp(500., 1000.));
+
+                if maximum_feet > Self::MODE_4C_MINIMUM_CLIMB_FEET {
+                    Self::alert_below(
+                        radio_altitude_feet,
+                        clearance_floor,
+                        GpwsAuralAlert::TooLowTerrain,
+                    )
+                } else {
+                    GpwsAuralAlert::None
+                }
+            }
+            _ => GpwsAuralAlert::None,
+        }
+    }
+
+    fn alert_below(
+        radio_altitude_feet: f64,
+        boundary_feet: f64,
+        alert: GpwsAuralAlert,
+    ) -> GpwsAuralAlert {
+        if radio_altitude_feet < boundary_feet.min(Self::MAXIMUM_RADIO_ALTITUDE_FEET) {
+            alert
+        } else {
+            GpwsAuralAlert::None
+        }
+    }
+
+    fn reset_alert(&mut self) {
+        self.alert = GpwsAuralAlert::None;
+    }
+
+    fn alert(&self) -> GpwsAuralAlert {
+        self.alert
+    }
+}
+
+/// Mode 5: descent below the glideslope on an ILS approach.
+#[derive(Default)]
+struct BelowGlideslope {
+    alert: GpwsAuralAlert,
+}
+
+impl BelowGlideslope {
+    const MINIMUM_RADIO_ALTITUDE_FEET: f64 = 30.;
+    const MAXIMUM_RADIO_ALTITUDE_FEET: f64 = 1000.;
+    const HARD_ALERT_MAXIMUM_RADIO_ALTITUDE_FEET: f64 = 350.;
+
+    fn update(&mut self, radio_altitude: Length, is_armed: bool, deviation_dots: f64) {
+        let radio_altitude = radio_altitude.get::<foot>();
+
+        if !is_armed
+            || !(Self::MINIMUM_RADIO_ALTITUDE_FEET..=Self::MAXIMUM_RADIO_ALTITUDE_FEET)
+                .contains(&radio_altitude)
+        {
+            self.reset();
+            return;
+        }
+
+        let soft_alert_minimum_altitude = if deviation_dots < 2.9 {
+            -75. * deviation_dots + 247.5
+        } else {
+            30.
+        };
+        let hard_alert_minimum_altitude = if deviation_dots < 3.8 {
+            -66.66 * deviation_dots + 283.33
+        } else {
+            30.
+        };
+
+        let hard_alert = deviation_dots > 2.
+            && radio_altitude > hard_alert_minimum_altitude
+            && radio_altitude < Self::HARD_ALERT_MAXIMUM_RADIO_ALTITUDE_FEET;
+        let soft_alert = deviation_dots > 1.3 && radio_altitude > soft_alert_minimum_altitude;
+
+        self.alert = if hard_alert || soft_alert {
+            GpwsAuralAlert::Glideslope
+        } else {
+            GpwsAuralAlert::None
+        };
+    }
+
+    fn reset(&mut self) {
+        self.alert = GpwsAuralAlert::None;
+    }
+
+    fn alert(&self) -> GpwsAuralAlert {
+        self.alert
+    }
+}
+
+/// Calls out the radio altitude when descending through one of the callout altitudes.
+/// The last callout is held until the next one or until climbing back above it, so
+/// the sound consumer doesn't miss it when running at a lower rate than the systems.
+/// Which of the callouts are announced is selected by the consumer.
+#[derive(Default)]
+struct AltitudeCallouts {
+    previous_radio_altitude: Option<Length>,
+    callout: Option<Length>,
+}
+
+impl AltitudeCallouts {
+    const CALLOUT_ALTITUDES_FEET: [f64; 14] = [
+        2500., 2000., 1000., 500., 400., 300., 200., 100., 50., 40., 30., 20., 10., 5.,
+    ];
+    const REARM_HYSTERESIS_FEET: f64 = 10.;
+
+    fn update(&mut self, radio_altitude: Option<Length>) {
+        let crossed_callout = match (self.previous_radio_altitude, radio_altitude) {
+            (Some(previous), Some(current)) => Self::CALLOUT_ALTITUDES_FEET
+                .iter()
+                .filter(|&&callout| {
+                    previous.get::<foot>() > callout && current.get::<foot>() <= callout
+                })
+                .last()
+                .map(|&callout| Length::new::<foot>(callout)),
+            _ => None,
+        };
+
+        self.callout = match (crossed_callout, radio_altitude) {
+            (Some(callout), _) => Some(callout),
+            (None, Some(current)) => self.callout.filter(|callout| {
+                current.get::<foot>() <= callout.get::<foot>() + Self::REARM_HYSTERESIS_FEET
+            }),
+            (None, None) => None,
+        };
+
+        self.previous_radio_altitude = radio_altitude;
+    }
+
+    fn callout(&self) -> Option<Length> {
+        self.callout
+    }
+}
@@ -1,13 +1,18 @@
 use crate::{
     accept_iterable,
-    enhanced_gpwc::navigation_display::NavigationDisplay,
+    enhanced_gpwc::{
+        ground_proximity_warning::{GpwsInputs, GroundProximityWarning},
+        navigation_display::NavigationDisplay,
+        terrain_awareness::{ElevationGrid, TawsInputs, TerrainAwareness},
+    },
+    navigation::radio_altimeter::RadioAltimeter,
     shared::{
         arinc429::{Arinc429Word, SignStatus},
         AdirsMeasurementOutputs, ElectricalBusType, ElectricalBuses, LgciuGearExtension,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, VariableIdentifier, Write,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 use std::vec::Vec;
@@ -17,7 +22,9 @@ use uom::si::{
     velocity::foot_per_minute,
 };
 
+pub mod ground_proximity_warning;
 pub mod navigation_display;
+pub mod terrain_awareness;
 
 pub struct EnhancedGroundProximityWarningComputer {
     powered_by: ElectricalBusType,
@@ -35,6 +42,8 @@ pub struct EnhancedGroundProximityWarningComputer {
     vertical_speed: Arinc429Word<Velocity>,
     navigation_display_range_lookup: Vec<Length>,
     navigation_displays: [NavigationDisplay; 2],
+    ground_proximity_warning: GroundProximityWarning,
+    terrain_awareness: TerrainAwareness,
     gear_is_down: bool,
     terronnd_rendering_mode: u8,
     // output variables of the EGPWC
@@ -76,6 +85,8 @@ impl EnhancedGroundProximityWarningComputer {
                 NavigationDisplay::new(context, "L"),
                 NavigationDisplay::new(context, "R"),
             ],
+            ground_proximity_warning: GroundProximityWarning::new(context),
+            terrain_awareness: TerrainAwareness::new(context),
             gear_is_down: true,
             terronnd_rendering_mode,
             egpwc_destination_longitude_id: context.get_identifier("EGPWC_DEST_LONG".to_owned()),
@@ -105,10 +116,24 @@ impl EnhancedGroundProximityWarningComputer {
         self.vertical_speed = adirs_output.vertical_speed(1);
     }
 
+    /// Plugs in the terrain elevation data used by the terrain look-ahead alerting.
+    pub fn set_elevation_grid(&mut self, elevation_grid: Box<dyn ElevationGrid>) {
+        self.terrain_awareness.set_elevation_grid(elevation_grid);
+    }
+
+    /// Selects the radio altitude of the first radio altimeter which provides valid data.
+    fn radio_altitude(radio_altimeters: [&impl RadioAltimeter; 2]) -> Option<Length> {
+        radio_altimeters
+            .iter()
+            .find_map(|radio_altimeter| radio_altimeter.radio_altitude().normal_value())
+    }
+
     pub fn update(
         &mut self,
+        context: &UpdateContext,
         adirs_output: &impl AdirsMeasurementOutputs,
         lgcius: &impl LgciuGearExtension,
+        radio_altimeters: [&impl RadioAltimeter; 2],
     ) {
         if !self.is_powered {
             self.destination_longitude =
@@ -135,6 +160,33 @@ impl EnhancedGroundProximityWarningComputer {
                 adirs_output.is_fully_aligned(1),
             )
         });
+
+        let radio_altitude = Self::radio_altitude(radio_altimeters);
+        self.ground_proximity_warning.update(
+            context,
+            self.is_powered,
+            GpwsInputs {
+                radio_altitude,
+                barometric_altitude: adirs_output.altitude(1).normal_value(),
+                vertical_speed: adirs_output.vertical_speed(1).normal_value(),
+                computed_airspeed: adirs_output.computed_airspeed(1).normal_value(),
+                gear_is_down: lgcius.all_down_and_locked(),
+            },
+        );
+        self.terrain_awareness.update(
+            self.is_powered,
+            TawsInputs {
+                latitude: self.latitude.normal_value(),
+                longitude: self.longitude.normal_value(),
+                track: self.heading.normal_value(),
+                altitude: self.altitude.normal_value(),
+                vertical_speed: self.vertical_speed.normal_value(),
+                ground_speed: adirs_output.ground_speed(1).normal_value(),
+                radio_altitude,
+                destination_latitude: self.destination_latitude.normal_value(),
+                destination_longitude: self.destination_longitude.normal_value(),
+            },
+        );
     }
 }
 
@@ -186,6 +238,8 @@ impl SimulationElement for EnhancedGroundProximityWarningComputer {
 
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.navigation_displays, visitor);
+        self.ground_proximity_warning.accept(visitor);
+        self.terrain_awareness.accept(visitor);
         visitor.visit(self);
     }
 }
@@ -195,6 +249,7 @@ mod tests {
     use super::*;
     use crate::{
         electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        enhanced_gpwc::terrain_awareness::InMemoryElevationGrid,
         shared::PotentialOrigin,
         simulation::{
             test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -202,12 +257,13 @@ mod tests {
         },
     };
     use ntest::assert_about_eq;
+    use std::time::Duration;
     use uom::si::{
         angle::degree,
         electric_potential::volt,
         f64::*,
         length::{foot, nautical_mile},
-        velocity::foot_per_minute,
+        velocity::{foot_per_minute, knot},
     };
 
     struct TestAdirs {
@@ -217,6 +273,8 @@ mod tests {
         heading: Arinc429Word<Angle>,
         vertical_speed: Arinc429Word<Velocity>,
         altitude: Arinc429Word<Length>,
+        computed_airspeed: Arinc429Word<Velocity>,
+        ground_speed: Arinc429Word<Velocity>,
     }
     impl TestAdirs {
         fn new() -> Self {
@@ -227,6 +285,11 @@ mod tests {
                 heading: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
                 vertical_speed: Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning),
                 altitude: Arinc429Word::new(Length::default(), SignStatus::FailureWarning),
+                computed_airspeed: Arinc429Word::new(
+                    Velocity::default(),
+                    SignStatus::FailureWarning,
+                ),
+                ground_speed: Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning),
             }
         }
 
@@ -244,6 +307,10 @@ mod tests {
             );
             self.altitude =
                 Arinc429Word::new(Length::new::<foot>(15000.0), SignStatus::NormalOperation);
+            self.computed_airspeed =
+                Arinc429Word::new(Velocity::new::<knot>(250.0), SignStatus::NormalOperation);
+            self.ground_speed =
+                Arinc429Word::new(Velocity::new::<knot>(240.0), SignStatus::NormalOperation);
         }
     }
     impl AdirsMeasurementOutputs for TestAdirs {
@@ -274,6 +341,34 @@ mod tests {
         fn altitude(&self, _adiru_number: usize) -> Arinc429Word<Length> {
             self.altitude
         }
+
+        fn computed_airspeed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
+            self.computed_airspeed
+        }
+
+        fn ground_speed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
+            self.ground_speed
+        }
+    }
+
+    struct TestRadioAltimeter {
+        radio_altitude: Arinc429Word<Length>,
+    }
+    impl TestRadioAltimeter {
+        fn new() -> Self {
+            Self {
+                radio_altitude: Arinc429Word::new(Length::default(), SignStatus::FailureWarning),
+            }
+        }
+
+        fn set_radio_altitude(&mut self, radio_altitude: Length) {
+            self.radio_altitude = Arinc429Word::new(radio_altitude, SignStatus::NormalOperation);
+        }
+    }
+    impl RadioAltimeter for TestRadioAltimeter {
+        fn radio_altitude(&self) -> Arinc429Word<Length> {
+            self.radio_altitude
+        }
     }
 
     struct TestLgciu {
@@ -318,6 +413,8 @@ mod tests {
     struct EgpwcTestAircraft {
         adirs: TestAdirs,
         lgciu: TestLgciu,
+        radio_altimeter_1: TestRadioAltimeter,
+        radio_altimeter_2: TestRadioAltimeter,
         egpwc: EnhancedGroundProximityWarningComputer,
         powered_source_dc: TestElectricitySource,
         dc_1_bus: ElectricalBus,
@@ -328,6 +425,8 @@ mod tests {
             Self {
                 adirs: TestAdirs::new(),
                 lgciu: TestLgciu::new(),
+                radio_altimeter_1: TestRadioAltimeter::new(),
+                radio_altimeter_2: TestRadioAltimeter::new(),
                 egpwc: EnhancedGroundProximityWarningComputer::new(
                     context,
                     ElectricalBusType::DirectCurrent(1),
@@ -350,8 +449,13 @@ mod tests {
             }
         }
 
-        fn update(&mut self) {
-            self.egpwc.update(&self.adirs, &self.lgciu);
+        fn update(&mut self, context: &UpdateContext) {
+            self.egpwc.update(
+                context,
+                &self.adirs,
+                &self.lgciu,
+                [&self.radio_altimeter_1, &self.radio_altimeter_2],
+            );
         }
 
         fn initialize_adiru(&mut self) {
@@ -362,6 +466,55 @@ mod tests {
             self.lgciu.set_gear_down(true);
         }
 
+        fn gear_up(&mut self) {
+            self.lgciu.set_gear_down(false);
+        }
+
+        fn set_radio_altitude(&mut self, radio_altitude: Length) {
+            self.radio_altimeter_1.set_radio_altitude(radio_altitude);
+            self.radio_altimeter_2.set_radio_altitude(radio_altitude);
+        }
+
+        fn fail_radio_altimeter_1(&mut self) {
+            self.radio_altimeter_1.radio_altitude =
+                Arinc429Word::new(Length::default(), SignStatus::FailureWarning);
+        }
+
+        fn set_radio_altitude_2(&mut self, radio_altitude: Length) {
+            self.radio_altimeter_2.set_radio_altitude(radio_altitude);
+        }
+
+        fn set_altitude(&mut self, altitude: Length) {
+            self.adirs.altitude = Arinc429Word::new(altitude, SignStatus::NormalOperation);
+        }
+
+        fn set_vertical_speed(&mut self, vertical_speed: Velocity) {
+            self.adirs.vertical_speed =
+                Arinc429Word::new(vertical_speed, SignStatus::NormalOperation);
+        }
+
+        fn set_position(&mut self, latitude: Angle, longitude: Angle) {
+            self.adirs.latitude = Arinc429Word::new(latitude, SignStatus::NormalOperation);
+            self.adirs.longitude = Arinc429Word::new(longitude, SignStatus::NormalOperation);
+        }
+
+        fn set_heading(&mut self, heading: Angle) {
+            self.adirs.heading = Arinc429Word::new(heading, SignStatus::NormalOperation);
+        }
+
+        fn set_ground_speed(&mut self, ground_speed: Velocity) {
+            self.adirs.ground_speed = Arinc429Word::new(ground_speed, SignStatus::NormalOperation);
+        }
+
+        fn set_elevation_grid(&mut self, elevation_grid: InMemoryElevationGrid) {
+            self.egpwc.set_elevation_grid(Box::new(elevation_grid));
+        }
+
+        fn set_computed_airspeed(&mut self, computed_airspeed: Velocity) {
+            self.adirs.computed_airspeed =
+                Arinc429Word::new(computed_airspeed, SignStatus::NormalOperation);
+        }
+
         fn set_elec_powered(&mut self, is_powered: bool) {
             self.is_elec_powered = is_powered;
         }
@@ -381,8 +534,8 @@ mod tests {
             }
         }
 
-        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
-            self.update();
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.update(context);
         }
     }
     impl SimulationElement for EgpwcTestAircraft {
@@ -617,4 +770,577 @@ mod tests {
         let nd_terr_activate_fo: bool = test_bed.read_by_name("EGPWC_ND_R_TERRAIN_ACTIVE");
         assert!(!nd_terr_activate_fo);
     }
+
+    mod gpws {
+        use super::*;
+        use crate::enhanced_gpwc::ground_proximity_warning::GpwsAuralAlert;
+
+        fn test_bed_in_flight() -> SimulationTestBed<EgpwcTestAircraft> {
+            let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+            test_bed.command(|a| a.set_elec_powered(true));
+            test_bed.command(|a| a.initialize_adiru());
+            test_bed.command(|a| a.set_vertical_speed(Velocity::default()));
+
+            test_bed
+        }
+
+        fn run_at(test_bed: &mut SimulationTestBed<EgpwcTestAircraft>, radio_altitude_feet: f64) {
+            test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(radio_altitude_feet)));
+            test_bed.run();
+        }
+
+        fn aural_alert(test_bed: &mut SimulationTestBed<EgpwcTestAircraft>) -> u8 {
+            test_bed.read_by_name("EGPWC_GPWS_AURAL_ALERT")
+        }
+
+        fn gpws_warning(test_bed: &mut SimulationTestBed<EgpwcTestAircraft>) -> bool {
+            test_bed.read_by_name("EGPWC_GPWS_WARNING")
+        }
+
+        fn glideslope_warning(test_bed: &mut SimulationTestBed<EgpwcTestAircraft>) -> bool {
+            test_bed.read_by_name("EGPWC_GS_WARNING")
+        }
+
+        fn altitude_callout(test_bed: &mut SimulationTestBed<EgpwcTestAircraft>) -> f64 {
+            test_bed.read_by_name("EGPWC_ALTITUDE_CALLOUT")
+        }
+
+        #[test]
+        fn no_alert_in_level_flight() {
+            let mut test_bed = test_bed_in_flight();
+            run_at(&mut test_bed, 2000.);
+
+            assert_eq!(aural_alert(&mut test_bed), GpwsAuralAlert::None as u8);
+            assert!(!gpws_warning(&mut test_bed));
+            assert!(!glideslope_warning(&mut test_bed));
+        }
+
+        #[test]
+        fn mode_1_sink_rate() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-5000.)));
+            run_at(&mut test_bed, 2000.);
+
+            assert_eq!(aural_alert(&mut test_bed), GpwsAuralAlert::SinkRate as u8);
+            assert!(gpws_warning(&mut test_bed));
+        }
+
+        #[test]
+        fn mode_1_pull_up() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-5000.)));
+            run_at(&mut test_bed, 1200.);
+
+            assert_eq!(aural_alert(&mut test_bed), GpwsAuralAlert::PullUp as u8);
+            assert!(gpws_warning(&mut test_bed));
+        }
+
+        #[test]
+        fn gpws_sys_off_inhibits_the_alerts() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.write_by_name("GPWS_SYS_OFF", true);
+            test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-5000.)));
+            run_at(&mut test_bed, 1200.);
+
+            assert_eq!(aural_alert(&mut test_bed), GpwsAuralAlert::None as u8);
+            assert!(!gpws_warning(&mut test_bed));
+        }
+
+        #[test]
+        fn no_alert_when_unpowered() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.set_elec_powered(false));
+            test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-5000.)));
+            run_at(&mut test_bed, 1200.);
+
+            assert_eq!(aural_alert(&mut test_bed), GpwsAuralAlert::None as u8);
+            assert!(!gpws_warning(&mut test_bed));
+        }
+
+        #[test]
+        fn uses_radio_altimeter_2_when_radio_altimeter_1_failed() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-5000.)));
+            test_bed.command(|a| a.fail_radio_altimeter_1());
+            test_bed.command(|a| a.set_radio_altitude_2(Length::new::<foot>(1200.)));
+            test_bed.run();
+
+            assert_eq!(aural_alert(&mut test_bed), GpwsAuralAlert::PullUp as u8);
+        }
+
+        #[test]
+        fn mode_2_terrain_followed_by_pull_up_with_gear_up() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(250.)));
+
+            let closure_rate_feet_per_second = 4000. / 60.;
+            let mut radio_altitude = 1500.;
+            let mut alerts = vec![];
+            for _ in 0..50 {
+                test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(radio_altitude)));
+                test_bed.run_with_delta(Duration::from_millis(100));
+                alerts.push(aural_alert(&mut test_bed));
+
+                radio_altitude -= closure_rate_feet_per_second * 0.1;
+            }
+
+            assert!(alerts.contains(&(GpwsAuralAlert::Terrain as u8)));
+            assert_eq!(*alerts.last().unwrap(), GpwsAuralAlert::PullUp as u8);
+            assert!(gpws_warning(&mut test_bed));
+        }
+
+        #[test]
+        fn mode_3_dont_sink_after_takeoff() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.set_altitude(Length::new::<foot>(1000.)));
+            run_at(&mut test_bed, 5.);
+
+            test_bed.command(|a| a.set_altitude(Length::new::<foot>(1300.)));
+            run_at(&mut test_bed, 300.);
+            assert_eq!(aural_alert(&mut test_bed), GpwsAuralAlert::None as u8);
+
+            test_bed.command(|a| a.set_altitude(Length::new::<foot>(1250.)));
+            run_at(&mut test_bed, 260.);
+            assert_eq!(aural_alert(&mut test_bed), GpwsAuralAlert::DontSink as u8);
+            assert!(gpws_warning(&mut test_bed));
+        }
+
+        #[test]
+        fn mode_3_is_not_active_outside_takeoff() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.gear_down());
+            test_bed.write_by_name("FLAPS_HANDLE_INDEX", 4);
+            test_bed.command(|a| a.set_altitude(Length::new::<foot>(1300.)));
+            run_at(&mut test_bed, 300.);
+
+            test_bed.command(|a| a.set_altitude(Length::new::<foot>(1250.)));
+            run_at(&mut test_bed, 260.);
+            assert_eq!(aural_alert(&mut test_bed), GpwsAuralAlert::None as u8);
+        }
+
+        #[test]
+        fn mode_4_too_low_gear() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.gear_up());
+            test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(180.)));
+            run_at(&mut test_bed, 400.);
+
+            assert_eq!(aural_alert(&mut test_bed), GpwsAuralAlert::TooLowGear as u8);
+            assert!(gpws_warning(&mut test_bed));
+        }
+
+        #[test]
+        fn mode_4_too_low_terrain_at_high_speed() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.gear_up());
+            test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(250.)));
+            run_at(&mut test_bed, 800.);
+
+            assert_eq!(
+                aural_alert(&mut test_bed),
+                GpwsAuralAlert::TooLowTerrain as u8
+            );
+        }
+
+        #[test]
+        fn mode_4_too_low_flaps() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.gear_down());
+            test_bed.write_by_name("FLAPS_HANDLE_INDEX", 2);
+            test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(150.)));
+            run_at(&mut test_bed, 200.);
+
+            assert_eq!(
+                aural_alert(&mut test_bed),
+                GpwsAuralAlert::TooLowFlaps as u8
+            );
+        }
+
+        #[test]
+        fn mode_4_flap_mode_off_inhibits_too_low_flaps() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.gear_down());
+            test_bed.write_by_name("FLAPS_HANDLE_INDEX", 2);
+            test_bed.write_by_name("GPWS_FLAP_OFF", true);
+            test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(150.)));
+            run_at(&mut test_bed, 200.);
+
+            assert_eq!(aural_alert(&mut test_bed), GpwsAuralAlert::None as u8);
+        }
+
+        #[test]
+        fn mode_4_landing_flaps_3_selected() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.gear_down());
+            test_bed.write_by_name("FLAPS_HANDLE_INDEX", 3);
+            test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(150.)));
+            run_at(&mut test_bed, 200.);
+            assert_eq!(
+                aural_alert(&mut test_bed),
+                GpwsAuralAlert::TooLowFlaps as u8
+            );
+
+            test_bed.write_by_name("GPWS_FLAPS3", true);
+            run_at(&mut test_bed, 200.);
+            assert_eq!(aural_alert(&mut test_bed), GpwsAuralAlert::None as u8);
+        }
+
+        #[test]
+        fn mode_5_glideslope() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.gear_down());
+            test_bed.write_by_name("FLAPS_HANDLE_INDEX", 4);
+            test_bed.write_by_name("RADIO_RECEIVER_GS_IS_VALID", true);
+            test_bed.write_by_name("RADIO_RECEIVER_GS_DEVIATION", -0.8);
+            run_at(&mut test_bed, 500.);
+
+            assert_eq!(aural_alert(&mut test_bed), GpwsAuralAlert::Glideslope as u8);
+            assert!(glideslope_warning(&mut test_bed));
+            assert!(!gpws_warning(&mut test_bed));
+        }
+
+        #[test]
+        fn mode_5_is_inhibited_by_gs_mode_off() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.gear_down());
+            test_bed.write_by_name("FLAPS_HANDLE_INDEX", 4);
+            test_bed.write_by_name("GPWS_GS_OFF", true);
+            test_bed.write_by_name("RADIO_RECEIVER_GS_IS_VALID", true);
+            test_bed.write_by_name("RADIO_RECEIVER_GS_DEVIATION", -0.8);
+            run_at(&mut test_bed, 500.);
+
+            assert_eq!(aural_alert(&mut test_bed), GpwsAuralAlert::None as u8);
+            assert!(!glideslope_warning(&mut test_bed));
+        }
+
+        #[test]
+        fn mode_5_is_inactive_close_to_the_glideslope() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.gear_down());
+            test_bed.write_by_name("FLAPS_HANDLE_INDEX", 4);
+            test_bed.write_by_name("RADIO_RECEIVER_GS_IS_VALID", true);
+            test_bed.write_by_name("RADIO_RECEIVER_GS_DEVIATION", -0.2);
+            run_at(&mut test_bed, 500.);
+
+            assert!(!glideslope_warning(&mut test_bed));
+        }
+
+        #[test]
+        fn altitude_is_called_out_when_descending_through_it() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.gear_down());
+            test_bed.write_by_name("FLAPS_HANDLE_INDEX", 4);
+
+            run_at(&mut test_bed, 60.);
+            assert_about_eq!(altitude_callout(&mut test_bed), 0.);
+
+            run_at(&mut test_bed, 48.);
+            assert_about_eq!(altitude_callout(&mut test_bed), 50.);
+
+            run_at(&mut test_bed, 45.);
+            assert_about_eq!(altitude_callout(&mut test_bed), 50.);
+
+            run_at(&mut test_bed, 38.);
+            assert_about_eq!(altitude_callout(&mut test_bed), 40.);
+        }
+
+        #[test]
+        fn altitude_callout_is_cleared_when_climbing_back_above_it() {
+            let mut test_bed = test_bed_in_flight();
+            test_bed.command(|a| a.gear_down());
+            test_bed.write_by_name("FLAPS_HANDLE_INDEX", 4);
+
+            run_at(&mut test_bed, 110.);
+            run_at(&mut test_bed, 98.);
+            assert_about_eq!(altitude_callout(&mut test_bed), 100.);
+
+            run_at(&mut test_bed, 105.);
+            assert_about_eq!(altitude_callout(&mut test_bed), 100.);
+
+            run_at(&mut test_bed, 115.);
+            assert_about_eq!(altitude_callout(&mut test_bed), 0.);
+        }
+
+        #[test]
+        fn altitude_is_not_called_out_when_climbing() {
+            let mut test_bed = test_bed_in_flight();
+
+            run_at(&mut test_bed, 990.);
+            run_at(&mut test_bed, 1010.);
+            assert_about_eq!(altitude_callout(&mut test_bed), 0.);
+        }
+    }
+
+    mod taws {
+        use super::*;
+        use crate::enhanced_gpwc::terrain_awareness::TawsAuralAlert;
+
+        const LATITUDE: f64 = 20.3;
+        const LONGITUDE: f64 = 30.3;
+
+        /// A grid of flat terrain at sea level, rising to the given elevation from
+        /// the given distance north of the aircraft position.
+        fn terrain_rising_north_of_aircraft(
+            distance_nm: f64,
+            elevation: Length,
+        ) -> InMemoryElevationGrid {
+            let spacing = 0.001;
+            let rows = 1000;
+            let columns = 200;
+            let south_west_latitude = LATITUDE - 0.1;
+            let ridge_latitude = LATITUDE + distance_nm / 60.;
+
+            let elevations = (0..rows)
+                .flat_map(|row| {
+                    let latitude = south_west_latitude + row as f64 * spacing;
+                    let elevation = if latitude >= ridge_latitude {
+                        elevation
+                    } else {
+                        Length::default()
+                    };
+
+                    vec![elevation; columns]
+                })
+                .collect();
+
+            InMemoryElevationGrid::new(
+                Angle::new::<degree>(south_west_latitude),
+                Angle::new::<degree>(LONGITUDE - 0.1),
+                Angle::new::<degree>(spacing),
+                columns,
+                elevations,
+            )
+        }
+
+        fn test_bed_heading_north(
+            altitude_feet: f64,
+            vertical_speed_fpm: f64,
+            ground_speed_knots: f64,
+        ) -> SimulationTestBed<EgpwcTestAircraft> {
+            let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+            test_bed.command(|a| a.set_elec_powered(true));
+            test_bed.command(|a| a.initialize_adiru());
+            test_bed.command(|a| {
+                a.set_position(
+                    Angle::new::<degree>(LATITUDE),
+                    Angle::new::<degree>(LONGITUDE),
+                )
+            });
+            test_bed.command(|a| a.set_heading(Angle::default()));
+            test_bed.command(|a| a.set_altitude(Length::new::<foot>(altitude_feet)));
+            test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(altitude_feet)));
+            test_bed.command(|a| {
+                a.set_vertical_speed(Velocity::new::<foot_per_minute>(vertical_speed_fpm))
+            });
+            test_bed.command(|a| a.set_ground_speed(Velocity::new::<knot>(ground_speed_knots)));
+
+            test_bed
+        }
+
+        fn set_destination_north_of_aircraft(
+            test_bed: &mut SimulationTestBed<EgpwcTestAircraft>,
+            distance_nm: f64,
+        ) {
+            test_bed.write_by_name("FM1_DEST_LAT_SSM", 3);
+            test_bed.write_by_name("FM1_DEST_LAT", LATITUDE + distance_nm / 60.);
+            test_bed.write_by_name("FM1_DEST_LONG_SSM", 3);
+            test_bed.write_by_name("FM1_DEST_LONG", LONGITUDE);
+        }
+
+        fn terrain_caution(test_bed: &mut SimulationTestBed<EgpwcTestAircraft>) -> bool {
+            test_bed.read_by_name("EGPWC_TERRAIN_CAUTION")
+        }
+
+        fn terrain_warning(test_bed: &mut SimulationTestBed<EgpwcTestAircraft>) -> bool {
+            test_bed.read_by_name("EGPWC_TERRAIN_WARNING")
+        }
+
+        fn aural_alert(test_bed: &mut SimulationTestBed<EgpwcTestAircraft>) -> u8 {
+            test_bed.read_by_name("EGPWC_TAWS_AURAL_ALERT")
+        }
+
+        #[test]
+        fn no_alert_over_flat_terrain() {
+            let mut test_bed = test_bed_heading_north(3000., 0., 240.);
+            test_bed.command(|a| {
+                a.set_elevation_grid(terrain_rising_north_of_aircraft(3., Length::default()))
+            });
+            test_bed.run();
+
+            assert!(!terrain_caution(&mut test_bed));
+            assert!(!terrain_warning(&mut test_bed));
+            assert_eq!(aural_alert(&mut test_bed), TawsAuralAlert::None as u8);
+        }
+
+        #[test]
+        fn caution_for_terrain_ahead_within_a_minute() {
+            let mut test_bed = test_bed_heading_north(3000., 0., 240.);
+            test_bed.command(|a| {
+                a.set_elevation_grid(terrain_rising_north_of_aircraft(
+                    3.,
+                    Length::new::<foot>(2800.),
+                ))
+            });
+            test_bed.run();
+
+            assert!(terrain_caution(&mut test_bed));
+            assert!(!terrain_warning(&mut test_bed));
+            assert_eq!(
+                aural_alert(&mut test_bed),
+                TawsAuralAlert::TerrainAhead as u8
+            );
+        }
+
+        #[test]
+        fn warning_for_terrain_ahead_within_half_a_minute() {
+            let mut test_bed = test_bed_heading_north(3000., 0., 240.);
+            test_bed.command(|a| {
+                a.set_elevation_grid(terrain_rising_north_of_aircraft(
+                    1.5,
+                    Length::new::<foot>(2800.),
+                ))
+            });
+            test_bed.run();
+
+            assert!(!terrain_caution(&mut test_bed));
+            assert!(terrain_warning(&mut test_bed));
+            assert_eq!(
+                aural_alert(&mut test_bed),
+                TawsAuralAlert::TerrainAheadPullUp as u8
+            );
+        }
+
+        #[test]
+        fn descending_flight_path_is_taken_into_account() {
+            let mut test_bed = test_bed_heading_north(3000., -1500., 240.);
+            test_bed.command(|a| {
+                a.set_elevation_grid(terrain_rising_north_of_aircraft(
+                    3.,
+                    Length::new::<foot>(1500.),
+                ))
+            });
+            test_bed.run();
+
+            assert!(terrain_caution(&mut test_bed));
+        }
+
+        #[test]
+        fn terrain_behind_the_aircraft_is_ignored() {
+            let mut test_bed = test_bed_heading_north(3000., 0., 240.);
+            test_bed.command(|a| a.set_heading(Angle::new::<degree>(180.)));
+            test_bed.command(|a| {
+                a.set_elevation_grid(terrain_rising_north_of_aircraft(
+                    0.5,
+                    Length::new::<foot>(2800.),
+                ))
+            });
+            test_bed.run();
+
+            assert!(!terrain_caution(&mut test_bed));
+            assert!(!terrain_warning(&mut test_bed));
+        }
+
+        #[test]
+        fn no_look_ahead_alert_without_elevation_grid() {
+            let mut test_bed = test_bed_heading_north(3000., -6000., 240.);
+            test_bed.run();
+
+            assert!(!terrain_caution(&mut test_bed));
+            assert!(!terrain_warning(&mut test_bed));
+        }
+
+        #[test]
+        fn terrain_off_inhibits_the_alerts() {
+            let mut test_bed = test_bed_heading_north(3000., 0., 240.);
+            test_bed.write_by_name("GPWS_TERR_OFF", true);
+            test_bed.command(|a| {
+                a.set_elevation_grid(terrain_rising_north_of_aircraft(
+                    1.5,
+                    Length::new::<foot>(2800.),
+                ))
+            });
+            test_bed.run();
+
+            assert!(!terrain_warning(&mut test_bed));
+            assert_eq!(aural_alert(&mut test_bed), TawsAuralAlert::None as u8);
+        }
+
+        #[test]
+        fn no_alert_when_unpowered() {
+            let mut test_bed = test_bed_heading_north(3000., 0., 240.);
+            test_bed.command(|a| a.set_elec_powered(false));
+            test_bed.command(|a| {
+                a.set_elevation_grid(terrain_rising_north_of_aircraft(
+                    1.5,
+                    Length::new::<foot>(2800.),
+                ))
+            });
+            test_bed.run();
+
+            assert!(!terrain_warning(&mut test_bed));
+        }
+
+        #[test]
+        fn required_clearance_is_reduced_on_approach_to_the_destination() {
+            let mut test_bed = test_bed_heading_north(950., -700., 140.);
+            test_bed.command(|a| {
+                a.set_elevation_grid(terrain_rising_north_of_aircraft(3., Length::default()))
+            });
+            test_bed.run();
+            assert!(terrain_caution(&mut test_bed));
+
+            set_destination_north_of_aircraft(&mut test_bed, 3.);
+            test_bed.run();
+            assert!(!terrain_caution(&mut test_bed));
+            assert!(!terrain_warning(&mut test_bed));
+        }
+
+        #[test]
+        fn look_ahead_is_inhibited_close_to_the_destination() {
+            let mut test_bed = test_bed_heading_north(100., -700., 140.);
+            test_bed.command(|a| {
+                a.set_elevation_grid(terrain_rising_north_of_aircraft(
+                    0.2,
+                    Length::new::<foot>(200.),
+                ))
+            });
+            set_destination_north_of_aircraft(&mut test_bed, 0.5);
+            test_bed.run();
+
+            assert!(!terrain_caution(&mut test_bed));
+            assert!(!terrain_warning(&mut test_bed));
+        }
+
+        #[test]
+        fn terrain_clearance_floor_alert_when_too_low_on_approach() {
+            let mut test_bed = test_bed_heading_north(200., 0., 140.);
+            set_destination_north_of_aircraft(&mut test_bed, 5.);
+            test_bed.run();
+
+            assert!(terrain_caution(&mut test_bed));
+            assert!(!terrain_warning(&mut test_bed));
+            assert_eq!(
+                aural_alert(&mut test_bed),
+                TawsAuralAlert::TooLowTerrain as u8
+            );
+        }
+
+        #[test]
+        fn no_terrain_clearance_floor_alert_above_the_floor() {
+            let mut test_bed = test_bed_heading_north(600., 0., 140.);
+            set_destination_north_of_aircraft(&mut test_bed, 5.);
+            test_bed.run();
+
+            assert!(!terrain_caution(&mut test_bed));
+        }
+
+        #[test]
+        fn no_terrain_clearance_floor_alert_far_from_the_destination() {
+            let mut test_bed = test_bed_heading_north(200., 0., 140.);
+            set_destination_north_of_aircraft(&mut test_bed, 20.);
+            test_bed.run();
+
+            assert!(!terrain_caution(&mut test_bed));
+        }
+    }
 }
@@ -0,0 +1,349 @@
+use crate::simulation::{
+    InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, VariableIdentifier,
+    Write,
+};
+use std::time::Duration;
+use uom::si::{
+    angle::degree,
+    f64::{Angle, Length, Velocity},
+    length::foot,
+    ratio::ratio,
+    velocity::{foot_per_minute, knot},
+};
+
+/// Provides the terrain elevation used by the forward looking terrain awareness functions.
+pub trait ElevationGrid {
+    /// The elevation of the terrain at the given position, or `None` when the
+    /// grid doesn't cover the position.
+    fn elevation(&self, latitude: Angle, longitude: Angle) -> Option<Length>;
+}
+
+/// An elevation grid with equally spaced cells, held in memory.
+/// Each cell covers `spacing` degrees of latitude and longitude, starting at the
+/// south western corner of the grid. The elevations are stored row by row from
+/// south to north, each row from west to east.
+pub struct InMemoryElevationGrid {
+    south_west_latitude: Angle,
+    south_west_longitude: Angle,
+    spacing: Angle,
+    columns: usize,
+    elevations: Vec<Length>,
+}
+
+impl InMemoryElevationGrid {
+    pub fn new(
+        south_west_latitude: Angle,
+        south_west_longitude: Angle,
+        spacing: Angle,
+        columns: usize,
+        elevations: Vec<Length>,
+    ) -> Self {
+        debug_assert!(columns > 0 && elevations.len() % columns == 0);
+
+        Self {
+            south_west_latitude,
+            south_west_longitude,
+            spacing,
+            columns,
+            elevations,
+        }
+    }
+
+    fn rows(&self) -> usize {
+        self.elevations.len() / self.columns
+    }
+}
+
+impl ElevationGrid for InMemoryElevationGrid {
+    fn elevation(&self, latitude: Angle, longitude: Angle) -> Option<Length> {
+        let row = ((latitude - self.south_west_latitude) / self.spacing)
+            .get::<ratio>()
+            .floor();
+        let column = ((longitude - self.south_west_longitude) / self.spacing)
+            .get::<ratio>()
+            .floor();
+
+        if row < 0. || column < 0. || row >= self.rows() as f64 || column >= self.columns as f64 {
+            None
+        } else {
+            Some(self.elevations[row as usize * self.columns + column as usize])
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TawsAuralAlert {
+    #[default]
+    None = 0,
+    TooLowTerrain = 1,
+    TerrainAhead = 2,
+    TerrainAheadPullUp = 3,
+}
+
+/// The data the terrain awareness functions work with. A `None` value means that the
+/// data isn't available or invalid.
+pub(super) struct TawsInputs {
+    pub latitude: Option<Angle>,
+    pub longitude: Option<Angle>,
+    pub track: Option<Angle>,
+    pub altitude: Option<Length>,
+    pub vertical_speed: Option<Velocity>,
+    pub ground_speed: Option<Velocity>,
+    pub radio_altitude: Option<Length>,
+    pub destination_latitude: Option<Angle>,
+    pub destination_longitude: Option<Angle>,
+}
+
+/// Implements the terrain clearance floor (TCF) and the terrain look-ahead alerting of the EGPWC.
+///
+/// The look-ahead function extrapolates the flight path along the current track and vertical
+/// speed and compares it to the terrain elevation ahead. Close to the destination runway the
+/// required terrain clearance is reduced, the look-ahead doesn't extend beyond the runway and
+/// is inhibited altogether in its immediate vicinity.
+pub struct TerrainAwareness {
+    terrain_off_pb_id: VariableIdentifier,
+    terrain_off: bool,
+
+    elevation_grid: Option<Box<dyn ElevationGrid>>,
+
+    terrain_clearance_floor_alert: bool,
+    look_ahead_caution: bool,
+    look_ahead_warning: bool,
+
+    caution_id: VariableIdentifier,
+    warning_id: VariableIdentifier,
+    aural_alert_id: VariableIdentifier,
+}
+
+impl TerrainAwareness {
+    const CAUTION_LOOK_AHEAD_TIME: Duration = Duration::from_secs(60);
+    const WARNING_LOOK_AHEAD_TIME: Duration = Duration::from_secs(30);
+    const LOOK_AHEAD_SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+    const REQUIRED_TERRAIN_CLEARANCE_FEET: f64 = 500.;
+    const REQUIRED_TERRAIN_CLEARANCE_NEAR_DESTINATION_FEET_PER_NM: f64 = 100.;
+    const DESTINATION_INHIBIT_DISTANCE_NM: f64 = 1.;
+    const MINIMUM_RADIO_ALTITUDE_FEET: f64 = 30.;
+
+    const TCF_MAXIMUM_DISTANCE_NM: f64 = 12.;
+    const TCF_FLOOR_START_DISTANCE_NM: f64 = 1.;
+    const TCF_FLOOR_SLOPE_FEET_PER_NM: f64 = 100.;
+    const TCF_MAXIMUM_FLOOR_FEET: f64 = 700.;
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            terrain_off_pb_id: context.get_identifier("GPWS_TERR_OFF".to_owned()),
+            terrain_off: false,
+
+            elevation_grid: None,
+
+            terrain_clearance_floor_alert: false,
+            look_ahead_caution: false,
+            look_ahead_warning: false,
+
+            caution_id: context.get_identifier("EGPWC_TERRAIN_CAUTION".to_owned()),
+            warning_id: context.get_identifier("EGPWC_TERRAIN_WARNING".to_owned()),
+            aural_alert_id: context.get_identifier("EGPWC_TAWS_AURAL_ALERT".to_owned()),
+        }
+    }
+
+    pub fn set_elevation_grid(&mut self, elevation_grid: Box<dyn ElevationGrid>) {
+        self.elevation_grid = Some(elevation_grid);
+    }
+
+    pub(super) fn update(&mut self, is_powered: bool, inputs: TawsInputs) {
+        self.terrain_clearance_floor_alert = false;
+        self.look_ahead_caution = false;
+        self.look_ahead_warning = false;
+
+        let radio_altitude_above_minimum = inputs.radio_altitude.map_or(true, |radio_altitude| {
+            radio_altitude.get::<foot>() >= Self::MINIMUM_RADIO_ALTITUDE_FEET
+        });
+        if !is_powered || self.terrain_off || !radio_altitude_above_minimum {
+            return;
+        }
+
+        let (latitude, longitude) = match (inputs.latitude, inputs.longitude) {
+            (Some(latitude), Some(longitude)) => (latitude, longitude),
+            _ => return,
+        };
+
+        let distance_to_destination_nm =
+            match (inputs.destination_latitude, inputs.destination_longitude) {
+                (Some(destination_latitude), Some(destination_longitude)) => Some(distance_nm(
+                    latitude,
+                    longitude,
+                    destination_latitude,
+                    destination_longitude,
+                )),
+                _ => None,
+            };
+
+        self.update_terrain_clearance_floor(inputs.radio_altitude, distance_to_destination_nm);
+        self.update_look_ahead(latitude, longitude, &inputs, distance_to_destination_nm);
+    }
+
+    fn update_terrain_clearance_floor(
+        &mut self,
+        radio_altitude: Option<Length>,
+        distance_to_destination_nm: Option<f64>,
+    ) {
+        if let (Some(radio_altitude), Some(distance_to_destination_nm)) =
+            (radio_altitude, distance_to_destination_nm)
+        {
+            let floor = ((distance_to_destination_nm - Self::TCF_FLOOR_START_DISTANCE_NM)
+                * Self::TCF_FLOOR_SLOPE_FEET_PER_NM)
+                .clamp(0., Self::TCF_MAXIMUM_FLOOR_FEET);
+
+            self.terrain_clearance_floor_alert = distance_to_destination_nm
+                < Self::TCF_MAXIMUM_DISTANCE_NM
+                && radio_altitude.get::<foot>() < floor;
+        }
+    }
+
+    fn update_look_ahead(
+        &mut self,
+        latitude: Angle,
+        longitude: Angle,
+        inputs: &TawsInputs,
+        distance_to_destination_nm: Option<f64>,
+    ) {
+        let elevation_grid = match &self.elevation_grid {
+            Some(elevation_grid) => elevation_grid,
+            None => return,
+        };
+        let (track, altitude, vertical_speed, ground_speed) = match (
+            inputs.track,
+            inputs.altitude,
+            inputs.vertical_speed,
+            inputs.ground_speed,
+        ) {
+            (Some(track), Some(altitude), Some(vertical_speed), Some(ground_speed)) => {
+                (track, altitude, vertical_speed, ground_speed)
+            }
+            _ => return,
+        };
+
+        if distance_to_destination_nm
+            .is_some_and(|distance| distance < Self::DESTINATION_INHIBIT_DISTANCE_NM)
+        {
+            return;
+        }
+
+        let maximum_look_ahead_distance_nm = distance_to_destination_nm.unwrap_or(f64::MAX);
+        let mut time = Self::LOOK_AHEAD_SAMPLE_INTERVAL;
+        while time <= Self::CAUTION_LOOK_AHEAD_TIME {
+            let look_ahead_distance_nm =
+                ground_speed.get::<knot>().max(0.) * time.as_secs_f64() / 3600.;
+            if look_ahead_distance_nm > maximum_look_ahead_distance_nm {
+                break;
+            }
+
+            let (sample_latitude, sample_longitude) =
+                position_along_track(latitude, longitude, track, look_ahead_distance_nm);
+
+            if let Some(elevation) = elevation_grid.elevation(sample_latitude, sample_longitude) {
+                let required_clearance = Self::required_terrain_clearance_feet(
+                    sample_latitude,
+                    sample_longitude,
+                    inputs,
+                );
+                let predicted_altitude = altitude.get::<foot>()
+                    + vertical_speed.get::<foot_per_minute>() * time.as_secs_f64() / 60.;
+
+                if predicted_altitude < elevation.get::<foot>() + required_clearance {
+                    self.look_ahead_caution = true;
+                    if time <= Self::WARNING_LOOK_AHEAD_TIME {
+                        self.look_ahead_warning = true;
+                    }
+                }
+            }
+
+            time += Self::LOOK_AHEAD_SAMPLE_INTERVAL;
+        }
+    }
+
+    /// The required clearance above the terrain, which is reduced towards the destination
+    /// runway to allow for a normal approach.
+    fn required_terrain_clearance_feet(
+        latitude: Angle,
+        longitude: Angle,
+        inputs: &TawsInputs,
+    ) -> f64 {
+        match (inputs.destination_latitude, inputs.destination_longitude) {
+            (Some(destination_latitude), Some(destination_longitude)) => (distance_nm(
+                latitude,
+                longitude,
+                destination_latitude,
+                destination_longitude,
+            )
+                * Self::REQUIRED_TERRAIN_CLEARANCE_NEAR_DESTINATION_FEET_PER_NM)
+                .min(Self::REQUIRED_TERRAIN_CLEARANCE_FEET),
+            _ => Self::REQUIRED_TERRAIN_CLEARANCE_FEET,
+        }
+    }
+
+    pub fn caution(&self) -> bool {
+        (self.look_ahead_caution || self.terrain_clearance_floor_alert) && !self.warning()
+    }
+
+    pub fn warning(&self) -> bool {
+        self.look_ahead_warning
+    }
+
+    pub fn aural_alert(&self) -> TawsAuralAlert {
+        if self.look_ahead_warning {
+            TawsAuralAlert::TerrainAheadPullUp
+        } else if self.look_ahead_caution {
+            TawsAuralAlert::TerrainAhead
+        } else if self.terrain_clearance_floor_alert {
+            TawsAuralAlert::TooLowTerrain
+        } else {
+            TawsAuralAlert::None
+        }
+    }
+}
+
+impl SimulationElement for TerrainAwareness {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.terrain_off = reader.read(&self.terrain_off_pb_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.caution_id, self.caution());
+        writer.write(&self.warning_id, self.warning());
+        writer.write(&self.aural_alert_id, self.aural_alert() as u8);
+    }
+}
+
+/// The position reached when travelling the given distance along the track,
+/// using a flat earth approximation which is sufficient for the look-ahead distances.
+fn position_along_track(
+    latitude: Angle,
+    longitude: Angle,
+    track: Angle,
+    distance_nm: f64,
+) -> (Angle, Angle) {
+    let north_nm = distance_nm * track.cos().get::<ratio>();
+    let east_nm = distance_nm * track.sin().get::<ratio>();
+
+    (
+        latitude + Angle::new::<degree>(north_nm / 60.),
+        longitude
+            + Angle::new::<degree>(east_nm / (60. * latitude.cos().get::<ratio>().abs().max(0.01))),
+    )
+}
+
+/// The distance between two positions, using an equirectangular approximation.
+fn distance_nm(
+    from_latitude: Angle,
+    from_longitude: Angle,
+    to_latitude: Angle,
+    to_longitude: Angle,
+) -> f64 {
+    let mean_latitude = (from_latitude + to_latitude) / 2.;
+    let north_nm = (to_latitude - from_latitude).get::<degree>() * 60.;
+    let east_nm =
+        (to_longitude - from_longitude).get::<degree>() * 60. * mean_latitude.cos().get::<ratio>();
+
+    north_nm.hypot(east_nm)
+}
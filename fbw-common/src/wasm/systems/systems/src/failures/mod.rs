@@ -1,9 +1,14 @@
 use crate::air_conditioning::{acs_controller::AcscId, ZoneType};
+use crate::pneumatic::BleedDuct;
 use crate::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, GearActuatorId,
-    HydraulicColor, LgciuId, ProximityDetectorId,
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, FlightControlActuatorId,
+    GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
 };
-use crate::simulation::SimulationElement;
+use crate::simulation::{SimulationElement, SnapshotReader, SnapshotWriter};
+
+mod scheduler;
+pub(crate) use scheduler::FailureScheduler;
+pub use scheduler::{FailureState, FailureTrigger};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum FailureType {
@@ -15,7 +20,10 @@ pub enum FailureType {
     TrimAirHighPressure,
     GalleyFans,
     Generator(usize),
+    GeneratorVoltageRegulator(usize),
+    IdgConstantSpeedDrive(usize),
     ApuGenerator(usize),
+    BatteryThermalRunaway(usize),
     TransformerRectifier(usize),
     StaticInverter,
     ElectricalBus(ElectricalBusType),
@@ -28,9 +36,24 @@ pub enum FailureType {
     LgciuInternalError(LgciuId),
     GearProxSensorDamage(ProximityDetectorId),
     GearActuatorJammed(GearActuatorId),
+    FlightControlActuatorJam(FlightControlActuatorId),
+    FlightControlActuatorRunaway(FlightControlActuatorId),
+    FlightControlActuatorDisconnect(FlightControlActuatorId),
     BrakeHydraulicLeak(HydraulicColor),
     BrakeAccumulatorGasLeak,
+    TireBurst(usize),
+    TireSlowLeak(usize),
+    WheelSpeedSensor(usize),
+    AntiSkid,
     RadioAltimeter(usize),
+    AdrFault(usize),
+    IrFault(usize),
+    PitotBlockage(usize),
+    StaticPortBlockage(usize),
+    AngleOfAttackVaneJam(usize),
+    GpsReceiver(usize),
+    FuelPump(usize),
+    BleedDuctLeak(BleedDuct),
 }
 
 pub struct Failure {
@@ -55,6 +78,20 @@ impl SimulationElement for Failure {
             self.is_active = is_active;
         }
     }
+
+    fn active_failure(&self) -> Option<FailureType> {
+        self.is_active.then_some(self.failure_type)
+    }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_bool("is_active", self.is_active);
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(is_active) = reader.read_bool("is_active") {
+            self.is_active = is_active;
+        }
+    }
 }
 
 #[cfg(test)]
@@ -88,4 +125,23 @@ mod tests {
 
         assert!(test_bed.query_element(|el| !el.is_active()));
     }
+
+    #[test]
+    fn restoring_a_snapshot_restores_whether_failed() {
+        let mut test_bed =
+            SimulationTestBed::from(Failure::new(FailureType::TransformerRectifier(1)));
+        test_bed.fail(FailureType::TransformerRectifier(1));
+        test_bed.run();
+        let snapshot = test_bed.snapshot();
+
+        test_bed.unfail(FailureType::TransformerRectifier(1));
+        test_bed.run();
+        test_bed.restore(&snapshot).unwrap();
+
+        assert!(test_bed.query_element(|el| el.is_active()));
+        assert_eq!(
+            test_bed.failure_state(FailureType::TransformerRectifier(1)),
+            FailureState::Active
+        );
+    }
 }
@@ -0,0 +1,477 @@
+use std::time::Duration;
+
+use uom::si::{f64::*, length::foot, velocity::knot};
+
+use crate::simulation::UpdateContext;
+
+use super::FailureType;
+
+/// The condition upon which an armed failure activates.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FailureTrigger {
+    /// Activates once the simulation time reaches the given time.
+    AtSimulationTime(Duration),
+    /// Activates when the indicated altitude is at or above the given altitude.
+    AltitudeAbove(Length),
+    /// Activates when the indicated altitude is at or below the given altitude.
+    AltitudeBelow(Length),
+    /// Activates when the indicated airspeed is at or above the given airspeed.
+    IndicatedAirspeedAbove(Velocity),
+    /// Activates when the indicated airspeed is at or below the given airspeed.
+    IndicatedAirspeedBelow(Velocity),
+    /// Activates when the aircraft leaves the ground.
+    Takeoff,
+    /// Activates when the aircraft touches down.
+    Touchdown,
+    /// Activates randomly, with the given mean time between failures.
+    MeanTimeBetweenFailures(Duration),
+}
+impl FailureTrigger {
+    /// Decodes a trigger from the type and value the simulator uses to arm a failure:
+    /// - 1: at simulation time, value in seconds.
+    /// - 2: altitude above, value in feet.
+    /// - 3: altitude below, value in feet.
+    /// - 4: indicated airspeed above, value in knots.
+    /// - 5: indicated airspeed below, value in knots.
+    /// - 6: takeoff, no value.
+    /// - 7: touchdown, no value.
+    /// - 8: random, value is the mean time between failures in hours.
+    pub fn decode(trigger_type: u8, value: f64) -> Option<Self> {
+        match trigger_type {
+            1 => Some(FailureTrigger::AtSimulationTime(Duration::from_secs_f64(
+                value.max(0.),
+            ))),
+            2 => Some(FailureTrigger::AltitudeAbove(Length::new::<foot>(value))),
+            3 => Some(FailureTrigger::AltitudeBelow(Length::new::<foot>(value))),
+            4 => Some(FailureTrigger::IndicatedAirspeedAbove(
+                Velocity::new::<knot>(value),
+            )),
+            5 => Some(FailureTrigger::IndicatedAirspeedBelow(
+                Velocity::new::<knot>(value),
+            )),
+            6 => Some(FailureTrigger::Takeoff),
+            7 => Some(FailureTrigger::Touchdown),
+            8 => Some(FailureTrigger::MeanTimeBetweenFailures(
+                Duration::from_secs_f64(value.max(0.) * 3600.),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Encodes the trigger into the type and value decoded by [`decode`].
+    ///
+    /// [`decode`]: #method.decode
+    pub fn encode(&self) -> (u8, f64) {
+        match self {
+            FailureTrigger::AtSimulationTime(time) => (1, time.as_secs_f64()),
+            FailureTrigger::AltitudeAbove(altitude) => (2, altitude.get::<foot>()),
+            FailureTrigger::AltitudeBelow(altitude) => (3, altitude.get::<foot>()),
+            FailureTrigger::IndicatedAirspeedAbove(airspeed) => (4, airspeed.get::<knot>()),
+            FailureTrigger::IndicatedAirspeedBelow(airspeed) => (5, airspeed.get::<knot>()),
+            FailureTrigger::Takeoff => (6, 0.),
+            FailureTrigger::Touchdown => (7, 0.),
+            FailureTrigger::MeanTimeBetweenFailures(time) => (8, time.as_secs_f64() / 3600.),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum FailureState {
+    #[default]
+    Inactive = 0,
+    Armed = 1,
+    Active = 2,
+}
+
+struct ArmedFailure {
+    failure_type: FailureType,
+    trigger: FailureTrigger,
+    was_on_ground: Option<bool>,
+}
+impl ArmedFailure {
+    fn new(failure_type: FailureType, trigger: FailureTrigger) -> Self {
+        Self {
+            failure_type,
+            trigger,
+            was_on_ground: None,
+        }
+    }
+
+    fn should_activate(&mut self, context: &UpdateContext) -> bool {
+        let was_on_ground = self.was_on_ground.replace(context.is_on_ground());
+
+        match self.trigger {
+            FailureTrigger::AtSimulationTime(time) => {
+                context.simulation_time() >= time.as_secs_f64()
+            }
+            FailureTrigger::AltitudeAbove(altitude) => context.indicated_altitude() >= altitude,
+            FailureTrigger::AltitudeBelow(altitude) => context.indicated_altitude() <= altitude,
+            FailureTrigger::IndicatedAirspeedAbove(airspeed) => {
+                context.indicated_airspeed() >= airspeed
+            }
+            FailureTrigger::IndicatedAirspeedBelow(airspeed) => {
+                context.indicated_airspeed() <= airspeed
+            }
+            FailureTrigger::Takeoff => was_on_ground == Some(true) && !context.is_on_ground(),
+            FailureTrigger::Touchdown => was_on_ground == Some(false) && context.is_on_ground(),
+            FailureTrigger::MeanTimeBetweenFailures(mean_time_between_failures) => {
+                let probability = 1.
+                    - (-context.delta_as_secs_f64()
+                        / mean_time_between_failures.as_secs_f64().max(f64::EPSILON))
+                    .exp();
+
+                context.random_from_range(0., 1.) < probability
+            }
+        }
+    }
+}
+
+/// Keeps track of failures which are armed to activate upon a trigger, and
+/// of failures which are active.
+pub(crate) struct FailureScheduler {
+    armed: Vec<ArmedFailure>,
+    active: Vec<FailureType>,
+}
+impl FailureScheduler {
+    pub(crate) fn new() -> Self {
+        Self {
+            armed: Vec::new(),
+            active: Vec::new(),
+        }
+    }
+
+    /// Arms the failure to activate upon the given trigger. Arming a failure which
+    /// is already armed replaces its trigger.
+    pub(crate) fn arm(&mut self, failure_type: FailureType, trigger: FailureTrigger) {
+        self.disarm(failure_type);
+        self.armed.push(ArmedFailure::new(failure_type, trigger));
+    }
+
+    pub(crate) fn disarm(&mut self, failure_type: FailureType) {
+        self.armed
+            .retain(|armed| armed.failure_type != failure_type);
+    }
+
+    /// Records that the failure was activated. An armed failure is disarmed
+    /// when it is activated by other means than its trigger.
+    pub(crate) fn activated(&mut self, failure_type: FailureType) {
+        self.disarm(failure_type);
+        if !self.active.contains(&failure_type) {
+            self.active.push(failure_type);
+        }
+    }
+
+    pub(crate) fn deactivated(&mut self, failure_type: FailureType) {
+        self.active.retain(|active| *active != failure_type);
+    }
+
+    /// Replaces the active failures by those of a restored snapshot. Armed failures
+    /// remain armed, unless they are active in the snapshot.
+    pub(crate) fn restore_active(&mut self, active: Vec<FailureType>) {
+        self.active = Vec::new();
+        for failure_type in active {
+            self.activated(failure_type);
+        }
+    }
+
+    pub(crate) fn state(&self, failure_type: FailureType) -> FailureState {
+        if self.active.contains(&failure_type) {
+            FailureState::Active
+        } else if self
+            .armed
+            .iter()
+            .any(|armed| armed.failure_type == failure_type)
+        {
+            FailureState::Armed
+        } else {
+            FailureState::Inactive
+        }
+    }
+
+    /// Evaluates the triggers of the armed failures and returns the failures
+    /// which have to be activated.
+    pub(crate) fn update(&mut self, context: &UpdateContext) -> Vec<FailureType> {
+        let mut triggered = Vec::new();
+        self.armed.retain_mut(|armed| {
+            if armed.should_activate(context) {
+                triggered.push(armed.failure_type);
+                false
+            } else {
+                true
+            }
+        });
+
+        for failure_type in triggered.iter() {
+            self.activated(*failure_type);
+        }
+
+        triggered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        failures::Failure,
+        simulation::test::{SimulationTestBed, TestAircraft, TestBed},
+    };
+
+    const FAILURE_TYPE: FailureType = FailureType::TransformerRectifier(1);
+
+    fn test_bed() -> SimulationTestBed<TestAircraft<Failure>> {
+        SimulationTestBed::from(Failure::new(FAILURE_TYPE))
+    }
+
+    fn is_failed(test_bed: &SimulationTestBed<TestAircraft<Failure>>) -> bool {
+        test_bed.query_element(|failure| failure.is_active())
+    }
+
+    #[test]
+    fn armed_failure_is_reported_as_armed() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(FAILURE_TYPE, FailureTrigger::Takeoff);
+        test_bed.run();
+
+        assert_eq!(test_bed.failure_state(FAILURE_TYPE), FailureState::Armed);
+        assert!(!is_failed(&test_bed));
+    }
+
+    #[test]
+    fn restoring_a_snapshot_keeps_armed_failures_armed() {
+        let mut test_bed = test_bed();
+        let snapshot = test_bed.snapshot();
+        test_bed.arm_failure(FAILURE_TYPE, FailureTrigger::Takeoff);
+        test_bed.run();
+
+        test_bed.restore(&snapshot).unwrap();
+
+        assert_eq!(test_bed.failure_state(FAILURE_TYPE), FailureState::Armed);
+    }
+
+    #[test]
+    fn restoring_a_snapshot_with_the_failure_active_disarms_it() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FAILURE_TYPE);
+        test_bed.run();
+        let snapshot = test_bed.snapshot();
+        test_bed.unfail(FAILURE_TYPE);
+        test_bed.arm_failure(FAILURE_TYPE, FailureTrigger::Takeoff);
+        test_bed.run();
+
+        test_bed.restore(&snapshot).unwrap();
+
+        assert_eq!(test_bed.failure_state(FAILURE_TYPE), FailureState::Active);
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn failure_is_inactive_when_not_armed() {
+        let test_bed = test_bed();
+
+        assert_eq!(test_bed.failure_state(FAILURE_TYPE), FailureState::Inactive);
+    }
+
+    #[test]
+    fn disarmed_failure_does_not_activate() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(
+            FAILURE_TYPE,
+            FailureTrigger::AltitudeAbove(Length::new::<foot>(10000.)),
+        );
+        test_bed.disarm_failure(FAILURE_TYPE);
+        test_bed.set_indicated_altitude(Length::new::<foot>(12000.));
+        test_bed.run();
+
+        assert_eq!(test_bed.failure_state(FAILURE_TYPE), FailureState::Inactive);
+        assert!(!is_failed(&test_bed));
+    }
+
+    #[test]
+    fn activates_at_simulation_time() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(
+            FAILURE_TYPE,
+            FailureTrigger::AtSimulationTime(Duration::from_secs(50)),
+        );
+        test_bed.run();
+
+        assert_eq!(test_bed.failure_state(FAILURE_TYPE), FailureState::Active);
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn does_not_activate_before_simulation_time() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(
+            FAILURE_TYPE,
+            FailureTrigger::AtSimulationTime(Duration::from_secs(500)),
+        );
+        test_bed.run();
+
+        assert_eq!(test_bed.failure_state(FAILURE_TYPE), FailureState::Armed);
+        assert!(!is_failed(&test_bed));
+    }
+
+    #[test]
+    fn activates_above_altitude() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(
+            FAILURE_TYPE,
+            FailureTrigger::AltitudeAbove(Length::new::<foot>(10000.)),
+        );
+        test_bed.set_indicated_altitude(Length::new::<foot>(9000.));
+        test_bed.run();
+        assert!(!is_failed(&test_bed));
+
+        test_bed.set_indicated_altitude(Length::new::<foot>(10100.));
+        test_bed.run();
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn activates_below_altitude() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(
+            FAILURE_TYPE,
+            FailureTrigger::AltitudeBelow(Length::new::<foot>(1000.)),
+        );
+        test_bed.set_indicated_altitude(Length::new::<foot>(1500.));
+        test_bed.run();
+        assert!(!is_failed(&test_bed));
+
+        test_bed.set_indicated_altitude(Length::new::<foot>(900.));
+        test_bed.run();
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn activates_above_indicated_airspeed() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(
+            FAILURE_TYPE,
+            FailureTrigger::IndicatedAirspeedAbove(Velocity::new::<knot>(300.)),
+        );
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+        test_bed.run();
+        assert!(!is_failed(&test_bed));
+
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(310.));
+        test_bed.run();
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn activates_below_indicated_airspeed() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(
+            FAILURE_TYPE,
+            FailureTrigger::IndicatedAirspeedBelow(Velocity::new::<knot>(140.)),
+        );
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(160.));
+        test_bed.run();
+        assert!(!is_failed(&test_bed));
+
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(130.));
+        test_bed.run();
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn activates_on_takeoff() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(FAILURE_TYPE, FailureTrigger::Takeoff);
+        test_bed.set_on_ground(true);
+        test_bed.run();
+        assert!(!is_failed(&test_bed));
+
+        test_bed.set_on_ground(false);
+        test_bed.run();
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn does_not_activate_on_takeoff_when_armed_in_flight() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(FAILURE_TYPE, FailureTrigger::Takeoff);
+        test_bed.set_on_ground(false);
+        test_bed.run();
+        test_bed.run();
+
+        assert!(!is_failed(&test_bed));
+    }
+
+    #[test]
+    fn activates_on_touchdown() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(FAILURE_TYPE, FailureTrigger::Touchdown);
+        test_bed.set_on_ground(false);
+        test_bed.run();
+        assert!(!is_failed(&test_bed));
+
+        test_bed.set_on_ground(true);
+        test_bed.run();
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn activates_randomly_with_short_mean_time_between_failures() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(
+            FAILURE_TYPE,
+            FailureTrigger::MeanTimeBetweenFailures(Duration::from_secs(1)),
+        );
+
+        for _ in 0..100 {
+            test_bed.run();
+        }
+
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn does_not_activate_with_very_long_mean_time_between_failures() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(
+            FAILURE_TYPE,
+            FailureTrigger::MeanTimeBetweenFailures(Duration::from_secs(1_000_000_000)),
+        );
+
+        for _ in 0..100 {
+            test_bed.run();
+        }
+
+        assert!(!is_failed(&test_bed));
+    }
+
+    #[test]
+    fn manually_activating_an_armed_failure_disarms_it() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(FAILURE_TYPE, FailureTrigger::Touchdown);
+        test_bed.fail(FAILURE_TYPE);
+        test_bed.unfail(FAILURE_TYPE);
+
+        test_bed.set_on_ground(false);
+        test_bed.run();
+        test_bed.set_on_ground(true);
+        test_bed.run();
+
+        assert_eq!(test_bed.failure_state(FAILURE_TYPE), FailureState::Inactive);
+        assert!(!is_failed(&test_bed));
+    }
+
+    #[test]
+    fn deactivated_failure_is_reported_as_inactive() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(
+            FAILURE_TYPE,
+            FailureTrigger::AtSimulationTime(Duration::from_secs(0)),
+        );
+        test_bed.run();
+        test_bed.unfail(FAILURE_TYPE);
+        test_bed.run();
+
+        assert_eq!(test_bed.failure_state(FAILURE_TYPE), FailureState::Inactive);
+        assert!(!is_failed(&test_bed));
+    }
+}
@@ -1,10 +1,22 @@
-use crate::simulation::{
-    InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-    VariableIdentifier,
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{ConsumePower, ElectricalBusType, ElectricalBuses},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
 };
 use nalgebra::Vector3;
 use num_traits::Zero;
-use uom::si::{f64::Mass, mass::kilogram};
+use std::time::Duration;
+use uom::si::{
+    f64::{Length, Mass, Power, Pressure, Ratio},
+    length::foot,
+    mass::kilogram,
+    power::watt,
+    pressure::psi,
+    ratio::percent,
+};
 
 pub const FUEL_GALLONS_TO_KG: f64 = 3.039075693483925;
 
@@ -19,6 +31,15 @@ pub trait FuelPayload {
 pub trait FuelCG {
     fn center_of_gravity(&self) -> Vector3<f64>;
 }
+
+/// Provides the state of the fuel feed towards the engines and APU.
+pub trait FuelFeed {
+    /// Indicates if the given engine receives fuel from the fuel system.
+    fn engine_is_fed(&self, engine_number: usize) -> bool;
+    /// Indicates if the APU receives fuel from the fuel system.
+    fn apu_is_fed(&self) -> bool;
+}
+
 #[derive(Debug)]
 pub struct FuelInfo<'a> {
     pub fuel_tank_id: &'a str,
@@ -28,6 +49,7 @@ pub struct FuelInfo<'a> {
 #[derive(Debug)]
 pub struct FuelTank {
     fuel_id: VariableIdentifier,
+    quantity_request_id: Option<VariableIdentifier>,
     location: Vector3<f64>,
     quantity: Mass,
 }
@@ -35,6 +57,24 @@ impl FuelTank {
     pub fn new(context: &mut InitContext, id: &str, location: Vector3<f64>) -> Self {
         FuelTank {
             fuel_id: context.get_identifier(id.to_owned()),
+            quantity_request_id: None,
+            location,
+            quantity: Mass::default(),
+        }
+    }
+
+    /// Creates a tank of which the quantity can be changed by the simulation.
+    /// The quantity is read from the simulator every tick, after which the quantity resulting
+    /// from fuel transfers within the simulation is written to the given request variable.
+    pub fn new_with_quantity_request(
+        context: &mut InitContext,
+        id: &str,
+        quantity_request_id: &str,
+        location: Vector3<f64>,
+    ) -> Self {
+        FuelTank {
+            fuel_id: context.get_identifier(id.to_owned()),
+            quantity_request_id: Some(context.get_identifier(quantity_request_id.to_owned())),
             location,
             quantity: Mass::default(),
         }
@@ -47,12 +87,258 @@ impl FuelTank {
     pub fn quantity(&self) -> Mass {
         self.quantity
     }
+
+    pub fn has_fuel(&self) -> bool {
+        self.quantity > Mass::default()
+    }
+
+    /// Removes up to the given mass of fuel from the tank.
+    /// Returns the mass of fuel which was actually removed.
+    pub fn remove_fuel(&mut self, mass: Mass) -> Mass {
+        let removed = mass.min(self.quantity).max(Mass::default());
+        self.quantity -= removed;
+
+        removed
+    }
+
+    pub fn add_fuel(&mut self, mass: Mass) {
+        self.quantity += mass.max(Mass::default());
+    }
 }
 impl SimulationElement for FuelTank {
     fn read(&mut self, reader: &mut SimulatorReader) {
         let volume: f64 = reader.read(&self.fuel_id);
         self.quantity = Mass::new::<kilogram>(volume * FUEL_GALLONS_TO_KG);
     }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        if let Some(quantity_request_id) = &self.quantity_request_id {
+            writer.write(
+                quantity_request_id,
+                self.quantity.get::<kilogram>() / FUEL_GALLONS_TO_KG,
+            );
+        }
+    }
+}
+
+/// An electrically driven fuel pump submerged in a tank.
+/// The pump delivers pressure to the feed line it is connected to when it runs
+/// and the tank it is located in contains fuel.
+pub struct FuelPump {
+    pressure_id: VariableIdentifier,
+    is_running_id: VariableIdentifier,
+
+    pressure: Pressure,
+    is_running: bool,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    failure: Failure,
+}
+impl FuelPump {
+    const NOMINAL_PRESSURE_PSI: f64 = 23.;
+    const LOW_PRESSURE_THRESHOLD_PSI: f64 = 6.;
+    const PRESSURE_TIME_CONSTANT: Duration = Duration::from_millis(500);
+
+    // Average of the three phase 115V AC pump current when running
+    const POWER_CONSUMPTION_WATT: f64 = 690.;
+
+    pub fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
+        Self {
+            pressure_id: context.get_identifier(format!("FUEL_PUMP_{}_PRESSURE", number)),
+            is_running_id: context.get_identifier(format!("FUEL_PUMP_{}_IS_RUNNING", number)),
+
+            pressure: Pressure::default(),
+            is_running: false,
+
+            powered_by,
+            is_powered: false,
+            failure: Failure::new(FailureType::FuelPump(number)),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, is_commanded_on: bool, tank: &FuelTank) {
+        self.is_running = is_commanded_on && self.is_powered && !self.failure.is_active();
+
+        let target_pressure = if self.is_running && tank.has_fuel() {
+            Pressure::new::<psi>(Self::NOMINAL_PRESSURE_PSI)
+        } else {
+            Pressure::default()
+        };
+
+        self.pressure += (target_pressure - self.pressure)
+            * (1.
+                - (-context.delta_as_secs_f64() / Self::PRESSURE_TIME_CONSTANT.as_secs_f64())
+                    .exp());
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        self.pressure
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.is_running
+    }
+
+    /// Indicates if the pump delivers enough pressure for its low pressure switch to open.
+    pub fn is_pressurised(&self) -> bool {
+        self.pressure > Pressure::new::<psi>(Self::LOW_PRESSURE_THRESHOLD_PSI)
+    }
+
+    pub fn has_failed(&self) -> bool {
+        self.failure.is_active()
+    }
+}
+impl SimulationElement for FuelPump {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.pressure_id, self.pressure);
+        writer.write(&self.is_running_id, self.is_running);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        if self.is_running {
+            consumption.consume_from_bus(
+                self.powered_by,
+                Power::new::<watt>(Self::POWER_CONSUMPTION_WATT),
+            );
+        }
+    }
+}
+
+/// A motor operated fuel valve, such as a crossfeed, transfer or low pressure valve.
+/// The valve keeps its last position when its motor is unpowered.
+pub struct FuelValve {
+    open_amount_id: VariableIdentifier,
+
+    open_amount: Ratio,
+    travel_time: Duration,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+}
+impl FuelValve {
+    pub fn new(
+        context: &mut InitContext,
+        name: &str,
+        travel_time: Duration,
+        powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            open_amount_id: context.get_identifier(format!("FUEL_{}_VALVE_OPEN_AMOUNT", name)),
+
+            open_amount: Ratio::default(),
+            travel_time,
+
+            powered_by,
+            is_powered: false,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, should_open: bool) {
+        if !self.is_powered {
+            return;
+        }
+
+        let change = Ratio::new::<percent>(
+            100. * context.delta_as_secs_f64() / self.travel_time.as_secs_f64(),
+        );
+        self.open_amount = if should_open {
+            (self.open_amount + change).min(Ratio::new::<percent>(100.))
+        } else {
+            (self.open_amount - change).max(Ratio::default())
+        };
+    }
+
+    pub fn open_amount(&self) -> Ratio {
+        self.open_amount
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open_amount > Ratio::default()
+    }
+
+    pub fn is_fully_open(&self) -> bool {
+        self.open_amount >= Ratio::new::<percent>(100.)
+    }
+
+    pub fn is_closed(&self) -> bool {
+        !self.is_open()
+    }
+}
+impl SimulationElement for FuelValve {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.open_amount_id, self.open_amount);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+/// Determines if a consumer connected to a feed line receives fuel.
+/// Without pump pressure, engines can still suction feed from their
+/// own tank below a certain altitude.
+pub struct FuelFeedLine {
+    low_pressure_id: VariableIdentifier,
+
+    pressure: Pressure,
+    can_suction_feed: bool,
+}
+impl FuelFeedLine {
+    const LOW_PRESSURE_THRESHOLD_PSI: f64 = 6.;
+    const SUCTION_FEED_CEILING_FOOT: f64 = 15000.;
+
+    pub fn new(context: &mut InitContext, name: &str) -> Self {
+        Self {
+            low_pressure_id: context.get_identifier(format!("FUEL_{}_FEED_LO_PR", name)),
+
+            pressure: Pressure::default(),
+            can_suction_feed: false,
+        }
+    }
+
+    pub fn update<'a>(
+        &mut self,
+        context: &UpdateContext,
+        pumps: impl IntoIterator<Item = &'a FuelPump>,
+        suction_tank: &FuelTank,
+    ) {
+        self.pressure = pumps
+            .into_iter()
+            .map(|pump| pump.pressure())
+            .fold(Pressure::default(), |acc, pressure| acc.max(pressure));
+
+        self.can_suction_feed = suction_tank.has_fuel()
+            && context.indicated_altitude() < Length::new::<foot>(Self::SUCTION_FEED_CEILING_FOOT);
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        self.pressure
+    }
+
+    pub fn is_low_pressure(&self) -> bool {
+        self.pressure <= Pressure::new::<psi>(Self::LOW_PRESSURE_THRESHOLD_PSI)
+    }
+
+    /// Indicates if fuel is delivered to the consumers of the feed line.
+    pub fn delivers_fuel(&self) -> bool {
+        !self.is_low_pressure() || self.can_suction_feed
+    }
+}
+impl SimulationElement for FuelFeedLine {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.low_pressure_id, self.is_low_pressure());
+    }
 }
 
 pub struct FuelSystem<const N: usize> {
@@ -103,6 +389,19 @@ impl<const N: usize> FuelSystem<N> {
     pub fn tank_mass(&self, t: usize) -> Mass {
         self.fuel_tanks[t].quantity()
     }
+
+    pub fn tank(&self, t: usize) -> &FuelTank {
+        &self.fuel_tanks[t]
+    }
+
+    /// Moves up to the given mass of fuel from one tank to another.
+    /// Returns the mass of fuel which was actually transferred.
+    pub fn transfer_fuel(&mut self, from: usize, to: usize, mass: Mass) -> Mass {
+        let transferred = self.fuel_tanks[from].remove_fuel(mass);
+        self.fuel_tanks[to].add_fuel(transferred);
+
+        transferred
+    }
 }
 impl<const N: usize> SimulationElement for FuelSystem<N> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -115,3 +414,207 @@ impl<const N: usize> SimulationElement for FuelSystem<N> {
         self.fuel_total_weight = reader.read(&self.fuel_total_weight_id);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::{test::TestElectricitySource, ElectricalBus, Electricity};
+    use crate::shared::{PotentialOrigin, PowerConsumptionReport};
+    use crate::simulation::test::{SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::Aircraft;
+    use ntest::assert_about_eq;
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        ac_1_bus: ElectricalBus,
+        tank: FuelTank,
+        pump: FuelPump,
+        valve: FuelValve,
+        pump_commanded_on: bool,
+        valve_commanded_open: bool,
+        power_consumption: Power,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                ac_1_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                tank: FuelTank::new_with_quantity_request(
+                    context,
+                    "FUEL TANK TEST QUANTITY",
+                    "FUEL_TANK_TEST_QUANTITY_REQ",
+                    Vector3::zeros(),
+                ),
+                pump: FuelPump::new(context, 1, ElectricalBusType::AlternatingCurrent(1)),
+                valve: FuelValve::new(
+                    context,
+                    "TEST",
+                    Duration::from_secs(2),
+                    ElectricalBusType::AlternatingCurrent(1),
+                ),
+                pump_commanded_on: false,
+                valve_commanded_open: false,
+                power_consumption: Power::default(),
+            }
+        }
+
+        fn unpower(&mut self) {
+            self.electricity_source.unpower();
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.electricity_source);
+            electricity.flow(&self.electricity_source, &self.ac_1_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.pump
+                .update(context, self.pump_commanded_on, &self.tank);
+            self.valve.update(context, self.valve_commanded_open);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.tank.accept(visitor);
+            self.pump.accept(visitor);
+            self.valve.accept(visitor);
+
+            visitor.visit(self);
+        }
+
+        fn process_power_consumption_report<T: PowerConsumptionReport>(
+            &mut self,
+            _: &UpdateContext,
+            report: &T,
+        ) {
+            self.power_consumption =
+                report.total_consumption_of(PotentialOrigin::EngineGenerator(1));
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.write_by_name("FUEL TANK TEST QUANTITY", 1000.);
+
+        test_bed
+    }
+
+    #[test]
+    fn pump_pressurises_when_commanded_on_and_powered() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.pump_commanded_on = true);
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.pump.is_running()));
+        assert!(test_bed.query(|a| a.pump.is_pressurised()));
+    }
+
+    #[test]
+    fn pump_does_not_pressurise_when_commanded_off() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.pump.is_pressurised()));
+    }
+
+    #[test]
+    fn pump_does_not_pressurise_when_unpowered() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.pump_commanded_on = true);
+        test_bed.command(|a| a.unpower());
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.pump.is_running()));
+        assert!(!test_bed.query(|a| a.pump.is_pressurised()));
+    }
+
+    #[test]
+    fn pump_does_not_pressurise_when_tank_is_empty() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("FUEL TANK TEST QUANTITY", 0.);
+        test_bed.command(|a| a.pump_commanded_on = true);
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.pump.is_pressurised()));
+    }
+
+    #[test]
+    fn failed_pump_does_not_pressurise() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.pump_commanded_on = true);
+        test_bed.fail(FailureType::FuelPump(1));
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.pump.is_pressurised()));
+    }
+
+    #[test]
+    fn running_pump_consumes_power() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.pump_commanded_on = true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_about_eq!(
+            test_bed.query(|a| a.power_consumption.get::<watt>()),
+            FuelPump::POWER_CONSUMPTION_WATT
+        );
+    }
+
+    #[test]
+    fn valve_opens_over_its_travel_time() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.valve_commanded_open = true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_about_eq!(
+            test_bed.query(|a| a.valve.open_amount().get::<percent>()),
+            50.
+        );
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.valve.is_fully_open()));
+    }
+
+    #[test]
+    fn unpowered_valve_keeps_its_position() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.valve_commanded_open = true);
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        test_bed.command(|a| a.valve_commanded_open = false);
+        test_bed.command(|a| a.unpower());
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        assert!(test_bed.query(|a| a.valve.is_fully_open()));
+    }
+
+    #[test]
+    fn tank_writes_quantity_request() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        assert!(test_bed.contains_variable_with_name("FUEL_TANK_TEST_QUANTITY_REQ"));
+    }
+
+    #[test]
+    fn tank_cannot_give_more_fuel_than_it_contains() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("FUEL TANK TEST QUANTITY", 10.);
+        test_bed.run();
+
+        test_bed.command(|a| {
+            a.tank.remove_fuel(Mass::new::<kilogram>(1000.));
+        });
+
+        assert_about_eq!(test_bed.query(|a| a.tank.quantity().get::<kilogram>()), 0.);
+    }
+}
@@ -14,7 +14,8 @@ use std::fmt::Debug;
 use std::time::Duration;
 
 use uom::si::{
-    acceleration::meter_per_second_squared, f64::*, pressure::psi, ratio::ratio, volume::gallon,
+    acceleration::meter_per_second_squared, f64::*, pressure::psi, ratio::ratio, velocity::knot,
+    volume::gallon,
 };
 
 use super::linear_actuator::Actuator;
@@ -586,6 +587,76 @@ impl Default for AutobrakeDecelerationGovernor {
     }
 }
 
+/// Anti-skid channel of one main gear.
+///
+/// Releases brake pressure as soon as the wheels slip more than the target slip ratio, so
+/// the tires keep working near the peak of their friction curve instead of locking. The release
+/// follows the slip in excess right away, while the part holding the brakes at the slip limit
+/// builds up slowly so the brakes aren't fully released at each skid.
+pub struct AntiSkidController {
+    sustained_release: f64,
+    release: f64,
+    is_active: bool,
+}
+impl AntiSkidController {
+    const TARGET_SLIP: f64 = 0.1;
+    const RELEASE_PER_SLIP: f64 = 3.;
+    const SUSTAINED_RELEASE_RATE_PER_SLIP_PER_SECOND: f64 = 5.;
+
+    // Below that reference speed the anti-skid is inhibited so the aircraft can stop
+    const MIN_REFERENCE_SPEED_KNOT: f64 = 20.;
+
+    pub fn new() -> Self {
+        Self {
+            sustained_release: 0.,
+            release: 0.,
+            is_active: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        is_available: bool,
+        reference_speed: Option<Velocity>,
+        slip: Ratio,
+    ) {
+        self.is_active = is_available
+            && reference_speed.map_or(false, |speed| {
+                speed > Velocity::new::<knot>(Self::MIN_REFERENCE_SPEED_KNOT)
+            });
+
+        if self.is_active {
+            let slip_error = slip.get::<ratio>() - Self::TARGET_SLIP;
+
+            self.sustained_release = (self.sustained_release
+                + Self::SUSTAINED_RELEASE_RATE_PER_SLIP_PER_SECOND
+                    * slip_error
+                    * context.delta_as_secs_f64())
+            .clamp(0., 1.);
+            self.release =
+                (self.sustained_release + Self::RELEASE_PER_SLIP * slip_error).clamp(0., 1.);
+        } else {
+            self.sustained_release = 0.;
+            self.release = 0.;
+        }
+    }
+
+    /// Brake demand once the pressure released by the anti-skid is taken out.
+    pub fn modulated_demand(&self, demand: Ratio) -> Ratio {
+        demand * (1. - self.release)
+    }
+
+    pub fn is_releasing(&self) -> bool {
+        self.is_active && self.release > 0.
+    }
+}
+impl Default for AntiSkidController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 pub struct BrakeAccumulatorCharacteristics {
     total_volume: Volume,
     gas_precharge: Pressure,
@@ -741,6 +812,8 @@ mod tests {
 
     impl SimulationElement for BrakeActuator {}
 
+    impl SimulationElement for AntiSkidController {}
+
     struct TestAircraft {
         brake_circuit: BrakeCircuit,
 
@@ -1041,6 +1114,65 @@ mod tests {
         assert!(test_bed.query(|a| a.right_brake_pressure()) <= pressure_limit);
     }
 
+    #[test]
+    fn anti_skid_releases_pressure_when_wheels_slip() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|_| AntiSkidController::new()));
+
+        test_bed.set_update_after_power_distribution(|e, context| {
+            e.update(
+                context,
+                true,
+                Some(Velocity::new::<knot>(100.)),
+                Ratio::new::<ratio>(0.5),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert!(test_bed.query_element(|e| e.is_releasing()));
+        assert!(
+            test_bed.query_element(|e| e.modulated_demand(Ratio::new::<ratio>(1.)))
+                < Ratio::new::<ratio>(0.5)
+        );
+    }
+
+    #[test]
+    fn anti_skid_does_not_release_pressure_below_target_slip() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|_| AntiSkidController::new()));
+
+        test_bed.set_update_after_power_distribution(|e, context| {
+            e.update(
+                context,
+                true,
+                Some(Velocity::new::<knot>(100.)),
+                Ratio::new::<ratio>(0.05),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert!(!test_bed.query_element(|e| e.is_releasing()));
+        assert!(
+            test_bed.query_element(|e| e.modulated_demand(Ratio::new::<ratio>(1.)))
+                == Ratio::new::<ratio>(1.)
+        );
+    }
+
+    #[test]
+    fn anti_skid_inhibited_at_low_speed() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|_| AntiSkidController::new()));
+
+        test_bed.set_update_after_power_distribution(|e, context| {
+            e.update(
+                context,
+                true,
+                Some(Velocity::new::<knot>(10.)),
+                Ratio::new::<ratio>(1.),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert!(!test_bed.query_element(|e| e.is_releasing()));
+    }
+
     fn brake_circuit(context: &mut InitContext, init_max_vol: Volume) -> BrakeCircuit {
         BrakeCircuit::new(
             context,
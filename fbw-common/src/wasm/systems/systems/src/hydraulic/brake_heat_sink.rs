@@ -0,0 +1,729 @@
+use crate::{
+    shared::{BrakeTemperatures, ConsumePower, ElectricalBusType, ElectricalBuses, GearWheel},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+use std::time::Duration;
+
+use uom::si::{
+    acceleration::meter_per_second_squared, angular_velocity::radian_per_second, f64::*,
+    length::meter, mass::kilogram, power::watt, pressure::psi,
+    thermodynamic_temperature::degree_celsius, velocity::meter_per_second,
+};
+
+/// Electrically driven fans blowing air through the brake heat sinks of the main landing gear.
+pub struct BrakeFans {
+    brake_fan_id: VariableIdentifier,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    is_running: bool,
+}
+impl BrakeFans {
+    const POWER_CONSUMPTION_WATT: f64 = 400.;
+
+    pub fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
+        Self {
+            brake_fan_id: context.get_identifier("BRAKE_FAN".to_owned()),
+
+            powered_by,
+            is_powered: false,
+            is_running: false,
+        }
+    }
+
+    fn update(&mut self, is_commanded_on: bool) {
+        self.is_running = is_commanded_on && self.is_powered;
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.is_running
+    }
+}
+impl SimulationElement for BrakeFans {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.brake_fan_id, self.is_running);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        if self.is_running {
+            consumption.consume_from_bus(
+                self.powered_by,
+                Power::new::<watt>(Self::POWER_CONSUMPTION_WATT),
+            );
+        }
+    }
+}
+
+/// Carbon heat sink of a single wheel brake.
+///
+/// The heat sink absorbs the kinetic energy dissipated by its brake and loses it to the
+/// surrounding air. The temperature probe of the brake lags behind the heat sink temperature.
+struct BrakeHeatSink {
+    temperature_id: VariableIdentifier,
+    reported_temperature_id: VariableIdentifier,
+    wheel_rpm_id: VariableIdentifier,
+
+    gear: GearWheel,
+    wheel_rpm: AngularVelocity,
+
+    temperature: ThermodynamicTemperature,
+    reported_temperature: ThermodynamicTemperature,
+}
+impl BrakeHeatSink {
+    // Specific heat capacity of carbon at typical brake operating temperatures
+    const SPECIFIC_HEAT_CAPACITY_J_PER_KG_K: f64 = 1420.;
+
+    // Time constant for the heat sink cooling down in still air
+    const STILL_AIR_COOLING_TIME_CONSTANT: Duration = Duration::from_secs(2700);
+
+    // Cooling gain per m/s of airflow through an extended landing gear
+    const AIRFLOW_COOLING_GAIN_PER_M_S: f64 = 0.02;
+
+    const FANS_COOLING_GAIN: f64 = 3.5;
+
+    const PROBE_TIME_CONSTANT: Duration = Duration::from_secs(60);
+
+    fn new(context: &mut InitContext, number: usize, gear: GearWheel, sim_wheel_id: usize) -> Self {
+        Self {
+            temperature_id: context.get_identifier(format!("BRAKE_TEMPERATURE_{}", number)),
+            reported_temperature_id: context
+                .get_identifier(format!("REPORTED_BRAKE_TEMPERATURE_{}", number)),
+            wheel_rpm_id: context.get_identifier(format!("WHEEL RPM:{}", sim_wheel_id)),
+
+            gear,
+            wheel_rpm: AngularVelocity::default(),
+
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            reported_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+        }
+    }
+
+    fn initialise(&mut self, temperature: ThermodynamicTemperature) {
+        self.temperature = temperature;
+        self.reported_temperature = temperature;
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        absorbed_power: Power,
+        heat_sink_mass: Mass,
+        airflow_speed: Velocity,
+        fans_running: bool,
+    ) {
+        let ambient = context.ambient_temperature().get::<degree_celsius>();
+        let mut temperature = self.temperature.get::<degree_celsius>();
+
+        temperature += absorbed_power.get::<watt>() * context.delta_as_secs_f64()
+            / (heat_sink_mass.get::<kilogram>() * Self::SPECIFIC_HEAT_CAPACITY_J_PER_KG_K);
+
+        let mut cooling_gain =
+            1. + Self::AIRFLOW_COOLING_GAIN_PER_M_S * airflow_speed.get::<meter_per_second>();
+        if fans_running {
+            cooling_gain *= Self::FANS_COOLING_GAIN;
+        }
+
+        let cooling_time_constant =
+            Self::STILL_AIR_COOLING_TIME_CONSTANT.as_secs_f64() / cooling_gain;
+        temperature = ambient
+            + (temperature - ambient)
+                * (-context.delta_as_secs_f64() / cooling_time_constant).exp();
+
+        // Fans cool the temperature probe faster than the heat sink itself
+        let probe_target = if fans_running {
+            ambient + (temperature - ambient) / 2.
+        } else {
+            temperature
+        };
+        let reported_temperature = self.reported_temperature.get::<degree_celsius>();
+        let probe_filter_gain =
+            1. - (-context.delta_as_secs_f64() / Self::PROBE_TIME_CONSTANT.as_secs_f64()).exp();
+
+        self.temperature = ThermodynamicTemperature::new::<degree_celsius>(temperature);
+        self.reported_temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            reported_temperature + (probe_target - reported_temperature) * probe_filter_gain,
+        );
+    }
+
+    fn wheel_speed(&self, tire_radius: Length) -> Velocity {
+        Velocity::new::<meter_per_second>(
+            self.wheel_rpm.get::<radian_per_second>().abs() * tire_radius.get::<meter>(),
+        )
+    }
+}
+impl SimulationElement for BrakeHeatSink {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.wheel_rpm = reader.read(&self.wheel_rpm_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.temperature_id, self.temperature);
+        writer.write(&self.reported_temperature_id, self.reported_temperature);
+    }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64("temperature", self.temperature.get::<degree_celsius>());
+        writer.write_f64(
+            "reported_temperature",
+            self.reported_temperature.get::<degree_celsius>(),
+        );
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(temperature) = reader.read_f64("temperature") {
+            self.temperature = ThermodynamicTemperature::new::<degree_celsius>(temperature);
+        }
+        if let Some(temperature) = reader.read_f64("reported_temperature") {
+            self.reported_temperature =
+                ThermodynamicTemperature::new::<degree_celsius>(temperature);
+        }
+    }
+}
+
+/// Thermal model of the wheel brakes of an aircraft.
+///
+/// Each brake heats up with the power it dissipates. This power is derived from the
+/// braking force its pressure produces on the aircraft mass and from the speed of its wheel.
+/// Brakes are numbered from 1 in the order given at construction, from left to right.
+pub struct BrakeHeatSinks {
+    brakes_hot_id: VariableIdentifier,
+
+    heat_sinks: Vec<BrakeHeatSink>,
+    heat_sink_mass: Mass,
+    tire_radius: Length,
+
+    fans: Option<BrakeFans>,
+
+    is_initialised: bool,
+}
+impl BrakeHeatSinks {
+    // Aircraft deceleration produced by all brakes at reference pressure
+    const MAX_BRAKING_DECELERATION_M_S2: f64 = 4.;
+    const REFERENCE_PRESSURE_FOR_MAX_DECELERATION_PSI: f64 = 3000.;
+
+    const BRAKES_HOT_THRESHOLD_DEGREE_CELSIUS: f64 = 300.;
+
+    /// Creates the heat sinks for the given brakes. Each brake is described by the gear it
+    /// belongs to and the simulator wheel from which it reads the wheel speed.
+    pub fn new(
+        context: &mut InitContext,
+        brakes: &[(GearWheel, usize)],
+        heat_sink_mass: Mass,
+        tire_radius: Length,
+        fans: Option<BrakeFans>,
+    ) -> Self {
+        Self {
+            brakes_hot_id: context.get_identifier("BRAKES_HOT".to_owned()),
+
+            heat_sinks: brakes
+                .iter()
+                .enumerate()
+                .map(|(index, &(gear, sim_wheel_id))| {
+                    BrakeHeatSink::new(context, index + 1, gear, sim_wheel_id)
+                })
+                .collect(),
+            heat_sink_mass,
+            tire_radius,
+
+            fans,
+
+            is_initialised: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        left_brake_pressure: Pressure,
+        right_brake_pressure: Pressure,
+        main_gear_extended: bool,
+        fans_commanded_on: bool,
+    ) {
+        if !self.is_initialised {
+            self.heat_sinks
+                .iter_mut()
+                .for_each(|heat_sink| heat_sink.initialise(context.ambient_temperature()));
+            self.is_initialised = true;
+        }
+
+        if let Some(fans) = &mut self.fans {
+            fans.update(fans_commanded_on && main_gear_extended);
+        }
+        let fans_running = self.fans_running();
+
+        let airflow_speed = if main_gear_extended {
+            context.true_airspeed().abs()
+        } else {
+            Velocity::default()
+        };
+
+        let braking_force_per_brake = context.total_weight()
+            * Acceleration::new::<meter_per_second_squared>(Self::MAX_BRAKING_DECELERATION_M_S2)
+            / self.heat_sinks.len().max(1) as f64;
+
+        for heat_sink in &mut self.heat_sinks {
+            let brake_pressure = match heat_sink.gear {
+                GearWheel::LEFT => left_brake_pressure,
+                GearWheel::RIGHT => right_brake_pressure,
+                GearWheel::NOSE => Pressure::default(),
+            };
+            let pressure_ratio = (brake_pressure.get::<psi>()
+                / Self::REFERENCE_PRESSURE_FOR_MAX_DECELERATION_PSI)
+                .max(0.)
+                .min(1.);
+
+            let absorbed_power: Power =
+                braking_force_per_brake * pressure_ratio * heat_sink.wheel_speed(self.tire_radius);
+
+            heat_sink.update(
+                context,
+                absorbed_power,
+                self.heat_sink_mass,
+                airflow_speed,
+                fans_running,
+            );
+        }
+    }
+
+    /// Temperature of the heat sink of the given brake, numbered from 1.
+    pub fn temperature(&self, brake_number: usize) -> ThermodynamicTemperature {
+        self.heat_sinks[brake_number - 1].temperature
+    }
+
+    /// Temperature measured by the probe of the given brake, numbered from 1.
+    pub fn reported_temperature(&self, brake_number: usize) -> ThermodynamicTemperature {
+        self.heat_sinks[brake_number - 1].reported_temperature
+    }
+
+    /// Hottest heat sink temperature of the brakes on the given gear.
+    pub fn max_temperature(&self, gear: GearWheel) -> Option<ThermodynamicTemperature> {
+        self.heat_sinks
+            .iter()
+            .filter(|heat_sink| heat_sink.gear == gear)
+            .map(|heat_sink| heat_sink.temperature)
+            .reduce(|a, b| a.max(b))
+    }
+
+    /// Hottest probe temperature of the brakes on the given gear.
+    pub fn max_reported_temperature(&self, gear: GearWheel) -> Option<ThermodynamicTemperature> {
+        self.heat_sinks
+            .iter()
+            .filter(|heat_sink| heat_sink.gear == gear)
+            .map(|heat_sink| heat_sink.reported_temperature)
+            .reduce(|a, b| a.max(b))
+    }
+
+    /// Brakes are hot when any probe reports a temperature above 300°C.
+    pub fn brakes_hot(&self) -> bool {
+        self.heat_sinks.iter().any(|heat_sink| {
+            heat_sink.reported_temperature.get::<degree_celsius>()
+                > Self::BRAKES_HOT_THRESHOLD_DEGREE_CELSIUS
+        })
+    }
+
+    pub fn fans_running(&self) -> bool {
+        self.fans.as_ref().map_or(false, |fans| fans.is_running())
+    }
+}
+impl BrakeTemperatures for BrakeHeatSinks {
+    fn brake_temperature(&self, brake_number: usize) -> ThermodynamicTemperature {
+        self.temperature(brake_number)
+    }
+}
+impl SimulationElement for BrakeHeatSinks {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.heat_sinks
+            .iter_mut()
+            .for_each(|heat_sink| heat_sink.accept(visitor));
+
+        if let Some(fans) = &mut self.fans {
+            fans.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.brakes_hot_id, self.brakes_hot());
+    }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_bool("initialised", self.is_initialised);
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(is_initialised) = reader.read_bool("initialised") {
+            self.is_initialised = is_initialised;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::electrical::test::TestElectricitySource;
+    use crate::electrical::{ElectricalBus, Electricity};
+    use crate::shared::PotentialOrigin;
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::Aircraft;
+    use ntest::assert_about_eq;
+    use uom::si::{electric_potential::volt, velocity::knot};
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        ac_1_bus: ElectricalBus,
+
+        heat_sinks: BrakeHeatSinks,
+
+        left_brake_pressure: Pressure,
+        right_brake_pressure: Pressure,
+        main_gear_extended: bool,
+        fans_commanded_on: bool,
+        is_ac_1_powered: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let fans = BrakeFans::new(context, ElectricalBusType::AlternatingCurrent(1));
+
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                ac_1_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+
+                heat_sinks: BrakeHeatSinks::new(
+                    context,
+                    &[
+                        (GearWheel::LEFT, 1),
+                        (GearWheel::LEFT, 1),
+                        (GearWheel::RIGHT, 2),
+                        (GearWheel::RIGHT, 2),
+                    ],
+                    Mass::new::<kilogram>(60.),
+                    Length::new::<meter>(0.58),
+                    Some(fans),
+                ),
+
+                left_brake_pressure: Pressure::default(),
+                right_brake_pressure: Pressure::default(),
+                main_gear_extended: true,
+                fans_commanded_on: false,
+                is_ac_1_powered: true,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(115.));
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_ac_1_powered {
+                electricity.flow(&self.electricity_source, &self.ac_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.heat_sinks.update(
+                context,
+                self.left_brake_pressure,
+                self.right_brake_pressure,
+                self.main_gear_extended,
+                self.fans_commanded_on,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.heat_sinks.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    struct BrakeHeatSinksTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl BrakeHeatSinksTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            test_bed.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(64000.));
+
+            test_bed
+        }
+
+        fn and(self) -> Self {
+            self
+        }
+
+        fn rolling_at(mut self, speed: Velocity) -> Self {
+            let wheel_rpm =
+                AngularVelocity::new::<radian_per_second>(speed.get::<meter_per_second>() / 0.58);
+            self.write_by_name("WHEEL RPM:1", wheel_rpm);
+            self.write_by_name("WHEEL RPM:2", wheel_rpm);
+
+            self
+        }
+
+        fn left_brake_pressure(mut self, pressure: Pressure) -> Self {
+            self.command(|a| a.left_brake_pressure = pressure);
+            self
+        }
+
+        fn brake_pressure(mut self, pressure: Pressure) -> Self {
+            self.command(|a| {
+                a.left_brake_pressure = pressure;
+                a.right_brake_pressure = pressure;
+            });
+            self
+        }
+
+        fn fans_on(mut self) -> Self {
+            self.command(|a| a.fans_commanded_on = true);
+            self
+        }
+
+        fn gear_retracted(mut self) -> Self {
+            self.command(|a| a.main_gear_extended = false);
+            self
+        }
+
+        fn ac_1_unpowered(mut self) -> Self {
+            self.command(|a| a.is_ac_1_powered = false);
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.test_bed.run_multiple_frames(duration);
+            self
+        }
+
+        fn temperature(&self, brake_number: usize) -> ThermodynamicTemperature {
+            self.query(|a| a.heat_sinks.temperature(brake_number))
+        }
+
+        fn reported_temperature(&self, brake_number: usize) -> ThermodynamicTemperature {
+            self.query(|a| a.heat_sinks.reported_temperature(brake_number))
+        }
+
+        fn max_temperature(&self, gear: GearWheel) -> Option<ThermodynamicTemperature> {
+            self.query(|a| a.heat_sinks.max_temperature(gear))
+        }
+
+        fn fans_running(&mut self) -> bool {
+            self.read_by_name("BRAKE_FAN")
+        }
+
+        fn brakes_hot(&mut self) -> bool {
+            self.read_by_name("BRAKES_HOT")
+        }
+
+        fn heat_up_brakes(self) -> Self {
+            self.rolling_at(Velocity::new::<knot>(100.))
+                .brake_pressure(Pressure::new::<psi>(1500.))
+                .run_for(Duration::from_secs(10))
+                .rolling_at(Velocity::default())
+                .brake_pressure(Pressure::default())
+        }
+    }
+    impl TestBed for BrakeHeatSinksTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> BrakeHeatSinksTestBed {
+        BrakeHeatSinksTestBed::new()
+    }
+
+    fn celsius(temperature: ThermodynamicTemperature) -> f64 {
+        temperature.get::<degree_celsius>()
+    }
+
+    #[test]
+    fn brakes_start_at_ambient_temperature() {
+        let test_bed = test_bed().run_for(Duration::from_secs(1));
+
+        for brake in 1..=4 {
+            assert_about_eq!(celsius(test_bed.temperature(brake)), 15., 0.1);
+            assert_about_eq!(celsius(test_bed.reported_temperature(brake)), 15., 0.1);
+        }
+    }
+
+    #[test]
+    fn applied_brakes_do_not_heat_up_when_wheels_are_stopped() {
+        let test_bed = test_bed()
+            .brake_pressure(Pressure::new::<psi>(3000.))
+            .run_for(Duration::from_secs(30));
+
+        assert_about_eq!(celsius(test_bed.temperature(1)), 15., 0.1);
+    }
+
+    #[test]
+    fn rolling_wheels_do_not_heat_up_released_brakes() {
+        let test_bed = test_bed()
+            .rolling_at(Velocity::new::<knot>(100.))
+            .run_for(Duration::from_secs(30));
+
+        assert_about_eq!(celsius(test_bed.temperature(1)), 15., 0.1);
+    }
+
+    #[test]
+    fn braking_from_high_speed_heats_up_the_brakes() {
+        let test_bed = test_bed().heat_up_brakes();
+
+        assert!(celsius(test_bed.temperature(1)) > 100.);
+        assert!(celsius(test_bed.temperature(4)) > 100.);
+    }
+
+    #[test]
+    fn only_brakes_of_the_braked_gear_heat_up() {
+        let test_bed = test_bed()
+            .rolling_at(Velocity::new::<knot>(100.))
+            .and()
+            .left_brake_pressure(Pressure::new::<psi>(3000.))
+            .run_for(Duration::from_secs(15));
+
+        assert!(celsius(test_bed.max_temperature(GearWheel::LEFT).unwrap()) > 150.);
+        assert_about_eq!(
+            celsius(test_bed.max_temperature(GearWheel::RIGHT).unwrap()),
+            15.,
+            0.1
+        );
+        assert!(test_bed.max_temperature(GearWheel::NOSE).is_none());
+    }
+
+    #[test]
+    fn reported_temperature_lags_behind_heat_sink_temperature() {
+        let test_bed = test_bed().heat_up_brakes();
+
+        assert!(test_bed.reported_temperature(1) < test_bed.temperature(1));
+
+        let test_bed = test_bed.run_for(Duration::from_secs(300));
+
+        assert_about_eq!(
+            celsius(test_bed.reported_temperature(1)),
+            celsius(test_bed.temperature(1)),
+            10.
+        );
+    }
+
+    #[test]
+    fn hot_brakes_cool_down_towards_ambient_temperature() {
+        let test_bed = test_bed().heat_up_brakes();
+        let hot_temperature = celsius(test_bed.temperature(1));
+
+        let test_bed = test_bed.run_for(Duration::from_secs(1800));
+
+        assert!(celsius(test_bed.temperature(1)) < hot_temperature);
+        assert!(celsius(test_bed.temperature(1)) > 15.);
+    }
+
+    #[test]
+    fn fans_cool_brakes_faster() {
+        let without_fans = test_bed()
+            .heat_up_brakes()
+            .run_for(Duration::from_secs(600));
+        let mut with_fans = test_bed()
+            .heat_up_brakes()
+            .fans_on()
+            .run_for(Duration::from_secs(600));
+
+        assert!(with_fans.fans_running());
+        assert!(with_fans.temperature(1) < without_fans.temperature(1));
+    }
+
+    #[test]
+    fn fans_cool_the_probe_below_the_heat_sink_temperature() {
+        let test_bed = test_bed()
+            .heat_up_brakes()
+            .fans_on()
+            .run_for(Duration::from_secs(300));
+
+        assert!(test_bed.reported_temperature(1) < test_bed.temperature(1));
+    }
+
+    #[test]
+    fn fans_do_not_run_without_power() {
+        let mut test_bed = test_bed()
+            .ac_1_unpowered()
+            .fans_on()
+            .run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.fans_running());
+    }
+
+    #[test]
+    fn fans_do_not_run_with_gear_retracted() {
+        let mut test_bed = test_bed()
+            .gear_retracted()
+            .fans_on()
+            .run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.fans_running());
+    }
+
+    #[test]
+    fn brakes_hot_when_a_probe_exceeds_300_degrees() {
+        let mut test_bed = test_bed();
+        assert!(!test_bed.brakes_hot());
+
+        let mut test_bed = test_bed
+            .rolling_at(Velocity::new::<knot>(130.))
+            .brake_pressure(Pressure::new::<psi>(3000.))
+            .run_for(Duration::from_secs(10))
+            .rolling_at(Velocity::default())
+            .brake_pressure(Pressure::default())
+            .run_for(Duration::from_secs(300));
+
+        assert!(test_bed.brakes_hot());
+    }
+
+    #[test]
+    fn restoring_a_snapshot_restores_brake_temperatures() {
+        let mut test_bed = test_bed().heat_up_brakes();
+        let snapshot = test_bed.snapshot();
+        let hot_temperature = celsius(test_bed.temperature(1));
+
+        let mut test_bed = test_bed.run_for(Duration::from_secs(1800));
+        test_bed.restore(&snapshot).unwrap();
+
+        assert_about_eq!(celsius(test_bed.temperature(1)), hot_temperature, 0.01);
+    }
+
+    #[test]
+    fn brakes_restored_into_a_new_simulation_keep_their_temperature() {
+        let mut hot = test_bed().heat_up_brakes();
+        let snapshot = hot.snapshot();
+        let hot_temperature = celsius(hot.temperature(1));
+
+        let mut restored = test_bed();
+        restored.restore(&snapshot).unwrap();
+        let restored = restored.run_for(Duration::from_secs(1));
+
+        assert!(celsius(restored.temperature(1)) > 100.);
+        assert!(celsius(restored.temperature(1)) <= hot_temperature);
+    }
+}
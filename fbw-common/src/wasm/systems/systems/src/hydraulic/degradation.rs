@@ -0,0 +1,456 @@
+use crate::simulation::{SnapshotReader, SnapshotWriter, UpdateContext};
+
+use uom::si::{
+    angular_velocity::revolution_per_minute, f64::*, pressure::psi, ratio::ratio, time::hour,
+    volume_rate::gallon_per_minute,
+};
+
+/// Long term wear of a hydraulic pump.
+///
+/// Wear builds up with the time the pump is turning and faster when the fluid it pumps is
+/// contaminated. A worn pump loses volumetric efficiency, leaks more of its flow through its
+/// case drain and sheds debris into the fluid.
+pub struct PumpWear {
+    operating_time: Time,
+    wear: Ratio,
+    debris: Ratio,
+}
+impl PumpWear {
+    const RATED_LIFE_HOURS: f64 = 20000.;
+
+    // Wear rate multiplier at full fluid contamination
+    const CONTAMINATION_WEAR_GAIN: f64 = 4.;
+
+    const MIN_SPEED_FOR_WEAR_RPM: f64 = 100.;
+
+    const MAX_VOLUMETRIC_EFFICIENCY_LOSS: f64 = 0.15;
+
+    // Part of the pump flow leaking through the case drain at reference pressure when fully worn
+    const MAX_CASE_DRAIN_LEAKAGE: f64 = 0.1;
+    const CASE_DRAIN_REFERENCE_PRESSURE_PSI: f64 = 3000.;
+
+    // Fluid contamination produced per unit of wear
+    const DEBRIS_PER_WEAR: f64 = 0.5;
+
+    pub fn new() -> Self {
+        Self {
+            operating_time: Time::default(),
+            wear: Ratio::default(),
+            debris: Ratio::default(),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        speed: AngularVelocity,
+        contamination: Ratio,
+    ) {
+        self.debris = Ratio::default();
+
+        if speed.get::<revolution_per_minute>() <= Self::MIN_SPEED_FOR_WEAR_RPM {
+            return;
+        }
+
+        self.operating_time += context.delta_as_time();
+
+        let wear_increment = context.delta_as_time().get::<hour>() / Self::RATED_LIFE_HOURS
+            * (1. + Self::CONTAMINATION_WEAR_GAIN * contamination.get::<ratio>());
+        let new_wear = (self.wear.get::<ratio>() + wear_increment).min(1.);
+
+        self.debris =
+            Ratio::new::<ratio>((new_wear - self.wear.get::<ratio>()) * Self::DEBRIS_PER_WEAR);
+        self.wear = Ratio::new::<ratio>(new_wear);
+    }
+
+    pub fn wear(&self) -> Ratio {
+        self.wear
+    }
+
+    pub fn operating_time(&self) -> Time {
+        self.operating_time
+    }
+
+    /// Contamination shed into the fluid during the last update.
+    pub fn debris(&self) -> Ratio {
+        self.debris
+    }
+
+    pub fn volumetric_efficiency(&self) -> Ratio {
+        Ratio::new::<ratio>(1. - Self::MAX_VOLUMETRIC_EFFICIENCY_LOSS * self.wear.get::<ratio>())
+    }
+
+    /// Part of the pump flow lost through the case drain at the given outlet pressure.
+    pub fn case_drain_leakage(&self, pressure: Pressure) -> Ratio {
+        Ratio::new::<ratio>(
+            (Self::MAX_CASE_DRAIN_LEAKAGE
+                * self.wear.get::<ratio>()
+                * pressure.get::<psi>().max(0.)
+                / Self::CASE_DRAIN_REFERENCE_PRESSURE_PSI)
+                .min(1.),
+        )
+    }
+
+    pub fn save_persistent_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64("wear", self.wear.get::<ratio>());
+        writer.write_f64("operating_hours", self.operating_time.get::<hour>());
+    }
+
+    pub fn restore_persistent_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(wear) = reader.read_f64("wear") {
+            self.wear = Ratio::new::<ratio>(wear.clamp(0., 1.));
+        }
+        if let Some(operating_hours) = reader.read_f64("operating_hours") {
+            self.operating_time = Time::new::<hour>(operating_hours.max(0.));
+        }
+    }
+}
+impl Default for PumpWear {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Filter on the return line of a hydraulic circuit.
+///
+/// Contamination enters the fluid over time and from pump debris. The filter captures it
+/// while fluid flows through, and clogs up doing so. A clogged filter builds up pressure on
+/// the return line, trips its differential pressure indicator and, once the differential
+/// pressure reaches its bypass valve setting, lets unfiltered fluid through.
+pub struct ReturnFilter {
+    contamination: Ratio,
+    clogging: Ratio,
+
+    differential_pressure: Pressure,
+    return_pressure_rise: Pressure,
+    is_bypassed: bool,
+    clogged_indication: bool,
+}
+impl ReturnFilter {
+    const RATED_FLOW_GPM: f64 = 40.;
+    const CLEAN_DIFFERENTIAL_PRESSURE_PSI: f64 = 10.;
+    const MAX_CLOGGING: f64 = 0.95;
+
+    const CLOGGED_INDICATION_DIFFERENTIAL_PRESSURE_PSI: f64 = 70.;
+    const BYPASS_DIFFERENTIAL_PRESSURE_PSI: f64 = 100.;
+
+    // Contamination entering the fluid from seals and reservoir breathing
+    const INGRESS_PER_HOUR: f64 = 0.0005;
+
+    // Rate at which contamination is captured when the filter sees its rated flow
+    const FILTRATION_RATE_PER_HOUR: f64 = 2.;
+
+    // Clogging caused per unit of captured contamination
+    const CLOGGING_PER_CAPTURED_CONTAMINATION: f64 = 0.4;
+
+    pub fn new() -> Self {
+        Self {
+            contamination: Ratio::default(),
+            clogging: Ratio::default(),
+
+            differential_pressure: Pressure::default(),
+            return_pressure_rise: Pressure::default(),
+            is_bypassed: false,
+            clogged_indication: false,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, return_flow: VolumeRate) {
+        let flow_ratio = (return_flow.get::<gallon_per_minute>() / Self::RATED_FLOW_GPM).max(0.);
+        let hours = context.delta_as_time().get::<hour>();
+
+        self.differential_pressure = Pressure::new::<psi>(Self::clogged_differential_pressure_psi(
+            flow_ratio,
+            self.clogging.get::<ratio>(),
+        ));
+        self.is_bypassed =
+            self.differential_pressure.get::<psi>() >= Self::BYPASS_DIFFERENTIAL_PRESSURE_PSI;
+        if self.is_bypassed {
+            self.differential_pressure =
+                Pressure::new::<psi>(Self::BYPASS_DIFFERENTIAL_PRESSURE_PSI);
+        }
+
+        self.return_pressure_rise = (self.differential_pressure
+            - Pressure::new::<psi>(Self::clogged_differential_pressure_psi(flow_ratio, 0.)))
+        .max(Pressure::default());
+
+        self.clogged_indication = self.clogged_indication
+            || self.differential_pressure.get::<psi>()
+                >= Self::CLOGGED_INDICATION_DIFFERENTIAL_PRESSURE_PSI;
+
+        let mut contamination = self.contamination.get::<ratio>() + Self::INGRESS_PER_HOUR * hours;
+
+        if !self.is_bypassed {
+            let captured =
+                contamination * (1. - (-Self::FILTRATION_RATE_PER_HOUR * flow_ratio * hours).exp());
+            contamination -= captured;
+
+            self.clogging = Ratio::new::<ratio>(
+                (self.clogging.get::<ratio>()
+                    + captured * Self::CLOGGING_PER_CAPTURED_CONTAMINATION)
+                    .min(Self::MAX_CLOGGING),
+            );
+        }
+
+        self.contamination = Ratio::new::<ratio>(contamination.clamp(0., 1.));
+    }
+
+    fn clogged_differential_pressure_psi(flow_ratio: f64, clogging: f64) -> f64 {
+        Self::CLEAN_DIFFERENTIAL_PRESSURE_PSI * flow_ratio / (1. - clogging).powi(2)
+    }
+
+    pub fn add_contamination(&mut self, contamination: Ratio) {
+        self.contamination = Ratio::new::<ratio>(
+            (self.contamination + contamination)
+                .get::<ratio>()
+                .clamp(0., 1.),
+        );
+    }
+
+    pub fn contamination(&self) -> Ratio {
+        self.contamination
+    }
+
+    pub fn clogging(&self) -> Ratio {
+        self.clogging
+    }
+
+    pub fn is_bypassed(&self) -> bool {
+        self.is_bypassed
+    }
+
+    pub fn is_clogged(&self) -> bool {
+        self.clogged_indication
+    }
+
+    /// Pressure built up on the return line compared to a clean filter.
+    pub fn return_pressure_rise(&self) -> Pressure {
+        self.return_pressure_rise
+    }
+
+    pub fn save_persistent_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64("contamination", self.contamination.get::<ratio>());
+        writer.write_f64("filter_clogging", self.clogging.get::<ratio>());
+    }
+
+    pub fn restore_persistent_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(contamination) = reader.read_f64("contamination") {
+            self.contamination = Ratio::new::<ratio>(contamination.clamp(0., 1.));
+        }
+        if let Some(clogging) = reader.read_f64("filter_clogging") {
+            self.clogging = Ratio::new::<ratio>(clogging.clamp(0., Self::MAX_CLOGGING));
+        }
+    }
+}
+impl Default for ReturnFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{SimulationTestBed, TestBed},
+        Aircraft, InitContext, SimulationElement,
+    };
+
+    use std::time::Duration;
+    use uom::si::time::second;
+
+    struct TestAircraft {
+        pump_wear: PumpWear,
+        filter: ReturnFilter,
+
+        pump_speed: AngularVelocity,
+        return_flow: VolumeRate,
+    }
+    impl TestAircraft {
+        fn new(_: &mut InitContext) -> Self {
+            Self {
+                pump_wear: PumpWear::new(),
+                filter: ReturnFilter::new(),
+
+                pump_speed: AngularVelocity::default(),
+                return_flow: VolumeRate::default(),
+            }
+        }
+
+        fn set_pump_speed(&mut self, speed: AngularVelocity) {
+            self.pump_speed = speed;
+        }
+
+        fn set_return_flow(&mut self, flow: VolumeRate) {
+            self.return_flow = flow;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.pump_wear
+                .update(context, self.pump_speed, self.filter.contamination());
+            self.filter.update(context, self.return_flow);
+            self.filter.add_contamination(self.pump_wear.debris());
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn save_persistent_state(&self, writer: &mut SnapshotWriter) {
+            self.pump_wear.save_persistent_state(writer);
+            self.filter.save_persistent_state(writer);
+        }
+
+        fn restore_persistent_state(&mut self, reader: &mut SnapshotReader) {
+            self.pump_wear.restore_persistent_state(reader);
+            self.filter.restore_persistent_state(reader);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    fn run_hours(test_bed: &mut SimulationTestBed<TestAircraft>, hours: f64) {
+        test_bed.run_with_delta(Duration::from_secs_f64(
+            Time::new::<hour>(hours).get::<second>(),
+        ));
+    }
+
+    #[test]
+    fn new_pump_has_no_wear_penalty() {
+        let test_bed = test_bed();
+
+        assert_eq!(
+            test_bed.query(|a| a.pump_wear.volumetric_efficiency()),
+            Ratio::new::<ratio>(1.)
+        );
+        assert_eq!(
+            test_bed.query(|a| a.pump_wear.case_drain_leakage(Pressure::new::<psi>(3000.))),
+            Ratio::default()
+        );
+    }
+
+    #[test]
+    fn pump_does_not_wear_when_not_turning() {
+        let mut test_bed = test_bed();
+
+        run_hours(&mut test_bed, 1000.);
+
+        assert_eq!(test_bed.query(|a| a.pump_wear.wear()), Ratio::default());
+        assert_eq!(
+            test_bed.query(|a| a.pump_wear.operating_time()),
+            Time::default()
+        );
+    }
+
+    #[test]
+    fn turning_pump_wears_and_loses_efficiency() {
+        let mut test_bed = test_bed();
+        test_bed
+            .command(|a| a.set_pump_speed(AngularVelocity::new::<revolution_per_minute>(4000.)));
+
+        run_hours(&mut test_bed, 10000.);
+
+        assert!(test_bed.query(|a| a.pump_wear.wear()) > Ratio::new::<ratio>(0.45));
+        assert!(
+            test_bed.query(|a| a.pump_wear.volumetric_efficiency()) < Ratio::new::<ratio>(0.95)
+        );
+        assert!(
+            test_bed.query(|a| a.pump_wear.case_drain_leakage(Pressure::new::<psi>(3000.)))
+                > Ratio::new::<ratio>(0.04)
+        );
+    }
+
+    #[test]
+    fn contaminated_fluid_wears_pump_faster() {
+        let mut clean_test_bed = test_bed();
+        clean_test_bed
+            .command(|a| a.set_pump_speed(AngularVelocity::new::<revolution_per_minute>(4000.)));
+
+        let mut dirty_test_bed = test_bed();
+        dirty_test_bed
+            .command(|a| a.set_pump_speed(AngularVelocity::new::<revolution_per_minute>(4000.)));
+        dirty_test_bed.command(|a| a.filter.add_contamination(Ratio::new::<ratio>(0.5)));
+
+        run_hours(&mut clean_test_bed, 100.);
+        run_hours(&mut dirty_test_bed, 100.);
+
+        assert!(
+            dirty_test_bed.query(|a| a.pump_wear.wear())
+                > clean_test_bed.query(|a| a.pump_wear.wear()) * 2.
+        );
+    }
+
+    #[test]
+    fn filter_captures_contamination_and_clogs() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.filter.add_contamination(Ratio::new::<ratio>(0.5)));
+        test_bed.command(|a| a.set_return_flow(VolumeRate::new::<gallon_per_minute>(40.)));
+
+        run_hours(&mut test_bed, 2.);
+
+        assert!(test_bed.query(|a| a.filter.contamination()) < Ratio::new::<ratio>(0.05));
+        assert!(test_bed.query(|a| a.filter.clogging()) > Ratio::new::<ratio>(0.15));
+    }
+
+    #[test]
+    fn clogged_filter_raises_return_pressure_and_indicates_clogging() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.filter.clogging = Ratio::new::<ratio>(0.65));
+        test_bed.command(|a| a.set_return_flow(VolumeRate::new::<gallon_per_minute>(40.)));
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.filter.is_clogged()));
+        assert!(!test_bed.query(|a| a.filter.is_bypassed()));
+        assert!(test_bed.query(|a| a.filter.return_pressure_rise()) > Pressure::new::<psi>(50.));
+    }
+
+    #[test]
+    fn fully_clogged_filter_bypasses_and_stops_filtering() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.filter.clogging = Ratio::new::<ratio>(0.9));
+        test_bed.command(|a| a.filter.add_contamination(Ratio::new::<ratio>(0.5)));
+        test_bed.command(|a| a.set_return_flow(VolumeRate::new::<gallon_per_minute>(40.)));
+
+        run_hours(&mut test_bed, 1.);
+
+        assert!(test_bed.query(|a| a.filter.is_bypassed()));
+        assert!(test_bed.query(|a| a.filter.contamination()) >= Ratio::new::<ratio>(0.5));
+        assert!(
+            (test_bed
+                .query(|a| a.filter.return_pressure_rise())
+                .get::<psi>()
+                - (ReturnFilter::BYPASS_DIFFERENTIAL_PRESSURE_PSI
+                    - ReturnFilter::CLEAN_DIFFERENTIAL_PRESSURE_PSI))
+                .abs()
+                < 0.001
+        );
+    }
+
+    #[test]
+    fn wear_and_clogging_are_kept_in_persistent_snapshot() {
+        let mut original = test_bed();
+        original
+            .command(|a| a.set_pump_speed(AngularVelocity::new::<revolution_per_minute>(4000.)));
+        original.command(|a| a.filter.clogging = Ratio::new::<ratio>(0.3));
+        run_hours(&mut original, 100.);
+
+        let snapshot = original.persistent_snapshot();
+
+        let mut restored = test_bed();
+        restored.restore_persistent(&snapshot).unwrap();
+
+        assert_eq!(
+            restored.query(|a| a.pump_wear.wear()),
+            original.query(|a| a.pump_wear.wear())
+        );
+        assert_eq!(
+            restored.query(|a| a.pump_wear.operating_time()),
+            original.query(|a| a.pump_wear.operating_time())
+        );
+        assert_eq!(
+            restored.query(|a| a.filter.clogging()),
+            original.query(|a| a.filter.clogging())
+        );
+    }
+}
@@ -19,10 +19,11 @@ use uom::si::{
 };
 
 use crate::{
+    failures::{Failure, FailureType},
     shared::{
         interpolation, low_pass_filter::LowPassFilter, pid::PidController,
-        random_from_normal_distribution, random_from_range, ConsumePower, ElectricalBusType,
-        ElectricalBuses,
+        random_from_normal_distribution, random_from_range, ConsumePower, DelayedTrueLogicGate,
+        ElectricalBusType, ElectricalBuses, FlightControlActuatorId,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -1149,6 +1150,18 @@ impl LinearActuator {
         self.speed = self.delta_displacement / context.delta_as_time();
     }
 
+    /// A disconnected actuator is dragged along by the surface without acting on it,
+    /// its control valve being cut from the surface it doesn't draw any fluid.
+    fn update_disconnected(
+        &mut self,
+        context: &UpdateContext,
+        connected_body: &LinearActuatedRigidBodyOnHingeAxis,
+    ) {
+        self.update_speed_position(context, connected_body);
+
+        self.signed_flow = VolumeRate::default();
+    }
+
     fn update_fluid_displacements(&mut self, context: &UpdateContext) {
         let mut volume_to_actuator = Volume::new::<cubic_meter>(0.);
         let mut volume_to_reservoir = Volume::new::<cubic_meter>(0.);
@@ -1280,6 +1293,16 @@ pub trait HydraulicAssemblyController {
     fn should_run_electro_hydrostatic_backup(&self) -> bool {
         false
     }
+
+    /// A jammed actuator mechanically holds the surface where it is
+    fn is_jammed(&self) -> bool {
+        false
+    }
+
+    /// A disconnected actuator doesn't apply any force to the surface
+    fn is_disconnected(&self) -> bool {
+        false
+    }
 }
 
 pub trait HydraulicLocking {
@@ -1308,6 +1331,137 @@ pub fn get_more_restrictive_soft_lock_velocities(
     )
 }
 
+/// Failures of the servo actuator of a flight control surface.
+///
+/// A runaway drives the servo valve hardover so the actuator moves the surface to one of its
+/// stops. A runaway or a disconnection of an actuator in active control is detected by the
+/// servo loop monitoring after a short delay, so the actuator can be passivated.
+pub struct FlightControlActuatorFailures {
+    jam_failure: Failure,
+    runaway_failure: Failure,
+    disconnect_failure: Failure,
+
+    runaway_position: Ratio,
+    was_running_away: bool,
+
+    servo_loop_monitor: DelayedTrueLogicGate,
+    is_failure_detected: bool,
+}
+impl FlightControlActuatorFailures {
+    const SERVO_LOOP_MONITORING_DELAY: Duration = Duration::from_millis(300);
+
+    pub fn new(id: FlightControlActuatorId) -> Self {
+        Self {
+            jam_failure: Failure::new(FailureType::FlightControlActuatorJam(id)),
+            runaway_failure: Failure::new(FailureType::FlightControlActuatorRunaway(id)),
+            disconnect_failure: Failure::new(FailureType::FlightControlActuatorDisconnect(id)),
+
+            runaway_position: Ratio::default(),
+            was_running_away: false,
+
+            servo_loop_monitor: DelayedTrueLogicGate::new(Self::SERVO_LOOP_MONITORING_DELAY),
+            is_failure_detected: false,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, is_in_active_control: bool) {
+        let has_servo_loop_failure =
+            self.runaway_failure.is_active() || self.disconnect_failure.is_active();
+
+        self.servo_loop_monitor
+            .update(context, has_servo_loop_failure && is_in_active_control);
+
+        // Once detected, the actuator stays passivated until the failure is cleared
+        self.is_failure_detected = has_servo_loop_failure
+            && (self.is_failure_detected || self.servo_loop_monitor.output());
+
+        // Hardover direction is only drawn when the runaway occurs
+        if self.runaway_failure.is_active() && !self.was_running_away {
+            self.runaway_position = Self::random_hardover_position();
+        }
+        self.was_running_away = self.runaway_failure.is_active();
+    }
+
+    fn random_hardover_position() -> Ratio {
+        if random_from_range(0., 1.) < 0.5 {
+            Ratio::new::<ratio>(0.)
+        } else {
+            Ratio::new::<ratio>(1.)
+        }
+    }
+
+    pub fn is_jammed(&self) -> bool {
+        self.jam_failure.is_active()
+    }
+
+    pub fn is_disconnected(&self) -> bool {
+        self.disconnect_failure.is_active()
+    }
+
+    pub fn is_running_away(&self) -> bool {
+        self.runaway_failure.is_active()
+    }
+
+    pub fn is_failure_detected(&self) -> bool {
+        self.is_failure_detected
+    }
+
+    /// Position the actuator actually goes to for a given position request
+    pub fn requested_position(&self, requested_position: Ratio) -> Ratio {
+        if self.is_running_away() {
+            self.runaway_position
+        } else {
+            requested_position
+        }
+    }
+}
+impl SimulationElement for FlightControlActuatorFailures {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.jam_failure.accept(visitor);
+        self.runaway_failure.accept(visitor);
+        self.disconnect_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// Passivates the actuators of a surface which servo loop monitoring detected a failure on.
+/// If the surface was under active control and no healthy actuator is left in position control,
+/// the first healthy damping actuator takes over.
+pub fn passivate_failed_actuators<const N: usize>(
+    modes: [LinearActuatorMode; N],
+    failures_detected: [bool; N],
+) -> [LinearActuatorMode; N] {
+    let mut passivated_modes = modes;
+    let mut has_lost_active_control = false;
+
+    for (mode, failure_detected) in passivated_modes.iter_mut().zip(failures_detected) {
+        if failure_detected && *mode == LinearActuatorMode::PositionControl {
+            *mode = LinearActuatorMode::ActiveDamping;
+            has_lost_active_control = true;
+        }
+    }
+
+    if has_lost_active_control
+        && !passivated_modes
+            .iter()
+            .any(|mode| *mode == LinearActuatorMode::PositionControl)
+    {
+        if let Some(mode) = passivated_modes
+            .iter_mut()
+            .zip(failures_detected)
+            .find(|(mode, failure_detected)| {
+                !failure_detected && **mode == LinearActuatorMode::ActiveDamping
+            })
+            .map(|(mode, _)| mode)
+        {
+            *mode = LinearActuatorMode::PositionControl;
+        }
+    }
+
+    passivated_modes
+}
+
 pub struct HydraulicLinearActuatorAssembly<const N: usize> {
     linear_actuators: [LinearActuator; N],
     rigid_body: LinearActuatedRigidBodyOnHingeAxis,
@@ -1359,18 +1513,24 @@ impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
             self.update_soft_lock_mechanism(assembly_controllers);
 
             for (index, actuator) in self.linear_actuators.iter_mut().enumerate() {
-                actuator.update_before_rigid_body(
-                    context,
-                    &mut self.rigid_body,
-                    &assembly_controllers[index],
-                    current_pressure[index],
-                );
+                if !assembly_controllers[index].is_disconnected() {
+                    actuator.update_before_rigid_body(
+                        context,
+                        &mut self.rigid_body,
+                        &assembly_controllers[index],
+                        current_pressure[index],
+                    );
+                }
             }
 
             self.rigid_body.update(context);
 
-            for actuator in &mut self.linear_actuators {
-                actuator.update_after_rigid_body(context, &self.rigid_body);
+            for (index, actuator) in self.linear_actuators.iter_mut().enumerate() {
+                if assembly_controllers[index].is_disconnected() {
+                    actuator.update_disconnected(context, &self.rigid_body);
+                } else {
+                    actuator.update_after_rigid_body(context, &self.rigid_body);
+                }
             }
         } else {
             self.rigid_body.update(context);
@@ -1381,6 +1541,19 @@ impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
         &mut self,
         assembly_controllers: &[impl HydraulicAssemblyController],
     ) {
+        // A jammed actuator still connected to the body holds it where it is, whatever the other
+        // controllers request
+        if assembly_controllers
+            .iter()
+            .any(|controller| controller.is_jammed() && !controller.is_disconnected())
+        {
+            if !self.rigid_body.is_locked() {
+                self.rigid_body.lock_at_current_position();
+            }
+
+            return;
+        }
+
         // The first controller requesting a lock locks the body
         let mut no_lock = true;
         for controller in assembly_controllers {
@@ -1851,6 +2024,13 @@ impl LinearActuatedRigidBodyOnHingeAxis {
         self.lock_position_request = position_normalized;
     }
 
+    pub fn lock_at_current_position(&mut self) {
+        self.is_lock_requested = true;
+        self.lock_position_request = self.position_normalized;
+        self.is_locked = true;
+        self.angular_speed = AngularVelocity::new::<radian_per_second>(0.);
+    }
+
     pub fn is_locked(&self) -> bool {
         self.is_locked
     }
@@ -1977,6 +2157,9 @@ mod tests {
 
         should_activate_elec_backup: bool,
         should_activate_elec_backup_refill: bool,
+
+        is_jammed: bool,
+        is_disconnected: bool,
     }
     impl TestHydraulicAssemblyController {
         fn new() -> Self {
@@ -1991,6 +2174,9 @@ mod tests {
 
                 should_activate_elec_backup: false,
                 should_activate_elec_backup_refill: false,
+
+                is_jammed: false,
+                is_disconnected: false,
             }
         }
 
@@ -2023,6 +2209,14 @@ mod tests {
         fn set_elec_backup_refill(&mut self, is_on: bool) {
             self.should_activate_elec_backup_refill = is_on;
         }
+
+        fn set_jammed(&mut self, is_jammed: bool) {
+            self.is_jammed = is_jammed;
+        }
+
+        fn set_disconnected(&mut self, is_disconnected: bool) {
+            self.is_disconnected = is_disconnected;
+        }
     }
     impl HydraulicAssemblyController for TestHydraulicAssemblyController {
         fn requested_mode(&self) -> LinearActuatorMode {
@@ -2040,6 +2234,14 @@ mod tests {
         fn requested_lock_position(&self) -> Ratio {
             self.lock_position
         }
+
+        fn is_jammed(&self) -> bool {
+            self.is_jammed
+        }
+
+        fn is_disconnected(&self) -> bool {
+            self.is_disconnected
+        }
     }
     impl HydraulicLocking for TestHydraulicAssemblyController {
         fn should_soft_lock(&self) -> bool {
@@ -2172,6 +2374,20 @@ mod tests {
             self.hydraulic_assembly.linear_actuators[actuator_id].used_volume()
         }
 
+        fn command_jam(&mut self, is_jammed: bool, actuator_id: usize) {
+            assert!(actuator_id < N);
+            self.controllers[actuator_id].set_jammed(is_jammed);
+        }
+
+        fn command_disconnect(&mut self, is_disconnected: bool, actuator_id: usize) {
+            assert!(actuator_id < N);
+            self.controllers[actuator_id].set_disconnected(is_disconnected);
+        }
+
+        fn actuator_flow(&self, actuator_id: usize) -> VolumeRate {
+            self.hydraulic_assembly.actuator_flow(actuator_id)
+        }
+
         fn command_empty_eha_accumulator(&mut self, actuator_id: usize) {
             assert!(actuator_id < N);
             if let Some(eha) = self.hydraulic_assembly.linear_actuators[actuator_id]
@@ -3229,6 +3445,154 @@ mod tests {
         assert!(test_bed.query(|a| a.body_position()) > Ratio::new::<ratio>(0.95));
     }
 
+    #[test]
+    fn aileron_jammed_actuator_holds_surface_against_other_actuator() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = aileron_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| {
+            a.set_pressures([Pressure::new::<psi>(3000.), Pressure::new::<psi>(3000.)])
+        });
+        test_bed.command(|a| a.command_jam(true, 0));
+        test_bed.command(|a| a.command_active_damping_mode(0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(1.), 1));
+        test_bed.run_with_delta(Duration::from_secs_f64(2.));
+
+        assert!(test_bed.query(|a| a.is_locked()));
+        assert!(test_bed.query(|a| a.body_position()) < Ratio::new::<ratio>(0.05));
+    }
+
+    #[test]
+    fn aileron_moves_up_when_jammed_actuator_is_freed() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = aileron_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| {
+            a.set_pressures([Pressure::new::<psi>(3000.), Pressure::new::<psi>(3000.)])
+        });
+        test_bed.command(|a| a.command_jam(true, 0));
+        test_bed.command(|a| a.command_active_damping_mode(0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(1.), 1));
+        test_bed.run_with_delta(Duration::from_secs_f64(1.));
+
+        test_bed.command(|a| a.command_jam(false, 0));
+        test_bed.run_with_delta(Duration::from_secs_f64(2.));
+
+        assert!(!test_bed.query(|a| a.is_locked()));
+        assert!(test_bed.query(|a| a.body_position()) > Ratio::new::<ratio>(0.95));
+    }
+
+    #[test]
+    fn aileron_disconnected_jammed_actuator_does_not_hold_surface() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = aileron_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| {
+            a.set_pressures([Pressure::new::<psi>(3000.), Pressure::new::<psi>(3000.)])
+        });
+        test_bed.command(|a| a.command_jam(true, 0));
+        test_bed.command(|a| a.command_disconnect(true, 0));
+        test_bed.command(|a| a.command_active_damping_mode(0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(1.), 1));
+        test_bed.run_with_delta(Duration::from_secs_f64(2.));
+
+        assert!(!test_bed.query(|a| a.is_locked()));
+        assert!(test_bed.query(|a| a.body_position()) > Ratio::new::<ratio>(0.95));
+    }
+
+    #[test]
+    fn aileron_disconnected_actuator_in_position_control_cannot_move_surface() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = aileron_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| {
+            a.set_pressures([Pressure::new::<psi>(3000.), Pressure::new::<psi>(3000.)])
+        });
+        test_bed.command(|a| a.command_disconnect(true, 1));
+        test_bed.command(|a| a.command_active_damping_mode(0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(1.), 1));
+        test_bed.run_with_delta(Duration::from_secs_f64(2.));
+
+        assert!(test_bed.query(|a| a.body_position()) < Ratio::new::<ratio>(0.1));
+        assert!(test_bed.query(|a| a.actuator_flow(1)) == VolumeRate::default());
+    }
+
+    #[test]
+    fn aileron_disconnected_damping_actuator_does_not_prevent_position_control() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = aileron_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| {
+            a.set_pressures([Pressure::new::<psi>(3000.), Pressure::new::<psi>(3000.)])
+        });
+        test_bed.command(|a| a.command_disconnect(true, 0));
+        test_bed.command(|a| a.command_active_damping_mode(0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(1.), 1));
+        test_bed.run_with_delta(Duration::from_secs_f64(2.));
+
+        assert!(test_bed.query(|a| a.body_position()) > Ratio::new::<ratio>(0.95));
+        assert!(test_bed.query(|a| a.actuator_flow(0)) == VolumeRate::default());
+    }
+
+    #[test]
+    fn failed_actuator_in_position_control_is_passivated_and_healthy_one_takes_over() {
+        let modes = passivate_failed_actuators(
+            [
+                LinearActuatorMode::PositionControl,
+                LinearActuatorMode::ActiveDamping,
+            ],
+            [true, false],
+        );
+
+        assert!(modes[0] == LinearActuatorMode::ActiveDamping);
+        assert!(modes[1] == LinearActuatorMode::PositionControl);
+    }
+
+    #[test]
+    fn failed_damping_actuator_leaves_modes_unchanged() {
+        let modes = passivate_failed_actuators(
+            [
+                LinearActuatorMode::ActiveDamping,
+                LinearActuatorMode::PositionControl,
+                LinearActuatorMode::ActiveDamping,
+            ],
+            [true, false, false],
+        );
+
+        assert!(modes[0] == LinearActuatorMode::ActiveDamping);
+        assert!(modes[1] == LinearActuatorMode::PositionControl);
+        assert!(modes[2] == LinearActuatorMode::ActiveDamping);
+    }
+
+    #[test]
+    fn failed_actuator_is_not_replaced_when_surface_is_not_under_active_control() {
+        let modes = passivate_failed_actuators(
+            [
+                LinearActuatorMode::ClosedCircuitDamping,
+                LinearActuatorMode::ClosedCircuitDamping,
+            ],
+            [true, false],
+        );
+
+        assert!(modes[0] == LinearActuatorMode::ClosedCircuitDamping);
+        assert!(modes[1] == LinearActuatorMode::ClosedCircuitDamping);
+    }
+
     #[test]
     fn aileron_drops_from_middle_pos_in_more_20s_in_closed_circuit_damping() {
         let mut test_bed = SimulationTestBed::new(|context| {
@@ -2,7 +2,9 @@ use self::brake_circuit::BrakeAccumulatorCharacteristics;
 use self::linear_actuator::Actuator;
 use crate::failures::{Failure, FailureType};
 use crate::hydraulic::{
-    electrical_pump_physics::ElectricalPumpPhysics, pumps::PumpCharacteristics,
+    degradation::{PumpWear, ReturnFilter},
+    electrical_pump_physics::ElectricalPumpPhysics,
+    pumps::PumpCharacteristics,
 };
 use crate::pneumatic::PressurizeableReservoir;
 use crate::wind_turbine::WindTurbine;
@@ -15,7 +17,7 @@ use crate::shared::{
 };
 use crate::simulation::{
     InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-    SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext, VariableIdentifier, Write,
 };
 use nalgebra::Vector3;
 
@@ -34,7 +36,9 @@ use uom::si::{
 
 pub mod aerodynamic_model;
 pub mod brake_circuit;
+pub mod brake_heat_sink;
 pub mod cargo_doors;
+pub mod degradation;
 pub mod electrical_generator;
 pub mod electrical_pump_physics;
 pub mod flap_slat;
@@ -944,6 +948,37 @@ impl Accumulator {
         gas_precharge * total_volume / (total_volume - current_volume)
     }
 
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64(
+            "accumulator_gas_precharge",
+            self.current_gas_init_precharge.get::<psi>(),
+        );
+        writer.write_f64("accumulator_gas_pressure", self.gas_pressure.get::<psi>());
+        writer.write_f64("accumulator_gas_volume", self.gas_volume.get::<gallon>());
+        writer.write_f64(
+            "accumulator_fluid_volume",
+            self.fluid_volume.get::<gallon>(),
+        );
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(precharge) = reader.read_f64("accumulator_gas_precharge") {
+            self.current_gas_init_precharge = Pressure::new::<psi>(precharge);
+        }
+
+        if let Some(pressure) = reader.read_f64("accumulator_gas_pressure") {
+            self.gas_pressure = Pressure::new::<psi>(pressure);
+        }
+
+        if let Some(volume) = reader.read_f64("accumulator_gas_volume") {
+            self.gas_volume = Volume::new::<gallon>(volume);
+        }
+
+        if let Some(volume) = reader.read_f64("accumulator_fluid_volume") {
+            self.fluid_volume = Volume::new::<gallon>(volume);
+        }
+    }
+
     #[cfg(test)]
     fn total_volume(&self) -> Volume {
         self.total_volume
@@ -1825,6 +1860,28 @@ impl SimulationElement for Section {
             self.pressure_switch_state() == PressureSwitchState::Pressurised,
         );
     }
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64("volume", self.current_volume.get::<gallon>());
+        writer.write_f64("pressure", self.current_pressure.get::<psi>());
+
+        if let Some(accumulator) = &self.accumulator {
+            accumulator.save_state(writer);
+        }
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(volume) = reader.read_f64("volume") {
+            self.current_volume = Volume::new::<gallon>(volume);
+        }
+
+        if let Some(pressure) = reader.read_f64("pressure") {
+            self.current_pressure = Pressure::new::<psi>(pressure);
+        }
+
+        if let Some(accumulator) = &mut self.accumulator {
+            accumulator.restore_state(reader);
+        }
+    }
 }
 impl SectionPressure for Section {
     fn pressure(&self) -> Pressure {
@@ -2217,6 +2274,7 @@ pub struct Reservoir {
     low_level_id: VariableIdentifier,
     low_air_press_id: VariableIdentifier,
     overheating_id: VariableIdentifier,
+    return_filter_clogged_id: VariableIdentifier,
 
     max_capacity: Volume,
     max_gaugeable: Volume,
@@ -2237,6 +2295,8 @@ pub struct Reservoir {
 
     total_return_flow: VolumeRate,
     total_return_volume: Volume,
+
+    return_filter: ReturnFilter,
 }
 impl Reservoir {
     const MIN_USABLE_VOLUME_GAL: f64 = 0.2;
@@ -2267,6 +2327,8 @@ impl Reservoir {
             low_air_press_id: context
                 .get_identifier(format!("HYD_{}_RESERVOIR_AIR_PRESSURE_IS_LOW", hyd_loop_id)),
             overheating_id: context.get_identifier(format!("HYD_{}_RESERVOIR_OVHT", hyd_loop_id)),
+            return_filter_clogged_id: context
+                .get_identifier(format!("HYD_{}_RETURN_FILTER_CLOGGED", hyd_loop_id)),
 
             max_capacity,
             max_gaugeable,
@@ -2292,6 +2354,8 @@ impl Reservoir {
             ),
             total_return_flow: VolumeRate::default(),
             total_return_volume: Volume::default(),
+
+            return_filter: ReturnFilter::new(),
         }
     }
 
@@ -2304,6 +2368,7 @@ impl Reservoir {
         self.air_pressure = air_pressure;
 
         self.update_return_flow(context);
+        self.return_filter.update(context, self.total_return_flow);
         self.update_heat(context, fluid);
 
         self.fluid_physics.update(context);
@@ -2413,6 +2478,23 @@ impl Reservoir {
     pub fn is_low_level(&self) -> bool {
         self.level_switch.is_low_level()
     }
+
+    pub fn contamination(&self) -> Ratio {
+        self.return_filter.contamination()
+    }
+
+    fn add_contamination(&mut self, contamination: Ratio) {
+        self.return_filter.add_contamination(contamination);
+    }
+
+    // Pressure rise on the return line caused by a clogged return filter
+    fn return_pressure_rise(&self) -> Pressure {
+        self.return_filter.return_pressure_rise()
+    }
+
+    pub fn is_return_filter_clogged(&self) -> bool {
+        self.return_filter.is_clogged()
+    }
 }
 impl SimulationElement for Reservoir {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -2427,6 +2509,27 @@ impl SimulationElement for Reservoir {
         writer.write(&self.low_level_id, self.is_low_level());
         writer.write(&self.low_air_press_id, self.is_low_air_pressure());
         writer.write(&self.overheating_id, self.is_overheating());
+        writer.write(
+            &self.return_filter_clogged_id,
+            self.is_return_filter_clogged(),
+        );
+    }
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64("level", self.current_level.get::<gallon>());
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(level) = reader.read_f64("level") {
+            self.current_level = Volume::new::<gallon>(level);
+        }
+    }
+
+    fn save_persistent_state(&self, writer: &mut SnapshotWriter) {
+        self.return_filter.save_persistent_state(writer);
+    }
+
+    fn restore_persistent_state(&mut self, reader: &mut SnapshotReader) {
+        self.return_filter.restore_persistent_state(reader);
     }
 }
 impl PressurizeableReservoir for Reservoir {
@@ -2468,6 +2571,8 @@ pub struct Pump {
     speed: AngularVelocity,
 
     cavitation_efficiency: Ratio,
+
+    wear: PumpWear,
 }
 impl Pump {
     const SECONDS_PER_MINUTES: f64 = 60.;
@@ -2488,6 +2593,8 @@ impl Pump {
             speed: AngularVelocity::new::<revolution_per_minute>(0.),
 
             cavitation_efficiency: Ratio::new::<ratio>(1.),
+
+            wear: PumpWear::new(),
         }
     }
 
@@ -2501,20 +2608,27 @@ impl Pump {
     ) {
         self.speed = speed;
 
+        self.wear.update(context, speed, reservoir.contamination());
+
         self.update_cavitation(reservoir);
 
-        let theoretical_displacement = self.calculate_displacement(section, controller);
+        // A clogged return filter makes the pump work against a higher pressure
+        let theoretical_displacement = self.calculate_displacement(
+            section.pressure() + reservoir.return_pressure_rise(),
+            controller,
+        );
 
         self.current_max_displacement.update(
             context.delta(),
             self.cavitation_efficiency
+                * self.wear.volumetric_efficiency()
                 * theoretical_displacement
                 * controller.max_displacement_restriction(),
         );
 
-        let max_flow = self
-            .get_max_flow_from_max_displacement()
-            .max(VolumeRate::new::<gallon_per_second>(0.));
+        let max_flow = (self.get_max_flow_from_max_displacement()
+            * (Ratio::new::<ratio>(1.) - self.wear.case_drain_leakage(section.pressure())))
+        .max(VolumeRate::new::<gallon_per_second>(0.));
 
         let max_flow_available_from_reservoir =
             reservoir.request_flow_availability(context, max_flow);
@@ -2535,12 +2649,11 @@ impl Pump {
 
     fn calculate_displacement<T: PumpController>(
         &self,
-        section: &impl SectionPressure,
+        pressure: Pressure,
         controller: &T,
     ) -> Volume {
         if controller.should_pressurise() {
-            self.pump_characteristics
-                .current_displacement(section.pressure())
+            self.pump_characteristics.current_displacement(pressure)
         } else {
             Volume::new::<cubic_inch>(0.)
         }
@@ -2599,6 +2712,18 @@ impl Pump {
     fn cavitation_efficiency(&self) -> Ratio {
         self.cavitation_efficiency
     }
+
+    fn wear(&self) -> Ratio {
+        self.wear.wear()
+    }
+
+    fn save_persistent_state(&self, writer: &mut SnapshotWriter) {
+        self.wear.save_persistent_state(writer);
+    }
+
+    fn restore_persistent_state(&mut self, reader: &mut SnapshotReader) {
+        self.wear.restore_persistent_state(reader);
+    }
 }
 impl PressureSource for Pump {
     fn delta_vol_max(&self) -> Volume {
@@ -2620,7 +2745,9 @@ impl PressureSource for Pump {
             reservoir.try_take_flow(context, max_current_flow)
         } else {
             VolumeRate::new::<gallon_per_second>(0.)
-        }
+        };
+
+        reservoir.add_contamination(self.wear.debris());
     }
 
     fn flow(&self) -> VolumeRate {
@@ -2635,6 +2762,7 @@ impl PressureSource for Pump {
 pub struct ElectricPump {
     cavitation_id: VariableIdentifier,
     overheat_id: VariableIdentifier,
+    wear_id: VariableIdentifier,
     pump: Pump,
     pump_physics: ElectricalPumpPhysics,
 }
@@ -2650,6 +2778,7 @@ impl ElectricPump {
         Self {
             cavitation_id: context.get_identifier(format!("HYD_{}_EPUMP_CAVITATION", id)),
             overheat_id: context.get_identifier(format!("HYD_{}_EPUMP_OVHT", id)),
+            wear_id: context.get_identifier(format!("HYD_{}_EPUMP_WEAR", id)),
             pump: Pump::new(pump_characteristics),
             pump_physics: ElectricalPumpPhysics::new(
                 context,
@@ -2692,6 +2821,10 @@ impl ElectricPump {
     pub fn speed(&self) -> AngularVelocity {
         self.pump.speed
     }
+
+    pub fn wear(&self) -> Ratio {
+        self.pump.wear()
+    }
 }
 impl PressureSource for ElectricPump {
     fn delta_vol_max(&self) -> Volume {
@@ -2734,6 +2867,15 @@ impl SimulationElement for ElectricPump {
             self.cavitation_efficiency().get::<ratio>(),
         );
         writer.write(&self.overheat_id, self.is_overheating());
+        writer.write(&self.wear_id, self.wear());
+    }
+
+    fn save_persistent_state(&self, writer: &mut SnapshotWriter) {
+        self.pump.save_persistent_state(writer);
+    }
+
+    fn restore_persistent_state(&mut self, reader: &mut SnapshotReader) {
+        self.pump.restore_persistent_state(reader);
     }
 }
 impl HeatingElement for ElectricPump {
@@ -2749,6 +2891,7 @@ impl HeatingPressureSource for ElectricPump {}
 
 pub struct EngineDrivenPump {
     active_id: VariableIdentifier,
+    wear_id: VariableIdentifier,
 
     is_active: bool,
     speed: AngularVelocity,
@@ -2775,6 +2918,7 @@ impl EngineDrivenPump {
     ) -> Self {
         Self {
             active_id: context.get_identifier(format!("HYD_{}_EDPUMP_ACTIVE", id)),
+            wear_id: context.get_identifier(format!("HYD_{}_EDPUMP_WEAR", id)),
             is_active: false,
             speed: AngularVelocity::new::<revolution_per_minute>(0.),
             pump: Pump::new(pump_characteristics),
@@ -2823,6 +2967,10 @@ impl EngineDrivenPump {
 
         self.is_active = controller.should_pressurise();
     }
+
+    pub fn wear(&self) -> Ratio {
+        self.pump.wear()
+    }
 }
 impl PressureSource for EngineDrivenPump {
     fn delta_vol_max(&self) -> Volume {
@@ -2860,6 +3008,15 @@ impl SimulationElement for EngineDrivenPump {
 
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.active_id, self.is_active);
+        writer.write(&self.wear_id, self.wear());
+    }
+
+    fn save_persistent_state(&self, writer: &mut SnapshotWriter) {
+        self.pump.save_persistent_state(writer);
+    }
+
+    fn restore_persistent_state(&mut self, reader: &mut SnapshotReader) {
+        self.pump.restore_persistent_state(reader);
     }
 }
 impl HeatingElement for EngineDrivenPump {
@@ -3345,6 +3502,47 @@ mod tests {
         assert!(volume_taken.get::<gallon>() == 5. - Reservoir::MIN_USABLE_VOLUME_GAL);
     }
 
+    #[test]
+    fn reservoir_writes_return_filter_state() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            reservoir(
+                context,
+                HydraulicColor::Green,
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(5.),
+            )
+        }));
+
+        test_bed.run();
+
+        assert!(test_bed.contains_variable_with_name("HYD_GREEN_RETURN_FILTER_CLOGGED"));
+    }
+
+    #[test]
+    fn reservoir_contamination_is_kept_in_persistent_snapshot() {
+        let element = |context: &mut InitContext| {
+            reservoir(
+                context,
+                HydraulicColor::Green,
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(5.),
+            )
+        };
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(element));
+        test_bed.command_element(|r| r.add_contamination(Ratio::new::<ratio>(0.2)));
+        let snapshot = test_bed.persistent_snapshot();
+
+        let mut restored = SimulationTestBed::from(ElementCtorFn(element));
+        restored.restore_persistent(&snapshot).unwrap();
+
+        assert_about_eq!(
+            restored.query_element(|r| r.contamination().get::<ratio>()),
+            0.2
+        );
+    }
+
     #[test]
     fn leak_measurement_valve_init_with_zero_pressures() {
         let test_bed = SimulationTestBed::from(ElementCtorFn(|_| {
@@ -3454,6 +3652,39 @@ mod tests {
         assert!(volume_after_leak_gallon < 4.5);
     }
 
+    #[test]
+    fn reservoir_restored_from_snapshot_regains_leaked_fluid() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            reservoir(
+                context,
+                HydraulicColor::Green,
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(2.),
+                Volume::new::<gallon>(5.),
+            )
+        }));
+
+        test_bed.set_update_after_power_distribution(|reservoir, context| {
+            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+        });
+
+        test_bed.run();
+        let snapshot = test_bed.snapshot();
+        let volume_before_leak_gallon =
+            test_bed.query_element(|r| r.fluid_level_real().get::<gallon>());
+
+        test_bed.fail(FailureType::ReservoirLeak(HydraulicColor::Green));
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+        test_bed.unfail(FailureType::ReservoirLeak(HydraulicColor::Green));
+
+        test_bed.restore(&snapshot).unwrap();
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        let volume_after_restore_gallon =
+            test_bed.query_element(|r| r.fluid_level_real().get::<gallon>());
+        assert_about_eq!(volume_after_restore_gallon, volume_before_leak_gallon);
+    }
+
     #[test]
     fn reservoir_leaking_cant_go_lower_then_0() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
@@ -1,5 +1,9 @@
 use std::time::Duration;
 
+pub mod tire;
+pub mod wheel_slip;
+pub mod wheel_speed_sensor;
+
 use crate::{
     failures::{Failure, FailureType},
     shared::arinc429::{Arinc429Word, SignStatus},
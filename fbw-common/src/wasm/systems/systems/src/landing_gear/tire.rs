@@ -0,0 +1,806 @@
+use std::time::Duration;
+
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{
+        arinc429::SignStatus, interpolation, BrakeTemperatures, BrakingEffectiveness,
+        ElectricalBusType, ElectricalBuses, GearWheel, TirePressures,
+    },
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use uom::si::{
+    acceleration::meter_per_second_squared,
+    angular_velocity::radian_per_second,
+    f64::*,
+    length::meter,
+    power::watt,
+    pressure::psi,
+    ratio::ratio,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    velocity::meter_per_second,
+};
+
+/// A landing gear tire.
+///
+/// The tire heats up from rolling under load and from the heat conducted by its brake.
+/// Its pressure follows the temperature of the gas it contains, relative to the ambient temperature
+/// it was inflated at. When the wheel gets too hot
+/// the fusible plug melts and deflates the tire before it can burst.
+pub struct Tire {
+    wheel_rpm_id: VariableIdentifier,
+
+    gear: GearWheel,
+    brake_number: Option<usize>,
+    nominal_pressure: Pressure,
+    radius: Length,
+
+    wheel_rpm: AngularVelocity,
+    temperature: ThermodynamicTemperature,
+    inflation_temperature: ThermodynamicTemperature,
+    inflation: Ratio,
+    is_fusible_plug_melted: bool,
+    is_burst: bool,
+
+    burst_failure: Failure,
+    slow_leak_failure: Failure,
+    was_failed: bool,
+}
+impl Tire {
+    // Until the first update gives the ambient temperature
+    const INITIAL_TEMPERATURE_DEGREE_CELSIUS: f64 = 15.;
+
+    const HEAT_CAPACITY_J_PER_K: f64 = 170000.;
+    // Share of the rolling resistance power heating up the tire
+    const ROLLING_HEAT_COEFFICIENT: f64 = 0.004;
+    const COOLING_TIME_CONSTANT: Duration = Duration::from_secs(1800);
+    const BRAKE_CONDUCTION_TIME_CONSTANT: Duration = Duration::from_secs(1800);
+
+    const FUSIBLE_PLUG_MELTING_TEMPERATURE_DEGREE_CELSIUS: f64 = 177.;
+    const FUSIBLE_PLUG_DEFLATION_TIME_CONSTANT: Duration = Duration::from_secs(20);
+
+    const BURST_TEMPERATURE_DEGREE_CELSIUS: f64 = 250.;
+    const BURST_PRESSURE_RATIO: f64 = 2.5;
+
+    const SLOW_LEAK_RATIO_PER_SECOND: f64 = 0.01 / 60.;
+
+    const INFLATION_BREAKPOINTS: [f64; 3] = [0., 0.8, 1.];
+    const BRAKING_EFFECTIVENESS_FOR_INFLATION: [f64; 3] = [0.3, 1., 1.];
+
+    /// Creates a tire fitted to the given simulator wheel. Tires of braked wheels conduct the
+    /// heat of the brake with the given number.
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        gear: GearWheel,
+        sim_wheel_id: usize,
+        brake_number: Option<usize>,
+        nominal_pressure: Pressure,
+        radius: Length,
+    ) -> Self {
+        Self {
+            wheel_rpm_id: context.get_identifier(format!("WHEEL RPM:{}", sim_wheel_id)),
+
+            gear,
+            brake_number,
+            nominal_pressure,
+            radius,
+
+            wheel_rpm: AngularVelocity::default(),
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(
+                Self::INITIAL_TEMPERATURE_DEGREE_CELSIUS,
+            ),
+            inflation_temperature: ThermodynamicTemperature::new::<degree_celsius>(
+                Self::INITIAL_TEMPERATURE_DEGREE_CELSIUS,
+            ),
+            inflation: Ratio::new::<ratio>(1.),
+            is_fusible_plug_melted: false,
+            is_burst: false,
+
+            burst_failure: Failure::new(FailureType::TireBurst(number)),
+            slow_leak_failure: Failure::new(FailureType::TireSlowLeak(number)),
+            was_failed: false,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        load: Force,
+        brake_temperatures: &impl BrakeTemperatures,
+    ) {
+        self.update_temperature(context, load, brake_temperatures);
+        self.update_failures(context);
+        self.update_inflation(context);
+    }
+
+    fn update_temperature(
+        &mut self,
+        context: &UpdateContext,
+        load: Force,
+        brake_temperatures: &impl BrakeTemperatures,
+    ) {
+        let delta = context.delta_as_secs_f64();
+        let ambient = context.ambient_temperature().get::<degree_celsius>();
+        let mut temperature = self.temperature.get::<degree_celsius>();
+
+        let rolling_heat_power: Power = load * self.wheel_speed() * Self::ROLLING_HEAT_COEFFICIENT;
+        temperature += rolling_heat_power.get::<watt>() * delta / Self::HEAT_CAPACITY_J_PER_K;
+
+        if let Some(brake_number) = self.brake_number {
+            let brake_temperature = brake_temperatures
+                .brake_temperature(brake_number)
+                .get::<degree_celsius>();
+            temperature += (brake_temperature - temperature)
+                * (1. - (-delta / Self::BRAKE_CONDUCTION_TIME_CONSTANT.as_secs_f64()).exp());
+        }
+
+        temperature = ambient
+            + (temperature - ambient) * (-delta / Self::COOLING_TIME_CONSTANT.as_secs_f64()).exp();
+
+        self.temperature = ThermodynamicTemperature::new::<degree_celsius>(temperature);
+    }
+
+    /// Fits a new tire, inflated to its nominal pressure at the given ambient temperature.
+    fn inflate(&mut self, ambient_temperature: ThermodynamicTemperature) {
+        self.temperature = ambient_temperature;
+        self.inflation_temperature = ambient_temperature;
+        self.inflation = Ratio::new::<ratio>(1.);
+        self.is_fusible_plug_melted = false;
+        self.is_burst = false;
+    }
+
+    fn update_failures(&mut self, context: &UpdateContext) {
+        let is_failed = self.burst_failure.is_active() || self.slow_leak_failure.is_active();
+
+        if self.burst_failure.is_active() {
+            self.is_burst = true;
+        } else if self.was_failed && !is_failed {
+            // Removing the failure is considered a tire change
+            self.inflate(context.ambient_temperature());
+        }
+
+        self.was_failed = is_failed;
+    }
+
+    fn update_inflation(&mut self, context: &UpdateContext) {
+        let delta = context.delta_as_secs_f64();
+
+        if self.temperature.get::<degree_celsius>()
+            > Self::FUSIBLE_PLUG_MELTING_TEMPERATURE_DEGREE_CELSIUS
+        {
+            self.is_fusible_plug_melted = true;
+        }
+
+        if self.inflation.get::<ratio>() > 0.5
+            && (self.temperature.get::<degree_celsius>() > Self::BURST_TEMPERATURE_DEGREE_CELSIUS
+                || self.pressure() > self.nominal_pressure * Self::BURST_PRESSURE_RATIO)
+        {
+            self.is_burst = true;
+        }
+
+        if self.is_burst {
+            self.inflation = Ratio::default();
+        } else if self.is_fusible_plug_melted {
+            self.inflation *=
+                (-delta / Self::FUSIBLE_PLUG_DEFLATION_TIME_CONSTANT.as_secs_f64()).exp();
+        } else if self.slow_leak_failure.is_active() {
+            self.inflation = (self.inflation
+                - Ratio::new::<ratio>(Self::SLOW_LEAK_RATIO_PER_SECOND * delta))
+            .max(Ratio::default());
+        }
+    }
+
+    fn wheel_speed(&self) -> Velocity {
+        Velocity::new::<meter_per_second>(
+            self.wheel_rpm.get::<radian_per_second>().abs() * self.radius.get::<meter>(),
+        )
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        self.nominal_pressure
+            * self.inflation.get::<ratio>()
+            * (self.temperature.get::<kelvin>() / self.inflation_temperature.get::<kelvin>())
+    }
+
+    pub fn nominal_pressure(&self) -> Pressure {
+        self.nominal_pressure
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    pub fn is_fusible_plug_melted(&self) -> bool {
+        self.is_fusible_plug_melted
+    }
+
+    pub fn is_burst(&self) -> bool {
+        self.is_burst
+    }
+
+    fn braking_effectiveness(&self) -> Ratio {
+        Ratio::new::<ratio>(interpolation(
+            &Self::INFLATION_BREAKPOINTS,
+            &Self::BRAKING_EFFECTIVENESS_FOR_INFLATION,
+            self.inflation.get::<ratio>(),
+        ))
+    }
+}
+impl SimulationElement for Tire {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.burst_failure.accept(visitor);
+        self.slow_leak_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.wheel_rpm = reader.read(&self.wheel_rpm_id);
+    }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_f64("temperature", self.temperature.get::<degree_celsius>());
+        writer.write_f64(
+            "inflation_temperature",
+            self.inflation_temperature.get::<degree_celsius>(),
+        );
+        writer.write_f64("inflation", self.inflation.get::<ratio>());
+        writer.write_bool("fusible_plug_melted", self.is_fusible_plug_melted);
+        writer.write_bool("burst", self.is_burst);
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(temperature) = reader.read_f64("temperature") {
+            self.temperature = ThermodynamicTemperature::new::<degree_celsius>(temperature);
+        }
+        if let Some(temperature) = reader.read_f64("inflation_temperature") {
+            self.inflation_temperature =
+                ThermodynamicTemperature::new::<degree_celsius>(temperature);
+        }
+        if let Some(inflation) = reader.read_f64("inflation") {
+            self.inflation = Ratio::new::<ratio>(inflation);
+        }
+        if let Some(is_melted) = reader.read_bool("fusible_plug_melted") {
+            self.is_fusible_plug_melted = is_melted;
+        }
+        if let Some(is_burst) = reader.read_bool("burst") {
+            self.is_burst = is_burst;
+        }
+    }
+}
+
+/// All tires of an aircraft, numbered from 1 in the order given at construction.
+pub struct Tires {
+    tires: Vec<Tire>,
+    is_initialised: bool,
+}
+impl Tires {
+    pub fn new(tires: Vec<Tire>) -> Self {
+        Self {
+            tires,
+            is_initialised: false,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, brake_temperatures: &impl BrakeTemperatures) {
+        if !self.is_initialised {
+            self.tires
+                .iter_mut()
+                .for_each(|tire| tire.inflate(context.ambient_temperature()));
+            self.is_initialised = true;
+        }
+
+        // The weight is considered evenly spread over all tires while on ground
+        let load_per_tire = if context.is_on_ground() {
+            context.total_weight() * Acceleration::new::<meter_per_second_squared>(9.81)
+                / self.tires.len().max(1) as f64
+        } else {
+            Force::default()
+        };
+
+        self.tires
+            .iter_mut()
+            .for_each(|tire| tire.update(context, load_per_tire, brake_temperatures));
+    }
+
+    pub fn tire(&self, tire_number: usize) -> &Tire {
+        &self.tires[tire_number - 1]
+    }
+}
+impl TirePressures for Tires {
+    fn tire_count(&self) -> usize {
+        self.tires.len()
+    }
+
+    fn tire_pressure(&self, tire_number: usize) -> Pressure {
+        self.tire(tire_number).pressure()
+    }
+
+    fn tire_nominal_pressure(&self, tire_number: usize) -> Pressure {
+        self.tire(tire_number).nominal_pressure()
+    }
+}
+impl BrakingEffectiveness for Tires {
+    fn braking_effectiveness(&self, gear: GearWheel) -> Ratio {
+        let gear_tires: Vec<&Tire> = self.tires.iter().filter(|tire| tire.gear == gear).collect();
+
+        if gear_tires.is_empty() {
+            Ratio::new::<ratio>(1.)
+        } else {
+            gear_tires
+                .iter()
+                .map(|tire| tire.braking_effectiveness())
+                .fold(Ratio::default(), |sum, effectiveness| sum + effectiveness)
+                / gear_tires.len() as f64
+        }
+    }
+}
+impl SimulationElement for Tires {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.tires.iter_mut().for_each(|tire| tire.accept(visitor));
+
+        visitor.visit(self);
+    }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_bool("initialised", self.is_initialised);
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(is_initialised) = reader.read_bool("initialised") {
+            self.is_initialised = is_initialised;
+        }
+    }
+}
+
+/// The tire pressure indicating system measures the pressure of every tire
+/// and warns when one of them is too low.
+pub struct TirePressureIndicatingSystem {
+    tire_pressure_ids: Vec<VariableIdentifier>,
+    low_pressure_id: VariableIdentifier,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    pressures: Vec<Pressure>,
+    is_low_pressure: bool,
+}
+impl TirePressureIndicatingSystem {
+    const LOW_PRESSURE_RATIO: f64 = 0.9;
+
+    pub fn new(
+        context: &mut InitContext,
+        tire_count: usize,
+        powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            tire_pressure_ids: (1..=tire_count)
+                .map(|number| context.get_identifier(format!("TPIS_TIRE_{}_PRESSURE", number)))
+                .collect(),
+            low_pressure_id: context.get_identifier("TPIS_TIRE_LO_PR".to_owned()),
+
+            powered_by,
+            is_powered: false,
+
+            pressures: vec![Pressure::default(); tire_count],
+            is_low_pressure: false,
+        }
+    }
+
+    pub fn update(&mut self, tires: &impl TirePressures) {
+        let tire_count = self.pressures.len().min(tires.tire_count());
+
+        for number in 1..=tire_count {
+            self.pressures[number - 1] = tires.tire_pressure(number);
+        }
+
+        self.is_low_pressure = self.is_powered
+            && (1..=tire_count).any(|number| {
+                tires.tire_pressure(number)
+                    < tires.tire_nominal_pressure(number) * Self::LOW_PRESSURE_RATIO
+            });
+    }
+
+    pub fn is_low_pressure(&self) -> bool {
+        self.is_low_pressure
+    }
+}
+impl SimulationElement for TirePressureIndicatingSystem {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        let ssm = if self.is_powered {
+            SignStatus::NormalOperation
+        } else {
+            SignStatus::FailureWarning
+        };
+
+        for (id, pressure) in self.tire_pressure_ids.iter().zip(&self.pressures) {
+            writer.write_arinc429(id, pressure.get::<psi>(), ssm);
+        }
+
+        writer.write(&self.low_pressure_id, self.is_low_pressure);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::electrical::test::TestElectricitySource;
+    use crate::electrical::{ElectricalBus, Electricity};
+    use crate::shared::arinc429::Arinc429Word;
+    use crate::shared::PotentialOrigin;
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::Aircraft;
+    use ntest::assert_about_eq;
+    use uom::si::{electric_potential::volt, mass::kilogram, velocity::knot};
+
+    struct TestBrakes {
+        temperature: ThermodynamicTemperature,
+    }
+    impl BrakeTemperatures for TestBrakes {
+        fn brake_temperature(&self, _: usize) -> ThermodynamicTemperature {
+            self.temperature
+        }
+    }
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+
+        tires: Tires,
+        tpis: TirePressureIndicatingSystem,
+        brakes: TestBrakes,
+
+        is_dc_1_powered: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::TransformerRectifier(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+
+                tires: Tires::new(vec![
+                    Tire::new(
+                        context,
+                        1,
+                        GearWheel::LEFT,
+                        1,
+                        Some(1),
+                        Pressure::new::<psi>(200.),
+                        Length::new::<meter>(0.584),
+                    ),
+                    Tire::new(
+                        context,
+                        2,
+                        GearWheel::RIGHT,
+                        2,
+                        Some(2),
+                        Pressure::new::<psi>(200.),
+                        Length::new::<meter>(0.584),
+                    ),
+                    Tire::new(
+                        context,
+                        3,
+                        GearWheel::NOSE,
+                        0,
+                        None,
+                        Pressure::new::<psi>(180.),
+                        Length::new::<meter>(0.38),
+                    ),
+                ]),
+                tpis: TirePressureIndicatingSystem::new(
+                    context,
+                    3,
+                    ElectricalBusType::DirectCurrent(1),
+                ),
+                brakes: TestBrakes {
+                    temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+                },
+
+                is_dc_1_powered: true,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(28.));
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_dc_1_powered {
+                electricity.flow(&self.electricity_source, &self.dc_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.tires.update(context, &self.brakes);
+            self.tpis.update(&self.tires);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.tires.accept(visitor);
+            self.tpis.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    struct TiresTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl TiresTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            test_bed.set_on_ground(true);
+            test_bed.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(64000.));
+
+            test_bed
+        }
+
+        fn rolling_at(mut self, speed: Velocity) -> Self {
+            for (wheel, radius) in [(0, 0.38), (1, 0.584), (2, 0.584)] {
+                self.write_by_name(
+                    &format!("WHEEL RPM:{}", wheel),
+                    AngularVelocity::new::<radian_per_second>(
+                        speed.get::<meter_per_second>() / radius,
+                    ),
+                );
+            }
+
+            self
+        }
+
+        fn brake_temperature(mut self, temperature: ThermodynamicTemperature) -> Self {
+            self.command(|a| a.brakes.temperature = temperature);
+            self
+        }
+
+        fn dc_1_unpowered(mut self) -> Self {
+            self.command(|a| a.is_dc_1_powered = false);
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.test_bed.run_multiple_frames(duration);
+            self
+        }
+
+        fn pressure(&self, tire_number: usize) -> Pressure {
+            self.query(|a| a.tires.tire(tire_number).pressure())
+        }
+
+        fn temperature(&self, tire_number: usize) -> ThermodynamicTemperature {
+            self.query(|a| a.tires.tire(tire_number).temperature())
+        }
+
+        fn is_burst(&self, tire_number: usize) -> bool {
+            self.query(|a| a.tires.tire(tire_number).is_burst())
+        }
+
+        fn is_fusible_plug_melted(&self, tire_number: usize) -> bool {
+            self.query(|a| a.tires.tire(tire_number).is_fusible_plug_melted())
+        }
+
+        fn braking_effectiveness(&self, gear: GearWheel) -> Ratio {
+            self.query(|a| a.tires.braking_effectiveness(gear))
+        }
+
+        fn tpis_pressure(&mut self, tire_number: usize) -> Arinc429Word<f64> {
+            self.read_arinc429_by_name(&format!("TPIS_TIRE_{}_PRESSURE", tire_number))
+        }
+
+        fn tpis_low_pressure(&mut self) -> bool {
+            self.read_by_name("TPIS_TIRE_LO_PR")
+        }
+    }
+    impl TestBed for TiresTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> TiresTestBed {
+        TiresTestBed::new()
+    }
+
+    #[test]
+    fn cold_tires_are_at_nominal_pressure() {
+        let test_bed = test_bed().run_for(Duration::from_secs(1));
+
+        assert_about_eq!(test_bed.pressure(1).get::<psi>(), 200., 0.1);
+        assert_about_eq!(test_bed.pressure(3).get::<psi>(), 180., 0.1);
+    }
+
+    #[test]
+    fn tires_are_at_nominal_pressure_at_the_ambient_temperature_they_were_inflated_at() {
+        let mut test_bed = test_bed();
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(35.));
+        let test_bed = test_bed.run_for(Duration::from_secs(1));
+
+        assert_about_eq!(test_bed.pressure(1).get::<psi>(), 200., 0.1);
+    }
+
+    #[test]
+    fn tires_inflated_when_warm_lose_pressure_in_the_cold() {
+        let mut test_bed = test_bed();
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(35.));
+        let mut test_bed = test_bed.run_for(Duration::from_secs(1));
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-10.));
+        let mut test_bed = test_bed.run_for(Duration::from_secs(3600));
+
+        assert!(test_bed.pressure(3).get::<psi>() < 180. * 0.9);
+        assert!(test_bed.tpis_low_pressure());
+    }
+
+    #[test]
+    fn rolling_heats_up_tires_and_raises_pressure() {
+        let test_bed = test_bed()
+            .rolling_at(Velocity::new::<knot>(20.))
+            .run_for(Duration::from_secs(600));
+
+        assert!(test_bed.temperature(3).get::<degree_celsius>() > 20.);
+        assert!(test_bed.pressure(3).get::<psi>() > 180.);
+    }
+
+    #[test]
+    fn hot_brakes_heat_up_braked_tires_only() {
+        let test_bed = test_bed()
+            .brake_temperature(ThermodynamicTemperature::new::<degree_celsius>(150.))
+            .run_for(Duration::from_secs(600));
+
+        assert!(test_bed.temperature(1).get::<degree_celsius>() > 40.);
+        assert_about_eq!(test_bed.temperature(3).get::<degree_celsius>(), 15., 0.1);
+    }
+
+    #[test]
+    fn overheated_wheel_melts_fusible_plug_and_deflates_tire() {
+        let test_bed = test_bed()
+            .brake_temperature(ThermodynamicTemperature::new::<degree_celsius>(700.))
+            .run_for(Duration::from_secs(900));
+
+        assert!(test_bed.is_fusible_plug_melted(1));
+        assert!(!test_bed.is_burst(1));
+        assert!(test_bed.pressure(1).get::<psi>() < 10.);
+    }
+
+    #[test]
+    fn deflated_tires_reduce_braking_effectiveness_of_their_gear() {
+        let test_bed = test_bed()
+            .brake_temperature(ThermodynamicTemperature::new::<degree_celsius>(700.))
+            .run_for(Duration::from_secs(900));
+
+        assert!(
+            test_bed
+                .braking_effectiveness(GearWheel::LEFT)
+                .get::<ratio>()
+                < 0.5
+        );
+    }
+
+    #[test]
+    fn burst_failure_bursts_the_tire() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::TireBurst(2));
+        let test_bed = test_bed.run_for(Duration::from_secs(1));
+
+        assert!(test_bed.is_burst(2));
+        assert_about_eq!(test_bed.pressure(2).get::<psi>(), 0.);
+        assert_about_eq!(
+            test_bed
+                .braking_effectiveness(GearWheel::RIGHT)
+                .get::<ratio>(),
+            0.3
+        );
+        assert_about_eq!(
+            test_bed
+                .braking_effectiveness(GearWheel::LEFT)
+                .get::<ratio>(),
+            1.
+        );
+    }
+
+    #[test]
+    fn removing_burst_failure_replaces_the_tire() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::TireBurst(2));
+        let mut test_bed = test_bed.run_for(Duration::from_secs(1));
+        test_bed.unfail(FailureType::TireBurst(2));
+        let test_bed = test_bed.run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.is_burst(2));
+        assert_about_eq!(test_bed.pressure(2).get::<psi>(), 200., 1.);
+    }
+
+    #[test]
+    fn slow_leak_slowly_deflates_the_tire() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::TireSlowLeak(1));
+        let test_bed = test_bed.run_for(Duration::from_secs(60));
+
+        assert!(test_bed.pressure(1).get::<psi>() < 199.);
+        assert!(test_bed.pressure(1).get::<psi>() > 190.);
+    }
+
+    #[test]
+    fn tpis_reports_tire_pressures() {
+        let mut test_bed = test_bed().run_for(Duration::from_secs(1));
+
+        let pressure = test_bed.tpis_pressure(1);
+        assert!(pressure.is_normal_operation());
+        assert_about_eq!(pressure.value(), 200., 0.1);
+        assert!(!test_bed.tpis_low_pressure());
+    }
+
+    #[test]
+    fn tpis_warns_of_low_tire_pressure() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::TireBurst(3));
+        let mut test_bed = test_bed.run_for(Duration::from_secs(1));
+
+        assert!(test_bed.tpis_low_pressure());
+    }
+
+    #[test]
+    fn unpowered_tpis_reports_failure() {
+        let mut test_bed = test_bed().dc_1_unpowered().run_for(Duration::from_secs(1));
+
+        assert!(test_bed.tpis_pressure(1).is_failure_warning());
+        assert!(!test_bed.tpis_low_pressure());
+    }
+
+    #[test]
+    fn restoring_a_snapshot_restores_a_burst_tire() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::TireBurst(1));
+        let mut test_bed = test_bed.run_for(Duration::from_secs(1));
+        let snapshot = test_bed.snapshot();
+
+        let mut restored = TiresTestBed::new();
+        restored.restore(&snapshot).unwrap();
+        let restored = restored.run_for(Duration::from_secs(1));
+
+        assert!(restored.is_burst(1));
+    }
+
+    #[test]
+    fn tires_restored_into_a_new_simulation_keep_their_temperature() {
+        let mut test_bed = test_bed()
+            .rolling_at(Velocity::new::<knot>(20.))
+            .run_for(Duration::from_secs(600));
+        let snapshot = test_bed.snapshot();
+        let hot_temperature = test_bed.temperature(1);
+
+        let mut restored = TiresTestBed::new();
+        restored.restore(&snapshot).unwrap();
+        let restored = restored.run_for(Duration::from_secs(1));
+
+        assert_about_eq!(
+            restored.temperature(1).get::<degree_celsius>(),
+            hot_temperature.get::<degree_celsius>(),
+            1.
+        );
+    }
+}
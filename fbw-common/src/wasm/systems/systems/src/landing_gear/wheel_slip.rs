@@ -0,0 +1,453 @@
+use crate::{
+    shared::{interpolation, GearWheel, WheelSlipRatios},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SurfaceTypeMsfs, UpdateContext, VariableIdentifier,
+    },
+};
+use uom::si::{
+    angular_velocity::radian_per_second,
+    f64::*,
+    length::{meter, millimeter},
+    ratio::ratio,
+    thermodynamic_temperature::degree_celsius,
+    velocity::meter_per_second,
+};
+
+/// Condition of the runway under the wheels, as far as tire grip is concerned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RunwayCondition {
+    Dry,
+    Wet,
+    Flooded,
+    Snow,
+    Ice,
+}
+impl RunwayCondition {
+    const MIN_WET_PRECIPITATION_RATE_MILLIMETER: f64 = 0.1;
+    const MIN_FLOODING_PRECIPITATION_RATE_MILLIMETER: f64 = 4.;
+
+    pub fn from_context(context: &UpdateContext) -> Self {
+        let precipitation_rate = context.precipitation_rate().get::<millimeter>();
+
+        match context.surface_type() {
+            SurfaceTypeMsfs::Water => RunwayCondition::Flooded,
+            SurfaceTypeMsfs::Snow => RunwayCondition::Snow,
+            SurfaceTypeMsfs::Ice => RunwayCondition::Ice,
+            _ if precipitation_rate > Self::MIN_WET_PRECIPITATION_RATE_MILLIMETER
+                && context.ambient_temperature().get::<degree_celsius>() <= 0. =>
+            {
+                RunwayCondition::Snow
+            }
+            _ if precipitation_rate > Self::MIN_FLOODING_PRECIPITATION_RATE_MILLIMETER => {
+                RunwayCondition::Flooded
+            }
+            _ if precipitation_rate > Self::MIN_WET_PRECIPITATION_RATE_MILLIMETER => {
+                RunwayCondition::Wet
+            }
+            _ => RunwayCondition::Dry,
+        }
+    }
+
+    /// Highest braking force the tires can transmit, as a ratio of the maximum brake force.
+    fn peak_friction(self) -> f64 {
+        match self {
+            RunwayCondition::Dry => 0.9,
+            RunwayCondition::Wet => 0.55,
+            RunwayCondition::Flooded => 0.3,
+            RunwayCondition::Snow => 0.3,
+            RunwayCondition::Ice => 0.12,
+        }
+    }
+
+    /// Braking force transmitted by a locked wheel, as a ratio of the maximum brake force.
+    fn locked_wheel_friction(self) -> f64 {
+        match self {
+            RunwayCondition::Dry => 0.6,
+            RunwayCondition::Wet => 0.3,
+            RunwayCondition::Flooded => 0.12,
+            RunwayCondition::Snow => 0.2,
+            RunwayCondition::Ice => 0.06,
+        }
+    }
+}
+
+/// Slip of a braked wheel relative to the ground.
+///
+/// Below the peak of the friction curve the wheel settles almost instantly at the slip which
+/// transmits the brake force. When the brake asks for more than the tire can grip, the wheel
+/// keeps decelerating past the peak and ends up locked, transmitting less force than at the peak.
+struct WheelSlip {
+    wheel_rpm_id: VariableIdentifier,
+
+    gear: GearWheel,
+    radius: Length,
+
+    wheel_rpm: AngularVelocity,
+    slip: Ratio,
+    braking_force: Ratio,
+}
+impl WheelSlip {
+    const SLIP_BREAKPOINTS: [f64; 5] = [0., 0.04, 0.12, 0.3, 1.];
+    const PEAK_SLIP: f64 = 0.12;
+
+    // Rate at which the slip grows with the brake force in excess of what the tire transmits
+    const SLIP_RATE_PER_EXCESS_FORCE_PER_SECOND: f64 = 5.;
+
+    const MIN_SLIPPING_SPEED_METER_PER_SECOND: f64 = 2.5;
+
+    fn new(
+        context: &mut InitContext,
+        gear: GearWheel,
+        sim_wheel_id: usize,
+        radius: Length,
+    ) -> Self {
+        Self {
+            wheel_rpm_id: context.get_identifier(format!("WHEEL RPM:{}", sim_wheel_id)),
+
+            gear,
+            radius,
+
+            wheel_rpm: AngularVelocity::default(),
+            slip: Ratio::default(),
+            braking_force: Ratio::default(),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, brake_force: Ratio, condition: RunwayCondition) {
+        let brake_force = brake_force.get::<ratio>();
+        let peak_friction = condition.peak_friction();
+        let slip = self.slip.get::<ratio>();
+
+        let (slip, braking_force) = if !context.is_on_ground()
+            || self.rolling_speed().get::<meter_per_second>()
+                < Self::MIN_SLIPPING_SPEED_METER_PER_SECOND
+        {
+            (0., brake_force)
+        } else if slip <= Self::PEAK_SLIP && brake_force <= peak_friction {
+            (
+                interpolation(
+                    &[0., 0.7 * peak_friction, peak_friction],
+                    &Self::SLIP_BREAKPOINTS[..3],
+                    brake_force,
+                ),
+                brake_force,
+            )
+        } else {
+            let slip = (slip
+                + Self::SLIP_RATE_PER_EXCESS_FORCE_PER_SECOND
+                    * (brake_force - Self::friction(condition, slip))
+                    * context.delta_as_secs_f64())
+            .clamp(0., 1.);
+
+            (slip, Self::friction(condition, slip))
+        };
+
+        self.slip = Ratio::new::<ratio>(slip);
+        self.braking_force = Ratio::new::<ratio>(braking_force);
+    }
+
+    fn friction(condition: RunwayCondition, slip: f64) -> f64 {
+        let peak_friction = condition.peak_friction();
+        let locked_wheel_friction = condition.locked_wheel_friction();
+
+        interpolation(
+            &Self::SLIP_BREAKPOINTS,
+            &[
+                0.,
+                0.7 * peak_friction,
+                peak_friction,
+                (0.9 * peak_friction).max(locked_wheel_friction),
+                locked_wheel_friction,
+            ],
+            slip,
+        )
+    }
+
+    fn rolling_speed(&self) -> Velocity {
+        Velocity::new::<meter_per_second>(
+            self.wheel_rpm.get::<radian_per_second>().abs() * self.radius.get::<meter>(),
+        )
+    }
+}
+impl SimulationElement for WheelSlip {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.wheel_rpm = reader.read(&self.wheel_rpm_id);
+    }
+}
+
+/// The braked wheels of an aircraft, numbered from 1 in the order given at construction.
+///
+/// Turns the force the brakes of each gear apply into the force the tires actually
+/// transmit to the runway.
+pub struct WheelSlips {
+    wheels: Vec<WheelSlip>,
+    runway_condition: RunwayCondition,
+}
+impl WheelSlips {
+    /// Creates the braked wheels. Each wheel is described by the gear it belongs to and the
+    /// simulator wheel from which it reads the wheel speed.
+    pub fn new(
+        context: &mut InitContext,
+        wheels: &[(GearWheel, usize)],
+        tire_radius: Length,
+    ) -> Self {
+        Self {
+            wheels: wheels
+                .iter()
+                .map(|&(gear, sim_wheel_id)| {
+                    WheelSlip::new(context, gear, sim_wheel_id, tire_radius)
+                })
+                .collect(),
+            runway_condition: RunwayCondition::Dry,
+        }
+    }
+
+    /// Updates the wheels from the brake force applied on each main gear, as a ratio of the
+    /// maximum brake force.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        left_brake_force: Ratio,
+        right_brake_force: Ratio,
+    ) {
+        self.runway_condition = RunwayCondition::from_context(context);
+
+        for wheel in self.wheels.iter_mut() {
+            let brake_force = match wheel.gear {
+                GearWheel::LEFT => left_brake_force,
+                GearWheel::RIGHT => right_brake_force,
+                GearWheel::NOSE => Ratio::default(),
+            };
+
+            wheel.update(context, brake_force, self.runway_condition);
+        }
+    }
+
+    /// The braking force the tires of a gear transmit to the runway, as a ratio of the maximum
+    /// brake force.
+    pub fn braking_force(&self, gear: GearWheel) -> Ratio {
+        let gear_wheels: Vec<&WheelSlip> = self
+            .wheels
+            .iter()
+            .filter(|wheel| wheel.gear == gear)
+            .collect();
+
+        if gear_wheels.is_empty() {
+            Ratio::default()
+        } else {
+            gear_wheels
+                .iter()
+                .map(|wheel| wheel.braking_force)
+                .fold(Ratio::default(), |sum, force| sum + force)
+                / gear_wheels.len() as f64
+        }
+    }
+
+    pub fn runway_condition(&self) -> RunwayCondition {
+        self.runway_condition
+    }
+}
+impl WheelSlipRatios for WheelSlips {
+    fn slip_ratio(&self, wheel_number: usize) -> Ratio {
+        self.wheels[wheel_number - 1].slip
+    }
+}
+impl SimulationElement for WheelSlips {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.wheels
+            .iter_mut()
+            .for_each(|wheel| wheel.accept(visitor));
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::simulation::test::{SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::Aircraft;
+    use std::time::Duration;
+    use uom::si::velocity::knot;
+
+    const TIRE_RADIUS_METER: f64 = 0.584;
+
+    struct TestAircraft {
+        wheel_slips: WheelSlips,
+        brake_force: Ratio,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                wheel_slips: WheelSlips::new(
+                    context,
+                    &[(GearWheel::LEFT, 1), (GearWheel::RIGHT, 2)],
+                    Length::new::<meter>(TIRE_RADIUS_METER),
+                ),
+                brake_force: Ratio::default(),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.wheel_slips
+                .update(context, self.brake_force, self.brake_force);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.wheel_slips.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    struct WheelSlipsTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl WheelSlipsTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_on_ground(true);
+
+            test_bed
+        }
+
+        fn rolling_at(mut self, speed: Velocity) -> Self {
+            let wheel_rpm = AngularVelocity::new::<radian_per_second>(
+                speed.get::<meter_per_second>() / TIRE_RADIUS_METER,
+            );
+            self.write_by_name("WHEEL RPM:1", wheel_rpm);
+            self.write_by_name("WHEEL RPM:2", wheel_rpm);
+
+            self
+        }
+
+        fn on_icy_runway(mut self) -> Self {
+            self.write_by_name("SURFACE TYPE", 9.);
+            self
+        }
+
+        fn in_rain(mut self, precipitation_rate: Length) -> Self {
+            self.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            self.write_by_name(
+                "AMBIENT PRECIP RATE",
+                precipitation_rate.get::<millimeter>(),
+            );
+            self
+        }
+
+        fn braking(mut self, brake_force: f64) -> Self {
+            self.command(|a| a.brake_force = Ratio::new::<ratio>(brake_force));
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.test_bed.run_multiple_frames(duration);
+            self
+        }
+
+        fn slip(&self) -> f64 {
+            self.query(|a| a.wheel_slips.slip_ratio(1).get::<ratio>())
+        }
+
+        fn braking_force(&self) -> f64 {
+            self.query(|a| a.wheel_slips.braking_force(GearWheel::LEFT).get::<ratio>())
+        }
+
+        fn runway_condition(&self) -> RunwayCondition {
+            self.query(|a| a.wheel_slips.runway_condition())
+        }
+    }
+    impl TestBed for WheelSlipsTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> WheelSlipsTestBed {
+        WheelSlipsTestBed::new()
+    }
+
+    #[test]
+    fn moderate_braking_on_dry_runway_is_fully_transmitted() {
+        let test_bed = test_bed()
+            .rolling_at(Velocity::new::<knot>(100.))
+            .braking(0.5)
+            .run_for(Duration::from_secs(2));
+
+        assert!(test_bed.slip() < WheelSlip::PEAK_SLIP);
+        assert!((test_bed.braking_force() - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn full_braking_on_icy_runway_locks_the_wheels() {
+        let test_bed = test_bed()
+            .on_icy_runway()
+            .rolling_at(Velocity::new::<knot>(100.))
+            .braking(1.)
+            .run_for(Duration::from_secs(2));
+
+        assert_eq!(test_bed.runway_condition(), RunwayCondition::Ice);
+        assert!(test_bed.slip() > 0.9);
+        assert!(test_bed.braking_force() < RunwayCondition::Ice.peak_friction());
+    }
+
+    #[test]
+    fn locked_wheels_spin_up_again_once_brakes_are_released() {
+        let test_bed = test_bed()
+            .on_icy_runway()
+            .rolling_at(Velocity::new::<knot>(100.))
+            .braking(1.)
+            .run_for(Duration::from_secs(2))
+            .braking(0.)
+            .run_for(Duration::from_secs(4));
+
+        assert!(test_bed.slip() < 0.01);
+    }
+
+    #[test]
+    fn wheels_do_not_slip_when_stopped() {
+        let test_bed = test_bed()
+            .on_icy_runway()
+            .braking(1.)
+            .run_for(Duration::from_secs(2));
+
+        assert!(test_bed.slip() < 0.001);
+        assert!((test_bed.braking_force() - 1.).abs() < 0.001);
+    }
+
+    #[test]
+    fn precipitation_below_freezing_covers_runway_with_snow() {
+        let mut test_bed = test_bed();
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-5.));
+        test_bed.write_by_name("AMBIENT PRECIP RATE", 1.);
+        test_bed = test_bed.run_for(Duration::from_millis(100));
+
+        assert_eq!(test_bed.runway_condition(), RunwayCondition::Snow);
+    }
+
+    #[test]
+    fn runway_gets_wet_then_flooded_with_rain() {
+        let test_bed = test_bed()
+            .in_rain(Length::new::<millimeter>(1.))
+            .run_for(Duration::from_millis(100));
+
+        assert_eq!(test_bed.runway_condition(), RunwayCondition::Wet);
+
+        let test_bed = test_bed
+            .in_rain(Length::new::<millimeter>(10.))
+            .run_for(Duration::from_millis(100));
+
+        assert_eq!(test_bed.runway_condition(), RunwayCondition::Flooded);
+    }
+}
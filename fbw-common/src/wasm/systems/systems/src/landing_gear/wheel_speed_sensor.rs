@@ -0,0 +1,526 @@
+use std::time::Duration;
+
+use super::wheel_slip::RunwayCondition;
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{GearWheel, WheelSlipRatios, WheelSpeeds},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        UpdateContext, VariableIdentifier,
+    },
+};
+use uom::si::{
+    acceleration::meter_per_second_squared,
+    angular_velocity::radian_per_second,
+    f64::*,
+    length::meter,
+    pressure::psi,
+    ratio::ratio,
+    velocity::{knot, meter_per_second},
+};
+
+/// A tachometer measuring the rotation speed of a landing gear wheel.
+///
+/// The measured speed follows the simulator wheel, slowed down by the slip of the braked wheel.
+/// When its tire aquaplanes on a flooded runway, above the aquaplaning speed the wheel spins down
+/// while the aircraft keeps rolling, and it only spins up again once the aircraft slows below
+/// the spin-up speed.
+pub struct WheelSpeedSensor {
+    wheel_rpm_id: VariableIdentifier,
+
+    gear: GearWheel,
+    radius: Length,
+    aquaplaning_speed: Velocity,
+    spin_up_speed: Velocity,
+
+    wheel_rpm: AngularVelocity,
+    slip: Ratio,
+    spin_ratio: Ratio,
+    is_aquaplaning: bool,
+
+    failure: Failure,
+}
+impl WheelSpeedSensor {
+    // Dynamic aquaplaning speeds of a tire, in knots per square root of its pressure in psi
+    const AQUAPLANING_SPEED_FACTOR: f64 = 9.;
+    const SPIN_UP_SPEED_FACTOR: f64 = 7.7;
+
+    const SPIN_DOWN_TIME_CONSTANT: Duration = Duration::from_millis(1500);
+    const SPIN_UP_TIME_CONSTANT: Duration = Duration::from_millis(200);
+
+    fn new(
+        context: &mut InitContext,
+        number: usize,
+        gear: GearWheel,
+        sim_wheel_id: usize,
+        radius: Length,
+        tire_pressure: Pressure,
+    ) -> Self {
+        let sqrt_tire_pressure = tire_pressure.get::<psi>().sqrt();
+
+        Self {
+            wheel_rpm_id: context.get_identifier(format!("WHEEL RPM:{}", sim_wheel_id)),
+
+            gear,
+            radius,
+            aquaplaning_speed: Velocity::new::<knot>(
+                Self::AQUAPLANING_SPEED_FACTOR * sqrt_tire_pressure,
+            ),
+            spin_up_speed: Velocity::new::<knot>(Self::SPIN_UP_SPEED_FACTOR * sqrt_tire_pressure),
+
+            wheel_rpm: AngularVelocity::default(),
+            slip: Ratio::default(),
+            spin_ratio: Ratio::new::<ratio>(1.),
+            is_aquaplaning: false,
+
+            failure: Failure::new(FailureType::WheelSpeedSensor(number)),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, slip: Ratio) {
+        let rolling_speed = self.rolling_speed();
+        self.slip = slip;
+
+        if !context.is_on_ground()
+            || RunwayCondition::from_context(context) != RunwayCondition::Flooded
+            || rolling_speed < self.spin_up_speed
+        {
+            self.is_aquaplaning = false;
+        } else if rolling_speed > self.aquaplaning_speed {
+            self.is_aquaplaning = true;
+        }
+
+        let (target, time_constant) = if self.is_aquaplaning {
+            (0., Self::SPIN_DOWN_TIME_CONSTANT)
+        } else {
+            (1., Self::SPIN_UP_TIME_CONSTANT)
+        };
+        let spin_ratio = self.spin_ratio.get::<ratio>();
+        self.spin_ratio = Ratio::new::<ratio>(
+            target
+                + (spin_ratio - target)
+                    * (-context.delta_as_secs_f64() / time_constant.as_secs_f64()).exp(),
+        );
+    }
+
+    fn rolling_speed(&self) -> Velocity {
+        Velocity::new::<meter_per_second>(
+            self.wheel_rpm.get::<radian_per_second>().abs() * self.radius.get::<meter>(),
+        )
+    }
+
+    /// The measured wheel speed, or none when the sensor is failed.
+    pub fn wheel_speed(&self) -> Option<Velocity> {
+        if self.failure.is_active() {
+            None
+        } else {
+            Some(
+                self.rolling_speed()
+                    * self.spin_ratio.get::<ratio>()
+                    * (1. - self.slip.get::<ratio>()),
+            )
+        }
+    }
+
+    pub fn gear(&self) -> GearWheel {
+        self.gear
+    }
+
+    pub fn is_aquaplaning(&self) -> bool {
+        self.is_aquaplaning
+    }
+}
+impl SimulationElement for WheelSpeedSensor {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.wheel_rpm = reader.read(&self.wheel_rpm_id);
+    }
+}
+
+/// The wheel speed sensors of an aircraft, numbered from 1 in the order given at construction.
+pub struct WheelSpeedSensors {
+    sensors: Vec<WheelSpeedSensor>,
+}
+impl WheelSpeedSensors {
+    /// Creates one sensor per given wheel. Each wheel is described by the gear it belongs to
+    /// and the simulator wheel from which it reads the wheel speed.
+    pub fn new(
+        context: &mut InitContext,
+        wheels: &[(GearWheel, usize)],
+        tire_radius: Length,
+        tire_pressure: Pressure,
+    ) -> Self {
+        Self {
+            sensors: wheels
+                .iter()
+                .enumerate()
+                .map(|(index, &(gear, sim_wheel_id))| {
+                    WheelSpeedSensor::new(
+                        context,
+                        index + 1,
+                        gear,
+                        sim_wheel_id,
+                        tire_radius,
+                        tire_pressure,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, wheel_slips: &impl WheelSlipRatios) {
+        self.sensors
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, sensor)| sensor.update(context, wheel_slips.slip_ratio(index + 1)));
+    }
+
+    pub fn sensor(&self, wheel_number: usize) -> &WheelSpeedSensor {
+        &self.sensors[wheel_number - 1]
+    }
+}
+impl WheelSpeeds for WheelSpeedSensors {
+    fn wheel_count(&self) -> usize {
+        self.sensors.len()
+    }
+
+    fn wheel_speed(&self, wheel_number: usize) -> Option<Velocity> {
+        self.sensor(wheel_number).wheel_speed()
+    }
+}
+impl SimulationElement for WheelSpeedSensors {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.sensors
+            .iter_mut()
+            .for_each(|sensor| sensor.accept(visitor));
+
+        visitor.visit(self);
+    }
+}
+
+/// Reference speed computed by a brake control unit from its wheel speed sensors.
+///
+/// It follows the fastest wheel with a valid measurement. Wheels spinning down when skidding or
+/// aquaplaning cannot drag it down faster than the aircraft is able to decelerate.
+/// It is invalid when no wheel speed is measured.
+pub struct WheelReferenceSpeed {
+    speed: Velocity,
+    is_valid: bool,
+}
+impl WheelReferenceSpeed {
+    const MAX_DECELERATION_M_S2: f64 = 8.;
+    const MIN_SLIP_SPEED_M_S: f64 = 1.;
+
+    pub fn new() -> Self {
+        Self {
+            speed: Velocity::default(),
+            is_valid: true,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, wheel_speeds: &impl WheelSpeeds) {
+        let fastest_wheel_speed = (1..=wheel_speeds.wheel_count())
+            .filter_map(|number| wheel_speeds.wheel_speed(number))
+            .reduce(|fastest, speed| fastest.max(speed));
+
+        match fastest_wheel_speed {
+            Some(fastest_wheel_speed) => {
+                let min_speed = self.speed
+                    - Acceleration::new::<meter_per_second_squared>(Self::MAX_DECELERATION_M_S2)
+                        * context.delta_as_time();
+
+                self.speed = if self.is_valid {
+                    fastest_wheel_speed.max(min_speed)
+                } else {
+                    fastest_wheel_speed
+                };
+                self.is_valid = true;
+            }
+            None => {
+                self.speed = Velocity::default();
+                self.is_valid = false;
+            }
+        }
+    }
+
+    /// The reference speed, or none when no wheel speed is available.
+    pub fn speed(&self) -> Option<Velocity> {
+        if self.is_valid {
+            Some(self.speed)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.is_valid
+    }
+
+    /// Slip of a wheel estimated from its measured speed against the reference speed.
+    pub fn wheel_slip(&self, wheel_speed: Option<Velocity>) -> Ratio {
+        match wheel_speed {
+            Some(wheel_speed)
+                if self.is_valid
+                    && self.speed > Velocity::new::<meter_per_second>(Self::MIN_SLIP_SPEED_M_S) =>
+            {
+                Ratio::new::<ratio>((1. - (wheel_speed / self.speed).get::<ratio>()).clamp(0., 1.))
+            }
+            _ => Ratio::default(),
+        }
+    }
+}
+impl Default for WheelReferenceSpeed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::simulation::test::{SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::Aircraft;
+    use ntest::assert_about_eq;
+
+    struct TestWheelSlips {
+        slip: Ratio,
+    }
+    impl WheelSlipRatios for TestWheelSlips {
+        fn slip_ratio(&self, _: usize) -> Ratio {
+            self.slip
+        }
+    }
+
+    struct TestAircraft {
+        sensors: WheelSpeedSensors,
+        reference_speed: WheelReferenceSpeed,
+        wheel_slips: TestWheelSlips,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                sensors: WheelSpeedSensors::new(
+                    context,
+                    &[(GearWheel::LEFT, 1), (GearWheel::RIGHT, 2)],
+                    Length::new::<meter>(0.584),
+                    Pressure::new::<psi>(200.),
+                ),
+                reference_speed: WheelReferenceSpeed::new(),
+                wheel_slips: TestWheelSlips {
+                    slip: Ratio::default(),
+                },
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.sensors.update(context, &self.wheel_slips);
+            self.reference_speed.update(context, &self.sensors);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.sensors.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    struct WheelSpeedSensorsTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl WheelSpeedSensorsTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_on_ground(true);
+
+            test_bed
+        }
+
+        fn wheel_rolling_at(mut self, sim_wheel_id: usize, speed: Velocity) -> Self {
+            self.write_by_name(
+                &format!("WHEEL RPM:{}", sim_wheel_id),
+                AngularVelocity::new::<radian_per_second>(speed.get::<meter_per_second>() / 0.584),
+            );
+
+            self
+        }
+
+        fn rolling_at(self, speed: Velocity) -> Self {
+            self.wheel_rolling_at(1, speed).wheel_rolling_at(2, speed)
+        }
+
+        fn wheels_slipping(mut self, slip: Ratio) -> Self {
+            self.command(|a| a.wheel_slips.slip = slip);
+            self
+        }
+
+        fn on_flooded_runway(mut self) -> Self {
+            self.write_by_name("SURFACE TYPE", 2.);
+            self
+        }
+
+        fn sensor_failed(mut self, number: usize) -> Self {
+            self.fail(FailureType::WheelSpeedSensor(number));
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.test_bed.run_multiple_frames(duration);
+            self
+        }
+
+        fn wheel_speed(&self, number: usize) -> Option<Velocity> {
+            self.query(|a| a.sensors.wheel_speed(number))
+        }
+
+        fn is_aquaplaning(&self, number: usize) -> bool {
+            self.query(|a| a.sensors.sensor(number).is_aquaplaning())
+        }
+
+        fn reference_speed(&self) -> Option<Velocity> {
+            self.query(|a| a.reference_speed.speed())
+        }
+
+        fn estimated_slip(&self, number: usize) -> Ratio {
+            self.query(|a| a.reference_speed.wheel_slip(a.sensors.wheel_speed(number)))
+        }
+    }
+    impl TestBed for WheelSpeedSensorsTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> WheelSpeedSensorsTestBed {
+        WheelSpeedSensorsTestBed::new()
+    }
+
+    #[test]
+    fn sensors_measure_the_wheel_speed() {
+        let test_bed = test_bed()
+            .rolling_at(Velocity::new::<knot>(60.))
+            .run_for(Duration::from_secs(1));
+
+        assert_about_eq!(test_bed.wheel_speed(1).unwrap().get::<knot>(), 60., 0.5);
+        assert_about_eq!(test_bed.reference_speed().unwrap().get::<knot>(), 60., 0.5);
+    }
+
+    #[test]
+    fn slipping_wheels_turn_slower_than_the_aircraft_rolls() {
+        let test_bed = test_bed()
+            .rolling_at(Velocity::new::<knot>(100.))
+            .wheels_slipping(Ratio::new::<ratio>(0.2))
+            .run_for(Duration::from_secs(1));
+
+        assert_about_eq!(test_bed.wheel_speed(1).unwrap().get::<knot>(), 80., 0.5);
+    }
+
+    #[test]
+    fn slip_of_a_wheel_is_estimated_against_the_reference_speed() {
+        let test_bed = test_bed()
+            .wheel_rolling_at(1, Velocity::new::<knot>(80.))
+            .wheel_rolling_at(2, Velocity::new::<knot>(100.))
+            .run_for(Duration::from_secs(1));
+
+        assert_about_eq!(test_bed.estimated_slip(1).get::<ratio>(), 0.2, 0.01);
+        assert_about_eq!(test_bed.estimated_slip(2).get::<ratio>(), 0., 0.01);
+    }
+
+    #[test]
+    fn reference_speed_follows_the_fastest_wheel() {
+        let test_bed = test_bed()
+            .wheel_rolling_at(1, Velocity::new::<knot>(40.))
+            .wheel_rolling_at(2, Velocity::new::<knot>(60.))
+            .run_for(Duration::from_secs(1));
+
+        assert_about_eq!(test_bed.reference_speed().unwrap().get::<knot>(), 60., 0.5);
+    }
+
+    #[test]
+    fn reference_speed_does_not_drop_faster_than_the_aircraft_can_decelerate() {
+        let test_bed = test_bed()
+            .rolling_at(Velocity::new::<knot>(100.))
+            .run_for(Duration::from_secs(1))
+            .rolling_at(Velocity::new::<knot>(0.))
+            .run_for(Duration::from_millis(500));
+
+        assert!(test_bed.reference_speed().unwrap().get::<knot>() > 80.);
+    }
+
+    #[test]
+    fn failed_sensor_has_no_speed() {
+        let test_bed = test_bed()
+            .rolling_at(Velocity::new::<knot>(60.))
+            .sensor_failed(1)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.wheel_speed(1).is_none());
+        assert_about_eq!(test_bed.reference_speed().unwrap().get::<knot>(), 60., 0.5);
+    }
+
+    #[test]
+    fn reference_speed_is_invalid_when_all_sensors_failed() {
+        let test_bed = test_bed()
+            .rolling_at(Velocity::new::<knot>(60.))
+            .sensor_failed(1)
+            .sensor_failed(2)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.reference_speed().is_none());
+    }
+
+    #[test]
+    fn wheels_do_not_aquaplane_on_a_dry_runway() {
+        let test_bed = test_bed()
+            .rolling_at(Velocity::new::<knot>(140.))
+            .run_for(Duration::from_secs(5));
+
+        assert!(!test_bed.is_aquaplaning(1));
+        assert_about_eq!(test_bed.wheel_speed(1).unwrap().get::<knot>(), 140., 0.5);
+    }
+
+    #[test]
+    fn wheels_spin_down_when_aquaplaning_on_a_flooded_runway() {
+        let test_bed = test_bed()
+            .on_flooded_runway()
+            .rolling_at(Velocity::new::<knot>(140.))
+            .run_for(Duration::from_secs(3));
+
+        assert!(test_bed.is_aquaplaning(1));
+        assert!(test_bed.wheel_speed(1).unwrap().get::<knot>() < 40.);
+        assert!(test_bed.reference_speed().unwrap() > test_bed.wheel_speed(1).unwrap());
+    }
+
+    #[test]
+    fn aquaplaning_wheels_spin_up_again_below_spin_up_speed() {
+        let test_bed = test_bed()
+            .on_flooded_runway()
+            .rolling_at(Velocity::new::<knot>(140.))
+            .run_for(Duration::from_secs(3))
+            .rolling_at(Velocity::new::<knot>(120.))
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.is_aquaplaning(1));
+
+        let test_bed = test_bed
+            .rolling_at(Velocity::new::<knot>(100.))
+            .run_for(Duration::from_secs(2));
+
+        assert!(!test_bed.is_aquaplaning(1));
+        assert_about_eq!(test_bed.wheel_speed(1).unwrap().get::<knot>(), 100., 0.5);
+    }
+}
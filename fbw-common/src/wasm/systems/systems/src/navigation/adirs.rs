@@ -1,6 +1,7 @@
 use crate::air_conditioning::AdirsToAirCondInterface;
 use crate::simulation::{InitContext, VariableIdentifier};
 use crate::{
+    failures::{Failure, FailureType},
     overhead::{IndicationLight, OnOffFaultPushButton},
     shared::{
         arinc429::{Arinc429Word, SignStatus},
@@ -9,7 +10,7 @@ use crate::{
     },
     simulation::{
         Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, Write, Writer,
+        SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext, Write, Writer,
     },
 };
 use bitflags::bitflags;
@@ -25,6 +26,7 @@ use uom::si::{
     length::foot,
     pressure::hectopascal,
     ratio::ratio,
+    thermodynamic_temperature::kelvin,
     velocity::{foot_per_minute, knot},
 };
 
@@ -81,7 +83,12 @@ impl AirDataInertialReferenceSystemOverheadPanel {
         self.ir
             .iter_mut()
             .enumerate()
-            .for_each(|(index, ir)| ir.set_fault(adirs.ir_has_fault(index + 1)))
+            .for_each(|(index, ir)| ir.set_fault(adirs.ir_has_fault(index + 1)));
+
+        self.adr
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, adr)| adr.set_fault(adirs.adr_has_fault(index + 1)));
     }
 
     fn mode_of(&self, number: usize) -> InertialReferenceMode {
@@ -359,6 +366,8 @@ pub struct AirDataInertialReferenceSystem {
     adirus: [AirDataInertialReferenceUnit; 3],
     configured_align_time: AlignTime,
     simulator_data: AdirsSimulatorData,
+    /// The GPS receivers which supply the ADIRUs with the position used for GPIRS hybridisation.
+    gps_receiver_failures: [Failure; 2],
 }
 impl AirDataInertialReferenceSystem {
     const REMAINING_ALIGNMENT_TIME_KEY: &'static str = "ADIRS_REMAINING_IR_ALIGNMENT_TIME";
@@ -382,6 +391,10 @@ impl AirDataInertialReferenceSystem {
             ],
             configured_align_time: AlignTime::Realistic,
             simulator_data: AdirsSimulatorData::new(context),
+            gps_receiver_failures: [
+                Failure::new(FailureType::GpsReceiver(1)),
+                Failure::new(FailureType::GpsReceiver(2)),
+            ],
         }
     }
 
@@ -392,9 +405,20 @@ impl AirDataInertialReferenceSystem {
     ) {
         let align_time = self.configured_align_time;
         let simulator_data = self.simulator_data;
+        let gps_available = [1, 2, 3].map(|number| self.gps_available_to(number));
         self.adirus
             .iter_mut()
-            .for_each(|adiru| adiru.update(context, overhead, align_time, simulator_data));
+            .zip(gps_available)
+            .for_each(|(adiru, gps_available)| {
+                adiru.update(context, overhead, align_time, simulator_data, gps_available)
+            });
+    }
+
+    /// ADIRU 1 and 3 are supplied by GPS receiver 1, ADIRU 2 by GPS receiver 2.
+    fn gps_available_to(&self, adiru_number: usize) -> bool {
+        let receiver_number = if adiru_number == 2 { 2 } else { 1 };
+
+        !self.gps_receiver_failures[receiver_number - 1].is_active()
     }
 
     fn remaining_align_duration(&self) -> Duration {
@@ -409,20 +433,28 @@ impl AirDataInertialReferenceSystem {
             .unwrap_or_else(|| Duration::from_secs(0))
     }
 
-    fn any_adiru_fully_aligned_with_ir_on(&self) -> bool {
-        self.adirus
-            .iter()
-            .any(|adiru| adiru.is_fully_aligned() && adiru.ir_is_on())
+    fn uses_gps_as_primary(&self) -> bool {
+        self.adirus.iter().enumerate().any(|(index, adiru)| {
+            adiru.is_fully_aligned()
+                && adiru.ir_is_on()
+                && !adiru.ir_has_failed()
+                && self.gps_available_to(index + 1)
+        })
     }
 
     fn ir_has_fault(&self, number: usize) -> bool {
         self.adirus[number - 1].ir_has_fault()
     }
+
+    fn adr_has_fault(&self, number: usize) -> bool {
+        self.adirus[number - 1].adr_has_fault()
+    }
 }
 impl SimulationElement for AirDataInertialReferenceSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.adirus, visitor);
         self.simulator_data.accept(visitor);
+        accept_iterable!(self.gps_receiver_failures, visitor);
 
         visitor.visit(self);
     }
@@ -436,10 +468,7 @@ impl SimulationElement for AirDataInertialReferenceSystem {
             &self.remaining_alignment_time_id,
             self.remaining_align_duration(),
         );
-        writer.write(
-            &self.uses_gps_as_primary_id,
-            self.any_adiru_fully_aligned_with_ir_on(),
-        )
+        writer.write(&self.uses_gps_as_primary_id, self.uses_gps_as_primary())
     }
 }
 impl AdirsToAirCondInterface for AirDataInertialReferenceSystem {
@@ -501,6 +530,14 @@ impl AdirsMeasurementOutputs for AirDataInertialReferenceSystem {
     fn altitude(&self, adiru_number: usize) -> Arinc429Word<Length> {
         self.adirus[adiru_number - 1].altitude()
     }
+
+    fn computed_airspeed(&self, adiru_number: usize) -> Arinc429Word<Velocity> {
+        self.adirus[adiru_number - 1].computed_airspeed()
+    }
+
+    fn ground_speed(&self, adiru_number: usize) -> Arinc429Word<Velocity> {
+        self.adirus[adiru_number - 1].ground_speed()
+    }
 }
 
 struct AirDataInertialReferenceUnit {
@@ -535,10 +572,17 @@ impl AirDataInertialReferenceUnit {
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
         align_time: AlignTime,
         simulator_data: AdirsSimulatorData,
+        gps_available: bool,
     ) {
         self.adr.update(context, overhead, simulator_data);
-        self.ir
-            .update(context, &self.adr, overhead, align_time, simulator_data);
+        self.ir.update(
+            context,
+            &self.adr,
+            overhead,
+            align_time,
+            simulator_data,
+            gps_available,
+        );
 
         self.update_discrete_outputs();
     }
@@ -573,6 +617,14 @@ impl AirDataInertialReferenceUnit {
         self.ir.has_fault()
     }
 
+    fn ir_has_failed(&self) -> bool {
+        self.ir.has_failed()
+    }
+
+    fn adr_has_fault(&self) -> bool {
+        self.adr.has_fault()
+    }
+
     fn adr_is_valid(&self) -> bool {
         self.adr.is_valid()
     }
@@ -661,6 +713,10 @@ impl AirDataInertialReferenceUnit {
         self.adr.true_airspeed()
     }
 
+    fn computed_airspeed(&self) -> Arinc429Word<Velocity> {
+        self.adr.computed_airspeed()
+    }
+
     fn baro_correction_1(&self) -> Arinc429Word<Pressure> {
         self.adr.baro_correction_1()
     }
@@ -817,6 +873,16 @@ struct AirDataReference {
     angle_of_attack: AdirsData<Angle>,
 
     remaining_initialisation_duration: Option<Duration>,
+
+    failure: Failure,
+    pitot_blockage: Failure,
+    static_port_blockage: Failure,
+    angle_of_attack_vane_jam: Failure,
+    /// The total pressure trapped in the pitot probe, when blocked.
+    blocked_total_pressure: Option<Pressure>,
+    /// The static pressure trapped in the static ports, when blocked.
+    blocked_static_pressure: Option<Pressure>,
+    jammed_angle_of_attack: Option<Angle>,
 }
 impl AirDataReference {
     const INITIALISATION_DURATION: Duration = Duration::from_secs(18);
@@ -839,6 +905,9 @@ impl AirDataReference {
     const MINIMUM_CAS: f64 = 30.;
     const MINIMUM_MACH: f64 = 0.1;
     const MINIMUM_CAS_FOR_AOA: f64 = 60.;
+    const SEA_LEVEL_PRESSURE_HPA: f64 = 1013.25;
+    const SEA_LEVEL_SPEED_OF_SOUND_KNOTS: f64 = 661.4786;
+    const SPEED_OF_SOUND_KNOTS_PER_SQRT_KELVIN: f64 = 38.967854;
 
     fn new(context: &mut InitContext, number: usize) -> Self {
         Self {
@@ -891,6 +960,14 @@ impl AirDataReference {
 
             // Start fully initialised.
             remaining_initialisation_duration: Some(Duration::from_secs(0)),
+
+            failure: Failure::new(FailureType::AdrFault(number)),
+            pitot_blockage: Failure::new(FailureType::PitotBlockage(number)),
+            static_port_blockage: Failure::new(FailureType::StaticPortBlockage(number)),
+            angle_of_attack_vane_jam: Failure::new(FailureType::AngleOfAttackVaneJam(number)),
+            blocked_total_pressure: None,
+            blocked_static_pressure: None,
+            jammed_angle_of_attack: None,
         }
     }
 
@@ -902,9 +979,43 @@ impl AirDataReference {
     ) {
         self.is_on = overhead.adr_is_on(self.number);
         self.update_remaining_initialisation_duration(context, overhead);
+        self.update_probes(context, simulator_data);
         self.update_values(context, simulator_data);
     }
 
+    /// Traps the pressures and the vane angle present when a probe becomes blocked or jammed.
+    /// They are held for as long as the failure remains active.
+    fn update_probes(&mut self, context: &UpdateContext, simulator_data: AdirsSimulatorData) {
+        let static_pressure = context.ambient_pressure();
+        let total_pressure =
+            static_pressure + impact_pressure_from_computed_airspeed(context.indicated_airspeed());
+
+        self.blocked_total_pressure = if self.pitot_blockage.is_active() {
+            Some(self.blocked_total_pressure.unwrap_or(total_pressure))
+        } else {
+            None
+        };
+
+        self.blocked_static_pressure = if self.static_port_blockage.is_active() {
+            Some(self.blocked_static_pressure.unwrap_or(static_pressure))
+        } else {
+            None
+        };
+
+        self.jammed_angle_of_attack = if self.angle_of_attack_vane_jam.is_active() {
+            Some(
+                self.jammed_angle_of_attack
+                    .unwrap_or(simulator_data.angle_of_attack),
+            )
+        } else {
+            None
+        };
+    }
+
+    fn has_blocked_probe(&self) -> bool {
+        self.blocked_total_pressure.is_some() || self.blocked_static_pressure.is_some()
+    }
+
     fn update_remaining_initialisation_duration(
         &mut self,
         context: &UpdateContext,
@@ -963,37 +1074,64 @@ impl AirDataReference {
                     .clamp(-131072., 131072.),
             );
 
+            let static_pressure = self
+                .blocked_static_pressure
+                .unwrap_or_else(|| context.ambient_pressure());
+
+            // A blocked static port keeps the altitude at the value it had when the port
+            // became blocked, while the computer still considers it valid.
+            let (pressure_alt, baro_alt, vertical_speed) = match self.blocked_static_pressure {
+                Some(blocked_static_pressure) => {
+                    let altitude_error = pressure_altitude(blocked_static_pressure)
+                        - pressure_altitude(context.ambient_pressure());
+                    (
+                        pressure_alt + altitude_error,
+                        baro_alt + altitude_error,
+                        Velocity::default(),
+                    )
+                }
+                None => (pressure_alt, baro_alt, simulator_data.vertical_speed),
+            };
+
             self.corrected_average_static_pressure
-                .set_normal_operation_value(context.ambient_pressure());
+                .set_normal_operation_value(static_pressure);
             self.altitude.set_normal_operation_value(pressure_alt);
             self.baro_corrected_altitude_1
                 .set_normal_operation_value(baro_alt);
             self.baro_corrected_altitude_2
                 .set_normal_operation_value(baro_alt);
             self.barometric_vertical_speed
-                .set_normal_operation_value(simulator_data.vertical_speed.get::<foot_per_minute>());
+                .set_normal_operation_value(vertical_speed.get::<foot_per_minute>());
+
+            let (computed_airspeed, mach, true_airspeed) = if self.has_blocked_probe() {
+                self.air_data_from_blocked_probes(context, static_pressure)
+            } else {
+                (
+                    context.indicated_airspeed(),
+                    simulator_data.mach,
+                    simulator_data.true_airspeed,
+                )
+            };
 
             // If CAS is below 30kn, output as 0 with SSM = NCD
-            let computed_airspeed = context.indicated_airspeed();
             self.computed_airspeed.normal_above_threshold_ncd_otherwise(
                 Velocity::new::<knot>(Self::MINIMUM_CAS),
                 computed_airspeed,
             );
 
             // If mach is below 0.1, output as 0 with SSM = NCD
-            self.mach.normal_above_threshold_ncd_otherwise(
-                MachNumber::from(Self::MINIMUM_MACH),
-                simulator_data.mach,
-            );
+            self.mach
+                .normal_above_threshold_ncd_otherwise(MachNumber::from(Self::MINIMUM_MACH), mach);
 
             // If TAS is below 60 kts, output as 0 kt with SSM = NCD.
             self.true_airspeed.normal_above_threshold_ncd_otherwise(
                 Velocity::new::<knot>(Self::MINIMUM_TAS),
-                simulator_data.true_airspeed,
+                true_airspeed,
             );
 
             self.angle_of_attack.set_value(
-                simulator_data.angle_of_attack,
+                self.jammed_angle_of_attack
+                    .unwrap_or(simulator_data.angle_of_attack),
                 if computed_airspeed < Velocity::new::<knot>(Self::MINIMUM_CAS_FOR_AOA) {
                     SignStatus::NoComputedData
                 } else {
@@ -1008,18 +1146,57 @@ impl AirDataReference {
         }
     }
 
+    /// Computes the CAS, mach and TAS from the pressures sensed by the probes,
+    /// some of which are blocked.
+    fn air_data_from_blocked_probes(
+        &self,
+        context: &UpdateContext,
+        static_pressure: Pressure,
+    ) -> (Velocity, MachNumber, Velocity) {
+        let total_pressure = self.blocked_total_pressure.unwrap_or_else(|| {
+            context.ambient_pressure()
+                + impact_pressure_from_computed_airspeed(context.indicated_airspeed())
+        });
+        let impact_pressure = (total_pressure - static_pressure).max(Pressure::default());
+
+        let computed_airspeed = Velocity::new::<knot>(
+            Self::SEA_LEVEL_SPEED_OF_SOUND_KNOTS
+                * subsonic_mach_from_pressure_ratio(
+                    impact_pressure.get::<hectopascal>() / Self::SEA_LEVEL_PRESSURE_HPA,
+                ),
+        );
+
+        let mach = subsonic_mach_from_pressure_ratio(
+            impact_pressure.get::<hectopascal>() / static_pressure.get::<hectopascal>().max(1.),
+        );
+        let true_airspeed = Velocity::new::<knot>(
+            mach * Self::SPEED_OF_SOUND_KNOTS_PER_SQRT_KELVIN
+                * context.ambient_temperature().get::<kelvin>().sqrt(),
+        );
+
+        (computed_airspeed, MachNumber::from(mach), true_airspeed)
+    }
+
     fn is_initialised(&self) -> bool {
         self.remaining_initialisation_duration == Some(Duration::from_secs(0))
     }
 
     fn is_valid(&self) -> bool {
-        self.is_on && self.is_initialised()
+        self.is_on && self.is_initialised() && !self.failure.is_active()
+    }
+
+    fn has_fault(&self) -> bool {
+        self.failure.is_active()
     }
 
     fn computed_airspeed_raw(&self) -> Velocity {
         self.computed_airspeed.value()
     }
 
+    fn computed_airspeed(&self) -> Arinc429Word<Velocity> {
+        Arinc429Word::new(self.computed_airspeed.value(), self.computed_airspeed.ssm())
+    }
+
     fn altitude(&self) -> Arinc429Word<Length> {
         Arinc429Word::new(self.altitude.value(), self.altitude.ssm())
     }
@@ -1044,6 +1221,15 @@ impl TrueAirspeedSource for AirDataReference {
     }
 }
 impl SimulationElement for AirDataReference {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+        self.pitot_blockage.accept(visitor);
+        self.static_port_blockage.accept(visitor);
+        self.angle_of_attack_vane_jam.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         self.baro_correction_1_hpa
             .write_to_converted(writer, |value| value.get::<hectopascal>());
@@ -1154,9 +1340,24 @@ struct InertialReference {
     wind_speed_bnr: AdirsData<Velocity>,
     /// Label 315, 10 Hz (-180, 180]
     wind_direction_bnr: AdirsData<Angle>,
+    /// The GPIRS hybrid position, or the inertial position corrected by the last known
+    /// GPS position when GPS is lost.
     latitude: AdirsData<Angle>,
     longitude: AdirsData<Angle>,
+    /// The pure inertial position.
+    inertial_latitude: AdirsData<Angle>,
+    inertial_longitude: AdirsData<Angle>,
     maint_word: AdirsData<u32>,
+
+    /// Rate at which the inertial position drifts away from the actual position,
+    /// in nautical miles per hour towards the north and east.
+    drift_rate: Option<Vector2<f64>>,
+    /// Error of the pure inertial position, in nautical miles towards the north and east.
+    inertial_position_error: Vector2<f64>,
+    /// Error of the hybrid position, in nautical miles towards the north and east.
+    hybrid_position_error: Vector2<f64>,
+
+    failure: Failure,
 }
 impl InertialReference {
     const FAST_ALIGNMENT_TIME_IN_SECS: f64 = 90.;
@@ -1187,12 +1388,19 @@ impl InertialReference {
     const WIND_SPEED_BNR: &'static str = "WIND_SPEED_BNR";
     const LATITUDE: &'static str = "LATITUDE";
     const LONGITUDE: &'static str = "LONGITUDE";
+    const INERTIAL_LATITUDE: &'static str = "INERTIAL_LATITUDE";
+    const INERTIAL_LONGITUDE: &'static str = "INERTIAL_LONGITUDE";
     const MAINT_WORD: &'static str = "MAINT_WORD";
     const MINIMUM_TRUE_AIRSPEED_FOR_WIND_DETERMINATION_KNOTS: f64 = 100.;
     const MINIMUM_GROUND_SPEED_FOR_TRACK_KNOTS: f64 = 50.;
 
     const WIND_VELOCITY_TIME_CONSTANT: Duration = Duration::from_millis(100);
 
+    const MEAN_DRIFT_RATE_NM_PER_HOUR: f64 = 1.;
+    const DRIFT_RATE_STANDARD_DEVIATION_NM_PER_HOUR: f64 = 0.3;
+    const MINIMUM_DRIFT_RATE_NM_PER_HOUR: f64 = 0.1;
+    const GPIRS_HYBRIDISATION_TIME_CONSTANT: Duration = Duration::from_secs(60);
+
     fn new(context: &mut InitContext, number: usize) -> Self {
         Self {
             number,
@@ -1231,8 +1439,16 @@ impl InertialReference {
             wind_speed_bnr: AdirsData::new_ir(context, number, Self::WIND_SPEED_BNR),
             latitude: AdirsData::new_ir(context, number, Self::LATITUDE),
             longitude: AdirsData::new_ir(context, number, Self::LONGITUDE),
+            inertial_latitude: AdirsData::new_ir(context, number, Self::INERTIAL_LATITUDE),
+            inertial_longitude: AdirsData::new_ir(context, number, Self::INERTIAL_LONGITUDE),
             /// label 270
             maint_word: AdirsData::new_ir(context, number, Self::MAINT_WORD),
+
+            drift_rate: None,
+            inertial_position_error: Vector2::default(),
+            hybrid_position_error: Vector2::default(),
+
+            failure: Failure::new(FailureType::IrFault(number)),
         }
     }
 
@@ -1243,6 +1459,7 @@ impl InertialReference {
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
         configured_align_time: AlignTime,
         simulator_data: AdirsSimulatorData,
+        gps_available: bool,
     ) {
         self.is_on = overhead.ir_is_on(self.number);
 
@@ -1254,12 +1471,49 @@ impl InertialReference {
             configured_align_time,
             simulator_data,
         );
+        self.update_position_errors(context, gps_available);
 
         self.update_latitude(simulator_data);
         self.update_attitude_values(context, simulator_data);
         self.update_heading_values(overhead, simulator_data);
         self.update_non_attitude_values(context, true_airspeed_source, overhead, simulator_data);
         self.update_maint_word(overhead);
+
+        if self.is_on && self.failure.is_active() {
+            self.set_failure_warning();
+        }
+    }
+
+    /// A failed IR flags all of its outputs as failed, except for the maintenance word
+    /// which reports the fault.
+    fn set_failure_warning(&mut self) {
+        self.pitch.set_failure_warning();
+        self.roll.set_failure_warning();
+        self.heading.set_failure_warning();
+        self.true_heading.set_failure_warning();
+        self.track.set_failure_warning();
+        self.true_track.set_failure_warning();
+        self.drift_angle.set_failure_warning();
+        self.flight_path_angle.set_failure_warning();
+        self.body_pitch_rate.set_failure_warning();
+        self.body_roll_rate.set_failure_warning();
+        self.body_yaw_rate.set_failure_warning();
+        self.body_longitudinal_acc.set_failure_warning();
+        self.body_lateral_acc.set_failure_warning();
+        self.body_normal_acc.set_failure_warning();
+        self.heading_rate.set_failure_warning();
+        self.pitch_att_rate.set_failure_warning();
+        self.roll_att_rate.set_failure_warning();
+        self.vertical_speed.set_failure_warning();
+        self.ground_speed.set_failure_warning();
+        self.wind_speed.set_failure_warning();
+        self.wind_direction.set_failure_warning();
+        self.wind_speed_bnr.set_failure_warning();
+        self.wind_direction_bnr.set_failure_warning();
+        self.latitude.set_failure_warning();
+        self.longitude.set_failure_warning();
+        self.inertial_latitude.set_failure_warning();
+        self.inertial_longitude.set_failure_warning();
     }
 
     fn update_fault_flash_duration(
@@ -1319,6 +1573,44 @@ impl InertialReference {
         };
     }
 
+    /// The inertial position drifts at a constant rate from the moment the IR is aligned.
+    /// While GPS is available the hybrid position converges on the GPS position. Once it is lost,
+    /// the hybrid position drifts away from the last GPS corrected position at the inertial rate.
+    fn update_position_errors(&mut self, context: &UpdateContext, gps_available: bool) {
+        if !self.is_on || !self.is_fully_aligned() {
+            self.drift_rate = None;
+            self.inertial_position_error = Vector2::default();
+            self.hybrid_position_error = Vector2::default();
+            return;
+        }
+
+        let drift_rate = *self
+            .drift_rate
+            .get_or_insert_with(|| Self::random_drift_rate(context));
+        let drift = drift_rate * (context.delta_as_secs_f64() / 3600.);
+
+        self.inertial_position_error += drift;
+        if gps_available {
+            self.hybrid_position_error *= (-context.delta_as_secs_f64()
+                / Self::GPIRS_HYBRIDISATION_TIME_CONSTANT.as_secs_f64())
+            .exp();
+        } else {
+            self.hybrid_position_error += drift;
+        }
+    }
+
+    fn random_drift_rate(context: &UpdateContext) -> Vector2<f64> {
+        let magnitude = context
+            .random_from_normal_distribution(
+                Self::MEAN_DRIFT_RATE_NM_PER_HOUR,
+                Self::DRIFT_RATE_STANDARD_DEVIATION_NM_PER_HOUR,
+            )
+            .max(Self::MINIMUM_DRIFT_RATE_NM_PER_HOUR);
+        let direction = context.random_from_range(0., std::f64::consts::TAU);
+
+        Vector2::new(magnitude * direction.cos(), magnitude * direction.sin())
+    }
+
     fn update_latitude(&mut self, simulator_data: AdirsSimulatorData) {
         let latitude = simulator_data.latitude.get::<degree>();
         let longitude = simulator_data.longitude.get::<degree>();
@@ -1597,8 +1889,21 @@ impl InertialReference {
         self.ground_speed
             .set_value(simulator_data.ground_speed, ssm);
 
-        self.latitude.set_value(simulator_data.latitude, ssm);
-        self.longitude.set_value(simulator_data.longitude, ssm);
+        let (latitude, longitude) = position_with_error(
+            simulator_data.latitude,
+            simulator_data.longitude,
+            self.hybrid_position_error,
+        );
+        self.latitude.set_value(latitude, ssm);
+        self.longitude.set_value(longitude, ssm);
+
+        let (inertial_latitude, inertial_longitude) = position_with_error(
+            simulator_data.latitude,
+            simulator_data.longitude,
+            self.inertial_position_error,
+        );
+        self.inertial_latitude.set_value(inertial_latitude, ssm);
+        self.inertial_longitude.set_value(inertial_longitude, ssm);
 
         self.update_wind_velocity(context, true_airspeed_source, overhead, simulator_data);
     }
@@ -1640,6 +1945,10 @@ impl InertialReference {
 
         // TODO DC fault during DC operation last power up
 
+        if self.failure.is_active() {
+            maint_word |= IrMaintFlags::IR_FAULT;
+        }
+
         // TODO align fault
 
         // TODO No IRS initial pos
@@ -1711,7 +2020,11 @@ impl InertialReference {
     }
 
     fn has_fault(&self) -> bool {
-        self.ir_fault_flash_duration.is_some()
+        self.ir_fault_flash_duration.is_some() || self.has_failed()
+    }
+
+    fn has_failed(&self) -> bool {
+        self.failure.is_active()
     }
 
     fn has_magnetic_data(&self) -> bool {
@@ -1746,6 +2059,12 @@ impl InertialReference {
     }
 }
 impl SimulationElement for InertialReference {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         self.pitch.write_to(writer);
         self.roll.write_to(writer);
@@ -1782,8 +2101,72 @@ impl SimulationElement for InertialReference {
         self.wind_speed_bnr.write_to(writer);
         self.latitude.write_to(writer);
         self.longitude.write_to(writer);
+        self.inertial_latitude.write_to(writer);
+        self.inertial_longitude.write_to(writer);
         self.maint_word.write_to(writer);
     }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write_bool("is_on", self.is_on);
+        writer.write_optional_duration("remaining_align_duration", self.remaining_align_duration);
+        writer.write_optional_duration(
+            "remaining_attitude_initialisation_duration",
+            self.remaining_attitude_initialisation_duration,
+        );
+
+        writer.write_bool("has_drift_rate", self.drift_rate.is_some());
+        let drift_rate = self.drift_rate.unwrap_or_default();
+        writer.write_f64("drift_rate_north", drift_rate.x);
+        writer.write_f64("drift_rate_east", drift_rate.y);
+        writer.write_f64(
+            "inertial_position_error_north",
+            self.inertial_position_error.x,
+        );
+        writer.write_f64(
+            "inertial_position_error_east",
+            self.inertial_position_error.y,
+        );
+        writer.write_f64("hybrid_position_error_north", self.hybrid_position_error.x);
+        writer.write_f64("hybrid_position_error_east", self.hybrid_position_error.y);
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(is_on) = reader.read_bool("is_on") {
+            self.is_on = is_on;
+        }
+
+        if let Some(duration) = reader.read_optional_duration("remaining_align_duration") {
+            self.remaining_align_duration = duration;
+        }
+
+        if let Some(duration) =
+            reader.read_optional_duration("remaining_attitude_initialisation_duration")
+        {
+            self.remaining_attitude_initialisation_duration = duration;
+        }
+
+        if let (Some(has_drift_rate), Some(north), Some(east)) = (
+            reader.read_bool("has_drift_rate"),
+            reader.read_f64("drift_rate_north"),
+            reader.read_f64("drift_rate_east"),
+        ) {
+            self.drift_rate = has_drift_rate.then(|| Vector2::new(north, east));
+        }
+
+        if let (Some(north), Some(east)) = (
+            reader.read_f64("inertial_position_error_north"),
+            reader.read_f64("inertial_position_error_east"),
+        ) {
+            self.inertial_position_error = Vector2::new(north, east);
+        }
+
+        if let (Some(north), Some(east)) = (
+            reader.read_f64("hybrid_position_error_north"),
+            reader.read_f64("hybrid_position_error_east"),
+        ) {
+            self.hybrid_position_error = Vector2::new(north, east);
+        }
+    }
 }
 
 fn remaining_initialisation_duration(
@@ -1805,6 +2188,48 @@ fn subtract_delta_from_duration(context: &UpdateContext, duration: Duration) ->
     Duration::from_secs_f64((duration.as_secs_f64() - context.delta_as_secs_f64()).max(0.))
 }
 
+/// Offsets the position by the given error, in nautical miles towards the north and east.
+fn position_with_error(latitude: Angle, longitude: Angle, error: Vector2<f64>) -> (Angle, Angle) {
+    let latitude_with_error = (latitude + Angle::new::<degree>(error.x / 60.))
+        .max(Angle::new::<degree>(-90.))
+        .min(Angle::new::<degree>(90.));
+
+    let longitude_error =
+        Angle::new::<degree>(error.y / (60. * latitude.cos().get::<ratio>().abs().max(0.01)));
+    let longitude_with_error = (longitude + longitude_error).normalised_180();
+
+    (latitude_with_error, longitude_with_error)
+}
+
+/// The impact pressure (total minus static pressure) which corresponds to the given CAS.
+fn impact_pressure_from_computed_airspeed(computed_airspeed: Velocity) -> Pressure {
+    let speed_ratio =
+        computed_airspeed.get::<knot>().max(0.) / AirDataReference::SEA_LEVEL_SPEED_OF_SOUND_KNOTS;
+
+    Pressure::new::<hectopascal>(
+        AirDataReference::SEA_LEVEL_PRESSURE_HPA
+            * ((1. + 0.2 * speed_ratio.powi(2)).powf(3.5) - 1.),
+    )
+}
+
+/// Solves the subsonic compressible flow equation for the mach number, given the ratio of
+/// impact pressure to the static pressure it is measured against.
+fn subsonic_mach_from_pressure_ratio(impact_pressure_ratio: f64) -> f64 {
+    (5. * ((impact_pressure_ratio.max(0.) + 1.).powf(2. / 7.) - 1.)).sqrt()
+}
+
+/// The altitude at which the standard atmosphere has the given static pressure.
+fn pressure_altitude(static_pressure: Pressure) -> Length {
+    Length::new::<foot>(
+        145366.45
+            * (1.
+                - (static_pressure.get::<hectopascal>()
+                    / AirDataReference::SEA_LEVEL_PRESSURE_HPA)
+                    .max(0.)
+                    .powf(0.190284)),
+    )
+}
+
 trait NormaliseAngleExt {
     fn normalised(self) -> Angle;
     fn normalised_180(self) -> Angle;
@@ -2098,6 +2523,13 @@ mod tests {
             )))
         }
 
+        fn adr_fault_light_illuminated(&mut self, number: usize) -> bool {
+            self.read_by_name(&OnOffFaultPushButton::has_fault_id(&format!(
+                "ADIRS_ADR_{}",
+                number
+            )))
+        }
+
         fn is_aligned(&mut self, adiru_number: usize) -> bool {
             self.align_state(adiru_number) == AlignState::Aligned
         }
@@ -2469,6 +2901,66 @@ mod tests {
             ))
         }
 
+        fn inertial_latitude(&mut self, adiru_number: usize) -> Arinc429Word<Angle> {
+            self.read_arinc429_by_name(&output_data_id(
+                OutputDataType::Ir,
+                adiru_number,
+                InertialReference::INERTIAL_LATITUDE,
+            ))
+        }
+
+        fn inertial_longitude(&mut self, adiru_number: usize) -> Arinc429Word<Angle> {
+            self.read_arinc429_by_name(&output_data_id(
+                OutputDataType::Ir,
+                adiru_number,
+                InertialReference::INERTIAL_LONGITUDE,
+            ))
+        }
+
+        /// The distance between the position output by the IR and the given actual position.
+        fn position_error_nm(
+            latitude: Arinc429Word<Angle>,
+            longitude: Arinc429Word<Angle>,
+            actual_latitude: Angle,
+            actual_longitude: Angle,
+        ) -> f64 {
+            let north = (latitude.value() - actual_latitude).get::<degree>() * 60.;
+            let east = (longitude.value() - actual_longitude).get::<degree>()
+                * 60.
+                * actual_latitude.cos().get::<ratio>();
+
+            Vector2::new(north, east).magnitude()
+        }
+
+        fn hybrid_position_error_nm(
+            &mut self,
+            adiru_number: usize,
+            actual_latitude: Angle,
+            actual_longitude: Angle,
+        ) -> f64 {
+            let latitude = self.latitude(adiru_number);
+            let longitude = self.longitude(adiru_number);
+            Self::position_error_nm(latitude, longitude, actual_latitude, actual_longitude)
+        }
+
+        fn inertial_position_error_nm(
+            &mut self,
+            adiru_number: usize,
+            actual_latitude: Angle,
+            actual_longitude: Angle,
+        ) -> f64 {
+            let latitude = self.inertial_latitude(adiru_number);
+            let longitude = self.inertial_longitude(adiru_number);
+            Self::position_error_nm(latitude, longitude, actual_latitude, actual_longitude)
+        }
+
+        fn run_for(&mut self, duration: Duration) {
+            let minutes = duration.as_secs() / 60;
+            for _ in 0..minutes {
+                self.run_with_delta(Duration::from_secs(60));
+            }
+        }
+
         fn maint_word(&mut self, adiru_number: usize) -> Arinc429Word<u32> {
             self.read_arinc429_by_name(&output_data_id(
                 OutputDataType::Ir,
@@ -2971,6 +3463,25 @@ mod tests {
         assert!(test_bed.remaining_alignment_time() > single_adiru_remaining_alignment_time);
     }
 
+    #[test]
+    fn restoring_a_snapshot_restores_alignment_progress() {
+        let mut test_bed =
+            test_bed_with().ir_mode_selector_set_to(1, InertialReferenceMode::Navigation);
+        test_bed.run_without_delta();
+        test_bed.run_with_delta(Duration::from_secs(60));
+        let remaining_alignment_time = test_bed.remaining_alignment_time();
+        let snapshot = test_bed.snapshot();
+
+        test_bed.run_with_delta(Duration::from_secs(60));
+        test_bed.restore(&snapshot).unwrap();
+        test_bed.run_without_delta();
+
+        assert_about_eq!(
+            test_bed.remaining_alignment_time().as_secs_f64(),
+            remaining_alignment_time.as_secs_f64()
+        );
+    }
+
     #[test]
     fn remaining_alignment_time_is_greater_than_zero_when_a_single_adiru_is_aligned_but_another_is_still_aligning(
     ) {
@@ -3366,6 +3877,185 @@ mod tests {
                 SignStatus::NoComputedData
             );
         }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn adr_fault_makes_only_the_failed_adr_data_invalid(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::AdrFault(adiru_number));
+            test_bed.run();
+
+            for number in 1..=3 {
+                test_bed.assert_adr_data_valid(number != adiru_number, number);
+            }
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn adr_fault_illuminates_the_adr_fault_light(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.run();
+            assert!(!test_bed.adr_fault_light_illuminated(adiru_number));
+
+            test_bed.fail(FailureType::AdrFault(adiru_number));
+            test_bed.run();
+            assert!(test_bed.adr_fault_light_illuminated(adiru_number));
+
+            test_bed.unfail(FailureType::AdrFault(adiru_number));
+            test_bed.run();
+            assert!(!test_bed.adr_fault_light_illuminated(adiru_number));
+            test_bed.assert_adr_data_valid(true, adiru_number);
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn blocked_pitot_holds_the_airspeed_when_the_aircraft_slows_down(
+            #[case] adiru_number: usize,
+        ) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.set_ambient_pressure(Pressure::new::<hectopascal>(697.));
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+            test_bed.run();
+
+            test_bed.fail(FailureType::PitotBlockage(adiru_number));
+            test_bed.run();
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(200.));
+            test_bed.run();
+
+            let computed_airspeed = test_bed.computed_airspeed(adiru_number);
+            assert!(computed_airspeed.is_normal_operation());
+            assert!((computed_airspeed.value().get::<knot>() - 250.).abs() < 0.1);
+
+            let other_adiru = adiru_number % 3 + 1;
+            assert_about_eq!(
+                test_bed
+                    .computed_airspeed(other_adiru)
+                    .normal_value()
+                    .unwrap()
+                    .get::<knot>(),
+                200.
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn blocked_pitot_increases_the_airspeed_in_a_climb(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.set_ambient_pressure(Pressure::new::<hectopascal>(697.));
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+            test_bed.run();
+
+            test_bed.fail(FailureType::PitotBlockage(adiru_number));
+            test_bed.run();
+            test_bed.set_ambient_pressure(Pressure::new::<hectopascal>(644.));
+            test_bed.run();
+
+            let computed_airspeed = test_bed.computed_airspeed(adiru_number);
+            assert!(computed_airspeed.is_normal_operation());
+            assert!(computed_airspeed.value() > Velocity::new::<knot>(270.));
+            assert!(test_bed.mach(adiru_number).is_normal_operation());
+            assert!(test_bed.true_airspeed(adiru_number).is_normal_operation());
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn blocked_static_port_holds_the_altitude_in_a_climb(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .vertical_speed_of(Velocity::new::<foot_per_minute>(2000.));
+            test_bed.set_ambient_pressure(Pressure::new::<hectopascal>(696.8));
+            test_bed.set_pressure_altitude(Length::new::<foot>(10000.));
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+            test_bed.run();
+
+            test_bed.fail(FailureType::StaticPortBlockage(adiru_number));
+            test_bed.run();
+            test_bed.set_ambient_pressure(Pressure::new::<hectopascal>(644.4));
+            test_bed.set_pressure_altitude(Length::new::<foot>(12000.));
+            test_bed.run();
+
+            let altitude = test_bed.altitude(adiru_number);
+            assert!(altitude.is_normal_operation());
+            assert!((altitude.value().get::<foot>() - 10000.).abs() < 10.);
+            assert_about_eq!(
+                test_bed
+                    .barometric_vertical_speed(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<foot_per_minute>(),
+                0.
+            );
+            assert_about_eq!(
+                test_bed
+                    .corrected_average_static_pressure(adiru_number)
+                    .normal_value()
+                    .unwrap(),
+                696.8,
+                // ARINC 429 words carry their value as f32.
+                1e-3
+            );
+            assert!(
+                test_bed
+                    .computed_airspeed(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    < Velocity::new::<knot>(250.)
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn cleared_static_port_blockage_restores_the_altitude(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.set_ambient_pressure(Pressure::new::<hectopascal>(696.8));
+            test_bed.set_pressure_altitude(Length::new::<foot>(10000.));
+            test_bed.fail(FailureType::StaticPortBlockage(adiru_number));
+            test_bed.run();
+
+            test_bed.set_ambient_pressure(Pressure::new::<hectopascal>(644.4));
+            test_bed.set_pressure_altitude(Length::new::<foot>(12000.));
+            test_bed.unfail(FailureType::StaticPortBlockage(adiru_number));
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.altitude(adiru_number).normal_value().unwrap(),
+                Length::new::<foot>(12000.)
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn jammed_angle_of_attack_vane_holds_the_angle_of_attack(#[case] adiru_number: usize) {
+            let mut test_bed =
+                all_adirus_aligned_test_bed_with().angle_of_attack_of(Angle::new::<degree>(3.));
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(200.));
+            test_bed.fail(FailureType::AngleOfAttackVaneJam(adiru_number));
+            test_bed.run();
+
+            test_bed = test_bed.angle_of_attack_of(Angle::new::<degree>(12.));
+            test_bed.run();
+
+            assert_eq!(
+                test_bed
+                    .angle_of_attack(adiru_number)
+                    .normal_value()
+                    .unwrap(),
+                Angle::new::<degree>(3.)
+            );
+        }
     }
 
     mod ir {
@@ -4188,6 +4878,46 @@ mod tests {
                 longitude
             );
         }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn ir_fault_makes_all_ir_data_failure_warning(#[case] adiru_number: usize) {
+            let mut test_bed =
+                all_adirus_aligned_test_bed_with().true_airspeed_of(Velocity::new::<knot>(
+                    InertialReference::MINIMUM_TRUE_AIRSPEED_FOR_WIND_DETERMINATION_KNOTS + 0.01,
+                ));
+            test_bed.fail(FailureType::IrFault(adiru_number));
+            test_bed.run();
+
+            test_bed.assert_all_ir_data_available(false, adiru_number);
+            assert!(test_bed.pitch(adiru_number).is_failure_warning());
+            assert!(test_bed.latitude(adiru_number).is_failure_warning());
+
+            let other_adiru = adiru_number % 3 + 1;
+            test_bed.assert_all_ir_data_available(true, other_adiru);
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn ir_fault_is_reported_in_the_maint_word_and_by_the_fault_light(
+            #[case] adiru_number: usize,
+        ) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::IrFault(adiru_number));
+            test_bed.run();
+
+            let maint_word = test_bed.maint_word(adiru_number);
+            assert!(maint_word.is_normal_operation());
+            assert_eq!(
+                IrMaintFlags::from_bits(maint_word.value()).unwrap() & IrMaintFlags::IR_FAULT,
+                IrMaintFlags::IR_FAULT
+            );
+            assert!(test_bed.ir_fault_light_illuminated(adiru_number));
+        }
     }
 
     mod gps {
@@ -4198,7 +4928,7 @@ mod tests {
         #[case(2)]
         #[case(3)]
         fn uses_gps_as_primary_when_any_adiru_is_aligned(#[case] adiru_number: usize) {
-            // The GPSSU is for now assumed to always work. Thus, when any ADIRU is aligned
+            // When any ADIRU is aligned and supplied by a working GPS receiver,
             // GPS is used as the primary means of navigation.
             let mut test_bed = test_bed_with()
                 .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Navigation)
@@ -4207,6 +4937,103 @@ mod tests {
             assert!(test_bed.uses_gps_as_primary());
         }
 
+        fn positioned_test_bed() -> (AdirsTestBed, Angle, Angle) {
+            let latitude = Angle::new::<degree>(45.);
+            let longitude = Angle::new::<degree>(10.);
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(latitude)
+                .longitude_of(longitude);
+            test_bed.run();
+
+            (test_bed, latitude, longitude)
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn inertial_position_drifts_with_time_since_alignment(#[case] adiru_number: usize) {
+            let (mut test_bed, latitude, longitude) = positioned_test_bed();
+
+            test_bed.run_for(Duration::from_secs(30 * 60));
+            let error_after_half_an_hour =
+                test_bed.inertial_position_error_nm(adiru_number, latitude, longitude);
+
+            test_bed.run_for(Duration::from_secs(30 * 60));
+            let error_after_an_hour =
+                test_bed.inertial_position_error_nm(adiru_number, latitude, longitude);
+
+            assert!(error_after_half_an_hour > 0.);
+            assert!(error_after_an_hour > error_after_half_an_hour);
+            assert!(error_after_an_hour < 3.);
+            assert!(test_bed
+                .inertial_latitude(adiru_number)
+                .is_normal_operation());
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn hybrid_position_is_accurate_when_gps_is_available(#[case] adiru_number: usize) {
+            let (mut test_bed, latitude, longitude) = positioned_test_bed();
+
+            test_bed.run_for(Duration::from_secs(60 * 60));
+
+            assert!(test_bed.hybrid_position_error_nm(adiru_number, latitude, longitude) < 0.01);
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn hybrid_position_drifts_when_gps_is_lost(#[case] adiru_number: usize) {
+            let (mut test_bed, latitude, longitude) = positioned_test_bed();
+            test_bed.fail(FailureType::GpsReceiver(1));
+            test_bed.fail(FailureType::GpsReceiver(2));
+
+            test_bed.run_for(Duration::from_secs(60 * 60));
+
+            let error = test_bed.hybrid_position_error_nm(adiru_number, latitude, longitude);
+            assert!(error > 0.05);
+            assert!(error < 3.);
+            assert!(test_bed.latitude(adiru_number).is_normal_operation());
+            assert!(!test_bed.uses_gps_as_primary());
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn hybrid_position_converges_on_gps_position_when_gps_is_regained(
+            #[case] adiru_number: usize,
+        ) {
+            let (mut test_bed, latitude, longitude) = positioned_test_bed();
+            test_bed.fail(FailureType::GpsReceiver(1));
+            test_bed.fail(FailureType::GpsReceiver(2));
+            test_bed.run_for(Duration::from_secs(60 * 60));
+
+            test_bed.unfail(FailureType::GpsReceiver(1));
+            test_bed.unfail(FailureType::GpsReceiver(2));
+            test_bed.run_for(Duration::from_secs(10 * 60));
+
+            assert!(test_bed.hybrid_position_error_nm(adiru_number, latitude, longitude) < 0.01);
+            assert!(test_bed.uses_gps_as_primary());
+        }
+
+        #[test]
+        fn adiru_2_is_supplied_by_gps_receiver_2() {
+            let (mut test_bed, latitude, longitude) = positioned_test_bed();
+            test_bed.fail(FailureType::GpsReceiver(1));
+
+            test_bed.run_for(Duration::from_secs(60 * 60));
+
+            assert!(test_bed.hybrid_position_error_nm(1, latitude, longitude) > 0.05);
+            assert!(test_bed.hybrid_position_error_nm(2, latitude, longitude) < 0.01);
+            assert!(test_bed.hybrid_position_error_nm(3, latitude, longitude) > 0.05);
+            assert!(test_bed.uses_gps_as_primary());
+        }
+
         #[test]
         fn does_not_use_gps_as_primary_when_no_adiru_is_aligned() {
             let mut test_bed = test_bed();
@@ -0,0 +1,295 @@
+use std::time::Duration;
+
+use uom::si::{
+    f64::*, mass_rate::kilogram_per_second, temperature_interval,
+    thermodynamic_temperature::degree_celsius,
+};
+
+use crate::{
+    failures::{Failure, FailureType},
+    pneumatic::{valve::PneumaticExhaust, PneumaticContainer},
+    simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+
+/// A section of bleed duct which is surrounded by an overheat detection loop.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BleedDuct {
+    /// The duct in the pylon of the given engine, upstream of the precooler.
+    Engine(usize),
+    /// The duct running through the wing and the belly fairing, 1 being the left wing.
+    Wing(usize),
+    /// The duct from the APU to the crossbleed duct.
+    Apu,
+}
+
+pub trait BleedLeakDetection {
+    /// Indicates if the overheat detection loop of the given duct detects an overheat.
+    fn has_overheat(&self, duct: BleedDuct) -> bool;
+}
+
+/// A leak in a bleed duct, which releases the hot air of the duct into the
+/// surrounding compartment when failed.
+pub struct BleedDuctLeak {
+    failure: Failure,
+    exhaust: PneumaticExhaust,
+    flow: MassRate,
+    air_temperature: ThermodynamicTemperature,
+}
+impl BleedDuctLeak {
+    const EXHAUST_SPEED: f64 = 0.1;
+
+    pub fn new(duct: BleedDuct) -> Self {
+        Self {
+            failure: Failure::new(FailureType::BleedDuctLeak(duct)),
+            exhaust: PneumaticExhaust::new(
+                Self::EXHAUST_SPEED,
+                Self::EXHAUST_SPEED,
+                Pressure::default(),
+            ),
+            flow: MassRate::default(),
+            air_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+        }
+    }
+
+    pub fn update_move_fluid(
+        &mut self,
+        context: &UpdateContext,
+        from: &mut impl PneumaticContainer,
+    ) {
+        if self.failure.is_active() {
+            self.air_temperature = from.temperature();
+            self.exhaust.update_move_fluid(context, from);
+            self.flow = self.exhaust.fluid_flow().abs();
+        } else {
+            self.air_temperature = context.ambient_temperature();
+            self.flow = MassRate::default();
+        }
+    }
+
+    pub fn flow(&self) -> MassRate {
+        self.flow
+    }
+
+    pub fn air_temperature(&self) -> ThermodynamicTemperature {
+        self.air_temperature
+    }
+}
+impl SimulationElement for BleedDuctLeak {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// A continuous overheat detection loop running alongside a bleed duct. Air leaking from the
+/// duct heats the surrounding compartment, until the loop's alarm temperature is reached.
+pub struct OverheatDetectionLoop {
+    alarm_temperature: ThermodynamicTemperature,
+    compartment_temperature: ThermodynamicTemperature,
+    has_overheat: bool,
+}
+impl OverheatDetectionLoop {
+    const COMPARTMENT_HEATING_TIME_CONSTANT: Duration = Duration::from_secs(10);
+    /// Leaks of at least this flow heat the compartment up to the temperature of the leaking air.
+    const FULL_HEATING_LEAK_FLOW_KG_PER_S: f64 = 0.1;
+    const ALARM_RESET_HYSTERESIS_C: f64 = 10.;
+
+    pub fn new(alarm_temperature: ThermodynamicTemperature) -> Self {
+        Self {
+            alarm_temperature,
+            compartment_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            has_overheat: false,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, leak: &BleedDuctLeak) {
+        let heating_ratio = (leak.flow().get::<kilogram_per_second>()
+            / Self::FULL_HEATING_LEAK_FLOW_KG_PER_S)
+            .min(1.);
+        let ambient_temperature = context.ambient_temperature().get::<degree_celsius>();
+        let target_temperature = ambient_temperature
+            + (leak.air_temperature().get::<degree_celsius>() - ambient_temperature)
+                * heating_ratio;
+
+        let temperature_change = (target_temperature
+            - self.compartment_temperature.get::<degree_celsius>())
+            * (1.
+                - (-context.delta_as_secs_f64()
+                    / Self::COMPARTMENT_HEATING_TIME_CONSTANT.as_secs_f64())
+                .exp());
+        self.compartment_temperature +=
+            TemperatureInterval::new::<temperature_interval::degree_celsius>(temperature_change);
+
+        let alarm_temperature = self.alarm_temperature.get::<degree_celsius>();
+        let compartment_temperature = self.compartment_temperature.get::<degree_celsius>();
+        self.has_overheat = if self.has_overheat {
+            compartment_temperature > alarm_temperature - Self::ALARM_RESET_HYSTERESIS_C
+        } else {
+            compartment_temperature > alarm_temperature
+        };
+    }
+
+    pub fn has_overheat(&self) -> bool {
+        self.has_overheat
+    }
+}
+
+/// Latches a leak detected by an overheat detection loop. Once the loop no longer detects an
+/// overheat, the leak is reset by switching off the bleed source feeding the duct.
+#[derive(Default)]
+pub struct BleedLeakMonitor {
+    has_leak: bool,
+}
+impl BleedLeakMonitor {
+    pub fn update(&mut self, has_overheat: bool, reset: bool) {
+        self.has_leak = has_overheat || (self.has_leak && !reset);
+    }
+
+    pub fn has_leak(&self) -> bool {
+        self.has_leak
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        pneumatic::{CompressionChamber, TargetPressureTemperatureSignal},
+        shared::ControllerSignal,
+        simulation::{
+            test::{SimulationTestBed, TestBed},
+            Aircraft, InitContext,
+        },
+    };
+    use uom::si::{pressure::psi, volume::cubic_meter};
+
+    struct ConstantBleedAirController;
+    impl ControllerSignal<TargetPressureTemperatureSignal> for ConstantBleedAirController {
+        fn signal(&self) -> Option<TargetPressureTemperatureSignal> {
+            Some(TargetPressureTemperatureSignal::new(
+                Pressure::new::<psi>(50.),
+                ThermodynamicTemperature::new::<degree_celsius>(200.),
+            ))
+        }
+    }
+
+    struct TestAircraft {
+        duct: CompressionChamber,
+        leak: BleedDuctLeak,
+        detection_loop: OverheatDetectionLoop,
+        monitor: BleedLeakMonitor,
+        is_bleed_off: bool,
+    }
+    impl TestAircraft {
+        fn new(_: &mut InitContext) -> Self {
+            Self {
+                duct: CompressionChamber::new(Volume::new::<cubic_meter>(1.)),
+                leak: BleedDuctLeak::new(BleedDuct::Wing(1)),
+                detection_loop: OverheatDetectionLoop::new(ThermodynamicTemperature::new::<
+                    degree_celsius,
+                >(124.)),
+                monitor: BleedLeakMonitor::default(),
+                is_bleed_off: false,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            if !self.is_bleed_off {
+                self.duct.update(&ConstantBleedAirController);
+            }
+            self.leak.update_move_fluid(context, &mut self.duct);
+            self.detection_loop.update(context, &self.leak);
+            self.monitor
+                .update(self.detection_loop.has_overheat(), self.is_bleed_off);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.leak.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    fn run_for(test_bed: &mut SimulationTestBed<TestAircraft>, duration: Duration) {
+        test_bed.run_multiple_frames(duration);
+    }
+
+    #[test]
+    fn duct_without_failure_does_not_leak() {
+        let mut test_bed = test_bed();
+        run_for(&mut test_bed, Duration::from_secs(60));
+
+        assert_eq!(test_bed.query(|a| a.leak.flow()), MassRate::default());
+        assert!(!test_bed.query(|a| a.detection_loop.has_overheat()));
+        assert!(!test_bed.query(|a| a.monitor.has_leak()));
+    }
+
+    #[test]
+    fn failed_duct_leaks_hot_air() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuct::Wing(1)));
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.leak.flow()) > MassRate::default());
+        assert!(
+            test_bed.query(|a| a.leak.air_temperature())
+                > ThermodynamicTemperature::new::<degree_celsius>(50.)
+        );
+    }
+
+    #[test]
+    fn leak_is_only_detected_after_the_compartment_heated_up() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuct::Wing(1)));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.detection_loop.has_overheat()));
+
+        run_for(&mut test_bed, Duration::from_secs(30));
+
+        assert!(test_bed.query(|a| a.detection_loop.has_overheat()));
+        assert!(test_bed.query(|a| a.monitor.has_leak()));
+    }
+
+    #[test]
+    fn leak_of_another_duct_is_not_detected() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuct::Wing(2)));
+        run_for(&mut test_bed, Duration::from_secs(60));
+
+        assert!(!test_bed.query(|a| a.monitor.has_leak()));
+    }
+
+    #[test]
+    fn detected_leak_remains_latched_while_bleed_is_supplied() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuct::Wing(1)));
+        run_for(&mut test_bed, Duration::from_secs(30));
+
+        test_bed.unfail(FailureType::BleedDuctLeak(BleedDuct::Wing(1)));
+        run_for(&mut test_bed, Duration::from_secs(120));
+
+        assert!(!test_bed.query(|a| a.detection_loop.has_overheat()));
+        assert!(test_bed.query(|a| a.monitor.has_leak()));
+    }
+
+    #[test]
+    fn detected_leak_resets_when_bleed_is_switched_off_after_the_compartment_cooled_down() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuct::Wing(1)));
+        run_for(&mut test_bed, Duration::from_secs(30));
+
+        test_bed.unfail(FailureType::BleedDuctLeak(BleedDuct::Wing(1)));
+        test_bed.command(|a| a.is_bleed_off = true);
+        run_for(&mut test_bed, Duration::from_secs(120));
+
+        assert!(!test_bed.query(|a| a.monitor.has_leak()));
+    }
+}
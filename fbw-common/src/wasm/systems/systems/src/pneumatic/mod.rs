@@ -21,6 +21,8 @@ use uom::si::{
     volume::cubic_meter,
 };
 
+mod leak_detection;
+pub use leak_detection::*;
 pub mod valve;
 
 pub trait PneumaticValveSignal {
@@ -192,6 +192,19 @@ pub enum GearActuatorId {
     GearDoorRight,
 }
 
+/// Servo actuator of a flight control surface. Actuators are numbered from 1 on their
+/// surface, in the order the hydraulic controllers of the aircraft list them.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FlightControlActuatorId {
+    LeftAileron(usize),
+    RightAileron(usize),
+    LeftElevator(usize),
+    RightElevator(usize),
+    Rudder(usize),
+    LeftSpoiler(usize),
+    RightSpoiler(usize),
+}
+
 pub trait EngineCorrectedN1 {
     fn corrected_n1(&self) -> Ratio;
 }
@@ -247,6 +260,8 @@ pub trait AdirsMeasurementOutputs {
     fn true_heading(&self, adiru_number: usize) -> Arinc429Word<Angle>;
     fn vertical_speed(&self, adiru_number: usize) -> Arinc429Word<Velocity>;
     fn altitude(&self, adiru_number: usize) -> Arinc429Word<Length>;
+    fn computed_airspeed(&self, adiru_number: usize) -> Arinc429Word<Velocity>;
+    fn ground_speed(&self, adiru_number: usize) -> Arinc429Word<Velocity>;
 }
 
 pub trait AdirsDiscreteOutputs {
@@ -256,12 +271,40 @@ pub trait AdirsDiscreteOutputs {
     fn low_speed_warning_4_260kts(&self, adiru_number: usize) -> bool;
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum GearWheel {
     NOSE = 0,
     LEFT = 1,
     RIGHT = 2,
 }
 
+pub trait BrakeTemperatures {
+    fn brake_temperature(&self, brake_number: usize) -> ThermodynamicTemperature;
+}
+
+/// Fraction of the braking force which the tires of a gear can transmit to the ground.
+pub trait BrakingEffectiveness {
+    fn braking_effectiveness(&self, gear: GearWheel) -> Ratio;
+}
+
+pub trait TirePressures {
+    fn tire_count(&self) -> usize;
+    fn tire_pressure(&self, tire_number: usize) -> Pressure;
+    fn tire_nominal_pressure(&self, tire_number: usize) -> Pressure;
+}
+
+/// Wheel speeds measured by the tachometers of an aircraft, numbered from 1.
+/// A wheel without a valid measurement has no speed.
+pub trait WheelSpeeds {
+    fn wheel_count(&self) -> usize;
+    fn wheel_speed(&self, wheel_number: usize) -> Option<Velocity>;
+}
+
+/// Slip of the braked wheels relative to the ground, numbered as their wheel speed sensors.
+pub trait WheelSlipRatios {
+    fn slip_ratio(&self, wheel_number: usize) -> Ratio;
+}
+
 pub trait SectionPressure {
     fn pressure(&self) -> Pressure;
     fn pressure_downstream_leak_valve(&self) -> Pressure;
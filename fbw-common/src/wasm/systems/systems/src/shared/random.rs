@@ -1,80 +1,232 @@
-#[cfg(any(target_arch = "wasm32", doc))]
-pub use wasm::*;
-
-#[cfg(any(target_arch = "wasm32", doc))]
-mod wasm {
-    use rand::rngs::SmallRng;
-    use rand::{Rng, SeedableRng};
-    use rand_distr::{Distribution, Normal};
-    use std::mem::MaybeUninit;
-    use std::sync::Once;
-
-    static RAND_INIT: Once = Once::new();
-    static mut RAND: MaybeUninit<SmallRng> = MaybeUninit::uninit();
-
-    pub fn random_number() -> u8 {
-        // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
-        RAND_INIT.call_once(|| unsafe {
-            RAND = MaybeUninit::new(SmallRng::from_entropy());
-        });
+use rand::{Error, Rng, RngCore, SeedableRng};
+use rand_distr::{Distribution, Normal};
+use rand_pcg::Pcg32;
+use std::cell::RefCell;
+
+thread_local! {
+    /// The generator of the simulation which is currently running on this thread.
+    /// When no simulation is running, a generator seeded from entropy is used.
+    static ACTIVE_GENERATOR: RefCell<Option<CountingPcg32>> = RefCell::new(None);
+}
+
+/// A [`Pcg32`] which counts the steps it took, such that it can be brought back to the
+/// same position by advancing a generator with the same seed by that number of steps.
+#[derive(Clone)]
+struct CountingPcg32 {
+    generator: Pcg32,
+    steps: u64,
+}
+impl CountingPcg32 {
+    fn new(seed: u64, steps: u64) -> Self {
+        let mut generator = Pcg32::seed_from_u64(seed);
+        generator.advance(steps);
+
+        Self { generator, steps }
+    }
+}
+impl RngCore for CountingPcg32 {
+    fn next_u32(&mut self) -> u32 {
+        self.steps += 1;
+        self.generator.next_u32()
+    }
 
-        // SAFETY: `RAND` was initialized above.
-        unsafe { (*RAND.as_mut_ptr()).gen() }
+    fn next_u64(&mut self) -> u64 {
+        // A 64 bit value is made up of two consecutive 32 bit values.
+        self.steps += 2;
+        self.generator.next_u64()
     }
 
-    pub fn random_from_range(from: f64, to: f64) -> f64 {
-        // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
-        RAND_INIT.call_once(|| unsafe {
-            RAND = MaybeUninit::new(SmallRng::from_entropy());
-        });
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(4) {
+            chunk.copy_from_slice(&self.next_u32().to_le_bytes()[..chunk.len()]);
+        }
+    }
 
-        // SAFETY: `RAND` was initialized above.
-        unsafe { (*RAND.as_mut_ptr()).gen_range(from..to) }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
     }
+}
 
-    // Generates a random number based on normal distribution
-    pub fn random_from_normal_distribution(mean: f64, std_dev: f64) -> f64 {
-        // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
-        RAND_INIT.call_once(|| unsafe {
-            RAND = MaybeUninit::new(SmallRng::from_entropy());
-        });
+/// The position of a [`SimulationRandom`], from which it continues drawing the same
+/// random numbers once restored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SimulationRandomState {
+    pub(crate) seed: u64,
+    pub(crate) steps: u64,
+}
+
+/// The random number generator owned by a simulation.
+///
+/// All random numbers requested through [`random_number`], [`random_from_range`] and
+/// [`random_from_normal_distribution`] while the generator is active are drawn from it.
+/// A simulation created with the same seed therefore draws the same random numbers.
+pub struct SimulationRandom {
+    seed: u64,
+    generator: CountingPcg32,
+}
+impl SimulationRandom {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            generator: CountingPcg32::new(seed, 0),
+        }
+    }
+
+    /// Returns a seed taken from the entropy source of the system.
+    pub fn entropy_seed() -> u64 {
+        Pcg32::from_entropy().gen()
+    }
 
-        let normal = Normal::new(mean, std_dev).unwrap();
-        let limit_offset = 4. * std_dev;
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
 
-        // SAFETY: `RAND` was initialized above.
-        unsafe {
-            normal
-                .sample(&mut *RAND.as_mut_ptr())
-                .max(mean - limit_offset)
-                .min(mean + limit_offset)
+    pub fn state(&self) -> SimulationRandomState {
+        SimulationRandomState {
+            seed: self.seed,
+            steps: self.generator.steps,
         }
     }
+
+    /// Moves the generator to the given position, which may have been taken from a
+    /// generator with another seed.
+    pub fn restore(&mut self, state: SimulationRandomState) {
+        self.seed = state.seed;
+        self.generator = CountingPcg32::new(state.seed, state.steps);
+    }
+
+    /// Runs the given function with this generator as the source of all random numbers.
+    /// The previously active generator is restored afterwards.
+    pub fn activate<T, U: FnOnce() -> T>(&mut self, func: U) -> T {
+        let previous = ACTIVE_GENERATOR.with(|active| active.replace(Some(self.generator.clone())));
+
+        let result = (func)();
+
+        self.generator = ACTIVE_GENERATOR
+            .with(|active| active.replace(previous))
+            .expect("The simulation random generator was removed while it was active.");
+
+        result
+    }
+}
+
+fn with_active_generator<T, U: FnOnce(&mut CountingPcg32) -> T>(func: U) -> T {
+    ACTIVE_GENERATOR.with(|active| {
+        let mut active = active.borrow_mut();
+        (func)(
+            active.get_or_insert_with(|| CountingPcg32::new(SimulationRandom::entropy_seed(), 0)),
+        )
+    })
+}
+
+pub fn random_number() -> u8 {
+    with_active_generator(|generator| generator.gen())
+}
+
+pub fn random_from_range(from: f64, to: f64) -> f64 {
+    with_active_generator(|generator| generator.gen_range(from..to))
 }
 
-#[cfg(not(any(target_arch = "wasm32", doc)))]
-pub use not_wasm::*;
+/// Random value from normal distribution. Output limited to -4 / +4 sigma
+pub fn random_from_normal_distribution(mean: f64, std_dev: f64) -> f64 {
+    let normal = Normal::new(mean, std_dev).unwrap();
+    let limit_offset = 4. * std_dev;
+
+    with_active_generator(|generator| normal.sample(generator))
+        .max(mean - limit_offset)
+        .min(mean + limit_offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw(random: &mut SimulationRandom) -> Vec<f64> {
+        draw_n(random, 10)
+    }
+
+    fn draw_n(random: &mut SimulationRandom, count: usize) -> Vec<f64> {
+        random.activate(|| {
+            (0..count)
+                .map(|_| {
+                    random_number() as f64
+                        + random_from_range(0., 1.)
+                        + random_from_normal_distribution(10., 2.)
+                })
+                .collect()
+        })
+    }
+
+    #[test]
+    fn same_seed_draws_same_numbers() {
+        assert_eq!(
+            draw(&mut SimulationRandom::new(42)),
+            draw(&mut SimulationRandom::new(42))
+        );
+    }
+
+    #[test]
+    fn different_seeds_draw_different_numbers() {
+        assert_ne!(
+            draw(&mut SimulationRandom::new(42)),
+            draw(&mut SimulationRandom::new(43))
+        );
+    }
+
+    #[test]
+    fn generator_continues_where_it_left_off() {
+        let mut random = SimulationRandom::new(42);
+        let first = draw(&mut random);
+        let second = draw(&mut random);
+
+        assert_eq!(
+            [first, second].concat(),
+            draw_n(&mut SimulationRandom::new(42), 20)
+        );
+    }
+
+    #[test]
+    fn restored_generator_continues_from_the_restored_position() {
+        let mut random = SimulationRandom::new(42);
+        draw(&mut random);
+        let state = random.state();
+        let expected = draw(&mut random);
 
-#[cfg(not(any(target_arch = "wasm32", doc)))]
-mod not_wasm {
-    use rand::Rng;
-    use rand_distr::{Distribution, Normal};
+        let mut restored = SimulationRandom::new(7);
+        restored.restore(state);
 
-    pub fn random_number() -> u8 {
-        rand::thread_rng().gen()
+        assert_eq!(restored.seed(), 42);
+        assert_eq!(draw(&mut restored), expected);
     }
 
-    pub fn random_from_range(from: f64, to: f64) -> f64 {
-        rand::thread_rng().gen_range(from..to)
+    #[test]
+    fn previously_active_generator_is_restored() {
+        let mut outer = SimulationRandom::new(1);
+        let mut inner = SimulationRandom::new(2);
+
+        let (before, after) = outer.activate(|| {
+            let before = random_number();
+            draw(&mut inner);
+            let after = random_number();
+
+            (before, after)
+        });
+
+        let mut expected = SimulationRandom::new(1);
+        let expected = expected.activate(|| (random_number(), random_number()));
+
+        assert_eq!((before, after), expected);
     }
 
-    /// Random value from normal distribution. Output limited to -4 / +4 sigma
-    pub fn random_from_normal_distribution(mean: f64, std_dev: f64) -> f64 {
-        let normal = Normal::new(mean, std_dev).unwrap();
-        let limit_offset = 4. * std_dev;
-        normal
-            .sample(&mut rand::thread_rng())
-            .max(mean - limit_offset)
-            .min(mean + limit_offset)
+    #[test]
+    fn normal_distribution_is_limited_to_four_sigma() {
+        let mut random = SimulationRandom::new(42);
+        random.activate(|| {
+            for _ in 0..1000 {
+                let value = random_from_normal_distribution(0., 1.);
+                assert!((-4. ..=4.).contains(&value));
+            }
+        });
     }
 }
@@ -0,0 +1,80 @@
+/// Reads the little endian values of the binary formats used by the simulation,
+/// such as flight traces, snapshots and telemetry datagrams.
+pub(super) struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+impl<'a> ByteReader<'a> {
+    pub(super) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub(super) fn take(&mut self, length: usize) -> Result<&'a [u8], Truncated> {
+        if self.bytes.len() < length {
+            return Err(Truncated);
+        }
+
+        let (taken, remaining) = self.bytes.split_at(length);
+        self.bytes = remaining;
+
+        Ok(taken)
+    }
+
+    pub(super) fn read_u8(&mut self) -> Result<u8, Truncated> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub(super) fn read_u16(&mut self) -> Result<u16, Truncated> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    pub(super) fn read_u32(&mut self) -> Result<u32, Truncated> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    pub(super) fn read_u64(&mut self) -> Result<u64, Truncated> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    pub(super) fn read_f64(&mut self) -> Result<f64, Truncated> {
+        Ok(f64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+}
+
+/// The data ended before the value which was to be read.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct Truncated;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_little_endian_values() {
+        let mut bytes = vec![7];
+        bytes.extend_from_slice(&258_u16.to_le_bytes());
+        bytes.extend_from_slice(&70_000_u32.to_le_bytes());
+        bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+        bytes.extend_from_slice(&1.5_f64.to_le_bytes());
+
+        let mut reader = ByteReader::new(&bytes);
+        assert_eq!(reader.read_u8(), Ok(7));
+        assert_eq!(reader.read_u16(), Ok(258));
+        assert_eq!(reader.read_u32(), Ok(70_000));
+        assert_eq!(reader.read_u64(), Ok(u64::MAX));
+        assert_eq!(reader.read_f64(), Ok(1.5));
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn reading_past_the_end_fails_without_consuming() {
+        let bytes = [1, 2, 3];
+
+        let mut reader = ByteReader::new(&bytes);
+        assert_eq!(reader.read_u32(), Err(Truncated));
+        assert_eq!(reader.take(3), Ok(&bytes[..]));
+    }
+}
@@ -0,0 +1,887 @@
+use std::{collections::hash_map::Entry, error::Error, fmt::Display, mem, time::Duration};
+
+use fxhash::FxHashMap;
+
+use super::{
+    byte_reader::{ByteReader, Truncated},
+    SimulationSnapshot, SimulatorReaderWriter, SnapshotError, StartState, VariableIdentifier,
+    VariableRegistry,
+};
+use crate::failures::FailureTrigger;
+
+/// The simulator variables read by a simulation, recorded frame by frame.
+///
+/// A trace is recorded using a [`FlightTraceRecorder`] and replayed using a
+/// [`FlightTraceReplay`]. Variables are identified by their name, as the identifiers
+/// handed out by a [`VariableRegistry`] differ between the simulator and a replay.
+/// To keep the trace compact, a frame only contains the values which changed since
+/// the previous frame.
+///
+/// Serialised, the trace is a header followed by a stream of records. A record either
+/// introduces a variable or contains a frame. As records are never changed after they
+/// are written, a trace can be written to a file incrementally.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlightTrace {
+    start_state: StartState,
+    random_seed: u64,
+    variable_names: Vec<String>,
+    frames: Vec<FlightTraceFrame>,
+}
+impl FlightTrace {
+    /// The version of the serialised format. Increase it whenever the layout of
+    /// [`to_bytes`] changes.
+    ///
+    /// [`to_bytes`]: #method.to_bytes
+    pub const FORMAT_VERSION: u32 = 2;
+    const MAGIC: &'static [u8; 4] = b"FBWT";
+    const VARIABLE_RECORD: u8 = 1;
+    const FRAME_RECORD: u8 = 2;
+
+    pub fn start_state(&self) -> StartState {
+        self.start_state
+    }
+
+    /// The seed of the random number generator of the recorded simulation.
+    pub fn random_seed(&self) -> u64 {
+        self.random_seed
+    }
+
+    pub fn variable_names(&self) -> &[String] {
+        &self.variable_names
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Serialises the trace into a little endian binary format prefixed with a
+    /// magic number and the [`FORMAT_VERSION`].
+    ///
+    /// [`FORMAT_VERSION`]: #associatedconstant.FORMAT_VERSION
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        Self::write_header(&mut bytes, self.start_state, self.random_seed);
+        for name in &self.variable_names {
+            Self::write_variable(&mut bytes, name);
+        }
+
+        for frame in &self.frames {
+            frame.write(&mut bytes);
+        }
+
+        bytes
+    }
+
+    /// Deserialises a trace which was serialised by [`to_bytes`] or written by
+    /// [`FlightTraceRecorder::take_bytes`].
+    ///
+    /// [`to_bytes`]: #method.to_bytes
+    /// [`FlightTraceRecorder::take_bytes`]: struct.FlightTraceRecorder.html#method.take_bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FlightTraceError> {
+        let mut bytes = ByteReader::new(bytes);
+        if bytes.take(Self::MAGIC.len())? != Self::MAGIC {
+            return Err(FlightTraceError::InvalidHeader);
+        }
+
+        let version = bytes.read_u32()?;
+        if version != Self::FORMAT_VERSION {
+            return Err(FlightTraceError::UnsupportedVersion(version));
+        }
+
+        let start_state = StartState::from(bytes.read_f64()?);
+        let random_seed = bytes.read_u64()?;
+
+        let mut variable_names = Vec::new();
+        let mut frames = Vec::new();
+        while !bytes.is_empty() {
+            match bytes.read_u8()? {
+                Self::VARIABLE_RECORD => {
+                    let name_length = bytes.read_u16()?;
+                    let name = String::from_utf8(bytes.take(name_length as usize)?.to_vec())
+                        .map_err(|_| FlightTraceError::InvalidVariableName)?;
+                    variable_names.push(name);
+                }
+                Self::FRAME_RECORD => {
+                    frames.push(FlightTraceFrame::read(&mut bytes, variable_names.len())?)
+                }
+                record => return Err(FlightTraceError::UnknownRecord(record)),
+            }
+        }
+
+        Ok(Self {
+            start_state,
+            random_seed,
+            variable_names,
+            frames,
+        })
+    }
+
+    fn write_header(bytes: &mut Vec<u8>, start_state: StartState, random_seed: u64) {
+        bytes.extend_from_slice(Self::MAGIC);
+        bytes.extend_from_slice(&Self::FORMAT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&f64::from(start_state).to_le_bytes());
+        bytes.extend_from_slice(&random_seed.to_le_bytes());
+    }
+
+    fn write_variable(bytes: &mut Vec<u8>, name: &str) {
+        bytes.push(Self::VARIABLE_RECORD);
+        bytes.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(name.as_bytes());
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct FlightTraceFrame {
+    delta: Duration,
+    simulation_time: f64,
+    values: Vec<(u32, f64)>,
+    events: Vec<FlightTraceEvent>,
+}
+impl FlightTraceFrame {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.push(FlightTrace::FRAME_RECORD);
+        bytes.extend_from_slice(&self.delta.as_secs_f64().to_le_bytes());
+        bytes.extend_from_slice(&self.simulation_time.to_le_bytes());
+        bytes.extend_from_slice(&(self.values.len() as u32).to_le_bytes());
+        for (index, value) in &self.values {
+            bytes.extend_from_slice(&index.to_le_bytes());
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        bytes.extend_from_slice(&(self.events.len() as u32).to_le_bytes());
+        for event in &self.events {
+            event.write(bytes);
+        }
+    }
+
+    fn read(bytes: &mut ByteReader, variable_count: usize) -> Result<Self, FlightTraceError> {
+        let delta = Duration::from_secs_f64(bytes.read_f64()?.max(0.));
+        let simulation_time = bytes.read_f64()?;
+        let value_count = bytes.read_u32()?;
+        let mut values = Vec::with_capacity(value_count as usize);
+        for _ in 0..value_count {
+            let index = bytes.read_u32()?;
+            if index as usize >= variable_count {
+                return Err(FlightTraceError::UnknownVariable(index));
+            }
+
+            values.push((index, bytes.read_f64()?));
+        }
+
+        let event_count = bytes.read_u32()?;
+        let mut events = Vec::with_capacity(event_count as usize);
+        for _ in 0..event_count {
+            events.push(FlightTraceEvent::read(bytes)?);
+        }
+
+        Ok(Self {
+            delta,
+            simulation_time,
+            values,
+            events,
+        })
+    }
+}
+
+/// A change made to the simulation outside of the variables it reads, which has to be
+/// made again at the same frame for a replay to match the recording. Failures are
+/// identified by the identifier the simulator uses to activate them.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FlightTraceEvent {
+    FailureActivated(u64),
+    FailureDeactivated(u64),
+    FailureArmed {
+        identifier: u64,
+        trigger: FailureTrigger,
+    },
+    FailureDisarmed(u64),
+    PersistentStateRestored(SimulationSnapshot),
+}
+impl FlightTraceEvent {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        match self {
+            FlightTraceEvent::FailureActivated(identifier) => {
+                bytes.push(1);
+                bytes.extend_from_slice(&identifier.to_le_bytes());
+            }
+            FlightTraceEvent::FailureDeactivated(identifier) => {
+                bytes.push(2);
+                bytes.extend_from_slice(&identifier.to_le_bytes());
+            }
+            FlightTraceEvent::FailureArmed {
+                identifier,
+                trigger,
+            } => {
+                let (trigger_type, value) = trigger.encode();
+                bytes.push(3);
+                bytes.extend_from_slice(&identifier.to_le_bytes());
+                bytes.push(trigger_type);
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+            FlightTraceEvent::FailureDisarmed(identifier) => {
+                bytes.push(4);
+                bytes.extend_from_slice(&identifier.to_le_bytes());
+            }
+            FlightTraceEvent::PersistentStateRestored(snapshot) => {
+                let snapshot = snapshot.to_bytes();
+                bytes.push(5);
+                bytes.extend_from_slice(&(snapshot.len() as u32).to_le_bytes());
+                bytes.extend_from_slice(&snapshot);
+            }
+        }
+    }
+
+    fn read(bytes: &mut ByteReader) -> Result<Self, FlightTraceError> {
+        match bytes.read_u8()? {
+            1 => Ok(FlightTraceEvent::FailureActivated(bytes.read_u64()?)),
+            2 => Ok(FlightTraceEvent::FailureDeactivated(bytes.read_u64()?)),
+            3 => {
+                let identifier = bytes.read_u64()?;
+                let trigger_type = bytes.read_u8()?;
+                let trigger = FailureTrigger::decode(trigger_type, bytes.read_f64()?)
+                    .ok_or(FlightTraceError::UnknownFailureTrigger(trigger_type))?;
+
+                Ok(FlightTraceEvent::FailureArmed {
+                    identifier,
+                    trigger,
+                })
+            }
+            4 => Ok(FlightTraceEvent::FailureDisarmed(bytes.read_u64()?)),
+            5 => {
+                let length = bytes.read_u32()?;
+                let snapshot = SimulationSnapshot::from_bytes(bytes.take(length as usize)?)
+                    .map_err(FlightTraceError::InvalidSnapshot)?;
+
+                Ok(FlightTraceEvent::PersistentStateRestored(snapshot))
+            }
+            event => Err(FlightTraceError::UnknownEvent(event)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FlightTraceError {
+    InvalidHeader,
+    UnsupportedVersion(u32),
+    Truncated,
+    UnknownRecord(u8),
+    InvalidVariableName,
+    UnknownVariable(u32),
+    UnknownEvent(u8),
+    UnknownFailureTrigger(u8),
+    InvalidSnapshot(SnapshotError),
+}
+impl Display for FlightTraceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FlightTraceError::InvalidHeader => write!(f, "The data is not a flight trace."),
+            FlightTraceError::UnsupportedVersion(version) => write!(
+                f,
+                "Flight trace format version {} is not supported, expected version {}.",
+                version,
+                FlightTrace::FORMAT_VERSION
+            ),
+            FlightTraceError::Truncated => write!(f, "The flight trace data ended unexpectedly."),
+            FlightTraceError::UnknownRecord(record) => {
+                write!(
+                    f,
+                    "The flight trace contains unknown record type {}.",
+                    record
+                )
+            }
+            FlightTraceError::InvalidVariableName => {
+                write!(
+                    f,
+                    "The flight trace contains a variable name which isn't UTF-8."
+                )
+            }
+            FlightTraceError::UnknownVariable(index) => write!(
+                f,
+                "The flight trace contains a value for unknown variable {}.",
+                index
+            ),
+            FlightTraceError::UnknownEvent(event) => {
+                write!(f, "The flight trace contains unknown event type {}.", event)
+            }
+            FlightTraceError::UnknownFailureTrigger(trigger_type) => write!(
+                f,
+                "The flight trace contains unknown failure trigger type {}.",
+                trigger_type
+            ),
+            FlightTraceError::InvalidSnapshot(error) => write!(
+                f,
+                "The flight trace contains an invalid persistent state: {}",
+                error
+            ),
+        }
+    }
+}
+impl Error for FlightTraceError {}
+impl From<Truncated> for FlightTraceError {
+    fn from(_: Truncated) -> Self {
+        FlightTraceError::Truncated
+    }
+}
+
+/// Records the variables a simulation reads into a [`FlightTrace`].
+///
+/// The recorder learns the name of every variable through the registry returned by
+/// [`variable_registry`], which has to be used to create the simulation. Every
+/// frame is then recorded by passing the reader writer returned by [`frame`] to
+/// [`Simulation::tick`]. The recorder only keeps the records which weren't taken
+/// yet through [`take_bytes`], such that a long flight can be written to a file
+/// incrementally without holding the whole trace in memory:
+/// ```rust
+/// # use std::time::Duration;
+/// # use systems::simulation::{Aircraft, InitContext, Simulation, SimulationElement,
+/// # SimulatorReaderWriter, StartState, VariableIdentifier, VariableRegistry, FlightTraceRecorder};
+/// # struct MyAircraft {}
+/// # impl Aircraft for MyAircraft {}
+/// # impl SimulationElement for MyAircraft {}
+/// # struct MyReaderWriter {}
+/// # impl SimulatorReaderWriter for MyReaderWriter {
+/// #     fn read(&mut self, identifier: &VariableIdentifier) -> f64 { 0.0 }
+/// #     fn write(&mut self, identifier: &VariableIdentifier, value: f64) { }
+/// # }
+/// # struct MyVariableRegistry {}
+/// # impl VariableRegistry for MyVariableRegistry {
+/// #     fn get(&mut self, name: String) -> VariableIdentifier {
+/// #         Default::default()
+/// #     }
+/// # }
+/// let mut registry = MyVariableRegistry {};
+/// let mut recorder = FlightTraceRecorder::new(StartState::Cruise, 42);
+/// let mut simulation = Simulation::new_with_random_seed(
+///     StartState::Cruise,
+///     |_: &mut InitContext| MyAircraft {},
+///     &mut recorder.variable_registry(&mut registry),
+///     42,
+/// );
+///
+/// let mut reader_writer = MyReaderWriter {};
+/// let delta = Duration::from_millis(50);
+/// simulation.tick(delta, 20., &mut recorder.frame(delta, 20., &mut reader_writer));
+///
+/// // Append these bytes to the file containing the trace.
+/// let bytes = recorder.take_bytes();
+/// ```
+/// [`variable_registry`]: #method.variable_registry
+/// [`frame`]: #method.frame
+/// [`take_bytes`]: #method.take_bytes
+/// [`Simulation::tick`]: struct.Simulation.html#method.tick
+pub struct FlightTraceRecorder {
+    start_state: StartState,
+    random_seed: u64,
+    identifier_to_index: FxHashMap<VariableIdentifier, u32>,
+    variable_names: Vec<String>,
+    last_values: Vec<Option<f64>>,
+    header_taken: bool,
+    taken_variable_count: usize,
+    pending_events: Vec<FlightTraceEvent>,
+    frames: Vec<FlightTraceFrame>,
+}
+impl FlightTraceRecorder {
+    pub fn new(start_state: StartState, random_seed: u64) -> Self {
+        Self {
+            start_state,
+            random_seed,
+            identifier_to_index: FxHashMap::default(),
+            variable_names: Vec::new(),
+            last_values: Vec::new(),
+            header_taken: false,
+            taken_variable_count: 0,
+            pending_events: Vec::new(),
+            frames: Vec::new(),
+        }
+    }
+
+    /// Wraps the registry used to create the simulation, such that the recorder
+    /// knows the names of the variables the simulation reads.
+    pub fn variable_registry<'a, T: VariableRegistry>(
+        &'a mut self,
+        registry: &'a mut T,
+    ) -> RecordingVariableRegistry<'a, T> {
+        RecordingVariableRegistry {
+            recorder: self,
+            registry,
+        }
+    }
+
+    /// Records an event into the next frame, such that a replay makes the same
+    /// change before ticking that frame.
+    pub fn record_event(&mut self, event: FlightTraceEvent) {
+        self.pending_events.push(event);
+    }
+
+    /// Starts recording a new frame. The returned reader writer records all reads
+    /// made through it into the frame.
+    pub fn frame<'a, T: SimulatorReaderWriter>(
+        &'a mut self,
+        delta: Duration,
+        simulation_time: f64,
+        reader_writer: &'a mut T,
+    ) -> RecordingReaderWriter<'a, T> {
+        self.frames.push(FlightTraceFrame {
+            delta,
+            simulation_time,
+            values: Vec::new(),
+            events: mem::take(&mut self.pending_events),
+        });
+
+        RecordingReaderWriter {
+            recorder: self,
+            reader_writer,
+        }
+    }
+
+    /// Takes the serialised records which were recorded since the previous call,
+    /// preceded by the header of the trace on the first call. Concatenated, the
+    /// taken bytes form a trace which can be read by [`FlightTrace::from_bytes`].
+    ///
+    /// [`FlightTrace::from_bytes`]: struct.FlightTrace.html#method.from_bytes
+    pub fn take_bytes(&mut self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        if !self.header_taken {
+            FlightTrace::write_header(&mut bytes, self.start_state, self.random_seed);
+            self.header_taken = true;
+        }
+
+        for name in &self.variable_names[self.taken_variable_count..] {
+            FlightTrace::write_variable(&mut bytes, name);
+        }
+        self.taken_variable_count = self.variable_names.len();
+
+        for frame in self.frames.drain(..) {
+            frame.write(&mut bytes);
+        }
+
+        bytes
+    }
+
+    fn register(&mut self, identifier: VariableIdentifier, name: String) {
+        if let Entry::Vacant(entry) = self.identifier_to_index.entry(identifier) {
+            entry.insert(self.variable_names.len() as u32);
+            self.variable_names.push(name);
+            self.last_values.push(None);
+        }
+    }
+
+    fn record(&mut self, identifier: &VariableIdentifier, value: f64) {
+        if let Some(&index) = self.identifier_to_index.get(identifier) {
+            let last_value = &mut self.last_values[index as usize];
+            if *last_value != Some(value) {
+                *last_value = Some(value);
+                if let Some(frame) = self.frames.last_mut() {
+                    frame.values.push((index, value));
+                }
+            }
+        }
+    }
+}
+
+pub struct RecordingVariableRegistry<'a, T: VariableRegistry> {
+    recorder: &'a mut FlightTraceRecorder,
+    registry: &'a mut T,
+}
+impl<'a, T: VariableRegistry> VariableRegistry for RecordingVariableRegistry<'a, T> {
+    fn get(&mut self, name: String) -> VariableIdentifier {
+        let identifier = self.registry.get(name.clone());
+        self.recorder.register(identifier, name);
+
+        identifier
+    }
+}
+
+pub struct RecordingReaderWriter<'a, T: SimulatorReaderWriter> {
+    recorder: &'a mut FlightTraceRecorder,
+    reader_writer: &'a mut T,
+}
+impl<'a, T: SimulatorReaderWriter> SimulatorReaderWriter for RecordingReaderWriter<'a, T> {
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        let value = self.reader_writer.read(identifier);
+        self.recorder.record(identifier, value);
+
+        value
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        self.reader_writer.write(identifier, value);
+    }
+}
+
+/// Replays a [`FlightTrace`] into a simulation. The replay acts as the variable registry
+/// used to create the simulation and as the reader writer passed to [`Simulation::tick`].
+///
+/// [`Simulation::tick`]: struct.Simulation.html#method.tick
+pub struct FlightTraceReplay {
+    trace: FlightTrace,
+    next_frame: usize,
+    name_to_identifier: FxHashMap<String, VariableIdentifier>,
+    next_identifier: VariableIdentifier,
+    index_to_identifier: Vec<Option<VariableIdentifier>>,
+    values: FxHashMap<VariableIdentifier, f64>,
+    written_values: FxHashMap<VariableIdentifier, f64>,
+}
+impl FlightTraceReplay {
+    pub fn new(trace: FlightTrace) -> Self {
+        let variable_count = trace.variable_names.len();
+        Self {
+            trace,
+            next_frame: 0,
+            name_to_identifier: FxHashMap::default(),
+            next_identifier: VariableIdentifier::default(),
+            index_to_identifier: vec![None; variable_count],
+            values: FxHashMap::default(),
+            written_values: FxHashMap::default(),
+        }
+    }
+
+    pub fn start_state(&self) -> StartState {
+        self.trace.start_state
+    }
+
+    pub fn random_seed(&self) -> u64 {
+        self.trace.random_seed
+    }
+
+    /// Moves to the next frame of the trace and returns its delta and simulation
+    /// time, which have to be passed to [`Simulation::tick`]. Returns `None` when
+    /// all frames have been replayed.
+    ///
+    /// [`Simulation::tick`]: struct.Simulation.html#method.tick
+    pub fn next_frame(&mut self) -> Option<(Duration, f64)> {
+        let frame = self.trace.frames.get(self.next_frame)?;
+        self.next_frame += 1;
+
+        for (index, value) in &frame.values {
+            let index = *index as usize;
+            if self.index_to_identifier[index].is_none() {
+                self.index_to_identifier[index] = self
+                    .name_to_identifier
+                    .get(&self.trace.variable_names[index])
+                    .copied();
+            }
+
+            if let Some(identifier) = self.index_to_identifier[index] {
+                self.values.insert(identifier, *value);
+            }
+        }
+
+        Some((frame.delta, frame.simulation_time))
+    }
+
+    /// The events recorded into the frame moved to by [`next_frame`]. They have to be
+    /// applied to the simulation before ticking the frame.
+    ///
+    /// [`next_frame`]: #method.next_frame
+    pub fn events(&self) -> &[FlightTraceEvent] {
+        self.next_frame
+            .checked_sub(1)
+            .and_then(|index| self.trace.frames.get(index))
+            .map_or(&[], |frame| &frame.events)
+    }
+
+    /// The value of the variable with the given name. Values written by the simulation
+    /// take precedence over values read from the trace.
+    pub fn value(&self, name: &str) -> Option<f64> {
+        let identifier = self.name_to_identifier.get(name)?;
+        self.written_values
+            .get(identifier)
+            .or_else(|| self.values.get(identifier))
+            .copied()
+    }
+}
+impl VariableRegistry for FlightTraceReplay {
+    fn get(&mut self, name: String) -> VariableIdentifier {
+        match self.name_to_identifier.get(&name).copied() {
+            Some(identifier) => identifier,
+            None => {
+                let identifier = self.next_identifier;
+                self.name_to_identifier.insert(name, identifier);
+                self.next_identifier = identifier.next();
+
+                identifier
+            }
+        }
+    }
+}
+impl SimulatorReaderWriter for FlightTraceReplay {
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        *self.values.get(identifier).unwrap_or(&0.)
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        self.written_values.insert(*identifier, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        Aircraft, InitContext, Read, Simulation, SimulationElement, SimulatorReader,
+        SimulatorWriter, UpdateContext, Write,
+    };
+
+    /// Counts up the value it reads and writes the result.
+    struct Counter {
+        input_id: VariableIdentifier,
+        output_id: VariableIdentifier,
+        input: f64,
+        total: f64,
+    }
+    impl Counter {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                input_id: context.get_identifier("COUNTER_INPUT".to_owned()),
+                output_id: context.get_identifier("COUNTER_TOTAL".to_owned()),
+                input: 0.,
+                total: 0.,
+            }
+        }
+    }
+    impl Aircraft for Counter {
+        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
+            self.total += self.input;
+        }
+    }
+    impl SimulationElement for Counter {
+        fn read(&mut self, reader: &mut SimulatorReader) {
+            self.input = reader.read(&self.input_id);
+        }
+
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&self.output_id, self.total);
+        }
+    }
+
+    #[derive(Default)]
+    struct TestRegistry {
+        names: FxHashMap<String, VariableIdentifier>,
+        next_identifier: VariableIdentifier,
+    }
+    impl VariableRegistry for TestRegistry {
+        fn get(&mut self, name: String) -> VariableIdentifier {
+            // Hand out other identifiers than the replay does, to ensure
+            // replaying doesn't depend on the recorded identifiers.
+            let identifier = self.next_identifier.next().next();
+            self.next_identifier = identifier;
+            *self.names.entry(name).or_insert(identifier)
+        }
+    }
+
+    #[derive(Default)]
+    struct TestReaderWriter {
+        values: FxHashMap<VariableIdentifier, f64>,
+    }
+    impl SimulatorReaderWriter for TestReaderWriter {
+        fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+            *self.values.get(identifier).unwrap_or(&0.)
+        }
+
+        fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+            self.values.insert(*identifier, value);
+        }
+    }
+
+    fn record(inputs: &[f64]) -> FlightTrace {
+        record_with_events(inputs, vec![])
+    }
+
+    /// Records a frame per input, with the given events recorded before the frame at
+    /// the given index. The bytes are taken after every frame, as the simulator does.
+    fn record_with_events(inputs: &[f64], events: Vec<(usize, FlightTraceEvent)>) -> FlightTrace {
+        let mut registry = TestRegistry::default();
+        let mut recorder = FlightTraceRecorder::new(StartState::Taxi, 7);
+        let mut simulation = Simulation::new_with_random_seed(
+            StartState::Taxi,
+            Counter::new,
+            &mut recorder.variable_registry(&mut registry),
+            7,
+        );
+
+        let input_id = registry.names["COUNTER_INPUT"];
+        let mut reader_writer = TestReaderWriter::default();
+        let mut bytes = Vec::new();
+        for (i, input) in inputs.iter().enumerate() {
+            for (_, event) in events.iter().filter(|(index, _)| *index == i) {
+                recorder.record_event(event.clone());
+            }
+
+            reader_writer.values.insert(input_id, *input);
+            let delta = Duration::from_millis(100);
+            let simulation_time = 10. + i as f64 * 0.1;
+            simulation.tick(
+                delta,
+                simulation_time,
+                &mut recorder.frame(delta, simulation_time, &mut reader_writer),
+            );
+            bytes.extend(recorder.take_bytes());
+        }
+
+        FlightTrace::from_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    fn records_a_frame_per_tick() {
+        let trace = record(&[1., 2., 3.]);
+
+        assert_eq!(trace.frame_count(), 3);
+    }
+
+    #[test]
+    fn records_the_names_of_read_variables() {
+        let trace = record(&[1.]);
+
+        assert!(trace.variable_names().contains(&"COUNTER_INPUT".to_owned()));
+    }
+
+    #[test]
+    fn only_records_changed_values() {
+        let trace = record(&[1., 1., 1.]);
+
+        let counter_input_values: usize = trace
+            .frames
+            .iter()
+            .map(|frame| {
+                frame
+                    .values
+                    .iter()
+                    .filter(|(index, _)| trace.variable_names[*index as usize] == "COUNTER_INPUT")
+                    .count()
+            })
+            .sum();
+        assert_eq!(counter_input_values, 1);
+    }
+
+    #[test]
+    fn replay_produces_the_same_outputs() {
+        let trace = FlightTrace::from_bytes(&record(&[1., 2., 3., 3., 0.5]).to_bytes()).unwrap();
+
+        let mut replay = FlightTraceReplay::new(trace);
+        let start_state = replay.start_state();
+        let random_seed = replay.random_seed();
+        let mut simulation =
+            Simulation::new_with_random_seed(start_state, Counter::new, &mut replay, random_seed);
+
+        let mut frames = 0;
+        while let Some((delta, simulation_time)) = replay.next_frame() {
+            simulation.tick(delta, simulation_time, &mut replay);
+            frames += 1;
+        }
+
+        assert_eq!(frames, 5);
+        assert_eq!(replay.value("COUNTER_TOTAL"), Some(9.5));
+    }
+
+    #[test]
+    fn serialisation_round_trip_preserves_the_trace() {
+        let trace = record(&[1., 2.]);
+
+        assert_eq!(FlightTrace::from_bytes(&trace.to_bytes()), Ok(trace));
+    }
+
+    #[test]
+    fn stores_start_state_and_random_seed() {
+        let trace = FlightTrace::from_bytes(&record(&[1.]).to_bytes()).unwrap();
+
+        assert_eq!(trace.start_state(), StartState::Taxi);
+        assert_eq!(trace.random_seed(), 7);
+    }
+
+    #[test]
+    fn rejects_data_which_is_not_a_trace() {
+        assert_eq!(
+            FlightTrace::from_bytes(b"not a trace"),
+            Err(FlightTraceError::InvalidHeader)
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        let bytes = record(&[1., 2.]).to_bytes();
+
+        assert_eq!(
+            FlightTrace::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(FlightTraceError::Truncated)
+        );
+    }
+
+    #[test]
+    fn taking_bytes_only_returns_what_was_recorded_since_the_previous_take() {
+        let mut registry = TestRegistry::default();
+        let mut recorder = FlightTraceRecorder::new(StartState::Taxi, 7);
+        let mut simulation = Simulation::new_with_random_seed(
+            StartState::Taxi,
+            Counter::new,
+            &mut recorder.variable_registry(&mut registry),
+            7,
+        );
+
+        let mut reader_writer = TestReaderWriter::default();
+        let delta = Duration::from_millis(100);
+        simulation.tick(
+            delta,
+            10.,
+            &mut recorder.frame(delta, 10., &mut reader_writer),
+        );
+        let mut bytes = recorder.take_bytes();
+        let first_take_length = bytes.len();
+
+        simulation.tick(
+            delta,
+            10.1,
+            &mut recorder.frame(delta, 10.1, &mut reader_writer),
+        );
+        bytes.extend(recorder.take_bytes());
+
+        assert!(bytes.len() - first_take_length < first_take_length);
+        assert!(recorder.take_bytes().is_empty());
+        assert_eq!(FlightTrace::from_bytes(&bytes).unwrap().frame_count(), 2);
+    }
+
+    #[test]
+    fn replay_provides_the_events_of_the_current_frame() {
+        let event = FlightTraceEvent::FailureActivated(24000);
+        let trace = record_with_events(&[1., 2., 3.], vec![(1, event.clone())]);
+
+        let mut replay = FlightTraceReplay::new(trace);
+        assert!(replay.events().is_empty());
+
+        replay.next_frame();
+        assert!(replay.events().is_empty());
+
+        replay.next_frame();
+        assert_eq!(replay.events(), &[event]);
+
+        replay.next_frame();
+        assert!(replay.events().is_empty());
+    }
+
+    #[test]
+    fn serialisation_round_trip_preserves_events() {
+        let trace = record_with_events(
+            &[1., 2.],
+            vec![
+                (0, FlightTraceEvent::FailureActivated(1)),
+                (0, FlightTraceEvent::FailureDeactivated(2)),
+                (
+                    1,
+                    FlightTraceEvent::FailureArmed {
+                        identifier: 3,
+                        trigger: FailureTrigger::Touchdown,
+                    },
+                ),
+                (1, FlightTraceEvent::FailureDisarmed(4)),
+                (
+                    1,
+                    FlightTraceEvent::PersistentStateRestored(SimulationSnapshot::default()),
+                ),
+            ],
+        );
+
+        assert_eq!(trace.frames[0].events.len(), 2);
+        assert_eq!(trace.frames[1].events.len(), 3);
+        assert_eq!(FlightTrace::from_bytes(&trace.to_bytes()), Ok(trace));
+    }
+}
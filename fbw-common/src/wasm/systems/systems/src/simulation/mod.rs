@@ -1,16 +1,25 @@
 use std::time::Duration;
 
+mod byte_reader;
+mod flight_trace;
+mod snapshot;
+#[cfg(not(target_arch = "wasm32"))]
+mod telemetry;
 mod update_context;
 use crate::electrical::{ElectricalElementIdentifier, ElectricalElementIdentifierProvider};
-use crate::shared::{from_bool, ElectricalBusType};
+use crate::shared::{from_bool, ElectricalBusType, SimulationRandom};
 use crate::{
     electrical::Electricity,
-    failures::FailureType,
+    failures::{FailureScheduler, FailureState, FailureTrigger, FailureType},
     shared::arinc429::{from_arinc429, to_arinc429, Arinc429Word, SignStatus},
     shared::arinc825::{from_arinc825, to_arinc825, Arinc825Word},
     shared::{to_bool, ConsumePower, ElectricalBuses, MachNumber, PowerConsumptionReport},
 };
 
+pub use flight_trace::*;
+pub use snapshot::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use telemetry::*;
 use uom::si::mass_rate::kilogram_per_second;
 use uom::si::{
     acceleration::foot_per_second_squared, angle::degree, angular_velocity::revolution_per_minute,
@@ -115,6 +124,7 @@ pub struct InitContext<'a> {
     start_state: StartState,
     electrical_identifier_provider: &'a mut dyn ElectricalElementIdentifierProvider,
     registry: &'a mut dyn VariableRegistry,
+    random_seed: u64,
 }
 
 impl<'a> InitContext<'a> {
@@ -127,6 +137,7 @@ impl<'a> InitContext<'a> {
             start_state,
             electrical_identifier_provider: electricity,
             registry,
+            random_seed: 0,
         }
     }
 
@@ -138,6 +149,11 @@ impl<'a> InitContext<'a> {
         self.start_state
     }
 
+    /// The seed of the random number generator owned by the simulation.
+    pub fn random_seed(&self) -> u64 {
+        self.random_seed
+    }
+
     pub fn start_gear_down(&self) -> bool {
         self.is_on_ground() || self.start_state == StartState::Final
     }
@@ -329,6 +345,53 @@ pub trait SimulationElement {
 
     /// Receives a failure in order to activate or deactivate it.
     fn receive_failure(&mut self, _failure_type: FailureType, _is_active: bool) {}
+
+    /// The failure the element represents, when it is active. Used to determine the active
+    /// failures after restoring a [`SimulationSnapshot`].
+    ///
+    /// [`SimulationSnapshot`]: struct.SimulationSnapshot.html
+    fn active_failure(&self) -> Option<FailureType> {
+        None
+    }
+
+    /// Saves the internal state of the element into a [`SimulationSnapshot`].
+    /// Only state which isn't read from the simulator on every tick needs to be saved.
+    /// # Examples
+    /// ```rust
+    /// # use systems::simulation::{SimulationElement, SnapshotReader, SnapshotWriter};
+    /// struct MySimulationElement {
+    ///     charge: f64,
+    /// }
+    /// impl SimulationElement for MySimulationElement {
+    ///     fn save_state(&self, writer: &mut SnapshotWriter) {
+    ///         writer.write_f64("charge", self.charge);
+    ///     }
+    ///
+    ///     fn restore_state(&mut self, reader: &mut SnapshotReader) {
+    ///         if let Some(charge) = reader.read_f64("charge") {
+    ///             self.charge = charge;
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    /// [`SimulationSnapshot`]: struct.SimulationSnapshot.html
+    fn save_state(&self, _writer: &mut SnapshotWriter) {}
+
+    /// Restores the internal state of the element which was saved by [`save_state`].
+    ///
+    /// [`save_state`]: #method.save_state
+    fn restore_state(&mut self, _reader: &mut SnapshotReader) {}
+
+    /// Saves the state of the element which outlives a simulator session, such as the wear
+    /// of a component, into a persistent [`SimulationSnapshot`].
+    ///
+    /// [`SimulationSnapshot`]: struct.SimulationSnapshot.html
+    fn save_persistent_state(&self, _writer: &mut SnapshotWriter) {}
+
+    /// Restores the state of the element which was saved by [`save_persistent_state`].
+    ///
+    /// [`save_persistent_state`]: #method.save_persistent_state
+    fn restore_persistent_state(&mut self, _reader: &mut SnapshotReader) {}
 }
 
 /// Trait for visitors that visit the aircraft's system simulation to call
@@ -360,23 +423,52 @@ pub struct Simulation<T: Aircraft> {
     aircraft: T,
     electricity: Electricity,
     update_context: UpdateContext,
+    random: SimulationRandom,
+    failure_scheduler: FailureScheduler,
 }
 impl<T: Aircraft> Simulation<T> {
+    /// Creates a simulation whose random number generator is seeded from entropy.
     pub fn new<U: FnOnce(&mut InitContext) -> T>(
         start_state: StartState,
         aircraft_ctor_fn: U,
         registry: &mut impl VariableRegistry,
     ) -> Self {
+        Self::new_with_random_seed(
+            start_state,
+            aircraft_ctor_fn,
+            registry,
+            SimulationRandom::entropy_seed(),
+        )
+    }
+
+    /// Creates a simulation whose random number generator is seeded with the given seed.
+    /// Simulations created with the same seed and fed the same simulator data behave identically.
+    pub fn new_with_random_seed<U: FnOnce(&mut InitContext) -> T>(
+        start_state: StartState,
+        aircraft_ctor_fn: U,
+        registry: &mut impl VariableRegistry,
+        random_seed: u64,
+    ) -> Self {
+        let mut random = SimulationRandom::new(random_seed);
         let mut electricity = Electricity::new();
         let mut context = InitContext::new(start_state, &mut electricity, registry);
+        context.random_seed = random_seed;
         let update_context = UpdateContext::new_for_simulation(&mut context);
+        let aircraft = random.activate(|| (aircraft_ctor_fn)(&mut context));
+
         Self {
-            aircraft: (aircraft_ctor_fn)(&mut context),
+            aircraft,
             electricity,
             update_context,
+            random,
+            failure_scheduler: FailureScheduler::new(),
         }
     }
 
+    pub fn random_seed(&self) -> u64 {
+        self.random.seed()
+    }
+
     /// Execute a single run of the simulation using the specified `delta` duration
     /// as the amount of time that has passed since the previous run.
     ///
@@ -434,41 +526,102 @@ impl<T: Aircraft> Simulation<T> {
         simulation_time: f64,
         reader_writer: &mut impl SimulatorReaderWriter,
     ) {
-        self.electricity.pre_tick();
+        let aircraft = &mut self.aircraft;
+        let electricity = &mut self.electricity;
+        let update_context = &mut self.update_context;
+        let failure_scheduler = &mut self.failure_scheduler;
+
+        self.random.activate(|| {
+            electricity.pre_tick();
+
+            let mut reader = SimulatorReader::new(reader_writer);
+            update_context.update(&mut reader, delta, simulation_time);
+
+            for failure_type in failure_scheduler.update(update_context) {
+                aircraft.accept(&mut FailureSimulationElementVisitor::new(
+                    failure_type,
+                    true,
+                ));
+            }
 
-        let mut reader = SimulatorReader::new(reader_writer);
-        self.update_context
-            .update(&mut reader, delta, simulation_time);
+            let mut visitor = SimulatorToSimulationVisitor::new(&mut reader);
+            aircraft.accept(&mut visitor);
 
-        let mut visitor = SimulatorToSimulationVisitor::new(&mut reader);
-        self.aircraft.accept(&mut visitor);
+            aircraft.update_before_power_distribution(update_context, electricity);
 
-        self.aircraft
-            .update_before_power_distribution(&self.update_context, &mut self.electricity);
+            aircraft.distribute_electricity(update_context, electricity);
 
-        self.aircraft
-            .distribute_electricity(&self.update_context, &self.electricity);
+            aircraft.update_after_power_distribution(update_context);
+            aircraft.consume_electricity(update_context, electricity);
+            aircraft.report_electricity_consumption(update_context, electricity);
 
-        self.aircraft
-            .update_after_power_distribution(&self.update_context);
-        self.aircraft
-            .consume_electricity(&self.update_context, &mut self.electricity);
-        self.aircraft
-            .report_electricity_consumption(&self.update_context, &self.electricity);
+            let mut writer = SimulatorWriter::new(reader_writer);
+            let mut visitor = SimulationToSimulatorVisitor::new(&mut writer);
+            aircraft.accept(&mut visitor);
+        });
+    }
+
+    /// Takes a snapshot of the internal state of the aircraft, together with the position
+    /// of the random number generator.
+    pub fn snapshot(&mut self) -> SimulationSnapshot {
+        SimulationSnapshot::save(&mut self.aircraft).with_random(self.random.state())
+    }
+
+    /// Restores the internal state of the aircraft and the position of the random number
+    /// generator from a snapshot taken from a simulation of the same aircraft. The failures
+    /// which are active in the snapshot become active, while armed failures remain armed.
+    pub fn restore(&mut self, snapshot: &SimulationSnapshot) -> Result<(), SnapshotError> {
+        let aircraft = &mut self.aircraft;
+        self.random.activate(|| snapshot.restore(aircraft))?;
+        if let Some(random) = snapshot.random() {
+            self.random.restore(random);
+        }
 
-        let mut writer = SimulatorWriter::new(reader_writer);
-        let mut visitor = SimulationToSimulatorVisitor::new(&mut writer);
+        let mut visitor = ActiveFailureVisitor::default();
         self.aircraft.accept(&mut visitor);
+        self.failure_scheduler.restore_active(visitor.active);
+
+        Ok(())
+    }
+
+    /// Takes a snapshot of the state of the aircraft which outlives a simulator session.
+    pub fn persistent_snapshot(&mut self) -> SimulationSnapshot {
+        SimulationSnapshot::save_persistent(&mut self.aircraft)
+    }
+
+    /// Restores the state of the aircraft which outlives a simulator session from a
+    /// persistent snapshot taken from a simulation of the same aircraft.
+    pub fn restore_persistent(
+        &mut self,
+        snapshot: &SimulationSnapshot,
+    ) -> Result<(), SnapshotError> {
+        snapshot.restore_persistent(&mut self.aircraft)
     }
 
     pub fn activate_failure(&mut self, failure_type: FailureType) {
+        self.failure_scheduler.activated(failure_type);
         self.handle_failure(failure_type, true);
     }
 
     pub fn deactivate_failure(&mut self, failure_type: FailureType) {
+        self.failure_scheduler.deactivated(failure_type);
         self.handle_failure(failure_type, false);
     }
 
+    /// Arms the failure to activate at the start of the first tick in which the
+    /// trigger's condition is met. Arming an armed failure replaces its trigger.
+    pub fn arm_failure(&mut self, failure_type: FailureType, trigger: FailureTrigger) {
+        self.failure_scheduler.arm(failure_type, trigger);
+    }
+
+    pub fn disarm_failure(&mut self, failure_type: FailureType) {
+        self.failure_scheduler.disarm(failure_type);
+    }
+
+    pub fn failure_state(&self, failure_type: FailureType) -> FailureState {
+        self.failure_scheduler.state(failure_type)
+    }
+
     fn handle_failure(&mut self, failure_type: FailureType, is_active: bool) {
         self.aircraft
             .accept(&mut FailureSimulationElementVisitor::new(
@@ -515,6 +668,19 @@ impl SimulationElementVisitor for FailureSimulationElementVisitor {
     }
 }
 
+/// Collects the failures which are active within the aircraft.
+#[derive(Default)]
+struct ActiveFailureVisitor {
+    active: Vec<FailureType>,
+}
+impl SimulationElementVisitor for ActiveFailureVisitor {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        if let Some(failure_type) = visited.active_failure() {
+            self.active.push(failure_type);
+        }
+    }
+}
+
 /// Visits aircraft components in order to pass data coming
 /// from the simulator into the aircraft system simulation.
 pub(crate) struct SimulatorToSimulationVisitor<'a> {
@@ -986,4 +1152,83 @@ mod tests {
             assert!(!context.has_engines_running());
         }
     }
+
+    mod random {
+        use super::*;
+        use std::time::Duration;
+        use test::{SimulationTestBed, TestBed};
+
+        struct RandomAircraft {
+            drawn_at_construction: f64,
+            drawn_during_update: Vec<f64>,
+        }
+        impl RandomAircraft {
+            fn new(_: &mut InitContext) -> Self {
+                Self {
+                    drawn_at_construction: crate::shared::random_from_range(0., 1.),
+                    drawn_during_update: Vec::new(),
+                }
+            }
+
+            fn drawn(&self) -> (f64, Vec<f64>) {
+                (self.drawn_at_construction, self.drawn_during_update.clone())
+            }
+        }
+        impl Aircraft for RandomAircraft {
+            fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+                self.drawn_during_update
+                    .push(context.random_from_normal_distribution(0., 1.));
+            }
+        }
+        impl SimulationElement for RandomAircraft {}
+
+        fn run_seeded(seed: u64) -> (f64, Vec<f64>) {
+            let mut test_bed = SimulationTestBed::new_with_random_seed(seed, RandomAircraft::new);
+            test_bed.run_multiple_frames(Duration::from_secs(5));
+
+            test_bed.query(|a| a.drawn())
+        }
+
+        #[test]
+        fn same_seed_results_in_identical_simulation() {
+            assert_eq!(run_seeded(42), run_seeded(42));
+        }
+
+        #[test]
+        fn different_seeds_result_in_different_simulation() {
+            assert_ne!(run_seeded(42), run_seeded(43));
+        }
+
+        #[test]
+        fn test_bed_reports_its_random_seed() {
+            let test_bed = SimulationTestBed::new_with_random_seed(1234, RandomAircraft::new);
+
+            assert_eq!(test_bed.random_seed(), 1234);
+        }
+
+        #[test]
+        fn restored_simulation_draws_the_same_random_numbers() {
+            let mut test_bed = SimulationTestBed::new_with_random_seed(42, RandomAircraft::new);
+            test_bed.run_multiple_frames(Duration::from_secs(1));
+            let snapshot = test_bed.snapshot();
+            let drawn_before_snapshot = test_bed.query(|a| a.drawn_during_update.len());
+            test_bed.run_multiple_frames(Duration::from_secs(1));
+            let expected =
+                test_bed.query(|a| a.drawn_during_update[drawn_before_snapshot..].to_vec());
+
+            let mut restored = SimulationTestBed::new_with_random_seed(7, RandomAircraft::new);
+            restored.run_multiple_frames(Duration::from_secs(1));
+            restored.restore(&snapshot).unwrap();
+            let drawn_before_restore = restored.query(|a| a.drawn_during_update.len());
+            restored.run_multiple_frames(Duration::from_secs(1));
+
+            // The drawn numbers aren't part of the snapshot, thus only those drawn after
+            // restoring are expected to match.
+            assert_eq!(
+                restored.query(|a| a.drawn_during_update[drawn_before_restore..].to_vec()),
+                expected
+            );
+            assert_eq!(restored.random_seed(), 42);
+        }
+    }
 }
@@ -0,0 +1,725 @@
+use std::{any::type_name, error::Error, fmt::Display, time::Duration};
+
+use fxhash::FxHashMap;
+
+use super::{
+    byte_reader::{ByteReader, Truncated},
+    SimulationElement, SimulationElementVisitor,
+};
+use crate::shared::{from_bool, to_bool, SimulationRandomState};
+
+/// The internal state of all elements within a simulation at a moment in time.
+///
+/// The state of every visited element is stored in visiting order, which means a snapshot
+/// can only be restored into a simulation of the same aircraft with the same element tree.
+/// Only state which cannot be derived from the simulator's variables needs to be saved
+/// by an element, see [`SimulationElement::save_state`].
+///
+/// A snapshot taken from a whole simulation also contains the position of the simulation's
+/// random number generator. Active failures are restored through the state of their
+/// [`Failure`] elements. The following is not part of a snapshot:
+/// - Armed failures, which are kept as they are when restoring.
+/// - The state of filters, delays and timers within controllers, such as the low pass
+///   filters of the hydraulic actuator controllers. It settles within seconds of restoring.
+/// - The pressure, temperature and mass of air within pneumatic containers, which follow
+///   the bleed sources within seconds of restoring.
+///
+/// [`Failure`]: ../failures/struct.Failure.html
+///
+/// A persistent snapshot only contains the state which outlives a simulator session, see
+/// [`SimulationElement::save_persistent_state`]. It is meant to be stored between sessions.
+/// As the aircraft might gain or lose elements between sessions, the state in a persistent
+/// snapshot is identified by the type of the element and the occurrence of the element among
+/// the elements of that type, instead of by its position in the element tree.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SimulationSnapshot {
+    elements: Vec<ElementState>,
+    random: Option<SimulationRandomState>,
+}
+impl SimulationSnapshot {
+    /// The version of the serialised format. Increase it whenever the layout of
+    /// [`to_bytes`] changes.
+    ///
+    /// [`to_bytes`]: #method.to_bytes
+    pub const FORMAT_VERSION: u32 = 3;
+    const MAGIC: &'static [u8; 4] = b"FBWS";
+
+    pub(super) fn save<T: SimulationElement>(element: &mut T) -> Self {
+        Self::save_with(element, false)
+    }
+
+    pub(super) fn save_persistent<T: SimulationElement>(element: &mut T) -> Self {
+        Self::save_with(element, true)
+    }
+
+    fn save_with<T: SimulationElement>(element: &mut T, persistent: bool) -> Self {
+        let mut visitor = SimulationToSnapshotVisitor {
+            elements: vec![],
+            keys: ElementKeys::default(),
+            persistent,
+        };
+        element.accept(&mut visitor);
+
+        Self {
+            elements: visitor.elements,
+            random: None,
+        }
+    }
+
+    pub(super) fn with_random(mut self, random: SimulationRandomState) -> Self {
+        self.random = Some(random);
+        self
+    }
+
+    /// The position of the simulation's random number generator, which is only part of
+    /// a snapshot taken from a whole simulation.
+    pub(super) fn random(&self) -> Option<SimulationRandomState> {
+        self.random
+    }
+
+    pub(super) fn restore<T: SimulationElement>(
+        &self,
+        element: &mut T,
+    ) -> Result<(), SnapshotError> {
+        self.restore_with(element, false)
+    }
+
+    pub(super) fn restore_persistent<T: SimulationElement>(
+        &self,
+        element: &mut T,
+    ) -> Result<(), SnapshotError> {
+        self.restore_with(element, true)
+    }
+
+    fn restore_with<T: SimulationElement>(
+        &self,
+        element: &mut T,
+        persistent: bool,
+    ) -> Result<(), SnapshotError> {
+        if persistent {
+            let mut visitor = KeyedSnapshotToSimulationVisitor {
+                elements: self
+                    .elements
+                    .iter()
+                    .map(|state| (state.key.as_str(), state.values.as_slice()))
+                    .collect(),
+                keys: ElementKeys::default(),
+            };
+            element.accept(&mut visitor);
+
+            return Ok(());
+        }
+
+        let mut counter = ElementCounter::default();
+        element.accept(&mut counter);
+        if counter.count != self.elements.len() {
+            return Err(SnapshotError::ElementCountMismatch {
+                expected: counter.count,
+                actual: self.elements.len(),
+            });
+        }
+
+        let mut visitor = SnapshotToSimulationVisitor {
+            elements: self.elements.iter(),
+        };
+        element.accept(&mut visitor);
+
+        Ok(())
+    }
+
+    /// The number of elements of which the state is contained in the snapshot.
+    pub fn element_count(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Serialises the snapshot into a little endian binary format prefixed with a
+    /// magic number and the [`FORMAT_VERSION`].
+    ///
+    /// [`FORMAT_VERSION`]: #associatedconstant.FORMAT_VERSION
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(Self::MAGIC);
+        bytes.extend_from_slice(&Self::FORMAT_VERSION.to_le_bytes());
+        match self.random {
+            Some(random) => {
+                bytes.push(1);
+                bytes.extend_from_slice(&random.seed.to_le_bytes());
+                bytes.extend_from_slice(&random.steps.to_le_bytes());
+            }
+            None => bytes.push(0),
+        }
+
+        bytes.extend_from_slice(&(self.elements.len() as u32).to_le_bytes());
+
+        for element in &self.elements {
+            bytes.extend_from_slice(&(element.key.len() as u16).to_le_bytes());
+            bytes.extend_from_slice(element.key.as_bytes());
+            bytes.extend_from_slice(&(element.values.len() as u16).to_le_bytes());
+            for (key, value) in &element.values {
+                bytes.extend_from_slice(&(key.len() as u16).to_le_bytes());
+                bytes.extend_from_slice(key.as_bytes());
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        bytes
+    }
+
+    /// Deserialises a snapshot which was serialised by [`to_bytes`].
+    ///
+    /// [`to_bytes`]: #method.to_bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        let mut bytes = ByteReader::new(bytes);
+        if bytes.take(Self::MAGIC.len())? != Self::MAGIC {
+            return Err(SnapshotError::InvalidHeader);
+        }
+
+        let version = bytes.read_u32()?;
+        if version != Self::FORMAT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+
+        let random = match bytes.read_u8()? {
+            0 => None,
+            _ => Some(SimulationRandomState {
+                seed: bytes.read_u64()?,
+                steps: bytes.read_u64()?,
+            }),
+        };
+
+        let element_count = bytes.read_u32()?;
+        let mut elements = Vec::with_capacity(element_count as usize);
+        for _ in 0..element_count {
+            let key = Self::read_key(&mut bytes)?;
+            let value_count = bytes.read_u16()?;
+            let mut values = Vec::with_capacity(value_count as usize);
+            for _ in 0..value_count {
+                values.push((Self::read_key(&mut bytes)?, bytes.read_f64()?));
+            }
+
+            elements.push(ElementState { key, values });
+        }
+
+        if !bytes.is_empty() {
+            return Err(SnapshotError::TrailingData);
+        }
+
+        Ok(Self { elements, random })
+    }
+
+    fn read_key(bytes: &mut ByteReader) -> Result<String, SnapshotError> {
+        let key_length = bytes.read_u16()?;
+        String::from_utf8(bytes.take(key_length as usize)?.to_vec())
+            .map_err(|_| SnapshotError::InvalidKey)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SnapshotError {
+    InvalidHeader,
+    UnsupportedVersion(u32),
+    Truncated,
+    TrailingData,
+    InvalidKey,
+    ElementCountMismatch { expected: usize, actual: usize },
+}
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnapshotError::InvalidHeader => write!(f, "The data is not a simulation snapshot."),
+            SnapshotError::UnsupportedVersion(version) => write!(
+                f,
+                "Snapshot format version {} is not supported, expected version {}.",
+                version,
+                SimulationSnapshot::FORMAT_VERSION
+            ),
+            SnapshotError::Truncated => write!(f, "The snapshot data ended unexpectedly."),
+            SnapshotError::TrailingData => {
+                write!(f, "The snapshot data continues after the last element.")
+            }
+            SnapshotError::InvalidKey => {
+                write!(f, "The snapshot contains a key which isn't UTF-8.")
+            }
+            SnapshotError::ElementCountMismatch { expected, actual } => write!(
+                f,
+                "The simulation contains {} elements, while the snapshot contains {}.",
+                expected, actual
+            ),
+        }
+    }
+}
+impl Error for SnapshotError {}
+impl From<Truncated> for SnapshotError {
+    fn from(_: Truncated) -> Self {
+        SnapshotError::Truncated
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct ElementState {
+    key: String,
+    values: Vec<(String, f64)>,
+}
+
+/// Identifies an element by its type and its occurrence among the visited elements
+/// of that type, e.g. the second battery visited is `systems::electrical::battery::Battery#1`.
+#[derive(Default)]
+struct ElementKeys {
+    occurrences: FxHashMap<&'static str, usize>,
+}
+impl ElementKeys {
+    fn next<T: SimulationElement>(&mut self) -> String {
+        let type_name = type_name::<T>();
+        let occurrence = self.occurrences.entry(type_name).or_insert(0);
+        let key = format!("{}#{}", type_name, occurrence);
+        *occurrence += 1;
+
+        key
+    }
+}
+
+/// Collects the state an element saves into a [`SimulationSnapshot`].
+#[derive(Default)]
+pub struct SnapshotWriter {
+    values: Vec<(String, f64)>,
+}
+impl SnapshotWriter {
+    pub fn write_f64(&mut self, key: &str, value: f64) {
+        self.values.push((key.to_owned(), value));
+    }
+
+    pub fn write_bool(&mut self, key: &str, value: bool) {
+        self.write_f64(key, from_bool(value));
+    }
+
+    /// Writes an optional duration, where `None` is stored as a negative value.
+    pub fn write_optional_duration(&mut self, key: &str, value: Option<Duration>) {
+        self.write_f64(key, value.map_or(-1., |duration| duration.as_secs_f64()));
+    }
+}
+
+/// Provides an element with the state it saved into a [`SimulationSnapshot`].
+/// Keys which are not part of the snapshot return `None`, in which case the element
+/// should keep its current state.
+pub struct SnapshotReader<'a> {
+    values: &'a [(String, f64)],
+}
+impl<'a> SnapshotReader<'a> {
+    pub fn read_f64(&mut self, key: &str) -> Option<f64> {
+        self.values
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| *value)
+    }
+
+    pub fn read_bool(&mut self, key: &str) -> Option<bool> {
+        self.read_f64(key).map(to_bool)
+    }
+
+    /// Reads an optional duration which was written by
+    /// [`SnapshotWriter::write_optional_duration`].
+    pub fn read_optional_duration(&mut self, key: &str) -> Option<Option<Duration>> {
+        self.read_f64(key).map(|value| {
+            if value < 0. {
+                None
+            } else {
+                Some(Duration::from_secs_f64(value))
+            }
+        })
+    }
+}
+
+struct SimulationToSnapshotVisitor {
+    elements: Vec<ElementState>,
+    keys: ElementKeys,
+    persistent: bool,
+}
+impl SimulationElementVisitor for SimulationToSnapshotVisitor {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        let key = self.keys.next::<T>();
+        let mut writer = SnapshotWriter::default();
+        if self.persistent {
+            visited.save_persistent_state(&mut writer);
+
+            // Elements are found by their key, thus those without state can be left out.
+            if writer.values.is_empty() {
+                return;
+            }
+        } else {
+            visited.save_state(&mut writer);
+        }
+
+        self.elements.push(ElementState {
+            key,
+            values: writer.values,
+        });
+    }
+}
+
+struct SnapshotToSimulationVisitor<'a> {
+    elements: std::slice::Iter<'a, ElementState>,
+}
+impl SimulationElementVisitor for SnapshotToSimulationVisitor<'_> {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        if let Some(state) = self.elements.next() {
+            if !state.values.is_empty() {
+                visited.restore_state(&mut SnapshotReader {
+                    values: &state.values,
+                });
+            }
+        }
+    }
+}
+
+/// Restores the state of a persistent snapshot into the elements with the same key.
+/// Elements without state in the snapshot keep their current state.
+struct KeyedSnapshotToSimulationVisitor<'a> {
+    elements: FxHashMap<&'a str, &'a [(String, f64)]>,
+    keys: ElementKeys,
+}
+impl SimulationElementVisitor for KeyedSnapshotToSimulationVisitor<'_> {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        if let Some(values) = self.elements.get(self.keys.next::<T>().as_str()) {
+            visited.restore_persistent_state(&mut SnapshotReader { values });
+        }
+    }
+}
+
+#[derive(Default)]
+struct ElementCounter {
+    count: usize,
+}
+impl SimulationElementVisitor for ElementCounter {
+    fn visit<T: SimulationElement>(&mut self, _: &mut T) {
+        self.count += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter {
+        count: f64,
+        is_on: bool,
+    }
+    impl Counter {
+        fn new() -> Self {
+            Self {
+                count: 0.,
+                is_on: false,
+            }
+        }
+    }
+    impl SimulationElement for Counter {
+        fn save_state(&self, writer: &mut SnapshotWriter) {
+            writer.write_f64("count", self.count);
+            writer.write_bool("is_on", self.is_on);
+        }
+
+        fn restore_state(&mut self, reader: &mut SnapshotReader) {
+            if let Some(count) = reader.read_f64("count") {
+                self.count = count;
+            }
+
+            if let Some(is_on) = reader.read_bool("is_on") {
+                self.is_on = is_on;
+            }
+        }
+    }
+
+    struct Stateless {}
+    impl SimulationElement for Stateless {}
+
+    struct Wearing {
+        wear: f64,
+        is_on: bool,
+    }
+    impl SimulationElement for Wearing {
+        fn save_state(&self, writer: &mut SnapshotWriter) {
+            writer.write_bool("is_on", self.is_on);
+        }
+
+        fn restore_state(&mut self, reader: &mut SnapshotReader) {
+            if let Some(is_on) = reader.read_bool("is_on") {
+                self.is_on = is_on;
+            }
+        }
+
+        fn save_persistent_state(&self, writer: &mut SnapshotWriter) {
+            writer.write_f64("wear", self.wear);
+        }
+
+        fn restore_persistent_state(&mut self, reader: &mut SnapshotReader) {
+            if let Some(wear) = reader.read_f64("wear") {
+                self.wear = wear;
+            }
+        }
+    }
+
+    struct Parent {
+        left: Counter,
+        stateless: Stateless,
+        right: Counter,
+    }
+    impl Parent {
+        fn new() -> Self {
+            Self {
+                left: Counter::new(),
+                stateless: Stateless {},
+                right: Counter::new(),
+            }
+        }
+    }
+    impl SimulationElement for Parent {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.left.accept(visitor);
+            self.stateless.accept(visitor);
+            self.right.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct WearingParent {
+        wearing: Vec<Wearing>,
+    }
+    impl WearingParent {
+        fn new(wear: &[f64]) -> Self {
+            Self {
+                wearing: wear
+                    .iter()
+                    .map(|&wear| Wearing { wear, is_on: false })
+                    .collect(),
+            }
+        }
+    }
+    impl SimulationElement for WearingParent {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.wearing.iter_mut().for_each(|w| w.accept(visitor));
+
+            visitor.visit(self);
+        }
+    }
+
+    fn saved_parent() -> (Parent, SimulationSnapshot) {
+        let mut parent = Parent::new();
+        parent.left.count = 5.;
+        parent.right.count = 7.;
+        parent.right.is_on = true;
+
+        let snapshot = SimulationSnapshot::save(&mut parent);
+
+        (parent, snapshot)
+    }
+
+    #[test]
+    fn contains_every_visited_element() {
+        let (_, snapshot) = saved_parent();
+
+        assert_eq!(snapshot.element_count(), 4);
+    }
+
+    #[test]
+    fn restores_state_of_each_element() {
+        let (_, snapshot) = saved_parent();
+
+        let mut restored = Parent::new();
+        assert_eq!(snapshot.restore(&mut restored), Ok(()));
+
+        assert_eq!(restored.left.count, 5.);
+        assert!(!restored.left.is_on);
+        assert_eq!(restored.right.count, 7.);
+        assert!(restored.right.is_on);
+    }
+
+    #[test]
+    fn restore_overwrites_state_changed_after_saving() {
+        let (mut parent, snapshot) = saved_parent();
+        parent.left.count = 100.;
+
+        snapshot.restore(&mut parent).unwrap();
+
+        assert_eq!(parent.left.count, 5.);
+    }
+
+    #[test]
+    fn restore_into_different_tree_fails() {
+        let (_, snapshot) = saved_parent();
+
+        assert_eq!(
+            snapshot.restore(&mut Counter::new()),
+            Err(SnapshotError::ElementCountMismatch {
+                expected: 1,
+                actual: 4
+            })
+        );
+    }
+
+    #[test]
+    fn missing_keys_keep_current_state() {
+        let mut counter = Counter::new();
+        counter.count = 3.;
+
+        let snapshot = SimulationSnapshot {
+            elements: vec![ElementState {
+                key: String::new(),
+                values: vec![("is_on".to_owned(), 1.)],
+            }],
+            random: None,
+        };
+        snapshot.restore(&mut counter).unwrap();
+
+        assert_eq!(counter.count, 3.);
+        assert!(counter.is_on);
+    }
+
+    #[test]
+    fn persistent_snapshot_only_restores_persistent_state() {
+        let mut wearing = Wearing {
+            wear: 0.3,
+            is_on: true,
+        };
+        let snapshot = SimulationSnapshot::save_persistent(&mut wearing);
+
+        let mut restored = Wearing {
+            wear: 0.,
+            is_on: false,
+        };
+        snapshot.restore_persistent(&mut restored).unwrap();
+
+        assert_eq!(restored.wear, 0.3);
+        assert!(!restored.is_on);
+    }
+
+    #[test]
+    fn persistent_snapshot_restores_into_a_tree_with_other_elements() {
+        let mut saved = WearingParent::new(&[0.1, 0.2]);
+        let snapshot = SimulationSnapshot::save_persistent(&mut saved);
+
+        struct GrownParent {
+            counter: Counter,
+            wearing: WearingParent,
+        }
+        impl SimulationElement for GrownParent {
+            fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+                self.counter.accept(visitor);
+                self.wearing.accept(visitor);
+
+                visitor.visit(self);
+            }
+        }
+
+        let mut restored = GrownParent {
+            counter: Counter::new(),
+            wearing: WearingParent::new(&[0., 0., 0.]),
+        };
+        snapshot.restore_persistent(&mut restored).unwrap();
+
+        let wear: Vec<f64> = restored.wearing.wearing.iter().map(|w| w.wear).collect();
+        assert_eq!(wear, vec![0.1, 0.2, 0.]);
+    }
+
+    #[test]
+    fn persistent_snapshot_only_contains_elements_with_persistent_state() {
+        let mut parent = WearingParent::new(&[0.1, 0.2]);
+
+        assert_eq!(
+            SimulationSnapshot::save_persistent(&mut parent).element_count(),
+            2
+        );
+    }
+
+    #[test]
+    fn snapshot_does_not_contain_persistent_state() {
+        let mut wearing = Wearing {
+            wear: 0.3,
+            is_on: true,
+        };
+        let snapshot = SimulationSnapshot::save(&mut wearing);
+
+        let mut restored = Wearing {
+            wear: 0.,
+            is_on: false,
+        };
+        snapshot.restore(&mut restored).unwrap();
+
+        assert_eq!(restored.wear, 0.);
+        assert!(restored.is_on);
+    }
+
+    #[test]
+    fn optional_durations_round_trip() {
+        let mut writer = SnapshotWriter::default();
+        writer.write_optional_duration("some", Some(Duration::from_secs(5)));
+        writer.write_optional_duration("none", None);
+
+        let mut reader = SnapshotReader {
+            values: &writer.values,
+        };
+
+        assert_eq!(
+            reader.read_optional_duration("some"),
+            Some(Some(Duration::from_secs(5)))
+        );
+        assert_eq!(reader.read_optional_duration("none"), Some(None));
+        assert_eq!(reader.read_optional_duration("missing"), None);
+    }
+
+    #[test]
+    fn survives_serialisation() {
+        let (_, snapshot) = saved_parent();
+
+        assert_eq!(
+            SimulationSnapshot::from_bytes(&snapshot.to_bytes()),
+            Ok(snapshot)
+        );
+    }
+
+    #[test]
+    fn random_state_survives_serialisation() {
+        let (_, snapshot) = saved_parent();
+        let snapshot = snapshot.with_random(SimulationRandomState {
+            seed: u64::MAX,
+            steps: 12,
+        });
+
+        assert_eq!(
+            SimulationSnapshot::from_bytes(&snapshot.to_bytes()),
+            Ok(snapshot)
+        );
+    }
+
+    #[test]
+    fn deserialising_other_data_fails() {
+        assert_eq!(
+            SimulationSnapshot::from_bytes(b"not a snapshot"),
+            Err(SnapshotError::InvalidHeader)
+        );
+    }
+
+    #[test]
+    fn deserialising_other_version_fails() {
+        let (_, snapshot) = saved_parent();
+        let mut bytes = snapshot.to_bytes();
+        bytes[4..8].copy_from_slice(&(SimulationSnapshot::FORMAT_VERSION + 1).to_le_bytes());
+
+        assert_eq!(
+            SimulationSnapshot::from_bytes(&bytes),
+            Err(SnapshotError::UnsupportedVersion(
+                SimulationSnapshot::FORMAT_VERSION + 1
+            ))
+        );
+    }
+
+    #[test]
+    fn deserialising_truncated_data_fails() {
+        let (_, snapshot) = saved_parent();
+        let bytes = snapshot.to_bytes();
+
+        assert_eq!(
+            SimulationSnapshot::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(SnapshotError::Truncated)
+        );
+    }
+}
@@ -0,0 +1,762 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    io,
+    net::{SocketAddr, ToSocketAddrs, UdpSocket},
+};
+
+use fxhash::FxHashMap;
+
+use super::{
+    byte_reader::{ByteReader, Truncated},
+    SimulatorReaderWriter, VariableIdentifier, VariableRegistry,
+};
+
+/// Streams a selected set of simulation variables to an external application over a
+/// local UDP socket, and accepts overrides for the variables the simulation reads.
+///
+/// Variables are identified by the name they are registered with, without the aircraft's
+/// key prefix. The bridge learns these names through the registry returned by
+/// [`variable_registry`], which has to be used to create the simulation. Every frame
+/// then consists of receiving overrides, ticking the simulation with the reader writer
+/// returned by [`frame`] and publishing the values:
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use systems::simulation::{Aircraft, InitContext, Simulation, SimulationElement,
+/// # SimulatorReaderWriter, StartState, VariableIdentifier, VariableRegistry, TelemetryBridge};
+/// # struct MyAircraft {}
+/// # impl Aircraft for MyAircraft {}
+/// # impl SimulationElement for MyAircraft {}
+/// # struct MyReaderWriter {}
+/// # impl SimulatorReaderWriter for MyReaderWriter {
+/// #     fn read(&mut self, identifier: &VariableIdentifier) -> f64 { 0.0 }
+/// #     fn write(&mut self, identifier: &VariableIdentifier, value: f64) { }
+/// # }
+/// # struct MyVariableRegistry {}
+/// # impl VariableRegistry for MyVariableRegistry {
+/// #     fn get(&mut self, name: String) -> VariableIdentifier {
+/// #         Default::default()
+/// #     }
+/// # }
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut registry = MyVariableRegistry {};
+/// let mut bridge = TelemetryBridge::bind(
+///     "127.0.0.1:51000",
+///     "127.0.0.1:51001",
+///     &["ELEC_AC_1_BUS_IS_POWERED"],
+/// )?;
+/// let mut simulation = Simulation::new(
+///     StartState::Cruise,
+///     |_: &mut InitContext| MyAircraft {},
+///     &mut bridge.variable_registry(&mut registry),
+/// );
+///
+/// let mut reader_writer = MyReaderWriter {};
+/// bridge.receive_overrides()?;
+/// simulation.tick(Duration::from_millis(50), 20., &mut bridge.frame(20., &mut reader_writer));
+/// bridge.publish()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Format
+/// Both directions use little endian binary datagrams, starting with a magic number
+/// and the [`FORMAT_VERSION`]. Names are UTF-8 and prefixed with their length.
+///
+/// A frame sent by the bridge, see [`TelemetryFrame`]:
+/// - `FBWF` (4 bytes), version (`u32`), simulation time in seconds (`f64`),
+///   value count (`u32`), and per value: name length (`u16`), name, value (`f64`).
+///
+/// Overrides received by the bridge, see [`TelemetryOverrides`]:
+/// - `FBWO` (4 bytes), version (`u32`), override count (`u32`), and per override:
+///   name length (`u16`), name, value (`f64`).
+///
+/// An override replaces the value the simulation reads for the variable until it is
+/// cleared by sending an override with a NaN value. Overrides don't affect the values
+/// the simulation writes.
+///
+/// [`variable_registry`]: #method.variable_registry
+/// [`frame`]: #method.frame
+/// [`FORMAT_VERSION`]: #associatedconstant.FORMAT_VERSION
+pub struct TelemetryBridge {
+    socket: UdpSocket,
+    published_names: Vec<String>,
+    published_values: Vec<Option<f64>>,
+    identifier_to_published_index: FxHashMap<VariableIdentifier, usize>,
+    name_to_identifier: FxHashMap<String, VariableIdentifier>,
+    overrides: FxHashMap<VariableIdentifier, f64>,
+    simulation_time: f64,
+}
+impl TelemetryBridge {
+    /// The version of the datagram format. Increase it whenever the layout of
+    /// [`TelemetryFrame`] or [`TelemetryOverrides`] changes.
+    pub const FORMAT_VERSION: u32 = 1;
+    const MAX_DATAGRAM_SIZE: usize = 65_507;
+
+    /// Binds the bridge to the local address. Frames are sent to and overrides are
+    /// only accepted from the peer address.
+    pub fn bind(
+        local_address: impl ToSocketAddrs,
+        peer_address: impl ToSocketAddrs,
+        published_names: &[&str],
+    ) -> io::Result<Self> {
+        let socket = UdpSocket::bind(local_address)?;
+        socket.connect(peer_address)?;
+        socket.set_nonblocking(true)?;
+
+        Ok(Self {
+            socket,
+            published_names: published_names
+                .iter()
+                .map(|name| (*name).to_owned())
+                .collect(),
+            published_values: vec![None; published_names.len()],
+            identifier_to_published_index: FxHashMap::default(),
+            name_to_identifier: FxHashMap::default(),
+            overrides: FxHashMap::default(),
+            simulation_time: 0.,
+        })
+    }
+
+    pub fn local_address(&self) -> io::Result<SocketAddr> {
+        self.socket.local_addr()
+    }
+
+    /// Wraps the registry used to create the simulation, such that the bridge
+    /// knows the names of the variables the simulation reads and writes.
+    pub fn variable_registry<'a, T: VariableRegistry>(
+        &'a mut self,
+        registry: &'a mut T,
+    ) -> TelemetryVariableRegistry<'a, T> {
+        TelemetryVariableRegistry {
+            bridge: self,
+            registry,
+        }
+    }
+
+    /// Starts a new frame. The returned reader writer applies the overrides to reads and
+    /// captures the published variables read and written through it.
+    pub fn frame<'a, T: SimulatorReaderWriter>(
+        &'a mut self,
+        simulation_time: f64,
+        reader_writer: &'a mut T,
+    ) -> TelemetryReaderWriter<'a, T> {
+        self.simulation_time = simulation_time;
+
+        TelemetryReaderWriter {
+            bridge: self,
+            reader_writer,
+        }
+    }
+
+    /// Receives all overrides sent by the peer since the previous call. Invalid datagrams
+    /// are skipped and reported in the result, such that they don't hold up the datagrams
+    /// following them. Only socket errors are returned as an error.
+    pub fn receive_overrides(&mut self) -> Result<ReceivedOverrides, TelemetryError> {
+        let mut buffer = vec![0; Self::MAX_DATAGRAM_SIZE];
+        let mut received = ReceivedOverrides::default();
+        loop {
+            match self.socket.recv(&mut buffer) {
+                Ok(length) => match TelemetryOverrides::from_bytes(&buffer[..length])
+                    .and_then(|overrides| self.apply(overrides))
+                {
+                    Ok(()) => received.applied_count += 1,
+                    Err(error) => received.rejected.push(error),
+                },
+                // The peer not listening yet is reported as a refused connection.
+                Err(error) if error.kind() == io::ErrorKind::ConnectionRefused => {}
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => return Ok(received),
+                Err(error) => return Err(error.into()),
+            }
+        }
+    }
+
+    /// Sends the published variables of the current frame to the peer. Variables which
+    /// weren't read or written by the simulation yet are left out.
+    pub fn publish(&mut self) -> Result<(), TelemetryError> {
+        match self.socket.send(&self.current_frame().to_bytes()) {
+            Ok(_) => Ok(()),
+            Err(error) if error.kind() == io::ErrorKind::ConnectionRefused => Ok(()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn is_overridden(&self, name: &str) -> bool {
+        self.name_to_identifier
+            .get(name)
+            .map_or(false, |identifier| self.overrides.contains_key(identifier))
+    }
+
+    fn current_frame(&self) -> TelemetryFrame {
+        TelemetryFrame {
+            simulation_time: self.simulation_time,
+            values: self
+                .published_names
+                .iter()
+                .zip(&self.published_values)
+                .filter_map(|(name, value)| value.map(|value| (name.clone(), value)))
+                .collect(),
+        }
+    }
+
+    fn apply(&mut self, overrides: TelemetryOverrides) -> Result<(), TelemetryError> {
+        // Validate all names first, such that a datagram is either applied entirely or not at all.
+        let identifiers = overrides
+            .values
+            .iter()
+            .map(|(name, _)| {
+                self.name_to_identifier
+                    .get(name)
+                    .copied()
+                    .ok_or_else(|| TelemetryError::UnknownVariable(name.clone()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (identifier, (_, value)) in identifiers.into_iter().zip(overrides.values) {
+            if value.is_nan() {
+                self.overrides.remove(&identifier);
+            } else {
+                self.overrides.insert(identifier, value);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn register(&mut self, identifier: VariableIdentifier, name: String) {
+        if let Some(index) = self.published_names.iter().position(|n| *n == name) {
+            self.identifier_to_published_index.insert(identifier, index);
+        }
+
+        self.name_to_identifier.insert(name, identifier);
+    }
+
+    fn capture(&mut self, identifier: &VariableIdentifier, value: f64) {
+        if let Some(&index) = self.identifier_to_published_index.get(identifier) {
+            self.published_values[index] = Some(value);
+        }
+    }
+}
+
+/// The datagrams received by [`TelemetryBridge::receive_overrides`].
+///
+/// [`TelemetryBridge::receive_overrides`]: struct.TelemetryBridge.html#method.receive_overrides
+#[derive(Debug, Default)]
+pub struct ReceivedOverrides {
+    applied_count: usize,
+    rejected: Vec<TelemetryError>,
+}
+impl ReceivedOverrides {
+    /// The number of datagrams of which the overrides were applied.
+    pub fn applied_count(&self) -> usize {
+        self.applied_count
+    }
+
+    /// The reasons for rejecting the invalid datagrams, one per datagram.
+    pub fn rejected(&self) -> &[TelemetryError] {
+        &self.rejected
+    }
+
+    /// The number of datagrams received, whether valid or not.
+    pub fn count(&self) -> usize {
+        self.applied_count + self.rejected.len()
+    }
+}
+
+pub struct TelemetryVariableRegistry<'a, T: VariableRegistry> {
+    bridge: &'a mut TelemetryBridge,
+    registry: &'a mut T,
+}
+impl<'a, T: VariableRegistry> VariableRegistry for TelemetryVariableRegistry<'a, T> {
+    fn get(&mut self, name: String) -> VariableIdentifier {
+        let identifier = self.registry.get(name.clone());
+        self.bridge.register(identifier, name);
+
+        identifier
+    }
+}
+
+pub struct TelemetryReaderWriter<'a, T: SimulatorReaderWriter> {
+    bridge: &'a mut TelemetryBridge,
+    reader_writer: &'a mut T,
+}
+impl<'a, T: SimulatorReaderWriter> SimulatorReaderWriter for TelemetryReaderWriter<'a, T> {
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        let value = match self.bridge.overrides.get(identifier) {
+            Some(value) => *value,
+            None => self.reader_writer.read(identifier),
+        };
+        self.bridge.capture(identifier, value);
+
+        value
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        self.reader_writer.write(identifier, value);
+        self.bridge.capture(identifier, value);
+    }
+}
+
+/// The values of the published variables at the end of a frame, as sent by a [`TelemetryBridge`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TelemetryFrame {
+    simulation_time: f64,
+    values: Vec<(String, f64)>,
+}
+impl TelemetryFrame {
+    const MAGIC: &'static [u8; 4] = b"FBWF";
+
+    pub fn simulation_time(&self) -> f64 {
+        self.simulation_time
+    }
+
+    pub fn value(&self, name: &str) -> Option<f64> {
+        self.values
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| *value)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(Self::MAGIC);
+        bytes.extend_from_slice(&TelemetryBridge::FORMAT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&self.simulation_time.to_le_bytes());
+        write_values(&mut bytes, &self.values);
+
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TelemetryError> {
+        let mut bytes = ByteReader::new(bytes);
+        read_header(&mut bytes, Self::MAGIC)?;
+        let simulation_time = bytes.read_f64()?;
+        let values = read_values(&mut bytes)?;
+
+        Ok(Self {
+            simulation_time,
+            values,
+        })
+    }
+}
+
+/// Overrides for the variables read by the simulation, as received by a [`TelemetryBridge`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TelemetryOverrides {
+    values: Vec<(String, f64)>,
+}
+impl TelemetryOverrides {
+    const MAGIC: &'static [u8; 4] = b"FBWO";
+
+    /// Overrides the value of the variable with the given name.
+    pub fn set(mut self, name: &str, value: f64) -> Self {
+        self.values.push((name.to_owned(), value));
+        self
+    }
+
+    /// Clears the override of the variable with the given name.
+    pub fn clear(self, name: &str) -> Self {
+        self.set(name, f64::NAN)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(Self::MAGIC);
+        bytes.extend_from_slice(&TelemetryBridge::FORMAT_VERSION.to_le_bytes());
+        write_values(&mut bytes, &self.values);
+
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TelemetryError> {
+        let mut bytes = ByteReader::new(bytes);
+        read_header(&mut bytes, Self::MAGIC)?;
+        let values = read_values(&mut bytes)?;
+
+        Ok(Self { values })
+    }
+}
+
+fn write_values(bytes: &mut Vec<u8>, values: &[(String, f64)]) {
+    bytes.extend_from_slice(&(values.len() as u32).to_le_bytes());
+    for (name, value) in values {
+        bytes.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(name.as_bytes());
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+fn read_header(bytes: &mut ByteReader, magic: &[u8; 4]) -> Result<(), TelemetryError> {
+    if bytes.take(magic.len())? != magic {
+        return Err(TelemetryError::InvalidHeader);
+    }
+
+    let version = bytes.read_u32()?;
+    if version != TelemetryBridge::FORMAT_VERSION {
+        return Err(TelemetryError::UnsupportedVersion(version));
+    }
+
+    Ok(())
+}
+
+fn read_values(bytes: &mut ByteReader) -> Result<Vec<(String, f64)>, TelemetryError> {
+    let count = bytes.read_u32()?;
+    let mut values = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let name_length = bytes.read_u16()?;
+        let name = String::from_utf8(bytes.take(name_length as usize)?.to_vec())
+            .map_err(|_| TelemetryError::InvalidVariableName)?;
+        let value = bytes.read_f64()?;
+
+        values.push((name, value));
+    }
+
+    if !bytes.is_empty() {
+        return Err(TelemetryError::TrailingData);
+    }
+
+    Ok(values)
+}
+
+#[derive(Debug)]
+pub enum TelemetryError {
+    Io(io::Error),
+    InvalidHeader,
+    UnsupportedVersion(u32),
+    Truncated,
+    TrailingData,
+    InvalidVariableName,
+    UnknownVariable(String),
+}
+impl Display for TelemetryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TelemetryError::Io(error) => write!(f, "Telemetry socket error: {}.", error),
+            TelemetryError::InvalidHeader => write!(f, "The datagram is not telemetry data."),
+            TelemetryError::UnsupportedVersion(version) => write!(
+                f,
+                "Telemetry format version {} is not supported, expected version {}.",
+                version,
+                TelemetryBridge::FORMAT_VERSION
+            ),
+            TelemetryError::Truncated => write!(f, "The telemetry datagram ended unexpectedly."),
+            TelemetryError::TrailingData => {
+                write!(f, "The telemetry datagram continues after the last value.")
+            }
+            TelemetryError::InvalidVariableName => write!(
+                f,
+                "The telemetry datagram contains a variable name which isn't UTF-8."
+            ),
+            TelemetryError::UnknownVariable(name) => write!(
+                f,
+                "The telemetry datagram overrides unknown variable '{}'.",
+                name
+            ),
+        }
+    }
+}
+impl Error for TelemetryError {}
+impl From<Truncated> for TelemetryError {
+    fn from(_: Truncated) -> Self {
+        TelemetryError::Truncated
+    }
+}
+impl From<io::Error> for TelemetryError {
+    fn from(error: io::Error) -> Self {
+        TelemetryError::Io(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        Aircraft, InitContext, Read, Simulation, SimulationElement, SimulatorReader,
+        SimulatorWriter, StartState, UpdateContext, Write,
+    };
+    use std::time::{Duration, Instant};
+
+    /// Counts up the value it reads and writes the result.
+    struct Counter {
+        input_id: VariableIdentifier,
+        output_id: VariableIdentifier,
+        input: f64,
+        total: f64,
+    }
+    impl Counter {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                input_id: context.get_identifier("COUNTER_INPUT".to_owned()),
+                output_id: context.get_identifier("COUNTER_TOTAL".to_owned()),
+                input: 0.,
+                total: 0.,
+            }
+        }
+    }
+    impl Aircraft for Counter {
+        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
+            self.total += self.input;
+        }
+    }
+    impl SimulationElement for Counter {
+        fn read(&mut self, reader: &mut SimulatorReader) {
+            self.input = reader.read(&self.input_id);
+        }
+
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&self.output_id, self.total);
+        }
+    }
+
+    #[derive(Default)]
+    struct TestReaderWriter {
+        names: FxHashMap<String, VariableIdentifier>,
+        next_identifier: VariableIdentifier,
+        values: FxHashMap<VariableIdentifier, f64>,
+    }
+    impl TestReaderWriter {
+        fn set(&mut self, name: &str, value: f64) {
+            let identifier = self.names[name];
+            self.values.insert(identifier, value);
+        }
+    }
+    impl VariableRegistry for TestReaderWriter {
+        fn get(&mut self, name: String) -> VariableIdentifier {
+            let identifier = self.next_identifier;
+            self.next_identifier = identifier.next();
+            *self.names.entry(name).or_insert(identifier)
+        }
+    }
+    impl SimulatorReaderWriter for TestReaderWriter {
+        fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+            *self.values.get(identifier).unwrap_or(&0.)
+        }
+
+        fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+            self.values.insert(*identifier, value);
+        }
+    }
+
+    struct TestBed {
+        peer: UdpSocket,
+        bridge: TelemetryBridge,
+        simulation: Simulation<Counter>,
+        reader_writer: TestReaderWriter,
+        simulation_time: f64,
+    }
+    impl TestBed {
+        fn new(published_names: &[&str]) -> Self {
+            let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+            peer.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+            let mut bridge =
+                TelemetryBridge::bind("127.0.0.1:0", peer.local_addr().unwrap(), published_names)
+                    .unwrap();
+            peer.connect(bridge.local_address().unwrap()).unwrap();
+
+            let mut reader_writer = TestReaderWriter::default();
+            let simulation = Simulation::new(
+                StartState::Cruise,
+                Counter::new,
+                &mut bridge.variable_registry(&mut reader_writer),
+            );
+
+            Self {
+                peer,
+                bridge,
+                simulation,
+                reader_writer,
+                simulation_time: 0.,
+            }
+        }
+
+        fn run(&mut self) {
+            self.simulation_time += 0.1;
+            self.simulation.tick(
+                Duration::from_millis(100),
+                self.simulation_time,
+                &mut self
+                    .bridge
+                    .frame(self.simulation_time, &mut self.reader_writer),
+            );
+            self.bridge.publish().unwrap();
+        }
+
+        fn received_frame(&self) -> TelemetryFrame {
+            let mut buffer = [0; 1024];
+            let length = self.peer.recv(&mut buffer).unwrap();
+            TelemetryFrame::from_bytes(&buffer[..length]).unwrap()
+        }
+
+        fn send_overrides(&mut self, overrides: TelemetryOverrides) -> ReceivedOverrides {
+            self.send_datagrams(&[overrides.to_bytes()])
+        }
+
+        fn send_datagrams(&mut self, datagrams: &[Vec<u8>]) -> ReceivedOverrides {
+            for datagram in datagrams {
+                self.peer.send(datagram).unwrap();
+            }
+
+            // Loopback datagrams usually arrive immediately, but give them some time.
+            let mut received = ReceivedOverrides::default();
+            let start = Instant::now();
+            while received.count() < datagrams.len() {
+                let mut newly_received = self.bridge.receive_overrides().unwrap();
+                received.applied_count += newly_received.applied_count;
+                received.rejected.append(&mut newly_received.rejected);
+
+                assert!(
+                    start.elapsed() < Duration::from_secs(5),
+                    "The overrides didn't arrive."
+                );
+                std::thread::sleep(Duration::from_millis(1));
+            }
+
+            received
+        }
+    }
+
+    #[test]
+    fn publishes_the_selected_variables_every_frame() {
+        let mut test_bed = TestBed::new(&["COUNTER_TOTAL"]);
+        test_bed.reader_writer.set("COUNTER_INPUT", 2.);
+
+        test_bed.run();
+        assert_eq!(test_bed.received_frame().value("COUNTER_TOTAL"), Some(2.));
+
+        test_bed.run();
+        let frame = test_bed.received_frame();
+        assert_eq!(frame.value("COUNTER_TOTAL"), Some(4.));
+        assert_eq!(frame.value("COUNTER_INPUT"), None);
+        assert!((frame.simulation_time() - 0.2).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn publishes_read_variables() {
+        let mut test_bed = TestBed::new(&["COUNTER_INPUT"]);
+        test_bed.reader_writer.set("COUNTER_INPUT", 3.);
+
+        test_bed.run();
+
+        assert_eq!(test_bed.received_frame().value("COUNTER_INPUT"), Some(3.));
+    }
+
+    #[test]
+    fn override_replaces_the_value_read_by_the_simulation() {
+        let mut test_bed = TestBed::new(&["COUNTER_TOTAL"]);
+        test_bed.reader_writer.set("COUNTER_INPUT", 1.);
+
+        test_bed.send_overrides(TelemetryOverrides::default().set("COUNTER_INPUT", 5.));
+        test_bed.run();
+
+        assert!(test_bed.bridge.is_overridden("COUNTER_INPUT"));
+        assert_eq!(test_bed.received_frame().value("COUNTER_TOTAL"), Some(5.));
+    }
+
+    #[test]
+    fn cleared_override_no_longer_replaces_the_value_read_by_the_simulation() {
+        let mut test_bed = TestBed::new(&["COUNTER_TOTAL"]);
+        test_bed.reader_writer.set("COUNTER_INPUT", 1.);
+        test_bed.send_overrides(TelemetryOverrides::default().set("COUNTER_INPUT", 5.));
+
+        test_bed.send_overrides(TelemetryOverrides::default().clear("COUNTER_INPUT"));
+        test_bed.run();
+
+        assert!(!test_bed.bridge.is_overridden("COUNTER_INPUT"));
+        assert_eq!(test_bed.received_frame().value("COUNTER_TOTAL"), Some(1.));
+    }
+
+    #[test]
+    fn override_of_unknown_variable_is_rejected() {
+        let mut test_bed = TestBed::new(&[]);
+
+        let received = test_bed.send_overrides(
+            TelemetryOverrides::default()
+                .set("COUNTER_INPUT", 5.)
+                .set("UNKNOWN", 1.),
+        );
+
+        assert_eq!(received.applied_count(), 0);
+        assert!(
+            matches!(received.rejected(), [TelemetryError::UnknownVariable(name)] if name == "UNKNOWN")
+        );
+        assert!(!test_bed.bridge.is_overridden("COUNTER_INPUT"));
+    }
+
+    #[test]
+    fn invalid_datagram_doesnt_hold_up_the_datagrams_following_it() {
+        let mut test_bed = TestBed::new(&["COUNTER_TOTAL"]);
+        test_bed.reader_writer.set("COUNTER_INPUT", 1.);
+
+        let received = test_bed.send_datagrams(&[
+            b"not telemetry".to_vec(),
+            TelemetryOverrides::default()
+                .set("COUNTER_INPUT", 5.)
+                .to_bytes(),
+        ]);
+        test_bed.run();
+
+        assert_eq!(received.applied_count(), 1);
+        assert!(matches!(
+            received.rejected(),
+            [TelemetryError::InvalidHeader]
+        ));
+        assert_eq!(test_bed.received_frame().value("COUNTER_TOTAL"), Some(5.));
+    }
+
+    #[test]
+    fn publishing_without_a_listening_peer_succeeds() {
+        let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let peer_address = peer.local_addr().unwrap();
+        drop(peer);
+
+        let mut bridge = TelemetryBridge::bind("127.0.0.1:0", peer_address, &[]).unwrap();
+
+        assert!(bridge.publish().is_ok());
+        assert!(bridge.publish().is_ok());
+        assert!(matches!(bridge.receive_overrides(), Ok(received) if received.count() == 0));
+    }
+
+    #[test]
+    fn frame_serialisation_round_trip_preserves_the_frame() {
+        let frame = TelemetryFrame {
+            simulation_time: 12.5,
+            values: vec![("A".to_owned(), 1.), ("B".to_owned(), -2.5)],
+        };
+
+        assert_eq!(
+            TelemetryFrame::from_bytes(&frame.to_bytes()).unwrap(),
+            frame
+        );
+    }
+
+    #[test]
+    fn rejects_data_which_is_not_telemetry() {
+        assert!(matches!(
+            TelemetryOverrides::from_bytes(b"not telemetry"),
+            Err(TelemetryError::InvalidHeader)
+        ));
+    }
+
+    #[test]
+    fn rejects_frames_as_overrides() {
+        let frame = TelemetryFrame {
+            simulation_time: 1.,
+            values: vec![],
+        };
+
+        assert!(matches!(
+            TelemetryOverrides::from_bytes(&frame.to_bytes()),
+            Err(TelemetryError::InvalidHeader)
+        ));
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        let bytes = TelemetryOverrides::default().set("A", 1.).to_bytes();
+
+        assert!(matches!(
+            TelemetryOverrides::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(TelemetryError::Truncated)
+        ));
+    }
+}
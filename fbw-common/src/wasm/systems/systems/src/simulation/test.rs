@@ -1,5 +1,6 @@
 use fxhash::FxHashMap;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg32;
 use std::{cell::Ref, time::Duration};
 use uom::si::{
     acceleration::foot_per_second_squared,
@@ -14,13 +15,13 @@ use uom::si::{
 
 use crate::{
     electrical::{Electricity, Potential},
-    failures::FailureType,
+    failures::{FailureState, FailureTrigger, FailureType},
 };
 
 use super::{
     Aircraft, Read, Reader, Simulation, SimulationElement, SimulationElementVisitor,
-    SimulationToSimulatorVisitor, SimulatorReaderWriter, SimulatorWriter, UpdateContext, Write,
-    Writer,
+    SimulationSnapshot, SimulationToSimulatorVisitor, SimulatorReaderWriter, SimulatorWriter,
+    SnapshotError, UpdateContext, Write, Writer,
 };
 use crate::landing_gear::LandingGear;
 use crate::shared::arinc429::{from_arinc429, to_arinc429, Arinc429Word, SignStatus};
@@ -55,10 +56,38 @@ pub trait TestBed {
         self.test_bed_mut().unfail(failure_type);
     }
 
+    fn arm_failure(&mut self, failure_type: FailureType, trigger: FailureTrigger) {
+        self.test_bed_mut().arm_failure(failure_type, trigger);
+    }
+
+    fn disarm_failure(&mut self, failure_type: FailureType) {
+        self.test_bed_mut().disarm_failure(failure_type);
+    }
+
+    fn failure_state(&self, failure_type: FailureType) -> FailureState {
+        self.test_bed().failure_state(failure_type)
+    }
+
     fn command<V: FnOnce(&mut Self::Aircraft)>(&mut self, func: V) {
         self.test_bed_mut().command(func);
     }
 
+    fn snapshot(&mut self) -> SimulationSnapshot {
+        self.test_bed_mut().snapshot()
+    }
+
+    fn restore(&mut self, snapshot: &SimulationSnapshot) -> Result<(), SnapshotError> {
+        self.test_bed_mut().restore(snapshot)
+    }
+
+    fn persistent_snapshot(&mut self) -> SimulationSnapshot {
+        self.test_bed_mut().persistent_snapshot()
+    }
+
+    fn restore_persistent(&mut self, snapshot: &SimulationSnapshot) -> Result<(), SnapshotError> {
+        self.test_bed_mut().restore_persistent(snapshot)
+    }
+
     fn query<V: FnOnce(&Self::Aircraft) -> W, W>(&self, func: V) -> W {
         self.test_bed().query(func)
     }
@@ -237,8 +266,15 @@ pub struct SimulationTestBed<T: Aircraft> {
     reader_writer: TestReaderWriter,
     simulation: Simulation<T>,
     variable_registry: TestVariableRegistry,
+    frame_delta_random: Pcg32,
 }
 impl<T: Aircraft> SimulationTestBed<T> {
+    /// The seed used by test beds which aren't given an explicit seed. A fixed seed keeps
+    /// test runs reproducible; use [`new_with_random_seed`] to test other seeds.
+    ///
+    /// [`new_with_random_seed`]: #method.new_with_random_seed
+    pub const DEFAULT_RANDOM_SEED: u64 = 0x5EED;
+
     pub fn new<U: FnOnce(&mut InitContext) -> T>(aircraft_ctor_fn: U) -> Self {
         Self::new_with_start_state(Default::default(), aircraft_ctor_fn)
     }
@@ -246,12 +282,45 @@ impl<T: Aircraft> SimulationTestBed<T> {
     pub fn new_with_start_state<U: FnOnce(&mut InitContext) -> T>(
         start_state: StartState,
         aircraft_ctor_fn: U,
+    ) -> Self {
+        Self::new_with_start_state_and_random_seed(
+            start_state,
+            aircraft_ctor_fn,
+            Self::DEFAULT_RANDOM_SEED,
+        )
+    }
+
+    /// Creates a test bed of which the simulation and the frame durations used by
+    /// [`run_multiple_frames`] are deterministic for the given seed.
+    ///
+    /// [`run_multiple_frames`]: #method.run_multiple_frames
+    pub fn new_with_random_seed<U: FnOnce(&mut InitContext) -> T>(
+        random_seed: u64,
+        aircraft_ctor_fn: U,
+    ) -> Self {
+        Self::new_with_start_state_and_random_seed(
+            Default::default(),
+            aircraft_ctor_fn,
+            random_seed,
+        )
+    }
+
+    pub fn new_with_start_state_and_random_seed<U: FnOnce(&mut InitContext) -> T>(
+        start_state: StartState,
+        aircraft_ctor_fn: U,
+        random_seed: u64,
     ) -> Self {
         let mut variable_registry = TestVariableRegistry::default();
         let mut test_bed = Self {
             reader_writer: TestReaderWriter::new(),
-            simulation: Simulation::new(start_state, aircraft_ctor_fn, &mut variable_registry),
+            simulation: Simulation::new_with_random_seed(
+                start_state,
+                aircraft_ctor_fn,
+                &mut variable_registry,
+                random_seed,
+            ),
             variable_registry,
+            frame_delta_random: Pcg32::seed_from_u64(random_seed),
         };
 
         test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
@@ -278,6 +347,10 @@ impl<T: Aircraft> SimulationTestBed<T> {
         self.simulation.accept(&mut visitor);
     }
 
+    pub fn random_seed(&self) -> u64 {
+        self.simulation.random_seed()
+    }
+
     /// Runs a single 1 second duration [Simulation] tick on the contained [Aircraft].
     fn run(&mut self) {
         self.run_with_delta(Duration::from_secs(1));
@@ -296,12 +369,10 @@ impl<T: Aircraft> SimulationTestBed<T> {
     /// [`Aircraft`]: ../trait.Aircraft.html
     /// [`Simulation`]: ../struct.Simulation.html
     pub fn run_multiple_frames(&mut self, delta: Duration) {
-        let mut rng = rand::thread_rng();
-
         let mut executed_duration = Duration::from_secs(0);
         while executed_duration < delta {
             // Randomly set delta for 12 to 200ms, giving a simulated 83 to 5 fps refresh
-            let current_delta = Duration::from_millis(rng.gen_range(12..200));
+            let current_delta = Duration::from_millis(self.frame_delta_random.gen_range(12..200));
 
             if executed_duration + current_delta > delta {
                 self.simulation.tick(
@@ -321,6 +392,22 @@ impl<T: Aircraft> SimulationTestBed<T> {
         }
     }
 
+    fn snapshot(&mut self) -> SimulationSnapshot {
+        self.simulation.snapshot()
+    }
+
+    fn restore(&mut self, snapshot: &SimulationSnapshot) -> Result<(), SnapshotError> {
+        self.simulation.restore(snapshot)
+    }
+
+    fn persistent_snapshot(&mut self) -> SimulationSnapshot {
+        self.simulation.persistent_snapshot()
+    }
+
+    fn restore_persistent(&mut self, snapshot: &SimulationSnapshot) -> Result<(), SnapshotError> {
+        self.simulation.restore_persistent(snapshot)
+    }
+
     fn fail(&mut self, failure_type: FailureType) {
         self.simulation.activate_failure(failure_type);
     }
@@ -329,6 +416,18 @@ impl<T: Aircraft> SimulationTestBed<T> {
         self.simulation.deactivate_failure(failure_type);
     }
 
+    fn arm_failure(&mut self, failure_type: FailureType, trigger: FailureTrigger) {
+        self.simulation.arm_failure(failure_type, trigger);
+    }
+
+    fn disarm_failure(&mut self, failure_type: FailureType) {
+        self.simulation.disarm_failure(failure_type);
+    }
+
+    fn failure_state(&self, failure_type: FailureType) -> FailureState {
+        self.simulation.failure_state(failure_type)
+    }
+
     fn aircraft(&self) -> &T {
         self.simulation.aircraft()
     }
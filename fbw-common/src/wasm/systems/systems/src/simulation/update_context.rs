@@ -14,7 +14,10 @@ use uom::si::{
 
 use super::{Read, SimulatorReader};
 use crate::{
-    shared::{low_pass_filter::LowPassFilter, MachNumber},
+    shared::{
+        low_pass_filter::LowPassFilter, random_from_normal_distribution, random_from_range,
+        random_number, MachNumber,
+    },
     simulation::{InitContext, VariableIdentifier},
 };
 use nalgebra::{Rotation3, Vector3};
@@ -274,6 +277,8 @@ pub struct UpdateContext {
 
     rotation_accel: Vector3<AngularAcceleration>,
     rotation_vel: Vector3<AngularVelocity>,
+
+    random_seed: u64,
 }
 impl UpdateContext {
     pub(crate) const IS_READY_KEY: &'static str = "IS_READY";
@@ -437,6 +442,8 @@ impl UpdateContext {
 
             rotation_accel: Vector3::default(),
             rotation_vel: Vector3::default(),
+
+            random_seed: 0,
         }
     }
 
@@ -530,6 +537,8 @@ impl UpdateContext {
 
             rotation_accel: Vector3::default(),
             rotation_vel: Vector3::default(),
+
+            random_seed: context.random_seed(),
         }
     }
 
@@ -697,6 +706,27 @@ impl UpdateContext {
         self.simulation_time
     }
 
+    /// The seed of the random number generator owned by the simulation.
+    pub fn random_seed(&self) -> u64 {
+        self.random_seed
+    }
+
+    /// Draws a random number from the simulation's random number generator.
+    pub fn random_number(&self) -> u8 {
+        random_number()
+    }
+
+    /// Draws a random number within the given range from the simulation's random number generator.
+    pub fn random_from_range(&self, from: f64, to: f64) -> f64 {
+        random_from_range(from, to)
+    }
+
+    /// Draws a random number from a normal distribution using the simulation's random
+    /// number generator. The output is limited to -4 / +4 sigma.
+    pub fn random_from_normal_distribution(&self, mean: f64, std_dev: f64) -> f64 {
+        random_from_normal_distribution(mean, std_dev)
+    }
+
     pub fn is_sim_ready(&self) -> bool {
         self.simulation_time >= 2.0 && self.is_ready
     }
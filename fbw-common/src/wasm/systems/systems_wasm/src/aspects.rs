@@ -1,9 +1,9 @@
+use crate::sim_connect::{SimConnect, SimConnectRecv, SIMCONNECT_OBJECT_ID_USER};
 use crate::{
     f64_to_sim_connect_32k_pos, sim_connect_32k_pos_inv_to_f64, sim_connect_32k_pos_to_f64,
     MsfsVariableRegistry, Variable,
 };
 use enum_dispatch::enum_dispatch;
-use msfs::sim_connect::{SimConnect, SimConnectRecv, SIMCONNECT_OBJECT_ID_USER};
 use msfs::sys;
 use std::error::Error;
 use std::time::{Duration, Instant};
@@ -762,22 +762,18 @@ impl EventToVariable {
         })
     }
 
-    fn map_to_value(
-        &self,
-        e: &msfs::sys::SIMCONNECT_RECV_EVENT,
-        variables: &mut MsfsVariableRegistry,
-    ) -> f64 {
+    fn map_to_value(&self, event_data: sys::DWORD, variables: &mut MsfsVariableRegistry) -> f64 {
         match self.mapping {
             EventToVariableMapping::Value(value) => value,
-            EventToVariableMapping::EventDataRaw => e.data() as f64,
-            EventToVariableMapping::EventData32kPosition => sim_connect_32k_pos_to_f64(e.data()),
+            EventToVariableMapping::EventDataRaw => event_data as f64,
+            EventToVariableMapping::EventData32kPosition => sim_connect_32k_pos_to_f64(event_data),
             EventToVariableMapping::EventData32kPositionInverted => {
-                sim_connect_32k_pos_inv_to_f64(e.data())
+                sim_connect_32k_pos_inv_to_f64(event_data)
             }
-            EventToVariableMapping::EventDataToValue(func) => func(e.data()),
+            EventToVariableMapping::EventDataToValue(func) => func(event_data),
             EventToVariableMapping::CurrentValueToValue(func) => func(variables.read(&self.target)),
             EventToVariableMapping::EventDataAndCurrentValueToValue(func) => {
-                func(e.data(), variables.read(&self.target))
+                func(event_data, variables.read(&self.target))
             }
             EventToVariableMapping::SmoothPress(..) => variables.read(&self.target),
         }
@@ -806,7 +802,7 @@ impl HandleMessages for EventToVariable {
         match message {
             SimConnectRecv::Event(e) if e.id() == self.event_id => {
                 if self.debounce.should_handle() {
-                    let mapped_value = self.map_to_value(e, variables);
+                    let mapped_value = self.map_to_value(e.data(), variables);
                     variables.write(&self.target, mapped_value);
 
                     self.debounce.notify_handled();
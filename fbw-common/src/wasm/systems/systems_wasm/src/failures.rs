@@ -5,25 +5,58 @@ use msfs::legacy::NamedVariable;
 
 use fxhash::FxHashMap;
 
-use systems::failures::FailureType;
+use systems::failures::{FailureState, FailureTrigger, FailureType};
 
 pub(super) struct Failures {
+    key_prefix: String,
     activate_sim_var: NamedVariable,
     deactivate_sim_var: NamedVariable,
+    arm_sim_var: NamedVariable,
+    arm_trigger_sim_var: NamedVariable,
+    arm_trigger_value_sim_var: NamedVariable,
+    disarm_sim_var: NamedVariable,
     identifier_to_failure_type: FxHashMap<u64, FailureType>,
+    identifier_to_state_sim_var: FxHashMap<u64, NamedVariable>,
+    identifier_to_written_state: FxHashMap<u64, FailureState>,
 }
 impl Failures {
-    pub(super) fn new(activate_sim_var: NamedVariable, deactivate_sim_var: NamedVariable) -> Self {
+    pub(super) fn new(key_prefix: &str) -> Self {
         Self {
-            activate_sim_var,
-            deactivate_sim_var,
+            key_prefix: key_prefix.to_owned(),
+            activate_sim_var: Self::named_variable(key_prefix, "FAILURE_ACTIVATE"),
+            deactivate_sim_var: Self::named_variable(key_prefix, "FAILURE_DEACTIVATE"),
+            arm_sim_var: Self::named_variable(key_prefix, "FAILURE_ARM"),
+            arm_trigger_sim_var: Self::named_variable(key_prefix, "FAILURE_ARM_TRIGGER"),
+            arm_trigger_value_sim_var: Self::named_variable(
+                key_prefix,
+                "FAILURE_ARM_TRIGGER_VALUE",
+            ),
+            disarm_sim_var: Self::named_variable(key_prefix, "FAILURE_DISARM"),
             identifier_to_failure_type: FxHashMap::default(),
+            identifier_to_state_sim_var: FxHashMap::default(),
+            identifier_to_written_state: FxHashMap::default(),
         }
     }
 
+    fn named_variable(key_prefix: &str, name: &str) -> NamedVariable {
+        NamedVariable::from(&format!("{}{}", key_prefix, name))
+    }
+
     pub(super) fn add(&mut self, identifier: u64, failure_type: FailureType) {
         self.identifier_to_failure_type
             .insert(identifier, failure_type);
+        self.identifier_to_state_sim_var.insert(
+            identifier,
+            Self::named_variable(&self.key_prefix, &format!("FAILURE_STATE_{}", identifier)),
+        );
+    }
+
+    /// The identifier the simulator uses for the given failure.
+    pub(super) fn identifier(&self, failure_type: FailureType) -> Option<u64> {
+        self.identifier_to_failure_type
+            .iter()
+            .find(|(_, &candidate)| candidate == failure_type)
+            .map(|(&identifier, _)| identifier)
     }
 
     pub(super) fn read_failure_activate(&self) -> Option<FailureType> {
@@ -34,6 +67,37 @@ impl Failures {
         self.read_failure(&self.deactivate_sim_var)
     }
 
+    pub(super) fn read_failure_disarm(&self) -> Option<FailureType> {
+        self.read_failure(&self.disarm_sim_var)
+    }
+
+    /// Reads the failure to arm together with its trigger, see [`FailureTrigger::decode`].
+    pub(super) fn read_failure_arm(&self) -> Option<(FailureType, FailureTrigger)> {
+        let trigger_type: f64 = self.arm_trigger_sim_var.get_value();
+        let value: f64 = self.arm_trigger_value_sim_var.get_value();
+        let trigger = FailureTrigger::decode(trigger_type as u8, value);
+
+        let failure_type = self.read_failure(&self.arm_sim_var)?;
+        self.arm_trigger_sim_var.set_value(0.);
+        self.arm_trigger_value_sim_var.set_value(0.);
+
+        trigger.map(|trigger| (failure_type, trigger))
+    }
+
+    /// Writes the state of every failure whose state changed since it was last written:
+    /// 0 when inactive, 1 when armed and 2 when active.
+    pub(super) fn write_failure_states(&mut self, state_of: impl Fn(FailureType) -> FailureState) {
+        for (identifier, sim_var) in self.identifier_to_state_sim_var.iter() {
+            if let Some(failure_type) = self.identifier_to_failure_type.get(identifier) {
+                let state = state_of(*failure_type);
+                if self.identifier_to_written_state.get(identifier) != Some(&state) {
+                    sim_var.set_value(state as u8 as f64);
+                    self.identifier_to_written_state.insert(*identifier, state);
+                }
+            }
+        }
+    }
+
     fn read_failure(&self, from: &NamedVariable) -> Option<FailureType> {
         let identifier: f64 = from.get_value();
         if let Some(failure_type) = self.identifier_to_failure_type.get(&(identifier as u64)) {
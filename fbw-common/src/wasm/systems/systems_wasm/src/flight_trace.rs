@@ -0,0 +1,63 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    time::Duration,
+};
+
+use systems::simulation::{FlightTraceEvent, FlightTraceRecorder, StartState};
+
+/// Captures the simulator variables read by the simulation into a flight trace file,
+/// such that a session can be replayed outside of the simulator. The records of the
+/// trace are appended to the file periodically, as the simulator gives no guarantee
+/// that a last frame is handled before the aircraft is unloaded.
+pub(super) struct FlightTraceCapture {
+    path: String,
+    recorder: FlightTraceRecorder,
+    unsaved_bytes: Vec<u8>,
+    time_since_save: Duration,
+}
+impl FlightTraceCapture {
+    const SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+    pub(super) fn new(path: &str, start_state: StartState, random_seed: u64) -> Self {
+        // The trace of the previous session is replaced.
+        let _ = File::create(path);
+
+        Self {
+            path: path.to_owned(),
+            recorder: FlightTraceRecorder::new(start_state, random_seed),
+            unsaved_bytes: Vec::new(),
+            time_since_save: Duration::default(),
+        }
+    }
+
+    pub(super) fn recorder(&mut self) -> &mut FlightTraceRecorder {
+        &mut self.recorder
+    }
+
+    pub(super) fn record_event(&mut self, event: FlightTraceEvent) {
+        self.recorder.record_event(event);
+    }
+
+    pub(super) fn update(&mut self, delta: Duration) {
+        self.time_since_save += delta;
+
+        if self.time_since_save >= Self::SAVE_INTERVAL {
+            self.time_since_save = Duration::default();
+            self.unsaved_bytes.extend(self.recorder.take_bytes());
+
+            // Saving is best effort. Bytes which couldn't be appended are retried
+            // at the next interval.
+            if self.append_unsaved_bytes().is_ok() {
+                self.unsaved_bytes.clear();
+            }
+        }
+    }
+
+    fn append_unsaved_bytes(&self) -> std::io::Result<()> {
+        OpenOptions::new()
+            .append(true)
+            .open(&self.path)?
+            .write_all(&self.unsaved_bytes)
+    }
+}
@@ -0,0 +1,114 @@
+//! A headless, in-process stand-in for Microsoft Flight Simulator. It drives the
+//! [MsfsHandler] and thus the aspects, failures and simulation built through the
+//! [crate::MsfsSimulationBuilder], without the need for the simulator itself.
+//!
+//! Variables live in a store which is local to the current thread. Creating a
+//! [HeadlessSimulator] clears that store, thus only one headless simulator
+//! should be active per thread.
+use crate::msfs::{
+    sim_connect::{RecvEvent, RecvSimObjectData, SimConnect, SimConnectRecv},
+    store, GaugeDrawData, MSFSEvent,
+};
+use crate::{MsfsHandler, SimulationTime};
+use msfs::sys;
+use std::{error::Error, time::Duration};
+use systems::simulation::{Aircraft, Simulation};
+
+pub struct HeadlessSimulator<T: Aircraft> {
+    simulation: Simulation<T>,
+    handler: MsfsHandler,
+    sim_connect: SimConnect<'static>,
+    simulation_time: Duration,
+}
+impl<T: Aircraft> HeadlessSimulator<T> {
+    /// Creates a headless simulator. The given function builds the simulation and handler,
+    /// usually through a [crate::MsfsSimulationBuilder] configured like the aircraft's gauge.
+    pub fn new(
+        build: impl FnOnce(&mut SimConnect) -> Result<(Simulation<T>, MsfsHandler), Box<dyn Error>>,
+    ) -> Result<Self, Box<dyn Error>> {
+        store::clear();
+
+        let mut sim_connect = SimConnect::default();
+        let (simulation, handler) = build(&mut sim_connect)?;
+
+        Ok(Self {
+            simulation,
+            handler,
+            sim_connect,
+            simulation_time: Duration::ZERO,
+        })
+    }
+
+    /// Advances the simulation time by the given delta and draws a frame,
+    /// which executes a single tick of the simulation.
+    pub fn tick(&mut self, delta: Duration) -> Result<(), Box<dyn Error>> {
+        self.simulation_time += delta;
+        let simulation_time = RecvSimObjectData::new(
+            SimulationTime::REQUEST_ID,
+            SimulationTime {
+                value: self.simulation_time.as_secs_f64(),
+            },
+        );
+        self.handle(MSFSEvent::SimConnect(SimConnectRecv::SimObjectData(
+            &simulation_time,
+        )))?;
+
+        self.handle(MSFSEvent::PreDraw(&GaugeDrawData {}))?;
+        self.handle(MSFSEvent::PostDraw(&GaugeDrawData {}))
+    }
+
+    /// Sends the event with the given name and data to every client event mapped to it.
+    pub fn send_event(&mut self, event_name: &str, data: sys::DWORD) -> Result<(), Box<dyn Error>> {
+        let ids = self.sim_connect.client_event_ids(event_name);
+        if ids.is_empty() {
+            return Err(format!("No client event is mapped to event '{}'.", event_name).into());
+        }
+
+        ids.into_iter().try_for_each(|id| {
+            let event = RecvEvent::new(id, data);
+            self.handle(MSFSEvent::SimConnect(SimConnectRecv::Event(&event)))
+        })
+    }
+
+    /// Indicates if the event with the given name is masked, meaning
+    /// the simulator itself no longer processes it.
+    pub fn event_is_masked(&self, event_name: &str) -> bool {
+        self.sim_connect.event_is_masked(event_name)
+    }
+
+    /// Returns the events transmitted to the simulator since the previous call,
+    /// as pairs of event name and data.
+    pub fn take_transmitted_events(&mut self) -> Vec<(String, sys::DWORD)> {
+        self.sim_connect.take_transmitted_events()
+    }
+
+    /// Returns the calculator code executed since the previous call.
+    pub fn take_executed_calculator_code(&mut self) -> Vec<String> {
+        store::take_executed_calculator_code()
+    }
+
+    /// The number of times data of the type with the given name was set on the user's sim
+    /// object. The name is matched against the end of the full type name.
+    pub fn data_on_sim_object_writes(&self, type_name: &str) -> usize {
+        self.sim_connect.data_on_sim_object_writes(type_name)
+    }
+
+    pub fn write_aircraft_variable(&mut self, name: &str, index: usize, value: f64) {
+        store::write(&store::aircraft_variable_name(name, index), value);
+    }
+
+    /// Writes the named variable. The name includes the key prefix, e.g. `A32NX_`.
+    pub fn write_named_variable(&mut self, name: &str, value: f64) {
+        store::write(name, value);
+    }
+
+    /// Reads the named variable. The name includes the key prefix, e.g. `A32NX_`.
+    pub fn read_named_variable(&self, name: &str) -> f64 {
+        store::read(name)
+    }
+
+    fn handle(&mut self, event: MSFSEvent) -> Result<(), Box<dyn Error>> {
+        self.handler
+            .handle(event, &mut self.simulation, &mut self.sim_connect)
+    }
+}
@@ -3,30 +3,47 @@ pub mod aspects;
 mod electrical;
 mod engines;
 mod failures;
+mod flight_trace;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod headless;
 mod msfs;
+mod persistence;
 
 #[cfg(not(target_arch = "wasm32"))]
 use crate::msfs::legacy::{AircraftVariable, NamedVariable};
 #[cfg(target_arch = "wasm32")]
 use ::msfs::legacy::{AircraftVariable, NamedVariable};
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::msfs::MSFSEvent;
+#[cfg(target_arch = "wasm32")]
+use ::msfs::MSFSEvent;
+
+/// The SimConnect types used by the bridging layer. On non-wasm targets these are
+/// provided by the headless stand-in, such that aspects can be driven natively.
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::msfs::sim_connect;
+#[cfg(target_arch = "wasm32")]
+pub use ::msfs::sim_connect;
+
 use crate::aspects::{Aspect, ExecuteOn, MsfsAspectBuilder};
 use crate::electrical::{auxiliary_power_unit, electrical_buses};
 use crate::engines::engines;
-use ::msfs::{
-    sim_connect::{data_definition, Period, SimConnect, SimConnectRecv, SIMCONNECT_OBJECT_ID_USER},
-    sys, MSFSEvent,
-};
+use ::msfs::{sim_connect::data_definition, sys};
 use failures::Failures;
+use flight_trace::FlightTraceCapture;
 use fxhash::FxHashMap;
+use persistence::PersistentState;
+use sim_connect::{Period, SimConnect, SimConnectRecv, SIMCONNECT_OBJECT_ID_USER};
 use std::fmt::{Display, Formatter};
 use std::{error::Error, time::Duration};
-use systems::shared::ElectricalBusType;
+use systems::shared::{ElectricalBusType, SimulationRandom};
 use systems::simulation::{InitContext, StartState};
 use systems::{
     failures::FailureType,
     simulation::{
-        Aircraft, Simulation, SimulatorReaderWriter, VariableIdentifier, VariableRegistry,
+        Aircraft, FlightTraceEvent, Simulation, SimulatorReaderWriter, VariableIdentifier,
+        VariableRegistry,
     },
 };
 
@@ -38,6 +55,9 @@ pub struct MsfsSimulationBuilder<'a, 'b> {
     start_state: StartState,
     sim_connect: &'a mut SimConnect<'b>,
     failures: Option<Failures>,
+    persistent_state: Option<PersistentState>,
+    flight_trace_path: Option<String>,
+    random_seed: Option<u64>,
     aspects: Vec<Box<dyn Aspect>>,
 }
 
@@ -55,6 +75,9 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
             key_prefix: key_prefix.into(),
             sim_connect,
             failures: None,
+            persistent_state: None,
+            flight_trace_path: None,
+            random_seed: None,
             aspects: vec![],
         }
     }
@@ -64,11 +87,46 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
         aircraft_ctor_fn: U,
     ) -> Result<(Simulation<T>, MsfsHandler), Box<dyn Error>> {
         let mut registry = self.variable_registry.unwrap();
-        let simulation = Simulation::new(self.start_state, aircraft_ctor_fn, &mut registry);
+        let random_seed = self
+            .random_seed
+            .unwrap_or_else(SimulationRandom::entropy_seed);
+        // Logged such that a session can be reproduced by passing the seed to `with_random_seed`.
+        println!("SYSTEM WASM RANDOM SEED {}.", random_seed);
+
+        let mut flight_trace = self
+            .flight_trace_path
+            .map(|path| FlightTraceCapture::new(&path, self.start_state, random_seed));
+        let mut simulation = match &mut flight_trace {
+            Some(flight_trace) => Simulation::new_with_random_seed(
+                self.start_state,
+                aircraft_ctor_fn,
+                &mut flight_trace.recorder().variable_registry(&mut registry),
+                random_seed,
+            ),
+            None => Simulation::new_with_random_seed(
+                self.start_state,
+                aircraft_ctor_fn,
+                &mut registry,
+                random_seed,
+            ),
+        };
+        if let Some(persistent_state) = &self.persistent_state {
+            let snapshot = persistent_state.restore_into(&mut simulation);
+            if let (Some(snapshot), Some(flight_trace)) = (snapshot, &mut flight_trace) {
+                flight_trace.record_event(FlightTraceEvent::PersistentStateRestored(snapshot));
+            }
+        }
 
         Ok((
             simulation,
-            MsfsHandler::new(registry, self.aspects, self.failures, self.sim_connect)?,
+            MsfsHandler::new(
+                registry,
+                self.aspects,
+                self.failures,
+                self.persistent_state,
+                flight_trace,
+                self.sim_connect,
+            )?,
         ))
     }
 
@@ -116,10 +174,7 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
     }
 
     pub fn with_failures(mut self, failures: Vec<(u64, FailureType)>) -> Self {
-        let mut f = Failures::new(
-            NamedVariable::from(&format!("{}{}", &self.key_prefix, "FAILURE_ACTIVATE")),
-            NamedVariable::from(&format!("{}{}", &self.key_prefix, "FAILURE_DEACTIVATE")),
-        );
+        let mut f = Failures::new(&self.key_prefix);
         for failure in failures {
             f.add(failure.0, failure.1);
         }
@@ -129,6 +184,35 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
         self
     }
 
+    /// Persists the state which outlives a simulator session, such as component wear,
+    /// in the file at the given path.
+    pub fn with_persistent_state(mut self, path: &str) -> Self {
+        self.persistent_state = Some(PersistentState::new(path));
+
+        self
+    }
+
+    /// Records the simulator variables read by the simulation into a flight trace in the
+    /// file at the given path, when the given variable is set at the time the simulation
+    /// is built. The trace can be replayed outside of the simulator.
+    pub fn with_flight_trace(mut self, path: &str, enabled_variable: Variable) -> Self {
+        let enabled_variable_value: VariableValue = (&enabled_variable).into();
+        if enabled_variable_value.read() > 0. {
+            self.flight_trace_path = Some(path.to_owned());
+        }
+
+        self
+    }
+
+    /// Seeds the random number generator of the simulation with the given seed, instead
+    /// of with a seed taken from entropy. The seed of a session is logged when the
+    /// simulation is built.
+    pub fn with_random_seed(mut self, seed: u64) -> Self {
+        self.random_seed = Some(seed);
+
+        self
+    }
+
     pub fn provides_aircraft_variable(
         mut self,
         name: &str,
@@ -160,6 +244,8 @@ pub struct MsfsHandler {
     variables: Option<MsfsVariableRegistry>,
     aspects: Vec<Box<dyn Aspect>>,
     failures: Option<Failures>,
+    persistent_state: Option<PersistentState>,
+    flight_trace: Option<FlightTraceCapture>,
     time: Time,
 }
 impl MsfsHandler {
@@ -167,12 +253,16 @@ impl MsfsHandler {
         variables: MsfsVariableRegistry,
         aspects: Vec<Box<dyn Aspect>>,
         failures: Option<Failures>,
+        persistent_state: Option<PersistentState>,
+        flight_trace: Option<FlightTraceCapture>,
         sim_connect: &mut SimConnect,
     ) -> Result<Self, Box<dyn Error>> {
         Ok(Self {
             variables: Some(variables),
             aspects,
             failures,
+            persistent_state,
+            flight_trace,
             time: Time::new(sim_connect)?,
         })
     }
@@ -189,10 +279,36 @@ impl MsfsHandler {
                     let delta_time = self.time.take();
                     self.pre_tick(sim_connect, delta_time)?;
                     if let Some(failures) = &self.failures {
-                        Self::read_failures_into_simulation(failures, simulation);
+                        Self::read_failures_into_simulation(
+                            failures,
+                            simulation,
+                            &mut self.flight_trace,
+                        );
+                    }
+
+                    let simulation_time = self.time.simulation_time();
+                    if let Some(mut flight_trace) = self.flight_trace.take() {
+                        simulation.tick(
+                            delta_time,
+                            simulation_time,
+                            &mut flight_trace
+                                .recorder()
+                                .frame(delta_time, simulation_time, self),
+                        );
+                        flight_trace.update(delta_time);
+                        self.flight_trace = Some(flight_trace);
+                    } else {
+                        simulation.tick(delta_time, simulation_time, self);
+                    }
+                    if let Some(failures) = &mut self.failures {
+                        failures.write_failure_states(|failure_type| {
+                            simulation.failure_state(failure_type)
+                        });
+                    }
+                    if let Some(persistent_state) = &mut self.persistent_state {
+                        persistent_state.update(delta_time, simulation);
                     }
 
-                    simulation.tick(delta_time, self.time.simulation_time(), self);
                     self.post_tick(sim_connect)?;
                 }
             }
@@ -254,16 +370,42 @@ impl MsfsHandler {
         Ok(())
     }
 
+    /// Reads the failures to change into the simulation. The changes are recorded into
+    /// the flight trace, as a replay has to make them at the same frame.
     fn read_failures_into_simulation<T: Aircraft>(
         failures: &Failures,
         simulation: &mut Simulation<T>,
+        flight_trace: &mut Option<FlightTraceCapture>,
     ) {
+        let mut record = |failure_type, event: &dyn Fn(u64) -> FlightTraceEvent| {
+            if let (Some(flight_trace), Some(identifier)) =
+                (flight_trace.as_mut(), failures.identifier(failure_type))
+            {
+                flight_trace.record_event(event(identifier));
+            }
+        };
+
         if let Some(failure_type) = failures.read_failure_activate() {
             simulation.activate_failure(failure_type);
+            record(failure_type, &FlightTraceEvent::FailureActivated);
         }
 
         if let Some(failure_type) = failures.read_failure_deactivate() {
             simulation.deactivate_failure(failure_type);
+            record(failure_type, &FlightTraceEvent::FailureDeactivated);
+        }
+
+        if let Some((failure_type, trigger)) = failures.read_failure_arm() {
+            simulation.arm_failure(failure_type, trigger);
+            record(failure_type, &|identifier| FlightTraceEvent::FailureArmed {
+                identifier,
+                trigger,
+            });
+        }
+
+        if let Some(failure_type) = failures.read_failure_disarm() {
+            simulation.disarm_failure(failure_type);
+            record(failure_type, &FlightTraceEvent::FailureDisarmed);
         }
     }
 }
@@ -1,47 +1,283 @@
-//! Module declared to be able to compile documentation.
-//! Does not provide any meaningful functionality.
+//! Module declared to be able to compile documentation and to run the bridging layer natively.
+//! On non-wasm targets it provides a headless, in-process stand-in for the parts of
+//! Microsoft Flight Simulator the bridging layer interacts with. See [crate::headless].
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) mod legacy {
-    use std::cell::Cell;
-    use std::rc::Rc;
+    use super::store;
 
-    pub fn execute_calculator_code<T>(_code: &str) {}
+    pub fn execute_calculator_code<T>(code: &str) {
+        store::execute_calculator_code(code);
+    }
 
     #[derive(Debug)]
-    pub struct AircraftVariable {}
+    pub struct AircraftVariable {
+        name: String,
+    }
 
     impl AircraftVariable {
         pub fn from(
-            _name: &str,
+            name: &str,
             _units: &str,
-            _index: usize,
+            index: usize,
         ) -> Result<Self, Box<dyn std::error::Error>> {
-            Ok(Self {})
+            Ok(Self {
+                name: store::aircraft_variable_name(name, index),
+            })
         }
 
         pub fn get(&self) -> f64 {
-            0.
+            store::read(&self.name)
         }
     }
 
     #[derive(Debug)]
     pub struct NamedVariable {
-        value: Rc<Cell<f64>>,
+        name: String,
     }
 
     impl NamedVariable {
-        pub fn from(_name: &str) -> Self {
+        pub fn from(name: &str) -> Self {
             Self {
-                value: Rc::new(Cell::new(0.)),
+                name: name.to_owned(),
             }
         }
 
         pub fn get_value(&self) -> f64 {
-            self.value.get()
+            store::read(&self.name)
         }
 
         pub fn set_value(&self, value: f64) {
-            self.value.set(value);
+            store::write(&self.name, value);
+        }
+    }
+}
+
+/// Stores the variables and executed calculator code of the headless simulator.
+/// The store is local to the thread, such that tests running in parallel don't interfere.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) mod store {
+    use fxhash::FxHashMap;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    struct Store {
+        variables: FxHashMap<String, f64>,
+        executed_calculator_code: Vec<String>,
+    }
+
+    thread_local! {
+        static STORE: RefCell<Store> = RefCell::new(Store::default());
+    }
+
+    pub(crate) fn aircraft_variable_name(name: &str, index: usize) -> String {
+        if index > 0 {
+            format!("{}:{}", name, index)
+        } else {
+            name.to_owned()
         }
     }
+
+    pub(crate) fn read(name: &str) -> f64 {
+        STORE.with(|store| {
+            store
+                .borrow()
+                .variables
+                .get(name)
+                .copied()
+                .unwrap_or_default()
+        })
+    }
+
+    pub(crate) fn write(name: &str, value: f64) {
+        STORE.with(|store| {
+            store.borrow_mut().variables.insert(name.to_owned(), value);
+        });
+    }
+
+    pub(crate) fn execute_calculator_code(code: &str) {
+        STORE.with(|store| {
+            store
+                .borrow_mut()
+                .executed_calculator_code
+                .push(code.to_owned())
+        });
+    }
+
+    pub(crate) fn take_executed_calculator_code() -> Vec<String> {
+        STORE.with(|store| std::mem::take(&mut store.borrow_mut().executed_calculator_code))
+    }
+
+    pub(crate) fn clear() {
+        STORE.with(|store| *store.borrow_mut() = Store::default());
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod sim_connect {
+    use fxhash::FxHashMap;
+    use msfs::sys;
+    use std::{any::Any, error::Error, marker::PhantomData};
+
+    pub const SIMCONNECT_OBJECT_ID_USER: sys::DWORD = 0;
+
+    pub enum Period {
+        Never,
+        Once,
+        VisualFrame,
+        SimFrame,
+        Second,
+    }
+
+    /// A stand-in for the SimConnect connection. Client events are mapped to
+    /// identifiers and everything sent to the simulator is recorded, such that
+    /// it can be asserted upon.
+    #[derive(Default)]
+    pub struct SimConnect<'a> {
+        client_events: Vec<ClientEvent>,
+        transmitted_events: Vec<(sys::DWORD, sys::DWORD)>,
+        data_on_sim_object_writes: FxHashMap<&'static str, usize>,
+        _lifetime: PhantomData<&'a ()>,
+    }
+
+    struct ClientEvent {
+        name: String,
+        is_masked: bool,
+    }
+
+    impl<'a> SimConnect<'a> {
+        pub fn map_client_event_to_sim_event(
+            &mut self,
+            event_name: &str,
+            mask: bool,
+        ) -> Result<sys::DWORD, Box<dyn Error>> {
+            self.client_events.push(ClientEvent {
+                name: event_name.to_owned(),
+                is_masked: mask,
+            });
+
+            Ok((self.client_events.len() - 1) as sys::DWORD)
+        }
+
+        pub fn transmit_client_event(
+            &mut self,
+            _object_id: sys::DWORD,
+            event_id: sys::DWORD,
+            data: sys::DWORD,
+        ) -> Result<(), Box<dyn Error>> {
+            self.transmitted_events.push((event_id, data));
+
+            Ok(())
+        }
+
+        pub fn request_data_on_sim_object<T>(
+            &mut self,
+            _request_id: sys::DWORD,
+            _object_id: sys::DWORD,
+            _period: Period,
+        ) -> Result<(), Box<dyn Error>> {
+            Ok(())
+        }
+
+        pub fn set_data_on_sim_object<T>(
+            &mut self,
+            _object_id: sys::DWORD,
+            _data: &T,
+        ) -> Result<(), Box<dyn Error>> {
+            *self
+                .data_on_sim_object_writes
+                .entry(std::any::type_name::<T>())
+                .or_default() += 1;
+
+            Ok(())
+        }
+
+        /// The identifiers of the client events mapped to the event with the given name.
+        pub(crate) fn client_event_ids(&self, event_name: &str) -> Vec<sys::DWORD> {
+            self.client_events
+                .iter()
+                .enumerate()
+                .filter(|(_, event)| event.name == event_name)
+                .map(|(id, _)| id as sys::DWORD)
+                .collect()
+        }
+
+        pub(crate) fn event_is_masked(&self, event_name: &str) -> bool {
+            self.client_events
+                .iter()
+                .any(|event| event.name == event_name && event.is_masked)
+        }
+
+        pub(crate) fn take_transmitted_events(&mut self) -> Vec<(String, sys::DWORD)> {
+            std::mem::take(&mut self.transmitted_events)
+                .into_iter()
+                .map(|(id, data)| (self.client_events[id as usize].name.clone(), data))
+                .collect()
+        }
+
+        /// The number of times data of the type with the given name was set on the
+        /// user's sim object. The name is matched against the end of the full type name.
+        pub(crate) fn data_on_sim_object_writes(&self, type_name: &str) -> usize {
+            self.data_on_sim_object_writes
+                .iter()
+                .filter(|(full_name, _)| full_name.ends_with(type_name))
+                .map(|(_, count)| count)
+                .sum()
+        }
+    }
+
+    pub enum SimConnectRecv<'a> {
+        Event(&'a RecvEvent),
+        SimObjectData(&'a RecvSimObjectData),
+    }
+
+    pub struct RecvEvent {
+        id: sys::DWORD,
+        data: sys::DWORD,
+    }
+
+    impl RecvEvent {
+        pub(crate) fn new(id: sys::DWORD, data: sys::DWORD) -> Self {
+            Self { id, data }
+        }
+
+        pub fn id(&self) -> sys::DWORD {
+            self.id
+        }
+
+        pub fn data(&self) -> sys::DWORD {
+            self.data
+        }
+    }
+
+    pub struct RecvSimObjectData {
+        request_id: sys::DWORD,
+        data: Box<dyn Any>,
+    }
+
+    impl RecvSimObjectData {
+        pub(crate) fn new<T: Any>(request_id: sys::DWORD, data: T) -> Self {
+            Self {
+                request_id,
+                data: Box::new(data),
+            }
+        }
+
+        pub fn id(&self) -> sys::DWORD {
+            self.request_id
+        }
+
+        pub fn into<T: Any>(&self, _sim_connect: &SimConnect) -> Option<&T> {
+            self.data.downcast_ref()
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub struct GaugeDrawData {}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub enum MSFSEvent<'a> {
+    PreDraw(&'a GaugeDrawData),
+    PostDraw(&'a GaugeDrawData),
+    SimConnect(sim_connect::SimConnectRecv<'a>),
 }
@@ -0,0 +1,56 @@
+use std::{fs, time::Duration};
+
+use systems::simulation::{Aircraft, Simulation, SimulationSnapshot};
+
+/// Stores the state which outlives a simulator session, such as component wear, in a file.
+/// The state is restored when the simulation is built and saved periodically afterwards.
+pub(super) struct PersistentState {
+    path: String,
+    time_since_save: Duration,
+}
+impl PersistentState {
+    const SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+    pub(super) fn new(path: &str) -> Self {
+        Self {
+            path: path.to_owned(),
+            time_since_save: Duration::default(),
+        }
+    }
+
+    /// Restores the state from the file and returns the restored snapshot. A missing file
+    /// leaves the simulation in its initial state, as is the case on the very first session.
+    /// A file which can't be restored, e.g. as it was written in an older format, is discarded.
+    pub(super) fn restore_into<T: Aircraft>(
+        &self,
+        simulation: &mut Simulation<T>,
+    ) -> Option<SimulationSnapshot> {
+        let bytes = fs::read(&self.path).ok()?;
+        let result = SimulationSnapshot::from_bytes(&bytes).and_then(|snapshot| {
+            simulation.restore_persistent(&snapshot)?;
+            Ok(snapshot)
+        });
+
+        match result {
+            Ok(snapshot) => Some(snapshot),
+            Err(error) => {
+                eprintln!(
+                    "Discarded the persistent state in \"{}\": {}",
+                    self.path, error
+                );
+                None
+            }
+        }
+    }
+
+    pub(super) fn update<T: Aircraft>(&mut self, delta: Duration, simulation: &mut Simulation<T>) {
+        self.time_since_save += delta;
+
+        if self.time_since_save >= Self::SAVE_INTERVAL {
+            self.time_since_save = Duration::default();
+
+            // Saving is best effort, failing to do so only loses the state of the last interval.
+            let _ = fs::write(&self.path, simulation.persistent_snapshot().to_bytes());
+        }
+    }
+}